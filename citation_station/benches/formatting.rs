@@ -0,0 +1,112 @@
+//! Formatting of single citations, author lists and whole bibliographies.
+//!
+//! ```sh
+//! cargo bench --bench formatting
+//! ```
+
+use chrono::Month;
+use citation_station::{
+    api::{
+        author::{AcademicAuthor, PersonName},
+        citation::Citation,
+        date::PublishDate,
+        media::{common::CommonCitationData, journal_article::JournalArticle},
+        page_range::{ArticleLocator, PageRange},
+        style::CitationStyle,
+        title::Title,
+        volume::{Issue, Volume},
+    },
+    bibliography::Bibliography,
+};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+const SURNAMES: [&str; 8] = [
+    "Smith",
+    "Fuentes",
+    "Nakamura",
+    "Okafor",
+    "Lindqvist",
+    "Dubois",
+    "Kowalski",
+    "Haddad",
+];
+
+fn authors(count: usize, seed: usize) -> AcademicAuthor {
+    let persons = (0..count)
+        .map(|index| {
+            let surname = SURNAMES[(seed + index) % SURNAMES.len()];
+            PersonName::parse(&format!("{}, Jane Q.", surname)).unwrap()
+        })
+        .collect();
+    AcademicAuthor::Persons { persons }
+}
+
+fn article(index: usize, author_count: usize) -> Citation {
+    Citation::JournalArticle(JournalArticle {
+        common_data: CommonCitationData {
+            published: PublishDate::from_year_month_day(
+                1990 + (index % 35) as i32,
+                Month::March,
+                1 + (index % 28) as u32,
+            )
+            .ok(),
+            ..CommonCitationData::new(format!("article{}", index))
+        },
+        author: authors(author_count, index),
+        title: Title::with_subtitle("On the behaviour of examples", format!("number {}", index)),
+        journal: "Journal of Examples".to_string(),
+        volume: Some(Volume::parse(&(index % 60).to_string())),
+        number: Some(Issue::parse("3")),
+        locator: Some(ArticleLocator::Pages(PageRange {
+            start: 100,
+            end: 110,
+        })),
+        doi: Some(format!("10.1000/example.{}", index)),
+    })
+}
+
+fn single_citation(c: &mut Criterion) {
+    let citation = article(0, 3);
+    c.bench_function("citation/apa", |b| {
+        b.iter(|| citation.format(CitationStyle::Apa))
+    });
+    c.bench_function("citation/ieee", |b| {
+        b.iter(|| citation.format(CitationStyle::Ieee))
+    });
+}
+
+fn author_lists(c: &mut Criterion) {
+    let mut group = c.benchmark_group("authors");
+    for count in [1, 3, 8, 25] {
+        let author = authors(count, 0);
+        group.bench_with_input(BenchmarkId::new("apa", count), &author, |b, author| {
+            b.iter(|| author.as_apa_string())
+        });
+        group.bench_with_input(BenchmarkId::new("ieee", count), &author, |b, author| {
+            b.iter(|| author.as_ieee_string())
+        });
+    }
+    group.finish();
+}
+
+fn bibliography_10k(c: &mut Criterion) {
+    let mut bibliography = Bibliography::new();
+    for index in 0..10_000 {
+        bibliography
+            .add_citation(article(index, 1 + index % 5))
+            .unwrap();
+    }
+
+    let mut group = c.benchmark_group("bibliography_10k");
+    group.sample_size(10);
+    group.bench_function("apa", |b| {
+        b.iter(|| bibliography.format_reference_list(CitationStyle::Apa))
+    });
+    group.bench_function("ieee", |b| {
+        b.iter(|| bibliography.format_reference_list(CitationStyle::Ieee))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, single_citation, author_lists, bibliography_10k);
+criterion_main!(benches);
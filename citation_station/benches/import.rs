@@ -0,0 +1,98 @@
+//! Parsing of importer responses and bibliography files, and writing of
+//! BibTeX. No network access is involved.
+//!
+//! ```sh
+//! cargo bench --bench import
+//! ```
+
+use citation_station::{
+    bibliography::Bibliography,
+    cff::software_from_cff,
+    export::ExportOptions,
+    import::{
+        bookmarks::citations_from_bookmarks, doi::citation_from_crossref, freeform::parse_freeform,
+    },
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+const CROSSREF_JSON: &str = r#"{
+    "status": "ok",
+    "message": {
+        "DOI": "10.1000/XYZ123",
+        "type": "journal-article",
+        "title": ["A great paper"],
+        "author": [
+            {"given": "Jane Q.", "family": "Smith"},
+            {"given": "Humberto", "family": "Fuentes"}
+        ],
+        "container-title": ["Journal of Examples"],
+        "volume": "12",
+        "issue": "3",
+        "page": "100-110",
+        "published": {"date-parts": [[2023, 3]]}
+    }
+}"#;
+
+const CITATION_CFF: &str = "cff-version: 1.2.0
+message: If you use this software, please cite it as below.
+title: Citation Station
+version: 0.3.1
+doi: 10.5281/zenodo.1
+date-released: 2025-06-02
+authors:
+  - family-names: Smith
+    given-names: Jane
+  - family-names: Fuentes
+    given-names: Humberto
+";
+
+fn bookmarks(count: usize) -> String {
+    let mut html = String::from("<!DOCTYPE NETSCAPE-Bookmark-file-1>\n<DL><p>\n");
+    for index in 0..count {
+        html.push_str(&format!(
+            "<DT><A HREF=\"https://example.com/{}\" ADD_DATE=\"1700000000\">Page {}</A>\n",
+            index, index
+        ));
+    }
+    html.push_str("</DL><p>\n");
+    html
+}
+
+const IEEE_REFERENCE: &str = "[4] J. Smith and H. Fuentes, \u{201C}Repairing DNA,\u{201D} \
+    J. Tests, vol. 12, no. 3, pp. 3\u{2013}9, Mar. 2020.";
+
+fn responses(c: &mut Criterion) {
+    c.bench_function("crossref", |b| {
+        b.iter(|| citation_from_crossref(CROSSREF_JSON).unwrap())
+    });
+    c.bench_function("citation_cff", |b| {
+        b.iter(|| software_from_cff(CITATION_CFF).unwrap())
+    });
+    c.bench_function("freeform/ieee", |b| {
+        b.iter(|| parse_freeform(IEEE_REFERENCE).unwrap())
+    });
+}
+
+fn bibliography_files(c: &mut Criterion) {
+    let html = bookmarks(1_000);
+    c.bench_function("bookmarks_1k", |b| {
+        b.iter(|| citations_from_bookmarks(&html))
+    });
+
+    let mut bibliography = Bibliography::new();
+    bibliography.import_bookmarks(&html);
+    let json = serde_json::to_string(&bibliography).unwrap();
+    c.bench_function("bibliography_json_1k/load", |b| {
+        b.iter(|| serde_json::from_str::<Bibliography>(&json).unwrap())
+    });
+    c.bench_function("bibliography_json_1k/save", |b| {
+        b.iter(|| serde_json::to_string(&bibliography).unwrap())
+    });
+    let options = ExportOptions::default();
+    c.bench_function("bibtex_1k/write", |b| {
+        b.iter(|| bibliography.to_bibtex(&options))
+    });
+}
+
+criterion_group!(benches, responses, bibliography_files);
+criterion_main!(benches);
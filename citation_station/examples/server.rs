@@ -0,0 +1,34 @@
+//! Serve citation formatting and a bibliography over HTTP.
+//!
+//! ```sh
+//! cargo run --example server --features server -- my_bibliography.json
+//! curl -X POST --data @citation.json 'http://127.0.0.1:8080/format?style=ieee'
+//! ```
+//!
+//! The bibliography file is loaded if it exists. Citations added by
+//! clients are kept in memory only.
+
+use std::{env, path::Path, sync::Arc};
+
+use citation_station::{
+    bibliography::Bibliography,
+    server::{Service, serve},
+};
+use tokio::{net::TcpListener, runtime::Runtime};
+
+const ADDRESS: &str = "127.0.0.1:8080";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let bibliography = match env::args().nth(1) {
+        Some(path) if Path::new(&path).exists() => Bibliography::load(path)?,
+        _ => Bibliography::new(),
+    };
+    let service = Arc::new(Service::new(bibliography));
+
+    Runtime::new()?.block_on(async {
+        let listener = TcpListener::bind(ADDRESS).await?;
+        println!("Listening on http://{}", ADDRESS);
+        serve(listener, service).await
+    })?;
+    Ok(())
+}
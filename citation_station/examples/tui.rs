@@ -0,0 +1,305 @@
+//! A small terminal reference manager built on `citation_station`.
+//!
+//! ```sh
+//! cargo run --example tui --features tui -- my_bibliography.json
+//! ```
+//!
+//! Keys: `/` search, `a` add a book, `d` delete, `u`/`r` undo/redo a delete,
+//! `s` switch style, `↑`/`↓` move, `q` save and quit.
+
+use std::{io, path::PathBuf};
+
+use citation_station::{
+    api::{
+        author::{GenericAuthor, PersonName},
+        citation::Citation,
+        date::PublishDate,
+        media::{book::Book, common::CommonCitationData},
+        style::CitationStyle,
+        title::Title,
+    },
+    bibliography::Bibliography,
+};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+const ADD_FIELDS: [&str; 4] = ["ID", "Title", "Authors (First Last; ...)", "Year"];
+
+enum Mode {
+    Browse,
+    Search,
+    Add { field: usize, values: [String; 4] },
+}
+
+struct App {
+    path: PathBuf,
+    bibliography: Bibliography,
+    style: CitationStyle,
+    query: String,
+    list: ListState,
+    mode: Mode,
+    status: String,
+}
+
+impl App {
+    fn open(path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
+        let (bibliography, status) = if path.exists() {
+            let bibliography = Bibliography::load(&path)?;
+            let status = format!("Loaded {} citations", bibliography.len());
+            (bibliography, status)
+        } else {
+            (Bibliography::new(), "New bibliography".to_string())
+        };
+        let mut list = ListState::default();
+        list.select_first();
+        Ok(Self {
+            path,
+            bibliography,
+            style: CitationStyle::Apa,
+            query: String::new(),
+            list,
+            mode: Mode::Browse,
+            status,
+        })
+    }
+
+    fn visible(&self) -> Vec<&Citation> {
+        self.bibliography.search(&self.query)
+    }
+
+    fn selected_id(&self) -> Option<String> {
+        let visible = self.visible();
+        let index = self.list.selected()?.min(visible.len().checked_sub(1)?);
+        Some(visible[index].id())
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match &mut self.mode {
+                Mode::Browse => match key.code {
+                    KeyCode::Char('q') => return Ok(()),
+                    KeyCode::Char('/') => self.mode = Mode::Search,
+                    KeyCode::Char('a') => {
+                        self.mode = Mode::Add {
+                            field: 0,
+                            values: Default::default(),
+                        }
+                    }
+                    KeyCode::Char('d') => self.delete_selected(),
+                    KeyCode::Char('u') => {
+                        self.status = if self.bibliography.undo() {
+                            "Undone".to_string()
+                        } else {
+                            "Nothing to undo".to_string()
+                        }
+                    }
+                    KeyCode::Char('r') => {
+                        self.status = if self.bibliography.redo() {
+                            "Redone".to_string()
+                        } else {
+                            "Nothing to redo".to_string()
+                        }
+                    }
+                    KeyCode::Char('s') => {
+                        let index = CitationStyle::ALL
+                            .iter()
+                            .position(|style| *style == self.style)
+                            .unwrap_or_default();
+                        self.style = CitationStyle::ALL[(index + 1) % CitationStyle::ALL.len()];
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+                    KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+                    _ => {}
+                },
+                Mode::Search => match key.code {
+                    KeyCode::Enter | KeyCode::Esc => self.mode = Mode::Browse,
+                    KeyCode::Backspace => {
+                        self.query.pop();
+                        self.list.select_first();
+                    }
+                    KeyCode::Char(c) => {
+                        self.query.push(c);
+                        self.list.select_first();
+                    }
+                    _ => {}
+                },
+                Mode::Add { field, values } => match key.code {
+                    KeyCode::Esc => self.mode = Mode::Browse,
+                    KeyCode::Tab | KeyCode::Down => *field = (*field + 1) % ADD_FIELDS.len(),
+                    KeyCode::BackTab | KeyCode::Up => {
+                        *field = (*field + ADD_FIELDS.len() - 1) % ADD_FIELDS.len()
+                    }
+                    KeyCode::Backspace => {
+                        values[*field].pop();
+                    }
+                    KeyCode::Char(c) => values[*field].push(c),
+                    KeyCode::Enter => {
+                        let values = values.clone();
+                        self.add_book(&values);
+                    }
+                    _ => {}
+                },
+            }
+        }
+    }
+
+    fn delete_selected(&mut self) {
+        if let Some(id) = self.selected_id() {
+            self.bibliography.begin_snapshot();
+            self.bibliography.remove_citation(&id);
+            self.status = format!("Deleted '{}'", id);
+        }
+    }
+
+    fn add_book(&mut self, [id, title, authors, year]: &[String; 4]) {
+        match book_from_form(id, title, authors, year) {
+            Ok(citation) => match self.bibliography.add_citation(citation) {
+                Ok(()) => {
+                    self.status = format!("Added '{}'", id.trim());
+                    self.mode = Mode::Browse;
+                }
+                Err(err) => self.status = err.to_string(),
+            },
+            Err(message) => self.status = message,
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).areas(frame.area());
+        let [list_area, detail_area] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(main);
+
+        let items: Vec<ListItem> = self
+            .visible()
+            .iter()
+            .map(|citation| ListItem::new(citation.summary()))
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "Citations ({}) {}",
+                self.bibliography.len(),
+                if self.query.is_empty() {
+                    String::new()
+                } else {
+                    format!("matching \"{}\"", self.query)
+                }
+            )))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, list_area, &mut self.list);
+
+        let detail = match &self.mode {
+            Mode::Add { field, values } => ADD_FIELDS
+                .iter()
+                .zip(values)
+                .enumerate()
+                .map(|(index, (label, value))| {
+                    let marker = if index == *field { ">" } else { " " };
+                    Line::from(format!("{} {}: {}", marker, label, value))
+                })
+                .collect::<Vec<Line>>(),
+            _ => self
+                .selected_id()
+                .and_then(|id| self.bibliography.get_citation(&id))
+                .map(|citation| vec![Line::from(citation.format(self.style))])
+                .unwrap_or_default(),
+        };
+        let detail_title = match (&self.mode, self.style) {
+            (Mode::Add { .. }, _) => "Add book (Tab: next field, Enter: save, Esc: cancel)",
+            (_, style) => style.name(),
+        };
+        frame.render_widget(
+            Paragraph::new(detail)
+                .wrap(Wrap { trim: false })
+                .block(Block::default().borders(Borders::ALL).title(detail_title)),
+            detail_area,
+        );
+
+        let help = match self.mode {
+            Mode::Search => format!("/{}", self.query),
+            _ => format!(
+                "{} | /: search  a: add  d: delete  u/r: undo/redo  s: style  q: save & quit",
+                self.status
+            ),
+        };
+        frame.render_widget(
+            Paragraph::new(help).block(Block::default().borders(Borders::ALL)),
+            footer,
+        );
+    }
+}
+
+fn book_from_form(id: &str, title: &str, authors: &str, year: &str) -> Result<Citation, String> {
+    let id = id.trim();
+    let title = title.trim();
+    if id.is_empty() || title.is_empty() {
+        return Err("ID and title are required".to_string());
+    }
+
+    let persons = authors
+        .split(';')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| PersonName::parse(name).map_err(|err| err.to_string()))
+        .collect::<Result<Vec<PersonName>, String>>()?;
+    if persons.is_empty() {
+        return Err("At least one author is required".to_string());
+    }
+
+    let published = match year.trim() {
+        "" => None,
+        year => Some(PublishDate::from_year(
+            year.parse()
+                .map_err(|_| format!("Invalid year: {}", year))?,
+        )),
+    };
+
+    Ok(Citation::Book(Book {
+        common_data: CommonCitationData {
+            published,
+            ..CommonCitationData::new(id)
+        },
+        author: GenericAuthor::Persons { persons },
+        with_authors: Vec::new(),
+        title: Title::parse(title),
+        doi: None,
+        pages: None,
+        chapter: None,
+        version: None,
+    }))
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("bibliography.json"));
+    let mut app = App::open(path)?;
+
+    let mut terminal = ratatui::init();
+    let result = app.run(&mut terminal);
+    ratatui::restore();
+    result?;
+
+    app.bibliography.save(&app.path)?;
+    println!(
+        "Saved {} citations to {}",
+        app.bibliography.len(),
+        app.path.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use citation_station::import::arxiv::citation_from_datacite;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|json: &str| {
+    if let Ok(citation) = citation_from_datacite(json) {
+        let _ = citation.format_apa();
+        let _ = citation.format_ieee();
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use citation_station::{
+    api::style::CitationStyle,
+    bibtex::{citation_to_bibtex, read_bibtex},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let report = read_bibtex(text);
+    for citation in &report.citations {
+        for style in CitationStyle::ALL {
+            let _ = citation.try_format(style);
+        }
+        // Whatever was read must be writable again
+        let entry = citation_to_bibtex(citation);
+        assert!(entry.starts_with('@') && entry.ends_with('}'), "{}", entry);
+    }
+});
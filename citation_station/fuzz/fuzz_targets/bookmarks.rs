@@ -0,0 +1,11 @@
+#![no_main]
+
+use citation_station::import::bookmarks::read_bookmarks;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|html: &str| {
+    for citation in &read_bookmarks(html).citations {
+        let _ = citation.format_apa();
+        let _ = citation.format_ieee();
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use citation_station::cff::{software_from_cff, software_to_cff};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Ok(software) = software_from_cff(text) {
+        // Whatever was read must survive a round trip
+        let written = software_to_cff(&software);
+        assert!(software_from_cff(&written).is_ok(), "{}", written);
+    }
+});
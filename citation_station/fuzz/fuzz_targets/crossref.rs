@@ -0,0 +1,11 @@
+#![no_main]
+
+use citation_station::import::doi::citation_from_crossref;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|json: &str| {
+    if let Ok(citation) = citation_from_crossref(json) {
+        let _ = citation.format_apa();
+        let _ = citation.format_ieee();
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use citation_station::{api::style::CitationStyle, csv::read_csv};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    for citation in &read_csv(text).citations {
+        for style in CitationStyle::ALL {
+            let _ = citation.try_format(style);
+        }
+    }
+});
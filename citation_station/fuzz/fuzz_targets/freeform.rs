@@ -0,0 +1,12 @@
+#![no_main]
+
+use citation_station::import::freeform::parse_freeform;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Some(parsed) = parse_freeform(text) {
+        assert!((0.0..=1.0).contains(&parsed.style_confidence));
+        let _ = parsed.citation.format_apa();
+        let _ = parsed.citation.format_ieee();
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use citation_station::{api::date::AccessDate, import::github::software_from_github};
+use libfuzzer_sys::fuzz_target;
+
+// The repository response, release response and CITATION.cff, separated by
+// NUL bytes; the last two are optional
+fuzz_target!(|input: &str| {
+    let mut parts = input.split('\0');
+    let repo = parts.next().unwrap_or_default();
+    let (release, cff) = (parts.next(), parts.next());
+    let _ = software_from_github(repo, release, cff, AccessDate::default());
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use citation_station::{api::date::AccessDate, import::web::citation_from_html};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|html: &str| {
+    if let Ok(citation) = citation_from_html(html, "https://example.com/", AccessDate::default()) {
+        let _ = citation.format_apa();
+        let _ = citation.format_ieee();
+    }
+});
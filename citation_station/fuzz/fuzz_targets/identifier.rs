@@ -0,0 +1,15 @@
+#![no_main]
+
+use citation_station::import::identifier::Identifier;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Some(identifier) = Identifier::detect(input) {
+        // Normalized identifiers are recognized as themselves
+        assert!(
+            Identifier::detect(identifier.value()).is_some(),
+            "{:?}",
+            identifier
+        );
+    }
+});
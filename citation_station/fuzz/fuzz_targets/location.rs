@@ -0,0 +1,14 @@
+#![no_main]
+
+use citation_station::api::{location::LocationData, style::CitationStyle};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    if let Some(location) = LocationData::parse(text) {
+        let _ = location.find_country();
+        for style in CitationStyle::ALL {
+            let _ = location.format(style);
+            let _ = location.format_event(style);
+        }
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use citation_station::notes::{citation_from_note, citation_to_note};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|note: &str| {
+    if let Some(citation) = citation_from_note(note) {
+        // Whatever was read must be readable again once written
+        let written = citation_to_note(&citation);
+        assert!(citation_from_note(&written).is_some(), "{}", written);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use citation_station::import::onix::read_onix;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|xml: &str| {
+    if let Ok(report) = read_onix(xml) {
+        for citation in &report.citations {
+            let _ = citation.format_apa();
+            let _ = citation.format_ieee();
+        }
+    }
+});
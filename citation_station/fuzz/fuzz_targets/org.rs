@@ -0,0 +1,16 @@
+#![no_main]
+
+use citation_station::org::{citations_to_org, read_org};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let report = read_org(text);
+    // Whatever was read must be readable again once written
+    let written = citations_to_org(&report.citations);
+    assert_eq!(
+        read_org(&written).citations.len(),
+        report.citations.len(),
+        "{}",
+        written
+    );
+});
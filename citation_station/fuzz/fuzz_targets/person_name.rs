@@ -0,0 +1,13 @@
+#![no_main]
+
+use citation_station::api::author::PersonName;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|name: &str| {
+    if let Ok(person) = PersonName::parse(name) {
+        let _ = person.as_apa_string();
+        let _ = person.as_ieee_string();
+        let _ = person.initials();
+        let _ = person.full_name();
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use citation_station::api::date::PublishDate;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|date: &str| {
+    if let Some(date) = PublishDate::parse_iso(date) {
+        let _ = date.fmt_for_apa_citation();
+        let _ = date.fmt_for_ieee_citation();
+    }
+});
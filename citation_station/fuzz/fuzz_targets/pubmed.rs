@@ -0,0 +1,11 @@
+#![no_main]
+
+use citation_station::import::pubmed::doi_from_esummary;
+use libfuzzer_sys::fuzz_target;
+
+// The first line is the PMID that was asked for, the rest the response
+fuzz_target!(|input: &str| {
+    if let Some((pmid, json)) = input.split_once('\n') {
+        let _ = doi_from_esummary(json, pmid);
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use citation_station::{
+    api::style::CitationStyle,
+    ris::{citation_to_ris, read_ris},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let report = read_ris(text);
+    for citation in &report.citations {
+        for style in CitationStyle::ALL {
+            let _ = citation.try_format(style);
+        }
+        // Whatever was read must be writable again
+        let record = citation_to_ris(citation);
+        assert!(
+            record.starts_with("TY  - ") && record.ends_with("ER  - "),
+            "{}",
+            record
+        );
+    }
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use citation_station::{
+    api::{date::AccessDate, media::wikipedia_article::WikipediaArticle},
+    import::wikipedia::article_with_revision,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|json: &str| {
+    let article = WikipediaArticle::from_url(
+        "https://en.wikipedia.org/wiki/Oil_painting",
+        AccessDate::default(),
+    )
+    .unwrap();
+    if let Ok(article) = article_with_revision(article, json) {
+        assert!(article.revision.is_some());
+    }
+});
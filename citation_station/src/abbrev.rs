@@ -1,314 +1,538 @@
+//! Abbreviated journal and conference names, following the ISO 4 rules
+//! and the List of Title Word Abbreviations (LTWA) that IEEE, NLM and
+//! CAS abbreviations are based on.
+//!
+//! [`abbreviate_venue`] drops articles, prepositions and conjunctions and
+//! abbreviates each remaining word, except for names listed in
+//! [`VENUE_ABBREVIATIONS`] whose official abbreviation differs.
+//! [`expand_venue`] reverses the list, for styles such as APA that want
+//! full names. [`ieee_proceedings_venue`] builds IEEE's "Proc." phrase
+//! for conference names.
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use ordinal::ToOrdinal as _;
+
+/// Title words and their abbreviations, sorted by word
+// cSpell: disable
+const WORD_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("Abstracts", "Abstr."),
+    ("Academy", "Acad."),
+    ("Accelerator", "Accel."),
+    ("Acoustics", "Acoust."),
+    ("Active", "Act."),
+    ("Administration", "Admin."),
+    ("Administrative", "Administ."),
+    ("Advanced", "Adv."),
+    ("Aeronautics", "Aeronaut."),
+    ("Aerospace", "Aerosp."),
+    ("Affective", "Affect."),
+    ("Afric", "Afr."),
+    ("African", "Afr."),
+    ("Aircraft", "Aircr."),
+    ("Algebraic", "Algebr."),
+    ("American", "Amer."),
+    ("Analysis", "Anal."),
+    ("Annals", "Ann."),
+    ("Annual", "Annu."),
+    ("Apparatus", "App."),
+    ("Applications", "Appl."),
+    ("Applied", "Appl."),
+    ("Approximate", "Approx."),
+    ("Architecture", "Archit."),
+    ("Archiv", "Arch."),
+    ("Archives", "Arch."),
+    ("Artificial", "Artif."),
+    ("Assembly", "Assem."),
+    ("Association", "Assoc."),
+    ("Astronautics", "Astronaut."),
+    ("Astronomy", "Astron."),
+    ("Astrophysics", "Astrophys."),
+    ("Atmosphere", "Atmos."),
+    ("Atomic", "At."),
+    ("Atoms", "At."),
+    ("Australasian", "Australas."),
+    ("Australia", "Aust."),
+    ("Automatic", "Autom."),
+    ("Automation", "Automat."),
+    ("Automotive", "Automot."),
+    ("Autonomous", "Auton."),
+    ("Behavior", "Behav."),
+    ("Behavioral", "Behav."),
+    ("Belgian", "Belg."),
+    ("Biochemical", "Biochem."),
+    ("Bioinformatics", "Bioinf."),
+    ("Biological", "Biol."),
+    ("Biology", "Biol."),
+    ("Biomedical", "Biomed."),
+    ("Biophysics", "Biophys."),
+    ("British", "Brit."),
+    ("Broadcasting", "Broadcast."),
+    ("Bulletin", "Bull."),
+    ("Bureau", "Bur."),
+    ("Business", "Bus."),
+    ("Canadian", "Can."),
+    ("Ceramic", "Ceram."),
+    ("Chemical", "Chem."),
+    ("Chinese", "Chin."),
+    ("Climatology", "Climatol."),
+    ("Clinical", "Clin."),
+    ("Cognitive", "Cogn."),
+    ("Colloquium", "Colloq."),
+    ("Communications", "Commun."),
+    ("Compatibility", "Compat."),
+    ("Component", "Compon."),
+    ("Components", "Compon."),
+    ("Computational", "Comput."),
+    ("Computer", "Comput."),
+    ("Computers", "Comput."),
+    ("Computing", "Comput."),
+    ("Condensed", "Condens."),
+    ("Conference", "Conf."),
+    ("Congress", "Congr."),
+    ("Consumer", "Consum."),
+    ("Convention", "Conv."),
+    ("Conversion", "Convers."),
+    ("Correspondence", "Corresp."),
+    ("Critical", "Crit."),
+    ("Crystal", "Cryst."),
+    ("Crystallography", "Crystallogr."),
+    ("Cybernetics", "Cybern."),
+    ("Decision", "Decis."),
+    ("Delivery", "Del."),
+    ("Department", "Dept."),
+    ("Design", "Des."),
+    ("Detector", "Detect."),
+    ("Development", "Develop."),
+    ("Developmental", "Develop."),
+    ("Differential", "Differ."),
+    ("Digest", "Dig."),
+    ("Digital", "Digit."),
+    ("Disclosure", "Discl."),
+    ("Discussions", "Discuss."),
+    ("Dissertations", "Diss."),
+    ("Distributed", "Distrib."),
+    ("Dynamics", "Dyn."),
+    ("Earthquake", "Earthq."),
+    ("Economic", "Econ."),
+    ("Economics", "Econ."),
+    ("Edition", "Ed."),
+    ("Education", "Educ."),
+    ("Electrical", "Elect."),
+    ("Electrification", "Electrific."),
+    ("Electroacoustic", "Electroacoust."),
+    ("Electromagnetic", "Electromagn."),
+    ("Electronic", "Electron."),
+    ("Emerging", "Emerg."),
+    ("Engineering", "Eng."),
+    ("Environment", "Environ."),
+    ("Equations", "Equ."),
+    ("Equipment", "Equip."),
+    ("Ergonomics", "Ergonom."),
+    ("European", "Eur."),
+    ("Evaluation", "Eval."),
+    ("Evolutionary", "Evol."),
+    ("Exhibition", "Exhib."),
+    ("Experimental", "Exp."),
+    ("Exploratory", "Explor."),
+    ("Exposition", "Expo."),
+    ("Express", "Exp."),
+    ("Fabrication", "Fabr."),
+    ("Faculty", "Fac."),
+    ("Ferroelectrics", "Ferroelect."),
+    ("Foundation", "Found."),
+    ("Francais", "Fr."),
+    ("French", "Fr."),
+    ("Frequency", "Freq."),
+    ("Fundamental", "Fundam."),
+    ("Generation", "Gener."),
+    ("Geology", "Geol."),
+    ("Geophysics", "Geophys."),
+    ("Geoscience", "Geosci."),
+    ("Graphics", "Graph."),
+    ("Guidance", "Guid."),
+    ("Harmonic", "Harmon."),
+    ("Harmonics", "Harmon."),
+    ("History", "Hist."),
+    ("Horizon", "Horiz."),
+    ("Hungarian", "Hung."),
+    ("Hungary", "Hung."),
+    ("Hydraulics", "Hydraul."),
+    ("Hydrology", "Hydrol."),
+    ("Illuminating", "Illum."),
+    ("Imaging", "Imag."),
+    ("Industrial", "Ind."),
+    ("Informatics", "Inform."),
+    ("Information", "Inf."),
+    ("Innovation", "Innov."),
+    ("Institute", "Inst."),
+    ("Instrument", "Instrum."),
+    ("Instrumentation", "Instrum."),
+    ("Insulation", "Insul."),
+    ("Integrated", "Integr."),
+    ("Intelligence", "Intell."),
+    ("Intelligent", "Intell."),
+    ("Interactions", "Interact."),
+    ("International", "Int."),
+    ("Isotopes", "Isot."),
+    ("Israel", "Isr."),
+    ("Japan", "Jpn."),
+    ("Journal", "J."),
+    ("Knowledge", "Knowl."),
+    ("Laboratories", "Lab."),
+    ("Laboratory", "Lab."),
+    ("Language", "Lang."),
+    ("Learning", "Learn."),
+    ("Letter", "Lett."),
+    ("Letters", "Lett."),
+    ("Lightwave", "Lightw."),
+    ("Logic", "Log."),
+    ("Logical", "Log."),
+    ("Luminescence", "Lumin."),
+    ("Machine", "Mach."),
+    ("Magazine", "Mag."),
+    ("Magnetics", "Magn."),
+    ("Management", "Manage."),
+    ("Managing", "Manag."),
+    ("Manufacturing", "Manuf."),
+    ("Marine", "Mar."),
+    ("Material", "Mater."),
+    ("Mathematical", "Math."),
+    ("Mathematics", "Math."),
+    ("Measurement", "Meas."),
+    ("Mechanical", "Mech."),
+    ("Medical", "Med."),
+    ("Medicine", "Med."),
+    ("Metallurgy", "Metall."),
+    ("Metals", "Met."),
+    ("Meteorology", "Meteorol."),
+    ("Metropolitan", "Metrop."),
+    ("Mexican", "Mex."),
+    ("Mexico", "Mex."),
+    ("Microelectromechanical", "Microelectromech."),
+    ("Microgravity", "Microgr."),
+    ("Microscopy", "Microsc."),
+    ("Microwave", "Microw."),
+    ("Microwaves", "Microw."),
+    ("Military", "Mil."),
+    ("Modeling", "Model."),
+    ("Molecular", "Mol."),
+    ("Monitoring", "Monit."),
+    ("Multiphysics", "Multiphys."),
+    ("Nanobioscience", "Nanobiosci."),
+    ("Nanotechnology", "Nanotechnol."),
+    ("National", "Nat."),
+    ("Naval", "Nav."),
+    ("Navigation", "Navig."),
+    ("Network", "Netw."),
+    ("Networking", "Netw."),
+    ("Newsletter", "Newslett."),
+    ("Nondestructive", "Nondestruct."),
+    ("Nuclear", "Nucl."),
+    ("Numerical", "Numer."),
+    ("Observations", "Observ."),
+    ("Occupation", "Occupat."),
+    ("Oceanic", "Ocean."),
+    ("Oceanography", "Oceanogr."),
+    ("Operational", "Oper."),
+    ("Optical", "Opt."),
+    ("Optics", "Opt."),
+    ("Optimization", "Optim."),
+    ("Organization", "Org."),
+    ("Packaging", "Packag."),
+    ("Particle", "Part."),
+    ("Patent", "Pat."),
+    ("Performance", "Perform."),
+    ("Personal", "Pers."),
+    ("Philosophical", "Philos."),
+    ("Photonics", "Photon."),
+    ("Photovoltaics", "Photovolt."),
+    ("Physics", "Phys."),
+    ("Physiology", "Physiol."),
+    ("Planetary", "Planet."),
+    ("Pneumatics", "Pneum."),
+    ("Pollution", "Pollut."),
+    ("Polymer", "Polym."),
+    ("Polytechnic", "Polytech."),
+    ("Practice", "Pract."),
+    ("Precision", "Precis."),
+    ("Principles", "Princ."),
+    ("Proceedings", "Proc."),
+    ("Processing", "Process."),
+    ("Production", "Prod."),
+    ("Productivity", "Productiv."),
+    ("Programmable", "Program."),
+    ("Programming", "Program."),
+    ("Progress", "Prog."),
+    ("Propagation", "Propag."),
+    ("Psychology", "Psychol."),
+    ("Quality", "Qual."),
+    ("Quarterly", "Quart."),
+    ("Radiation", "Radiat."),
+    ("Radiology", "Radiol."),
+    ("Reactor", "React."),
+    ("Receivers", "Receiv."),
+    ("Recognition", "Recognit."),
+    ("Record", "Rec."),
+    ("Rehabilitation", "Rehabil."),
+    ("Reliability", "Rel."),
+    ("Report", "Rep."),
+    ("Research", "Res."),
+    ("Resonance", "Reson."),
+    ("Resources", "Resour."),
+    ("Review", "Rev."),
+    ("Robotics", "Robot."),
+    ("Royal", "Roy."),
+    ("Safety", "Saf."),
+    ("Satellite", "Satell."),
+    ("Scandinavian", "Scand."),
+    ("Science", "Sci."),
+    ("Section", "Sect."),
+    ("Security", "Secur."),
+    ("Seismology", "Seismol."),
+    ("Selected", "Sel."),
+    ("Semiconductor", "Semicond."),
+    ("Sensing", "Sens."),
+    ("Series", "Ser."),
+    ("Simulation", "Simul."),
+    ("Singapore", "Singap."),
+    ("Sistema", "Sist."),
+    ("Society", "Soc."),
+    ("Sociological", "Sociol."),
+    ("Software", "Softw."),
+    ("Solar", "Sol."),
+    ("Soviet", "Sov."),
+    ("Spectroscopy", "Spectrosc."),
+    ("Spectrum", "Spectr."),
+    ("Speculations", "Specul."),
+    ("Statistics", "Statist."),
+    ("Structure", "Struct."),
+    ("Studies", "Stud."),
+    ("Superconductivity", "Supercond."),
+    ("Supplement", "Suppl."),
+    ("Surface", "Surf."),
+    ("Survey", "Surv."),
+    ("Sustainable", "Sustain."),
+    ("Symposium", "Symp."),
+    ("Systems", "Syst."),
+    ("Technical", "Tech."),
+    ("Techniques", "Techn."),
+    ("Technology", "Technol."),
+    ("Telecommunications", "Telecommun."),
+    ("Television", "Telev."),
+    ("Temperature", "Temp."),
+    ("Terrestrial", "Terr."),
+    ("Theoretical", "Theor."),
+    ("Transactions", "Trans."),
+    ("Translation", "Transl."),
+    ("Transmission", "Transmiss."),
+    ("Transportation", "Transp."),
+    ("Tutorials", "Tut."),
+    ("Ultrasonic", "Ultrason."),
+    ("University", "Univ."),
+    ("Vacuum", "Vac."),
+    ("Vehicular", "Veh."),
+    ("Vibration", "Vib."),
+    ("Vision", "Vis."),
+    ("Visual", "Vis."),
+    ("Welding", "Weld."),
+    ("Working", "Work."),
+];
+
+/// Common venues whose abbreviation the word rules don't produce
+pub const VENUE_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("Communications of the ACM", "Commun. ACM"),
+    ("Journal of the ACM", "J. ACM"),
+    (
+        "Journal of the American Chemical Society",
+        "J. Am. Chem. Soc.",
+    ),
+    ("Journal of the American Medical Association", "JAMA"),
+    (
+        "Lecture Notes in Computer Science",
+        "Lect. Notes Comput. Sci.",
+    ),
+    ("New England Journal of Medicine", "N. Engl. J. Med."),
+    ("The New England Journal of Medicine", "N. Engl. J. Med."),
+    ("Physical Review Letters", "Phys. Rev. Lett."),
+    ("Proceedings of the IEEE", "Proc. IEEE"),
+    (
+        "Proceedings of the National Academy of Sciences",
+        "Proc. Natl. Acad. Sci. USA",
+    ),
+    (
+        "Proceedings of the National Academy of Sciences of the United States of America",
+        "Proc. Natl. Acad. Sci. USA",
+    ),
+];
+// cSpell: enable
+
+/// Words left out of abbreviated names: articles, prepositions and
+/// conjunctions
+const OMITTED_WORDS: [&str; 12] = [
+    "a", "an", "and", "at", "for", "in", "of", "on", "the", "to", "with", "&",
+];
+
+fn word_abbreviations() -> &'static HashMap<&'static str, &'static str> {
+    static WORDS: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| WORD_ABBREVIATIONS.iter().copied().collect())
+}
+
+/// The abbreviation of a single title word, or the word itself if it has
+/// none. Plurals are abbreviated like the singular.
 pub fn ieee_abbrev(full_word: &str) -> &str {
-    match full_word {
-        // cSpell: disable
-        "Abstracts" => "Abstr.",
-        "Academy" => "Acad.",
-        "Accelerator" => "Accel.",
-        "Acoustics" => "Acoust.",
-        "Active" => "Act.",
-        "Administration" => "Admin.",
-        "Administrative" => "Administ.",
-        "Advanced" => "Adv.",
-        "Aeronautics" => "Aeronaut.",
-        "Aerospace" => "Aerosp.",
-        "Affective" => "Affect.",
-        "Afric" | "African" => "Afr.",
-        "Aircraft" => "Aircr.",
-        "Algebraic" => "Algebr.",
-        "American" => "Amer.",
-        "Analysis" => "Anal.",
-        "Annals" => "Ann.",
-        "Annual" => "Annu.",
-        "Apparatus" => "App.",
-        "Applications" => "Appl.",
-        "Applied" => "Appl.",
-        "Approximate" => "Approx.",
-        "Architecture" => "Archit.",
-        "Archiv" | "Archives" => "Arch.",
-        "Artificial" => "Artif.",
-        "Assembly" => "Assem.",
-        "Association" => "Assoc.",
-        "Astronomy" => "Astron.",
-        "Astronautics" => "Astronaut.",
-        "Astrophysics" => "Astrophys.",
-        "Atmosphere" => "Atmos.",
-        "Atomic" | "Atoms" => "At.",
-        "Australasian" => "Australas.",
-        "Australia" => "Aust.",
-        "Automatic" => "Autom.",
-        "Automation" => "Automat.",
-        "Automotive" => "Automot.",
-        "Autonomous" => "Auton.",
-        "Behavior" | "Behavioral" => "Behav.",
-        "Belgian" => "Belg.",
-        "Biochemical" => "Biochem.",
-        "Bioinformatics" => "Bioinf.",
-        "Biolog" => ", Biological Biol.",
-        "Biomedical" => "Biomed.",
-        "Biophysics" => "Biophys.",
-        "British" => "Brit.",
-        "Broadcasting" => "Broadcast.",
-        "Bulletin" => "Bull.",
-        "Bureau" => "Bur.",
-        "Business" => "Bus.",
-        "Canadian" => "Can.",
-        "Ceramic" => "Ceram.",
-        "Chemical" => "Chem.",
-        "Chinese" => "Chin.",
-        "Climatology" => "Climatol.",
-        "Clinical" => "Clin.",
-        "Cognitive" => "Cogn.",
-        "Colloquium" => "Colloq.",
-        "Communications" => "Commun.",
-        "Compatibility" => "Compat.",
-        "Componen" => "(s) Compon.",
-        "Computational" | "Computer" | "Computers" | "Computing" => "Comput.",
-        "Condensed" => "Condens.",
-        "Conference" => "Conf.",
-        "Congress" => "Congr.",
-        "Consumer" => "Consum.",
-        "Conversion" => "Convers.",
-        "Convention" => "Conv.",
-        "Correspondence" => "Corresp.",
-        "Critical" => "Crit.",
-        "Crystal" => "Cryst.",
-        "Crystallography" => "Crystallogr.",
-        "Cybernetics" => "Cybern.",
-        "Decision" => "Decis.",
-        "Delivery" => "Del.",
-        "Department" => "Dept.",
-        "Design" => "Des.",
-        "Detector" => "Detect.",
-        "Development" | "Developmental" => "Develop.",
-        "Differential" => "Differ.",
-        "Digest" => "Dig.",
-        "Digital" => "Digit.",
-        "Disclosure" => "Discl.",
-        "Discussions" => "Discuss.",
-        "Dissertations" => "Diss.",
-        "Distributed" => "Distrib.",
-        "Dynamics" => "Dyn.",
-        "Earthquake" => "Earthq.",
-        "Economic" | "Economics" => "Econ.",
-        "Edition" => "Ed.",
-        "Education" => "Educ.",
-        "Electrical" => "Elect.",
-        "Electrification" => "Electrific.",
-        "Electromagnetic" => "Electromagn.",
-        "Electroacoustic" => "Electroacoust.",
-        "Electronic" => "Electron.",
-        "Emerging" => "Emerg.",
-        "Engineering" => "Eng.",
-        "Environment" => "Environ.",
-        "Equations" => "Equ.",
-        "Equipment" => "Equip.",
-        "Ergonomics" => "Ergonom.",
-        "European" => "Eur.",
-        "Evaluation" => "Eval.",
-        "Evolutionary" => "Evol.",
-        "Exhibition" => "Exhib.",
-        "Experimental" => "Exp.",
-        "Exploratory" => "Explor.",
-        "Exposition" => "Expo.",
-        "Express" => "Exp.",
-        "Fabrication" => "Fabr.",
-        "Faculty" => "Fac.",
-        "Ferroelectrics" => "Ferroelect.",
-        "Francais" | "French" => "Fr.",
-        "Frequency" => "Freq.",
-        "Foundation" => "Found.",
-        "Fundamental" => "Fundam.",
-        "Generation" => "Gener.",
-        "Geology" => "Geol.",
-        "Geophysics" => "Geophys.",
-        "Geoscience" => "Geosci.",
-        "Graphics" => "Graph.",
-        "Guidance" => "Guid. ",
-        "Harmonic" | "Harmonics" => "Harmon.",
-        "History" => "Hist.",
-        "Horizon" => "Horiz.",
-        "Hungary" | "Hungarian" => "Hung.",
-        "Hydraulics" => "Hydraul.",
-        "Hydrology" => "Hydrol.",
-        "Illuminating" => "Illum.",
-        "Imaging" => "Imag.",
-        "Industrial" => "Ind.",
-        "Information" => "Inf.",
-        "Informatics" => "Inform.",
-        "Innovation" => "Innov.",
-        "Institute" => "Inst.",
-        "Instrument" => "Instrum.",
-        "Instrumentation" => "Instrum.",
-        "Insulation" => "Insul.",
-        "Integrated" => "Integr.",
-        "Intelligence" => "Intell.",
-        "Intelligent" => "Intell.",
-        "Interactions" => "Interact.",
-        "International" => "Int.",
-        "Isotopes" => "Isot.",
-        "Israel" => "Isr.",
-        "Japan" => "Jpn.",
-        "Journal" => "J.",
-        "Knowledge" => "Knowl.",
-        "Laboratory" | "Labratories" => "Lab.",
-        "Language" => "Lang.",
-        "Learning" => "Learn.",
-        "Letter" | "Letters" => "Lett.",
-        "Lightwave" => "Lightw.",
-        "Logic" | "Logical" => "Log.",
-        "Luminescence" => "Lumin.",
-        "Machine" => "Mach.",
-        "Magazine" => "Mag.",
-        "Magnetics" => "Magn.",
-        "Management" => "Manage.",
-        "Managing" => "Manag.",
-        "Manufacturing" => "Manuf.",
-        "Marine" => "Mar.",
-        "Material" => "Mater.",
-        "Mathematical" => "Math.",
-        "Mathematics" => "Math.",
-        "Measurement" => "Meas.",
-        "Mechanical" => "Mech.",
-        "Medical" | "Medicine" => "Med.",
-        "Metals" => "Met.",
-        "Metallurgy" => "Metall.",
-        "Meteorology" => "Meteorol.",
-        "Metropolitan" => "Metrop.",
-        "Mexican" | "Mexico" => "Mex.",
-        "Microelectromechanical" => "Microelectromech.",
-        "Microgravity" => "Microgr.",
-        "Microscopy" => "Microsc.",
-        "Microwave" | "Microwaves" => "Microw.",
-        "Military" => "Mil.",
-        "Modeling" => "Model.",
-        "Molecular" => "Mol.",
-        "Monitoring" => "Monit.",
-        "Multiphysics" => "Multiphys.",
-        "Nanobioscience" => "Nanobiosci.",
-        "Nanotechnology" => "Nanotechnol.",
-        "National" => "Nat.",
-        "Naval" => "Nav.",
-        "Navigation" => "Navig.",
-        "Network" | "Networking" => "Netw.",
-        "Newsletter" => "Newslett.",
-        "Nondestructive" => "Nondestruct.",
-        "Nuclear" => "Nucl.",
-        "Numerical" => "Numer.",
-        "Observations" => "Observ.",
-        "Oceanic" => "Ocean.",
-        "Oceanography" => "Oceanogr.",
-        "Occupation" => "Occupat.",
-        "Operational" => "Oper.",
-        "Optical" => "Opt.",
-        "Optics" => "Opt.",
-        "Optimization" => "Optim.",
-        "Organization" => "Org.",
-        "Packaging" => "Packag.",
-        "Particle" => "Part.",
-        "Patent" => "Pat.",
-        "Performance" => "Perform.",
-        "Personal" => "Pers.",
-        "Philosophical" => "Philos.",
-        "Photonics" => "Photon.",
-        "Photovoltaics" => "Photovolt.",
-        "Physics" => "Phys.",
-        "Physiology" => "Physiol.",
-        "Planetary" => "Planet.",
-        "Pneumatics" => "Pneum.",
-        "Pollution" => "Pollut.",
-        "Polymer" => "Polym.",
-        "Polytechnic" => "Polytech.",
-        "Practice" => "Pract.",
-        "Precision" => "Precis.",
-        "Principles" => "Princ.",
-        "Proceedings" => "Proc.",
-        "Processing" => "Process.",
-        "Production" => "Prod.",
-        "Productivity" => "Productiv.",
-        "Programmable" => "Program.",
-        "Programming" => "Program.",
-        "Progress" => "Prog.",
-        "Propagation" => "Propag.",
-        "Psychology" => "Psychol.",
-        "Quality" => "Qual.",
-        "Quarterly" => "Quart.",
-        "Radiation" => "Radiat.",
-        "Radiology" => "Radiol.",
-        "Reactor" => "React.",
-        "Receivers" => "Receiv.",
-        "Recognition" => "Recognit.",
-        "Record" => "Rec.",
-        "Rehabilitation" => "Rehabil.",
-        "Reliability" => "Rel.",
-        "Report" => "Rep.",
-        "Research" => "Res.",
-        "Resonance" => "Reson.",
-        "Resources" => "Resour.",
-        "Review" => "Rev.",
-        "Robotics" => "Robot.",
-        "Royal" => "Roy.",
-        "Safety" => "Saf.",
-        "Satellite" => "Satell.",
-        "Scandinavian" => "Scand.",
-        "Science" => "Sci.",
-        "Section" => "Sect.",
-        "Security" => "Secur.",
-        "Seismology" => "Seismol.",
-        "Selected" => "Sel.",
-        "Semiconductor" => "Semicond.",
-        "Sensing" => "Sens.",
-        "Series" => "Ser.",
-        "Simulation" => "Simul.",
-        "Singapore" => "Singap.",
-        "Sistema" => "Sist.",
-        "Society" => "Soc.",
-        "Sociological" => "Sociol.",
-        "Software" => "Softw.",
-        "Solar" => "Sol.",
-        "Soviet" => "Sov.",
-        "Spectroscopy" => "Spectrosc.",
-        "Spectrum" => "Spectr.",
-        "Speculations" => "Specul.",
-        "Statistics" => "Statist.",
-        "Structure" => "Struct.",
-        "Studies" => "Stud.",
-        "Superconductivity" => "Supercond.",
-        "Supplement" => "Suppl.",
-        "Surface" => "Surf.",
-        "Survey" => "Surv.",
-        "Sustainable" => "Sustain.",
-        "Symposium" => "Symp.",
-        "Systems" => "Syst.",
-        "Technical" => "Tech.",
-        "Techniques" => "Techn.",
-        "Technology" => "Technol.",
-        "Telecommunications" => "Telecommun.",
-        "Television" => "Telev.",
-        "Temperature" => "Temp.",
-        "Terrestrial" => "Terr.",
-        "Theoretical" => "Theor.",
-        "Transactions" => "Trans.",
-        "Translation" => "Transl.",
-        "Transmission" => "Transmiss.",
-        "Transportation" => "Transp.",
-        "Tutorials" => "Tut.",
-        "Ultrasonic" => "Ultrason.",
-        "University" => "Univ.",
-        "Vacuum" => "Vac.",
-        "Vehicular" => "Veh.",
-        "Vibration" => "Vib.",
-        "Vision" => "Vis.",
-        "Visual" => "Vis.",
-        "Welding" => "Weld.",
-        "Working" => "Work.",
-        // cSpell: enable
-        _ => full_word,
+    let words = word_abbreviations();
+    words
+        .get(full_word)
+        .or_else(|| full_word.strip_suffix('s').and_then(|stem| words.get(stem)))
+        .copied()
+        .unwrap_or(full_word)
+}
+
+/// The abbreviated form of a journal or conference name, e.g. "IEEE
+/// Transactions on Software Engineering" becomes "IEEE Trans. Softw.
+/// Eng.". Names of a single word are left as they are.
+pub fn abbreviate_venue(name: &str) -> String {
+    let name = name.trim();
+    if let Some((_, abbreviation)) = VENUE_ABBREVIATIONS
+        .iter()
+        .find(|(full, _)| full.eq_ignore_ascii_case(name))
+    {
+        return abbreviation.to_string();
     }
+    if name.split_whitespace().nth(1).is_none() {
+        return name.to_string();
+    }
+
+    name.split_whitespace()
+        .filter(|word| !OMITTED_WORDS.contains(&word.to_lowercase().as_str()))
+        .map(|word| {
+            // Keep punctuation such as a subtitle's colon after the abbreviation
+            let bare = word.trim_end_matches([',', ':', ';']);
+            let punctuation = &word[bare.len()..];
+            format!("{}{}", ieee_abbrev(bare), punctuation)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The full name of an abbreviated venue listed in
+/// [`VENUE_ABBREVIATIONS`], e.g. "Proc. IEEE" becomes "Proceedings of the
+/// IEEE". Abbreviations made by the word rules can't be expanded reliably,
+/// since several words share an abbreviation.
+pub fn expand_venue(abbreviation: &str) -> Option<&'static str> {
+    let abbreviation = abbreviation.trim();
+    VENUE_ABBREVIATIONS
+        .iter()
+        .find(|(_, abbreviated)| *abbreviated == abbreviation)
+        .map(|(full, _)| *full)
+}
+
+/// Spelled-out ordinals below twenty, from "First"
+// cSpell: disable
+const SMALL_ORDINALS: [&str; 19] = [
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+];
+
+/// Tens, from "Twenty", as cardinals and as ordinals
+const TENS: [(&str, &str); 8] = [
+    ("twenty", "twentieth"),
+    ("thirty", "thirtieth"),
+    ("forty", "fortieth"),
+    ("fifty", "fiftieth"),
+    ("sixty", "sixtieth"),
+    ("seventy", "seventieth"),
+    ("eighty", "eightieth"),
+    ("ninety", "ninetieth"),
+];
+// cSpell: enable
+
+/// The number an ordinal word stands for, whether written in digits
+/// ("43rd") or spelled out ("Forty-Third")
+fn ordinal_value(word: &str) -> Option<u32> {
+    let word = word.to_lowercase();
+    if let Some(digits) = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        && !digits.is_empty()
+        && digits.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return digits.parse().ok();
+    }
+
+    let small = |word: &str| {
+        SMALL_ORDINALS
+            .iter()
+            .position(|ordinal| *ordinal == word)
+            .map(|index| index as u32 + 1)
+    };
+    if let Some(value) = small(&word) {
+        return Some(value);
+    }
+    TENS.iter()
+        .zip(2..)
+        .find_map(|((cardinal, ordinal), tens)| {
+            if word == *ordinal {
+                return Some(tens * 10);
+            }
+            let unit = small(word.strip_prefix(cardinal)?.strip_prefix('-')?)?;
+            (unit < 10).then_some(tens * 10 + unit)
+        })
+}
+
+/// The IEEE "Proc." phrase for a conference, e.g. "Proceedings of the
+/// Forty-Third Annual International Symposium on Computer Architecture"
+/// becomes "Proc. 43rd Annu. Int. Symp. Comput. Archit.". Ordinals are
+/// written in digits, and a leading "Proceedings of the" isn't repeated.
+pub fn ieee_proceedings_venue(conference_name: &str) -> String {
+    let name = conference_name.trim();
+    let name = ["proceedings of the ", "proceedings of ", "proc. "]
+        .iter()
+        .find_map(|prefix| {
+            name.get(..prefix.len())
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| &name[prefix.len()..])
+        })
+        .unwrap_or(name);
+
+    let name = name
+        .split_whitespace()
+        .map(|word| {
+            let bare = word.trim_end_matches([',', ':', ';']);
+            match ordinal_value(bare) {
+                Some(value) => format!("{}{}", value.to_ordinal_string(), &word[bare.len()..]),
+                None => word.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("Proc. {}", abbreviate_venue(&name))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::abbrev::ieee_abbrev;
+    use crate::abbrev::{
+        WORD_ABBREVIATIONS, abbreviate_venue, expand_venue, ieee_abbrev, ieee_proceedings_venue,
+        ordinal_value,
+    };
 
     #[test]
     fn test_passthrough() {
@@ -325,4 +549,82 @@ mod tests {
         // cSpell: disable-next-line
         assert_eq!(ieee_abbrev("Computing"), "Comput.");
     }
+
+    #[test]
+    fn test_word_table_is_sorted() {
+        assert!(
+            WORD_ABBREVIATIONS
+                .windows(2)
+                .all(|pair| pair[0].0 < pair[1].0)
+        );
+        assert!(
+            WORD_ABBREVIATIONS
+                .iter()
+                .all(|(_, abbreviation)| abbreviation.trim() == *abbreviation)
+        );
+    }
+
+    // cSpell: disable
+    #[test]
+    fn test_abbreviate_venue() {
+        assert_eq!(
+            abbreviate_venue("IEEE Transactions on Pattern Analysis and Machine Intelligence"),
+            "IEEE Trans. Pattern Anal. Mach. Intell."
+        );
+        assert_eq!(
+            abbreviate_venue("ACM Computing Surveys"),
+            "ACM Comput. Surv."
+        );
+        assert_eq!(
+            abbreviate_venue("Journal of Applied Physics"),
+            "J. Appl. Phys."
+        );
+        assert_eq!(abbreviate_venue("Nature"), "Nature");
+        assert_eq!(abbreviate_venue("Proceedings of the IEEE"), "Proc. IEEE");
+    }
+
+    #[test]
+    fn test_expand_venue() {
+        assert_eq!(
+            expand_venue("N. Engl. J. Med."),
+            Some("New England Journal of Medicine")
+        );
+        assert_eq!(expand_venue("IEEE Trans. Comput."), None);
+    }
+
+    #[test]
+    fn test_ordinal_value() {
+        assert_eq!(ordinal_value("43rd"), Some(43));
+        assert_eq!(ordinal_value("First"), Some(1));
+        assert_eq!(ordinal_value("Twelfth"), Some(12));
+        assert_eq!(ordinal_value("Fortieth"), Some(40));
+        assert_eq!(ordinal_value("Forty-Third"), Some(43));
+        assert_eq!(ordinal_value("Forty-Tenth"), None);
+        assert_eq!(ordinal_value("rd"), None);
+        assert_eq!(ordinal_value("Third-Party"), None);
+    }
+
+    #[test]
+    fn test_ieee_proceedings_venue() {
+        assert_eq!(
+            ieee_proceedings_venue(
+                "Proceedings of the Forty-Third Annual International Symposium on Computer Architecture"
+            ),
+            "Proc. 43rd Annu. Int. Symp. Comput. Archit."
+        );
+        assert_eq!(
+            ieee_proceedings_venue("43rd Annual International Symposium on Computer Architecture"),
+            "Proc. 43rd Annu. Int. Symp. Comput. Archit."
+        );
+        assert_eq!(
+            ieee_proceedings_venue("IEEE International Conference on Communications"),
+            "Proc. IEEE Int. Conf. Commun."
+        );
+        assert_eq!(
+            ieee_proceedings_venue("21st International Conference on Software Engineering"),
+            "Proc. 21st Int. Conf. Softw. Eng."
+        );
+        assert_eq!(ieee_proceedings_venue("SIGGRAPH"), "Proc. SIGGRAPH");
+    }
+    // cSpell: enable
 }
@@ -0,0 +1,327 @@
+//! Which entries of a bibliography share authors, venues or references,
+//! for literature-mapping tools.
+//!
+//! People are matched by surname and first initial, so "Jane Smith" and
+//! "J. Q. Smith" count as one author; organizations and pen names by
+//! their name, ignoring case. Works signed "Anonymous" share no author.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+};
+
+use crate::api::{author::Contributor, citation::Citation, citation_id::CitationId};
+
+/// The entries credited to one author.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorGroup {
+    /// The author's name as first written in the bibliography
+    pub name: String,
+    /// Entries crediting the author, in bibliography order
+    pub citation_ids: Vec<CitationId>,
+}
+
+/// Authors connected by the entries they wrote together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoAuthorGraph {
+    /// Each author's name as first written
+    names: BTreeMap<String, String>,
+    /// Author key to co-author key and the number of shared entries
+    edges: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+impl CoAuthorGraph {
+    /// Every author, sorted by name
+    pub fn authors(&self) -> Vec<&str> {
+        let mut authors: Vec<&str> = self.names.values().map(String::as_str).collect();
+        authors.sort_unstable();
+        authors
+    }
+
+    /// Co-authors of `author` and the number of entries they share, most
+    /// frequent first. `author` is matched like names in the bibliography.
+    pub fn co_authors(&self, author: &Contributor) -> Vec<(&str, usize)> {
+        let Some(neighbors) = author_key(author).and_then(|key| self.edges.get(&key)) else {
+            return Vec::new();
+        };
+        let mut co_authors: Vec<(&str, usize)> = neighbors
+            .iter()
+            .map(|(key, &shared)| (self.names[key].as_str(), shared))
+            .collect();
+        co_authors.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        co_authors
+    }
+
+    /// Every pair of co-authors with the number of entries they share,
+    /// each pair listed once
+    pub fn edges(&self) -> Vec<(&str, &str, usize)> {
+        self.edges
+            .iter()
+            .flat_map(|(from, neighbors)| {
+                neighbors
+                    .iter()
+                    .filter(move |(to, _)| from < *to)
+                    .map(move |(to, &shared)| {
+                        (self.names[from].as_str(), self.names[to].as_str(), shared)
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Two entries and how many references they share.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coupling {
+    pub first: CitationId,
+    pub second: CitationId,
+    /// Number of bibliography entries both reference
+    pub shared_references: usize,
+}
+
+/// The key people and organizations are matched by, or `None` for works
+/// signed "Anonymous"
+fn author_key(contributor: &Contributor) -> Option<String> {
+    match contributor {
+        Contributor::Person(person) => {
+            let initial = person
+                .given_names()
+                .and_then(|given| given.chars().next())
+                .map(|initial| initial.to_lowercase().to_string())
+                .unwrap_or_default();
+            Some(format!("{}|{}", person.surname().to_lowercase(), initial))
+        }
+        Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => {
+            Some(name.to_lowercase())
+        }
+        Contributor::Anonymous => None,
+    }
+}
+
+fn display_name(contributor: &Contributor) -> String {
+    match contributor {
+        Contributor::Person(person) => person.full_name(),
+        Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => {
+            name.clone()
+        }
+        Contributor::Anonymous => "Anonymous".to_string(),
+    }
+}
+
+/// The distinct authors of `citation` as (key, name) pairs
+fn authors(citation: &Citation) -> Vec<(String, String)> {
+    let mut authors: Vec<(String, String)> = Vec::new();
+    for contributor in citation.contributors() {
+        if let Some(key) = author_key(&contributor)
+            && !authors.iter().any(|(existing, _)| *existing == key)
+        {
+            authors.push((key, display_name(&contributor)));
+        }
+    }
+    authors
+}
+
+/// The journal or conference a work appeared in
+fn venue(citation: &Citation) -> Option<String> {
+    match citation {
+        Citation::JournalArticle(article) => Some(article.journal.clone()),
+        Citation::ConferencePaperOnline(paper) => {
+            Some(paper.series.full_name(&paper.conference_name))
+        }
+        Citation::ConferenceProceedingsOnline(proceedings) => {
+            Some(proceedings.series.full_name(&proceedings.conference_name))
+        }
+        Citation::ConferencePresentation(presentation) => {
+            Some(presentation.series.full_name(&presentation.conference_name))
+        }
+        _ => None,
+    }
+    .filter(|venue| !venue.trim().is_empty())
+}
+
+/// The entries credited to each author, sorted by author name
+pub fn group_by_author(citations: &[Citation]) -> Vec<AuthorGroup> {
+    let mut groups: HashMap<String, AuthorGroup> = HashMap::new();
+    for citation in citations {
+        for (key, name) in authors(citation) {
+            groups
+                .entry(key)
+                .or_insert_with(|| AuthorGroup {
+                    name,
+                    citation_ids: Vec::new(),
+                })
+                .citation_ids
+                .push(citation.citation_id());
+        }
+    }
+    let mut groups: Vec<AuthorGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| a.name.cmp(&b.name));
+    groups
+}
+
+/// The entries that appeared in each journal or conference, keyed by the
+/// venue's name as written in the first entry. Names are matched
+/// ignoring case.
+pub fn group_by_venue(citations: &[Citation]) -> BTreeMap<String, Vec<CitationId>> {
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut groups: BTreeMap<String, Vec<CitationId>> = BTreeMap::new();
+    for citation in citations {
+        if let Some(venue) = venue(citation) {
+            let name = names
+                .entry(venue.trim().to_lowercase())
+                .or_insert_with(|| venue.trim().to_string());
+            groups
+                .entry(name.clone())
+                .or_default()
+                .push(citation.citation_id());
+        }
+    }
+    groups
+}
+
+/// Authors linked by the entries they share
+pub fn co_author_graph(citations: &[Citation]) -> CoAuthorGraph {
+    let mut graph = CoAuthorGraph::default();
+    for citation in citations {
+        let authors = authors(citation);
+        for (key, name) in &authors {
+            graph
+                .names
+                .entry(key.clone())
+                .or_insert_with(|| name.clone());
+            graph.edges.entry(key.clone()).or_default();
+        }
+        for (i, (from, _)) in authors.iter().enumerate() {
+            for (to, _) in &authors[i + 1..] {
+                *graph
+                    .edges
+                    .entry(from.clone())
+                    .or_default()
+                    .entry(to.clone())
+                    .or_default() += 1;
+                *graph
+                    .edges
+                    .entry(to.clone())
+                    .or_default()
+                    .entry(from.clone())
+                    .or_default() += 1;
+            }
+        }
+    }
+    graph
+}
+
+/// Pairs of entries that reference at least one common entry
+/// (bibliographic coupling), most strongly coupled first
+pub fn bibliographic_coupling(citations: &[Citation]) -> Vec<Coupling> {
+    let mut couplings = Vec::new();
+    for (i, first) in citations.iter().enumerate() {
+        for second in &citations[i + 1..] {
+            let shared_references = first
+                .cites()
+                .iter()
+                .filter(|id| second.cites().contains(id))
+                .count();
+            if shared_references > 0 {
+                couplings.push(Coupling {
+                    first: first.citation_id(),
+                    second: second.citation_id(),
+                    shared_references,
+                });
+            }
+        }
+    }
+    couplings.sort_by_key(|coupling| Reverse(coupling.shared_references));
+    couplings
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        analysis::{bibliographic_coupling, co_author_graph, group_by_author, group_by_venue},
+        api::{
+            author::{AcademicAuthor, Contributor, PersonName},
+            citation::Citation,
+            citation_id::CitationId,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+        },
+    };
+
+    fn article(id: &str, authors: &[(&str, &str)], journal: &str, cites: &[&str]) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                cites: cites.iter().map(|&id| CitationId::from(id)).collect(),
+                ..CommonCitationData::new(id)
+            },
+            author: AcademicAuthor::Persons {
+                persons: authors
+                    .iter()
+                    .map(|(first, last)| PersonName::from_first_last(first, last).unwrap())
+                    .collect(),
+            },
+            title: id.into(),
+            journal: journal.to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: None,
+        })
+    }
+
+    fn citations() -> Vec<Citation> {
+        vec![
+            article(
+                "a",
+                &[("Jane", "Smith"), ("Bob", "Jones")],
+                "Nature",
+                &["x", "y"],
+            ),
+            article("b", &[("J.", "Smith"), ("Bob", "Jones")], "nature", &["y"]),
+            article(
+                "c",
+                &[("Bob", "Jones"), ("Ann", "Lee")],
+                "Science",
+                &["x", "y"],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_group_by_author() {
+        let groups = group_by_author(&citations());
+
+        let names: Vec<&str> = groups.iter().map(|group| group.name.as_str()).collect();
+        assert_eq!(names, ["Ann Lee", "Bob Jones", "Jane Smith"]);
+        assert_eq!(groups[2].citation_ids, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_group_by_venue() {
+        let venues = group_by_venue(&citations());
+
+        assert_eq!(venues["Nature"], ["a", "b"]);
+        assert_eq!(venues["Science"], ["c"]);
+    }
+
+    #[test]
+    fn test_co_author_graph() {
+        let graph = co_author_graph(&citations());
+        let jones = Contributor::Person(PersonName::from_first_last("B.", "Jones").unwrap());
+
+        assert_eq!(
+            graph.co_authors(&jones),
+            [("Jane Smith", 2), ("Ann Lee", 1)]
+        );
+        assert_eq!(graph.authors().len(), 3);
+        assert_eq!(graph.edges().len(), 2);
+    }
+
+    #[test]
+    fn test_bibliographic_coupling() {
+        let couplings = bibliographic_coupling(&citations());
+
+        assert_eq!(couplings[0].first, "a");
+        assert_eq!(couplings[0].second, "c");
+        assert_eq!(couplings[0].shared_references, 2);
+        assert_eq!(couplings.len(), 3);
+    }
+}
@@ -0,0 +1,123 @@
+//! Rendering references for screen readers, which spell out "vol." and
+//! "pp." letter by letter and read URLs character by character.
+//!
+//! Applied to a formatted reference when [`FormatOptions::accessible`] is
+//! set, so every style and media type gets the same treatment.
+//!
+//! [`FormatOptions::accessible`]: crate::api::style::FormatOptions::accessible
+
+use crate::api::style::Markup;
+
+/// Abbreviations used by the formatters and how they are written out.
+/// Only whole words are replaced, and all but "Vol.", "Ed." and "Eds."
+/// are lowercase so they can't be mistaken for an author's initial.
+const ABBREVIATIONS: [(&str, &str); 10] = [
+    ("Art.", "Article"),
+    ("Ed.", "Editor"),
+    ("Eds.", "Editors"),
+    ("Vol.", "Volume"),
+    ("ed.", "edition"),
+    ("n.d.", "no date"),
+    ("no.", "number"),
+    ("p.", "page"),
+    ("pp.", "pages"),
+    ("vol.", "volume"),
+];
+
+/// Make a formatted reference easier to follow with a screen reader:
+/// abbreviations are written out and, in HTML, URLs become links labelled
+/// with the site's name rather than read out in full
+pub fn accessible(reference: &str, markup: Markup) -> String {
+    reference
+        .split(' ')
+        .map(|word| {
+            let word = expand_abbreviation(word);
+            if markup == Markup::Html {
+                link_url(&word)
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `word` with an abbreviation written out, keeping brackets and
+/// punctuation around it, e.g. "(Vol." becomes "(Volume"
+fn expand_abbreviation(word: &str) -> String {
+    let core = word.trim_start_matches('(');
+    let opening = &word[..word.len() - core.len()];
+    for (abbreviation, expansion) in ABBREVIATIONS {
+        if let Some(rest) = core.strip_prefix(abbreviation)
+            && rest.chars().all(|c| ").,;:".contains(c))
+        {
+            return format!("{}{}{}", opening, expansion, rest);
+        }
+    }
+    word.to_string()
+}
+
+/// `word` as an HTML link if it is a URL, labelled with the DOI for
+/// doi.org links and with the host name otherwise
+fn link_url(word: &str) -> String {
+    let Some(start) = word.find("https://").or_else(|| word.find("http://")) else {
+        return word.to_string();
+    };
+    let (before, url) = word.split_at(start);
+    let url = url.trim_end_matches(['.', ',', ';', ')']);
+    let after = &word[start + url.len()..];
+
+    let location = url.split_once("://").map_or(url, |(_, location)| location);
+    let label = match location
+        .strip_prefix("doi.org/")
+        .or_else(|| location.strip_prefix("dx.doi.org/"))
+    {
+        Some(doi) => format!("DOI {}", doi),
+        None => location
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or(location)
+            .trim_start_matches("www.")
+            .to_string(),
+    };
+    format!(r#"{}<a href="{}">{}</a>{}"#, before, url, label, after)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{accessible::accessible, style::Markup};
+
+    #[test]
+    fn test_expand_abbreviations() {
+        assert_eq!(
+            accessible(
+                "P. Smith, \u{201C}Title,\u{201D} J. Tests, vol. 12, no. 3, pp. 1\u{2013}10, 2020.",
+                Markup::PlainText
+            ),
+            "P. Smith, \u{201C}Title,\u{201D} J. Tests, volume 12, number 3, pages 1\u{2013}10, 2020."
+        );
+        assert_eq!(
+            accessible(
+                "Smith, J. (n.d.). Book (2nd ed.) (Vol. 2).",
+                Markup::PlainText
+            ),
+            "Smith, J. (no date). Book (2nd edition) (Volume 2)."
+        );
+    }
+
+    #[test]
+    fn test_link_urls() {
+        assert_eq!(
+            accessible("Blog. https://www.example.com/why-rust?x=1", Markup::Html),
+            r#"Blog. <a href="https://www.example.com/why-rust?x=1">example.com</a>"#
+        );
+        assert_eq!(
+            accessible("Available: https://doi.org/10.1000/xyz.", Markup::Html),
+            r#"Available: <a href="https://doi.org/10.1000/xyz">DOI 10.1000/xyz</a>."#
+        );
+        assert_eq!(
+            accessible("Blog. https://example.com", Markup::PlainText),
+            "Blog. https://example.com"
+        );
+    }
+}
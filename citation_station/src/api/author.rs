@@ -1,16 +1,205 @@
 use serde::{Deserialize, Serialize};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::api::errors::NameError;
+use crate::api::{
+    errors::NameError,
+    style::{CitationStyle, FormatOptions},
+};
 
-const IEEE_ACADEMIC_ET_AL_CUTOFF: usize = 6;
-const APA_GENERIC_ET_AL_CUTOFF: usize = 5;
+/// How a style joins the names in the author list of a reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AuthorListRules {
+    /// Between names
+    pub separator: &'static str,
+    /// Before the last name, e.g. "and" or "&"
+    pub conjunction: &'static str,
+    /// Whether the separator also comes before the conjunction in a list
+    /// of two names
+    pub separator_with_two: bool,
+    /// Whether the separator also comes before the conjunction in a list
+    /// of three or more names (the serial comma)
+    pub serial_comma: bool,
+    /// How long lists are shortened
+    pub truncation: Truncation,
+    /// Punctuation between the list and the rest of the reference
+    pub trailing: &'static str,
+}
+
+/// How an author list that is too long is shortened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Truncation {
+    /// Every name is listed
+    None,
+    /// More than `above` names become the first name and "et al."
+    EtAl { above: usize },
+    /// More than `above` names become the first `above - 1` names, an
+    /// ellipsis, and the last name
+    Ellipsis { above: usize },
+    /// More than `above` names become the first `shown` names and "et al."
+    EtAlAfter { above: usize, shown: usize },
+}
+
+/// "A. Smith, B. Jones, and C. Lee," with "et al." after the first name
+/// when there are more than six
+pub const IEEE_AUTHOR_LIST: AuthorListRules = AuthorListRules {
+    separator: ", ",
+    conjunction: "and",
+    separator_with_two: false,
+    serial_comma: true,
+    truncation: Truncation::EtAl { above: 6 },
+    trailing: ",",
+};
+
+/// "Smith, A., Jones, B., & Lee, C." with an ellipsis before the last name
+/// when there are more than twenty
+pub const APA_AUTHOR_LIST: AuthorListRules = AuthorListRules {
+    separator: ", ",
+    conjunction: "&",
+    separator_with_two: true,
+    serial_comma: true,
+    truncation: Truncation::Ellipsis { above: 20 },
+    trailing: ".",
+};
+
+/// "SMITH, Jane, JONES, Bob and LEE, Chris." with "et al." after the
+/// first name when there are more than three
+pub const ISO_690_AUTHOR_LIST: AuthorListRules = AuthorListRules {
+    separator: ", ",
+    conjunction: "and",
+    separator_with_two: false,
+    serial_comma: false,
+    truncation: Truncation::EtAl { above: 3 },
+    trailing: ".",
+};
+
+/// "Smith J, Jones B, Lee C." with "et al." after the first three names
+/// when there are more than six
+pub const AMA_AUTHOR_LIST: AuthorListRules = AuthorListRules {
+    separator: ", ",
+    conjunction: "",
+    separator_with_two: true,
+    serial_comma: true,
+    truncation: Truncation::EtAlAfter { above: 6, shown: 3 },
+    trailing: ".",
+};
+
+/// "Smith, J.; Jones, B.; Lee, C." with every name listed
+pub const ACS_AUTHOR_LIST: AuthorListRules = AuthorListRules {
+    separator: "; ",
+    conjunction: "",
+    separator_with_two: true,
+    serial_comma: true,
+    truncation: Truncation::None,
+    trailing: ".",
+};
+
+/// "Smith J, Jones B, Lee C." with "et al." after the first six names
+/// when there are more
+pub const VANCOUVER_AUTHOR_LIST: AuthorListRules = AuthorListRules {
+    separator: ", ",
+    conjunction: "",
+    separator_with_two: true,
+    serial_comma: true,
+    truncation: Truncation::EtAlAfter { above: 6, shown: 6 },
+    trailing: ".",
+};
+
+impl AuthorListRules {
+    pub const fn for_style(style: CitationStyle) -> Self {
+        match style {
+            CitationStyle::Apa => APA_AUTHOR_LIST,
+            CitationStyle::Ieee => IEEE_AUTHOR_LIST,
+            CitationStyle::Iso690 => ISO_690_AUTHOR_LIST,
+            CitationStyle::Vancouver => VANCOUVER_AUTHOR_LIST,
+            CitationStyle::Ama => AMA_AUTHOR_LIST,
+            CitationStyle::Acs => ACS_AUTHOR_LIST,
+        }
+    }
+
+    /// Join already formatted names, or `None` if there are none
+    pub fn join(&self, names: &[String]) -> Option<String> {
+        let (last, init) = names.split_last()?;
+        match self.truncation {
+            Truncation::EtAl { above } if names.len() > above => {
+                return Some(format!("{} et al.", names[0]));
+            }
+            Truncation::Ellipsis { above } if names.len() > above => {
+                return Some(format!(
+                    "{}{}. . . {}",
+                    names[..above - 1].join(self.separator),
+                    self.separator,
+                    last
+                ));
+            }
+            Truncation::EtAlAfter { above, shown } if names.len() > above => {
+                return Some(format!(
+                    "{}{}et al.",
+                    names[..shown].join(self.separator),
+                    self.separator
+                ));
+            }
+            _ => {}
+        }
+        // Lists without a conjunction, e.g. "Smith J, Jones B, Lee C"
+        if self.conjunction.is_empty() {
+            return Some(names.join(self.separator));
+        }
+        let with_separator = match init.len() {
+            0 => return Some(last.clone()),
+            1 => self.separator_with_two,
+            _ => self.serial_comma,
+        };
+        let before_conjunction = if with_separator {
+            self.separator.trim_end()
+        } else {
+            ""
+        };
+
+        Some(format!(
+            "{}{} {} {}",
+            init.join(self.separator),
+            before_conjunction,
+            self.conjunction,
+            last
+        ))
+    }
+
+    /// The list followed by the style's trailing punctuation
+    pub fn terminate(&self, list: &str) -> String {
+        punctuate(list, self.trailing)
+    }
+}
+
+/// Append `mark`, unless it is a period and `text` already ends with one,
+/// as after an initial or "et al."
+pub(crate) fn punctuate(text: &str, mark: &str) -> String {
+    if mark == "." && text.ends_with('.') {
+        text.to_string()
+    } else {
+        format!("{}{}", text, mark)
+    }
+}
 
 fn first_grapheme_from_str(s: &str) -> Option<&str> {
     UnicodeSegmentation::graphemes(s, true).take(1).next()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// The initials of one given name, keeping the hyphen of hyphenated names:
+/// "Jean-Paul" gives "J.-P." with periods, or "J-P" without
+fn name_initials(name: &str, with_periods: bool) -> Option<String> {
+    let initials: Vec<String> = name
+        .split('-')
+        .filter_map(first_grapheme_from_str)
+        .map(|initial| match with_periods {
+            true => format!("{}.", initial),
+            false => initial.to_string(),
+        })
+        .collect();
+    (!initials.is_empty()).then(|| initials.join("-"))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum PersonName {
     SurnameOnly {
         surname: String,
@@ -32,13 +221,13 @@ impl PersonName {
         middle: &str,
         last: &str,
     ) -> Result<PersonName, NameError> {
-        if first.len() == 0 {
+        if first.is_empty() {
             return Err(NameError::EmptyString);
         }
-        if middle.len() == 0 {
+        if middle.is_empty() {
             return Err(NameError::EmptyString);
         }
-        if last.len() == 0 {
+        if last.is_empty() {
             return Err(NameError::EmptyString);
         }
         Ok(PersonName::SurnameAndFirstNameAndMiddleName {
@@ -49,10 +238,10 @@ impl PersonName {
     }
 
     pub fn from_first_last(first: &str, last: &str) -> Result<PersonName, NameError> {
-        if first.len() == 0 {
+        if first.is_empty() {
             return Err(NameError::EmptyString);
         }
-        if last.len() == 0 {
+        if last.is_empty() {
             return Err(NameError::EmptyString);
         }
         Ok(PersonName::SurnameAndFirstName {
@@ -62,7 +251,7 @@ impl PersonName {
     }
 
     pub fn from_last(last: &str) -> Result<PersonName, NameError> {
-        if last.len() == 0 {
+        if last.is_empty() {
             return Err(NameError::EmptyString);
         }
         Ok(PersonName::SurnameOnly {
@@ -70,6 +259,88 @@ impl PersonName {
         })
     }
 
+    /// Parse a name written as "First Middle Last" or "Last, First Middle".
+    /// Additional middle names are kept together as one middle name.
+    pub fn parse(name: &str) -> Result<PersonName, NameError> {
+        if let Some((last, given)) = name.split_once(',') {
+            let given: Vec<&str> = given.split_whitespace().collect();
+            let last = last.trim();
+            return match given.as_slice() {
+                [] => Self::from_last(last),
+                [first] => Self::from_first_last(first, last),
+                [first, middle @ ..] => {
+                    Self::from_first_middle_last(first, &middle.join(" "), last)
+                }
+            };
+        }
+        let words: Vec<&str> = name.split_whitespace().collect();
+        match words.as_slice() {
+            [] => Err(NameError::EmptyString),
+            [last] => Self::from_last(last),
+            [first, last] => Self::from_first_last(first, last),
+            [first, middle @ .., last] => {
+                Self::from_first_middle_last(first, &middle.join(" "), last)
+            }
+        }
+    }
+
+    pub fn surname(&self) -> &str {
+        match self {
+            PersonName::SurnameOnly { surname } => surname,
+            PersonName::SurnameAndFirstName { surname, .. } => surname,
+            PersonName::SurnameAndFirstNameAndMiddleName { surname, .. } => surname,
+        }
+    }
+
+    /// Given names followed by the surname, e.g. "Jane Q. Smith"
+    pub fn full_name(&self) -> String {
+        match self {
+            PersonName::SurnameOnly { surname } => surname.clone(),
+            PersonName::SurnameAndFirstName {
+                surname,
+                first_name,
+            } => format!("{} {}", first_name, surname),
+            PersonName::SurnameAndFirstNameAndMiddleName {
+                surname,
+                first_name,
+                middle_name,
+            } => format!("{} {} {}", first_name, middle_name, surname),
+        }
+    }
+
+    /// First and middle names, e.g. "Jane Q." (`None` for surname-only names)
+    pub fn given_names(&self) -> Option<String> {
+        match self {
+            PersonName::SurnameOnly { .. } => None,
+            PersonName::SurnameAndFirstName { first_name, .. } => Some(first_name.clone()),
+            PersonName::SurnameAndFirstNameAndMiddleName {
+                first_name,
+                middle_name,
+                ..
+            } => Some(format!("{} {}", first_name, middle_name)),
+        }
+    }
+
+    /// Initials of the given names, e.g. "J. Q." or "J.-P." (empty for
+    /// surname-only names)
+    pub fn initials(&self) -> String {
+        self.given_initials(true).join(" ")
+    }
+
+    /// Initials of the given names without periods or spaces, e.g. "JQ" or
+    /// "J-P", as written by AMA and Vancouver
+    pub fn compact_initials(&self) -> String {
+        self.given_initials(false).concat()
+    }
+
+    fn given_initials(&self, with_periods: bool) -> Vec<String> {
+        self.given_names()
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|name| name_initials(name, with_periods))
+            .collect()
+    }
+
     pub fn as_ieee_string(&self) -> String {
         match self {
             PersonName::SurnameOnly { surname } => surname.clone(),
@@ -103,6 +374,47 @@ impl PersonName {
         }
     }
 
+    /// Initials before the surname, e.g. "J. Q. Smith", as APA and IEEE
+    /// name editors
+    pub fn as_initials_first_string(&self) -> String {
+        let initials = self.initials();
+        if initials.is_empty() {
+            self.surname().to_string()
+        } else {
+            format!("{} {}", initials, self.surname())
+        }
+    }
+
+    /// Surname in capitals followed by the given names, e.g. "SMITH,
+    /// Jane Q."
+    pub fn as_iso690_string(&self) -> String {
+        match self.given_names() {
+            Some(given) => format!("{}, {}", self.surname().to_uppercase(), given),
+            None => self.surname().to_uppercase(),
+        }
+    }
+
+    /// Surname followed by initials without periods, e.g. "Smith JQ". AMA
+    /// writes names the same way.
+    pub fn as_vancouver_string(&self) -> String {
+        let initials = self.compact_initials();
+        if initials.is_empty() {
+            self.surname().to_string()
+        } else {
+            format!("{} {}", self.surname(), initials)
+        }
+    }
+
+    /// Surname followed by initials, e.g. "Smith, J. Q." or "Sartre, J.-P."
+    pub fn as_acs_string(&self) -> String {
+        let initials = self.initials();
+        if initials.is_empty() {
+            self.surname().to_string()
+        } else {
+            format!("{}, {}", self.surname(), initials)
+        }
+    }
+
     pub fn as_apa_string(&self) -> String {
         match self {
             PersonName::SurnameOnly { surname } => surname.clone(),
@@ -137,134 +449,337 @@ impl PersonName {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// The author of a work signed "Anonymous"
+const ANONYMOUS: &str = "Anonymous";
+
+/// A single contributor, independent of the author type used by a media type.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Contributor {
+    Person(PersonName),
+    Organization {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        abbreviation: Option<String>,
+    },
+    /// A work signed "Anonymous"
+    Anonymous,
+    /// A pen name, cited as it appears on the work
+    Pseudonym {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        real_name: Option<String>,
+    },
+}
+
+impl Contributor {
+    /// Surname of a person, or the full name of an organization or
+    /// pseudonym. This is also the name the work is sorted by, so works
+    /// signed "Anonymous" are sorted under "A".
+    pub fn short_name(&self) -> &str {
+        match self {
+            Contributor::Person(person) => person.surname(),
+            Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => name,
+            Contributor::Anonymous => ANONYMOUS,
+        }
+    }
+
+    /// The name as it appears in an author list of `style`
+    pub fn as_string_with(&self, style: CitationStyle, options: &FormatOptions) -> String {
+        match (self, style) {
+            (Contributor::Person(person), CitationStyle::Apa) => person.as_apa_string(),
+            (Contributor::Person(person), CitationStyle::Ieee) => person.as_ieee_string(),
+            (Contributor::Person(person), CitationStyle::Iso690) => person.as_iso690_string(),
+            (Contributor::Person(person), CitationStyle::Vancouver | CitationStyle::Ama) => {
+                person.as_vancouver_string()
+            }
+            (Contributor::Person(person), CitationStyle::Acs) => person.as_acs_string(),
+            (Contributor::Organization { name, abbreviation }, _) => organization_name(
+                name,
+                abbreviation.as_deref(),
+                options.organization_abbreviations,
+            ),
+            (Contributor::Anonymous, _) => ANONYMOUS.to_string(),
+            (Contributor::Pseudonym { name, .. }, _) => name.clone(),
+        }
+    }
+}
+
+/// Join persons and organizations in one author list, e.g. "A. Smith,
+/// The Consortium, and B. Jones"
+fn mixed_list(
+    contributors: &[Contributor],
+    style: CitationStyle,
+    options: &FormatOptions,
+) -> Option<String> {
+    AuthorListRules::for_style(style).join(
+        &contributors
+            .iter()
+            .map(|contributor| contributor.as_string_with(style, options))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// The name of an organization, followed by its abbreviation in brackets
+/// when there is one and `with_abbreviation` is set, e.g. "World Health
+/// Organization [WHO]"
+fn organization_name(name: &str, abbreviation: Option<&str>, with_abbreviation: bool) -> String {
+    match abbreviation {
+        Some(abbreviation) if with_abbreviation => format!("{} [{}]", name, abbreviation),
+        _ => name.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum AcademicAuthor {
-    Persons { persons: Vec<PersonName> },
-    Organization { name: String },
+    Persons {
+        persons: Vec<PersonName>,
+    },
+    Organization {
+        name: String,
+        /// Short form used in in-text citations after the first, e.g. "WHO"
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        abbreviation: Option<String>,
+    },
+    /// Persons and organizations together, e.g. a consortium credited
+    /// alongside named authors
+    Mixed {
+        contributors: Vec<Contributor>,
+    },
+    /// Only for works signed "Anonymous". Works that name no author are
+    /// cited by title instead.
+    Anonymous,
+    /// A pen name, cited as it appears on the work
+    Pseudonym {
+        name: String,
+        /// The author's real name, if known. Only used for searching.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        real_name: Option<String>,
+    },
 }
 
 impl AcademicAuthor {
+    /// The named persons among the authors, for editing in place
+    pub fn persons_mut(&mut self) -> Vec<&mut PersonName> {
+        match self {
+            AcademicAuthor::Persons { persons } => persons.iter_mut().collect(),
+            AcademicAuthor::Mixed { contributors } => contributors
+                .iter_mut()
+                .filter_map(|contributor| match contributor {
+                    Contributor::Person(person) => Some(person),
+                    _ => None,
+                })
+                .collect(),
+            AcademicAuthor::Organization { .. }
+            | AcademicAuthor::Anonymous
+            | AcademicAuthor::Pseudonym { .. } => Vec::new(),
+        }
+    }
+
+    pub fn contributors(&self) -> Vec<Contributor> {
+        match self {
+            AcademicAuthor::Persons { persons } => {
+                persons.iter().cloned().map(Contributor::Person).collect()
+            }
+            AcademicAuthor::Organization { name, abbreviation } => {
+                vec![Contributor::Organization {
+                    name: name.clone(),
+                    abbreviation: abbreviation.clone(),
+                }]
+            }
+            AcademicAuthor::Mixed { contributors } => contributors.clone(),
+            AcademicAuthor::Anonymous => vec![Contributor::Anonymous],
+            AcademicAuthor::Pseudonym { name, real_name } => vec![Contributor::Pseudonym {
+                name: name.clone(),
+                real_name: real_name.clone(),
+            }],
+        }
+    }
+
+    /// The authors joined by the rules of `style`, without trailing
+    /// punctuation
+    pub fn as_string_with(&self, style: CitationStyle, options: &FormatOptions) -> Option<String> {
+        mixed_list(&self.contributors(), style, options)
+    }
+
+    /// The authors joined by IEEE's rules, without trailing punctuation
     pub fn as_ieee_string(&self) -> Option<String> {
+        self.as_ieee_string_with(&FormatOptions::for_style(CitationStyle::Ieee))
+    }
+
+    pub fn as_ieee_string_with(&self, options: &FormatOptions) -> Option<String> {
         match self {
-            AcademicAuthor::Persons { persons } => match persons.as_slice() {
-                [] => None,
-                [first] => Some(format!("{},", first.as_ieee_string())),
-                [first, second] => Some(format!(
-                    "{} and {},",
-                    first.as_ieee_string(),
-                    second.as_ieee_string()
-                )),
-                [all @ ..] => {
-                    if all.len() > IEEE_ACADEMIC_ET_AL_CUTOFF {
-                        Some(format!("{} et al.,", all.first().unwrap().as_ieee_string()))
-                    } else {
-                        let mut persons_iter = all.into_iter();
-                        let last_person = persons_iter.next_back().unwrap();
-                        let persons_except_last = persons_iter
-                            .map(|person| person.as_ieee_string())
-                            .collect::<Vec<String>>()
-                            .join(", ");
-
-                        Some(format!(
-                            "{}, and {},",
-                            persons_except_last,
-                            last_person.as_ieee_string()
-                        ))
-                    }
-                }
-            },
-            AcademicAuthor::Organization { name } => Some(format!("{},", name.clone())),
+            AcademicAuthor::Persons { persons } => IEEE_AUTHOR_LIST.join(
+                &persons
+                    .iter()
+                    .map(PersonName::as_ieee_string)
+                    .collect::<Vec<_>>(),
+            ),
+            AcademicAuthor::Organization { name, abbreviation } => Some(organization_name(
+                name,
+                abbreviation.as_deref(),
+                options.organization_abbreviations,
+            )),
+            AcademicAuthor::Mixed { contributors } => {
+                mixed_list(contributors, CitationStyle::Ieee, options)
+            }
+            AcademicAuthor::Anonymous | AcademicAuthor::Pseudonym { .. } => {
+                mixed_list(&self.contributors(), CitationStyle::Ieee, options)
+            }
         }
     }
 
+    /// The authors joined by APA's rules, without trailing punctuation
     pub fn as_apa_string(&self) -> Option<String> {
+        self.as_apa_string_with(&FormatOptions::for_style(CitationStyle::Apa))
+    }
+
+    pub fn as_apa_string_with(&self, options: &FormatOptions) -> Option<String> {
         match self {
-            AcademicAuthor::Persons { persons } => match persons.as_slice() {
-                [] => None,
-                [first] => Some(first.as_apa_string()),
-                [first, second] => Some(format!(
-                    "{} & {}",
-                    first.as_apa_string(),
-                    second.as_apa_string()
-                )),
-                [all @ ..] => Some(format!("{} et al.", all.first().unwrap().as_apa_string())),
-            },
-            AcademicAuthor::Organization { name } => Some(name.clone()),
+            AcademicAuthor::Persons { persons } => APA_AUTHOR_LIST.join(
+                &persons
+                    .iter()
+                    .map(PersonName::as_apa_string)
+                    .collect::<Vec<_>>(),
+            ),
+            AcademicAuthor::Organization { name, abbreviation } => Some(organization_name(
+                name,
+                abbreviation.as_deref(),
+                options.organization_abbreviations,
+            )),
+            AcademicAuthor::Mixed { contributors } => {
+                mixed_list(contributors, CitationStyle::Apa, options)
+            }
+            AcademicAuthor::Anonymous | AcademicAuthor::Pseudonym { .. } => {
+                mixed_list(&self.contributors(), CitationStyle::Apa, options)
+            }
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum GenericAuthor {
-    Persons { persons: Vec<PersonName> },
-    Organization { name: String },
+    Persons {
+        persons: Vec<PersonName>,
+    },
+    Organization {
+        name: String,
+        /// Short form used in in-text citations after the first, e.g. "WHO"
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        abbreviation: Option<String>,
+    },
+    /// Persons and organizations together, e.g. a consortium credited
+    /// alongside named authors
+    Mixed {
+        contributors: Vec<Contributor>,
+    },
+    /// Only for works signed "Anonymous". Works that name no author are
+    /// cited by title instead.
+    Anonymous,
+    /// A pen name, cited as it appears on the work
+    Pseudonym {
+        name: String,
+        /// The author's real name, if known. Only used for searching.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        real_name: Option<String>,
+    },
 }
 
 impl GenericAuthor {
+    /// The named persons among the authors, for editing in place
+    pub fn persons_mut(&mut self) -> Vec<&mut PersonName> {
+        match self {
+            GenericAuthor::Persons { persons } => persons.iter_mut().collect(),
+            GenericAuthor::Mixed { contributors } => contributors
+                .iter_mut()
+                .filter_map(|contributor| match contributor {
+                    Contributor::Person(person) => Some(person),
+                    _ => None,
+                })
+                .collect(),
+            GenericAuthor::Organization { .. }
+            | GenericAuthor::Anonymous
+            | GenericAuthor::Pseudonym { .. } => Vec::new(),
+        }
+    }
+
+    pub fn contributors(&self) -> Vec<Contributor> {
+        match self {
+            GenericAuthor::Persons { persons } => {
+                persons.iter().cloned().map(Contributor::Person).collect()
+            }
+            GenericAuthor::Organization { name, abbreviation } => vec![Contributor::Organization {
+                name: name.clone(),
+                abbreviation: abbreviation.clone(),
+            }],
+            GenericAuthor::Mixed { contributors } => contributors.clone(),
+            GenericAuthor::Anonymous => vec![Contributor::Anonymous],
+            GenericAuthor::Pseudonym { name, real_name } => vec![Contributor::Pseudonym {
+                name: name.clone(),
+                real_name: real_name.clone(),
+            }],
+        }
+    }
+
+    /// The authors joined by the rules of `style`, without trailing
+    /// punctuation
+    pub fn as_string_with(&self, style: CitationStyle, options: &FormatOptions) -> Option<String> {
+        mixed_list(&self.contributors(), style, options)
+    }
+
+    /// The authors joined by IEEE's rules, without trailing punctuation
     pub fn as_ieee_string(&self) -> Option<String> {
+        self.as_ieee_string_with(&FormatOptions::for_style(CitationStyle::Ieee))
+    }
+
+    pub fn as_ieee_string_with(&self, options: &FormatOptions) -> Option<String> {
         match self {
-            GenericAuthor::Persons { persons } => match persons.as_slice() {
-                [] => None,
-                [first] => Some(format!("{}", first.as_ieee_string())),
-                [first, second] => Some(format!(
-                    "{} and {}",
-                    first.as_ieee_string(),
-                    second.as_ieee_string()
-                )),
-                [all @ ..] => {
-                    if all.len() > IEEE_ACADEMIC_ET_AL_CUTOFF {
-                        Some(format!("{} et al.", all.first().unwrap().as_ieee_string()))
-                    } else {
-                        // let mut s = all.into_iter().map(|person| person.as_ieee_string()).collect().join(", ");
-                        let mut persons_iter = all.into_iter();
-                        let last_person = persons_iter.next_back().unwrap();
-                        let persons_except_last = persons_iter
-                            .map(|person| person.as_ieee_string())
-                            .collect::<Vec<String>>()
-                            .join(", ");
-
-                        Some(format!(
-                            "{}, and {}",
-                            persons_except_last,
-                            last_person.as_ieee_string()
-                        ))
-                    }
-                }
-            },
-            GenericAuthor::Organization { name } => Some(format!("{},", name.clone())),
+            GenericAuthor::Persons { persons } => IEEE_AUTHOR_LIST.join(
+                &persons
+                    .iter()
+                    .map(PersonName::as_ieee_string)
+                    .collect::<Vec<_>>(),
+            ),
+            GenericAuthor::Organization { name, abbreviation } => Some(organization_name(
+                name,
+                abbreviation.as_deref(),
+                options.organization_abbreviations,
+            )),
+            GenericAuthor::Mixed { contributors } => {
+                mixed_list(contributors, CitationStyle::Ieee, options)
+            }
+            GenericAuthor::Anonymous | GenericAuthor::Pseudonym { .. } => {
+                mixed_list(&self.contributors(), CitationStyle::Ieee, options)
+            }
         }
     }
 
+    /// The authors joined by APA's rules, without trailing punctuation
     pub fn as_apa_string(&self) -> Option<String> {
+        self.as_apa_string_with(&FormatOptions::for_style(CitationStyle::Apa))
+    }
+
+    pub fn as_apa_string_with(&self, options: &FormatOptions) -> Option<String> {
         match self {
-            GenericAuthor::Persons { persons } => match persons.as_slice() {
-                [] => None,
-                [first] => Some(first.as_apa_string()),
-                [first, second] => Some(format!(
-                    "{}, & {}",
-                    first.as_apa_string(),
-                    second.as_apa_string()
-                )),
-                [all @ ..] => {
-                    if all.len() > APA_GENERIC_ET_AL_CUTOFF {
-                        let mut persons_iter = all.into_iter();
-                        let last_person = persons_iter.next_back().unwrap();
-                        let persons_except_last = persons_iter
-                            .map(|person| person.as_ieee_string())
-                            .collect::<Vec<String>>()
-                            .join(", ");
-
-                        Some(format!(
-                            "{}, & {}",
-                            persons_except_last,
-                            last_person.as_ieee_string()
-                        ))
-                    } else {
-                        Some(format!("{} et al.", all.first().unwrap().as_apa_string()))
-                    }
-                }
-            },
-            GenericAuthor::Organization { name } => Some(name.clone()),
+            GenericAuthor::Persons { persons } => APA_AUTHOR_LIST.join(
+                &persons
+                    .iter()
+                    .map(PersonName::as_apa_string)
+                    .collect::<Vec<_>>(),
+            ),
+            GenericAuthor::Organization { name, abbreviation } => Some(organization_name(
+                name,
+                abbreviation.as_deref(),
+                options.organization_abbreviations,
+            )),
+            GenericAuthor::Mixed { contributors } => {
+                mixed_list(contributors, CitationStyle::Apa, options)
+            }
+            GenericAuthor::Anonymous | GenericAuthor::Pseudonym { .. } => {
+                mixed_list(&self.contributors(), CitationStyle::Apa, options)
+            }
         }
     }
 }
@@ -286,7 +801,83 @@ impl Editors {
 
 #[cfg(test)]
 mod tests {
-    use crate::api::author::{AcademicAuthor, PersonName};
+    use crate::api::{
+        author::{
+            ACS_AUTHOR_LIST, AMA_AUTHOR_LIST, APA_AUTHOR_LIST, AcademicAuthor, Contributor,
+            GenericAuthor, IEEE_AUTHOR_LIST, PersonName,
+        },
+        style::{CitationStyle, FormatOptions},
+    };
+
+    fn persons(count: usize) -> Vec<PersonName> {
+        (1..=count)
+            .map(|n| PersonName::from_first_last("Ann", &format!("Author{}", n)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_person_initials() {
+        assert_eq!(
+            PersonName::from_first_middle_last("Jane", "Quinn", "Doe")
+                .unwrap()
+                .initials(),
+            "J. Q."
+        );
+        assert_eq!(PersonName::from_last("Doe").unwrap().initials(), "");
+    }
+
+    #[test]
+    fn test_compact_initials() {
+        let sartre = PersonName::from_first_last("Jean-Paul", "Sartre").unwrap();
+        assert_eq!(sartre.initials(), "J.-P.");
+        assert_eq!(sartre.compact_initials(), "J-P");
+        assert_eq!(sartre.as_vancouver_string(), "Sartre J-P");
+        assert_eq!(sartre.as_acs_string(), "Sartre, J.-P.");
+
+        let doe = PersonName::from_first_middle_last("Jane", "Quinn", "Doe").unwrap();
+        assert_eq!(doe.compact_initials(), "JQ");
+        assert_eq!(doe.as_vancouver_string(), "Doe JQ");
+        assert_eq!(PersonName::from_last("Doe").unwrap().as_acs_string(), "Doe");
+    }
+
+    #[test]
+    fn test_ama_and_acs_author_lists() {
+        let names: Vec<String> = persons(7)
+            .iter()
+            .map(PersonName::as_vancouver_string)
+            .collect();
+        assert_eq!(
+            AMA_AUTHOR_LIST.join(&names).unwrap(),
+            "Author1 A, Author2 A, Author3 A, et al."
+        );
+        assert_eq!(
+            AMA_AUTHOR_LIST.join(&names[..2]).unwrap(),
+            "Author1 A, Author2 A"
+        );
+
+        let names: Vec<String> = persons(3).iter().map(PersonName::as_acs_string).collect();
+        assert_eq!(
+            ACS_AUTHOR_LIST.join(&names).unwrap(),
+            "Author1, A.; Author2, A.; Author3, A."
+        );
+    }
+
+    #[test]
+    fn test_parse_person_name() {
+        assert_eq!(
+            PersonName::parse("Jane Quinn Doe").unwrap(),
+            PersonName::from_first_middle_last("Jane", "Quinn", "Doe").unwrap()
+        );
+        assert_eq!(
+            PersonName::parse("Doe, Jane").unwrap(),
+            PersonName::from_first_last("Jane", "Doe").unwrap()
+        );
+        assert_eq!(
+            PersonName::parse(" Plato ").unwrap(),
+            PersonName::from_last("Plato").unwrap()
+        );
+        assert!(PersonName::parse("  ").is_err());
+    }
 
     #[test]
     fn test_format_person_academic_author_ieee_last_name_only() {
@@ -294,7 +885,7 @@ mod tests {
             persons: vec![PersonName::from_last("Doe").unwrap()],
         };
 
-        assert_eq!(author.as_ieee_string(), Some("Doe,".to_string()))
+        assert_eq!(author.as_ieee_string(), Some("Doe".to_string()))
     }
 
     #[test]
@@ -303,7 +894,7 @@ mod tests {
             persons: vec![PersonName::from_first_last("Jane", "Doe").unwrap()],
         };
 
-        assert_eq!(author.as_ieee_string(), Some("J. Doe,".to_string()))
+        assert_eq!(author.as_ieee_string(), Some("J. Doe".to_string()))
     }
 
     #[test]
@@ -312,7 +903,7 @@ mod tests {
             persons: vec![PersonName::from_first_middle_last("Jane", "Dilly", "Doe").unwrap()],
         };
 
-        assert_eq!(author.as_ieee_string(), Some("J. D. Doe,".to_string()))
+        assert_eq!(author.as_ieee_string(), Some("J. D. Doe".to_string()))
     }
 
     #[test]
@@ -320,12 +911,10 @@ mod tests {
         let org_name = "The Corporation";
         let author = AcademicAuthor::Organization {
             name: org_name.to_string(),
+            abbreviation: None,
         };
 
-        assert_eq!(
-            author.as_ieee_string(),
-            Some(format!("{},", org_name.to_string()))
-        )
+        assert_eq!(author.as_ieee_string(), Some(org_name.to_string()))
     }
 
     #[test]
@@ -360,8 +949,177 @@ mod tests {
         let org_name = "The Corporation";
         let author = AcademicAuthor::Organization {
             name: org_name.to_string(),
+            abbreviation: None,
         };
 
         assert_eq!(author.as_apa_string(), Some(org_name.to_string()))
     }
+
+    #[test]
+    fn test_ieee_author_lists() {
+        let expected = [
+            "A. Author1",
+            "A. Author1 and A. Author2",
+            "A. Author1, A. Author2, and A. Author3",
+            "A. Author1, A. Author2, A. Author3, and A. Author4",
+            "A. Author1, A. Author2, A. Author3, A. Author4, and A. Author5",
+            "A. Author1, A. Author2, A. Author3, A. Author4, A. Author5, and A. Author6",
+            "A. Author1 et al.",
+            "A. Author1 et al.",
+            "A. Author1 et al.",
+            "A. Author1 et al.",
+        ];
+
+        for (count, expected) in (1..=10).zip(expected) {
+            let academic = AcademicAuthor::Persons {
+                persons: persons(count),
+            };
+            let generic = GenericAuthor::Persons {
+                persons: persons(count),
+            };
+
+            assert_eq!(academic.as_ieee_string().as_deref(), Some(expected));
+            assert_eq!(generic.as_ieee_string().as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_apa_author_lists() {
+        let expected = [
+            "Author1, A.",
+            "Author1, A., & Author2, A.",
+            "Author1, A., Author2, A., & Author3, A.",
+            "Author1, A., Author2, A., Author3, A., & Author4, A.",
+        ];
+
+        for (count, expected) in (1..=4).zip(expected) {
+            let academic = AcademicAuthor::Persons {
+                persons: persons(count),
+            };
+            let generic = GenericAuthor::Persons {
+                persons: persons(count),
+            };
+
+            assert_eq!(academic.as_apa_string().as_deref(), Some(expected));
+            assert_eq!(generic.as_apa_string().as_deref(), Some(expected));
+        }
+        for count in 5..=10 {
+            let list = AcademicAuthor::Persons {
+                persons: persons(count),
+            }
+            .as_apa_string()
+            .unwrap();
+
+            assert_eq!(list.matches(", Author").count(), count - 2);
+            assert!(list.ends_with(&format!(", & Author{}, A.", count)));
+            assert!(!list.contains("et al."));
+        }
+    }
+
+    #[test]
+    fn test_apa_author_list_ellipsis() {
+        let list = APA_AUTHOR_LIST
+            .join(&(1..=25).map(|n| n.to_string()).collect::<Vec<_>>())
+            .unwrap();
+
+        assert_eq!(
+            list,
+            "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, . . . 25"
+        );
+    }
+
+    #[test]
+    fn test_author_list_trailing_punctuation() {
+        let org = GenericAuthor::Organization {
+            name: "The Corporation".to_string(),
+            abbreviation: None,
+        };
+        let many = AcademicAuthor::Persons {
+            persons: persons(7),
+        };
+
+        assert_eq!(
+            IEEE_AUTHOR_LIST.terminate(&org.as_ieee_string().unwrap()),
+            "The Corporation,"
+        );
+        assert_eq!(
+            APA_AUTHOR_LIST.terminate(&org.as_apa_string().unwrap()),
+            "The Corporation."
+        );
+        assert_eq!(
+            IEEE_AUTHOR_LIST.terminate(&many.as_ieee_string().unwrap()),
+            "A. Author1 et al.,"
+        );
+        assert_eq!(
+            APA_AUTHOR_LIST.terminate(
+                &AcademicAuthor::Persons {
+                    persons: persons(1)
+                }
+                .as_apa_string()
+                .unwrap()
+            ),
+            "Author1, A."
+        );
+    }
+
+    #[test]
+    fn test_organization_abbreviation() {
+        let author = GenericAuthor::Organization {
+            name: "World Health Organization".to_string(),
+            abbreviation: Some("WHO".to_string()),
+        };
+        let options = FormatOptions {
+            organization_abbreviations: true,
+            venue_names: Default::default(),
+            ..FormatOptions::for_style(CitationStyle::Apa)
+        };
+
+        assert_eq!(
+            author.as_apa_string().as_deref(),
+            Some("World Health Organization")
+        );
+        assert_eq!(
+            author.as_apa_string_with(&options).as_deref(),
+            Some("World Health Organization [WHO]")
+        );
+    }
+
+    #[test]
+    fn test_organization_without_abbreviation_serialization() {
+        let json = r#"{"Organization":{"name":"The Corporation"}}"#;
+        let author: AcademicAuthor = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            author,
+            AcademicAuthor::Organization {
+                name: "The Corporation".to_string(),
+                abbreviation: None,
+            }
+        );
+        assert_eq!(serde_json::to_string(&author).unwrap(), json);
+    }
+
+    #[test]
+    fn test_mixed_author_list() {
+        let author = AcademicAuthor::Mixed {
+            contributors: vec![
+                Contributor::Person(PersonName::from_first_last("Jane", "Smith").unwrap()),
+                Contributor::Organization {
+                    name: "The Consortium".to_string(),
+                    abbreviation: None,
+                },
+                Contributor::Person(PersonName::from_first_last("Bo", "Lee").unwrap()),
+            ],
+        };
+
+        assert_eq!(
+            author.as_ieee_string().as_deref(),
+            Some("J. Smith, The Consortium, and B. Lee")
+        );
+        assert_eq!(
+            author.as_apa_string().as_deref(),
+            Some("Smith, J., The Consortium, & Lee, B.")
+        );
+        assert_eq!(author.contributors().len(), 3);
+    }
 }
@@ -1,36 +1,174 @@
 use std::fmt;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::api::{
-    date::PublishDate,
-    media::{
-        book::Book,
-        conference_paper::{ConferencePaperOnline, ConferenceProceedingsOnline},
-        online_manual::OnlineManual,
-        online_video::OnlineVideo,
+use crate::{
+    api::{
+        accessible::accessible,
+        author::{Contributor, PersonName},
+        citation_id::CitationId,
+        completeness::CompletenessReport,
+        content_hash,
+        date::{AccessDate, PublishDate},
+        errors::CitationError,
+        identifiers::Identifiers,
+        media::{
+            MediaKind,
+            book::Book,
+            common::{CommonCitationData, FormatOverride, Funder},
+            conference_paper::{ConferencePaperOnline, ConferenceProceedingsOnline},
+            conference_presentation::ConferencePresentation,
+            custom::CustomMedia,
+            journal_article::JournalArticle,
+            online_manual::{OnlineManual, OnlineManualAvailability},
+            online_video::OnlineVideo,
+            software::Software,
+            web_page::WebPage,
+            wikipedia_article::WikipediaArticle,
+        },
+        style::{CitationStyle, FormatOptions, Markup, PrintLink},
+        url::break_urls,
+        visitor::{self, CitationVisitor},
     },
+    bibtex::citation_to_bibtex,
+    import::{
+        ImportError,
+        freeform::{FreeformParse, parse_freeform},
+        identifier::{Identifier, import_identifier, import_identifier_async},
+        refresh::{MetadataRefresh, refresh_from_doi},
+    },
+    net::{AsyncHttpClient, HttpClient},
+    qr::QrCode,
+    ris::citation_to_ris,
+    unicode::EMDASH,
+    xml::escape,
 };
 
 pub trait IeeeFormatting {
-    fn citation_string(&self) -> String;
+    fn citation_string_with(&self, options: &FormatOptions) -> String;
+
+    fn citation_string(&self) -> String {
+        self.citation_string_with(&FormatOptions::for_style(CitationStyle::Ieee))
+    }
 }
 
 pub trait ApaFormatting {
-    fn citation_string(&self) -> String;
+    fn citation_string_with(&self, options: &FormatOptions) -> String;
+
+    fn citation_string(&self) -> String {
+        self.citation_string_with(&FormatOptions::for_style(CitationStyle::Apa))
+    }
+}
+
+pub trait Iso690Formatting {
+    fn citation_string_with(&self, options: &FormatOptions) -> String;
+
+    fn citation_string(&self) -> String {
+        self.citation_string_with(&FormatOptions::for_style(CitationStyle::Iso690))
+    }
 }
 
-/// A bibliographic entry representing a citable work
+pub trait VancouverFormatting {
+    fn citation_string_with(&self, options: &FormatOptions) -> String;
+
+    fn citation_string(&self) -> String {
+        self.citation_string_with(&FormatOptions::for_style(CitationStyle::Vancouver))
+    }
+}
+
+pub trait AmaFormatting {
+    fn citation_string_with(&self, options: &FormatOptions) -> String;
+
+    fn citation_string(&self) -> String {
+        self.citation_string_with(&FormatOptions::for_style(CitationStyle::Ama))
+    }
+}
+
+pub trait AcsFormatting {
+    fn citation_string_with(&self, options: &FormatOptions) -> String;
+
+    fn citation_string(&self) -> String {
+        self.citation_string_with(&FormatOptions::for_style(CitationStyle::Acs))
+    }
+}
+
+/// A bibliographic entry representing a citable work.
+///
+/// New media types are added in minor releases, so matches outside this
+/// crate need a wildcard arm. To handle one media type, use
+/// [`Citation::kind`] or the `as_*` accessors such as
+/// [`Citation::as_book`] instead.
+///
+/// Citations compare field by field, timestamps and overrides included,
+/// so they are neither `Eq` nor `Hash`. Key maps and sets on
+/// [`Citation::citation_id`] instead.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum Citation {
     Book(Book),
     ConferencePaperOnline(ConferencePaperOnline),
     ConferenceProceedingsOnline(ConferenceProceedingsOnline),
+    JournalArticle(JournalArticle),
+    ConferencePresentation(ConferencePresentation),
     OnlineManual(OnlineManual),
     OnlineVideo(OnlineVideo),
+    Software(Software),
+    WebPage(WebPage),
+    WikipediaArticle(WikipediaArticle),
+    /// A media type defined outside this crate
+    Custom(CustomMedia),
 }
 
 impl Citation {
+    /// Import a citation from a DOI, ISBN, arXiv ID, PMID or URL, whichever
+    /// `input` turns out to be. See [`Identifier::detect`].
+    #[cfg(feature = "net")]
+    pub fn from_identifier(input: &str) -> Result<(Identifier, Citation), ImportError> {
+        Self::from_identifier_with(&crate::net::UreqClient::new(), input)
+    }
+
+    /// Like [`Citation::from_identifier`], fetching with `client`.
+    pub fn from_identifier_with<C: HttpClient + ?Sized>(
+        client: &C,
+        input: &str,
+    ) -> Result<(Identifier, Citation), ImportError> {
+        import_identifier(client, input)
+    }
+
+    /// Like [`Citation::from_identifier`], fetching with an
+    /// [`AsyncHttpClient`] such as the browser's.
+    pub async fn from_identifier_async<C: AsyncHttpClient>(
+        client: &C,
+        input: &str,
+    ) -> Result<(Identifier, Citation), ImportError> {
+        import_identifier_async(client, input).await
+    }
+
+    /// Fetch this entry's canonical metadata from its DOI and compare it
+    /// with the entry, without changing anything. See
+    /// [`refresh_from_doi`](crate::import::refresh::refresh_from_doi).
+    #[cfg(feature = "net")]
+    pub fn refresh_from_doi(&self) -> Result<MetadataRefresh, ImportError> {
+        self.refresh_from_doi_with(&crate::net::UreqClient::new())
+    }
+
+    /// Like [`Citation::refresh_from_doi`], fetching with `client`.
+    pub fn refresh_from_doi_with<C: HttpClient + ?Sized>(
+        &self,
+        client: &C,
+    ) -> Result<MetadataRefresh, ImportError> {
+        refresh_from_doi(client, self)
+    }
+
+    /// Best-effort parse of a reference formatted in APA, IEEE or MLA
+    /// style, e.g. "Smith, J. (2023). A great paper. MIT Press.". See
+    /// [`parse_freeform`].
+    pub fn parse_freeform(text: &str) -> Option<FreeformParse> {
+        parse_freeform(text)
+    }
+
     pub fn id(&self) -> String {
         match self {
             Citation::Book(book) => book.common_data.id.clone(),
@@ -40,28 +178,127 @@ impl Citation {
             Citation::ConferenceProceedingsOnline(conference_proceedings_online) => {
                 conference_proceedings_online.common_data.id.clone()
             }
+            Citation::JournalArticle(journal_article) => journal_article.common_data.id.clone(),
+            Citation::ConferencePresentation(presentation) => presentation.common_data.id.clone(),
             Citation::OnlineManual(online_manual) => online_manual.common_data.id.clone(),
             Citation::OnlineVideo(online_video) => match online_video {
                 OnlineVideo::Generic { common_data, .. } => common_data.id.clone(),
                 OnlineVideo::YouTube { common_data, .. } => common_data.id.clone(),
             },
+            Citation::Software(software) => software.common_data.id.clone(),
+            Citation::WebPage(web_page) => web_page.common_data.id.clone(),
+            Citation::WikipediaArticle(article) => article.common_data.id.clone(),
+            Citation::Custom(custom) => custom.common_data().id.clone(),
+        }
+    }
+
+    /// Data shared by every media type
+    pub fn common_data(&self) -> &CommonCitationData {
+        match self {
+            Citation::Book(book) => &book.common_data,
+            Citation::ConferencePaperOnline(conference_paper_online) => {
+                &conference_paper_online.common_data
+            }
+            Citation::ConferenceProceedingsOnline(conference_proceedings_online) => {
+                &conference_proceedings_online.common_data
+            }
+            Citation::JournalArticle(journal_article) => &journal_article.common_data,
+            Citation::ConferencePresentation(presentation) => &presentation.common_data,
+            Citation::OnlineManual(online_manual) => &online_manual.common_data,
+            Citation::OnlineVideo(OnlineVideo::Generic { common_data, .. })
+            | Citation::OnlineVideo(OnlineVideo::YouTube { common_data, .. }) => common_data,
+            Citation::Software(software) => &software.common_data,
+            Citation::WebPage(web_page) => &web_page.common_data,
+            Citation::WikipediaArticle(article) => &article.common_data,
+            Citation::Custom(custom) => custom.common_data(),
         }
     }
 
+    pub fn common_data_mut(&mut self) -> &mut CommonCitationData {
+        match self {
+            Citation::Book(book) => &mut book.common_data,
+            Citation::ConferencePaperOnline(conference_paper_online) => {
+                &mut conference_paper_online.common_data
+            }
+            Citation::ConferenceProceedingsOnline(conference_proceedings_online) => {
+                &mut conference_proceedings_online.common_data
+            }
+            Citation::JournalArticle(journal_article) => &mut journal_article.common_data,
+            Citation::ConferencePresentation(presentation) => &mut presentation.common_data,
+            Citation::OnlineManual(online_manual) => &mut online_manual.common_data,
+            Citation::OnlineVideo(OnlineVideo::Generic { common_data, .. })
+            | Citation::OnlineVideo(OnlineVideo::YouTube { common_data, .. }) => common_data,
+            Citation::Software(software) => &mut software.common_data,
+            Citation::WebPage(web_page) => &mut web_page.common_data,
+            Citation::WikipediaArticle(article) => &mut article.common_data,
+            Citation::Custom(custom) => custom.common_data_mut(),
+        }
+    }
+
+    /// IDs of the other bibliography entries this work references
+    pub fn cites(&self) -> &[CitationId] {
+        &self.common_data().cites
+    }
+
+    /// Labels used to organize the bibliography
+    pub fn tags(&self) -> &[String] {
+        &self.common_data().tags
+    }
+
+    /// When the entry was added to a bibliography, if known
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.common_data().timestamps.created
+    }
+
+    /// When the entry was last changed in a bibliography, if ever
+    pub fn modified(&self) -> Option<DateTime<Utc>> {
+        self.common_data().timestamps.modified
+    }
+
+    /// Bodies that funded the work. Not part of any citation style.
+    pub fn funders(&self) -> &[Funder] {
+        &self.common_data().extended.funders
+    }
+
+    /// URLs of the licenses the work is published under. Not part of any
+    /// citation style.
+    pub fn licenses(&self) -> &[String] {
+        &self.common_data().extended.licenses
+    }
+
+    /// The ID as a hashable key, see [`CitationId`]
+    pub fn citation_id(&self) -> CitationId {
+        CitationId::from(self.id())
+    }
+
+    /// A key for deduplicating works imported under different IDs: the
+    /// normalized DOI when the work has one, otherwise the citation ID.
+    pub fn dedup_key(&self) -> CitationId {
+        self.doi()
+            .and_then(|doi| CitationId::from_doi(&doi))
+            .unwrap_or_else(|| self.citation_id())
+    }
+
     pub fn title(&self) -> String {
         match self {
-            Citation::Book(book) => book.title.clone(),
+            Citation::Book(book) => book.title.to_string(),
             Citation::ConferencePaperOnline(conference_paper_online) => {
-                conference_paper_online.title.clone()
+                conference_paper_online.title.to_string()
             }
             Citation::ConferenceProceedingsOnline(conference_proceedings_online) => {
-                conference_proceedings_online.title.clone()
+                conference_proceedings_online.title.to_string()
             }
-            Citation::OnlineManual(online_manual) => online_manual.title.clone(),
+            Citation::JournalArticle(journal_article) => journal_article.title.to_string(),
+            Citation::ConferencePresentation(presentation) => presentation.title.to_string(),
+            Citation::OnlineManual(online_manual) => online_manual.title.to_string(),
             Citation::OnlineVideo(online_video) => match online_video {
-                OnlineVideo::Generic { title, .. } => title.clone(),
-                OnlineVideo::YouTube { title, .. } => title.clone(),
+                OnlineVideo::Generic { title, .. } => title.to_string(),
+                OnlineVideo::YouTube { title, .. } => title.to_string(),
             },
+            Citation::Software(software) => software.title.to_string(),
+            Citation::WebPage(web_page) => web_page.title.to_string(),
+            Citation::WikipediaArticle(article) => article.title.clone(),
+            Citation::Custom(custom) => custom.title(),
         }
     }
 
@@ -74,34 +311,760 @@ impl Citation {
             Citation::ConferenceProceedingsOnline(conference_proceedings_online) => {
                 conference_proceedings_online.common_data.published.clone()
             }
+            Citation::JournalArticle(journal_article) => {
+                journal_article.common_data.published.clone()
+            }
+            Citation::ConferencePresentation(presentation) => {
+                presentation.common_data.published.clone()
+            }
             Citation::OnlineManual(online_manual) => online_manual.common_data.published.clone(),
             Citation::OnlineVideo(online_video) => match online_video {
                 OnlineVideo::Generic { common_data, .. } => common_data.published.clone(),
                 OnlineVideo::YouTube { common_data, .. } => common_data.published.clone(),
             },
+            Citation::Software(software) => software.common_data.published.clone(),
+            Citation::WebPage(web_page) => web_page.common_data.published.clone(),
+            Citation::WikipediaArticle(article) => article.common_data.published.clone(),
+            Citation::Custom(custom) => custom.common_data().published.clone(),
         }
     }
 
-    /// Format the citation in APA style
-    pub fn format_apa(&self) -> String {
+    /// When an online resource was accessed. `None` for media types that
+    /// don't record an access date.
+    pub fn accessed(&self) -> Option<&AccessDate> {
         match self {
-            Citation::Book(book) => ApaFormatting::citation_string(book),
-            Citation::ConferencePaperOnline(_paper) => todo!(),
-            Citation::ConferenceProceedingsOnline(_proceedings) => todo!(),
-            Citation::OnlineManual(_online_manual) => todo!(),
-            Citation::OnlineVideo(_online_video) => todo!(),
+            Citation::OnlineManual(online_manual) => Some(&online_manual.accessed),
+            Citation::OnlineVideo(OnlineVideo::Generic { accessed, .. })
+            | Citation::OnlineVideo(OnlineVideo::YouTube { accessed, .. }) => Some(accessed),
+            Citation::Software(software) => Some(&software.accessed),
+            Citation::WebPage(web_page) => Some(&web_page.accessed),
+            Citation::WikipediaArticle(article) => Some(&article.accessed),
+            Citation::Book(_)
+            | Citation::ConferencePaperOnline(_)
+            | Citation::ConferenceProceedingsOnline(_)
+            | Citation::ConferencePresentation(_)
+            | Citation::JournalArticle(_)
+            | Citation::Custom(_) => None,
         }
     }
 
-    pub fn format_ieee(&self) -> String {
+    /// Set the access date of an online resource.
+    ///
+    /// Returns `false` (and leaves the citation untouched) if the media
+    /// type doesn't record an access date.
+    pub fn set_accessed(&mut self, date: impl Into<AccessDate>) -> bool {
+        let slot = match self {
+            Citation::OnlineManual(online_manual) => &mut online_manual.accessed,
+            Citation::OnlineVideo(OnlineVideo::Generic { accessed, .. })
+            | Citation::OnlineVideo(OnlineVideo::YouTube { accessed, .. }) => accessed,
+            Citation::Software(software) => &mut software.accessed,
+            Citation::WebPage(web_page) => &mut web_page.accessed,
+            Citation::WikipediaArticle(article) => &mut article.accessed,
+            Citation::Book(_)
+            | Citation::ConferencePaperOnline(_)
+            | Citation::ConferenceProceedingsOnline(_)
+            | Citation::ConferencePresentation(_)
+            | Citation::JournalArticle(_)
+            | Citation::Custom(_) => return false,
+        };
+        *slot = date.into();
+        true
+    }
+
+    /// Set the access date of an online resource to today (local time).
+    pub fn set_accessed_now(&mut self) -> bool {
+        self.set_accessed(AccessDate::today())
+    }
+
+    /// Authors of the cited work, in citation order.
+    ///
+    /// A YouTube channel is treated as an organizational author. Media
+    /// types that don't record authors return an empty list.
+    pub fn contributors(&self) -> Vec<Contributor> {
+        match self {
+            Citation::Book(book) => book.author.contributors(),
+            Citation::JournalArticle(journal_article) => journal_article.author.contributors(),
+            Citation::ConferencePresentation(presentation) => presentation.author.contributors(),
+            Citation::OnlineManual(online_manual) => online_manual.author.contributors(),
+            Citation::OnlineVideo(OnlineVideo::YouTube { channel, .. }) => {
+                vec![Contributor::Organization {
+                    name: channel.clone(),
+                    abbreviation: None,
+                }]
+            }
+            Citation::Software(software) => software.author.contributors(),
+            Citation::WebPage(web_page) => web_page
+                .author
+                .as_ref()
+                .map(|author| author.contributors())
+                .unwrap_or_default(),
+            Citation::OnlineVideo(OnlineVideo::Generic { .. })
+            | Citation::ConferencePaperOnline(_)
+            | Citation::ConferenceProceedingsOnline(_)
+            | Citation::WikipediaArticle(_) => vec![],
+            Citation::Custom(custom) => custom.contributors(),
+        }
+    }
+
+    /// Every named person credited on the work, including assisting
+    /// authors
+    pub fn persons(&self) -> Vec<PersonName> {
+        let mut persons: Vec<PersonName> = self
+            .contributors()
+            .into_iter()
+            .filter_map(|contributor| match contributor {
+                Contributor::Person(person) => Some(person),
+                _ => None,
+            })
+            .collect();
+        if let Citation::Book(book) = self {
+            persons.extend(book.with_authors.iter().cloned());
+        }
+        persons
+    }
+
+    /// Every named person credited on the work, including assisting
+    /// authors, for editing in place
+    pub fn persons_mut(&mut self) -> Vec<&mut PersonName> {
         match self {
-            Citation::Book(book) => IeeeFormatting::citation_string(book),
-            Citation::ConferencePaperOnline(_paper) => todo!(),
-            Citation::ConferenceProceedingsOnline(_proceedings) => todo!(),
-            Citation::OnlineManual(_online_manual) => todo!(),
-            Citation::OnlineVideo(_online_video) => todo!(),
+            Citation::Book(book) => {
+                let mut persons = book.author.persons_mut();
+                persons.extend(book.with_authors.iter_mut());
+                persons
+            }
+            Citation::JournalArticle(journal_article) => journal_article.author.persons_mut(),
+            Citation::ConferencePresentation(presentation) => presentation.author.persons_mut(),
+            Citation::OnlineManual(online_manual) => online_manual.author.persons_mut(),
+            Citation::Software(software) => software.author.persons_mut(),
+            Citation::WebPage(web_page) => web_page
+                .author
+                .as_mut()
+                .map(|author| author.persons_mut())
+                .unwrap_or_default(),
+            Citation::OnlineVideo(_)
+            | Citation::ConferencePaperOnline(_)
+            | Citation::ConferenceProceedingsOnline(_)
+            | Citation::WikipediaArticle(_) => Vec::new(),
+            Citation::Custom(custom) => custom.persons_mut(),
+        }
+    }
+
+    /// The DOI of the cited work, for media types that record one
+    pub fn doi(&self) -> Option<String> {
+        match self {
+            Citation::Book(book) => book.doi.clone(),
+            Citation::JournalArticle(journal_article) => journal_article.doi.clone(),
+            Citation::Software(software) => software.doi.clone(),
+            Citation::ConferencePaperOnline(_)
+            | Citation::ConferenceProceedingsOnline(_)
+            | Citation::ConferencePresentation(_)
+            | Citation::OnlineManual(_)
+            | Citation::OnlineVideo(_)
+            | Citation::WebPage(_)
+            | Citation::WikipediaArticle(_) => None,
+            Citation::Custom(custom) => custom.doi(),
+        }
+    }
+
+    /// The URL of an online resource, for media types that record one
+    pub fn url(&self) -> Option<String> {
+        match self {
+            Citation::OnlineManual(online_manual) => match &online_manual.available_at {
+                OnlineManualAvailability::URL(url) => Some(url.clone()),
+                _ => None,
+            },
+            Citation::OnlineVideo(OnlineVideo::Generic { url, .. })
+            | Citation::OnlineVideo(OnlineVideo::YouTube { url, .. }) => url.clone(),
+            Citation::ConferencePresentation(presentation) => presentation.url.clone(),
+            Citation::Software(software) => software.url.clone(),
+            Citation::WebPage(web_page) => Some(web_page.url.clone()),
+            Citation::WikipediaArticle(article) => Some(article.url()),
+            Citation::Custom(custom) => custom.url(),
+            Citation::Book(_)
+            | Citation::ConferencePaperOnline(_)
+            | Citation::ConferenceProceedingsOnline(_)
+            | Citation::JournalArticle(_) => None,
+        }
+    }
+
+    /// Stable hash of the cited work's content, for recognizing the same
+    /// work across bibliographies or as a cache key. The ID, tags, cited
+    /// entries and access date are ignored. See
+    /// [`content_hash::canonical_json`].
+    pub fn content_hash(&self) -> u64 {
+        content_hash::content_hash(self)
+    }
+
+    /// Every known identifier of the cited work: those stored alongside the
+    /// citation, plus the DOI and URL of media types that record them.
+    pub fn identifiers(&self) -> Identifiers {
+        let mut identifiers = self.common_data().identifiers.clone();
+        if let Some(doi) = self.doi() {
+            identifiers.insert(Identifier::Doi(doi));
+        }
+        if let Some(url) = self.url() {
+            identifiers.insert(match self {
+                Citation::WikipediaArticle(_) => Identifier::Wikipedia(url),
+                _ => Identifier::Url(url),
+            });
+        }
+        identifiers
+    }
+
+    /// Whether every whitespace-separated term of `query` appears
+    /// (case-insensitively) in the ID, title, contributor names, year or
+    /// DOI. An empty query matches everything.
+    pub fn matches(&self, query: &str) -> bool {
+        let mut haystack = vec![self.id(), self.title()];
+        for contributor in self.contributors() {
+            match contributor {
+                Contributor::Person(person) => haystack.push(person.full_name()),
+                Contributor::Organization {
+                    name,
+                    abbreviation: other,
+                }
+                | Contributor::Pseudonym {
+                    name,
+                    real_name: other,
+                } => {
+                    haystack.push(name);
+                    haystack.extend(other);
+                }
+                Contributor::Anonymous => haystack.push(contributor.short_name().to_string()),
+            }
+        }
+        if let Some(published) = self.published() {
+            haystack.push(published.year().to_string());
+        }
+        if let Some(doi) = self.doi() {
+            haystack.push(doi);
+        }
+        let haystack = haystack.join(" ").to_lowercase();
+
+        query
+            .to_lowercase()
+            .split_whitespace()
+            .all(|term| haystack.contains(term))
+    }
+
+    /// The citation as a CITATION.cff file. Only software can be written
+    /// as CFF; other media types return `None`.
+    pub fn to_cff(&self) -> Option<String> {
+        match self {
+            Citation::Software(software) => Some(software.to_cff()),
+            _ => None,
+        }
+    }
+
+    /// The citation as a BibTeX entry. See [`citation_to_bibtex`].
+    pub fn to_bibtex(&self) -> String {
+        citation_to_bibtex(self)
+    }
+
+    /// The citation as an RIS record. See [`citation_to_ris`].
+    pub fn to_ris(&self) -> String {
+        citation_to_ris(self)
+    }
+
+    /// Which fields recommended by `style` are missing, with a score
+    pub fn completeness(&self, style: CitationStyle) -> CompletenessReport {
+        CompletenessReport::for_citation(self, style)
+    }
+
+    /// A compact one-line description for logs and lists, e.g.
+    /// "Smith 2023 — A Great Paper [Book]". Not a formatted reference.
+    pub fn summary(&self) -> String {
+        let contributors = self.contributors();
+        let mut head = match contributors.as_slice() {
+            [] => String::new(),
+            [only] => only.short_name().to_string(),
+            [first, second] => format!("{} & {}", first.short_name(), second.short_name()),
+            [first, ..] => format!("{} et al.", first.short_name()),
+        };
+        if let Some(published) = self.published() {
+            if !head.is_empty() {
+                head.push(' ');
+            }
+            head.push_str(&published.year().to_string());
+        }
+
+        if head.is_empty() {
+            format!("{} [{}]", self.title(), self.kind())
+        } else {
+            format!("{} {} {} [{}]", head, EMDASH, self.title(), self.kind())
+        }
+    }
+
+    /// Call the method of `visitor` for this citation's media type
+    pub fn accept<V: CitationVisitor + ?Sized>(&self, visitor: &mut V) {
+        visitor::accept(self, visitor)
+    }
+
+    /// The media type of the citation
+    pub fn kind(&self) -> MediaKind {
+        match self {
+            Citation::Book(_) => MediaKind::Book,
+            Citation::ConferencePaperOnline(_) => MediaKind::ConferencePaperOnline,
+            Citation::ConferenceProceedingsOnline(_) => MediaKind::ConferenceProceedingsOnline,
+            Citation::JournalArticle(_) => MediaKind::JournalArticle,
+            Citation::ConferencePresentation(_) => MediaKind::ConferencePresentation,
+            Citation::OnlineManual(_) => MediaKind::OnlineManual,
+            Citation::OnlineVideo(_) => MediaKind::OnlineVideo,
+            Citation::Software(_) => MediaKind::Software,
+            Citation::WebPage(_) => MediaKind::WebPage,
+            Citation::WikipediaArticle(_) => MediaKind::WikipediaArticle,
+            Citation::Custom(_) => MediaKind::Custom,
+        }
+    }
+
+    /// The book, if the citation is one
+    pub fn as_book(&self) -> Option<&Book> {
+        match self {
+            Citation::Book(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The conference paper, if the citation is one
+    pub fn as_conference_paper_online(&self) -> Option<&ConferencePaperOnline> {
+        match self {
+            Citation::ConferencePaperOnline(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The conference proceedings, if the citation is one
+    pub fn as_conference_proceedings_online(&self) -> Option<&ConferenceProceedingsOnline> {
+        match self {
+            Citation::ConferenceProceedingsOnline(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The journal article, if the citation is one
+    pub fn as_journal_article(&self) -> Option<&JournalArticle> {
+        match self {
+            Citation::JournalArticle(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The conference presentation, if the citation is one
+    pub fn as_conference_presentation(&self) -> Option<&ConferencePresentation> {
+        match self {
+            Citation::ConferencePresentation(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The online manual, if the citation is one
+    pub fn as_online_manual(&self) -> Option<&OnlineManual> {
+        match self {
+            Citation::OnlineManual(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The online video, if the citation is one
+    pub fn as_online_video(&self) -> Option<&OnlineVideo> {
+        match self {
+            Citation::OnlineVideo(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The software, if the citation is one
+    pub fn as_software(&self) -> Option<&Software> {
+        match self {
+            Citation::Software(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The web page, if the citation is one
+    pub fn as_web_page(&self) -> Option<&WebPage> {
+        match self {
+            Citation::WebPage(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The Wikipedia article, if the citation is one
+    pub fn as_wikipedia_article(&self) -> Option<&WikipediaArticle> {
+        match self {
+            Citation::WikipediaArticle(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// The custom media, if the citation is one
+    pub fn as_custom(&self) -> Option<&CustomMedia> {
+        match self {
+            Citation::Custom(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// Use `text` as the formatted entry in `style` instead of the
+    /// formatter's output, for sources it can't format right. Returns the
+    /// override it replaces.
+    pub fn set_override(
+        &mut self,
+        style: CitationStyle,
+        text: impl Into<String>,
+    ) -> Option<String> {
+        let entry = FormatOverride {
+            text: text.into(),
+            content_hash: Some(self.content_hash()),
+        };
+        let overrides = &mut self.common_data_mut().overrides;
+        overrides.insert(style, entry).map(|entry| entry.text)
+    }
+
+    /// The hand-written entry for `style`, if one was set
+    pub fn override_for(&self, style: CitationStyle) -> Option<&str> {
+        let overrides = &self.common_data().overrides;
+        overrides.get(style).map(|entry| entry.text.as_str())
+    }
+
+    /// Whether the metadata changed since the override for `style` was
+    /// set or last confirmed, so the hand-written entry may be out of date.
+    /// `false` if there is no override, or it doesn't record the metadata
+    /// it was written for.
+    pub fn is_override_stale(&self, style: CitationStyle) -> bool {
+        let overrides = &self.common_data().overrides;
+        overrides
+            .get(style)
+            .and_then(|entry| entry.content_hash)
+            .is_some_and(|hash| hash != self.content_hash())
+    }
+
+    /// Mark the override for `style` as checked against the current
+    /// metadata. Returns `false` if there is no override.
+    pub fn confirm_override(&mut self, style: CitationStyle) -> bool {
+        let hash = self.content_hash();
+        let overrides = &mut self.common_data_mut().overrides;
+        match overrides.get(style).cloned() {
+            Some(mut entry) => {
+                entry.content_hash = Some(hash);
+                overrides.insert(style, entry);
+                true
+            }
+            None => false,
         }
     }
+
+    /// Go back to formatting the citation in `style`, returning the
+    /// override that was set
+    pub fn clear_override(&mut self, style: CitationStyle) -> Option<String> {
+        let overrides = &mut self.common_data_mut().overrides;
+        overrides.remove(style).map(|entry| entry.text)
+    }
+
+    /// Format the citation in the given style
+    ///
+    /// # Panics
+    ///
+    /// If the citation is of a custom media type without rules for
+    /// `style`; see [`Citation::try_format`].
+    pub fn format(&self, style: CitationStyle) -> String {
+        self.format_with(style, &FormatOptions::for_style(style))
+    }
+
+    /// Format the citation in the given style with non-default options.
+    /// An override set with [`Citation::set_override`] is returned as is,
+    /// whatever the options, apart from being escaped for HTML or ODF.
+    ///
+    /// # Panics
+    ///
+    /// If the citation is of a custom media type without rules for
+    /// `style`; see [`Citation::try_format_with`].
+    pub fn format_with(&self, style: CitationStyle, options: &FormatOptions) -> String {
+        self.try_format_with(style, options)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Format the citation in the given style, or fail with
+    /// [`CitationError::UnsupportedStyle`] if it is of a custom media type
+    /// without rules for `style`
+    pub fn try_format(&self, style: CitationStyle) -> Result<String, CitationError> {
+        self.try_format_with(style, &FormatOptions::for_style(style))
+    }
+
+    /// [`Citation::format_with`], failing as [`Citation::try_format`] does
+    pub fn try_format_with(
+        &self,
+        style: CitationStyle,
+        options: &FormatOptions,
+    ) -> Result<String, CitationError> {
+        if let Some(text) = self.override_for(style) {
+            return Ok(options.titles().render(text));
+        }
+        let reference = options.titles().render(&match style {
+            CitationStyle::Apa => self.format_apa_with(options)?,
+            CitationStyle::Ieee => self.format_ieee_with(options)?,
+            CitationStyle::Iso690 => self.format_iso690_with(options)?,
+            CitationStyle::Vancouver => self.format_vancouver_with(options)?,
+            CitationStyle::Ama => self.format_ama_with(options)?,
+            CitationStyle::Acs => self.format_acs_with(options)?,
+        });
+        let reference = if options.accessible {
+            accessible(&reference, options.markup)
+        } else if options.url.break_long {
+            break_urls(&reference, options.markup)
+        } else {
+            reference
+        };
+        Ok(match self.print_link(options) {
+            Some(link) => format!("{} {}", reference, link),
+            None => reference,
+        })
+    }
+
+    /// The link added after the formatted citation by
+    /// [`FormatOptions::print_link`]
+    fn print_link(&self, options: &FormatOptions) -> Option<String> {
+        let doi = self.doi();
+        let short_doi = || {
+            let link = format!("doi.org/{}", doi.as_ref()?);
+            Some(match options.markup {
+                Markup::Html => format!(r#"<a href="https://{0}">{0}</a>"#, escape(&link)),
+                Markup::Odf => escape(&link),
+                Markup::PlainText => link,
+            })
+        };
+        match options.print_link {
+            PrintLink::None => None,
+            PrintLink::QrCode if options.markup == Markup::Html => {
+                let target = match &doi {
+                    Some(doi) => format!("https://doi.org/{}", doi),
+                    None => self.url()?,
+                };
+                let code = QrCode::encode(&target)?;
+                Some(format!(
+                    r#"<img class="qr" src="{}" alt="QR code for {}">"#,
+                    code.to_data_uri(),
+                    escape(&target)
+                ))
+            }
+            PrintLink::ShortDoi | PrintLink::QrCode => short_doi(),
+        }
+    }
+
+    /// Format the citation in APA style
+    pub fn format_apa(&self) -> String {
+        self.format(CitationStyle::Apa)
+    }
+
+    pub fn format_ieee(&self) -> String {
+        self.format(CitationStyle::Ieee)
+    }
+
+    pub fn format_iso690(&self) -> String {
+        self.format(CitationStyle::Iso690)
+    }
+
+    pub fn format_vancouver(&self) -> String {
+        self.format(CitationStyle::Vancouver)
+    }
+
+    pub fn format_ama(&self) -> String {
+        self.format(CitationStyle::Ama)
+    }
+
+    pub fn format_acs(&self) -> String {
+        self.format(CitationStyle::Acs)
+    }
+
+    fn format_apa_with(&self, options: &FormatOptions) -> Result<String, CitationError> {
+        Ok(match self {
+            Citation::Book(book) => ApaFormatting::citation_string_with(book, options),
+            Citation::ConferencePaperOnline(paper) => {
+                ApaFormatting::citation_string_with(paper, options)
+            }
+            Citation::ConferenceProceedingsOnline(proceedings) => {
+                ApaFormatting::citation_string_with(proceedings, options)
+            }
+            Citation::JournalArticle(journal_article) => {
+                ApaFormatting::citation_string_with(journal_article, options)
+            }
+            Citation::ConferencePresentation(presentation) => {
+                ApaFormatting::citation_string_with(presentation, options)
+            }
+            Citation::OnlineManual(online_manual) => {
+                ApaFormatting::citation_string_with(online_manual, options)
+            }
+            Citation::OnlineVideo(online_video) => {
+                ApaFormatting::citation_string_with(online_video, options)
+            }
+            Citation::Software(software) => ApaFormatting::citation_string_with(software, options),
+            Citation::WebPage(web_page) => ApaFormatting::citation_string_with(web_page, options),
+            Citation::WikipediaArticle(article) => {
+                ApaFormatting::citation_string_with(article, options)
+            }
+            Citation::Custom(custom) => return custom.format_with(CitationStyle::Apa, options),
+        })
+    }
+
+    fn format_ieee_with(&self, options: &FormatOptions) -> Result<String, CitationError> {
+        Ok(match self {
+            Citation::Book(book) => IeeeFormatting::citation_string_with(book, options),
+            Citation::ConferencePaperOnline(paper) => {
+                IeeeFormatting::citation_string_with(paper, options)
+            }
+            Citation::ConferenceProceedingsOnline(proceedings) => {
+                IeeeFormatting::citation_string_with(proceedings, options)
+            }
+            Citation::JournalArticle(journal_article) => {
+                IeeeFormatting::citation_string_with(journal_article, options)
+            }
+            Citation::ConferencePresentation(presentation) => {
+                IeeeFormatting::citation_string_with(presentation, options)
+            }
+            Citation::OnlineManual(online_manual) => {
+                IeeeFormatting::citation_string_with(online_manual, options)
+            }
+            Citation::OnlineVideo(online_video) => {
+                IeeeFormatting::citation_string_with(online_video, options)
+            }
+            Citation::Software(software) => IeeeFormatting::citation_string_with(software, options),
+            Citation::WebPage(web_page) => IeeeFormatting::citation_string_with(web_page, options),
+            Citation::WikipediaArticle(article) => {
+                IeeeFormatting::citation_string_with(article, options)
+            }
+            Citation::Custom(custom) => return custom.format_with(CitationStyle::Ieee, options),
+        })
+    }
+
+    fn format_iso690_with(&self, options: &FormatOptions) -> Result<String, CitationError> {
+        Ok(match self {
+            Citation::Book(book) => Iso690Formatting::citation_string_with(book, options),
+            Citation::ConferencePaperOnline(paper) => {
+                Iso690Formatting::citation_string_with(paper, options)
+            }
+            Citation::ConferenceProceedingsOnline(proceedings) => {
+                Iso690Formatting::citation_string_with(proceedings, options)
+            }
+            Citation::JournalArticle(journal_article) => {
+                Iso690Formatting::citation_string_with(journal_article, options)
+            }
+            Citation::ConferencePresentation(presentation) => {
+                Iso690Formatting::citation_string_with(presentation, options)
+            }
+            Citation::OnlineManual(online_manual) => {
+                Iso690Formatting::citation_string_with(online_manual, options)
+            }
+            Citation::OnlineVideo(online_video) => {
+                Iso690Formatting::citation_string_with(online_video, options)
+            }
+            Citation::Software(software) => {
+                Iso690Formatting::citation_string_with(software, options)
+            }
+            Citation::WebPage(web_page) => {
+                Iso690Formatting::citation_string_with(web_page, options)
+            }
+            Citation::WikipediaArticle(article) => {
+                Iso690Formatting::citation_string_with(article, options)
+            }
+            Citation::Custom(custom) => return custom.format_with(CitationStyle::Iso690, options),
+        })
+    }
+
+    fn format_vancouver_with(&self, options: &FormatOptions) -> Result<String, CitationError> {
+        Ok(match self {
+            Citation::Book(book) => VancouverFormatting::citation_string_with(book, options),
+            Citation::ConferencePaperOnline(paper) => {
+                VancouverFormatting::citation_string_with(paper, options)
+            }
+            Citation::ConferenceProceedingsOnline(proceedings) => {
+                VancouverFormatting::citation_string_with(proceedings, options)
+            }
+            Citation::JournalArticle(journal_article) => {
+                VancouverFormatting::citation_string_with(journal_article, options)
+            }
+            Citation::ConferencePresentation(presentation) => {
+                VancouverFormatting::citation_string_with(presentation, options)
+            }
+            Citation::OnlineManual(online_manual) => {
+                VancouverFormatting::citation_string_with(online_manual, options)
+            }
+            Citation::OnlineVideo(online_video) => {
+                VancouverFormatting::citation_string_with(online_video, options)
+            }
+            Citation::Software(software) => {
+                VancouverFormatting::citation_string_with(software, options)
+            }
+            Citation::WebPage(web_page) => {
+                VancouverFormatting::citation_string_with(web_page, options)
+            }
+            Citation::WikipediaArticle(article) => {
+                VancouverFormatting::citation_string_with(article, options)
+            }
+            Citation::Custom(custom) => {
+                return custom.format_with(CitationStyle::Vancouver, options);
+            }
+        })
+    }
+
+    fn format_ama_with(&self, options: &FormatOptions) -> Result<String, CitationError> {
+        Ok(match self {
+            Citation::Book(book) => AmaFormatting::citation_string_with(book, options),
+            Citation::ConferencePaperOnline(paper) => {
+                AmaFormatting::citation_string_with(paper, options)
+            }
+            Citation::ConferenceProceedingsOnline(proceedings) => {
+                AmaFormatting::citation_string_with(proceedings, options)
+            }
+            Citation::JournalArticle(journal_article) => {
+                AmaFormatting::citation_string_with(journal_article, options)
+            }
+            Citation::ConferencePresentation(presentation) => {
+                AmaFormatting::citation_string_with(presentation, options)
+            }
+            Citation::OnlineManual(online_manual) => {
+                AmaFormatting::citation_string_with(online_manual, options)
+            }
+            Citation::OnlineVideo(online_video) => {
+                AmaFormatting::citation_string_with(online_video, options)
+            }
+            Citation::Software(software) => AmaFormatting::citation_string_with(software, options),
+            Citation::WebPage(web_page) => AmaFormatting::citation_string_with(web_page, options),
+            Citation::WikipediaArticle(article) => {
+                AmaFormatting::citation_string_with(article, options)
+            }
+            Citation::Custom(custom) => return custom.format_with(CitationStyle::Ama, options),
+        })
+    }
+
+    fn format_acs_with(&self, options: &FormatOptions) -> Result<String, CitationError> {
+        Ok(match self {
+            Citation::Book(book) => AcsFormatting::citation_string_with(book, options),
+            Citation::ConferencePaperOnline(paper) => {
+                AcsFormatting::citation_string_with(paper, options)
+            }
+            Citation::ConferenceProceedingsOnline(proceedings) => {
+                AcsFormatting::citation_string_with(proceedings, options)
+            }
+            Citation::JournalArticle(journal_article) => {
+                AcsFormatting::citation_string_with(journal_article, options)
+            }
+            Citation::ConferencePresentation(presentation) => {
+                AcsFormatting::citation_string_with(presentation, options)
+            }
+            Citation::OnlineManual(online_manual) => {
+                AcsFormatting::citation_string_with(online_manual, options)
+            }
+            Citation::OnlineVideo(online_video) => {
+                AcsFormatting::citation_string_with(online_video, options)
+            }
+            Citation::Software(software) => AcsFormatting::citation_string_with(software, options),
+            Citation::WebPage(web_page) => AcsFormatting::citation_string_with(web_page, options),
+            Citation::WikipediaArticle(article) => {
+                AcsFormatting::citation_string_with(article, options)
+            }
+            Citation::Custom(custom) => return custom.format_with(CitationStyle::Acs, options),
+        })
+    }
 }
 
 impl fmt::Display for Citation {
@@ -112,25 +1075,235 @@ impl fmt::Display for Citation {
 
 #[cfg(test)]
 mod tests {
-    use crate::api::{
-        author::{GenericAuthor, PersonName},
-        citation::Citation,
-        media::{book::Book, common::CommonCitationData},
+    use std::collections::HashSet;
+
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    use crate::{
+        api::{
+            author::{GenericAuthor, PersonName},
+            citation::Citation,
+            citation_id::CitationId,
+            date::PublishDate,
+            identifiers::Identifiers,
+            media::{
+                MediaKind,
+                book::Book,
+                common::CommonCitationData,
+                conference_paper::{ConferencePaperOnline, ConferenceProceedingsOnline},
+                online_video::OnlineVideo,
+            },
+            style::{CitationStyle, FormatOptions, Markup, PrintLink},
+        },
+        import::identifier::Identifier,
     };
 
     #[test]
-    fn test_citation_creation() {
-        let citation = Citation::Book(Book {
+    fn test_set_accessed() {
+        let mut video = Citation::OnlineVideo(OnlineVideo::Generic {
+            common_data: CommonCitationData::new("video"),
+            title: "A Video".into(),
+            url: None,
+            accessed: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().into(),
+        });
+
+        assert!(video.set_accessed(NaiveDate::from_ymd_opt(2025, 10, 1).unwrap()));
+        assert_eq!(video.accessed().map(|accessed| accessed.year()), Some(2025));
+    }
+
+    #[test]
+    fn test_set_accessed_unsupported_media() {
+        let mut book = Citation::Book(Book {
+            common_data: CommonCitationData::new("book"),
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: "A Book".into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        });
+
+        assert!(!book.set_accessed_now());
+        assert_eq!(book.accessed(), None);
+    }
+
+    #[test]
+    fn test_print_link() {
+        let book = Citation::Book(Book {
+            common_data: CommonCitationData::new("book"),
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: "A Book".into(),
+            doi: Some("10.1000/xyz".to_string()),
+            pages: None,
+            chapter: None,
+            version: None,
+        });
+        let options = |print_link, markup| FormatOptions {
+            print_link,
+            markup,
+            ..FormatOptions::for_style(CitationStyle::Apa)
+        };
+
+        assert!(
+            book.format_with(
+                CitationStyle::Apa,
+                &options(PrintLink::ShortDoi, Markup::PlainText)
+            )
+            .ends_with(" doi.org/10.1000/xyz")
+        );
+        let html = book.format_with(
+            CitationStyle::Apa,
+            &options(PrintLink::QrCode, Markup::Html),
+        );
+        assert!(html.contains(r#"<img class="qr" src="data:image/svg+xml,"#));
+        assert!(html.ends_with(r#" alt="QR code for https://doi.org/10.1000/xyz">"#));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let mut book = Citation::Book(Book {
+            common_data: CommonCitationData::new("book"),
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: "A Book".into(),
+            doi: Some("10.1000/xyz".to_string()),
+            pages: None,
+            chapter: None,
+            version: None,
+        });
+        let formatted = book.format(CitationStyle::Ieee);
+        let hash = book.content_hash();
+
+        assert_eq!(
+            book.set_override(CitationStyle::Apa, "Org. (n.d.). A book."),
+            None
+        );
+        assert_eq!(book.format(CitationStyle::Apa), "Org. (n.d.). A book.");
+        let options = FormatOptions {
+            print_link: PrintLink::ShortDoi,
+            markup: Markup::Html,
+            ..FormatOptions::for_style(CitationStyle::Apa)
+        };
+        assert_eq!(
+            book.format_with(CitationStyle::Apa, &options),
+            "Org. (n.d.). A book."
+        );
+        assert_eq!(book.format(CitationStyle::Ieee), formatted);
+        // Overrides describe the entry, not the work
+        assert_eq!(book.content_hash(), hash);
+
+        let round_trip: Citation =
+            serde_json::from_str(&serde_json::to_string(&book).unwrap()).unwrap();
+        assert_eq!(
+            round_trip.override_for(CitationStyle::Apa),
+            Some("Org. (n.d.). A book.")
+        );
+        assert_eq!(
+            book.clear_override(CitationStyle::Apa).as_deref(),
+            Some("Org. (n.d.). A book.")
+        );
+        assert_eq!(book.override_for(CitationStyle::Apa), None);
+    }
+
+    #[test]
+    fn test_conference_entries_in_every_style() {
+        let common_data = CommonCitationData::new("icse2023");
+        let conference_date = Utc.with_ymd_and_hms(2023, 5, 14, 0, 0, 0).unwrap();
+        let paper = Citation::ConferencePaperOnline(ConferencePaperOnline {
+            common_data: common_data.clone(),
+            title: "A Paper".into(),
+            venue: None,
+            volume: None,
+            number: None,
+            conference_name: "RustConf".to_string(),
+            series: Default::default(),
+            conference_date,
+        });
+        let proceedings = Citation::ConferenceProceedingsOnline(ConferenceProceedingsOnline {
+            common_data,
+            title: "RustConf Proceedings".into(),
+            venue: None,
+            volume: None,
+            number: None,
+            conference_name: "RustConf".to_string(),
+            series: Default::default(),
+            conference_date,
+            editors: vec![PersonName::from_first_last("Jane", "Editor").unwrap()],
+        });
+
+        for style in CitationStyle::ALL {
+            assert!(paper.format(style).contains("A Paper"), "{:?}", style);
+            assert!(
+                proceedings.format(style).to_lowercase().contains("editor"),
+                "{:?}",
+                style
+            );
+        }
+    }
+
+    #[test]
+    fn test_kind_and_accessors() {
+        let video = Citation::OnlineVideo(OnlineVideo::Generic {
+            common_data: CommonCitationData::new("video"),
+            title: "A Video".into(),
+            url: None,
+            accessed: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().into(),
+        });
+
+        assert_eq!(video.kind(), MediaKind::OnlineVideo);
+        assert_eq!(video.kind().to_string(), "Online Video");
+        assert!(video.as_online_video().is_some());
+        assert!(video.as_book().is_none());
+    }
+
+    #[test]
+    fn test_identifiers() {
+        let book = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "cv_algo_practice".to_string(),
-                published: None,
+                identifiers: Identifiers::new().with(Identifier::Isbn("9780306406157".to_string())),
+                ..CommonCitationData::new("book")
+            },
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
             },
+            with_authors: Vec::new(),
+            title: "A Book".into(),
+            doi: Some("10.1000/book".to_string()),
+            pages: None,
+            chapter: None,
+            version: None,
+        });
+        let identifiers = book.identifiers();
+
+        assert_eq!(identifiers.len(), 2);
+        assert_eq!(identifiers.isbn(), Some("9780306406157"));
+        assert_eq!(identifiers.doi(), Some("10.1000/book"));
+        assert_eq!(identifiers.url(), None);
+    }
+
+    #[test]
+    fn test_citation_creation() {
+        let citation = Citation::Book(Book {
+            common_data: CommonCitationData::new("cv_algo_practice"),
             author: GenericAuthor::Persons {
                 persons: vec![
                     PersonName::from_first_middle_last("Colin", "James", "VanDervoort").unwrap(),
                 ],
             },
-            title: "algo_practice".to_string(),
+            with_authors: Vec::new(),
+            title: "algo_practice".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -140,4 +1313,82 @@ mod tests {
         assert_eq!(citation.id(), "cv_algo_practice");
         assert_eq!(citation.title(), "algo_practice");
     }
+
+    #[test]
+    fn test_summary() {
+        let mut citation = Citation::Book(Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("smith2023")
+            },
+            author: GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        });
+        assert_eq!(
+            citation.summary(),
+            "Smith 2023 \u{2014} A Great Paper [Book]"
+        );
+
+        if let Citation::Book(book) = &mut citation {
+            book.author = GenericAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_first_last("Ana", "Jones").unwrap(),
+                    PersonName::from_first_last("Li", "Wei").unwrap(),
+                ],
+            };
+            book.common_data.published = None;
+        }
+        assert_eq!(
+            citation.summary(),
+            "Smith et al. \u{2014} A Great Paper [Book]"
+        );
+    }
+
+    #[test]
+    fn test_citations_keyed_by_id() {
+        let book = |id: &str, doi: Option<&str>| {
+            Citation::Book(Book {
+                common_data: CommonCitationData::new(id),
+                author: GenericAuthor::Organization {
+                    name: "Org".to_string(),
+                    abbreviation: None,
+                },
+                with_authors: Vec::new(),
+                title: "A Book".into(),
+                doi: doi.map(str::to_string),
+                pages: None,
+                chapter: None,
+                version: None,
+            })
+        };
+
+        // A later edit of "a" is still the same entry
+        let mut edited = book("a", None);
+        edited.common_data_mut().tags.push("read".to_string());
+        let ids: HashSet<CitationId> = [book("a", None), edited, book("b", None)]
+            .iter()
+            .map(Citation::citation_id)
+            .collect();
+        assert_eq!(ids.len(), 2);
+
+        let keys: HashSet<CitationId> = [
+            book("a", Some("10.1000/XYZ")),
+            book("b", Some("https://doi.org/10.1000/xyz")),
+            book("c", None),
+        ]
+        .iter()
+        .map(Citation::dedup_key)
+        .collect();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains("10.1000/xyz"));
+        assert!(keys.contains("c"));
+    }
 }
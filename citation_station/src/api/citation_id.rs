@@ -0,0 +1,139 @@
+use std::{borrow::Borrow, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::import::doi::normalize_doi;
+
+/// The identity of a citation within a bibliography.
+///
+/// Two citations with the same ID refer to the same work, whatever their
+/// other fields contain, so this is the key to use in `HashMap`s and
+/// `HashSet`s for deduplication and graph algorithms.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct CitationId(String);
+
+impl CitationId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// The ID used for works keyed on their DOI (e.g. Crossref imports):
+    /// the normalized, lowercase DOI. Returns `None` if the input doesn't
+    /// look like a DOI.
+    pub fn from_doi(doi: &str) -> Option<Self> {
+        normalize_doi(doi).map(Self)
+    }
+
+    /// An ID made from free text such as a title or a surname: its letters
+    /// and digits, lowercased, with every run of other characters replaced
+    /// by a single "-". Returns `None` if `text` has no letters or digits.
+    pub fn slug(text: &str) -> Option<Self> {
+        let slug = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("-");
+        (!slug.is_empty()).then_some(Self(slug))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for CitationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for CitationId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for CitationId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl AsRef<str> for CitationId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Allows looking up `CitationId`-keyed maps and sets with a plain `&str`.
+impl Borrow<str> for CitationId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for CitationId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for CitationId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::api::citation_id::CitationId;
+
+    #[test]
+    fn test_from_doi_normalizes() {
+        assert_eq!(
+            CitationId::from_doi("https://doi.org/10.1000/XYZ123"),
+            Some(CitationId::from("10.1000/xyz123"))
+        );
+        assert_eq!(CitationId::from_doi("not a doi"), None);
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(
+            CitationId::slug("Deep Learning: A Survey (2nd ed.)"),
+            Some(CitationId::from("deep-learning-a-survey-2nd-ed"))
+        );
+        assert_eq!(
+            CitationId::slug("O'Brien"),
+            Some(CitationId::from("o-brien"))
+        );
+        assert_eq!(CitationId::slug("?!"), None);
+    }
+
+    #[test]
+    fn test_lookup_by_str() {
+        let ids: HashSet<CitationId> = ["smith2023", "jones2019"]
+            .into_iter()
+            .map(CitationId::from)
+            .collect();
+
+        assert!(ids.contains("smith2023"));
+        assert!(!ids.contains("doe2001"));
+    }
+
+    #[test]
+    fn test_serializes_as_plain_string() {
+        let id = CitationId::new("smith2023");
+
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"smith2023\"");
+    }
+}
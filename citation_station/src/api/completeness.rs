@@ -0,0 +1,242 @@
+use crate::api::{
+    citation::Citation,
+    media::{online_manual::OnlineManualAvailability, online_video::OnlineVideo},
+    requirements::CitationField,
+    style::CitationStyle,
+};
+
+/// Which recommended fields a citation fills in for a given style.
+/// Fields its media type has no place for are in neither list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletenessReport {
+    pub style: CitationStyle,
+    /// Recommended fields that are filled in
+    pub present: Vec<CitationField>,
+    /// Recommended fields that are empty
+    pub missing: Vec<CitationField>,
+}
+
+impl CompletenessReport {
+    pub fn for_citation(citation: &Citation, style: CitationStyle) -> Self {
+        let mut report = Self {
+            style,
+            present: Vec::new(),
+            missing: Vec::new(),
+        };
+        for (field, filled) in recommended_fields(citation, style) {
+            if filled {
+                report.present.push(field);
+            } else {
+                report.missing.push(field);
+            }
+        }
+        report
+    }
+
+    /// Share of recommended fields that are filled in, from 0 to 100.
+    /// A citation with nothing to recommend scores 100.
+    pub fn score(&self) -> u8 {
+        let total = self.present.len() + self.missing.len();
+        if total == 0 {
+            return 100;
+        }
+        (self.present.len() * 100 / total) as u8
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// The recommended fields and whether each is filled in, leaving out
+/// fields the citation's media type has no place for
+fn recommended_fields(citation: &Citation, style: CitationStyle) -> Vec<(CitationField, bool)> {
+    style
+        .requirements(citation.kind())
+        .recommended
+        .into_iter()
+        .filter_map(|field| Some((field, is_filled(citation, field)?)))
+        .collect()
+}
+
+/// Whether `citation` has a value for `field`, or `None` if its media
+/// type doesn't record the field
+fn is_filled(citation: &Citation, field: CitationField) -> Option<bool> {
+    use CitationField::*;
+
+    Some(match field {
+        Author => !citation.contributors().is_empty(),
+        Title => !citation.title().trim().is_empty(),
+        PublishDate => citation.published().is_some(),
+        // Media types with an access date always record one
+        AccessDate => return citation.accessed().map(|_| true),
+        Doi => match citation {
+            Citation::Book(_)
+            | Citation::JournalArticle(_)
+            | Citation::Software(_)
+            | Citation::Custom(_) => citation.doi().is_some(),
+            _ => return None,
+        },
+        Publisher => match citation {
+            // ONIX feeds and BibTeX files record a book's publisher here
+            Citation::Book(book) => book.common_data.extended.publisher.is_some(),
+            Citation::Software(software) => software.publisher.is_some(),
+            _ => return None,
+        },
+        Container => match citation {
+            Citation::JournalArticle(article) => !article.journal.trim().is_empty(),
+            Citation::ConferencePresentation(presentation) => {
+                !presentation.conference_name.trim().is_empty()
+            }
+            Citation::ConferencePaperOnline(paper) => !paper.conference_name.trim().is_empty(),
+            Citation::ConferenceProceedingsOnline(proceedings) => {
+                !proceedings.conference_name.trim().is_empty()
+            }
+            _ => return None,
+        },
+        Pages => match citation {
+            Citation::Book(book) => book.pages.is_some(),
+            Citation::JournalArticle(article) => article.locator.is_some(),
+            _ => return None,
+        },
+        Volume => match citation {
+            Citation::JournalArticle(article) => article.volume.is_some(),
+            Citation::ConferencePaperOnline(paper) => paper.volume.is_some(),
+            Citation::ConferenceProceedingsOnline(proceedings) => proceedings.volume.is_some(),
+            _ => return None,
+        },
+        Issue => match citation {
+            Citation::JournalArticle(article) => article.number.is_some(),
+            Citation::ConferencePaperOnline(paper) => paper.number.is_some(),
+            Citation::ConferenceProceedingsOnline(proceedings) => proceedings.number.is_some(),
+            _ => return None,
+        },
+        Availability => match citation {
+            Citation::OnlineManual(manual) => {
+                manual.available_at != OnlineManualAvailability::NotAvailable
+            }
+            Citation::OnlineVideo(OnlineVideo::Generic { url, .. })
+            | Citation::OnlineVideo(OnlineVideo::YouTube { url, .. }) => url.is_some(),
+            Citation::Software(software) => software.url.is_some() || software.doi.is_some(),
+            Citation::ConferencePresentation(presentation) => presentation.url.is_some(),
+            // Web pages and Wikipedia articles always have a URL
+            Citation::WebPage(_) | Citation::WikipediaArticle(_) => true,
+            _ => return None,
+        },
+        Version => match citation {
+            Citation::Book(book) => book.version.is_some(),
+            Citation::OnlineManual(manual) => manual.version.is_some(),
+            Citation::Software(software) => software.version.is_some(),
+            Citation::WikipediaArticle(article) => article.revision.is_some(),
+            _ => return None,
+        },
+        Venue => match citation {
+            Citation::WebPage(web_page) => web_page.site_name.is_some(),
+            Citation::ConferencePresentation(presentation) => presentation.location.is_some(),
+            Citation::ConferencePaperOnline(paper) => paper.venue.is_some(),
+            Citation::ConferenceProceedingsOnline(proceedings) => proceedings.venue.is_some(),
+            _ => return None,
+        },
+        Chapter => match citation {
+            Citation::Book(book) => book.chapter.is_some(),
+            _ => return None,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{
+        author::{AcademicAuthor, GenericAuthor},
+        citation::Citation,
+        completeness::is_filled,
+        date::PublishDate,
+        media::{book::Book, common::CommonCitationData, journal_article::JournalArticle},
+        requirements::CitationField,
+        style::CitationStyle,
+        volume::Volume,
+    };
+
+    fn article(doi: Option<&str>) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2020)),
+                ..CommonCitationData::new("article")
+            },
+            author: AcademicAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            title: "Title".into(),
+            journal: "Journal".to_string(),
+            volume: Some(Volume::parse("1")),
+            number: None,
+            locator: None,
+            doi: doi.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let report = article(None).completeness(CitationStyle::Apa);
+
+        assert_eq!(
+            report.missing,
+            [
+                CitationField::Issue,
+                CitationField::Pages,
+                CitationField::Doi
+            ]
+        );
+        assert_eq!(report.score(), 50);
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn test_score_improves_with_doi() {
+        let without = article(None).completeness(CitationStyle::Ieee).score();
+        let with = article(Some("10.1000/1"))
+            .completeness(CitationStyle::Ieee)
+            .score();
+
+        assert!(with > without);
+    }
+
+    #[test]
+    fn test_book_publisher() {
+        let mut book = Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2020)),
+                ..CommonCitationData::new("book")
+            },
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: "Title".into(),
+            chapter: None,
+            version: None,
+            doi: None,
+            pages: None,
+        };
+        let report = Citation::Book(book.clone()).completeness(CitationStyle::Ieee);
+        assert_eq!(report.missing, [CitationField::Publisher]);
+
+        // As read from an ONIX feed
+        book.common_data.extended.publisher = Some("Example Press".to_string());
+        let report = Citation::Book(book).completeness(CitationStyle::Ieee);
+        assert!(report.is_complete());
+        assert!(report.present.contains(&CitationField::Publisher));
+    }
+
+    #[test]
+    fn test_fields_without_a_place_are_not_applicable() {
+        let article = article(None);
+
+        assert_eq!(is_filled(&article, CitationField::Chapter), None);
+        assert_eq!(is_filled(&article, CitationField::Publisher), None);
+        assert_eq!(is_filled(&article, CitationField::AccessDate), None);
+        assert_eq!(is_filled(&article, CitationField::Volume), Some(true));
+    }
+}
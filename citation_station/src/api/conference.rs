@@ -0,0 +1,87 @@
+use ordinal::ToOrdinal as _;
+use serde::{Deserialize, Serialize};
+
+/// The numbering and sponsors of a conference in a series, kept apart
+/// from its name, e.g. the "45th" and "IEEE/ACM" of the 45th IEEE/ACM
+/// International Conference on Software Engineering
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConferenceSeries {
+    /// Which meeting of the series this was
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+    /// Sponsoring bodies, e.g. "IEEE" and "ACM"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sponsors: Vec<String>,
+}
+
+impl ConferenceSeries {
+    pub fn is_empty(&self) -> bool {
+        self.number.is_none() && self.sponsors.is_empty()
+    }
+
+    /// The conference's full name, e.g. "45th IEEE/ACM International
+    /// Conference on Software Engineering" for "International Conference
+    /// on Software Engineering". Names that already mention a sponsor
+    /// are left to speak for them.
+    pub fn full_name(&self, name: &str) -> String {
+        let name = name.trim();
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(number) = self.number {
+            parts.push(number.to_ordinal_string());
+        }
+        let named = self.sponsors.iter().any(|sponsor| {
+            name.split(|c: char| c.is_whitespace() || c == '/')
+                .any(|word| word == sponsor)
+        });
+        if !named && !self.sponsors.is_empty() {
+            parts.push(self.sponsors.join("/"));
+        }
+        parts.push(name.to_string());
+        parts.join(" ")
+    }
+
+    /// The title of the conference's proceedings, e.g. "Proceedings of the
+    /// 45th IEEE/ACM International Conference on Software Engineering"
+    pub fn proceedings_title(&self, name: &str) -> String {
+        let name = self.full_name(name);
+        if name.to_lowercase().starts_with("proceedings") {
+            name
+        } else {
+            format!("Proceedings of the {}", name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::conference::ConferenceSeries;
+
+    #[test]
+    fn test_full_name() {
+        let series = ConferenceSeries {
+            number: Some(45),
+            sponsors: vec!["IEEE".to_string(), "ACM".to_string()],
+        };
+        assert_eq!(
+            series.full_name("International Conference on Software Engineering"),
+            "45th IEEE/ACM International Conference on Software Engineering"
+        );
+        assert_eq!(
+            series.full_name("IEEE International Conference on Software Engineering"),
+            "45th IEEE International Conference on Software Engineering"
+        );
+        assert_eq!(
+            ConferenceSeries::default().full_name(" RustConf "),
+            "RustConf"
+        );
+        assert_eq!(
+            series.proceedings_title("International Conference on Software Engineering"),
+            "Proceedings of the 45th IEEE/ACM International Conference on Software Engineering"
+        );
+        assert_eq!(
+            ConferenceSeries::default().proceedings_title("Proceedings of the IEEE"),
+            "Proceedings of the IEEE"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use serde_json::{Map, Value};
+
+use crate::api::citation::Citation;
+
+/// Fields of the common data that describe the bibliography entry rather
+/// than the cited work
+const ENTRY_FIELDS: [&str; 5] = ["id", "cites", "tags", "timestamps", "overrides"];
+
+/// The date a work was accessed changes every time it is re-checked
+const VOLATILE_FIELDS: [&str; 1] = ["accessed"];
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// The citation as compact JSON with sorted keys, without entry-specific
+/// and volatile fields, with whitespace in strings collapsed, and without
+/// empty values. Two citations of the same work made in different
+/// bibliographies, or on different days, have the same canonical JSON.
+pub fn canonical_json(citation: &Citation) -> String {
+    let value = serde_json::to_value(citation).expect("citations serialize to JSON");
+    // serde_json's maps are ordered by key, so the output is deterministic
+    canonicalize(value).unwrap_or(Value::Null).to_string()
+}
+
+/// 64-bit FNV-1a hash of the canonical JSON. Unlike `std::hash::Hash`, the
+/// result is the same across builds, platforms and Rust versions, so it can
+/// be stored.
+pub fn content_hash(citation: &Citation) -> u64 {
+    canonical_json(citation)
+        .bytes()
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
+/// Normalize a value, returning `None` for values that are empty once
+/// normalized
+fn canonicalize(value: Value) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => {
+            let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+            (!s.is_empty()).then_some(Value::String(s))
+        }
+        Value::Array(items) => {
+            let items: Vec<Value> = items.into_iter().filter_map(canonicalize).collect();
+            (!items.is_empty()).then_some(Value::Array(items))
+        }
+        Value::Object(fields) => {
+            let fields: Map<String, Value> = fields
+                .into_iter()
+                .filter(|(key, _)| !VOLATILE_FIELDS.contains(&key.as_str()))
+                .filter_map(|(key, value)| match (key.as_str(), value) {
+                    ("common_data", Value::Object(common)) => canonicalize(Value::Object(
+                        common
+                            .into_iter()
+                            .filter(|(key, _)| !ENTRY_FIELDS.contains(&key.as_str()))
+                            .collect(),
+                    ))
+                    .map(|common| (key, common)),
+                    // Identifiers are a set; their order is insignificant
+                    ("identifiers", Value::Array(mut identifiers)) => {
+                        identifiers.sort_by_key(Value::to_string);
+                        canonicalize(Value::Array(identifiers)).map(|value| (key, value))
+                    }
+                    (_, value) => canonicalize(value).map(|value| (key, value)),
+                })
+                .collect();
+            (!fields.is_empty()).then_some(Value::Object(fields))
+        }
+        other => Some(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::{
+        api::{
+            citation::Citation,
+            content_hash::{canonical_json, content_hash},
+            identifiers::Identifiers,
+            media::{common::CommonCitationData, web_page::WebPage},
+        },
+        import::identifier::Identifier,
+    };
+
+    fn web_page(id: &str, title: &str, accessed: NaiveDate) -> WebPage {
+        WebPage {
+            common_data: CommonCitationData::new(id),
+            author: None,
+            title: title.into(),
+            site_name: None,
+            url: "https://example.com".to_string(),
+            accessed: accessed.into(),
+        }
+    }
+
+    #[test]
+    fn test_hash_ignores_id_tags_and_access_date() {
+        let a = web_page("a", "Example", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        let mut b = web_page(
+            "b",
+            "  Example\n",
+            NaiveDate::from_ymd_opt(2025, 10, 1).unwrap(),
+        );
+        b.common_data.tags = vec!["reading".to_string()];
+
+        assert_eq!(
+            content_hash(&Citation::WebPage(a.clone())),
+            content_hash(&Citation::WebPage(b))
+        );
+        assert_eq!(
+            canonical_json(&Citation::WebPage(a)),
+            r#"{"WebPage":{"title":"Example","url":"https://example.com"}}"#
+        );
+    }
+
+    #[test]
+    fn test_hash_depends_on_content() {
+        let accessed = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap();
+        let a = Citation::WebPage(web_page("a", "Example", accessed));
+        let b = Citation::WebPage(web_page("a", "Another example", accessed));
+
+        assert_ne!(content_hash(&a), content_hash(&b));
+    }
+
+    #[test]
+    fn test_hash_ignores_identifier_order() {
+        let accessed = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap();
+        let doi = Identifier::Doi("10.1000/a".to_string());
+        let pmid = Identifier::Pmid("42".to_string());
+        let mut a = web_page("a", "Example", accessed);
+        a.common_data.identifiers = Identifiers::new().with(doi.clone()).with(pmid.clone());
+        let mut b = web_page("a", "Example", accessed);
+        b.common_data.identifiers = Identifiers::new().with(pmid).with(doi);
+
+        assert_eq!(
+            content_hash(&Citation::WebPage(a)),
+            content_hash(&Citation::WebPage(b))
+        );
+    }
+}
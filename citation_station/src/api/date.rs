@@ -1,9 +1,11 @@
 use std::cmp::Ordering;
 
-use chrono::{DateTime, Datelike, Local, Month, NaiveDate, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Datelike, FixedOffset, Local, Month, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, de};
 use thiserror::Error;
 
+use crate::api::style::CitationStyle;
+
 /// Get the abbreviated name of the month (e.g. "Jan."")
 pub const fn ieee_abbreviated_month_name(month: &Month) -> &'static str {
     match month {
@@ -32,11 +34,23 @@ pub enum PublishDateParamError {
 
 /// This data model doesn't accommodate ranges of dates, like
 /// what would be seen in a conference.
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum PublishDate {
-    Year { year: i32 },
-    YearMonth { year: i32, month: Month },
-    YearMonthDay { year: i32, month: Month, day: u32 },
+    Year {
+        year: i32,
+    },
+    YearMonth {
+        year: i32,
+        #[cfg_attr(feature = "schema", schemars(schema_with = "crate::schema::month"))]
+        month: Month,
+    },
+    YearMonthDay {
+        year: i32,
+        #[cfg_attr(feature = "schema", schemars(schema_with = "crate::schema::month"))]
+        month: Month,
+        day: u32,
+    },
 }
 
 impl PublishDate {
@@ -55,13 +69,9 @@ impl PublishDate {
     ) -> Result<Self, PublishDateParamError> {
         let maybe_days_in_month = month.num_days(year);
         if let Some(days_in_month) = maybe_days_in_month {
-            let valid_day_range = 1..(u32::from(days_in_month));
+            let valid_day_range = 1..=(u32::from(days_in_month));
             if valid_day_range.contains(&day) {
-                Result::Ok(Self::YearMonthDay {
-                    year,
-                    month: month,
-                    day: day,
-                })
+                Result::Ok(Self::YearMonthDay { year, month, day })
             } else {
                 Result::Err(PublishDateParamError::InvalidDayForMonth)
             }
@@ -70,6 +80,30 @@ impl PublishDate {
         }
     }
 
+    /// Parse the date part of an ISO 8601 date or timestamp: "2023",
+    /// "2023-03", "2023-03-05" or "2023-03-05T10:00:00Z". The time and
+    /// timezone are ignored.
+    pub fn parse_iso(s: &str) -> Option<Self> {
+        let date = s.trim().split(['T', ' ']).next()?;
+        let mut parts = date.split('-');
+        let year_part = parts.next()?;
+        if year_part.len() != 4 {
+            return None;
+        }
+        let year: i32 = year_part.parse().ok()?;
+        let Some(month) = parts.next() else {
+            return Some(Self::from_year(year));
+        };
+        let month = Month::try_from(month.parse::<u8>().ok()?).ok()?;
+        let Some(day) = parts.next() else {
+            return Some(Self::from_year_month(year, month));
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        Self::from_year_month_day(year, month, day.parse().ok()?).ok()
+    }
+
     pub fn from_chrono_utc_datetime(datetime: DateTime<Utc>) -> Self {
         let month = Month::try_from(datetime.month() as u8).unwrap();
         Self::YearMonthDay {
@@ -107,10 +141,10 @@ impl PublishDate {
         match self {
             PublishDate::Year { year } => format!("{}", year),
             PublishDate::YearMonth { year, month } => {
-                format!("{}, {}", ieee_abbreviated_month_name(&month), year)
+                format!("{}, {}", ieee_abbreviated_month_name(month), year)
             }
             PublishDate::YearMonthDay { year, month, day } => {
-                format!("{} {}, {}", ieee_abbreviated_month_name(&month), day, year,)
+                format!("{} {}, {}", ieee_abbreviated_month_name(month), day, year,)
             }
         }
     }
@@ -124,6 +158,28 @@ impl PublishDate {
             }
         }
     }
+
+    /// e.g. "March 5, 2023"
+    pub fn fmt_for_ama_citation(&self) -> String {
+        match self {
+            PublishDate::Year { year } => format!("{}", year),
+            PublishDate::YearMonth { year, month } => format!("{} {}", month.name(), year),
+            PublishDate::YearMonthDay { year, month, day } => {
+                format!("{} {}, {}", month.name(), day, year)
+            }
+        }
+    }
+
+    /// e.g. "2023 Mar 5"
+    pub fn fmt_for_vancouver_citation(&self) -> String {
+        match self {
+            PublishDate::Year { year } => format!("{}", year),
+            PublishDate::YearMonth { year, month } => format!("{} {}", year, &month.name()[..3]),
+            PublishDate::YearMonthDay { year, month, day } => {
+                format!("{} {} {}", year, &month.name()[..3], day)
+            }
+        }
+    }
 }
 
 impl Ord for PublishDate {
@@ -153,12 +209,54 @@ impl PartialOrd for PublishDate {
 
 impl Eq for PublishDate {}
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// The calendar day on which an online resource was viewed.
+///
+/// Citations only ever show the date, so no time or timezone is stored.
+/// Converting a timezoned datetime keeps the calendar day *in that
+/// timezone*: 00:30 local time on Oct. 1 is accessed on Oct. 1, even if
+/// it was still Sep. 30 in UTC. Equality and ordering are by day.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AccessDate {
-    accessed: DateTime<Utc>,
+    #[serde(deserialize_with = "deserialize_access_day")]
+    accessed: NaiveDate,
+}
+
+/// Accept plain dates ("2025-10-01") as well as the RFC 3339 timestamps
+/// written by earlier versions, which stored a UTC datetime.
+fn deserialize_access_day<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+        .or_else(|_| {
+            DateTime::parse_from_rfc3339(&s).map(|datetime| datetime.to_utc().date_naive())
+        })
+        .map_err(de::Error::custom)
 }
 
 impl AccessDate {
+    pub const fn from_date(date: NaiveDate) -> Self {
+        Self { accessed: date }
+    }
+
+    /// The calendar day of `datetime` in its own timezone.
+    pub fn from_datetime<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self {
+        Self {
+            accessed: datetime.date_naive(),
+        }
+    }
+
+    /// Today, according to the system's local timezone.
+    pub fn today() -> Self {
+        Self::from_datetime(&Local::now())
+    }
+
+    pub const fn date(&self) -> NaiveDate {
+        self.accessed
+    }
+
     pub fn year(&self) -> i32 {
         self.accessed.year()
     }
@@ -183,49 +281,230 @@ impl AccessDate {
     pub fn fmt_for_apa_citation(&self) -> String {
         format!("{}, {} {}", self.year(), self.month().name(), self.day())
     }
+
+    /// e.g. "5 March 2023"
+    pub fn fmt_for_iso690_citation(&self) -> String {
+        format!("{} {} {}", self.day(), self.month().name(), self.year())
+    }
+
+    /// e.g. "March 5, 2023"
+    pub fn fmt_for_ama_citation(&self) -> String {
+        format!("{} {}, {}", self.month().name(), self.day(), self.year())
+    }
+
+    /// e.g. "2023-03-05"
+    pub fn fmt_for_acs_citation(&self) -> String {
+        self.accessed.format("%Y-%m-%d").to_string()
+    }
+
+    /// e.g. "2023 Mar 5"
+    pub fn fmt_for_vancouver_citation(&self) -> String {
+        format!(
+            "{} {} {}",
+            self.year(),
+            &self.month().name()[..3],
+            self.day()
+        )
+    }
 }
 
 impl Default for AccessDate {
     fn default() -> Self {
-        Self {
-            accessed: Utc::now(),
+        Self::today()
+    }
+}
+
+/// How a citation style writes publication and access dates. Every media
+/// type formats its dates through the formatter of the style at hand, so
+/// the same date reads the same way in every reference of a style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateFormatter {
+    style: CitationStyle,
+}
+
+impl DateFormatter {
+    pub const fn for_style(style: CitationStyle) -> Self {
+        Self { style }
+    }
+
+    pub const fn style(&self) -> CitationStyle {
+        self.style
+    }
+
+    /// The publication date as precise as it is known, e.g. "Mar. 5, 2023"
+    /// for IEEE. ISO 690 and ACS references give the year only.
+    pub fn published(&self, date: &PublishDate) -> String {
+        match self.style {
+            CitationStyle::Apa => date.fmt_for_apa_citation(),
+            CitationStyle::Ieee => date.fmt_for_ieee_citation(),
+            CitationStyle::Ama => date.fmt_for_ama_citation(),
+            CitationStyle::Vancouver => date.fmt_for_vancouver_citation(),
+            CitationStyle::Iso690 | CitationStyle::Acs => date.year().to_string(),
+        }
+    }
+
+    /// The access date, e.g. "Mar. 5, 2023" for IEEE
+    pub fn accessed(&self, date: &AccessDate) -> String {
+        match self.style {
+            CitationStyle::Apa | CitationStyle::Ama => date.fmt_for_ama_citation(),
+            CitationStyle::Ieee => date.fmt_for_ieee_citation(),
+            CitationStyle::Iso690 => date.fmt_for_iso690_citation(),
+            CitationStyle::Vancouver => date.fmt_for_vancouver_citation(),
+            CitationStyle::Acs => date.fmt_for_acs_citation(),
+        }
+    }
+
+    /// The access date with the style's wording and brackets, without
+    /// closing punctuation, e.g. "Accessed: Mar. 5, 2023" for IEEE or
+    /// "[cited 2023 Mar 5]" for Vancouver
+    pub fn access_note(&self, date: &AccessDate) -> String {
+        let accessed = self.accessed(date);
+        match self.style {
+            CitationStyle::Apa => format!("Retrieved {}", accessed),
+            CitationStyle::Ieee => format!("Accessed: {}", accessed),
+            CitationStyle::Iso690 => format!("[viewed {}]", accessed),
+            CitationStyle::Vancouver => format!("[cited {}]", accessed),
+            CitationStyle::Ama => format!("Accessed {}", accessed),
+            CitationStyle::Acs => format!("(accessed {})", accessed),
         }
     }
 }
 
+pub const APA_DATES: DateFormatter = DateFormatter::for_style(CitationStyle::Apa);
+pub const IEEE_DATES: DateFormatter = DateFormatter::for_style(CitationStyle::Ieee);
+pub const ISO_690_DATES: DateFormatter = DateFormatter::for_style(CitationStyle::Iso690);
+pub const VANCOUVER_DATES: DateFormatter = DateFormatter::for_style(CitationStyle::Vancouver);
+pub const AMA_DATES: DateFormatter = DateFormatter::for_style(CitationStyle::Ama);
+pub const ACS_DATES: DateFormatter = DateFormatter::for_style(CitationStyle::Acs);
+
+impl From<NaiveDate> for AccessDate {
+    fn from(value: NaiveDate) -> Self {
+        Self::from_date(value)
+    }
+}
+
 impl From<DateTime<Utc>> for AccessDate {
     fn from(value: DateTime<Utc>) -> Self {
-        Self { accessed: value }
+        Self::from_datetime(&value)
     }
 }
 
 impl From<DateTime<Local>> for AccessDate {
     fn from(value: DateTime<Local>) -> Self {
-        Self {
-            accessed: value.to_utc(),
-        }
+        Self::from_datetime(&value)
     }
 }
 
-impl From<NaiveDate> for AccessDate {
-    fn from(value: NaiveDate) -> Self {
-        let accessed_utc = value.and_hms_opt(0, 0, 0).unwrap().and_utc();
-        Self {
-            accessed: accessed_utc,
-        }
+impl From<DateTime<FixedOffset>> for AccessDate {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        Self::from_datetime(&value)
     }
 }
 
-impl Ord for AccessDate {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.accessed.cmp(&other.accessed)
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Month, NaiveDate, TimeZone, Utc};
+
+    use crate::api::{
+        date::{AccessDate, DateFormatter, PublishDate},
+        style::CitationStyle,
+    };
+
+    #[test]
+    fn test_publish_date_last_day_of_month() {
+        assert!(PublishDate::from_year_month_day(2023, Month::January, 31).is_ok());
+        assert!(PublishDate::from_year_month_day(2024, Month::February, 29).is_ok());
+        assert!(PublishDate::from_year_month_day(2023, Month::February, 29).is_err());
+        assert!(PublishDate::from_year_month_day(2023, Month::March, 0).is_err());
     }
-}
 
-impl PartialOrd for AccessDate {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    #[test]
+    fn test_publish_date_parse_iso() {
+        assert_eq!(
+            PublishDate::parse_iso("2023-03-05T10:00:00+02:00"),
+            Some(PublishDate::from_year_month_day(2023, Month::March, 5).unwrap())
+        );
+        assert_eq!(
+            PublishDate::parse_iso("2023-03"),
+            Some(PublishDate::from_year_month(2023, Month::March))
+        );
+        assert_eq!(
+            PublishDate::parse_iso("2023"),
+            Some(PublishDate::from_year(2023))
+        );
+        assert_eq!(PublishDate::parse_iso("2023-13-01"), None);
+        assert_eq!(PublishDate::parse_iso("March 2023"), None);
     }
-}
 
-impl Eq for AccessDate {}
+    #[test]
+    fn test_access_date_keeps_local_calendar_day() {
+        let just_after_midnight =
+            DateTime::parse_from_rfc3339("2025-10-01T00:30:00+05:00").unwrap();
+
+        let accessed = AccessDate::from(just_after_midnight);
+
+        assert_eq!(
+            accessed.date(),
+            NaiveDate::from_ymd_opt(2025, 10, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_access_date_equality_is_by_day() {
+        let morning = Utc.with_ymd_and_hms(2025, 10, 1, 8, 0, 0).unwrap();
+        let evening = Utc.with_ymd_and_hms(2025, 10, 1, 20, 0, 0).unwrap();
+        let next_day = Utc.with_ymd_and_hms(2025, 10, 2, 1, 0, 0).unwrap();
+
+        assert_eq!(AccessDate::from(morning), AccessDate::from(evening));
+        assert!(AccessDate::from(evening) < AccessDate::from(next_day));
+    }
+
+    #[test]
+    fn test_access_date_serde_round_trip() {
+        let accessed = AccessDate::from(NaiveDate::from_ymd_opt(2014, 4, 16).unwrap());
+
+        let json = serde_json::to_string(&accessed).unwrap();
+
+        assert_eq!(json, r#"{"accessed":"2014-04-16"}"#);
+        assert_eq!(serde_json::from_str::<AccessDate>(&json).unwrap(), accessed);
+    }
+
+    #[test]
+    fn test_date_formatter() {
+        let published = PublishDate::from_year_month_day(2023, Month::March, 5).unwrap();
+        let accessed = AccessDate::from(NaiveDate::from_ymd_opt(2014, 4, 16).unwrap());
+        let notes: Vec<String> = CitationStyle::ALL
+            .iter()
+            .map(|style| {
+                let dates = DateFormatter::for_style(*style);
+                format!(
+                    "{} / {}",
+                    dates.published(&published),
+                    dates.access_note(&accessed)
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            notes,
+            [
+                "2023, March 5 / Retrieved April 16, 2014",
+                "Mar. 5, 2023 / Accessed: Apr. 16, 2014",
+                "2023 / [viewed 16 April 2014]",
+                "2023 Mar 5 / [cited 2014 Apr 16]",
+                "March 5, 2023 / Accessed April 16, 2014",
+                "2023 / (accessed 2014-04-16)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_access_date_deserializes_legacy_timestamp() {
+        let legacy = r#"{"accessed":"2014-04-16T23:00:00Z"}"#;
+
+        assert_eq!(
+            serde_json::from_str::<AccessDate>(legacy).unwrap().date(),
+            NaiveDate::from_ymd_opt(2014, 4, 16).unwrap()
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::api::style::CitationStyle;
+
 #[derive(Error, Debug)]
 pub enum NameError {
     #[error("Empty string provided for name")]
@@ -7,6 +9,7 @@ pub enum NameError {
 }
 
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum CitationError {
     #[error("Invalid citation format: {0}")]
     InvalidFormat(String),
@@ -14,4 +17,24 @@ pub enum CitationError {
     MissingField(String),
     #[error("Parsing error: {0}")]
     ParseError(String),
+    #[error("No citation with ID '{0}'")]
+    UnknownId(String),
+    #[error("{media_type} citations can't be formatted in {}", .style.name())]
+    UnsupportedStyle {
+        media_type: String,
+        style: CitationStyle,
+    },
+}
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum StorageError {
+    #[error("Could not read or write bibliography file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid bibliography file: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid bibliography log (line {line}): {message}")]
+    Log { line: usize, message: String },
+    #[error("{0} was changed by another writer")]
+    ConcurrentWrite(String),
 }
@@ -0,0 +1,159 @@
+use std::mem;
+
+use serde::{Deserialize, Serialize};
+
+use crate::import::identifier::Identifier;
+
+/// Every known identifier of a work (DOI, ISBN, arXiv ID, PMID, URL), at
+/// most one of each kind.
+///
+/// Media types keep the identifiers that their formatting needs (e.g. a
+/// journal article's DOI) in dedicated fields; this collection holds the
+/// rest, so that importers don't have to throw them away.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Identifiers(Vec<Identifier>);
+
+impl Identifiers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an identifier, replacing (and returning) any of the same kind.
+    pub fn insert(&mut self, identifier: Identifier) -> Option<Identifier> {
+        match self
+            .0
+            .iter_mut()
+            .find(|existing| mem::discriminant(*existing) == mem::discriminant(&identifier))
+        {
+            Some(existing) => Some(mem::replace(existing, identifier)),
+            None => {
+                self.0.push(identifier);
+                None
+            }
+        }
+    }
+
+    /// Builder-style [`Identifiers::insert`]
+    pub fn with(mut self, identifier: Identifier) -> Self {
+        self.insert(identifier);
+        self
+    }
+
+    pub fn doi(&self) -> Option<&str> {
+        self.find(|identifier| match identifier {
+            Identifier::Doi(doi) => Some(doi),
+            _ => None,
+        })
+    }
+
+    pub fn isbn(&self) -> Option<&str> {
+        self.find(|identifier| match identifier {
+            Identifier::Isbn(isbn) => Some(isbn),
+            _ => None,
+        })
+    }
+
+    pub fn arxiv(&self) -> Option<&str> {
+        self.find(|identifier| match identifier {
+            Identifier::ArXiv(arxiv_id) => Some(arxiv_id),
+            _ => None,
+        })
+    }
+
+    pub fn pmid(&self) -> Option<&str> {
+        self.find(|identifier| match identifier {
+            Identifier::Pmid(pmid) => Some(pmid),
+            _ => None,
+        })
+    }
+
+    /// The URL, whether of a Wikipedia article or any other page
+    pub fn url(&self) -> Option<&str> {
+        self.find(|identifier| match identifier {
+            Identifier::Url(url) | Identifier::Wikipedia(url) => Some(url),
+            _ => None,
+        })
+    }
+
+    fn find<'a>(&'a self, value: impl Fn(&'a Identifier) -> Option<&'a String>) -> Option<&'a str> {
+        self.0.iter().find_map(value).map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Identifier> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Put the identifiers in a fixed order: by kind, then by value
+    pub fn sort(&mut self) {
+        self.0.sort();
+    }
+}
+
+impl FromIterator<Identifier> for Identifiers {
+    fn from_iter<I: IntoIterator<Item = Identifier>>(iter: I) -> Self {
+        let mut identifiers = Self::new();
+        for identifier in iter {
+            identifiers.insert(identifier);
+        }
+        identifiers
+    }
+}
+
+impl<'a> IntoIterator for &'a Identifiers {
+    type Item = &'a Identifier;
+    type IntoIter = std::slice::Iter<'a, Identifier>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{api::identifiers::Identifiers, import::identifier::Identifier};
+
+    #[test]
+    fn test_insert_replaces_same_kind() {
+        let mut identifiers = Identifiers::new()
+            .with(Identifier::Doi("10.1000/a".to_string()))
+            .with(Identifier::Pmid("42".to_string()));
+
+        let replaced = identifiers.insert(Identifier::Doi("10.1000/b".to_string()));
+
+        assert_eq!(replaced, Some(Identifier::Doi("10.1000/a".to_string())));
+        assert_eq!(identifiers.len(), 2);
+        assert_eq!(identifiers.doi(), Some("10.1000/b"));
+        assert_eq!(identifiers.pmid(), Some("42"));
+        assert_eq!(identifiers.isbn(), None);
+    }
+
+    #[test]
+    fn test_serialization() {
+        let identifiers: Identifiers = [
+            Identifier::ArXiv("1706.03762".to_string()),
+            Identifier::Url("https://example.com".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let json = serde_json::to_string(&identifiers).unwrap();
+
+        assert_eq!(
+            json,
+            r#"[{"ArXiv":"1706.03762"},{"Url":"https://example.com"}]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Identifiers>(&json).unwrap(),
+            identifiers
+        );
+    }
+}
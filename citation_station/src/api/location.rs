@@ -1,8 +1,444 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+use crate::api::style::CitationStyle;
+
+/// Where something was published or took place.
+///
+/// IEEE writes "Austin, TX, USA", the older numeric styles write
+/// "Cambridge, MA" for US locations, and APA 7 gives locations only for
+/// events such as conferences.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct LocationData {
     pub city: String,
+    /// State, province or region, e.g. "MA"
     pub state: Option<String>,
+    /// ISO 3166-1 alpha-2 code when the country is known, e.g. "US",
+    /// otherwise the name as entered
     pub country: String,
 }
+
+/// A country and the names styles use for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code, e.g. "GB"
+    pub code: &'static str,
+    /// ISO 3166-1 alpha-3 code, e.g. "GBR"
+    pub alpha3: &'static str,
+    /// Common English name, e.g. "United Kingdom"
+    pub name: &'static str,
+    /// IEEE's name, e.g. "U.K."
+    pub ieee_name: &'static str,
+}
+
+const fn country(
+    code: &'static str,
+    alpha3: &'static str,
+    name: &'static str,
+    ieee_name: &'static str,
+) -> Country {
+    Country {
+        code,
+        alpha3,
+        name,
+        ieee_name,
+    }
+}
+
+/// Countries recognized when normalizing locations, sorted by code
+pub const COUNTRIES: &[Country] = &[
+    country("AE", "ARE", "United Arab Emirates", "UAE"),
+    country("AT", "AUT", "Austria", "Austria"),
+    country("AU", "AUS", "Australia", "Australia"),
+    country("BE", "BEL", "Belgium", "Belgium"),
+    country("BR", "BRA", "Brazil", "Brazil"),
+    country("CA", "CAN", "Canada", "Canada"),
+    country("CH", "CHE", "Switzerland", "Switzerland"),
+    country("CN", "CHN", "China", "China"),
+    country("DE", "DEU", "Germany", "Germany"),
+    country("DK", "DNK", "Denmark", "Denmark"),
+    country("ES", "ESP", "Spain", "Spain"),
+    country("FI", "FIN", "Finland", "Finland"),
+    country("FR", "FRA", "France", "France"),
+    country("GB", "GBR", "United Kingdom", "U.K."),
+    country("GR", "GRC", "Greece", "Greece"),
+    country("HK", "HKG", "Hong Kong", "Hong Kong"),
+    country("IE", "IRL", "Ireland", "Ireland"),
+    country("IL", "ISR", "Israel", "Israel"),
+    country("IN", "IND", "India", "India"),
+    country("IT", "ITA", "Italy", "Italy"),
+    country("JP", "JPN", "Japan", "Japan"),
+    country("KR", "KOR", "South Korea", "South Korea"),
+    country("MX", "MEX", "Mexico", "Mexico"),
+    country("NL", "NLD", "Netherlands", "The Netherlands"),
+    country("NO", "NOR", "Norway", "Norway"),
+    country("NZ", "NZL", "New Zealand", "New Zealand"),
+    country("PL", "POL", "Poland", "Poland"),
+    country("PT", "PRT", "Portugal", "Portugal"),
+    country("RU", "RUS", "Russia", "Russia"),
+    country("SE", "SWE", "Sweden", "Sweden"),
+    country("SG", "SGP", "Singapore", "Singapore"),
+    country("TW", "TWN", "Taiwan", "Taiwan"),
+    country("US", "USA", "United States", "USA"),
+    country("ZA", "ZAF", "South Africa", "South Africa"),
+];
+
+/// Other names found in imported data, and the code they stand for
+// cSpell: disable
+const COUNTRY_ALIASES: &[(&str, &str)] = &[
+    ("America", "US"),
+    ("Deutschland", "DE"),
+    ("England", "GB"),
+    ("Great Britain", "GB"),
+    ("Holland", "NL"),
+    ("Korea", "KR"),
+    ("Northern Ireland", "GB"),
+    ("P. R. China", "CN"),
+    ("P.R. China", "CN"),
+    ("People's Republic of China", "CN"),
+    ("Republic of Korea", "KR"),
+    ("Russian Federation", "RU"),
+    ("Scotland", "GB"),
+    ("The Netherlands", "NL"),
+    ("U.S.", "US"),
+    ("U.S.A.", "US"),
+    ("UK", "GB"),
+    ("United States of America", "US"),
+    ("Wales", "GB"),
+];
+
+/// US states, the District of Columbia and Puerto Rico, by postal code
+pub const US_STATES: &[(&str, &str)] = &[
+    ("AK", "Alaska"),
+    ("AL", "Alabama"),
+    ("AR", "Arkansas"),
+    ("AZ", "Arizona"),
+    ("CA", "California"),
+    ("CO", "Colorado"),
+    ("CT", "Connecticut"),
+    ("DC", "District of Columbia"),
+    ("DE", "Delaware"),
+    ("FL", "Florida"),
+    ("GA", "Georgia"),
+    ("HI", "Hawaii"),
+    ("IA", "Iowa"),
+    ("ID", "Idaho"),
+    ("IL", "Illinois"),
+    ("IN", "Indiana"),
+    ("KS", "Kansas"),
+    ("KY", "Kentucky"),
+    ("LA", "Louisiana"),
+    ("MA", "Massachusetts"),
+    ("MD", "Maryland"),
+    ("ME", "Maine"),
+    ("MI", "Michigan"),
+    ("MN", "Minnesota"),
+    ("MO", "Missouri"),
+    ("MS", "Mississippi"),
+    ("MT", "Montana"),
+    ("NC", "North Carolina"),
+    ("ND", "North Dakota"),
+    ("NE", "Nebraska"),
+    ("NH", "New Hampshire"),
+    ("NJ", "New Jersey"),
+    ("NM", "New Mexico"),
+    ("NV", "Nevada"),
+    ("NY", "New York"),
+    ("OH", "Ohio"),
+    ("OK", "Oklahoma"),
+    ("OR", "Oregon"),
+    ("PA", "Pennsylvania"),
+    ("PR", "Puerto Rico"),
+    ("RI", "Rhode Island"),
+    ("SC", "South Carolina"),
+    ("SD", "South Dakota"),
+    ("TN", "Tennessee"),
+    ("TX", "Texas"),
+    ("UT", "Utah"),
+    ("VA", "Virginia"),
+    ("VT", "Vermont"),
+    ("WA", "Washington"),
+    ("WI", "Wisconsin"),
+    ("WV", "West Virginia"),
+    ("WY", "Wyoming"),
+];
+// cSpell: enable
+
+/// The country with `name` as its ISO 3166 code, one of its names or a
+/// common alias such as "UK", ignoring case
+pub fn find_country(name: &str) -> Option<&'static Country> {
+    let name = name.trim();
+    let code = COUNTRY_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, code)| *code);
+    COUNTRIES.iter().find(|country| {
+        [
+            country.code,
+            country.alpha3,
+            country.name,
+            country.ieee_name,
+        ]
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(code))
+    })
+}
+
+/// The postal code of a US state given its name or code, e.g.
+/// "Massachusetts" gives "MA"
+pub fn us_state_code(state: &str) -> Option<&'static str> {
+    let state = state.trim().trim_end_matches('.');
+    US_STATES
+        .iter()
+        .find(|(code, name)| code.eq_ignore_ascii_case(state) || name.eq_ignore_ascii_case(state))
+        .map(|(code, _)| *code)
+}
+
+/// The name of a US state given its postal code, e.g. "MA" gives
+/// "Massachusetts"
+pub fn us_state_name(code: &str) -> Option<&'static str> {
+    US_STATES
+        .iter()
+        .find(|(state, _)| state.eq_ignore_ascii_case(code.trim()))
+        .map(|(_, name)| *name)
+}
+
+impl LocationData {
+    /// A location with its country normalized to an ISO 3166 code where
+    /// the country is recognized, e.g. "United States" becomes "US"
+    pub fn new(city: impl Into<String>, country: impl Into<String>) -> Self {
+        let country = country.into();
+        LocationData {
+            city: city.into().trim().to_string(),
+            state: None,
+            country: match find_country(&country) {
+                Some(known) => known.code.to_string(),
+                None => country.trim().to_string(),
+            },
+        }
+    }
+
+    /// Sets the state, using the postal code for US states, e.g.
+    /// "Massachusetts" becomes "MA"
+    pub fn with_state(self, state: impl Into<String>) -> Self {
+        let mut state = state.into().trim().to_string();
+        if self.is_us()
+            && let Some(code) = us_state_code(&state)
+        {
+            state = code.to_string();
+        }
+        LocationData {
+            state: (!state.is_empty()).then_some(state),
+            ..self
+        }
+    }
+
+    /// Reads a location string such as BibTeX's `address` or RIS's `CY`,
+    /// e.g. "Cambridge, Massachusetts, United States" or "Cambridge, MA".
+    /// A US state is enough to imply the country, and takes precedence
+    /// over a country code that reads the same. Returns `None` for a lone
+    /// city, whose country is unknown.
+    pub fn parse(text: &str) -> Option<Self> {
+        let parts: Vec<&str> = text
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect();
+        let (&last, rest) = parts.split_last()?;
+        let (&city, middle) = rest.split_first()?;
+
+        if middle.is_empty() {
+            // "Bloomington, IN" is Indiana rather than India
+            return Some(match us_state_code(last) {
+                Some(state) => LocationData::new(city, "US").with_state(state),
+                None => LocationData::new(city, last),
+            });
+        }
+        Some(LocationData::new(city, last).with_state(middle.join(", ")))
+    }
+
+    /// The recognized country, if any
+    pub fn find_country(&self) -> Option<&'static Country> {
+        find_country(&self.country)
+    }
+
+    fn is_us(&self) -> bool {
+        self.find_country()
+            .is_some_and(|country| country.code == "US")
+    }
+
+    fn join(&self, country: Option<&str>) -> String {
+        let mut parts = vec![self.city.as_str()];
+        if let Some(state) = &self.state {
+            parts.push(state);
+        }
+        parts.extend(country);
+        parts.join(", ")
+    }
+
+    /// The location as a place of publication in `style`, or `None` for
+    /// APA, whose 7th edition dropped publisher locations
+    pub fn format(&self, style: CitationStyle) -> Option<String> {
+        let country = self.find_country();
+        match style {
+            CitationStyle::Apa => None,
+            CitationStyle::Ieee => Some(self.join(Some(
+                country.map_or(self.country.as_str(), |country| country.ieee_name),
+            ))),
+            CitationStyle::Iso690
+            | CitationStyle::Vancouver
+            | CitationStyle::Ama
+            | CitationStyle::Acs => {
+                // A US state is enough on its own
+                if self.is_us() && self.state.is_some() {
+                    Some(self.join(None))
+                } else {
+                    Some(self.join(Some(
+                        country.map_or(self.country.as_str(), |country| country.name),
+                    )))
+                }
+            }
+        }
+    }
+
+    /// The location of an event such as a conference in `style`. APA
+    /// still gives these in full, e.g. "Chicago, IL, United States".
+    pub fn format_event(&self, style: CitationStyle) -> String {
+        match self.format(style) {
+            Some(location) => location,
+            None => self.to_string(),
+        }
+    }
+}
+
+/// City, state and the country's English name
+impl fmt::Display for LocationData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let country = self
+            .find_country()
+            .map_or(self.country.as_str(), |country| country.name);
+        f.write_str(&self.join(Some(country)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{
+        location::{
+            COUNTRIES, LocationData, US_STATES, find_country, us_state_code, us_state_name,
+        },
+        style::CitationStyle,
+    };
+
+    #[test]
+    fn test_tables_are_sorted() {
+        assert!(US_STATES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!(find_country("UK").map(|country| country.code), Some("GB"));
+        assert_eq!(
+            find_country("United States of America").map(|country| country.code),
+            Some("US")
+        );
+        assert_eq!(us_state_code("massachusetts"), Some("MA"));
+        assert_eq!(us_state_code("Mass."), None);
+        assert_eq!(us_state_name("tx"), Some("Texas"));
+        assert_eq!(
+            LocationData::new("Boston", "USA")
+                .with_state("Massachusetts")
+                .state
+                .as_deref(),
+            Some("MA")
+        );
+        // Not a US state outside the US
+        assert_eq!(
+            LocationData::new("Perth", "Australia")
+                .with_state("WA")
+                .state
+                .as_deref(),
+            Some("WA")
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = LocationData::new("Cambridge", "US").with_state("MA");
+        assert_eq!(
+            LocationData::parse("Cambridge, Massachusetts, United States"),
+            Some(expected.clone())
+        );
+        assert_eq!(LocationData::parse("Cambridge, MA"), Some(expected.clone()));
+        assert_eq!(LocationData::parse("Cambridge, MA, USA"), Some(expected));
+        assert_eq!(
+            LocationData::parse("Bloomington, IN"),
+            Some(LocationData::new("Bloomington", "US").with_state("IN"))
+        );
+        assert_eq!(
+            LocationData::parse("London, England"),
+            Some(LocationData::new("London", "GB"))
+        );
+        // Countries missing from the table are kept as entered
+        assert_eq!(
+            LocationData::parse("Reykjavik, Iceland"),
+            Some(LocationData::new("Reykjavik", "Iceland"))
+        );
+        assert_eq!(LocationData::parse("Oxford"), None);
+        assert_eq!(LocationData::parse(" , "), None);
+    }
+
+    #[test]
+    fn test_country_normalization() {
+        assert_eq!(LocationData::new("Austin", "United States").country, "US");
+        assert_eq!(LocationData::new("Austin", "usa").country, "US");
+        assert_eq!(LocationData::new("Oxford", "GBR").country, "GB");
+        assert_eq!(
+            LocationData::new("Atlantis", " Atlantis ").country,
+            "Atlantis"
+        );
+        assert_eq!(find_country("U.K.").map(|country| country.code), Some("GB"));
+        assert!(COUNTRIES.windows(2).all(|pair| pair[0].code < pair[1].code));
+    }
+
+    #[test]
+    fn test_format() {
+        let austin = LocationData::new("Austin", "United States").with_state("TX");
+        assert_eq!(
+            austin.format(CitationStyle::Ieee).as_deref(),
+            Some("Austin, TX, USA")
+        );
+        assert_eq!(austin.format(CitationStyle::Apa), None);
+        assert_eq!(
+            austin.format(CitationStyle::Vancouver).as_deref(),
+            Some("Austin, TX")
+        );
+        assert_eq!(
+            austin.format_event(CitationStyle::Apa),
+            "Austin, TX, United States"
+        );
+
+        let london = LocationData::new("London", "GB");
+        assert_eq!(
+            london.format(CitationStyle::Ieee).as_deref(),
+            Some("London, U.K.")
+        );
+        assert_eq!(
+            london.format(CitationStyle::Ama).as_deref(),
+            Some("London, United Kingdom")
+        );
+
+        // Entered before normalization
+        let legacy = LocationData {
+            city: "Portland".to_string(),
+            state: Some("OR".to_string()),
+            country: "USA".to_string(),
+        };
+        assert_eq!(
+            legacy.format(CitationStyle::Ieee).as_deref(),
+            Some("Portland, OR, USA")
+        );
+    }
+}
@@ -2,23 +2,33 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    api::{
-        author::GenericAuthor,
-        citation::{ApaFormatting, IeeeFormatting},
-        media::{common::CommonCitationData, version::GenericMediaVersion},
-        page_range::PageRange,
+use crate::api::{
+    author::{
+        ACS_AUTHOR_LIST, AMA_AUTHOR_LIST, APA_AUTHOR_LIST, AuthorListRules, GenericAuthor,
+        IEEE_AUTHOR_LIST, ISO_690_AUTHOR_LIST, PersonName, VANCOUVER_AUTHOR_LIST, punctuate,
+    },
+    citation::{
+        AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+        VancouverFormatting,
     },
-    unicode::{LEFT_QUOTE, RIGHT_QUOTE},
+    date::IEEE_DATES,
+    media::{apa_lead, common::CommonCitationData, version::GenericMediaVersion},
+    page_range::PageRange,
+    style::{CitationStyle, FormatOptions},
+    title::Title,
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Book {
     pub common_data: CommonCitationData,
     /// Author
     pub author: GenericAuthor,
+    /// Contributors who assisted the author, credited as "with"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub with_authors: Vec<PersonName>,
     /// Book title
-    pub title: String,
+    pub title: Title,
     /// Chapter
     pub chapter: Option<String>,
     /// Edition
@@ -29,33 +39,60 @@ pub struct Book {
     pub pages: Option<PageRange>,
 }
 
+impl Book {
+    /// `authors` followed by the contributors who assisted them, e.g.
+    /// "Smith, J., with Jones, A."
+    fn with_assistants(
+        &self,
+        authors: String,
+        rules: &AuthorListRules,
+        name: fn(&PersonName) -> String,
+    ) -> String {
+        let assistants: Vec<String> = self.with_authors.iter().map(name).collect();
+        match rules.join(&assistants) {
+            Some(assistants) => format!("{}, with {}", authors, assistants),
+            None => authors,
+        }
+    }
+}
+
 impl IeeeFormatting for Book {
-    fn citation_string(&self) -> String {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
         let mut parts: Vec<String> = Vec::new();
 
-        if let Some(authors) = &self.author.as_ieee_string() {
-            parts.push(format!("{},", authors));
+        let titles = options.titles();
+        let title = self.title.as_ieee_string();
+
+        if let Some(authors) = self.author.as_ieee_string_with(options) {
+            let authors =
+                self.with_assistants(authors, &IEEE_AUTHOR_LIST, PersonName::as_ieee_string);
+            parts.push(IEEE_AUTHOR_LIST.terminate(&authors));
         }
 
         parts.push(match (&self.chapter, &self.version) {
-            (None, None) => format!("{}.", self.title),
-            (None, Some(version)) => format!("{}, {}", self.title, version.as_ieee_string()),
+            (None, None) => titles.italic(&title, "."),
+            (None, Some(version)) => {
+                format!(
+                    "{} {}",
+                    titles.italic(&title, ","),
+                    version.as_ieee_string()
+                )
+            }
             (Some(chapter), None) => format!(
-                "{}{},{} in {}.",
-                LEFT_QUOTE, chapter, RIGHT_QUOTE, self.title
+                "{} in {}",
+                titles.quoted(chapter, ","),
+                titles.italic(&title, ".")
             ),
             (Some(chapter), Some(version)) => format!(
-                "{}{},{} in {}, {}.",
-                LEFT_QUOTE,
-                chapter,
-                RIGHT_QUOTE,
-                self.title,
+                "{} in {} {}.",
+                titles.quoted(chapter, ","),
+                titles.italic(&title, ","),
                 version.as_ieee_string()
             ),
         });
 
         if let Some(published) = &self.common_data.published {
-            parts.push(format!("{}.", published.fmt_for_ieee_citation()));
+            parts.push(format!("{}.", IEEE_DATES.published(published)));
         }
 
         parts.join(" ")
@@ -63,31 +100,188 @@ impl IeeeFormatting for Book {
 }
 
 impl ApaFormatting for Book {
-    fn citation_string(&self) -> String {
-        let authors_editors = if let Some(authors) = &self.author.as_apa_string() {
-            format!("{} ", authors)
-        } else {
-            "".to_string()
-        };
-        let published_title_version = match (&self.common_data.published, &self.version) {
-            (None, None) => format!("{}.", self.title),
-            (None, Some(version)) => {
-                format!("{} {}.", self.title, version.as_apa_string())
-            }
-            (Some(published), None) => {
-                format!("({}). {}.", published.year(), self.title)
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let authors = self.author.as_apa_string_with(options).map(|authors| {
+            let authors =
+                self.with_assistants(authors, &APA_AUTHOR_LIST, PersonName::as_apa_string);
+            APA_AUTHOR_LIST.terminate(&authors)
+        });
+        let date = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| published.year().to_string());
+        let title = (!self.title.is_empty()).then(|| {
+            let title = self.title.as_apa_string();
+            match &self.version {
+                Some(version) => {
+                    format!("{} {}.", titles.italic(&title, ""), version.as_apa_string())
+                }
+                None => titles.italic(&title, "."),
             }
-            (Some(published), Some(version)) => {
-                format!(
-                    "({}). {}. {}.",
-                    published.year(),
-                    self.title,
-                    version.as_apa_string()
-                )
+        });
+
+        apa_lead(authors, date, title, "Untitled book").join(" ")
+    }
+}
+
+impl Iso690Formatting for Book {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Iso690, options) {
+            let authors =
+                self.with_assistants(authors, &ISO_690_AUTHOR_LIST, PersonName::as_iso690_string);
+            parts.push(ISO_690_AUTHOR_LIST.terminate(&authors));
+        }
+        match &self.chapter {
+            Some(chapter) => parts.push(format!(
+                "{} In: {}",
+                titles.plain(chapter, "."),
+                titles.italic(&self.title.as_apa_string(), ".")
+            )),
+            None => parts.push(titles.italic(&self.title.as_apa_string(), ".")),
+        }
+        if let Some(version) = &self.version {
+            parts.push(punctuate(&version.as_ieee_string(), "."));
+        }
+        if let Some(published) = &self.common_data.published {
+            parts.push(format!("{}.", published.year()));
+        }
+        if let Some(pages) = &self.pages {
+            parts.push(format!("{}.", pages.as_ieee_string()));
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("Available from: https://doi.org/{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for Book {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self
+            .author
+            .as_string_with(CitationStyle::Vancouver, options)
+        {
+            let authors = self.with_assistants(
+                authors,
+                &VANCOUVER_AUTHOR_LIST,
+                PersonName::as_vancouver_string,
+            );
+            parts.push(VANCOUVER_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(titles.plain(&self.title.as_apa_string(), "."));
+        if let Some(version) = &self.version {
+            parts.push(punctuate(&version.as_ieee_string(), "."));
+        }
+        if let Some(published) = &self.common_data.published {
+            parts.push(format!("{}.", published.year()));
+        }
+        match (&self.chapter, &self.pages) {
+            (Some(chapter), Some(pages)) => parts.push(format!(
+                "{}; p. {}.",
+                titles.plain(chapter, ""),
+                pages.as_vancouver_string()
+            )),
+            (Some(chapter), None) => parts.push(titles.plain(chapter, ".")),
+            (None, Some(pages)) => parts.push(format!("p. {}.", pages.as_vancouver_string())),
+            (None, None) => {}
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("doi:{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for Book {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Ama, options) {
+            let authors =
+                self.with_assistants(authors, &AMA_AUTHOR_LIST, PersonName::as_vancouver_string);
+            parts.push(AMA_AUTHOR_LIST.terminate(&authors));
+        }
+        match &self.chapter {
+            Some(chapter) => parts.push(format!(
+                "{} In: {}",
+                titles.plain(chapter, "."),
+                titles.italic(&self.title.as_apa_string(), ".")
+            )),
+            None => parts.push(titles.italic(&self.title.as_apa_string(), ".")),
+        }
+        if let Some(version) = &self.version {
+            parts.push(punctuate(&version.as_ieee_string(), "."));
+        }
+        // e.g. "2023:100-110."
+        let mut published = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| published.year().to_string())
+            .unwrap_or_default();
+        if let Some(pages) = &self.pages {
+            published.push(':');
+            published.push_str(&pages.as_ama_string());
+        }
+        if !published.is_empty() {
+            parts.push(format!("{}.", published));
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("doi:{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AcsFormatting for Book {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Acs, options) {
+            let authors =
+                self.with_assistants(authors, &ACS_AUTHOR_LIST, PersonName::as_acs_string);
+            parts.push(ACS_AUTHOR_LIST.terminate(&authors));
+        }
+        if let Some(chapter) = &self.chapter {
+            parts.push(format!("{} In", titles.plain(chapter, ".")));
+        }
+        // e.g. "A Great Book, 2nd ed.; 2023; pp 100–110."
+        let mut details = vec![match &self.version {
+            Some(version) => format!(
+                "{} {}",
+                titles.italic(&self.title.as_apa_string(), ","),
+                version.as_ieee_string()
+            ),
+            None => titles.italic(&self.title.as_apa_string(), ""),
+        }];
+        if let Some(published) = &self.common_data.published {
+            details.push(published.year().to_string());
+        }
+        if let Some(pages) = &self.pages {
+            match pages.start == pages.end {
+                true => details.push(format!("p {}", pages.as_apa_string())),
+                false => details.push(format!("pp {}", pages.as_apa_string())),
             }
-        };
+        }
+        parts.push(punctuate(&details.join("; "), "."));
+        if let Some(doi) = &self.doi {
+            parts.push(format!("DOI: {}", doi));
+        }
 
-        format!("{}{}", authors_editors, published_title_version)
+        parts.join(" ")
     }
 }
 
@@ -99,20 +293,23 @@ mod tests {
         author::{GenericAuthor, PersonName},
         citation::Citation,
         date::PublishDate,
-        media::{book::Book, common::CommonCitationData},
+        media::{book::Book, common::CommonCitationData, version::GenericMediaVersion},
+        page_range::PageRange,
+        style::{CitationStyle, FormatOptions, Markup},
     };
 
     #[test]
     fn test_book_apa_formatting_minimal() {
         let citation = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "test".to_string(),
                 published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
             },
             author: GenericAuthor::Persons {
                 persons: vec![PersonName::from_first_last("J", "Smith").unwrap()],
             },
-            title: "A Great Paper".to_string(),
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -123,12 +320,117 @@ mod tests {
         assert_eq!(formatted, "Smith, J. (2023). A Great Paper.");
     }
 
+    #[test]
+    fn test_book_apa_missing_elements() {
+        let book = Book {
+            common_data: CommonCitationData::new("test"),
+            author: GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("J", "Smith").unwrap()],
+            },
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        };
+        assert_eq!(
+            Citation::Book(book.clone()).format_apa(),
+            "Smith, J. (n.d.). A Great Paper."
+        );
+
+        let untitled = Book {
+            title: "".into(),
+            ..book.clone()
+        };
+        assert_eq!(
+            Citation::Book(untitled).format_apa(),
+            "Smith, J. (n.d.). [Untitled book]."
+        );
+
+        let anonymous = Book {
+            author: GenericAuthor::Persons {
+                persons: Vec::new(),
+            },
+            ..book
+        };
+        assert_eq!(
+            Citation::Book(anonymous).format_apa(),
+            "A Great Paper. (n.d.)."
+        );
+    }
+
+    #[test]
+    fn test_book_iso690_and_vancouver_formatting() {
+        let citation = Citation::Book(Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
+            },
+            author: GenericAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_first_last("Hugo", "Fuentes").unwrap(),
+                ],
+            },
+            with_authors: Vec::new(),
+            title: "A Great Book".into(),
+            doi: Some("10.1000/xyz".to_string()),
+            pages: None,
+            chapter: None,
+            version: None,
+        });
+
+        assert_eq!(
+            citation.format_iso690(),
+            "SMITH, Jane and FUENTES, Hugo. A Great Book. 2023. \
+             Available from: https://doi.org/10.1000/xyz"
+        );
+        assert_eq!(
+            citation.format_vancouver(),
+            "Smith J, Fuentes H. A Great Book. 2023. doi:10.1000/xyz"
+        );
+    }
+
+    #[test]
+    fn test_book_ama_and_acs_formatting() {
+        let citation = Citation::Book(Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
+            },
+            author: GenericAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_middle_last("Jane", "Quinn", "Smith").unwrap(),
+                ],
+            },
+            with_authors: Vec::new(),
+            title: "A Great Book".into(),
+            doi: None,
+            pages: Some(PageRange {
+                start: 100,
+                end: 110,
+            }),
+            chapter: Some("A Chapter".to_string()),
+            version: Some(GenericMediaVersion::Edition { number: 2 }),
+        });
+
+        assert_eq!(
+            citation.format_ama(),
+            "Smith JQ. A Chapter. In: A Great Book. 2nd ed. 2023:100-110."
+        );
+        assert_eq!(
+            citation.format_acs(),
+            "Smith, J. Q. A Chapter. In A Great Book, 2nd ed.; 2023; pp 100\u{2013}110."
+        );
+    }
+
     #[test]
     fn test_book_apa_formatting_two_authors() {
         let citation = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "test".to_string(),
                 published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
             },
             author: GenericAuthor::Persons {
                 persons: vec![
@@ -136,7 +438,8 @@ mod tests {
                     PersonName::from_first_last("Humberto", "Fuentes").unwrap(),
                 ],
             },
-            title: "A Great Paper".to_string(),
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -151,8 +454,8 @@ mod tests {
     fn test_book_apa_formatting_three_authors() {
         let citation = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "test".to_string(),
                 published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
             },
             author: GenericAuthor::Persons {
                 persons: vec![
@@ -161,7 +464,8 @@ mod tests {
                     PersonName::from_first_last("Isabel", "Popov").unwrap(),
                 ],
             },
-            title: "A Great Paper".to_string(),
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -169,20 +473,24 @@ mod tests {
         });
 
         let formatted = citation.format_apa();
-        assert_eq!(formatted, "Smith, J. et al. (2023). A Great Paper.");
+        assert_eq!(
+            formatted,
+            "Smith, J., Fuentes, H., & Popov, I. (2023). A Great Paper."
+        );
     }
 
     #[test]
     fn test_book_ieee_formatting_minimal() {
         let citation = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "test".to_string(),
                 published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
             },
             author: GenericAuthor::Persons {
                 persons: vec![PersonName::from_first_last("J", "Smith").unwrap()],
             },
-            title: "A Great Paper".to_string(),
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -197,8 +505,8 @@ mod tests {
     fn test_book_ieee_formatting_two_authors() {
         let citation = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "test".to_string(),
                 published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
             },
             author: GenericAuthor::Persons {
                 persons: vec![
@@ -206,7 +514,8 @@ mod tests {
                     PersonName::from_first_last("Humberto", "Fuentes").unwrap(),
                 ],
             },
-            title: "A Great Paper".to_string(),
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -221,8 +530,8 @@ mod tests {
     fn test_book_ieee_formatting_three_authors() {
         let citation = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "test".to_string(),
                 published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
             },
             author: GenericAuthor::Persons {
                 persons: vec![
@@ -231,7 +540,8 @@ mod tests {
                     PersonName::from_first_last("Isabel", "Popov").unwrap(),
                 ],
             },
-            title: "A Great Paper".to_string(),
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -249,8 +559,8 @@ mod tests {
     fn test_book_ieee_formatting_three_authors_full_date() {
         let citation = Citation::Book(Book {
             common_data: CommonCitationData {
-                id: "test".to_string(),
                 published: Some(PublishDate::from_year_month_day(2023, Month::January, 1).unwrap()),
+                ..CommonCitationData::new("test")
             },
             author: GenericAuthor::Persons {
                 persons: vec![
@@ -259,7 +569,8 @@ mod tests {
                     PersonName::from_first_last("Isabel", "Popov").unwrap(),
                 ],
             },
-            title: "A Great Paper".to_string(),
+            with_authors: Vec::new(),
+            title: "A Great Paper".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -272,4 +583,67 @@ mod tests {
             "J. Smith, H. Fuentes, and I. Popov, A Great Paper. Jan. 1, 2023."
         );
     }
+
+    #[test]
+    fn test_book_chapter_html_markup() {
+        let citation = Citation::Book(Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2023)),
+                ..CommonCitationData::new("test")
+            },
+            author: GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("J", "Smith").unwrap()],
+            },
+            with_authors: Vec::new(),
+            title: "Rust & Friends".into(),
+            doi: None,
+            pages: None,
+            chapter: Some("Ownership".to_string()),
+            version: None,
+        });
+        let options = FormatOptions {
+            markup: Markup::Html,
+            ..FormatOptions::for_style(CitationStyle::Ieee)
+        };
+
+        assert_eq!(
+            citation.format_with(CitationStyle::Ieee, &options),
+            "J. Smith, \u{201C}Ownership,\u{201D} in <i>Rust &amp; Friends</i>. 2023."
+        );
+        assert_eq!(
+            citation.format_ieee(),
+            "J. Smith, \u{201C}Ownership,\u{201D} in Rust & Friends. 2023."
+        );
+    }
+
+    #[test]
+    fn test_book_with_authors() {
+        let book = Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2019)),
+                ..CommonCitationData::new("memoir")
+            },
+            author: GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            with_authors: vec![PersonName::from_first_last("Alex", "Jones").unwrap()],
+            title: "My Life".into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        };
+        let json = serde_json::to_string(&book).unwrap();
+
+        assert_eq!(
+            Citation::Book(book.clone()).format_apa(),
+            "Smith, J., with Jones, A. (2019). My Life."
+        );
+        assert_eq!(
+            Citation::Book(book.clone()).format_ieee(),
+            "J. Smith, with A. Jones, My Life. 2019."
+        );
+        assert!(json.contains(r#""with_authors":[{"#));
+        assert_eq!(serde_json::from_str::<Book>(&json).unwrap(), book);
+    }
 }
@@ -1,6 +1,12 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::api::date::PublishDate;
+use crate::api::{
+    citation_id::CitationId, date::PublishDate, identifiers::Identifiers, location::LocationData,
+    style::CitationStyle,
+};
 
 /// Data that is shared between all types of sources.
 ///
@@ -10,10 +16,157 @@ use crate::api::date::PublishDate;
 ///
 /// Similarly, different types of media have different conventions
 /// around author attribution and formatting.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CommonCitationData {
     /// Unique identifier for the citation
     pub id: String,
     /// Date published
     pub published: Option<PublishDate>,
+    /// Other entries of the same bibliography that this work references
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cites: Vec<CitationId>,
+    /// Free-form labels for organizing the bibliography; never formatted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Identifiers of the work beyond those the media type stores itself
+    #[serde(default, skip_serializing_if = "Identifiers::is_empty")]
+    pub identifiers: Identifiers,
+    /// When the entry was added to and last changed in a bibliography
+    #[serde(default, skip_serializing_if = "Timestamps::is_empty")]
+    pub timestamps: Timestamps,
+    /// Metadata about the work that citations don't show
+    #[serde(default, skip_serializing_if = "ExtendedMetadata::is_empty")]
+    pub extended: ExtendedMetadata,
+    /// Hand-written formatted entries that replace the formatter's output
+    #[serde(default, skip_serializing_if = "FormatOverrides::is_empty")]
+    pub overrides: FormatOverrides,
+}
+
+impl CommonCitationData {
+    /// Data with the given ID and nothing else: no publication date,
+    /// references, tags, identifiers, timestamps or overrides.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            published: None,
+            cites: Vec::new(),
+            tags: Vec::new(),
+            identifiers: Default::default(),
+            timestamps: Default::default(),
+            extended: Default::default(),
+            overrides: Default::default(),
+        }
+    }
+}
+
+/// Metadata about a work that isn't part of any citation style, but that
+/// funding reports and data availability statements ask for.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExtendedMetadata {
+    /// Bodies that funded the work
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub funders: Vec<Funder>,
+    /// URLs of the licenses the work is published under
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub licenses: Vec<String>,
+    /// Publisher of the work, e.g. from a publisher's ONIX feed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    /// Where the work was published, e.g. "Cambridge, MA" for a book
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place: Option<LocationData>,
+}
+
+impl ExtendedMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.funders.is_empty()
+            && self.licenses.is_empty()
+            && self.publisher.is_none()
+            && self.place.is_none()
+    }
+}
+
+/// A formatted entry written by hand for one style, for sources the
+/// formatter can't get right.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FormatOverride {
+    /// The entry as it should appear, used verbatim
+    pub text: String,
+    /// The citation's content hash when the override was written, to tell
+    /// when the metadata has changed since
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<u64>,
+}
+
+/// The hand-written entries of a citation, at most one per style.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct FormatOverrides(BTreeMap<CitationStyle, FormatOverride>);
+
+impl FormatOverrides {
+    pub fn get(&self, style: CitationStyle) -> Option<&FormatOverride> {
+        self.0.get(&style)
+    }
+
+    /// Set the override for `style`, returning the one it replaces
+    pub fn insert(
+        &mut self,
+        style: CitationStyle,
+        entry: FormatOverride,
+    ) -> Option<FormatOverride> {
+        self.0.insert(style, entry)
+    }
+
+    pub fn remove(&mut self, style: CitationStyle) -> Option<FormatOverride> {
+        self.0.remove(&style)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CitationStyle, &FormatOverride)> {
+        self.0.iter().map(|(style, entry)| (*style, entry))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A body that funded a work, as registered with Crossref
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Funder {
+    pub name: String,
+    /// DOI in the Open Funder Registry, e.g. "10.13039/100000001"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+    /// Grant or award numbers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub awards: Vec<String>,
+}
+
+/// When a bibliography entry was created and last modified. These describe
+/// the entry rather than the cited work, and are kept up to date by the
+/// [`Bibliography`](crate::bibliography::Bibliography) methods that change
+/// entries.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Timestamps {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<DateTime<Utc>>,
+}
+
+impl Timestamps {
+    pub fn is_empty(&self) -> bool {
+        self.created.is_none() && self.modified.is_none()
+    }
+
+    /// When the entry was last created or changed
+    pub fn last_changed(&self) -> Option<DateTime<Utc>> {
+        self.modified.or(self.created)
+    }
 }
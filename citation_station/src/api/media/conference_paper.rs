@@ -1,33 +1,643 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Month, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::api::media::common::CommonCitationData;
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+use crate::{
+    abbrev::ieee_proceedings_venue,
+    api::{
+        author::{
+            APA_AUTHOR_LIST, AuthorListRules, Contributor, IEEE_AUTHOR_LIST, PersonName, Truncation,
+        },
+        citation::{
+            AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+            VancouverFormatting,
+        },
+        conference::ConferenceSeries,
+        date::{PublishDate, VANCOUVER_DATES, ieee_abbreviated_month_name},
+        media::{apa_lead, common::CommonCitationData},
+        style::{CitationStyle, FormatOptions},
+        title::Title,
+        volume::{Issue, Volume, apa_volume_issue, ieee_volume_issue},
+    },
+};
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConferencePaperOnline {
     pub common_data: CommonCitationData,
-    pub title: String,
+    pub title: Title,
     /// Journal or venue name
     pub venue: Option<String>,
     /// Volume number
-    pub volume: Option<String>,
+    pub volume: Option<Volume>,
     /// Issue or number
-    pub number: Option<String>,
+    pub number: Option<Issue>,
     pub conference_name: String,
+    /// Numbering and sponsors, added to the name when formatting
+    #[serde(default, skip_serializing_if = "ConferenceSeries::is_empty")]
+    pub series: ConferenceSeries,
     pub conference_date: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConferenceProceedingsOnline {
     pub common_data: CommonCitationData,
-    pub title: String,
+    pub title: Title,
     /// Journal or venue name
     pub venue: Option<String>,
     /// Volume number
-    pub volume: Option<String>,
+    pub volume: Option<Volume>,
     /// Issue or number
-    pub number: Option<String>,
+    pub number: Option<Issue>,
     pub conference_name: String,
+    /// Numbering and sponsors, added to the name when formatting
+    #[serde(default, skip_serializing_if = "ConferenceSeries::is_empty")]
+    pub series: ConferenceSeries,
     pub conference_date: DateTime<Utc>,
+    /// Editors of the proceedings volume
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub editors: Vec<PersonName>,
+}
+
+/// APA joins editors named initials first without a comma between two,
+/// e.g. "A. Editor & B. Editor"
+const APA_EDITOR_LIST: AuthorListRules = AuthorListRules {
+    separator_with_two: false,
+    truncation: Truncation::None,
+    ..APA_AUTHOR_LIST
+};
+
+/// "Ed." or "Eds."
+fn editor_role(editors: &[PersonName]) -> &'static str {
+    if editors.len() == 1 { "Ed." } else { "Eds." }
+}
+
+fn editor_names(editors: &[PersonName], rules: &AuthorListRules) -> Option<String> {
+    let names: Vec<String> = editors
+        .iter()
+        .map(PersonName::as_initials_first_string)
+        .collect();
+    rules.join(&names)
+}
+
+/// The editors in the author position of `style`, followed by their role,
+/// e.g. "EDITOR, Jane and REVISER, Bob, eds" for ISO 690
+fn editor_list(
+    editors: &[PersonName],
+    style: CitationStyle,
+    options: &FormatOptions,
+) -> Option<String> {
+    let names: Vec<String> = editors
+        .iter()
+        .map(|editor| Contributor::Person(editor.clone()).as_string_with(style, options))
+        .collect();
+    let names = AuthorListRules::for_style(style).join(&names)?;
+    let (one, several) = match style {
+        CitationStyle::Iso690 | CitationStyle::Ama => ("ed.", "eds."),
+        CitationStyle::Vancouver => ("editor", "editors"),
+        _ => ("Ed.", "Eds."),
+    };
+    let role = if editors.len() == 1 { one } else { several };
+    Some(format!("{}, {}", names, role))
+}
+
+/// The details IEEE lists after the title: the "Proc." phrase, editors,
+/// volume and number, and the month and year of the conference
+fn ieee_proceedings_details(
+    conference_name: &str,
+    editors: &[PersonName],
+    volume: Option<&Volume>,
+    number: Option<&Issue>,
+    conference_date: &DateTime<Utc>,
+) -> Vec<String> {
+    let mut details = vec![ieee_proceedings_venue(conference_name)];
+    if let Some(names) = editor_names(editors, &IEEE_AUTHOR_LIST) {
+        details.push(format!("{}, {}", names, editor_role(editors)));
+    }
+    details.extend(ieee_volume_issue(volume, number));
+    match Month::try_from(conference_date.month() as u8) {
+        Ok(month) => details.push(format!(
+            "{} {}",
+            ieee_abbreviated_month_name(&month),
+            conference_date.year()
+        )),
+        Err(_) => details.push(conference_date.year().to_string()),
+    }
+    details
+}
+
+impl IeeeFormatting for ConferencePaperOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let title = options.titles().quoted(&self.title.as_ieee_string(), ",");
+        let details = ieee_proceedings_details(
+            &self.series.full_name(&self.conference_name),
+            &[],
+            self.volume.as_ref(),
+            self.number.as_ref(),
+            &self.conference_date,
+        );
+        format!("{} in {}.", title, details.join(", "))
+    }
+}
+
+/// e.g. *Title*. Proc. 43rd Annu. Int. Symp. Comput. Archit., A. Editor
+/// and B. Editor, Eds., vol. 2, Jun. 2016.
+impl IeeeFormatting for ConferenceProceedingsOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let title = options.titles().italic(&self.title.as_ieee_string(), ".");
+        let details = ieee_proceedings_details(
+            &self.series.full_name(&self.conference_name),
+            &self.editors,
+            self.volume.as_ref(),
+            self.number.as_ref(),
+            &self.conference_date,
+        );
+        format!("{} {}.", title, details.join(", "))
+    }
+}
+
+/// The fields the two kinds of proceedings entries share, borrowed for
+/// formatting
+struct ProceedingsEntry<'a> {
+    /// Whether the title names a paper in the proceedings rather than the
+    /// proceedings volume itself
+    is_paper: bool,
+    common_data: &'a CommonCitationData,
+    title: &'a Title,
+    volume: Option<&'a Volume>,
+    number: Option<&'a Issue>,
+    conference_name: &'a str,
+    series: &'a ConferenceSeries,
+    conference_date: &'a DateTime<Utc>,
+    editors: &'a [PersonName],
+}
+
+impl ProceedingsEntry<'_> {
+    /// The publication year, or the year of the conference
+    fn year(&self) -> i32 {
+        match &self.common_data.published {
+            Some(published) => published.year(),
+            None => self.conference_date.year(),
+        }
+    }
+
+    /// The publication date, or the day of the conference
+    fn published(&self) -> PublishDate {
+        if let Some(published) = &self.common_data.published {
+            return published.clone();
+        }
+        let date = self.conference_date;
+        Month::try_from(date.month() as u8)
+            .ok()
+            .and_then(|month| PublishDate::from_year_month_day(date.year(), month, date.day()).ok())
+            .unwrap_or(PublishDate::from_year(date.year()))
+    }
+
+    /// e.g. Title. In: *Proceedings of the 43rd Annual International
+    /// Symposium on Computer Architecture*. 2016, vol. 2, no. 3.
+    ///
+    /// or, for a proceedings volume, EDITOR, Anne, ed. *Title*. 43rd
+    /// Annual International Symposium on Computer Architecture. 2016,
+    /// vol. 2.
+    fn iso690_string(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let mut parts: Vec<String> = Vec::new();
+
+        if self.is_paper {
+            parts.push(titles.plain(&self.title.as_apa_string(), "."));
+            parts.push(format!(
+                "In: {}",
+                titles.italic(&self.series.proceedings_title(self.conference_name), ".")
+            ));
+        } else {
+            if let Some(editors) = editor_list(self.editors, CitationStyle::Iso690, options) {
+                parts.push(editors);
+            }
+            parts.push(titles.italic(&self.title.as_apa_string(), "."));
+            parts.push(titles.plain(&self.series.full_name(self.conference_name), "."));
+        }
+
+        let mut details = vec![self.year().to_string()];
+        details.extend(ieee_volume_issue(self.volume, self.number));
+        parts.push(format!("{}.", details.join(", ")));
+
+        parts.join(" ")
+    }
+
+    /// e.g. Title. In: Proceedings of the 43rd Annual International
+    /// Symposium on Computer Architecture. 2016 Jun 18;2(3).
+    ///
+    /// or, for a proceedings volume, Editor A, editor. Title. 43rd Annual
+    /// International Symposium on Computer Architecture. 2016 Jun 18;2.
+    fn vancouver_string(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let mut parts: Vec<String> = Vec::new();
+
+        if self.is_paper {
+            parts.push(titles.plain(&self.title.as_apa_string(), "."));
+            parts.push(format!(
+                "In: {}",
+                titles.plain(&self.series.proceedings_title(self.conference_name), ".")
+            ));
+        } else {
+            if let Some(editors) = editor_list(self.editors, CitationStyle::Vancouver, options) {
+                parts.push(format!("{}.", editors));
+            }
+            parts.push(titles.plain(&self.title.as_apa_string(), "."));
+            parts.push(titles.plain(&self.series.full_name(self.conference_name), "."));
+        }
+
+        let mut source = VANCOUVER_DATES.published(&self.published());
+        if let Some(volume_issue) = apa_volume_issue(self.volume, self.number) {
+            source.push(';');
+            source.push_str(&volume_issue);
+        }
+        parts.push(format!("{}.", source));
+
+        parts.join(" ")
+    }
+
+    /// e.g. Title. (2016). In A. Editor & B. Editor (Eds.), *Proceedings
+    /// of the 43rd Annual International Symposium on Computer
+    /// Architecture* (Vol. 2).
+    fn apa_string(&self, options: &FormatOptions, description: &str) -> String {
+        let titles = options.titles();
+        let title =
+            (!self.title.is_empty()).then(|| titles.plain(&self.title.as_apa_string(), "."));
+        let mut parts = apa_lead(None, Some(self.year().to_string()), title, description);
+
+        let mut container = String::from("In ");
+        if let Some(names) = editor_names(self.editors, &APA_EDITOR_LIST) {
+            container.push_str(&format!("{} ({}), ", names, editor_role(self.editors)));
+        }
+        let proceedings = self.series.proceedings_title(self.conference_name);
+        match self.volume {
+            Some(volume) => container.push_str(&format!(
+                "{} (Vol. {}).",
+                titles.italic(&proceedings, ""),
+                volume
+            )),
+            None => container.push_str(&titles.italic(&proceedings, ".")),
+        }
+        parts.push(container);
+
+        parts.join(" ")
+    }
+
+    /// e.g. Title. In: *Proceedings of the 43rd Annual International
+    /// Symposium on Computer Architecture*. 2016;2(3).
+    ///
+    /// or, for a proceedings volume, Editor A, ed. *Title*. 43rd Annual
+    /// International Symposium on Computer Architecture. 2016;2.
+    fn ama_string(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let mut parts: Vec<String> = Vec::new();
+
+        if self.is_paper {
+            parts.push(titles.plain(&self.title.as_apa_string(), "."));
+            parts.push(format!(
+                "In: {}",
+                titles.italic(&self.series.proceedings_title(self.conference_name), ".")
+            ));
+        } else {
+            if let Some(editors) = editor_list(self.editors, CitationStyle::Ama, options) {
+                parts.push(editors);
+            }
+            parts.push(titles.italic(&self.title.as_apa_string(), "."));
+            parts.push(titles.plain(&self.series.full_name(self.conference_name), "."));
+        }
+
+        let mut source = self.year().to_string();
+        if let Some(volume_issue) = apa_volume_issue(self.volume, self.number) {
+            source.push(';');
+            source.push_str(&volume_issue);
+        }
+        parts.push(format!("{}.", source));
+
+        parts.join(" ")
+    }
+
+    /// e.g. Title. In *Proceedings of the 43rd Annual International
+    /// Symposium on Computer Architecture*; 2016; Vol. 2.
+    ///
+    /// or, for a proceedings volume, *Title*; Editor, A., Ed.; 43rd Annual
+    /// International Symposium on Computer Architecture; 2016; Vol. 2.
+    fn acs_string(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let mut elements: Vec<String> = Vec::new();
+
+        let lead = if self.is_paper {
+            format!(
+                "{} In {}",
+                titles.plain(&self.title.as_apa_string(), "."),
+                titles.italic(&self.series.proceedings_title(self.conference_name), "")
+            )
+        } else {
+            let mut lead = titles.italic(&self.title.as_apa_string(), "");
+            if let Some(editors) = editor_list(self.editors, CitationStyle::Acs, options) {
+                lead = format!("{}; {}", lead, editors);
+            }
+            format!("{}; {}", lead, self.series.full_name(self.conference_name))
+        };
+        elements.push(lead);
+        elements.push(self.year().to_string());
+        if let Some(volume) = self.volume {
+            elements.push(format!("Vol. {}", volume));
+        }
+
+        format!("{}.", elements.join("; "))
+    }
+}
+
+impl ConferencePaperOnline {
+    fn entry(&self) -> ProceedingsEntry<'_> {
+        ProceedingsEntry {
+            is_paper: true,
+            common_data: &self.common_data,
+            title: &self.title,
+            volume: self.volume.as_ref(),
+            number: self.number.as_ref(),
+            conference_name: &self.conference_name,
+            series: &self.series,
+            conference_date: &self.conference_date,
+            editors: &[],
+        }
+    }
+}
+
+impl ConferenceProceedingsOnline {
+    fn entry(&self) -> ProceedingsEntry<'_> {
+        ProceedingsEntry {
+            is_paper: false,
+            common_data: &self.common_data,
+            title: &self.title,
+            volume: self.volume.as_ref(),
+            number: self.number.as_ref(),
+            conference_name: &self.conference_name,
+            series: &self.series,
+            conference_date: &self.conference_date,
+            editors: &self.editors,
+        }
+    }
+}
+
+/// e.g. Title. (2016). In *Proceedings of the 43rd Annual International
+/// Symposium on Computer Architecture* (Vol. 2).
+impl ApaFormatting for ConferencePaperOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry()
+            .apa_string(options, "Untitled conference paper")
+    }
+}
+
+/// e.g. Title. (2016). In A. Editor & B. Editor (Eds.), *Proceedings of
+/// the 43rd Annual International Symposium on Computer Architecture*
+/// (Vol. 2).
+impl ApaFormatting for ConferenceProceedingsOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().apa_string(options, "Untitled proceedings")
+    }
+}
+
+impl Iso690Formatting for ConferencePaperOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().iso690_string(options)
+    }
+}
+
+impl Iso690Formatting for ConferenceProceedingsOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().iso690_string(options)
+    }
+}
+
+impl VancouverFormatting for ConferencePaperOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().vancouver_string(options)
+    }
+}
+
+impl VancouverFormatting for ConferenceProceedingsOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().vancouver_string(options)
+    }
+}
+
+impl AmaFormatting for ConferencePaperOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().ama_string(options)
+    }
+}
+
+impl AmaFormatting for ConferenceProceedingsOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().ama_string(options)
+    }
+}
+
+impl AcsFormatting for ConferencePaperOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().acs_string(options)
+    }
+}
+
+impl AcsFormatting for ConferenceProceedingsOnline {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        self.entry().acs_string(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::{
+        author::PersonName,
+        citation::{
+            AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+            VancouverFormatting,
+        },
+        conference::ConferenceSeries,
+        media::{
+            common::CommonCitationData,
+            conference_paper::{ConferencePaperOnline, ConferenceProceedingsOnline},
+        },
+        volume::{Issue, Volume},
+    };
+
+    // cSpell: disable
+    #[test]
+    fn test_ieee_proceedings_phrase() {
+        let paper = ConferencePaperOnline {
+            common_data: CommonCitationData::new("albericio2016"),
+            title: "Cnvlutin: Ineffectual-neuron-free deep neural network computing".into(),
+            venue: None,
+            volume: None,
+            number: None,
+            conference_name: "Proceedings of the Forty-Third Annual International Symposium on Computer Architecture".to_string(),
+            series: ConferenceSeries::default(),
+            conference_date: Utc.with_ymd_and_hms(2016, 6, 18, 0, 0, 0).unwrap(),
+        };
+        assert_eq!(
+            IeeeFormatting::citation_string(&paper),
+            "“Cnvlutin: Ineffectual-neuron-free deep neural network computing,” in Proc. 43rd Annu. Int. Symp. Comput. Archit., Jun. 2016."
+        );
+        assert_eq!(
+            ApaFormatting::citation_string(&paper),
+            "Cnvlutin: Ineffectual-neuron-free deep neural network computing. (2016). In \
+             Proceedings of the Forty-Third Annual International Symposium on Computer \
+             Architecture."
+        );
+
+        let proceedings = ConferenceProceedingsOnline {
+            common_data: CommonCitationData::new("cloud2009"),
+            title: "Cloud computing".into(),
+            venue: None,
+            volume: None,
+            number: None,
+            conference_name: "International Conference on Cloud Computing".to_string(),
+            series: ConferenceSeries {
+                number: Some(2),
+                sponsors: Vec::new(),
+            },
+            conference_date: Utc.with_ymd_and_hms(2009, 9, 21, 0, 0, 0).unwrap(),
+            editors: Vec::new(),
+        };
+        assert_eq!(
+            IeeeFormatting::citation_string(&proceedings),
+            "Cloud computing. Proc. 2nd Int. Conf. Cloud Comput., Sep. 2009."
+        );
+    }
+
+    #[test]
+    fn test_proceedings_editors() {
+        let mut proceedings = ConferenceProceedingsOnline {
+            common_data: CommonCitationData::new("icse2023"),
+            title: "Automated repair of flaky tests".into(),
+            venue: None,
+            volume: Some(Volume::parse("2")),
+            number: None,
+            conference_name: "International Conference on Software Engineering".to_string(),
+            series: ConferenceSeries {
+                number: Some(45),
+                sponsors: vec!["IEEE".to_string(), "ACM".to_string()],
+            },
+            conference_date: Utc.with_ymd_and_hms(2023, 5, 14, 0, 0, 0).unwrap(),
+            editors: vec![
+                PersonName::from_first_last("Jane", "Editor").unwrap(),
+                PersonName::from_first_last("Bob", "Reviser").unwrap(),
+            ],
+        };
+
+        assert_eq!(
+            ApaFormatting::citation_string(&proceedings),
+            "Automated repair of flaky tests. (2023). In J. Editor & B. Reviser (Eds.), \
+             Proceedings of the 45th IEEE/ACM International Conference on Software \
+             Engineering (Vol. 2)."
+        );
+        assert_eq!(
+            IeeeFormatting::citation_string(&proceedings),
+            "Automated repair of flaky tests. Proc. 45th IEEE/ACM Int. Conf. Softw. Eng., \
+             J. Editor and B. Reviser, Eds., vol. 2, May 2023."
+        );
+
+        proceedings.editors.truncate(1);
+        proceedings.volume = None;
+        assert_eq!(
+            ApaFormatting::citation_string(&proceedings),
+            "Automated repair of flaky tests. (2023). In J. Editor (Ed.), Proceedings of the 45th \
+             IEEE/ACM International Conference on Software Engineering."
+        );
+    }
+
+    fn paper() -> ConferencePaperOnline {
+        ConferencePaperOnline {
+            common_data: CommonCitationData::new("flaky2023"),
+            title: "Automated repair of flaky tests".into(),
+            venue: None,
+            volume: Some(Volume::parse("2")),
+            number: Some(Issue::parse("3")),
+            conference_name: "International Conference on Software Engineering".to_string(),
+            series: ConferenceSeries {
+                number: Some(45),
+                sponsors: vec!["IEEE".to_string(), "ACM".to_string()],
+            },
+            conference_date: Utc.with_ymd_and_hms(2023, 5, 14, 0, 0, 0).unwrap(),
+        }
+    }
+
+    fn proceedings() -> ConferenceProceedingsOnline {
+        let paper = paper();
+        ConferenceProceedingsOnline {
+            common_data: paper.common_data,
+            title: "Software engineering in practice".into(),
+            venue: None,
+            volume: paper.volume,
+            number: None,
+            conference_name: paper.conference_name,
+            series: paper.series,
+            conference_date: paper.conference_date,
+            editors: vec![
+                PersonName::from_first_last("Jane", "Editor").unwrap(),
+                PersonName::from_first_last("Bob", "Reviser").unwrap(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_iso690_formatting() {
+        assert_eq!(
+            Iso690Formatting::citation_string(&paper()),
+            "Automated repair of flaky tests. In: Proceedings of the 45th IEEE/ACM International \
+             Conference on Software Engineering. 2023, vol. 2, no. 3."
+        );
+        assert_eq!(
+            Iso690Formatting::citation_string(&proceedings()),
+            "EDITOR, Jane and REVISER, Bob, eds. Software engineering in practice. 45th IEEE/ACM \
+             International Conference on Software Engineering. 2023, vol. 2."
+        );
+    }
+
+    #[test]
+    fn test_vancouver_formatting() {
+        assert_eq!(
+            VancouverFormatting::citation_string(&paper()),
+            "Automated repair of flaky tests. In: Proceedings of the 45th IEEE/ACM International \
+             Conference on Software Engineering. 2023 May 14;2(3)."
+        );
+        assert_eq!(
+            VancouverFormatting::citation_string(&proceedings()),
+            "Editor J, Reviser B, editors. Software engineering in practice. 45th IEEE/ACM \
+             International Conference on Software Engineering. 2023 May 14;2."
+        );
+    }
+
+    #[test]
+    fn test_ama_formatting() {
+        assert_eq!(
+            AmaFormatting::citation_string(&paper()),
+            "Automated repair of flaky tests. In: Proceedings of the 45th IEEE/ACM International \
+             Conference on Software Engineering. 2023;2(3)."
+        );
+        assert_eq!(
+            AmaFormatting::citation_string(&proceedings()),
+            "Editor J, Reviser B, eds. Software engineering in practice. 45th IEEE/ACM \
+             International Conference on Software Engineering. 2023;2."
+        );
+    }
+
+    #[test]
+    fn test_acs_formatting() {
+        assert_eq!(
+            AcsFormatting::citation_string(&paper()),
+            "Automated repair of flaky tests. In Proceedings of the 45th IEEE/ACM International \
+             Conference on Software Engineering; 2023; Vol. 2."
+        );
+        assert_eq!(
+            AcsFormatting::citation_string(&proceedings()),
+            "Software engineering in practice; Editor, J.; Reviser, B., Eds.; 45th IEEE/ACM \
+             International Conference on Software Engineering; 2023; Vol. 2."
+        );
+    }
+    // cSpell: enable
 }
@@ -0,0 +1,494 @@
+use chrono::Month;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        author::{
+            ACS_AUTHOR_LIST, AMA_AUTHOR_LIST, APA_AUTHOR_LIST, AcademicAuthor, IEEE_AUTHOR_LIST,
+            ISO_690_AUTHOR_LIST, VANCOUVER_AUTHOR_LIST,
+        },
+        citation::{
+            AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+            VancouverFormatting,
+        },
+        conference::ConferenceSeries,
+        date::{
+            AMA_DATES, APA_DATES, IEEE_DATES, PublishDate, VANCOUVER_DATES,
+            ieee_abbreviated_month_name,
+        },
+        location::LocationData,
+        media::{apa_lead, common::CommonCitationData, doi_element, medium::Medium},
+        style::{CitationStyle, FormatOptions},
+        title::Title,
+    },
+    unicode::ENDASH,
+};
+
+/// What was presented
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum PresentationKind {
+    #[default]
+    Presentation,
+    /// A paper read at the conference
+    Paper,
+    Poster,
+    Keynote,
+}
+
+impl PresentationKind {
+    /// APA's bracketed description, e.g. "Poster presentation"
+    pub fn apa_description(&self) -> &'static str {
+        match self {
+            PresentationKind::Presentation => "Conference presentation",
+            PresentationKind::Paper => "Paper presentation",
+            PresentationKind::Poster => "Poster presentation",
+            PresentationKind::Keynote => "Keynote address",
+        }
+    }
+}
+
+/// A talk or poster presented at a conference, but not published in its
+/// proceedings. Published papers are [`super::conference_paper`] types.
+///
+/// The publish date is the (first) day of the presentation.
+///
+/// IEEE formatting rules taken from:
+/// * https://journals.ieeeauthorcenter.ieee.org/wp-content/uploads/sites/7/IEEE_Reference_Guide.pdf
+///
+/// APA formatting rules taken from:
+/// * https://apastyle.apa.org/style-grammar-guidelines/references/examples/conference-presentation-references
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConferencePresentation {
+    pub common_data: CommonCitationData,
+    /// Presenters
+    pub author: AcademicAuthor,
+    /// Title of the talk or poster
+    pub title: Title,
+    pub kind: PresentationKind,
+    pub conference_name: String,
+    /// Numbering and sponsors, added to the name when formatting
+    #[serde(default, skip_serializing_if = "ConferenceSeries::is_empty")]
+    pub series: ConferenceSeries,
+    /// Where the conference took place
+    pub location: Option<LocationData>,
+    /// Last day, for presentations spanning several days
+    pub ends: Option<PublishDate>,
+    /// Link to slides, a recording or an abstract
+    pub url: Option<String>,
+}
+
+impl ConferencePresentation {
+    /// The medium named in IEEE references to recorded or posted
+    /// presentations
+    pub const MEDIUM: Medium = Medium::Online;
+
+    /// The conference's full name, with its number and sponsors
+    fn conference(&self) -> String {
+        self.series.full_name(&self.conference_name)
+    }
+
+    fn location_string(&self, style: CitationStyle) -> Option<String> {
+        self.location
+            .as_ref()
+            .map(|location| location.format_event(style))
+    }
+
+    /// How the numeric styles introduce the conference, e.g. "Poster
+    /// session presented at"
+    fn presented_at(&self) -> &'static str {
+        match self.kind {
+            PresentationKind::Presentation => "Presented at",
+            PresentationKind::Paper => "Paper presented at",
+            PresentationKind::Poster => "Poster session presented at",
+            PresentationKind::Keynote => "Keynote address presented at",
+        }
+    }
+
+    /// Month and day of the last day, if the presentation spans several
+    /// days within one year
+    fn last_day(&self) -> Option<(Month, u32)> {
+        let start = self.common_data.published.as_ref()?;
+        let end = self.ends.as_ref()?;
+        start.day()?;
+        let (month, day) = (end.month()?, end.day()?);
+        (end.year() == start.year() && end > start).then_some((month, day))
+    }
+
+    /// The end of the date range, e.g. "–20" or "–Oct. 2", with the month
+    /// named only when it differs from the start
+    fn range_end(&self, start_month: Month, month_name: fn(&Month) -> &'static str) -> String {
+        match self.last_day() {
+            Some((month, day)) if month == start_month => format!("{}{}", ENDASH, day),
+            Some((month, day)) => format!("{}{} {}", ENDASH, month_name(&month), day),
+            None => String::new(),
+        }
+    }
+}
+
+impl IeeeFormatting for ConferencePresentation {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_ieee_string_with(options) {
+            parts.push(IEEE_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().quoted(&self.title.as_ieee_string(), ","));
+
+        let presented = match self.kind {
+            PresentationKind::Poster => "presented as a poster at the",
+            _ => "presented at the",
+        };
+        let mut details: Vec<String> = vec![format!("{} {}", presented, self.conference())];
+        if let Some(location) = self.location_string(CitationStyle::Ieee) {
+            details.push(location);
+        }
+        if let Some(published) = &self.common_data.published {
+            details.push(match (published.month(), published.day()) {
+                (Some(month), Some(day)) => format!(
+                    "{} {}{}, {}",
+                    ieee_abbreviated_month_name(&month),
+                    day,
+                    self.range_end(month, ieee_abbreviated_month_name),
+                    published.year()
+                ),
+                _ => IEEE_DATES.published(published),
+            });
+        }
+        parts.push(format!("{}.", details.join(", ")));
+
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, doi.is_some()))
+        {
+            parts.push(Self::MEDIUM.ieee_availability(Some(url)));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Ieee, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl ApaFormatting for ConferencePresentation {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let authors = self
+            .author
+            .as_apa_string_with(options)
+            .map(|authors| APA_AUTHOR_LIST.terminate(&authors));
+        let date = self.common_data.published.as_ref().map(|published| {
+            let end = match published.month() {
+                Some(month) => self.range_end(month, |month| month.name()),
+                None => String::new(),
+            };
+            format!("{}{}", APA_DATES.published(published), end)
+        });
+        let title = (!self.title.is_empty()).then(|| {
+            format!(
+                "{} [{}].",
+                options.titles().italic(&self.title.as_apa_string(), ""),
+                self.kind.apa_description()
+            )
+        });
+        let mut parts = apa_lead(authors, date, title, self.kind.apa_description());
+
+        let mut venue = vec![self.conference()];
+        if let Some(location) = self.location_string(CitationStyle::Apa) {
+            venue.push(location);
+        }
+        parts.push(format!("{}.", venue.join(", ")));
+
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, doi.is_some()))
+        {
+            parts.push(url);
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Apa, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl Iso690Formatting for ConferencePresentation {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Iso690, options) {
+            parts.push(ISO_690_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(format!(
+            "{} [{}].",
+            options.titles().italic(&self.title.as_apa_string(), ""),
+            self.kind.apa_description().to_lowercase()
+        ));
+
+        let mut venue = vec![self.conference()];
+        if let Some(location) = self.location_string(CitationStyle::Iso690) {
+            venue.push(location);
+        }
+        if let Some(published) = &self.common_data.published {
+            venue.push(published.year().to_string());
+        }
+        parts.push(format!("{}.", venue.join(", ")));
+
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, doi.is_some()))
+        {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Iso690, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for ConferencePresentation {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_string_with(CitationStyle::Vancouver, options)
+        {
+            parts.push(VANCOUVER_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().plain(&self.title.as_apa_string(), "."));
+
+        // e.g. "Paper presented at: Conference; 2023 Mar 5-7; City, Country."
+        let mut venue = vec![format!("{}: {}", self.presented_at(), self.conference())];
+        if let Some(published) = &self.common_data.published {
+            let end = match published.month() {
+                Some(month) => self.range_end(month, |month| &month.name()[..3]),
+                None => String::new(),
+            };
+            venue.push(format!("{}{}", VANCOUVER_DATES.published(published), end));
+        }
+        if let Some(location) = self.location_string(CitationStyle::Vancouver) {
+            venue.push(location);
+        }
+        parts.push(format!("{}.", venue.join("; ")));
+
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, doi.is_some()))
+        {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Vancouver, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for ConferencePresentation {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Ama, options) {
+            parts.push(AMA_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().plain(&self.title.as_apa_string(), "."));
+
+        // e.g. "Presented at: Conference; March 5-7, 2023; City, Country."
+        let mut venue = vec![format!("{}: {}", self.presented_at(), self.conference())];
+        if let Some(published) = &self.common_data.published {
+            venue.push(match (published.month(), published.day()) {
+                (Some(month), Some(day)) => format!(
+                    "{} {}{}, {}",
+                    month.name(),
+                    day,
+                    self.range_end(month, Month::name),
+                    published.year()
+                ),
+                _ => AMA_DATES.published(published),
+            });
+        }
+        if let Some(location) = self.location_string(CitationStyle::Ama) {
+            venue.push(location);
+        }
+        parts.push(format!("{}.", venue.join("; ")));
+
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, doi.is_some()))
+        {
+            parts.push(url);
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Ama, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AcsFormatting for ConferencePresentation {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Acs, options) {
+            parts.push(ACS_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().plain(&self.title.as_apa_string(), "."));
+
+        // e.g. "Presented at Conference, City, Country, Mar 5–7, 2023."
+        let mut venue = vec![format!("{} {}", self.presented_at(), self.conference())];
+        if let Some(location) = self.location_string(CitationStyle::Acs) {
+            venue.push(location);
+        }
+        if let Some(published) = &self.common_data.published {
+            let abbreviated = |month: &Month| &month.name()[..3];
+            venue.push(match (published.month(), published.day()) {
+                (Some(month), Some(day)) => format!(
+                    "{} {}{}, {}",
+                    abbreviated(&month),
+                    day,
+                    self.range_end(month, abbreviated),
+                    published.year()
+                ),
+                (Some(month), None) => format!("{} {}", abbreviated(&month), published.year()),
+                _ => published.year().to_string(),
+            });
+        }
+        parts.push(format!("{}.", venue.join(", ")));
+
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, doi.is_some()))
+        {
+            parts.push(url);
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Acs, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::api::{
+        author::{AcademicAuthor, PersonName},
+        citation::{ApaFormatting, IeeeFormatting},
+        conference::ConferenceSeries,
+        date::PublishDate,
+        location::LocationData,
+        media::{
+            common::CommonCitationData,
+            conference_presentation::{ConferencePresentation, PresentationKind},
+        },
+    };
+
+    fn presentation() -> ConferencePresentation {
+        ConferencePresentation {
+            common_data: CommonCitationData {
+                published: Some(
+                    PublishDate::from_year_month_day(2019, Month::September, 18).unwrap(),
+                ),
+                ..CommonCitationData::new("smith2019")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            title: "Memory safety without garbage collection".into(),
+            kind: PresentationKind::Presentation,
+            conference_name: "RustConf".to_string(),
+            series: ConferenceSeries::default(),
+            location: Some(LocationData {
+                city: "Portland".to_string(),
+                state: Some("OR".to_string()),
+                country: "USA".to_string(),
+            }),
+            ends: Some(PublishDate::from_year_month_day(2019, Month::September, 20).unwrap()),
+            url: None,
+        }
+    }
+
+    #[test]
+    fn test_conference_presentation_apa_formatting() {
+        assert_eq!(
+            ApaFormatting::citation_string(&presentation()),
+            "Smith, J. (2019, September 18\u{2013}20). Memory safety without garbage collection \
+             [Conference presentation]. RustConf, Portland, OR, United States."
+        );
+    }
+
+    #[test]
+    fn test_conference_presentation_ieee_formatting() {
+        assert_eq!(
+            IeeeFormatting::citation_string(&presentation()),
+            "J. Smith, \u{201C}Memory safety without garbage collection,\u{201D} presented at the \
+             RustConf, Portland, OR, USA, Sep. 18\u{2013}20, 2019."
+        );
+    }
+
+    #[test]
+    fn test_conference_series() {
+        let mut talk = presentation();
+        talk.conference_name = "International Conference on Software Engineering".to_string();
+        talk.series = ConferenceSeries {
+            number: Some(45),
+            sponsors: vec!["IEEE".to_string(), "ACM".to_string()],
+        };
+
+        assert_eq!(
+            ApaFormatting::citation_string(&talk),
+            "Smith, J. (2019, September 18\u{2013}20). Memory safety without garbage collection \
+             [Conference presentation]. 45th IEEE/ACM International Conference on Software \
+             Engineering, Portland, OR, United States."
+        );
+        assert_eq!(
+            IeeeFormatting::citation_string(&talk),
+            "J. Smith, \u{201C}Memory safety without garbage collection,\u{201D} presented at the \
+             45th IEEE/ACM International Conference on Software Engineering, Portland, OR, USA, \
+             Sep. 18\u{2013}20, 2019."
+        );
+    }
+
+    #[test]
+    fn test_poster_spanning_months() {
+        let mut poster = presentation();
+        poster.kind = PresentationKind::Poster;
+        poster.location = None;
+        poster.ends = Some(PublishDate::from_year_month_day(2019, Month::October, 2).unwrap());
+        poster.url = Some("https://example.com/poster.pdf".to_string());
+
+        assert_eq!(
+            ApaFormatting::citation_string(&poster),
+            "Smith, J. (2019, September 18\u{2013}October 2). Memory safety without garbage \
+             collection [Poster presentation]. RustConf. https://example.com/poster.pdf"
+        );
+        assert_eq!(
+            IeeeFormatting::citation_string(&poster),
+            "J. Smith, \u{201C}Memory safety without garbage collection,\u{201D} presented as a \
+             poster at the RustConf, Sep. 18\u{2013}Oct. 2, 2019. [Online]. Available: \
+             https://example.com/poster.pdf"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt,
+    sync::{OnceLock, RwLock},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+
+use crate::api::{
+    author::{Contributor, PersonName},
+    errors::CitationError,
+    media::common::CommonCitationData,
+    style::{CitationStyle, FormatOptions},
+};
+
+/// A media type defined outside this crate, e.g. a museum specimen.
+///
+/// Wrap values in [`CustomMedia`] to store them in a
+/// [`Citation::Custom`](crate::api::citation::Citation::Custom), and call
+/// [`register_custom_media`] so that they can be deserialized.
+pub trait CitationLike: Any + fmt::Debug + Send + Sync {
+    /// Stable name of the media type, e.g. "museum-specimen"
+    fn media_type(&self) -> &str;
+
+    fn common_data(&self) -> &CommonCitationData;
+
+    fn common_data_mut(&mut self) -> &mut CommonCitationData;
+
+    fn title(&self) -> String;
+
+    /// Authors, in citation order
+    fn contributors(&self) -> Vec<Contributor> {
+        Vec::new()
+    }
+
+    fn persons_mut(&mut self) -> Vec<&mut PersonName> {
+        Vec::new()
+    }
+
+    fn doi(&self) -> Option<String> {
+        None
+    }
+
+    fn url(&self) -> Option<String> {
+        None
+    }
+
+    /// The reference in `style`, or [`CitationError::UnsupportedStyle`] if
+    /// the media type has no rules for it
+    fn format_with(
+        &self,
+        style: CitationStyle,
+        options: &FormatOptions,
+    ) -> Result<String, CitationError>;
+
+    /// The media-specific data, as stored in bibliography files
+    fn to_json(&self) -> serde_json::Value;
+
+    fn clone_box(&self) -> Box<dyn CitationLike>;
+}
+
+/// Reads a custom media type from the JSON written by
+/// [`CitationLike::to_json`]
+pub type CustomMediaReader = fn(serde_json::Value) -> Result<Box<dyn CitationLike>, String>;
+
+fn registry() -> &'static RwLock<HashMap<String, CustomMediaReader>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, CustomMediaReader>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Make citations of `media_type` deserializable as `T`. Registering a
+/// media type again replaces the earlier registration.
+pub fn register_custom_media<T: CitationLike + DeserializeOwned>(media_type: &str) {
+    fn read<T: CitationLike + DeserializeOwned>(
+        value: serde_json::Value,
+    ) -> Result<Box<dyn CitationLike>, String> {
+        serde_json::from_value::<T>(value)
+            .map(|media| Box::new(media) as Box<dyn CitationLike>)
+            .map_err(|error| error.to_string())
+    }
+
+    registry()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(media_type.to_string(), read::<T>);
+}
+
+/// A citation of a media type defined outside this crate.
+///
+/// Serialized as `{"media_type": ..., "data": ...}`. Two custom citations
+/// are equal when they have the same media type and data.
+#[derive(Debug)]
+pub struct CustomMedia(Box<dyn CitationLike>);
+
+impl CustomMedia {
+    pub fn new(media: impl CitationLike) -> Self {
+        Self(Box::new(media))
+    }
+
+    /// The wrapped value, if it is a `T`
+    pub fn downcast_ref<T: CitationLike>(&self) -> Option<&T> {
+        let media: &dyn Any = self.0.as_ref();
+        media.downcast_ref()
+    }
+
+    pub fn downcast_mut<T: CitationLike>(&mut self) -> Option<&mut T> {
+        let media: &mut dyn Any = self.0.as_mut();
+        media.downcast_mut()
+    }
+}
+
+impl std::ops::Deref for CustomMedia {
+    type Target = dyn CitationLike;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl std::ops::DerefMut for CustomMedia {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut()
+    }
+}
+
+impl Clone for CustomMedia {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_box())
+    }
+}
+
+impl PartialEq for CustomMedia {
+    fn eq(&self, other: &Self) -> bool {
+        self.media_type() == other.media_type() && self.to_json() == other.to_json()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+struct CustomMediaRepr {
+    media_type: String,
+    data: serde_json::Value,
+}
+
+impl Serialize for CustomMedia {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CustomMediaRepr {
+            media_type: self.media_type().to_string(),
+            data: self.to_json(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomMedia {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CustomMediaRepr::deserialize(deserializer)?;
+        let read = registry()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&repr.media_type)
+            .copied()
+            .ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "unregistered custom media type \"{}\"",
+                    repr.media_type
+                ))
+            })?;
+        read(repr.data).map(Self).map_err(serde::de::Error::custom)
+    }
+}
+
+// The data depends on the registered media type, so it is left open
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for CustomMedia {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "CustomMedia".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        CustomMediaRepr::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::api::{
+        citation::Citation,
+        errors::CitationError,
+        media::{
+            MediaKind,
+            common::CommonCitationData,
+            custom::{CitationLike, CustomMedia, register_custom_media},
+        },
+        style::{CitationStyle, FormatOptions},
+    };
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Specimen {
+        common_data: CommonCitationData,
+        catalog_number: String,
+        museum: String,
+    }
+
+    impl CitationLike for Specimen {
+        fn media_type(&self) -> &str {
+            "museum-specimen"
+        }
+
+        fn common_data(&self) -> &CommonCitationData {
+            &self.common_data
+        }
+
+        fn common_data_mut(&mut self) -> &mut CommonCitationData {
+            &mut self.common_data
+        }
+
+        fn title(&self) -> String {
+            format!("Specimen {}", self.catalog_number)
+        }
+
+        fn format_with(
+            &self,
+            style: CitationStyle,
+            _options: &FormatOptions,
+        ) -> Result<String, CitationError> {
+            Ok(match style {
+                CitationStyle::Ieee => format!("{}, {}.", self.museum, self.title()),
+                _ => format!("{}. {}.", self.museum, self.title()),
+            })
+        }
+
+        fn to_json(&self) -> serde_json::Value {
+            serde_json::to_value(self).unwrap()
+        }
+
+        fn clone_box(&self) -> Box<dyn CitationLike> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn specimen() -> Citation {
+        Citation::Custom(CustomMedia::new(Specimen {
+            common_data: CommonCitationData::new("nhm-1234"),
+            catalog_number: "1234".to_string(),
+            museum: "Natural History Museum".to_string(),
+        }))
+    }
+
+    #[test]
+    fn test_custom_media_citation() {
+        let citation = specimen();
+
+        assert_eq!(citation.id(), "nhm-1234");
+        assert_eq!(citation.kind(), MediaKind::Custom);
+        assert_eq!(
+            citation.format(CitationStyle::Ieee),
+            "Natural History Museum, Specimen 1234."
+        );
+        assert_eq!(
+            citation
+                .as_custom()
+                .and_then(|custom| custom.downcast_ref::<Specimen>())
+                .map(|specimen| specimen.museum.as_str()),
+            Some("Natural History Museum")
+        );
+    }
+
+    #[test]
+    fn test_custom_media_round_trip() {
+        let json = serde_json::to_string(&specimen()).unwrap();
+        assert!(serde_json::from_str::<Citation>(&json).is_err());
+
+        register_custom_media::<Specimen>("museum-specimen");
+        let parsed: Citation = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, specimen());
+    }
+}
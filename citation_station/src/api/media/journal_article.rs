@@ -0,0 +1,452 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    author::{
+        ACS_AUTHOR_LIST, AMA_AUTHOR_LIST, APA_AUTHOR_LIST, AcademicAuthor, IEEE_AUTHOR_LIST,
+        ISO_690_AUTHOR_LIST, VANCOUVER_AUTHOR_LIST,
+    },
+    citation::{
+        AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+        VancouverFormatting,
+    },
+    date::{IEEE_DATES, VANCOUVER_DATES},
+    media::{apa_lead, common::CommonCitationData},
+    page_range::ArticleLocator,
+    style::{CitationStyle, FormatOptions},
+    title::Title,
+    volume::{Issue, Volume, apa_volume_issue, ieee_volume_issue},
+};
+
+/// An article published in an academic journal.
+///
+/// IEEE formatting rules taken from:
+/// * https://journals.ieeeauthorcenter.ieee.org/wp-content/uploads/sites/7/IEEE_Reference_Guide.pdf
+///
+/// APA formatting rules taken from:
+/// * https://apastyle.apa.org/style-grammar-guidelines/references/examples/journal-article-references
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JournalArticle {
+    pub common_data: CommonCitationData,
+    /// Author
+    pub author: AcademicAuthor,
+    /// Article title
+    pub title: Title,
+    /// Journal name
+    pub journal: String,
+    /// Volume number
+    pub volume: Option<Volume>,
+    /// Issue or number
+    pub number: Option<Issue>,
+    /// Page range or article number. Named `pages` in files written
+    /// before article numbers were supported.
+    #[serde(alias = "pages")]
+    pub locator: Option<ArticleLocator>,
+    /// DOI (Digital Object Identifier)
+    pub doi: Option<String>,
+}
+
+impl IeeeFormatting for JournalArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_ieee_string_with(options) {
+            parts.push(IEEE_AUTHOR_LIST.terminate(&authors));
+        }
+
+        let titles = options.titles();
+        parts.push(titles.quoted(&self.title.as_ieee_string(), ","));
+
+        let mut details: Vec<String> = vec![titles.italic(&options.venue(&self.journal), "")];
+        details.extend(ieee_volume_issue(
+            self.volume.as_ref(),
+            self.number.as_ref(),
+        ));
+        if let Some(locator) = &self.locator {
+            details.push(locator.as_ieee_string());
+        }
+        if let Some(published) = &self.common_data.published {
+            details.push(IEEE_DATES.published(published));
+        }
+        if let Some(doi) = &self.doi {
+            details.push(format!("doi: {}", doi));
+        }
+        parts.push(format!("{}.", details.join(", ")));
+
+        parts.join(" ")
+    }
+}
+
+impl ApaFormatting for JournalArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let authors = self
+            .author
+            .as_apa_string_with(options)
+            .map(|authors| APA_AUTHOR_LIST.terminate(&authors));
+        let date = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| published.year().to_string());
+        let title =
+            (!self.title.is_empty()).then(|| titles.plain(&self.title.as_apa_string(), "."));
+        let mut parts = apa_lead(authors, date, title, "Untitled article");
+
+        let mut source = titles.italic(&options.venue(&self.journal), "");
+        if let Some(volume_issue) = apa_volume_issue(self.volume.as_ref(), self.number.as_ref()) {
+            source.push_str(&format!(", {}", volume_issue));
+        }
+        if let Some(locator) = &self.locator {
+            source.push_str(&format!(", {}", locator.as_apa_string()));
+        }
+        parts.push(format!("{}.", source));
+
+        if let Some(doi) = &self.doi {
+            parts.push(format!("https://doi.org/{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl Iso690Formatting for JournalArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Iso690, options) {
+            parts.push(ISO_690_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(titles.plain(&self.title.as_apa_string(), "."));
+        parts.push(titles.italic(&options.venue(&self.journal), "."));
+
+        let mut details: Vec<String> = Vec::new();
+        if let Some(published) = &self.common_data.published {
+            details.push(published.year().to_string());
+        }
+        details.extend(ieee_volume_issue(
+            self.volume.as_ref(),
+            self.number.as_ref(),
+        ));
+        if let Some(locator) = &self.locator {
+            details.push(locator.as_ieee_string());
+        }
+        if !details.is_empty() {
+            parts.push(format!("{}.", details.join(", ")));
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("Available from: https://doi.org/{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for JournalArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self
+            .author
+            .as_string_with(CitationStyle::Vancouver, options)
+        {
+            parts.push(VANCOUVER_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(titles.plain(&self.title.as_apa_string(), "."));
+        parts.push(titles.plain(&options.venue(&self.journal), "."));
+
+        // e.g. "2023 Mar;12(3):100-10."
+        let mut source = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| VANCOUVER_DATES.published(published))
+            .unwrap_or_default();
+        if let Some(volume_issue) = apa_volume_issue(self.volume.as_ref(), self.number.as_ref()) {
+            source.push(';');
+            source.push_str(&volume_issue);
+        }
+        if let Some(locator) = &self.locator {
+            source.push(':');
+            source.push_str(&locator.as_vancouver_string());
+        }
+        if !source.is_empty() {
+            parts.push(format!("{}.", source));
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("doi:{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for JournalArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Ama, options) {
+            parts.push(AMA_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(titles.plain(&self.title.as_apa_string(), "."));
+        parts.push(titles.italic(&options.venue(&self.journal), "."));
+
+        // e.g. "2023;12(3):100-110."
+        let mut source = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| published.year().to_string())
+            .unwrap_or_default();
+        if let Some(volume_issue) = apa_volume_issue(self.volume.as_ref(), self.number.as_ref()) {
+            source.push(';');
+            source.push_str(&volume_issue);
+        }
+        if let Some(locator) = &self.locator {
+            source.push(':');
+            source.push_str(&locator.as_ama_string());
+        }
+        if !source.is_empty() {
+            parts.push(format!("{}.", source));
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("doi:{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AcsFormatting for JournalArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        let titles = options.titles();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Acs, options) {
+            parts.push(ACS_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(titles.plain(&self.title.as_apa_string(), "."));
+
+        // e.g. "Journal of Examples 2023, 12 (3), 100–110." with the journal
+        // in italics, the year in bold and the volume in italics
+        let mut details: Vec<String> = Vec::new();
+        let mut journal = titles.italic(&options.venue(&self.journal), "");
+        if let Some(published) = &self.common_data.published {
+            journal = format!("{} {}", journal, titles.bold(&published.year().to_string()));
+        }
+        details.push(journal);
+        if let Some(volume) = &self.volume {
+            let volume = titles.italic(&volume.to_string(), "");
+            match &self.number {
+                Some(issue) => details.push(format!("{} ({})", volume, issue)),
+                None => details.push(volume),
+            }
+        }
+        if let Some(locator) = &self.locator {
+            details.push(locator.as_apa_string());
+        }
+        parts.push(format!("{}.", details.join(", ")));
+        if let Some(doi) = &self.doi {
+            parts.push(format!("DOI: {}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::api::{
+        author::{AcademicAuthor, PersonName},
+        citation::{
+            AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+            VancouverFormatting,
+        },
+        date::PublishDate,
+        media::{common::CommonCitationData, journal_article::JournalArticle},
+        page_range::{ArticleLocator, PageRange},
+        style::{CitationStyle, FormatOptions, Markup, VenueNames},
+        title::{QuoteStyle, Title},
+        volume::{Issue, Volume},
+    };
+
+    fn article() -> JournalArticle {
+        JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month(2023, Month::March)),
+                ..CommonCitationData::new("smith2023")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_first_last("Humberto", "Fuentes").unwrap(),
+                ],
+            },
+            title: "A great paper".into(),
+            journal: "Journal of Examples".to_string(),
+            volume: Some(Volume::parse("12")),
+            number: Some(Issue::parse("3")),
+            locator: Some(
+                PageRange {
+                    start: 100,
+                    end: 110,
+                }
+                .into(),
+            ),
+            doi: Some("10.1000/xyz123".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_journal_article_ieee_formatting() {
+        assert_eq!(
+            IeeeFormatting::citation_string(&article()),
+            "J. Smith and H. Fuentes, \u{201C}A great paper,\u{201D} J. Examples, vol. 12, \
+             no. 3, pp. 100\u{2013}110, Mar., 2023, doi: 10.1000/xyz123."
+        );
+    }
+
+    #[test]
+    fn test_journal_article_apa_formatting() {
+        assert_eq!(
+            ApaFormatting::citation_string(&article()),
+            "Smith, J., & Fuentes, H. (2023). A great paper. Journal of Examples, 12(3), \
+             100\u{2013}110. https://doi.org/10.1000/xyz123"
+        );
+    }
+
+    #[test]
+    fn test_journal_article_iso690_formatting() {
+        assert_eq!(
+            Iso690Formatting::citation_string(&article()),
+            "SMITH, Jane and FUENTES, Humberto. A great paper. Journal of Examples. \
+             2023, vol. 12, no. 3, pp. 100\u{2013}110. Available from: https://doi.org/10.1000/xyz123"
+        );
+    }
+
+    #[test]
+    fn test_journal_article_vancouver_formatting() {
+        assert_eq!(
+            VancouverFormatting::citation_string(&article()),
+            "Smith J, Fuentes H. A great paper. Journal of Examples. 2023 Mar;12(3):100-10. \
+             doi:10.1000/xyz123"
+        );
+    }
+
+    #[test]
+    fn test_journal_article_ama_formatting() {
+        assert_eq!(
+            AmaFormatting::citation_string(&article()),
+            "Smith J, Fuentes H. A great paper. Journal of Examples. 2023;12(3):100-110. \
+             doi:10.1000/xyz123"
+        );
+    }
+
+    #[test]
+    fn test_journal_article_acs_formatting() {
+        assert_eq!(
+            AcsFormatting::citation_string(&article()),
+            "Smith, J.; Fuentes, H. A great paper. Journal of Examples 2023, 12 (3), \
+             100\u{2013}110. DOI: 10.1000/xyz123"
+        );
+
+        let html = FormatOptions {
+            markup: Markup::Html,
+            ..FormatOptions::for_style(CitationStyle::Acs)
+        };
+        assert!(
+            html.titles()
+                .render(&AcsFormatting::citation_string_with(&article(), &html))
+                .contains("<i>Journal of Examples</i> <b>2023</b>, <i>12</i> (3)")
+        );
+    }
+
+    #[test]
+    fn test_journal_article_ieee_formatting_minimal() {
+        let article = JournalArticle {
+            common_data: CommonCitationData::new("minimal"),
+            author: AcademicAuthor::Organization {
+                name: "The Consortium".to_string(),
+                abbreviation: None,
+            },
+            title: "Findings".into(),
+            journal: "Nature".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: None,
+        };
+
+        assert_eq!(
+            IeeeFormatting::citation_string(&article),
+            "The Consortium, \u{201C}Findings,\u{201D} Nature."
+        );
+    }
+
+    #[test]
+    fn test_journal_article_article_number() {
+        let mut article = article();
+        article.locator = ArticleLocator::parse("e0245678");
+
+        assert_eq!(
+            ApaFormatting::citation_string(&article),
+            "Smith, J., & Fuentes, H. (2023). A great paper. Journal of Examples, 12(3), \
+             Article e0245678. https://doi.org/10.1000/xyz123"
+        );
+        assert_eq!(
+            IeeeFormatting::citation_string(&article),
+            "J. Smith and H. Fuentes, \u{201C}A great paper,\u{201D} J. Examples, vol. 12, \
+             no. 3, Art. no. e0245678, Mar., 2023, doi: 10.1000/xyz123."
+        );
+    }
+
+    #[test]
+    fn test_journal_article_venue_names() {
+        let as_entered = FormatOptions {
+            venue_names: VenueNames::AsEntered,
+            ..FormatOptions::for_style(CitationStyle::Ieee)
+        };
+        assert!(
+            IeeeFormatting::citation_string_with(&article(), &as_entered)
+                .contains(" Journal of Examples, ")
+        );
+
+        let mut article = article();
+        article.journal = "Proc. IEEE".to_string();
+        assert!(ApaFormatting::citation_string(&article).contains(" Proceedings of the IEEE, "));
+    }
+
+    #[test]
+    fn test_journal_article_subtitle() {
+        let mut article = article();
+        article.title = Title::with_subtitle("A great paper", "methods and results");
+
+        assert_eq!(
+            ApaFormatting::citation_string(&article),
+            "Smith, J., & Fuentes, H. (2023). A great paper: Methods and results. Journal of \
+             Examples, 12(3), 100\u{2013}110. https://doi.org/10.1000/xyz123"
+        );
+        assert!(
+            IeeeFormatting::citation_string(&article)
+                .contains("\u{201C}A great paper: methods and results,\u{201D}")
+        );
+    }
+
+    #[test]
+    fn test_journal_article_quote_style() {
+        let options = FormatOptions {
+            quotes: QuoteStyle::Straight,
+            ..FormatOptions::for_style(CitationStyle::Ieee)
+        };
+
+        assert!(
+            IeeeFormatting::citation_string_with(&article(), &options)
+                .contains("Fuentes, \"A great paper,\" J. Examples")
+        );
+    }
+}
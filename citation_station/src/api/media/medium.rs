@@ -0,0 +1,70 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{media::doi_element, style::CitationStyle};
+
+/// The medium a work was consulted in, named in IEEE references by a
+/// bracketed designator after the access date, e.g. "[Online]".
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Medium {
+    Online,
+    OnlineVideo,
+}
+
+impl Medium {
+    /// The designator, e.g. "[Online]"
+    pub const fn ieee_designator(self) -> &'static str {
+        match self {
+            Medium::Online => "[Online]",
+            Medium::OnlineVideo => "[Online Video]",
+        }
+    }
+
+    /// The designator followed by where the work is available, e.g.
+    /// "[Online]. Available: https://example.com"
+    pub(crate) fn ieee_availability(self, available: Option<String>) -> String {
+        match available {
+            Some(available) => format!("{}. Available: {}", self.ieee_designator(), available),
+            None => format!("{}.", self.ieee_designator()),
+        }
+    }
+
+    /// Where an online work is found: [`Medium::ieee_availability`], then
+    /// the DOI if the work has one. The designator is left out when the
+    /// DOI replaces the URL.
+    pub(crate) fn ieee_location(self, available: Option<String>, doi: Option<&str>) -> String {
+        let Some(doi) = doi else {
+            return self.ieee_availability(available);
+        };
+        let doi = doi_element(CitationStyle::Ieee, doi);
+        match available {
+            Some(available) => format!("{} {}", self.ieee_availability(Some(available)), doi),
+            None => doi,
+        }
+    }
+}
+
+impl fmt::Display for Medium {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ieee_designator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::media::{MediaKind, medium::Medium};
+
+    #[test]
+    fn test_ieee_availability() {
+        assert_eq!(Medium::Online.to_string(), "[Online]");
+        assert_eq!(Medium::Online.ieee_availability(None), "[Online].");
+        assert_eq!(
+            Medium::OnlineVideo.ieee_availability(Some("https://example.com".to_string())),
+            "[Online Video]. Available: https://example.com"
+        );
+        assert_eq!(MediaKind::WebPage.medium(), Some(Medium::Online));
+        assert_eq!(MediaKind::JournalArticle.medium(), None);
+    }
+}
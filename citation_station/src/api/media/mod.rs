@@ -1,6 +1,136 @@
 pub mod book;
 pub mod common;
 pub mod conference_paper;
+pub mod conference_presentation;
+pub mod custom;
+pub mod journal_article;
+pub mod medium;
 pub mod online_manual;
 pub mod online_video;
+pub mod software;
 pub mod version;
+pub mod web_page;
+pub mod wikipedia_article;
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    media::{
+        conference_presentation::ConferencePresentation, medium::Medium,
+        online_manual::OnlineManual, online_video::OnlineVideo, software::Software,
+        web_page::WebPage, wikipedia_article::WikipediaArticle,
+    },
+    style::CitationStyle,
+};
+
+/// The media type of a citation, without its data.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum MediaKind {
+    Book,
+    ConferencePaperOnline,
+    ConferenceProceedingsOnline,
+    JournalArticle,
+    ConferencePresentation,
+    OnlineManual,
+    OnlineVideo,
+    Software,
+    WebPage,
+    WikipediaArticle,
+    /// A media type defined outside this crate, see [`custom::CitationLike`]
+    Custom,
+}
+
+impl MediaKind {
+    /// The built-in media types
+    pub const ALL: [MediaKind; 10] = [
+        MediaKind::Book,
+        MediaKind::ConferencePaperOnline,
+        MediaKind::ConferenceProceedingsOnline,
+        MediaKind::JournalArticle,
+        MediaKind::ConferencePresentation,
+        MediaKind::OnlineManual,
+        MediaKind::OnlineVideo,
+        MediaKind::Software,
+        MediaKind::WebPage,
+        MediaKind::WikipediaArticle,
+    ];
+
+    /// Display name, e.g. "Journal Article"
+    pub const fn name(self) -> &'static str {
+        match self {
+            MediaKind::Book => "Book",
+            MediaKind::ConferencePaperOnline => "Conference Paper",
+            MediaKind::ConferenceProceedingsOnline => "Conference Proceedings",
+            MediaKind::JournalArticle => "Journal Article",
+            MediaKind::ConferencePresentation => "Conference Presentation",
+            MediaKind::OnlineManual => "Online Manual",
+            MediaKind::OnlineVideo => "Online Video",
+            MediaKind::Software => "Software",
+            MediaKind::WebPage => "Web Page",
+            MediaKind::WikipediaArticle => "Wikipedia Article",
+            MediaKind::Custom => "Custom",
+        }
+    }
+
+    /// The medium named in IEEE references, if the media type has one
+    pub const fn medium(self) -> Option<Medium> {
+        match self {
+            MediaKind::ConferencePresentation => Some(ConferencePresentation::MEDIUM),
+            MediaKind::OnlineManual => Some(OnlineManual::MEDIUM),
+            MediaKind::OnlineVideo => Some(OnlineVideo::MEDIUM),
+            MediaKind::Software => Some(Software::MEDIUM),
+            MediaKind::WebPage => Some(WebPage::MEDIUM),
+            MediaKind::WikipediaArticle => Some(WikipediaArticle::MEDIUM),
+            MediaKind::Book
+            | MediaKind::ConferencePaperOnline
+            | MediaKind::ConferenceProceedingsOnline
+            | MediaKind::JournalArticle
+            | MediaKind::Custom => None,
+        }
+    }
+}
+
+impl fmt::Display for MediaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The author, date and title elements that start an APA reference,
+/// following APA 7's rules for missing elements: without an author the
+/// title moves to the author position, without a date "(n.d.)" is used,
+/// and without a title `description` is given in square brackets.
+///
+/// `author` is the terminated author list, `date` the text in the
+/// parentheses, and `title` the title element with its punctuation.
+pub(crate) fn apa_lead(
+    author: Option<String>,
+    date: Option<String>,
+    title: Option<String>,
+    description: &str,
+) -> Vec<String> {
+    let date = format!("({}).", date.as_deref().unwrap_or("n.d."));
+    let title = title.unwrap_or_else(|| format!("[{}].", description));
+    match author {
+        Some(author) => vec![author, date, title],
+        None => vec![title, date],
+    }
+}
+
+/// The DOI of an online work as written in `style`, e.g. "doi: 10.1000/xyz."
+/// in IEEE. It follows the URL, or takes its place when the style's
+/// [`UrlPolicy`] prefers the DOI.
+///
+/// [`UrlPolicy`]: crate::api::url::UrlPolicy
+pub(crate) fn doi_element(style: CitationStyle, doi: &str) -> String {
+    match style {
+        CitationStyle::Apa => format!("https://doi.org/{}", doi),
+        CitationStyle::Ieee => format!("doi: {}.", doi),
+        CitationStyle::Iso690 => format!("DOI: {}.", doi),
+        CitationStyle::Vancouver | CitationStyle::Ama => format!("doi:{}", doi),
+        CitationStyle::Acs => format!("DOI: {}", doi),
+    }
+}
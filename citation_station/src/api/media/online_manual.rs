@@ -2,13 +2,24 @@
 use serde::{Deserialize, Serialize};
 
 use crate::api::{
-    author::GenericAuthor,
-    citation::{ApaFormatting, IeeeFormatting},
-    date::AccessDate,
-    media::{common::CommonCitationData, version::GenericMediaVersion},
+    author::{
+        ACS_AUTHOR_LIST, AMA_AUTHOR_LIST, APA_AUTHOR_LIST, GenericAuthor, ISO_690_AUTHOR_LIST,
+        VANCOUVER_AUTHOR_LIST, punctuate,
+    },
+    citation::{
+        AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+        VancouverFormatting,
+    },
+    date::{
+        ACS_DATES, AMA_DATES, APA_DATES, AccessDate, IEEE_DATES, ISO_690_DATES, VANCOUVER_DATES,
+    },
+    media::{apa_lead, common::CommonCitationData, medium::Medium, version::GenericMediaVersion},
+    style::{CitationStyle, FormatOptions},
+    title::Title,
 };
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum OnlineManualAvailability {
     #[default]
     NotAvailable,
@@ -23,14 +34,15 @@ pub enum OnlineManualAvailability {
 /// * https://journals.ieeeauthorcenter.ieee.org/wp-content/uploads/sites/7/IEEE_Reference_Guide.pdf
 ///
 /// APA does not explicitly define formatting for online manuals citations.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct OnlineManual {
     /// Universal data shared between different media types
     pub common_data: CommonCitationData,
     /// Author
     pub author: GenericAuthor,
     /// Title
-    pub title: String,
+    pub title: Title,
     /// Version
     pub version: Option<GenericMediaVersion>,
     /// DOI, library database provider, or URL
@@ -39,59 +51,208 @@ pub struct OnlineManual {
     pub accessed: AccessDate,
 }
 
+impl OnlineManual {
+    /// The medium named in IEEE references
+    pub const MEDIUM: Medium = Medium::Online;
+
+    /// Where the manual can be found, for styles that write "Available from:".
+    /// A DOI among the identifiers replaces the URL if the URL policy says so.
+    fn available_from(&self, options: &FormatOptions) -> Option<String> {
+        match &self.available_at {
+            OnlineManualAvailability::NotAvailable => None,
+            OnlineManualAvailability::DOI(doi) => Some(format!("https://doi.org/{}", doi)),
+            OnlineManualAvailability::URL(url) => {
+                let doi = self.common_data.identifiers.doi();
+                options
+                    .url
+                    .apply(url, doi.is_some())
+                    .or_else(|| doi.map(|doi| format!("https://doi.org/{}", doi)))
+            }
+            OnlineManualAvailability::LibraryDatabaseProvider(provider) => Some(provider.clone()),
+        }
+    }
+}
+
 impl IeeeFormatting for OnlineManual {
-    fn citation_string(&self) -> String {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
         let mut parts: Vec<String> = Vec::new();
 
-        if let Some(author_formatted) = self.author.as_ieee_string() {
-            parts.push(format!("{}.", author_formatted));
+        if let Some(author_formatted) = self.author.as_ieee_string_with(options) {
+            parts.push(punctuate(&author_formatted, "."));
         }
 
+        let titles = options.titles();
         if let Some(version) = &self.version {
-            parts.push(format!("{} {}.", self.title, version.as_ieee_string()));
+            parts.push(format!(
+                "{} {}.",
+                titles.italic(&self.title.as_ieee_string(), ""),
+                version.as_ieee_string()
+            ));
         } else {
-            parts.push(format!("{}.", self.title));
+            parts.push(titles.italic(&self.title.as_ieee_string(), "."));
         }
 
         if let Some(published) = &self.common_data.published {
-            parts.push(format!("({}).", published.fmt_for_apa_citation()));
+            parts.push(format!("({}).", IEEE_DATES.published(published)));
         }
 
-        parts.push(format!(
-            "Accessed: {}. [Online].",
-            self.accessed.fmt_for_ieee_citation()
-        ));
+        parts.push(format!("{}.", IEEE_DATES.access_note(&self.accessed)));
+        match &self.available_at {
+            OnlineManualAvailability::NotAvailable => {
+                parts.push(Self::MEDIUM.ieee_availability(None));
+            }
+            OnlineManualAvailability::DOI(doi) => parts.push(format!("doi: {}.", doi)),
+            OnlineManualAvailability::URL(url) => {
+                let doi = self.common_data.identifiers.doi();
+                parts.push(Self::MEDIUM.ieee_location(options.url.apply(url, doi.is_some()), doi));
+            }
+            OnlineManualAvailability::LibraryDatabaseProvider(provider) => {
+                parts.push(Self::MEDIUM.ieee_availability(Some(format!("{}.", provider))));
+            }
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl ApaFormatting for OnlineManual {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let authors = self
+            .author
+            .as_apa_string_with(options)
+            .map(|authors| APA_AUTHOR_LIST.terminate(&authors));
+        let date = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| APA_DATES.published(published));
+        let title = (!self.title.is_empty())
+            .then(|| options.titles().italic(&self.title.as_apa_string(), "."));
+        let mut parts = apa_lead(authors, date, title, "Untitled manual");
 
         match &self.available_at {
             OnlineManualAvailability::NotAvailable => (),
-            OnlineManualAvailability::DOI(_) => todo!(),
-            OnlineManualAvailability::URL(url) => parts.push(format!("Available: {}", url.clone())),
-            OnlineManualAvailability::LibraryDatabaseProvider(_) => todo!(),
+            OnlineManualAvailability::DOI(doi) => parts.push(format!("https://doi.org/{}", doi)),
+            OnlineManualAvailability::URL(_) => {
+                if let Some(available) = self.available_from(options) {
+                    parts.push(available);
+                }
+            }
+            OnlineManualAvailability::LibraryDatabaseProvider(provider) => {
+                parts.push(format!("{}.", provider));
+            }
         }
 
         parts.join(" ")
     }
 }
 
-impl ApaFormatting for OnlineManual {
-    fn citation_string(&self) -> String {
+impl Iso690Formatting for OnlineManual {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
         let mut parts: Vec<String> = Vec::new();
 
-        if let Some(author_formatted) = self.author.as_apa_string() {
-            parts.push(format!("{}.", author_formatted));
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Iso690, options) {
+            parts.push(ISO_690_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(format!(
+            "{} [online].",
+            options.titles().italic(&self.title.as_apa_string(), "")
+        ));
+        if let Some(version) = &self.version {
+            parts.push(punctuate(&version.as_ieee_string(), "."));
         }
 
+        let viewed = format!("{}.", ISO_690_DATES.access_note(&self.accessed));
+        match &self.common_data.published {
+            Some(published) => parts.push(format!("{} {}", published.year(), viewed)),
+            None => parts.push(viewed),
+        }
+        if let Some(available) = self.available_from(options) {
+            parts.push(format!("Available from: {}", available));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for OnlineManual {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_string_with(CitationStyle::Vancouver, options)
+        {
+            parts.push(VANCOUVER_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(format!(
+            "{} [Internet].",
+            options.titles().plain(&self.title.as_apa_string(), "")
+        ));
+        if let Some(version) = &self.version {
+            parts.push(punctuate(&version.as_ieee_string(), "."));
+        }
+
+        let cited = format!("{}.", VANCOUVER_DATES.access_note(&self.accessed));
+        match &self.common_data.published {
+            Some(published) => parts.push(format!("{} {}", published.year(), cited)),
+            None => parts.push(cited),
+        }
+        if let Some(available) = self.available_from(options) {
+            parts.push(format!("Available from: {}", available));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for OnlineManual {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Ama, options) {
+            parts.push(AMA_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().italic(&self.title.as_apa_string(), "."));
+        if let Some(version) = &self.version {
+            parts.push(punctuate(&version.as_ieee_string(), "."));
+        }
         if let Some(published) = &self.common_data.published {
-            parts.push(format!("({}).", published.fmt_for_apa_citation()));
+            parts.push(format!("Published {}.", AMA_DATES.published(published)));
+        }
+        parts.push(format!("{}.", AMA_DATES.access_note(&self.accessed)));
+        if let Some(available) = self.available_from(options) {
+            parts.push(available);
         }
 
-        parts.push(format!("{}.", self.title));
+        parts.join(" ")
+    }
+}
 
-        match &self.available_at {
-            OnlineManualAvailability::NotAvailable => (),
-            OnlineManualAvailability::DOI(_) => todo!(),
-            OnlineManualAvailability::URL(url) => parts.push(url.clone()),
-            OnlineManualAvailability::LibraryDatabaseProvider(_) => todo!(),
+impl AcsFormatting for OnlineManual {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Acs, options) {
+            parts.push(ACS_AUTHOR_LIST.terminate(&authors));
+        }
+        let mut details = vec![match &self.version {
+            Some(version) => format!(
+                "{} {}",
+                options.titles().italic(&self.title.as_apa_string(), ","),
+                version.as_ieee_string()
+            ),
+            None => options.titles().italic(&self.title.as_apa_string(), ""),
+        }];
+        if let Some(published) = &self.common_data.published {
+            details.push(published.year().to_string());
+        }
+        parts.push(punctuate(&details.join("; "), "."));
+
+        let accessed = format!("{}.", ACS_DATES.access_note(&self.accessed));
+        match self.available_from(options) {
+            Some(available) => parts.push(format!("{} {}", available, accessed)),
+            None => parts.push(accessed),
         }
 
         parts.join(" ")
@@ -100,36 +261,42 @@ impl ApaFormatting for OnlineManual {
 
 #[cfg(test)]
 mod tests {
-    use chrono::NaiveDate;
+    use chrono::{Month, NaiveDate};
 
     use crate::api::{
         author::{GenericAuthor, PersonName},
-        citation::IeeeFormatting,
+        citation::{ApaFormatting, IeeeFormatting},
         date::PublishDate,
         media::{
             common::CommonCitationData,
             online_manual::{OnlineManual, OnlineManualAvailability},
             version::{GenericMediaVersion, SemVer},
         },
+        style::{CitationStyle, FormatOptions},
+        url::UrlPolicy,
     };
 
-    #[test]
-    fn test_format_online_manual_ieee() {
-        let manual = OnlineManual {
+    fn manual() -> OnlineManual {
+        OnlineManual {
             common_data: CommonCitationData {
-                id: "foo".to_string(),
                 published: Some(PublishDate::from_year(2003)),
+                ..CommonCitationData::new("foo")
             },
             author: GenericAuthor::Persons {
                 persons: vec![PersonName::from_first_last("L", "Breimann").unwrap()],
             },
-            title: "Manual on Setting Up, Using, and Understanding Random Forests".to_string(),
+            title: "Manual on Setting Up, Using, and Understanding Random Forests".into(),
             version: Some(GenericMediaVersion::SemVer(SemVer::from_major_minor(4, 0))),
             available_at: OnlineManualAvailability::URL(
                 "http://oz.berkeley.edu/users/breiman/Using_random_forests_v4.0.pdf".to_string(),
             ),
             accessed: NaiveDate::from_ymd_opt(2014, 4, 16).unwrap().into(),
-        };
+        }
+    }
+
+    #[test]
+    fn test_format_online_manual_ieee() {
+        let manual = manual();
 
         let expect = "L. Breimann. Manual on Setting Up, Using, and Understanding Random Forests \
                                     v4.0. (2003). Accessed: Apr. 16, 2014. [Online]. Available: \
@@ -137,4 +304,90 @@ mod tests {
 
         assert_eq!(IeeeFormatting::citation_string(&manual), expect)
     }
+
+    #[test]
+    fn test_format_online_manual_ieee_full_date() {
+        let mut manual = manual();
+        manual.common_data.published =
+            Some(PublishDate::from_year_month_day(2003, Month::March, 5).unwrap());
+
+        assert!(
+            IeeeFormatting::citation_string(&manual)
+                .contains("v4.0. (Mar. 5, 2003). Accessed: Apr. 16, 2014.")
+        );
+    }
+
+    #[test]
+    fn test_format_online_manual_ieee_shortened_url() {
+        let options = FormatOptions {
+            url: UrlPolicy {
+                prefer_doi: true,
+                strip_query: true,
+                shorten: true,
+                break_long: false,
+            },
+            ..FormatOptions::for_style(CitationStyle::Ieee)
+        };
+
+        let formatted = IeeeFormatting::citation_string_with(&manual(), &options);
+
+        assert!(
+            formatted.ends_with(
+                "Available: oz.berkeley.edu/users/breiman/Using_random_forests_v4.0.pdf"
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_online_manual_ieee_doi() {
+        let manual = OnlineManual {
+            available_at: OnlineManualAvailability::DOI("10.1000/rf.4".to_string()),
+            ..manual()
+        };
+
+        assert!(
+            IeeeFormatting::citation_string(&manual)
+                .ends_with("(2003). Accessed: Apr. 16, 2014. doi: 10.1000/rf.4.")
+        );
+    }
+
+    #[test]
+    fn test_format_online_manual_ieee_library_database() {
+        let manual = OnlineManual {
+            available_at: OnlineManualAvailability::LibraryDatabaseProvider("ProQuest".to_string()),
+            ..manual()
+        };
+
+        assert!(
+            IeeeFormatting::citation_string(&manual)
+                .ends_with("Accessed: Apr. 16, 2014. [Online]. Available: ProQuest.")
+        );
+    }
+
+    #[test]
+    fn test_format_online_manual_apa_doi() {
+        let manual = OnlineManual {
+            available_at: OnlineManualAvailability::DOI("10.1000/rf.4".to_string()),
+            ..manual()
+        };
+
+        assert_eq!(
+            ApaFormatting::citation_string(&manual),
+            "Breimann, L. (2003). Manual on Setting Up, Using, and Understanding Random \
+             Forests. https://doi.org/10.1000/rf.4"
+        );
+    }
+
+    #[test]
+    fn test_format_online_manual_apa_library_database() {
+        let manual = OnlineManual {
+            available_at: OnlineManualAvailability::LibraryDatabaseProvider("ProQuest".to_string()),
+            ..manual()
+        };
+
+        assert!(
+            ApaFormatting::citation_string(&manual)
+                .ends_with("Understanding Random Forests. ProQuest.")
+        );
+    }
 }
@@ -3,9 +3,16 @@
 use serde::{Deserialize, Serialize};
 
 use crate::api::{
-    citation::{ApaFormatting, IeeeFormatting},
-    date::{AccessDate, ieee_abbreviated_month_name},
-    media::common::CommonCitationData,
+    citation::{
+        AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+        VancouverFormatting,
+    },
+    date::{
+        ACS_DATES, AMA_DATES, APA_DATES, AccessDate, IEEE_DATES, ISO_690_DATES, VANCOUVER_DATES,
+    },
+    media::{apa_lead, common::CommonCitationData, doi_element, medium::Medium},
+    style::{CitationStyle, FormatOptions},
+    title::Title,
 };
 
 /// A video that was accessed via the internet.
@@ -15,17 +22,19 @@ use crate::api::{
 ///
 /// APA formatting rules taken from:
 /// * https://apastyle.apa.org/style-grammar-guidelines/references/examples/youtube-references
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum OnlineVideo {
     Generic {
         common_data: CommonCitationData,
-        title: String,
+        title: Title,
         url: Option<String>,
         accessed: AccessDate,
     },
     YouTube {
         common_data: CommonCitationData,
-        title: String,
+        title: Title,
         url: Option<String>,
         channel: String,
         accessed: AccessDate,
@@ -33,9 +42,27 @@ pub enum OnlineVideo {
 }
 
 impl IeeeFormatting for OnlineVideo {
-    fn citation_string(&self) -> String {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
         match self {
-            OnlineVideo::Generic { .. } => todo!(),
+            OnlineVideo::Generic {
+                common_data,
+                title,
+                url: maybe_url,
+                accessed,
+            } => {
+                let mut parts: Vec<String> =
+                    vec![options.titles().plain(&title.as_ieee_string(), ".")];
+                if let Some(published) = &common_data.published {
+                    parts.push(format!("({}).", IEEE_DATES.published(published)));
+                }
+                parts.push(format!("{}.", IEEE_DATES.access_note(accessed)));
+                let doi = common_data.identifiers.doi();
+                let url = maybe_url
+                    .as_ref()
+                    .and_then(|url| options.url.apply(url, doi.is_some()));
+                parts.push(Self::MEDIUM.ieee_location(url, doi));
+                parts.join(" ")
+            }
             OnlineVideo::YouTube {
                 common_data,
                 title,
@@ -45,19 +72,16 @@ impl IeeeFormatting for OnlineVideo {
             } => {
                 let mut parts: Vec<String> = vec![format!("{}.", channel)];
                 // TODO: owner location
-                parts.push(format!("{}.", title));
+                parts.push(options.titles().plain(&title.as_ieee_string(), "."));
                 if let Some(published) = &common_data.published {
-                    parts.push(format!("({}).", published.fmt_for_ieee_citation()));
-                }
-                parts.push(format!(
-                    "Accessed: {} {}, {}. [Online Video].",
-                    ieee_abbreviated_month_name(&accessed.month()),
-                    accessed.day(),
-                    accessed.year(),
-                ));
-                if let Some(url) = maybe_url {
-                    parts.push(format!("Available: {}", url.to_string()));
+                    parts.push(format!("({}).", IEEE_DATES.published(published)));
                 }
+                parts.push(format!("{}.", IEEE_DATES.access_note(accessed)));
+                let doi = common_data.identifiers.doi();
+                let url = maybe_url
+                    .as_ref()
+                    .and_then(|url| options.url.apply(url, doi.is_some()));
+                parts.push(Self::MEDIUM.ieee_location(url, doi));
                 parts.join(" ")
             }
         }
@@ -65,9 +89,40 @@ impl IeeeFormatting for OnlineVideo {
 }
 
 impl ApaFormatting for OnlineVideo {
-    fn citation_string(&self) -> String {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
         match self {
-            OnlineVideo::Generic { .. } => todo!(),
+            OnlineVideo::Generic {
+                common_data,
+                title,
+                url: maybe_url,
+                accessed,
+            } => {
+                let date = common_data
+                    .published
+                    .as_ref()
+                    .map(|published| APA_DATES.published(published));
+                let title = (!title.is_empty()).then(|| {
+                    format!(
+                        "{} [Video].",
+                        options.titles().italic(&title.as_apa_string(), "")
+                    )
+                });
+                let mut parts = apa_lead(None, date, title, "Video");
+                let retrieved = APA_DATES.access_note(accessed);
+                let doi = common_data.identifiers.doi();
+                match maybe_url
+                    .as_ref()
+                    .and_then(|url| options.url.apply(url, doi.is_some()))
+                {
+                    Some(url) => parts.push(format!("{}, from {}", retrieved, url)),
+                    None if doi.is_some() => {}
+                    None => parts.push(format!("{}.", retrieved)),
+                }
+                if let Some(doi) = doi {
+                    parts.push(doi_element(CitationStyle::Apa, doi));
+                }
+                parts.join(" ")
+            }
             OnlineVideo::YouTube {
                 common_data,
                 title,
@@ -77,24 +132,24 @@ impl ApaFormatting for OnlineVideo {
             } => {
                 let mut parts: Vec<String> = vec![format!("{}.", channel)];
                 if let Some(published) = &common_data.published {
-                    parts.push(format!("({}).", published.fmt_for_apa_citation()));
+                    parts.push(format!("({}).", APA_DATES.published(published)));
                 }
-                parts.push(format!("{} [Video]. YouTube.", title));
-                if let Some(url) = maybe_url {
-                    parts.push(format!(
-                        "Retrieved {} {}, {}, from {}",
-                        accessed.month().name(),
-                        accessed.day(),
-                        accessed.year(),
-                        url.to_string()
-                    ));
-                } else {
-                    parts.push(format!(
-                        "Retrieved {} {}, {}.",
-                        accessed.month().name(),
-                        accessed.day(),
-                        accessed.year()
-                    ));
+                parts.push(format!(
+                    "{} [Video]. YouTube.",
+                    options.titles().italic(&title.as_apa_string(), "")
+                ));
+                let retrieved = APA_DATES.access_note(accessed);
+                let doi = common_data.identifiers.doi();
+                match maybe_url
+                    .as_ref()
+                    .and_then(|url| options.url.apply(url, doi.is_some()))
+                {
+                    Some(url) => parts.push(format!("{}, from {}", retrieved, url)),
+                    None if doi.is_some() => {}
+                    None => parts.push(format!("{}.", retrieved)),
+                }
+                if let Some(doi) = doi {
+                    parts.push(doi_element(CitationStyle::Apa, doi));
                 }
                 parts.join(" ")
             }
@@ -102,6 +157,160 @@ impl ApaFormatting for OnlineVideo {
     }
 }
 
+impl OnlineVideo {
+    /// The medium named in IEEE references
+    pub const MEDIUM: Medium = Medium::OnlineVideo;
+
+    fn common_parts(&self) -> (&CommonCitationData, &Title, Option<&String>, &AccessDate) {
+        match self {
+            OnlineVideo::Generic {
+                common_data,
+                title,
+                url,
+                accessed,
+            }
+            | OnlineVideo::YouTube {
+                common_data,
+                title,
+                url,
+                accessed,
+                ..
+            } => (common_data, title, url.as_ref(), accessed),
+        }
+    }
+}
+
+impl Iso690Formatting for OnlineVideo {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let (common_data, title, url, accessed) = self.common_parts();
+        let mut parts: Vec<String> = Vec::new();
+
+        if let OnlineVideo::YouTube { channel, .. } = self {
+            parts.push(format!("{}.", channel));
+        }
+        parts.push(format!(
+            "{} [video].",
+            options.titles().italic(&title.as_apa_string(), "")
+        ));
+        let mut source: Vec<String> = Vec::new();
+        if let OnlineVideo::YouTube { .. } = self {
+            source.push("YouTube".to_string());
+        }
+        if let Some(published) = &common_data.published {
+            source.push(published.year().to_string());
+        }
+        source.push(format!("{}.", ISO_690_DATES.access_note(accessed)));
+        parts.push(match source.as_slice() {
+            [only] => only.clone(),
+            [init @ .., last] => format!("{} {}", init.join(", "), last),
+            [] => unreachable!(),
+        });
+        let doi = common_data.identifiers.doi();
+        if let Some(url) = url.and_then(|url| options.url.apply(url, doi.is_some())) {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Iso690, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for OnlineVideo {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let (common_data, title, url, accessed) = self.common_parts();
+        let mut parts: Vec<String> = Vec::new();
+
+        if let OnlineVideo::YouTube { channel, .. } = self {
+            parts.push(format!("{}.", channel));
+        }
+        parts.push(format!(
+            "{} [video on the Internet].",
+            options.titles().plain(&title.as_apa_string(), "")
+        ));
+        let cited = format!("{}.", VANCOUVER_DATES.access_note(accessed));
+        let date = match &common_data.published {
+            Some(published) => format!("{} {}", VANCOUVER_DATES.published(published), cited),
+            None => cited,
+        };
+        match self {
+            OnlineVideo::YouTube { .. } => parts.push(format!("YouTube; {}", date)),
+            OnlineVideo::Generic { .. } => parts.push(date),
+        }
+        let doi = common_data.identifiers.doi();
+        if let Some(url) = url.and_then(|url| options.url.apply(url, doi.is_some())) {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Vancouver, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for OnlineVideo {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let (common_data, title, url, accessed) = self.common_parts();
+        let mut parts: Vec<String> = Vec::new();
+
+        if let OnlineVideo::YouTube { channel, .. } = self {
+            parts.push(format!("{}.", channel));
+        }
+        parts.push(options.titles().plain(&title.as_apa_string(), "."));
+        if let OnlineVideo::YouTube { .. } = self {
+            parts.push("YouTube.".to_string());
+        }
+        if let Some(published) = &common_data.published {
+            parts.push(format!("Published {}.", AMA_DATES.published(published)));
+        }
+        parts.push(format!("{}.", AMA_DATES.access_note(accessed)));
+        let doi = common_data.identifiers.doi();
+        if let Some(url) = url.and_then(|url| options.url.apply(url, doi.is_some())) {
+            parts.push(url);
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Ama, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AcsFormatting for OnlineVideo {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let (common_data, title, url, accessed) = self.common_parts();
+        let mut parts: Vec<String> = Vec::new();
+
+        if let OnlineVideo::YouTube { channel, .. } = self {
+            parts.push(format!("{}.", channel));
+        }
+        parts.push(options.titles().plain(&title.as_apa_string(), "."));
+        let mut source: Vec<String> = Vec::new();
+        if let OnlineVideo::YouTube { .. } = self {
+            source.push("YouTube".to_string());
+        }
+        if let Some(published) = &common_data.published {
+            source.push(published.year().to_string());
+        }
+        if !source.is_empty() {
+            parts.push(format!("{}.", source.join(", ")));
+        }
+        let accessed = format!("{}.", ACS_DATES.access_note(accessed));
+        let doi = common_data.identifiers.doi();
+        match url.and_then(|url| options.url.apply(url, doi.is_some())) {
+            Some(url) => parts.push(format!("{} {}", url, accessed)),
+            None => parts.push(accessed),
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Acs, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{Month, NaiveDate};
@@ -116,10 +325,10 @@ mod tests {
     fn test_youtube_video_ieee_formatting() {
         let video = OnlineVideo::YouTube {
             common_data: CommonCitationData {
-                id: "foo".to_string(),
                 published: Some(PublishDate::from_year_month_day(2009, Month::April, 4).unwrap()),
+                ..CommonCitationData::new("foo")
             },
-            title: "Tribute to anomalocaris".to_string(),
+            title: "Tribute to anomalocaris".into(),
             url: Some("https://www.youtube.com/watch?v=6YsNRnZRgg8".to_string()),
             channel: "scorpiopede".to_string(),
             accessed: NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into(),
@@ -135,10 +344,10 @@ mod tests {
     fn test_youtube_video_apa_formatting() {
         let video = OnlineVideo::YouTube {
             common_data: CommonCitationData {
-                id: "foo".to_string(),
                 published: Some(PublishDate::from_year_month_day(2009, Month::April, 4).unwrap()),
+                ..CommonCitationData::new("foo")
             },
-            title: "Tribute to anomalocaris".to_string(),
+            title: "Tribute to anomalocaris".into(),
             url: Some("https://www.youtube.com/watch?v=6YsNRnZRgg8".to_string()),
             channel: "scorpiopede".to_string(),
             accessed: NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into(),
@@ -149,4 +358,23 @@ mod tests {
             "scorpiopede. (2009, April 4). Tribute to anomalocaris [Video]. YouTube. Retrieved October 1, 2025, from https://www.youtube.com/watch?v=6YsNRnZRgg8"
         )
     }
+
+    #[test]
+    fn test_generic_video_formatting() {
+        let video = OnlineVideo::Generic {
+            common_data: CommonCitationData::new("talk"),
+            title: "Ferris explains lifetimes".into(),
+            url: Some("https://example.com/lifetimes.mp4".to_string()),
+            accessed: NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into(),
+        };
+
+        assert_eq!(
+            IeeeFormatting::citation_string(&video),
+            "Ferris explains lifetimes. Accessed: Oct. 1, 2025. [Online Video]. Available: https://example.com/lifetimes.mp4"
+        );
+        assert_eq!(
+            ApaFormatting::citation_string(&video),
+            "Ferris explains lifetimes [Video]. (n.d.). Retrieved October 1, 2025, from https://example.com/lifetimes.mp4"
+        );
+    }
 }
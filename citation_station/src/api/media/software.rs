@@ -0,0 +1,440 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        author::{
+            ACS_AUTHOR_LIST, AMA_AUTHOR_LIST, APA_AUTHOR_LIST, GenericAuthor, IEEE_AUTHOR_LIST,
+            ISO_690_AUTHOR_LIST, VANCOUVER_AUTHOR_LIST, punctuate,
+        },
+        citation::{
+            AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+            VancouverFormatting,
+        },
+        date::{ACS_DATES, AMA_DATES, AccessDate, IEEE_DATES, ISO_690_DATES, VANCOUVER_DATES},
+        media::{apa_lead, common::CommonCitationData, doi_element, medium::Medium},
+        style::{CitationStyle, FormatOptions},
+        title::Title,
+    },
+    cff::{CffError, software_from_cff, software_to_cff},
+    import::{
+        ImportError,
+        github::{fetch_github_repo, fetch_github_repo_async},
+    },
+    net::{AsyncHttpClient, HttpClient},
+};
+
+/// A computer program, library or source code repository.
+///
+/// IEEE formatting rules taken from:
+/// * https://journals.ieeeauthorcenter.ieee.org/wp-content/uploads/sites/7/IEEE_Reference_Guide.pdf
+///
+/// APA formatting rules taken from:
+/// * https://apastyle.apa.org/style-grammar-guidelines/references/examples/software-references
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Software {
+    pub common_data: CommonCitationData,
+    /// Author
+    pub author: GenericAuthor,
+    /// Name of the program
+    pub title: Title,
+    /// Version or release, e.g. "1.2.0"
+    pub version: Option<String>,
+    /// Publisher or hosting platform, e.g. "GitHub"
+    pub publisher: Option<String>,
+    pub url: Option<String>,
+    /// DOI (Digital Object Identifier), e.g. of an archived release
+    pub doi: Option<String>,
+    /// When the software was retrieved
+    pub accessed: AccessDate,
+}
+
+impl Software {
+    /// The medium named in IEEE references
+    pub const MEDIUM: Medium = Medium::Online;
+
+    /// Import a GitHub repository: its name, canonical URL, latest
+    /// release, and the authors listed in its CITATION.cff. See
+    /// [`fetch_github_repo`].
+    #[cfg(feature = "net")]
+    pub fn from_github_repo(owner: &str, repo: &str) -> Result<Self, ImportError> {
+        Self::from_github_repo_with(&crate::net::UreqClient::new(), owner, repo)
+    }
+
+    /// Like [`Software::from_github_repo`], fetching with `client`.
+    pub fn from_github_repo_with<C: HttpClient + ?Sized>(
+        client: &C,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Self, ImportError> {
+        fetch_github_repo(client, owner, repo)
+    }
+
+    /// Like [`Software::from_github_repo`], fetching with an
+    /// [`AsyncHttpClient`] such as the browser's.
+    pub async fn from_github_repo_async<C: AsyncHttpClient>(
+        client: &C,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Self, ImportError> {
+        fetch_github_repo_async(client, owner, repo).await
+    }
+
+    /// Read a CITATION.cff file. See [`software_from_cff`].
+    pub fn from_cff(text: &str) -> Result<Self, CffError> {
+        software_from_cff(text)
+    }
+
+    /// Write a CITATION.cff file. See [`software_to_cff`].
+    pub fn to_cff(&self) -> String {
+        software_to_cff(self)
+    }
+
+    /// The publisher, unless it is also the author
+    fn distinct_publisher(&self) -> Option<&str> {
+        let publisher = self.publisher.as_deref()?;
+        match &self.author {
+            GenericAuthor::Organization { name, .. } if name == publisher => None,
+            _ => Some(publisher),
+        }
+    }
+}
+
+impl IeeeFormatting for Software {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_ieee_string_with(options) {
+            parts.push(IEEE_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().plain(&self.title.as_ieee_string(), "."));
+        if let Some(version) = &self.version {
+            parts.push(format!("({}).", version));
+        }
+
+        let mut imprint: Vec<String> = Vec::new();
+        if let Some(publisher) = self.distinct_publisher() {
+            imprint.push(publisher.to_string());
+        }
+        if let Some(published) = &self.common_data.published {
+            imprint.push(published.year().to_string());
+        }
+        if !imprint.is_empty() {
+            parts.push(format!("{}.", imprint.join(", ")));
+        }
+
+        parts.push(format!("{}.", IEEE_DATES.access_note(&self.accessed)));
+        let url = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, self.doi.is_some()));
+        parts.push(Self::MEDIUM.ieee_location(url, self.doi.as_deref()));
+
+        parts.join(" ")
+    }
+}
+
+impl ApaFormatting for Software {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let authors = self
+            .author
+            .as_apa_string_with(options)
+            .map(|authors| APA_AUTHOR_LIST.terminate(&authors));
+        let date = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| published.year().to_string());
+        let title = (!self.title.is_empty()).then(|| {
+            let title = options.titles().italic(&self.title.as_apa_string(), "");
+            match &self.version {
+                Some(version) => format!("{} (Version {}) [Computer software].", title, version),
+                None => format!("{} [Computer software].", title),
+            }
+        });
+        let mut parts = apa_lead(authors, date, title, "Computer software");
+        if let Some(publisher) = self.distinct_publisher() {
+            parts.push(format!("{}.", publisher));
+        }
+
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, self.doi.is_some()))
+        {
+            parts.push(url);
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(doi_element(CitationStyle::Apa, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl Iso690Formatting for Software {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Iso690, options) {
+            parts.push(ISO_690_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(format!(
+            "{} [software].",
+            options.titles().italic(&self.title.as_apa_string(), "")
+        ));
+        if let Some(version) = &self.version {
+            parts.push(format!("Version {}.", version));
+        }
+
+        let mut source: Vec<String> = Vec::new();
+        if let Some(publisher) = self.distinct_publisher() {
+            source.push(publisher.to_string());
+        }
+        if let Some(published) = &self.common_data.published {
+            source.push(published.year().to_string());
+        }
+        let viewed = format!("{}.", ISO_690_DATES.access_note(&self.accessed));
+        match source.is_empty() {
+            true => parts.push(viewed),
+            false => parts.push(format!("{} {}", source.join(", "), viewed)),
+        }
+
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, self.doi.is_some()))
+        {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(doi_element(CitationStyle::Iso690, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for Software {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_string_with(CitationStyle::Vancouver, options)
+        {
+            parts.push(VANCOUVER_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(format!(
+            "{} [software].",
+            options.titles().plain(&self.title.as_apa_string(), "")
+        ));
+        if let Some(version) = &self.version {
+            parts.push(format!("Version {}.", version));
+        }
+
+        let mut source: Vec<String> = Vec::new();
+        if let Some(publisher) = self.distinct_publisher() {
+            source.push(publisher.to_string());
+        }
+        if let Some(published) = &self.common_data.published {
+            source.push(published.year().to_string());
+        }
+        let cited = format!("{}.", VANCOUVER_DATES.access_note(&self.accessed));
+        match source.is_empty() {
+            true => parts.push(cited),
+            false => parts.push(format!("{} {}", source.join("; "), cited)),
+        }
+
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, self.doi.is_some()))
+        {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("doi:{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for Software {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Ama, options) {
+            parts.push(AMA_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().italic(&self.title.as_apa_string(), "."));
+        if let Some(version) = &self.version {
+            parts.push(format!("Version {}.", version));
+        }
+
+        let mut source: Vec<String> = Vec::new();
+        if let Some(publisher) = self.distinct_publisher() {
+            source.push(publisher.to_string());
+        }
+        if let Some(published) = &self.common_data.published {
+            source.push(published.year().to_string());
+        }
+        if !source.is_empty() {
+            parts.push(format!("{}.", source.join("; ")));
+        }
+        parts.push(format!("{}.", AMA_DATES.access_note(&self.accessed)));
+        if let Some(url) = self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, self.doi.is_some()))
+        {
+            parts.push(url);
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("doi:{}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AcsFormatting for Software {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self.author.as_string_with(CitationStyle::Acs, options) {
+            parts.push(ACS_AUTHOR_LIST.terminate(&authors));
+        }
+        // e.g. "Title, version 1.2; Publisher, 2023."
+        let title = match &self.version {
+            Some(version) => format!(
+                "{} version {}",
+                options.titles().italic(&self.title.as_apa_string(), ","),
+                version
+            ),
+            None => options.titles().italic(&self.title.as_apa_string(), ""),
+        };
+        let mut source: Vec<String> = Vec::new();
+        if let Some(publisher) = self.distinct_publisher() {
+            source.push(publisher.to_string());
+        }
+        if let Some(published) = &self.common_data.published {
+            source.push(published.year().to_string());
+        }
+        match source.is_empty() {
+            true => parts.push(punctuate(&title, ".")),
+            false => parts.push(format!("{}; {}.", title, source.join(", "))),
+        }
+
+        let accessed = format!("{}.", ACS_DATES.access_note(&self.accessed));
+        match self
+            .url
+            .as_ref()
+            .and_then(|url| options.url.apply(url, self.doi.is_some()))
+        {
+            Some(url) => parts.push(format!("{} {}", url, accessed)),
+            None if self.doi.is_none() => parts.push(accessed),
+            None => {}
+        }
+        if let Some(doi) = &self.doi {
+            parts.push(format!("DOI: {}", doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveDate};
+
+    use crate::api::{
+        author::{GenericAuthor, PersonName},
+        citation::{ApaFormatting, IeeeFormatting},
+        date::PublishDate,
+        media::{common::CommonCitationData, software::Software},
+        style::{CitationStyle, FormatOptions},
+        url::UrlPolicy,
+    };
+
+    fn software() -> Software {
+        Software {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month(2025, Month::June)),
+                ..CommonCitationData::new("citation_station")
+            },
+            author: GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            title: "citation_station".into(),
+            version: Some("0.3.1".to_string()),
+            publisher: Some("GitHub".to_string()),
+            url: Some("https://github.com/example/citation_station".to_string()),
+            doi: None,
+            accessed: NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into(),
+        }
+    }
+
+    #[test]
+    fn test_software_apa_formatting() {
+        assert_eq!(
+            ApaFormatting::citation_string(&software()),
+            "Smith, J. (2025). citation_station (Version 0.3.1) [Computer software]. GitHub. \
+             https://github.com/example/citation_station"
+        );
+    }
+
+    #[test]
+    fn test_software_ieee_formatting() {
+        assert_eq!(
+            IeeeFormatting::citation_string(&software()),
+            "J. Smith, citation_station. (0.3.1). GitHub, 2025. Accessed: Oct. 1, 2025. \
+             [Online]. Available: https://github.com/example/citation_station"
+        );
+    }
+
+    #[test]
+    fn test_software_apa_formatting_publisher_is_author() {
+        let mut software = software();
+        software.author = GenericAuthor::Organization {
+            name: "GitHub".to_string(),
+            abbreviation: None,
+        };
+        software.version = None;
+        software.common_data.published = None;
+        software.doi = Some("10.5281/zenodo.1234".to_string());
+
+        assert_eq!(
+            ApaFormatting::citation_string(&software),
+            "GitHub. (n.d.). citation_station [Computer software]. \
+             https://doi.org/10.5281/zenodo.1234"
+        );
+    }
+
+    #[test]
+    fn test_software_ieee_formatting_prefers_doi() {
+        let mut software = software();
+        software.doi = Some("10.5281/zenodo.1234".to_string());
+
+        assert!(
+            IeeeFormatting::citation_string(&software)
+                .ends_with("Accessed: Oct. 1, 2025. doi: 10.5281/zenodo.1234.")
+        );
+
+        // Without the preference, the URL is kept as well
+        let options = FormatOptions {
+            url: UrlPolicy {
+                prefer_doi: false,
+                ..UrlPolicy::verbatim()
+            },
+            ..FormatOptions::for_style(CitationStyle::Ieee)
+        };
+        assert!(
+            IeeeFormatting::citation_string_with(&software, &options).ends_with(
+                "[Online]. Available: https://github.com/example/citation_station \
+                 doi: 10.5281/zenodo.1234."
+            )
+        );
+    }
+}
@@ -1,14 +1,72 @@
 use ordinal::ToOrdinal as _;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use thiserror::Error;
 
 use crate::unicode::EMDASH;
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum SemVerError {
+    #[error("Invalid version number: {0}")]
+    InvalidNumber(String),
+    #[error("Invalid pre-release or build identifier: {0}")]
+    InvalidIdentifier(String),
+}
+
+/// A semantic version as used by software releases.
+///
+/// Pre-release tags and build metadata follow https://semver.org and are
+/// only available on full `major.minor.patch` versions.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum SemVer {
-    Major { major: u32 },
-    MajorMinor { major: u32, minor: u32 },
-    MajorMinorPatch { major: u32, minor: u32, patch: u32 },
+    Major {
+        major: u32,
+    },
+    MajorMinor {
+        major: u32,
+        minor: u32,
+    },
+    MajorMinorPatch {
+        major: u32,
+        minor: u32,
+        patch: u32,
+        /// e.g. "rc.1" in "1.0.0-rc.1"
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pre_release: Option<String>,
+        /// e.g. "20240101" in "1.0.0+20240101"
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        build: Option<String>,
+    },
+}
+
+/// Check dot-separated identifiers: non-empty, `[0-9A-Za-z-]` only, and
+/// (for pre-release tags) no leading zeros on numeric identifiers.
+fn validate_identifiers(identifiers: &str, allow_leading_zeros: bool) -> Result<(), SemVerError> {
+    let invalid = || SemVerError::InvalidIdentifier(identifiers.to_string());
+    for identifier in identifiers.split('.') {
+        if identifier.is_empty()
+            || !identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return Err(invalid());
+        }
+        let numeric = identifier.chars().all(|c| c.is_ascii_digit());
+        if numeric && !allow_leading_zeros && identifier.len() > 1 && identifier.starts_with('0') {
+            return Err(invalid());
+        }
+    }
+    Ok(())
+}
+
+fn parse_number(s: &str) -> Result<u32, SemVerError> {
+    if s.len() > 1 && s.starts_with('0') {
+        return Err(SemVerError::InvalidNumber(s.to_string()));
+    }
+    s.parse()
+        .map_err(|_| SemVerError::InvalidNumber(s.to_string()))
 }
 
 impl SemVer {
@@ -25,6 +83,98 @@ impl SemVer {
             major,
             minor,
             patch,
+            pre_release: None,
+            build: None,
+        }
+    }
+
+    /// Parse versions like "2", "4.0", "1.0.0-rc.1" or "v1.2.3+build.5".
+    pub fn parse(s: &str) -> Result<Self, SemVerError> {
+        let s = s.trim();
+        let s = s.strip_prefix(['v', 'V']).unwrap_or(s);
+        let (s, build) = match s.split_once('+') {
+            Some((version, build)) => (version, Some(build)),
+            None => (s, None),
+        };
+        let (core, pre_release) = match s.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (s, None),
+        };
+
+        let numbers = core
+            .split('.')
+            .map(parse_number)
+            .collect::<Result<Vec<u32>, SemVerError>>()?;
+        let version = match numbers.as_slice() {
+            [major] => Self::from_major(*major),
+            [major, minor] => Self::from_major_minor(*major, *minor),
+            [major, minor, patch] => Self::from_major_minor_patch(*major, *minor, *patch),
+            _ => return Err(SemVerError::InvalidNumber(core.to_string())),
+        };
+
+        let version = match pre_release {
+            Some(pre_release) => version.with_pre_release(pre_release)?,
+            None => version,
+        };
+        match build {
+            Some(build) => version.with_build(build),
+            None => Ok(version),
+        }
+    }
+
+    /// Attach a pre-release tag such as "rc.1". Shorter versions are
+    /// expanded to `major.minor.patch` first, since semver only allows
+    /// pre-release tags on full versions.
+    pub fn with_pre_release(self, pre_release: &str) -> Result<Self, SemVerError> {
+        validate_identifiers(pre_release, false)?;
+        let (major, minor, patch, _, build) = self.into_parts();
+        Ok(SemVer::MajorMinorPatch {
+            major,
+            minor,
+            patch,
+            pre_release: Some(pre_release.to_string()),
+            build,
+        })
+    }
+
+    /// Attach build metadata such as "20240101.sha.5114f85".
+    pub fn with_build(self, build: &str) -> Result<Self, SemVerError> {
+        validate_identifiers(build, true)?;
+        let (major, minor, patch, pre_release, _) = self.into_parts();
+        Ok(SemVer::MajorMinorPatch {
+            major,
+            minor,
+            patch,
+            pre_release,
+            build: Some(build.to_string()),
+        })
+    }
+
+    fn into_parts(self) -> (u32, u32, u32, Option<String>, Option<String>) {
+        match self {
+            SemVer::Major { major } => (major, 0, 0, None, None),
+            SemVer::MajorMinor { major, minor } => (major, minor, 0, None, None),
+            SemVer::MajorMinorPatch {
+                major,
+                minor,
+                patch,
+                pre_release,
+                build,
+            } => (major, minor, patch, pre_release, build),
+        }
+    }
+
+    pub fn pre_release(&self) -> Option<&str> {
+        match self {
+            SemVer::MajorMinorPatch { pre_release, .. } => pre_release.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn build(&self) -> Option<&str> {
+        match self {
+            SemVer::MajorMinorPatch { build, .. } => build.as_deref(),
+            _ => None,
         }
     }
 }
@@ -38,12 +188,24 @@ impl fmt::Display for SemVer {
                 major,
                 minor,
                 patch,
-            } => write!(f, "{}.{}.{}", major, minor, patch),
+                pre_release,
+                build,
+            } => {
+                write!(f, "{}.{}.{}", major, minor, patch)?;
+                if let Some(pre_release) = pre_release {
+                    write!(f, "-{}", pre_release)?;
+                }
+                if let Some(build) = build {
+                    write!(f, "+{}", build)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum GenericMediaVersion {
     DigitalEdition { number: u16 },
     Edition { number: u16 },
@@ -85,3 +247,75 @@ impl GenericMediaVersion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::api::media::version::{GenericMediaVersion, SemVer, SemVerError};
+
+    #[test]
+    fn test_parse_release() {
+        assert_eq!(SemVer::parse("4.0"), Ok(SemVer::from_major_minor(4, 0)));
+        assert_eq!(
+            SemVer::parse("v1.2.3"),
+            Ok(SemVer::from_major_minor_patch(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn test_parse_pre_release_and_build() {
+        let version = SemVer::parse("1.0.0-rc.1+build.5").unwrap();
+
+        assert_eq!(version.pre_release(), Some("rc.1"));
+        assert_eq!(version.build(), Some("build.5"));
+        assert_eq!(version.to_string(), "1.0.0-rc.1+build.5");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            SemVer::parse("1.x"),
+            Err(SemVerError::InvalidNumber(_))
+        ));
+        assert!(matches!(
+            SemVer::parse("1.0.0-rc..1"),
+            Err(SemVerError::InvalidIdentifier(_))
+        ));
+        assert!(matches!(
+            SemVer::parse("1.0.0-01"),
+            Err(SemVerError::InvalidIdentifier(_))
+        ));
+        assert!(matches!(
+            SemVer::parse("01.0.0"),
+            Err(SemVerError::InvalidNumber(_))
+        ));
+    }
+
+    #[test]
+    fn test_with_pre_release_expands_short_version() {
+        let version = SemVer::from_major(2).with_pre_release("beta").unwrap();
+
+        assert_eq!(version.to_string(), "2.0.0-beta");
+    }
+
+    #[test]
+    fn test_pre_release_in_formatted_version() {
+        let version = GenericMediaVersion::SemVer(SemVer::parse("1.0.0-rc.1").unwrap());
+
+        assert_eq!(version.as_ieee_string(), "v1.0.0-rc.1");
+        assert_eq!(version.as_apa_string(), "(v1.0.0-rc.1)");
+    }
+
+    #[test]
+    fn test_deserialize_without_pre_release() {
+        let json = r#"{"MajorMinorPatch":{"major":1,"minor":2,"patch":3}}"#;
+
+        assert_eq!(
+            serde_json::from_str::<SemVer>(json).unwrap(),
+            SemVer::from_major_minor_patch(1, 2, 3)
+        );
+        assert_eq!(
+            serde_json::to_string(&SemVer::from_major_minor_patch(1, 2, 3)).unwrap(),
+            json
+        );
+    }
+}
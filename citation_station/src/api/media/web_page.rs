@@ -0,0 +1,364 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    author::{
+        ACS_AUTHOR_LIST, AMA_AUTHOR_LIST, APA_AUTHOR_LIST, GenericAuthor, ISO_690_AUTHOR_LIST,
+        VANCOUVER_AUTHOR_LIST, punctuate,
+    },
+    citation::{
+        AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+        VancouverFormatting,
+    },
+    date::{
+        ACS_DATES, AMA_DATES, APA_DATES, AccessDate, IEEE_DATES, ISO_690_DATES, VANCOUVER_DATES,
+    },
+    media::{apa_lead, common::CommonCitationData, doi_element, medium::Medium},
+    style::{CitationStyle, FormatOptions},
+    title::Title,
+};
+
+/// A page on a website, such as a blog post or an article.
+///
+/// IEEE formatting rules taken from:
+/// * https://journals.ieeeauthorcenter.ieee.org/wp-content/uploads/sites/7/IEEE_Reference_Guide.pdf
+///
+/// APA formatting rules taken from:
+/// * https://apastyle.apa.org/style-grammar-guidelines/references/examples/webpage-website-references
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WebPage {
+    pub common_data: CommonCitationData,
+    /// Author, if the page names one
+    pub author: Option<GenericAuthor>,
+    /// Page title
+    pub title: Title,
+    /// Name of the website
+    pub site_name: Option<String>,
+    pub url: String,
+    /// When the page was viewed
+    pub accessed: AccessDate,
+}
+
+impl WebPage {
+    /// The medium named in IEEE references
+    pub const MEDIUM: Medium = Medium::Online;
+}
+
+impl IeeeFormatting for WebPage {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_ref()
+            .and_then(|a| a.as_ieee_string_with(options))
+        {
+            parts.push(punctuate(&authors, "."));
+        }
+        parts.push(options.titles().quoted(&self.title.as_ieee_string(), "."));
+        if let Some(site_name) = &self.site_name {
+            parts.push(format!("{}.", site_name));
+        }
+        parts.push(format!("{}.", IEEE_DATES.access_note(&self.accessed)));
+        let doi = self.common_data.identifiers.doi();
+        parts.push(Self::MEDIUM.ieee_location(options.url.apply(&self.url, doi.is_some()), doi));
+
+        parts.join(" ")
+    }
+}
+
+impl ApaFormatting for WebPage {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let authors = self
+            .author
+            .as_ref()
+            .and_then(|a| a.as_apa_string_with(options));
+        let title = (!self.title.is_empty())
+            .then(|| options.titles().italic(&self.title.as_apa_string(), "."));
+        let date = self
+            .common_data
+            .published
+            .as_ref()
+            .map(|published| APA_DATES.published(published));
+        let mut parts = apa_lead(
+            authors
+                .as_ref()
+                .map(|authors| APA_AUTHOR_LIST.terminate(authors)),
+            date,
+            title,
+            "Untitled web page",
+        );
+        // The site name is left out when it is also the author
+        if let Some(site_name) = &self.site_name
+            && authors.as_deref() != Some(site_name.as_str())
+        {
+            parts.push(format!("{}.", site_name));
+        }
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = options.url.apply(&self.url, doi.is_some()) {
+            parts.push(url);
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Apa, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl Iso690Formatting for WebPage {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_ref()
+            .and_then(|author| author.as_string_with(CitationStyle::Iso690, options))
+        {
+            parts.push(ISO_690_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(format!(
+            "{} [online].",
+            options.titles().italic(&self.title.as_apa_string(), "")
+        ));
+
+        let mut source: Vec<String> = Vec::new();
+        if let Some(site_name) = &self.site_name {
+            source.push(site_name.clone());
+        }
+        if let Some(published) = &self.common_data.published {
+            source.push(published.year().to_string());
+        }
+        let viewed = format!("{}.", ISO_690_DATES.access_note(&self.accessed));
+        match source.is_empty() {
+            true => parts.push(viewed),
+            false => parts.push(format!("{} {}", source.join(", "), viewed)),
+        }
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = options.url.apply(&self.url, doi.is_some()) {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Iso690, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for WebPage {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_ref()
+            .and_then(|author| author.as_string_with(CitationStyle::Vancouver, options))
+        {
+            parts.push(VANCOUVER_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(format!(
+            "{} [Internet].",
+            options.titles().plain(&self.title.as_apa_string(), "")
+        ));
+
+        let mut source: Vec<String> = Vec::new();
+        if let Some(site_name) = &self.site_name {
+            source.push(site_name.clone());
+        }
+        if let Some(published) = &self.common_data.published {
+            source.push(VANCOUVER_DATES.published(published));
+        }
+        let cited = format!("{}.", VANCOUVER_DATES.access_note(&self.accessed));
+        match source.is_empty() {
+            true => parts.push(cited),
+            false => parts.push(format!("{} {}", source.join("; "), cited)),
+        }
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = options.url.apply(&self.url, doi.is_some()) {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Vancouver, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for WebPage {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_ref()
+            .and_then(|author| author.as_string_with(CitationStyle::Ama, options))
+        {
+            parts.push(AMA_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().plain(&self.title.as_apa_string(), "."));
+        if let Some(site_name) = &self.site_name {
+            parts.push(punctuate(site_name, "."));
+        }
+        if let Some(published) = &self.common_data.published {
+            parts.push(format!("Published {}.", AMA_DATES.published(published)));
+        }
+        parts.push(format!("{}.", AMA_DATES.access_note(&self.accessed)));
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = options.url.apply(&self.url, doi.is_some()) {
+            parts.push(url);
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Ama, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AcsFormatting for WebPage {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some(authors) = self
+            .author
+            .as_ref()
+            .and_then(|author| author.as_string_with(CitationStyle::Acs, options))
+        {
+            parts.push(ACS_AUTHOR_LIST.terminate(&authors));
+        }
+        parts.push(options.titles().plain(&self.title.as_apa_string(), "."));
+        if let Some(site_name) = &self.site_name {
+            parts.push(punctuate(site_name, "."));
+        }
+        let accessed = format!("{}.", ACS_DATES.access_note(&self.accessed));
+        let doi = self.common_data.identifiers.doi();
+        match options.url.apply(&self.url, doi.is_some()) {
+            Some(url) => parts.push(format!("{} {}", url, accessed)),
+            None => parts.push(accessed),
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Acs, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveDate};
+
+    use crate::{
+        api::{
+            author::{GenericAuthor, PersonName},
+            citation::{ApaFormatting, Citation, IeeeFormatting},
+            date::PublishDate,
+            media::{common::CommonCitationData, web_page::WebPage},
+            style::{CitationStyle, FormatOptions, Markup},
+        },
+        import::identifier::Identifier,
+    };
+
+    fn page() -> WebPage {
+        WebPage {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month_day(2023, Month::March, 5).unwrap()),
+                ..CommonCitationData::new("post")
+            },
+            author: Some(GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            }),
+            title: "Why Rust?".into(),
+            site_name: Some("Example Blog".to_string()),
+            url: "https://example.com/why-rust".to_string(),
+            accessed: NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into(),
+        }
+    }
+
+    #[test]
+    fn test_web_page_ieee_formatting() {
+        assert_eq!(
+            IeeeFormatting::citation_string(&page()),
+            "J. Smith. \u{201C}Why Rust?\u{201D} Example Blog. Accessed: Oct. 1, 2025. \
+             [Online]. Available: https://example.com/why-rust"
+        );
+    }
+
+    #[test]
+    fn test_web_page_apa_formatting() {
+        assert_eq!(
+            ApaFormatting::citation_string(&page()),
+            "Smith, J. (2023, March 5). Why Rust? Example Blog. https://example.com/why-rust"
+        );
+    }
+
+    #[test]
+    fn test_web_page_apa_formatting_without_author() {
+        let mut page = page();
+        page.author = None;
+        page.common_data.published = None;
+
+        assert_eq!(
+            ApaFormatting::citation_string(&page),
+            "Why Rust? (n.d.). Example Blog. https://example.com/why-rust"
+        );
+    }
+
+    #[test]
+    fn test_web_page_prefers_doi() {
+        let mut page = page();
+        page.common_data
+            .identifiers
+            .insert(Identifier::Doi("10.1000/blog".to_string()));
+        let page = Citation::WebPage(page);
+
+        assert!(
+            page.format(CitationStyle::Ieee)
+                .ends_with("Accessed: Oct. 1, 2025. doi: 10.1000/blog.")
+        );
+        assert!(
+            page.format(CitationStyle::Apa)
+                .ends_with("Example Blog. https://doi.org/10.1000/blog")
+        );
+        assert!(
+            page.format(CitationStyle::Ama)
+                .ends_with("Accessed October 1, 2025. doi:10.1000/blog")
+        );
+        // ISO 690 and Vancouver give the URL as well as the DOI
+        assert!(
+            page.format(CitationStyle::Vancouver)
+                .ends_with("Available from: https://example.com/why-rust doi:10.1000/blog")
+        );
+        assert!(
+            page.format(CitationStyle::Iso690)
+                .ends_with("Available from: https://example.com/why-rust DOI: 10.1000/blog.")
+        );
+    }
+
+    #[test]
+    fn test_web_page_ieee_url_breaks() {
+        let page = Citation::WebPage(page());
+        let html = FormatOptions {
+            markup: Markup::Html,
+            ..FormatOptions::for_style(CitationStyle::Ieee)
+        };
+
+        assert!(
+            page.format_with(CitationStyle::Ieee, &html)
+                .ends_with("Available: https://example.com/<wbr>why-rust")
+        );
+        assert!(
+            !page
+                .format_with(
+                    CitationStyle::Apa,
+                    &FormatOptions {
+                        markup: Markup::Html,
+                        ..FormatOptions::for_style(CitationStyle::Apa)
+                    }
+                )
+                .contains("<wbr>")
+        );
+    }
+}
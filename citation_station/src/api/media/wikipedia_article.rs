@@ -0,0 +1,407 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    citation::{
+        AcsFormatting, AmaFormatting, ApaFormatting, IeeeFormatting, Iso690Formatting,
+        VancouverFormatting,
+    },
+    date::{
+        ACS_DATES, AMA_DATES, APA_DATES, AccessDate, IEEE_DATES, ISO_690_DATES, VANCOUVER_DATES,
+    },
+    media::{common::CommonCitationData, doi_element, medium::Medium},
+    style::{CitationStyle, FormatOptions},
+    url::percent_decode,
+};
+
+/// An article on Wikipedia.
+///
+/// Wikipedia articles change constantly, so the revision that was read is
+/// recorded and cited through its permanent link. The publish date is the
+/// date of that revision.
+///
+/// IEEE formatting follows the website rules in:
+/// * https://journals.ieeeauthorcenter.ieee.org/wp-content/uploads/sites/7/IEEE_Reference_Guide.pdf
+///
+/// APA formatting rules taken from:
+/// * https://apastyle.apa.org/style-grammar-guidelines/references/examples/wikipedia-references
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WikipediaArticle {
+    pub common_data: CommonCitationData,
+    /// Article title, with spaces rather than underscores
+    pub title: String,
+    /// Language edition, e.g. "en" for en.wikipedia.org
+    pub language: String,
+    /// ID of the cited revision (the "oldid" URL parameter)
+    pub revision: Option<u64>,
+    /// When the article was read
+    pub accessed: AccessDate,
+}
+
+impl WikipediaArticle {
+    /// The medium named in IEEE references
+    pub const MEDIUM: Medium = Medium::Online;
+
+    /// Recognize a Wikipedia article URL, e.g.
+    /// `https://en.wikipedia.org/wiki/Oil_painting` or a permanent link
+    /// `https://en.wikipedia.org/w/index.php?title=Oil_painting&oldid=931182215`.
+    ///
+    /// Mobile (`en.m.wikipedia.org`) links are accepted. Returns `None` for
+    /// anything that isn't an article on a Wikipedia language edition.
+    pub fn from_url(url: &str, accessed: AccessDate) -> Option<Self> {
+        let rest = url
+            .trim()
+            .strip_prefix("https://")
+            .or_else(|| url.trim().strip_prefix("http://"))?;
+        let (host, path) = rest.split_once('/')?;
+        let host = host.to_lowercase();
+        let host = host.strip_suffix(".wikipedia.org")?;
+        let language = host.strip_suffix(".m").unwrap_or(host);
+        if language.is_empty()
+            || !language
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return None;
+        }
+
+        let path = path.split('#').next().unwrap_or_default();
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let params: Vec<(&str, &str)> = query
+            .split('&')
+            .filter_map(|param| param.split_once('='))
+            .collect();
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+        };
+
+        let encoded_title = match path.strip_prefix("wiki/") {
+            Some(title) => title,
+            None if path == "w/index.php" => param("title")?,
+            None => return None,
+        };
+        let title = percent_decode(encoded_title)?.replace('_', " ");
+        let title = title.trim();
+        if title.is_empty() {
+            return None;
+        }
+        let revision = param("oldid").and_then(|oldid| oldid.parse().ok());
+
+        let mut article = Self {
+            common_data: CommonCitationData::new(String::new()),
+            title: title.to_string(),
+            language: language.to_string(),
+            revision,
+            accessed,
+        };
+        article.common_data.id = article.default_id();
+        Some(article)
+    }
+
+    /// The ID given to imported articles, e.g. "wikipedia:en:Oil_painting"
+    pub fn default_id(&self) -> String {
+        format!(
+            "wikipedia:{}:{}",
+            self.language,
+            self.title.replace(' ', "_")
+        )
+    }
+
+    /// Link to the current version of the article
+    pub fn article_url(&self) -> String {
+        format!(
+            "https://{}.wikipedia.org/wiki/{}",
+            self.language,
+            encode_title(&self.title)
+        )
+    }
+
+    /// Link to the cited revision, if known
+    pub fn permanent_url(&self) -> Option<String> {
+        self.revision.map(|revision| {
+            format!(
+                "https://{}.wikipedia.org/w/index.php?title={}&oldid={}",
+                self.language,
+                encode_title(&self.title),
+                revision
+            )
+        })
+    }
+
+    /// The permanent link when the revision is known, otherwise the link
+    /// to the current version
+    pub fn url(&self) -> String {
+        self.permanent_url().unwrap_or_else(|| self.article_url())
+    }
+}
+
+/// Encode a title for use in a URL path or query, the way MediaWiki does:
+/// spaces become underscores, and anything that could end the path or
+/// parameter is percent-encoded.
+fn encode_title(title: &str) -> String {
+    let mut encoded = String::new();
+    for byte in title.trim().replace(' ', "_").bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'('
+            | b')'
+            | b':'
+            | b','
+            | b'\''
+            | b'!'
+            | b'*'
+            | b'/' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+impl IeeeFormatting for WikipediaArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = vec![
+            options.titles().quoted(&self.title, "."),
+            "Wikipedia.".to_string(),
+            format!("{}.", IEEE_DATES.access_note(&self.accessed)),
+        ];
+        let doi = self.common_data.identifiers.doi();
+        parts.push(Self::MEDIUM.ieee_location(options.url.apply(&self.url(), doi.is_some()), doi));
+
+        parts.join(" ")
+    }
+}
+
+impl ApaFormatting for WikipediaArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let mut parts: Vec<String> = vec![titles.plain(&self.title, ".")];
+        match &self.common_data.published {
+            Some(published) => parts.push(format!("({}).", APA_DATES.published(published))),
+            None => parts.push("(n.d.).".to_string()),
+        }
+        parts.push(format!("In {}", titles.italic("Wikipedia", ".")));
+
+        // A permanent link never changes, so APA only asks for a retrieval
+        // date when citing the current version of the article
+        let doi = self.common_data.identifiers.doi();
+        let url = options.url.apply(&self.url(), doi.is_some());
+        match (self.permanent_url(), url) {
+            (Some(_), Some(url)) => parts.push(url),
+            (None, Some(url)) => parts.push(format!(
+                "{}, from {}",
+                APA_DATES.access_note(&self.accessed),
+                url
+            )),
+            (_, None) if doi.is_some() => {}
+            (_, None) => parts.push(format!("{}.", APA_DATES.access_note(&self.accessed))),
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Apa, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl Iso690Formatting for WikipediaArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let mut parts: Vec<String> = vec![
+            titles.plain(&self.title, "."),
+            format!("In: {} [online].", titles.italic("Wikipedia", "")),
+        ];
+        let viewed = format!("{}.", ISO_690_DATES.access_note(&self.accessed));
+        match &self.common_data.published {
+            Some(published) => parts.push(format!("{} {}", published.year(), viewed)),
+            None => parts.push(viewed),
+        }
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = options.url.apply(&self.url(), doi.is_some()) {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Iso690, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl VancouverFormatting for WikipediaArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let titles = options.titles();
+        let mut parts: Vec<String> = vec![
+            titles.plain(&self.title, "."),
+            "In: Wikipedia [Internet].".to_string(),
+        ];
+        let cited = format!("{}.", VANCOUVER_DATES.access_note(&self.accessed));
+        match &self.common_data.published {
+            Some(published) => parts.push(format!(
+                "{} {}",
+                VANCOUVER_DATES.published(published),
+                cited
+            )),
+            None => parts.push(cited),
+        }
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = options.url.apply(&self.url(), doi.is_some()) {
+            parts.push(format!("Available from: {}", url));
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Vancouver, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AmaFormatting for WikipediaArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = vec![
+            options.titles().plain(&self.title, "."),
+            "Wikipedia.".to_string(),
+        ];
+        if let Some(published) = &self.common_data.published {
+            parts.push(format!("Updated {}.", AMA_DATES.published(published)));
+        }
+        parts.push(format!("{}.", AMA_DATES.access_note(&self.accessed)));
+        let doi = self.common_data.identifiers.doi();
+        if let Some(url) = options.url.apply(&self.url(), doi.is_some()) {
+            parts.push(url);
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Ama, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+impl AcsFormatting for WikipediaArticle {
+    fn citation_string_with(&self, options: &FormatOptions) -> String {
+        let mut parts: Vec<String> = vec![options.titles().plain(&self.title, ".")];
+        match &self.common_data.published {
+            Some(published) => parts.push(format!("Wikipedia, {}.", published.year())),
+            None => parts.push("Wikipedia.".to_string()),
+        }
+        let accessed = format!("{}.", ACS_DATES.access_note(&self.accessed));
+        let doi = self.common_data.identifiers.doi();
+        match options.url.apply(&self.url(), doi.is_some()) {
+            Some(url) => parts.push(format!("{} {}", url, accessed)),
+            None => parts.push(accessed),
+        }
+        if let Some(doi) = doi {
+            parts.push(doi_element(CitationStyle::Acs, doi));
+        }
+
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveDate};
+
+    use crate::api::{
+        citation::{ApaFormatting, IeeeFormatting},
+        date::{AccessDate, PublishDate},
+        media::wikipedia_article::WikipediaArticle,
+    };
+
+    fn accessed() -> AccessDate {
+        NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into()
+    }
+
+    fn article() -> WikipediaArticle {
+        let mut article = WikipediaArticle::from_url(
+            "https://en.wikipedia.org/w/index.php?title=Oil_painting&oldid=931182215",
+            accessed(),
+        )
+        .unwrap();
+        article.common_data.published =
+            Some(PublishDate::from_year_month_day(2019, Month::December, 17).unwrap());
+        article
+    }
+
+    #[test]
+    fn test_from_url() {
+        let article = WikipediaArticle::from_url(
+            "https://de.m.wikipedia.org/wiki/Caf%C3%A9_Central#Geschichte",
+            accessed(),
+        )
+        .unwrap();
+
+        assert_eq!(article.title, "Café Central");
+        assert_eq!(article.language, "de");
+        assert_eq!(article.revision, None);
+        assert_eq!(
+            article.article_url(),
+            "https://de.wikipedia.org/wiki/Caf%C3%A9_Central"
+        );
+        assert_eq!(article.url(), article.article_url());
+    }
+
+    #[test]
+    fn test_from_url_permanent_link() {
+        let article = article();
+
+        assert_eq!(article.title, "Oil painting");
+        assert_eq!(article.revision, Some(931182215));
+        assert_eq!(
+            article.url(),
+            "https://en.wikipedia.org/w/index.php?title=Oil_painting&oldid=931182215"
+        );
+    }
+
+    #[test]
+    fn test_from_url_rejects_other_sites() {
+        assert!(WikipediaArticle::from_url("https://example.com/wiki/Rust", accessed()).is_none());
+        assert!(
+            WikipediaArticle::from_url("https://en.wikipedia.org/w/index.php", accessed())
+                .is_none()
+        );
+        assert!(
+            WikipediaArticle::from_url("https://wikipedia.org/wiki/Rust", accessed()).is_none()
+        );
+    }
+
+    #[test]
+    fn test_wikipedia_article_apa_formatting() {
+        assert_eq!(
+            ApaFormatting::citation_string(&article()),
+            "Oil painting. (2019, December 17). In Wikipedia. \
+             https://en.wikipedia.org/w/index.php?title=Oil_painting&oldid=931182215"
+        );
+    }
+
+    #[test]
+    fn test_wikipedia_article_apa_formatting_current_version() {
+        let mut article = article();
+        article.revision = None;
+        article.common_data.published = None;
+
+        assert_eq!(
+            ApaFormatting::citation_string(&article),
+            "Oil painting. (n.d.). In Wikipedia. Retrieved October 1, 2025, from \
+             https://en.wikipedia.org/wiki/Oil_painting"
+        );
+    }
+
+    #[test]
+    fn test_wikipedia_article_ieee_formatting() {
+        assert_eq!(
+            IeeeFormatting::citation_string(&article()),
+            "\u{201C}Oil painting.\u{201D} Wikipedia. Accessed: Oct. 1, 2025. [Online]. \
+             Available: https://en.wikipedia.org/w/index.php?title=Oil_painting&oldid=931182215"
+        );
+    }
+}
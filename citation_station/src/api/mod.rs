@@ -1,7 +1,19 @@
+pub mod accessible;
 pub mod author;
 pub mod citation;
+pub mod citation_id;
+pub mod completeness;
+pub mod conference;
+pub mod content_hash;
 pub mod date;
 pub mod errors;
+pub mod identifiers;
 pub mod location;
 pub mod media;
 pub mod page_range;
+pub mod requirements;
+pub mod style;
+pub mod title;
+pub mod url;
+pub mod visitor;
+pub mod volume;
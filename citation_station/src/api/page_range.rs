@@ -1,7 +1,226 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+use crate::unicode::ENDASH;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PageRange {
     pub start: u32,
     pub end: u32,
 }
+
+impl PageRange {
+    /// Parse a page range like "100-110" (hyphen or en dash) or a single page "42".
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut bounds = s
+            .split(['-', ENDASH])
+            .map(|bound| bound.trim().parse::<u32>());
+        let start = bounds.next()?.ok()?;
+        let end = match bounds.next() {
+            Some(end) => end.ok()?,
+            None => start,
+        };
+        if bounds.next().is_some() || end < start {
+            return None;
+        }
+        Some(Self { start, end })
+    }
+
+    pub fn as_ieee_string(&self) -> String {
+        if self.start == self.end {
+            format!("p. {}", self.start)
+        } else {
+            format!("pp. {}{}{}", self.start, ENDASH, self.end)
+        }
+    }
+
+    pub fn as_apa_string(&self) -> String {
+        if self.start == self.end {
+            format!("{}", self.start)
+        } else {
+            format!("{}{}{}", self.start, ENDASH, self.end)
+        }
+    }
+
+    /// e.g. "100-110"
+    pub fn as_ama_string(&self) -> String {
+        if self.start == self.end {
+            format!("{}", self.start)
+        } else {
+            format!("{}-{}", self.start, self.end)
+        }
+    }
+
+    /// The end page drops the leading digits it shares with the start,
+    /// e.g. "100-10" or "1234-41"
+    pub fn as_vancouver_string(&self) -> String {
+        if self.start == self.end {
+            return format!("{}", self.start);
+        }
+        let start = self.start.to_string();
+        let end = self.end.to_string();
+        let shared = if start.len() == end.len() {
+            start
+                .chars()
+                .zip(end.chars())
+                .take_while(|(a, b)| a == b)
+                .count()
+        } else {
+            0
+        };
+        format!("{}-{}", start, &end[shared..])
+    }
+}
+
+/// Where an article sits within its issue: a page range, or for journals
+/// that publish online only, an article number or e-locator such as
+/// "e0245678".
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ArticleLocator {
+    Pages(PageRange),
+    ArticleNumber(String),
+}
+
+impl ArticleLocator {
+    /// Parse a page range, falling back to an article number. A leading
+    /// "Article" or "Art. no." is dropped from article numbers.
+    pub fn parse(s: &str) -> Option<Self> {
+        if let Some(pages) = PageRange::parse(s) {
+            return Some(ArticleLocator::Pages(pages));
+        }
+        let number = s.trim();
+        let number = ["Art. no.", "Article"]
+            .iter()
+            .find_map(|prefix| number.strip_prefix(prefix))
+            .unwrap_or(number)
+            .trim();
+        (!number.is_empty()).then(|| ArticleLocator::ArticleNumber(number.to_string()))
+    }
+
+    /// e.g. "pp. 100–110" or "Art. no. e0245678"
+    pub fn as_ieee_string(&self) -> String {
+        match self {
+            ArticleLocator::Pages(pages) => pages.as_ieee_string(),
+            ArticleLocator::ArticleNumber(number) => format!("Art. no. {}", number),
+        }
+    }
+
+    /// e.g. "100–110" or "Article e0245678"
+    pub fn as_apa_string(&self) -> String {
+        match self {
+            ArticleLocator::Pages(pages) => pages.as_apa_string(),
+            ArticleLocator::ArticleNumber(number) => format!("Article {}", number),
+        }
+    }
+
+    /// e.g. "100-110" or "e0245678"
+    pub fn as_ama_string(&self) -> String {
+        match self {
+            ArticleLocator::Pages(pages) => pages.as_ama_string(),
+            ArticleLocator::ArticleNumber(number) => number.clone(),
+        }
+    }
+
+    /// e.g. "100-10" or "e0245678"
+    pub fn as_vancouver_string(&self) -> String {
+        match self {
+            ArticleLocator::Pages(pages) => pages.as_vancouver_string(),
+            ArticleLocator::ArticleNumber(number) => number.clone(),
+        }
+    }
+}
+
+impl From<PageRange> for ArticleLocator {
+    fn from(pages: PageRange) -> Self {
+        ArticleLocator::Pages(pages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::page_range::{ArticleLocator, PageRange};
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(
+            PageRange::parse("100-110"),
+            Some(PageRange {
+                start: 100,
+                end: 110
+            })
+        );
+        assert_eq!(
+            PageRange::parse("7\u{2013}9"),
+            Some(PageRange { start: 7, end: 9 })
+        );
+    }
+
+    #[test]
+    fn test_parse_single_page() {
+        assert_eq!(
+            PageRange::parse("42"),
+            Some(PageRange { start: 42, end: 42 })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(PageRange::parse("e0245678"), None);
+        assert_eq!(PageRange::parse("10-5"), None);
+        assert_eq!(PageRange::parse(""), None);
+    }
+
+    #[test]
+    fn test_format_ieee() {
+        assert_eq!(
+            PageRange { start: 1, end: 10 }.as_ieee_string(),
+            "pp. 1\u{2013}10"
+        );
+        assert_eq!(PageRange { start: 5, end: 5 }.as_ieee_string(), "p. 5");
+    }
+
+    #[test]
+    fn test_format_vancouver() {
+        let range = |start, end| PageRange { start, end }.as_vancouver_string();
+        assert_eq!(range(100, 110), "100-10");
+        assert_eq!(range(123, 129), "123-9");
+        assert_eq!(range(98, 102), "98-102");
+        assert_eq!(range(5, 5), "5");
+    }
+
+    #[test]
+    fn test_article_locator_parse() {
+        assert_eq!(
+            ArticleLocator::parse("100-110"),
+            Some(ArticleLocator::Pages(PageRange {
+                start: 100,
+                end: 110
+            }))
+        );
+        assert_eq!(
+            ArticleLocator::parse("Article 103421"),
+            Some(ArticleLocator::ArticleNumber("103421".to_string()))
+        );
+        assert_eq!(ArticleLocator::parse("  "), None);
+    }
+
+    #[test]
+    fn test_article_locator_format() {
+        let locator = ArticleLocator::parse("e0245678").unwrap();
+
+        assert_eq!(locator.as_ieee_string(), "Art. no. e0245678");
+        assert_eq!(locator.as_apa_string(), "Article e0245678");
+    }
+
+    #[test]
+    fn test_article_locator_reads_page_range_json() {
+        let locator: ArticleLocator = serde_json::from_str(r#"{"start": 1, "end": 9}"#).unwrap();
+
+        assert_eq!(
+            locator,
+            ArticleLocator::Pages(PageRange { start: 1, end: 9 })
+        );
+    }
+}
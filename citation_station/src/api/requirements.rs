@@ -0,0 +1,186 @@
+//! What each style needs from each media type, so forms and validators
+//! can be built from data instead of per-style code.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{media::MediaKind, style::CitationStyle};
+
+/// A field of a citation, as far as styles are concerned.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum CitationField {
+    Author,
+    Title,
+    /// The journal or conference a work appeared in
+    Container,
+    PublishDate,
+    /// When an online work was viewed
+    AccessDate,
+    Doi,
+    Pages,
+    Volume,
+    Issue,
+    /// A URL, DOI or database that says where an online work can be found
+    Availability,
+    Version,
+    Venue,
+    /// The chapter of a book
+    Chapter,
+    /// The publisher of a book or software
+    Publisher,
+}
+
+impl fmt::Display for CitationField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CitationField::Author => "author",
+            CitationField::Title => "title",
+            CitationField::Container => "journal or conference",
+            CitationField::PublishDate => "publish date",
+            CitationField::AccessDate => "access date",
+            CitationField::Doi => "DOI",
+            CitationField::Pages => "page range or article number",
+            CitationField::Volume => "volume",
+            CitationField::Issue => "issue",
+            CitationField::Availability => "URL or DOI",
+            CitationField::Version => "version",
+            CitationField::Venue => "venue",
+            CitationField::Chapter => "chapter",
+            CitationField::Publisher => "publisher",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The fields a style uses for one media type. A field is in at most one
+/// of the lists; fields in none of them are not shown by the style.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldRequirements {
+    /// Fields the reference can't be formatted without
+    pub required: Vec<CitationField>,
+    /// Fields a complete reference has
+    pub recommended: Vec<CitationField>,
+    /// Fields that are shown when present
+    pub optional: Vec<CitationField>,
+}
+
+impl FieldRequirements {
+    /// Whether the style shows `field` at all
+    pub fn supports(&self, field: CitationField) -> bool {
+        self.required.contains(&field)
+            || self.recommended.contains(&field)
+            || self.optional.contains(&field)
+    }
+
+    pub fn is_required(&self, field: CitationField) -> bool {
+        self.required.contains(&field)
+    }
+}
+
+/// See [`CitationStyle::requirements`]
+pub(crate) fn requirements(style: CitationStyle, kind: MediaKind) -> FieldRequirements {
+    use CitationField::*;
+
+    let mut fields = FieldRequirements {
+        required: vec![Title],
+        recommended: vec![Author, PublishDate],
+        optional: Vec::new(),
+    };
+    // APA leaves out the access date, except for pages that keep changing
+    let online_required = match style {
+        CitationStyle::Apa => vec![],
+        _ => vec![AccessDate],
+    };
+    match kind {
+        MediaKind::Book => {
+            // IEEE book references don't show a DOI
+            if style != CitationStyle::Ieee {
+                fields.recommended.push(Doi);
+            }
+            fields.recommended.push(Publisher);
+            fields.optional.extend([Version, Chapter, Pages]);
+        }
+        MediaKind::JournalArticle => {
+            fields.required.push(Container);
+            fields.recommended.extend([Volume, Issue, Pages, Doi]);
+        }
+        MediaKind::OnlineManual => {
+            fields.required.extend(online_required);
+            // APA manual references don't show a version
+            if style != CitationStyle::Apa {
+                fields.recommended.push(Version);
+            }
+            fields.recommended.push(Availability);
+        }
+        MediaKind::OnlineVideo => {
+            fields.required.extend(online_required);
+            fields.recommended.push(Availability);
+        }
+        MediaKind::Software => {
+            fields.required.extend(online_required);
+            fields.recommended.extend([Version, Availability]);
+            fields.optional.push(Publisher);
+        }
+        MediaKind::WebPage => {
+            fields.required.push(Availability);
+            fields.required.extend(online_required);
+            fields.recommended.push(Venue);
+        }
+        MediaKind::WikipediaArticle => {
+            // Articles have no listed authors; the revision is what matters
+            fields.required.extend([Availability, AccessDate]);
+            fields.recommended = vec![PublishDate, Version];
+        }
+        MediaKind::ConferencePresentation => {
+            fields.required.push(Container);
+            fields.recommended.push(Venue);
+            fields.optional.push(Availability);
+        }
+        MediaKind::ConferencePaperOnline | MediaKind::ConferenceProceedingsOnline => {
+            fields.required.push(Container);
+            fields.recommended.push(Venue);
+            fields.optional.extend([Volume, Issue]);
+        }
+        // Defined outside the crate, so only the basics are known
+        MediaKind::Custom => {}
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{media::MediaKind, requirements::CitationField, style::CitationStyle};
+
+    #[test]
+    fn test_requirements_differ_by_style() {
+        let apa = CitationStyle::Apa.requirements(MediaKind::Book);
+        let ieee = CitationStyle::Ieee.requirements(MediaKind::Book);
+
+        assert!(apa.supports(CitationField::Doi));
+        assert!(!ieee.supports(CitationField::Doi));
+        assert!(ieee.is_required(CitationField::Title));
+        assert!(ieee.supports(CitationField::Chapter));
+    }
+
+    #[test]
+    fn test_fields_are_listed_once() {
+        for style in CitationStyle::ALL {
+            for kind in MediaKind::ALL {
+                let requirements = style.requirements(kind);
+                let mut fields: Vec<CitationField> = requirements
+                    .required
+                    .iter()
+                    .chain(&requirements.recommended)
+                    .chain(&requirements.optional)
+                    .copied()
+                    .collect();
+                let count = fields.len();
+                fields.sort();
+                fields.dedup();
+                assert_eq!(fields.len(), count, "{:?} {:?}", style, kind);
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abbrev::{abbreviate_venue, expand_venue},
+    api::{
+        media::MediaKind,
+        requirements::{FieldRequirements, requirements},
+        title::{QuoteStyle, TitleRenderer},
+        url::UrlPolicy,
+    },
+};
+
+/// A citation style that references and in-text citations can be
+/// rendered in.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum CitationStyle {
+    /// APA 7th edition (author-date)
+    Apa,
+    /// IEEE (numeric)
+    Ieee,
+    /// ISO 690:2010, name-date system
+    Iso690,
+    /// Vancouver (numeric), as used by biomedical journals following the
+    /// NLM and ICMJE recommendations
+    Vancouver,
+    /// AMA Manual of Style, 11th edition (numeric)
+    Ama,
+    /// ACS Guide to Scholarly Communication (numeric)
+    Acs,
+}
+
+impl CitationStyle {
+    pub const ALL: [CitationStyle; 6] = [
+        CitationStyle::Apa,
+        CitationStyle::Ieee,
+        CitationStyle::Iso690,
+        CitationStyle::Vancouver,
+        CitationStyle::Ama,
+        CitationStyle::Acs,
+    ];
+
+    /// Whether in-text citations are reference numbers rather than author
+    /// and date
+    pub const fn is_numeric(self) -> bool {
+        match self {
+            CitationStyle::Ieee
+            | CitationStyle::Vancouver
+            | CitationStyle::Ama
+            | CitationStyle::Acs => true,
+            CitationStyle::Apa | CitationStyle::Iso690 => false,
+        }
+    }
+
+    /// Which fields the style requires, recommends and can show for
+    /// `kind`, e.g. to build a form for entering a reference
+    pub fn requirements(self, kind: MediaKind) -> FieldRequirements {
+        requirements(self, kind)
+    }
+
+    /// Short display name, e.g. "ISO 690"
+    pub const fn name(self) -> &'static str {
+        match self {
+            CitationStyle::Apa => "APA",
+            CitationStyle::Ieee => "IEEE",
+            CitationStyle::Iso690 => "ISO 690",
+            CitationStyle::Vancouver => "Vancouver",
+            CitationStyle::Ama => "AMA",
+            CitationStyle::Acs => "ACS",
+        }
+    }
+}
+
+/// Knobs that change how references are rendered without changing the
+/// style itself.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FormatOptions {
+    /// How URLs of online resources are printed
+    pub url: UrlPolicy,
+    /// Quotation marks around the titles of chapters, articles and pages
+    #[serde(default)]
+    pub quotes: QuoteStyle,
+    /// Whether references are marked up and escaped for HTML or ODF, or
+    /// left as plain text
+    #[serde(default)]
+    pub markup: Markup,
+    /// Whether organization authors are followed by their abbreviation in
+    /// brackets, e.g. "World Health Organization [WHO]"
+    #[serde(default)]
+    pub organization_abbreviations: bool,
+    /// Whether journal names are abbreviated
+    #[serde(default)]
+    pub venue_names: VenueNames,
+    /// Whether abbreviations such as "vol." are written out and, in HTML,
+    /// URLs are shown as labelled links, for screen readers
+    #[serde(default)]
+    pub accessible: bool,
+    /// A link added after each entry for readers of a printed copy
+    #[serde(default)]
+    pub print_link: PrintLink,
+}
+
+/// What is added after an entry so readers of a poster or handout can
+/// find the work.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum PrintLink {
+    #[default]
+    None,
+    /// The DOI as a short link, e.g. "doi.org/10.1000/xyz"
+    ShortDoi,
+    /// In HTML, a QR code image of the DOI link, or of the URL for works
+    /// without a DOI. Other markup gets the short DOI link instead.
+    QrCode,
+}
+
+/// How journal and conference names are written.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum VenueNames {
+    /// As stored in the citation
+    #[default]
+    AsEntered,
+    /// Abbreviated by the ISO 4 rules, e.g. "IEEE Trans. Softw. Eng."
+    Abbreviated,
+    /// Written out, for known abbreviations such as "Proc. IEEE"
+    Full,
+}
+
+/// The markup titles are rendered in.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Markup {
+    /// Italics are dropped
+    #[default]
+    PlainText,
+    /// Italics become `<i>` elements and the rest of the reference is
+    /// HTML-escaped
+    Html,
+    /// Italics become OpenDocument spans styled "Italic" and the rest of
+    /// the reference is XML-escaped. See [`crate::odf`].
+    Odf,
+}
+
+impl FormatOptions {
+    /// The defaults used when formatting in `style`.
+    pub fn for_style(style: CitationStyle) -> Self {
+        let venue_names = match style {
+            CitationStyle::Apa => VenueNames::Full,
+            CitationStyle::Ieee => VenueNames::Abbreviated,
+            CitationStyle::Iso690
+            | CitationStyle::Vancouver
+            | CitationStyle::Ama
+            | CitationStyle::Acs => VenueNames::AsEntered,
+        };
+        // APA, AMA and ACS give the DOI instead of the URL when there is
+        // one; ISO 690 and NLM (Vancouver) give both. IEEE lets long URLs
+        // break after slashes.
+        let url = match style {
+            CitationStyle::Apa | CitationStyle::Ama | CitationStyle::Acs => UrlPolicy::verbatim(),
+            CitationStyle::Ieee => UrlPolicy {
+                break_long: true,
+                ..UrlPolicy::verbatim()
+            },
+            CitationStyle::Iso690 | CitationStyle::Vancouver => UrlPolicy::with_doi(),
+        };
+        Self {
+            url,
+            quotes: QuoteStyle::Curly,
+            markup: Markup::PlainText,
+            organization_abbreviations: false,
+            venue_names,
+            accessible: false,
+            print_link: PrintLink::None,
+        }
+    }
+
+    /// The journal or conference name `name` as these options write it
+    pub fn venue<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match self.venue_names {
+            VenueNames::AsEntered => Cow::Borrowed(name),
+            VenueNames::Abbreviated => Cow::Owned(abbreviate_venue(name)),
+            VenueNames::Full => expand_venue(name).map_or(Cow::Borrowed(name), Cow::Borrowed),
+        }
+    }
+
+    /// Renders titles with these options' quotes and markup.
+    pub fn titles(&self) -> TitleRenderer {
+        TitleRenderer {
+            quotes: self.quotes,
+            markup: self.markup,
+        }
+    }
+}
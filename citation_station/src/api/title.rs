@@ -0,0 +1,564 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::style::Markup, xml::escape};
+
+/// The title of a work, with its subtitle kept apart so that each style
+/// can join them by its own rules.
+///
+/// Serialized as a plain string when there is no subtitle, so titles
+/// written before subtitles were supported still load.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(from = "TitleRepr", into = "TitleRepr")]
+pub struct Title {
+    pub main: String,
+    pub subtitle: Option<String>,
+}
+
+impl Title {
+    pub fn new(main: impl Into<String>) -> Self {
+        Self {
+            main: main.into(),
+            subtitle: None,
+        }
+    }
+
+    pub fn with_subtitle(main: impl Into<String>, subtitle: impl Into<String>) -> Self {
+        let subtitle = subtitle.into();
+        Self {
+            main: main.into(),
+            subtitle: (!subtitle.trim().is_empty()).then_some(subtitle),
+        }
+    }
+
+    /// Split a pre-concatenated title at its first colon, e.g.
+    /// "Rust: A Primer"
+    pub fn parse(title: &str) -> Self {
+        match title.split_once(':') {
+            Some((main, subtitle)) if !main.trim().is_empty() => {
+                Self::with_subtitle(main.trim(), subtitle.trim())
+            }
+            _ => Self::new(title.trim()),
+        }
+    }
+
+    /// Joined with ": ", with the first word of the subtitle capitalized as
+    /// APA requires. A main title ending in "?" or "!" is followed by the
+    /// subtitle without a colon.
+    pub fn as_apa_string(&self) -> String {
+        match &self.subtitle {
+            Some(subtitle) => join(&self.main, &capitalize_first(subtitle)),
+            None => self.main.clone(),
+        }
+    }
+
+    /// Joined with ": ", keeping the subtitle's capitalization as given
+    pub fn as_ieee_string(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.main.is_empty() && self.subtitle.is_none()
+    }
+
+    /// The title and subtitle each converted with [`sentence_case`]
+    pub fn to_sentence_case(&self) -> Self {
+        Self {
+            main: sentence_case(&self.main),
+            subtitle: self.subtitle.as_deref().map(sentence_case),
+        }
+    }
+}
+
+/// Convert `text` to sentence case: the first word and the first word
+/// after a colon are capitalized and other words are lowercased.
+///
+/// Words with a capital after their first letter, like "DNA" or "iPhone",
+/// keep their case, as does anything inside BibTeX protection braces,
+/// e.g. "{Escherichia coli}". The braces themselves are dropped.
+pub fn sentence_case(text: &str) -> String {
+    let mut cased = String::with_capacity(text.len());
+    let mut word = String::new();
+    let mut capitalize = true;
+    let mut depth = 0;
+
+    let flush = |word: &mut String, cased: &mut String, capitalize: &mut bool| {
+        if word.is_empty() {
+            return;
+        }
+        if has_inner_capital(word) {
+            cased.push_str(word);
+        } else if *capitalize {
+            cased.push_str(&capitalize_first(&word.to_lowercase()));
+        } else {
+            cased.push_str(&word.to_lowercase());
+        }
+        *capitalize = word.ends_with(':');
+        word.clear();
+    };
+
+    for c in text.chars() {
+        match c {
+            '{' => {
+                flush(&mut word, &mut cased, &mut capitalize);
+                depth += 1;
+            }
+            '}' if depth > 0 => depth -= 1,
+            _ if depth > 0 => {
+                cased.push(c);
+                capitalize = false;
+            }
+            _ if c.is_whitespace() => {
+                flush(&mut word, &mut cased, &mut capitalize);
+                cased.push(c);
+            }
+            _ => word.push(c),
+        }
+    }
+    flush(&mut word, &mut cased, &mut capitalize);
+    cased
+}
+
+/// Wrap the words of `text` that have a capital after their first letter,
+/// like "DNA" or "iPhone", in braces so that BibTeX styles don't change
+/// their case. Text that already contains braces is left alone.
+pub fn protect_case(text: &str) -> String {
+    if text.contains(['{', '}']) {
+        return text.to_string();
+    }
+    text.split(' ')
+        .map(|token| {
+            let (Some(start), Some(end)) = (
+                token.find(char::is_alphanumeric),
+                token.rfind(char::is_alphanumeric),
+            ) else {
+                return token.to_string();
+            };
+            let end = end + token[end..].chars().next().map_or(0, char::len_utf8);
+            if has_inner_capital(&token[start..end]) {
+                format!(
+                    "{}{{{}}}{}",
+                    &token[..start],
+                    &token[start..end],
+                    &token[end..]
+                )
+            } else {
+                token.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether a capital letter follows the first letter of `word`
+fn has_inner_capital(word: &str) -> bool {
+    word.chars()
+        .skip_while(|c| !c.is_alphabetic())
+        .skip(1)
+        .any(char::is_uppercase)
+}
+
+fn join(main: &str, subtitle: &str) -> String {
+    if main.ends_with(['?', '!', ':']) {
+        format!("{} {}", main, subtitle)
+    } else {
+        format!("{}: {}", main, subtitle)
+    }
+}
+
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// The title and subtitle joined with ": "
+impl fmt::Display for Title {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.subtitle {
+            Some(subtitle) => write!(f, "{}", join(&self.main, subtitle)),
+            None => write!(f, "{}", self.main),
+        }
+    }
+}
+
+impl From<String> for Title {
+    fn from(main: String) -> Self {
+        Self::new(main)
+    }
+}
+
+impl From<&str> for Title {
+    fn from(main: &str) -> Self {
+        Self::new(main)
+    }
+}
+
+impl PartialEq<str> for Title {
+    fn eq(&self, other: &str) -> bool {
+        match &self.subtitle {
+            Some(_) => {
+                let joined = self.to_string();
+                joined == other
+            }
+            None => self.main == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for Title {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// The quotation marks put around the titles of chapters, articles and
+/// other works that are part of a larger one.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+    /// \u{201C}Title\u{201D}, with \u{2018}single\u{2019} quotes nested
+    #[default]
+    Curly,
+    /// "Title", with 'single' quotes nested
+    Straight,
+    /// \u{AB}\u{A0}Title\u{A0}\u{BB} as used in French, with \u{201C}curly\u{201D}
+    /// quotes nested
+    Guillemets,
+}
+
+impl QuoteStyle {
+    fn outer(self) -> (&'static str, &'static str) {
+        match self {
+            QuoteStyle::Curly => ("\u{201C}", "\u{201D}"),
+            QuoteStyle::Straight => ("\"", "\""),
+            QuoteStyle::Guillemets => ("\u{AB}\u{A0}", "\u{A0}\u{BB}"),
+        }
+    }
+
+    fn nested(self) -> (&'static str, &'static str) {
+        match self {
+            QuoteStyle::Curly => ("\u{2018}", "\u{2019}"),
+            QuoteStyle::Straight => ("'", "'"),
+            QuoteStyle::Guillemets => ("\u{201C}", "\u{201D}"),
+        }
+    }
+
+    /// Put `text` in quotation marks. Double quotes already in `text` are
+    /// replaced with this style's nested quotes.
+    pub fn quote(self, text: &str) -> String {
+        let (open, close) = self.outer();
+        format!("{}{}{}", open, self.nest(text), close)
+    }
+
+    /// Quote `text` with `punctuation` placed where the style puts it: inside
+    /// the closing mark for English quotes, outside for guillemets.
+    pub fn quote_with(self, text: &str, punctuation: &str) -> String {
+        match self {
+            QuoteStyle::Guillemets => format!("{}{}", self.quote(text), punctuation),
+            QuoteStyle::Curly | QuoteStyle::Straight => {
+                self.quote(&format!("{}{}", text, punctuation))
+            }
+        }
+    }
+
+    fn nest(self, text: &str) -> String {
+        let (open, close) = self.nested();
+        let mut nested = String::with_capacity(text.len());
+        let mut inside = false;
+        for c in text.chars() {
+            match c {
+                '\u{201C}' => nested.push_str(open),
+                '\u{201D}' => nested.push_str(close),
+                '"' => {
+                    nested.push_str(if inside { close } else { open });
+                    inside = !inside;
+                }
+                c => nested.push(c),
+            }
+        }
+        nested
+    }
+}
+
+// Formatters mark emphasis with these private-use characters rather than
+// tags, so that `TitleRenderer::render` can escape the whole reference at
+// once without escaping its own markup.
+const ITALIC_START: char = '\u{E000}';
+const ITALIC_END: char = '\u{E001}';
+const BOLD_START: char = '\u{E002}';
+const BOLD_END: char = '\u{E003}';
+
+/// Sets titles apart from the rest of a reference. Formatters decide
+/// whether a title is quoted, italicized or left plain; how each of those
+/// looks is decided here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TitleRenderer {
+    pub quotes: QuoteStyle,
+    pub markup: Markup,
+}
+
+impl TitleRenderer {
+    /// A title that needs no emphasis, followed by `punctuation`
+    pub fn plain(&self, title: &str, punctuation: &str) -> String {
+        format!("{}{}", title, punctuation_after(title, punctuation))
+    }
+
+    /// The title of a work that is part of a larger one, such as an
+    /// article or a chapter, in quotation marks
+    pub fn quoted(&self, title: &str, punctuation: &str) -> String {
+        self.quotes
+            .quote_with(title, punctuation_after(title, punctuation))
+    }
+
+    /// The title of a work that stands alone, such as a book or a journal,
+    /// in italics. The punctuation is not italicized.
+    pub fn italic(&self, title: &str, punctuation: &str) -> String {
+        let punctuation = punctuation_after(title, punctuation);
+        match self.markup {
+            Markup::PlainText => format!("{}{}", title, punctuation),
+            Markup::Html | Markup::Odf => {
+                format!("{}{}{}{}", ITALIC_START, title, ITALIC_END, punctuation)
+            }
+        }
+    }
+
+    /// Bold text, such as the year and volume of an ACS journal reference
+    pub fn bold(&self, text: &str) -> String {
+        match self.markup {
+            Markup::PlainText => text.to_string(),
+            Markup::Html | Markup::Odf => format!("{}{}{}", BOLD_START, text, BOLD_END),
+        }
+    }
+
+    /// Turn a formatted reference into markup. Everything in it is escaped;
+    /// only the spans marked by [`italic`](Self::italic) and
+    /// [`bold`](Self::bold) become tags. Plain text is returned as is.
+    pub fn render(&self, formatted: &str) -> String {
+        let (italic, bold) = match self.markup {
+            Markup::PlainText => return formatted.to_string(),
+            Markup::Html => (("<i>", "</i>"), ("<b>", "</b>")),
+            Markup::Odf => (
+                ("<text:span text:style-name=\"Italic\">", "</text:span>"),
+                ("<text:span text:style-name=\"Bold\">", "</text:span>"),
+            ),
+        };
+        let mut rendered = String::with_capacity(formatted.len());
+        let mut text = String::new();
+        for c in formatted.chars() {
+            let tag = match c {
+                ITALIC_START => italic.0,
+                ITALIC_END => italic.1,
+                BOLD_START => bold.0,
+                BOLD_END => bold.1,
+                c => {
+                    text.push(c);
+                    continue;
+                }
+            };
+            rendered.push_str(&escape(&text));
+            rendered.push_str(tag);
+            text.clear();
+        }
+        rendered.push_str(&escape(&text));
+        rendered
+    }
+}
+
+/// The punctuation to put after `title`; left out after a title that
+/// already ends with a question or exclamation mark, or with the same mark
+fn punctuation_after<'a>(title: &str, punctuation: &'a str) -> &'a str {
+    let ends_sentence =
+        title.ends_with(['?', '!']) || (!punctuation.is_empty() && title.ends_with(punctuation));
+    if ends_sentence { "" } else { punctuation }
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum TitleRepr {
+    Plain(String),
+    WithSubtitle { main: String, subtitle: String },
+}
+
+impl From<TitleRepr> for Title {
+    fn from(repr: TitleRepr) -> Self {
+        match repr {
+            TitleRepr::Plain(main) => Self::new(main),
+            TitleRepr::WithSubtitle { main, subtitle } => Self::with_subtitle(main, subtitle),
+        }
+    }
+}
+
+impl From<Title> for TitleRepr {
+    fn from(title: Title) -> Self {
+        match title.subtitle {
+            Some(subtitle) => TitleRepr::WithSubtitle {
+                main: title.main,
+                subtitle,
+            },
+            None => TitleRepr::Plain(title.main),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{
+        style::Markup,
+        title::{QuoteStyle, Title, TitleRenderer, protect_case, sentence_case},
+    };
+
+    #[test]
+    fn test_style_joining() {
+        let title = Title::with_subtitle("Rust in action", "systems programming concepts");
+
+        assert_eq!(
+            title.as_apa_string(),
+            "Rust in action: Systems programming concepts"
+        );
+        assert_eq!(
+            title.as_ieee_string(),
+            "Rust in action: systems programming concepts"
+        );
+        assert_eq!(
+            Title::with_subtitle("Why Rust?", "a primer").as_apa_string(),
+            "Why Rust? A primer"
+        );
+        assert_eq!(Title::new("Plain").as_apa_string(), "Plain");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Title::parse("Principles of Things: A Primer"),
+            Title::with_subtitle("Principles of Things", "A Primer")
+        );
+        assert_eq!(Title::parse(": odd"), Title::new(": odd"));
+        assert_eq!(Title::parse("No subtitle"), "No subtitle");
+    }
+
+    #[test]
+    fn test_serialization() {
+        assert_eq!(
+            serde_json::to_string(&Title::new("Plain")).unwrap(),
+            "\"Plain\""
+        );
+        let title = Title::with_subtitle("Main", "sub");
+        let json = serde_json::to_string(&title).unwrap();
+
+        assert_eq!(json, r#"{"main":"Main","subtitle":"sub"}"#);
+        assert_eq!(serde_json::from_str::<Title>(&json).unwrap(), title);
+        assert_eq!(
+            serde_json::from_str::<Title>("\"Plain\"").unwrap(),
+            Title::new("Plain")
+        );
+    }
+
+    #[test]
+    fn test_quote_styles() {
+        assert_eq!(
+            QuoteStyle::Curly.quote_with("Findings", ","),
+            "\u{201C}Findings,\u{201D}"
+        );
+        assert_eq!(QuoteStyle::Straight.quote("Findings"), "\"Findings\"");
+        assert_eq!(
+            QuoteStyle::Guillemets.quote_with("Résultats", ","),
+            "\u{AB}\u{A0}Résultats\u{A0}\u{BB},"
+        );
+    }
+
+    #[test]
+    fn test_nested_quotes() {
+        assert_eq!(
+            QuoteStyle::Curly.quote("On \"Hamlet\" again"),
+            "\u{201C}On \u{2018}Hamlet\u{2019} again\u{201D}"
+        );
+        assert_eq!(
+            QuoteStyle::Straight.quote("On \u{201C}Hamlet\u{201D} again"),
+            "\"On 'Hamlet' again\""
+        );
+    }
+
+    #[test]
+    fn test_renderer_punctuation() {
+        let titles = TitleRenderer::default();
+
+        assert_eq!(titles.plain("Findings", "."), "Findings.");
+        assert_eq!(titles.plain("Why Rust?", "."), "Why Rust?");
+        assert_eq!(titles.italic("Findings.", "."), "Findings.");
+        assert_eq!(titles.quoted("Why Rust?", ","), "\u{201C}Why Rust?\u{201D}");
+    }
+
+    #[test]
+    fn test_renderer_html() {
+        let titles = TitleRenderer {
+            markup: Markup::Html,
+            ..Default::default()
+        };
+
+        let render = |text: String| titles.render(&text);
+
+        assert_eq!(
+            render(titles.italic("Tom & Jerry", ".")),
+            "<i>Tom &amp; Jerry</i>."
+        );
+        assert_eq!(
+            render(titles.quoted("Using <cite>", ",")),
+            "\u{201C}Using &lt;cite&gt;,\u{201D}"
+        );
+        assert_eq!(
+            render(format!("AT&T. {}", titles.bold("2020"))),
+            "AT&amp;T. <b>2020</b>"
+        );
+    }
+
+    #[test]
+    fn test_renderer_odf() {
+        let titles = TitleRenderer {
+            markup: Markup::Odf,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            titles.render(&titles.italic("Q&A", ".")),
+            "<text:span text:style-name=\"Italic\">Q&amp;A</text:span>."
+        );
+        assert_eq!(TitleRenderer::default().render("Q&A"), "Q&A");
+    }
+
+    #[test]
+    fn test_sentence_case() {
+        assert_eq!(
+            sentence_case("{DNA} Repair in {Escherichia coli}: A Review"),
+            "DNA repair in Escherichia coli: A review"
+        );
+        assert_eq!(sentence_case("THE iPhone AND NASA"), "THE iPhone AND NASA");
+        assert_eq!(
+            sentence_case("Learning Rust the Hard Way"),
+            "Learning rust the hard way"
+        );
+        assert_eq!(
+            Title::with_subtitle("Protein Folding", "{AlphaFold} And Beyond").to_sentence_case(),
+            Title::with_subtitle("Protein folding", "AlphaFold and beyond")
+        );
+    }
+
+    #[test]
+    fn test_protect_case_round_trip() {
+        let title = "Sequencing DNA (and RNA) with iPhones";
+
+        assert_eq!(
+            protect_case(title),
+            "Sequencing {DNA} (and {RNA}) with {iPhones}"
+        );
+        assert_eq!(
+            sentence_case(&protect_case(title)),
+            "Sequencing DNA (and RNA) with iPhones"
+        );
+        assert_eq!(protect_case("Already {Protected}"), "Already {Protected}");
+    }
+}
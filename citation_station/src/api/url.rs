@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::style::Markup;
+
+/// How URLs are shown in formatted references.
+///
+/// The same policy is applied by every media type that prints a URL, so
+/// switching a style's policy changes all web references consistently.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct UrlPolicy {
+    /// Leave the URL out when the work also has a DOI
+    pub prefer_doi: bool,
+    /// Remove the query string and fragment. Beware that some sites
+    /// (e.g. YouTube) need the query string to identify the resource.
+    pub strip_query: bool,
+    /// Remove the scheme and a leading "www."
+    pub shorten: bool,
+    /// Let long URLs wrap after their slashes, as IEEE allows. Only HTML
+    /// and ODF output can mark where a line may break; see [`break_urls`].
+    #[serde(default)]
+    pub break_long: bool,
+}
+
+impl UrlPolicy {
+    /// Show URLs exactly as stored, except when a DOI is available.
+    pub const fn verbatim() -> Self {
+        Self {
+            prefer_doi: true,
+            strip_query: false,
+            shorten: false,
+            break_long: false,
+        }
+    }
+
+    /// Show URLs exactly as stored, and alongside the DOI when the work has
+    /// one, as ISO 690 and Vancouver do
+    pub const fn with_doi() -> Self {
+        Self {
+            prefer_doi: false,
+            ..Self::verbatim()
+        }
+    }
+
+    /// The URL to print for a work, or `None` if it should be left out.
+    pub fn apply(&self, url: &str, has_doi: bool) -> Option<String> {
+        if self.prefer_doi && has_doi {
+            return None;
+        }
+
+        let mut url = url.trim();
+        if self.strip_query
+            && let Some(end) = url.find(['?', '#'])
+        {
+            url = &url[..end];
+        }
+        if self.shorten {
+            url = url
+                .strip_prefix("https://")
+                .or_else(|| url.strip_prefix("http://"))
+                .unwrap_or(url);
+            url = url.strip_prefix("www.").unwrap_or(url);
+        }
+
+        if url.is_empty() {
+            None
+        } else {
+            Some(url.to_string())
+        }
+    }
+}
+
+/// Mark where the URLs in a rendered reference may wrap: after each slash
+/// of the path, with `<wbr>` in HTML and a zero-width space in ODF. Plain
+/// text is returned as is. URLs already turned into links are left alone.
+pub(crate) fn break_urls(reference: &str, markup: Markup) -> String {
+    let opportunity = match markup {
+        Markup::PlainText => return reference.to_string(),
+        Markup::Html => "<wbr>",
+        Markup::Odf => "\u{200B}",
+    };
+    reference
+        .split(' ')
+        .map(|word| match word.split_once("://") {
+            Some((scheme, location)) if matches!(scheme, "http" | "https") => format!(
+                "{}://{}",
+                scheme,
+                location
+                    .split_inclusive('/')
+                    .collect::<Vec<_>>()
+                    .join(opportunity)
+            ),
+            _ => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Default for UrlPolicy {
+    fn default() -> Self {
+        Self::verbatim()
+    }
+}
+
+/// Decode `%XX` escapes. Returns `None` if an escape is malformed or the
+/// result isn't UTF-8.
+pub(crate) fn percent_decode(input: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(input.len());
+    let mut rest = input.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{
+        style::Markup,
+        url::{UrlPolicy, break_urls},
+    };
+
+    const URL: &str = "https://www.example.com/manual.pdf?utm_source=feed#page=4";
+
+    #[test]
+    fn test_verbatim() {
+        assert_eq!(
+            UrlPolicy::verbatim().apply(URL, false),
+            Some(URL.to_string())
+        );
+    }
+
+    #[test]
+    fn test_prefer_doi() {
+        assert_eq!(UrlPolicy::verbatim().apply(URL, true), None);
+    }
+
+    #[test]
+    fn test_strip_query_and_shorten() {
+        let policy = UrlPolicy {
+            prefer_doi: false,
+            strip_query: true,
+            shorten: true,
+            break_long: false,
+        };
+
+        assert_eq!(
+            policy.apply(URL, true),
+            Some("example.com/manual.pdf".to_string())
+        );
+    }
+
+    #[test]
+    fn test_break_urls() {
+        let reference = "Available: https://example.com/docs/intro.html?a=1&amp;b=2";
+
+        assert_eq!(
+            break_urls(reference, Markup::Html),
+            "Available: https://example.com/<wbr>docs/<wbr>intro.html?a=1&amp;b=2"
+        );
+        assert_eq!(
+            break_urls(reference, Markup::Odf),
+            "Available: https://example.com/\u{200B}docs/\u{200B}intro.html?a=1&amp;b=2"
+        );
+        assert_eq!(break_urls(reference, Markup::PlainText), reference);
+        assert_eq!(
+            break_urls(
+                r#"<a href="https://example.com/a">example.com</a>"#,
+                Markup::Html
+            ),
+            r#"<a href="https://example.com/a">example.com</a>"#
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Double dispatch over media types, so code outside the crate can handle
+//! each kind of citation without matching on [`Citation`].
+
+use crate::api::{
+    citation::Citation,
+    media::{
+        book::Book,
+        conference_paper::{ConferencePaperOnline, ConferenceProceedingsOnline},
+        conference_presentation::ConferencePresentation,
+        custom::CustomMedia,
+        journal_article::JournalArticle,
+        online_manual::OnlineManual,
+        online_video::OnlineVideo,
+        software::Software,
+        web_page::WebPage,
+        wikipedia_article::WikipediaArticle,
+    },
+};
+
+/// Called with the media-specific data of each citation passed to
+/// [`Citation::accept`].
+///
+/// Every method does nothing by default, so a visitor only implements the
+/// media types it cares about, and keeps compiling when new ones are
+/// added.
+pub trait CitationVisitor {
+    fn visit_book(&mut self, _book: &Book) {}
+
+    fn visit_conference_paper_online(&mut self, _paper: &ConferencePaperOnline) {}
+
+    fn visit_conference_proceedings_online(&mut self, _proceedings: &ConferenceProceedingsOnline) {}
+
+    fn visit_journal_article(&mut self, _article: &JournalArticle) {}
+
+    fn visit_conference_presentation(&mut self, _presentation: &ConferencePresentation) {}
+
+    fn visit_online_manual(&mut self, _manual: &OnlineManual) {}
+
+    fn visit_online_video(&mut self, _video: &OnlineVideo) {}
+
+    fn visit_software(&mut self, _software: &Software) {}
+
+    fn visit_web_page(&mut self, _page: &WebPage) {}
+
+    fn visit_wikipedia_article(&mut self, _article: &WikipediaArticle) {}
+
+    fn visit_custom(&mut self, _custom: &CustomMedia) {}
+}
+
+/// See [`Citation::accept`]
+pub(crate) fn accept<V: CitationVisitor + ?Sized>(citation: &Citation, visitor: &mut V) {
+    match citation {
+        Citation::Book(book) => visitor.visit_book(book),
+        Citation::ConferencePaperOnline(paper) => visitor.visit_conference_paper_online(paper),
+        Citation::ConferenceProceedingsOnline(proceedings) => {
+            visitor.visit_conference_proceedings_online(proceedings)
+        }
+        Citation::JournalArticle(article) => visitor.visit_journal_article(article),
+        Citation::ConferencePresentation(presentation) => {
+            visitor.visit_conference_presentation(presentation)
+        }
+        Citation::OnlineManual(manual) => visitor.visit_online_manual(manual),
+        Citation::OnlineVideo(video) => visitor.visit_online_video(video),
+        Citation::Software(software) => visitor.visit_software(software),
+        Citation::WebPage(page) => visitor.visit_web_page(page),
+        Citation::WikipediaArticle(article) => visitor.visit_wikipedia_article(article),
+        Citation::Custom(custom) => visitor.visit_custom(custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{
+        author::{AcademicAuthor, GenericAuthor},
+        citation::Citation,
+        media::{book::Book, common::CommonCitationData, journal_article::JournalArticle},
+        visitor::CitationVisitor,
+    };
+
+    fn common_data(id: &str) -> CommonCitationData {
+        CommonCitationData::new(id)
+    }
+
+    /// Collects the journals of articles and ignores everything else
+    #[derive(Default)]
+    struct Journals(Vec<String>);
+
+    impl CitationVisitor for Journals {
+        fn visit_journal_article(&mut self, article: &JournalArticle) {
+            self.0.push(article.journal.clone());
+        }
+    }
+
+    #[test]
+    fn test_accept_dispatches_by_media_type() {
+        let citations = [
+            Citation::JournalArticle(JournalArticle {
+                common_data: common_data("article"),
+                author: AcademicAuthor::Organization {
+                    name: "Org".to_string(),
+                    abbreviation: None,
+                },
+                title: "Title".into(),
+                journal: "J. Tests".to_string(),
+                volume: None,
+                number: None,
+                locator: None,
+                doi: None,
+            }),
+            Citation::Book(Book {
+                common_data: common_data("book"),
+                author: GenericAuthor::Organization {
+                    name: "Org".to_string(),
+                    abbreviation: None,
+                },
+                with_authors: Vec::new(),
+                title: "A Book".into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            }),
+        ];
+
+        let mut journals = Journals::default();
+        for citation in &citations {
+            citation.accept(&mut journals);
+        }
+
+        assert_eq!(journals.0, ["J. Tests"]);
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::unicode::ENDASH;
+
+/// The number of a volume or issue: a plain number, a range of combined
+/// numbers (e.g. a double issue "3–4"), or anything else, such as
+/// "Suppl. 2" or "Special Issue".
+///
+/// Serialized as the display string, so that both structured and free-form
+/// values round-trip through a single text field.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(from = "String", into = "String")]
+pub enum SerialNumber {
+    Number(u32),
+    Range { start: u32, end: u32 },
+    Named(String),
+}
+
+impl SerialNumber {
+    /// Read "12", "3-4" (or "3–4"), falling back to a named value.
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        if let Ok(number) = s.parse() {
+            return SerialNumber::Number(number);
+        }
+        if let Some((start, end)) = s.split_once(['-', ENDASH])
+            && let (Ok(start), Ok(end)) = (start.trim().parse(), end.trim().parse())
+        {
+            return SerialNumber::Range { start, end };
+        }
+        SerialNumber::Named(s.to_string())
+    }
+
+    /// `singular` or `plural` followed by the number, except for named
+    /// values, which already say what they are
+    fn with_label(&self, singular: &str, plural: &str) -> String {
+        match self {
+            SerialNumber::Number(_) => format!("{} {}", singular, self),
+            SerialNumber::Range { .. } => format!("{} {}", plural, self),
+            SerialNumber::Named(name) => name.clone(),
+        }
+    }
+}
+
+impl fmt::Display for SerialNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerialNumber::Number(number) => write!(f, "{}", number),
+            SerialNumber::Range { start, end } => write!(f, "{}{}{}", start, ENDASH, end),
+            SerialNumber::Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl From<String> for SerialNumber {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+impl From<SerialNumber> for String {
+    fn from(number: SerialNumber) -> Self {
+        number.to_string()
+    }
+}
+
+/// Volume of a journal or proceedings series
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Volume(pub SerialNumber);
+
+impl Volume {
+    pub fn parse(s: &str) -> Self {
+        Self(SerialNumber::parse(s))
+    }
+
+    /// e.g. "vol. 12", "vols. 3–4" or "Suppl. 2"
+    pub fn as_ieee_string(&self) -> String {
+        self.0.with_label("vol.", "vols.")
+    }
+}
+
+impl fmt::Display for Volume {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Issue (or number) within a volume
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Issue(pub SerialNumber);
+
+impl Issue {
+    pub fn parse(s: &str) -> Self {
+        Self(SerialNumber::parse(s))
+    }
+
+    /// e.g. "no. 3", "nos. 3–4" or "Suppl. 2"
+    pub fn as_ieee_string(&self) -> String {
+        self.0.with_label("no.", "nos.")
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Volume and issue as APA writes them after the journal name, e.g.
+/// "12(3)". The issue is only shown alongside a volume.
+pub fn apa_volume_issue(volume: Option<&Volume>, issue: Option<&Issue>) -> Option<String> {
+    let volume = volume?;
+    Some(match issue {
+        Some(issue) => format!("{}({})", volume, issue),
+        None => volume.to_string(),
+    })
+}
+
+/// Volume and issue as IEEE writes them, e.g. ["vol. 12", "no. 3"]
+pub fn ieee_volume_issue(volume: Option<&Volume>, issue: Option<&Issue>) -> Vec<String> {
+    volume
+        .map(Volume::as_ieee_string)
+        .into_iter()
+        .chain(issue.map(Issue::as_ieee_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::volume::{Issue, SerialNumber, Volume, apa_volume_issue, ieee_volume_issue};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(SerialNumber::parse(" 12 "), SerialNumber::Number(12));
+        assert_eq!(
+            SerialNumber::parse("3-4"),
+            SerialNumber::Range { start: 3, end: 4 }
+        );
+        assert_eq!(
+            SerialNumber::parse("Suppl. 2"),
+            SerialNumber::Named("Suppl. 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ieee_rendering() {
+        assert_eq!(
+            ieee_volume_issue(Some(&Volume::parse("12")), Some(&Issue::parse("3-4"))),
+            vec!["vol. 12", "nos. 3\u{2013}4"]
+        );
+        assert_eq!(
+            ieee_volume_issue(None, Some(&Issue::parse("Suppl. 2"))),
+            vec!["Suppl. 2"]
+        );
+    }
+
+    #[test]
+    fn test_apa_rendering() {
+        assert_eq!(
+            apa_volume_issue(Some(&Volume::parse("12")), Some(&Issue::parse("3"))),
+            Some("12(3)".to_string())
+        );
+        assert_eq!(apa_volume_issue(None, Some(&Issue::parse("3"))), None);
+    }
+
+    #[test]
+    fn test_serializes_as_string() {
+        let volume = Volume::parse("3-4");
+        let json = serde_json::to_string(&volume).unwrap();
+
+        assert_eq!(json, "\"3\u{2013}4\"");
+        assert_eq!(serde_json::from_str::<Volume>(&json).unwrap(), volume);
+        assert_eq!(
+            serde_json::from_str::<Issue>("\"7\"").unwrap(),
+            Issue(SerialNumber::Number(7))
+        );
+    }
+}
@@ -1,12 +1,155 @@
-use chrono::Month;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Month, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::api::{citation::Citation, date::PublishDate, errors::CitationError};
+#[cfg(feature = "net")]
+use crate::net::{FetchConfig, RetryingClient, UreqClient};
+use crate::{
+    analysis::{
+        AuthorGroup, CoAuthorGraph, Coupling, bibliographic_coupling, co_author_graph,
+        group_by_author, group_by_venue,
+    },
+    api::{
+        author::PersonName,
+        citation::Citation,
+        citation_id::CitationId,
+        date::{AccessDate, PublishDate},
+        errors::{CitationError, StorageError},
+        media::{MediaKind, book::Book, journal_article::JournalArticle, web_page::WebPage},
+        style::{CitationStyle, FormatOptions, Markup},
+    },
+    bibtex::citations_to_bibtex,
+    collation::{Collation, SortKey, SortOptions},
+    dedup::{DedupStrategy, DuplicatePair, find_duplicates},
+    export::ExportOptions,
+    graph::CitationGraph,
+    import::{
+        BatchImport, ImportError, ImportReport, Progress,
+        bookmarks::read_bookmarks,
+        doi::{fetch_doi, normalize_doi},
+        onix::read_onix,
+        record_cache_hit, run_bounded,
+        web::{fetch_web_page, normalize_url},
+    },
+    in_text::{
+        CiteRef, InTextCitation, apa_cluster, disambiguate, ieee_cluster, iso690_cluster,
+        vancouver_cluster,
+    },
+    jats::citations_to_jats,
+    length::{ReferenceListLength, measure},
+    lint::{LintIssue, lint},
+    merge::{Merge, merge3},
+    merge_proposal::{MergeError, MergeProposal, Resolutions},
+    microdata::{anchor, citation_to_microdata},
+    name_variants::{NameCluster, NameConfidence, find_name_variants},
+    net::HttpClient,
+    notes::{read_notes, write_notes},
+    odf::reference_list_to_odt,
+    org::{citations_to_org, read_org},
+    ris::citations_to_ris,
+    word::citations_to_word_sources,
+};
+
+/// A run of consecutive reference list entries, for showing a long list
+/// a page at a time. See [`Bibliography::render_chunks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceChunk {
+    /// The letter the entries are filed under in alphabetical styles,
+    /// e.g. "A"; `None` in numeric styles
+    pub heading: Option<String>,
+    /// Whether the chunk carries on from the previous one under the same
+    /// heading, e.g. to show "A (continued)"
+    pub continued: bool,
+    /// Position of the first entry in the whole list, from 0
+    pub start: usize,
+    /// The formatted entries, with their labels in numeric styles
+    pub entries: Vec<String>,
+}
+
+/// Every entry formatted in several styles: citation ID → style →
+/// formatted entry. See [`Bibliography::render_bundle`].
+pub type RenderBundle = BTreeMap<String, BTreeMap<CitationStyle, String>>;
+
+/// A change to a bibliography, as reported to observers registered with
+/// [`Bibliography::on_change`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChangeEvent {
+    /// A citation with this ID was added
+    Added(String),
+    /// The citation with this ID was removed
+    Removed(String),
+    /// A citation was changed in place. `id` differs from `previous_id`
+    /// if its ID was changed.
+    Updated { previous_id: String, id: String },
+    /// The citations were sorted
+    Reordered,
+}
+
+type Observer = Box<dyn FnMut(&ChangeEvent) + Send + Sync>;
+
+/// Observers of a bibliography, and the events held back by transactions
+/// that are in progress.
+///
+/// Observers belong to one bibliography instance: they are neither cloned
+/// nor saved.
+#[derive(Default)]
+struct Observers {
+    observers: Vec<Observer>,
+    pending: Option<Vec<ChangeEvent>>,
+}
+
+impl Observers {
+    fn notify(&mut self, event: ChangeEvent) {
+        match &mut self.pending {
+            Some(pending) => pending.push(event),
+            None => {
+                for observer in &mut self.observers {
+                    observer(&event);
+                }
+            }
+        }
+    }
+}
+
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} observer(s)", self.observers.len())
+    }
+}
+
+/// How many snapshots [`Bibliography::undo`] can go back
+const UNDO_LIMIT: usize = 100;
+
+/// Snapshots of the citations for [`Bibliography::undo`] and
+/// [`Bibliography::redo`], most recent last. Like observers, they are not
+/// saved.
+#[derive(Debug, Clone, Default)]
+struct History {
+    undo: Vec<Vec<Citation>>,
+    redo: Vec<Vec<Citation>>,
+}
 
 /// A collection of citations forming a bibliography
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Bibliography {
     citations: Vec<Citation>,
+    #[serde(skip)]
+    observers: Observers,
+    #[serde(skip)]
+    history: History,
 }
 
 impl Bibliography {
@@ -14,11 +157,24 @@ impl Bibliography {
     pub fn new() -> Self {
         Self {
             citations: Vec::new(),
+            observers: Observers::default(),
+            history: History::default(),
         }
     }
 
-    /// Add a citation to the bibliography
-    pub fn add_citation(&mut self, citation: Citation) -> Result<(), CitationError> {
+    /// Call `observer` after every change to the bibliography.
+    ///
+    /// Changes made within a [`Bibliography::transaction`] are reported
+    /// once it succeeds, and not at all if it is rolled back. Edits made
+    /// through [`Bibliography::get_citation_mut`] are not reported; use
+    /// [`Bibliography::update_citation`] instead.
+    pub fn on_change(&mut self, observer: impl FnMut(&ChangeEvent) + Send + Sync + 'static) {
+        self.observers.observers.push(Box::new(observer));
+    }
+
+    /// Add a citation to the bibliography. Its creation time is set to now
+    /// unless it already has one.
+    pub fn add_citation(&mut self, mut citation: Citation) -> Result<(), CitationError> {
         // Check for duplicate IDs
         if self.citations.iter().any(|c| c.id() == citation.id()) {
             return Err(CitationError::InvalidFormat(format!(
@@ -27,6 +183,9 @@ impl Bibliography {
             )));
         }
 
+        let timestamps = &mut citation.common_data_mut().timestamps;
+        timestamps.created.get_or_insert_with(Utc::now);
+        self.observers.notify(ChangeEvent::Added(citation.id()));
         self.citations.push(citation);
         Ok(())
     }
@@ -36,21 +195,1111 @@ impl Bibliography {
         self.citations.iter().find(|c| c.id() == id)
     }
 
+    /// Get a citation by ID for editing. Changing the ID through the
+    /// returned reference can introduce duplicates; use
+    /// [`Bibliography::replace_citation`] to change a citation's ID safely.
+    pub fn get_citation_mut(&mut self, id: &str) -> Option<&mut Citation> {
+        self.citations.iter_mut().find(|c| c.id() == id)
+    }
+
+    /// Remove the citation with the given ID and return it
+    pub fn remove_citation(&mut self, id: &str) -> Option<Citation> {
+        let position = self.citations.iter().position(|c| c.id() == id)?;
+        self.observers.notify(ChangeEvent::Removed(id.to_string()));
+        Some(self.citations.remove(position))
+    }
+
+    /// Edit the citation with ID `id` in place, update its modification
+    /// time and notify observers. Use [`Bibliography::replace_citation`]
+    /// to change its ID.
+    pub fn update_citation(
+        &mut self,
+        id: &str,
+        edit: impl FnOnce(&mut Citation),
+    ) -> Result<(), CitationError> {
+        let citation = self
+            .get_citation_mut(id)
+            .ok_or(CitationError::UnknownId(id.to_string()))?;
+        edit(citation);
+        mark_modified(citation);
+        let event = ChangeEvent::Updated {
+            previous_id: id.to_string(),
+            id: citation.id(),
+        };
+        self.observers.notify(event);
+        Ok(())
+    }
+
+    /// Replace the citation with ID `id`, keeping its position in the
+    /// bibliography, and return the old citation. The new citation may
+    /// have a different ID as long as no other entry uses it. It keeps the
+    /// old citation's creation time unless it has its own.
+    pub fn replace_citation(
+        &mut self,
+        id: &str,
+        mut citation: Citation,
+    ) -> Result<Citation, CitationError> {
+        let position = self
+            .citations
+            .iter()
+            .position(|c| c.id() == id)
+            .ok_or(CitationError::UnknownId(id.to_string()))?;
+        if citation.id() != id && self.get_citation(&citation.id()).is_some() {
+            return Err(CitationError::InvalidFormat(format!(
+                "Citation with ID '{}' already exists",
+                citation.id()
+            )));
+        }
+        let created = self.citations[position].common_data().timestamps.created;
+        let timestamps = &mut citation.common_data_mut().timestamps;
+        timestamps.created = timestamps.created.or(created);
+        mark_modified(&mut citation);
+        self.observers.notify(ChangeEvent::Updated {
+            previous_id: id.to_string(),
+            id: citation.id(),
+        });
+        Ok(std::mem::replace(&mut self.citations[position], citation))
+    }
+
+    /// Apply a batch of changes atomically.
+    ///
+    /// `changes` may add, remove, replace and edit entries as usual. If it
+    /// returns an error, every change it made is rolled back, including
+    /// snapshots taken and changes undone, and the error is passed on;
+    /// otherwise all of them are kept.
+    ///
+    /// ```
+    /// # use citation_station::{api::errors::CitationError, bibliography::Bibliography};
+    /// # let mut bibliography = Bibliography::new();
+    /// # let (a, b) = (Vec::new(), Vec::new());
+    /// bibliography.transaction(|tx| {
+    ///     for citation in a.into_iter().chain(b) {
+    ///         tx.add_citation(citation)?;
+    ///     }
+    ///     Ok::<_, CitationError>(())
+    /// })?;
+    /// # Ok::<_, CitationError>(())
+    /// ```
+    pub fn transaction<T, E>(
+        &mut self,
+        changes: impl FnOnce(&mut Bibliography) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let snapshot = (self.citations.clone(), self.history.clone());
+        let outer_pending = self.observers.pending.replace(Vec::new());
+        let result = changes(self);
+        let events = std::mem::replace(&mut self.observers.pending, outer_pending);
+        match &result {
+            Ok(_) => {
+                for event in events.unwrap_or_default() {
+                    self.observers.notify(event);
+                }
+            }
+            Err(_) => (self.citations, self.history) = snapshot,
+        }
+        result
+    }
+
+    /// Record the current citations, so that the changes that follow can
+    /// be reverted with [`Bibliography::undo`]. Call it before each
+    /// operation the user may want to take back, e.g. merging duplicates.
+    ///
+    /// Only the last 100 snapshots are kept. Taking a snapshot clears the
+    /// redo history.
+    pub fn begin_snapshot(&mut self) {
+        if self.history.undo.len() == UNDO_LIMIT {
+            self.history.undo.remove(0);
+        }
+        self.history.undo.push(self.citations.clone());
+        self.history.redo.clear();
+    }
+
+    /// Revert the changes made since the last [`Bibliography::begin_snapshot`].
+    /// Returns `false` if there is nothing to undo.
+    ///
+    /// Observers are told about every citation that was added, removed or
+    /// changed as a result.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.history.undo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.history.redo.push(current);
+        true
+    }
+
+    /// Reapply the changes reverted by the last [`Bibliography::undo`].
+    /// Returns `false` if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.history.redo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.history.undo.push(current);
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    /// Replace the citations with `snapshot`, report the differences to
+    /// observers and return the citations that were replaced
+    fn restore(&mut self, snapshot: Vec<Citation>) -> Vec<Citation> {
+        let previous = std::mem::replace(&mut self.citations, snapshot);
+        let before: HashMap<String, &Citation> = previous
+            .iter()
+            .map(|citation| (citation.id(), citation))
+            .collect();
+        let after: HashSet<String> = self.citations.iter().map(Citation::id).collect();
+
+        let mut events = Vec::new();
+        for citation in &previous {
+            if !after.contains(&citation.id()) {
+                events.push(ChangeEvent::Removed(citation.id()));
+            }
+        }
+        for citation in &self.citations {
+            let id = citation.id();
+            match before.get(&id) {
+                None => events.push(ChangeEvent::Added(id)),
+                Some(old) if *old != citation => events.push(ChangeEvent::Updated {
+                    previous_id: id.clone(),
+                    id,
+                }),
+                Some(_) => {}
+            }
+        }
+        // Citations present in both must also be in the same order
+        let kept_before = previous
+            .iter()
+            .map(Citation::id)
+            .filter(|id| after.contains(id));
+        let kept_after = self
+            .citations
+            .iter()
+            .map(Citation::id)
+            .filter(|id| before.contains_key(id));
+        if !kept_before.eq(kept_after) {
+            events.push(ChangeEvent::Reordered);
+        }
+
+        for event in events {
+            self.observers.notify(event);
+        }
+        previous
+    }
+
+    /// Citations matching every term of `query`, in bibliography order.
+    /// See [`Citation::matches`].
+    pub fn search(&self, query: &str) -> Vec<&Citation> {
+        self.citations
+            .iter()
+            .filter(|citation| citation.matches(query))
+            .collect()
+    }
+
+    /// Number of citations in the bibliography
+    pub fn len(&self) -> usize {
+        self.citations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.citations.is_empty()
+    }
+
+    /// Load a bibliography saved with [`Bibliography::save`]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StorageError> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Save the bibliography as pretty-printed JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), StorageError> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Save the bibliography in canonical form: citations sorted by ID,
+    /// object keys sorted and identifiers in a fixed order, so a
+    /// version-controlled file only changes where its content does.
+    /// Loads with [`Bibliography::load`].
+    pub fn save_canonical(&self, path: impl AsRef<Path>) -> Result<(), StorageError> {
+        fs::write(path, self.to_canonical_json())?;
+        Ok(())
+    }
+
+    /// The bibliography as canonical, pretty-printed JSON. See
+    /// [`Bibliography::save_canonical`].
+    pub fn to_canonical_json(&self) -> String {
+        let mut citations = self.citations.clone();
+        citations.sort_by_key(Citation::id);
+        for citation in &mut citations {
+            citation.common_data_mut().identifiers.sort();
+        }
+        // serde_json's maps are ordered by key, so struct fields come out
+        // sorted too
+        let value = serde_json::to_value(Bibliography {
+            citations,
+            observers: Observers::default(),
+            history: History::default(),
+        })
+        .expect("bibliographies serialize to JSON");
+        let mut json = serde_json::to_string_pretty(&value).expect("JSON values serialize");
+        json.push('\n');
+        json
+    }
+
     /// Get all citations
     pub fn citations(&self) -> &[Citation] {
         &self.citations
     }
 
-    // pub fn sort_by_author(&mut self) {
-    //     self.citations.sort_by(|a, b| {
-    //         match(a.authors().first(), b.authors().first()) {
-    //             (None, None) => Ordering::Equal,
-    //             (None, Some(_)) => Ordering::Less,
-    //             (Some(_), None) => Ordering::Greater,
-    //             (Some(author_a), Some(author_b)) => author_a.cmp(author_b)
-    //         }
-    //     });
-    // }
+    /// The citations in bibliography order
+    pub fn iter(&self) -> std::slice::Iter<'_, Citation> {
+        self.citations.iter()
+    }
+
+    /// The citations of media type `kind`, in bibliography order
+    pub fn of_kind(&self, kind: MediaKind) -> impl Iterator<Item = &Citation> {
+        self.citations
+            .iter()
+            .filter(move |citation| citation.kind() == kind)
+    }
+
+    /// The books, in bibliography order
+    pub fn books(&self) -> impl Iterator<Item = &Book> {
+        self.citations.iter().filter_map(Citation::as_book)
+    }
+
+    /// The journal articles, in bibliography order
+    pub fn journal_articles(&self) -> impl Iterator<Item = &JournalArticle> {
+        self.citations
+            .iter()
+            .filter_map(Citation::as_journal_article)
+    }
+
+    /// The web pages, in bibliography order
+    pub fn web_pages(&self) -> impl Iterator<Item = &WebPage> {
+        self.citations.iter().filter_map(Citation::as_web_page)
+    }
+
+    /// The citations retrieved online, which record an access date: web
+    /// pages, online manuals and videos, software and Wikipedia articles
+    pub fn online_sources(&self) -> impl Iterator<Item = &Citation> {
+        self.citations
+            .iter()
+            .filter(|citation| citation.accessed().is_some())
+    }
+
+    /// Up to `count` citations, most recently added first. Citations
+    /// without a creation time come last.
+    pub fn recently_added(&self, count: usize) -> Vec<&Citation> {
+        let mut citations: Vec<&Citation> = self.citations.iter().collect();
+        citations.sort_by_key(|citation| std::cmp::Reverse(citation.created()));
+        citations.truncate(count);
+        citations
+    }
+
+    /// Citations created or modified at or after `since`, in bibliography
+    /// order
+    pub fn changed_since(&self, since: DateTime<Utc>) -> Vec<&Citation> {
+        self.citations
+            .iter()
+            .filter(|citation| {
+                citation
+                    .common_data()
+                    .timestamps
+                    .last_changed()
+                    .is_some_and(|changed| changed >= since)
+            })
+            .collect()
+    }
+
+    /// Merge the changes made in `ours` and `theirs` since their common
+    /// ancestor `base`. See [`merge3`].
+    pub fn merge3(base: &Bibliography, ours: &Bibliography, theirs: &Bibliography) -> Merge {
+        merge3(base, ours, theirs)
+    }
+
+    /// Every citation as a BibTeX entry, in bibliography order
+    pub fn to_bibtex(&self, options: &ExportOptions) -> String {
+        citations_to_bibtex(&self.citations, options)
+    }
+
+    /// Every citation as an RIS record, in bibliography order
+    pub fn to_ris(&self, options: &ExportOptions) -> String {
+        citations_to_ris(&self.citations, options)
+    }
+
+    /// The bibliography as a JATS `<ref-list>`, for journal production.
+    /// See [`citations_to_jats`].
+    pub fn to_jats(&self) -> String {
+        citations_to_jats(&self.citations)
+    }
+
+    /// The bibliography as Org-mode headlines with org-bibtex properties.
+    /// See [`citations_to_org`].
+    pub fn to_org(&self) -> String {
+        citations_to_org(&self.citations)
+    }
+
+    /// The bibliography as a Microsoft Word source list (`Sources.xml`).
+    /// See [`citations_to_word_sources`].
+    pub fn to_word_sources(&self) -> String {
+        citations_to_word_sources(&self.citations)
+    }
+
+    /// Record that the entry `from` references the entry `to`.
+    ///
+    /// Both entries must already be in the bibliography. Recording the
+    /// same reference twice has no effect.
+    pub fn add_reference(&mut self, from: &str, to: &str) -> Result<(), CitationError> {
+        if self.get_citation(to).is_none() {
+            return Err(CitationError::UnknownId(to.to_string()));
+        }
+        let citation = self
+            .citations
+            .iter_mut()
+            .find(|c| c.id() == from)
+            .ok_or(CitationError::UnknownId(from.to_string()))?;
+        let cites = &mut citation.common_data_mut().cites;
+        if !cites.iter().any(|id| id == to) {
+            cites.push(CitationId::from(to));
+            mark_modified(citation);
+            self.observers.notify(ChangeEvent::Updated {
+                previous_id: from.to_string(),
+                id: from.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The references between entries of this bibliography
+    pub fn citation_graph(&self) -> CitationGraph {
+        CitationGraph::from_bibliography(self)
+    }
+
+    /// The citation graph in GraphViz DOT, for visualizing how entries
+    /// reference each other
+    pub fn to_dot(&self) -> String {
+        self.citation_graph().to_dot()
+    }
+
+    /// The citation graph as a Mermaid flowchart
+    pub fn to_mermaid(&self) -> String {
+        self.citation_graph().to_mermaid()
+    }
+
+    /// The entries credited to each author, sorted by author name
+    pub fn group_by_author(&self) -> Vec<AuthorGroup> {
+        group_by_author(&self.citations)
+    }
+
+    /// The entries that appeared in each journal or conference
+    pub fn group_by_venue(&self) -> BTreeMap<String, Vec<CitationId>> {
+        group_by_venue(&self.citations)
+    }
+
+    /// Authors linked by the entries they wrote together
+    pub fn co_author_graph(&self) -> CoAuthorGraph {
+        co_author_graph(&self.citations)
+    }
+
+    /// Pairs of entries that reference common entries, most strongly
+    /// coupled first
+    pub fn bibliographic_coupling(&self) -> Vec<Coupling> {
+        bibliographic_coupling(&self.citations)
+    }
+
+    /// Problems in the reference list for `style`, e.g. duplicate DOIs or
+    /// dates in the future
+    pub fn lint(&self, style: CitationStyle) -> Vec<LintIssue> {
+        lint(&self.citations, style)
+    }
+
+    /// Pairs of entries that `strategy` scores as the same work with at
+    /// least `threshold`, most likely first. See [`crate::dedup`].
+    pub fn find_duplicates(
+        &self,
+        strategy: &(impl DedupStrategy + ?Sized),
+        threshold: f64,
+    ) -> Vec<DuplicatePair> {
+        find_duplicates(&self.citations, strategy, threshold)
+    }
+
+    /// The choices to make to merge the entries with IDs `ids`, which
+    /// would keep the first ID. See [`MergeProposal`].
+    pub fn propose_merge(&self, ids: &[&str]) -> Result<MergeProposal, CitationError> {
+        let citations = ids
+            .iter()
+            .map(|id| {
+                self.get_citation(id)
+                    .ok_or(CitationError::UnknownId(id.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        MergeProposal::from_duplicates(&citations).ok_or(CitationError::MissingField(
+            "at least one ID to merge".to_string(),
+        ))
+    }
+
+    /// Merge the entries of a [`Bibliography::propose_merge`] proposal
+    /// with the chosen candidates: the first entry is replaced by the
+    /// merged entry, the others are removed, and references to them point
+    /// to the merged entry instead.
+    pub fn apply_merge(
+        &mut self,
+        proposal: &MergeProposal,
+        resolutions: &Resolutions,
+    ) -> Result<(), MergeError> {
+        let merged = proposal.apply(resolutions)?;
+        let Some((kept, removed)) = proposal.sources.split_first() else {
+            return Ok(());
+        };
+        if let Some(id) = proposal
+            .sources
+            .iter()
+            .find(|id| self.get_citation(id).is_none())
+        {
+            return Err(MergeError::UnknownId(id.clone()));
+        }
+        for id in removed {
+            self.remove_citation(id);
+        }
+        self.replace_citation(kept, merged)
+            .map_err(|_| MergeError::UnknownId(kept.clone()))?;
+        for citation in &mut self.citations {
+            let cites = &citation.common_data().cites;
+            if !cites
+                .iter()
+                .any(|id| removed.iter().any(|removed| id == removed.as_str()))
+            {
+                continue;
+            }
+            let is_kept = citation.id() == *kept;
+            let mut redirected: Vec<CitationId> = Vec::new();
+            for id in cites {
+                let id = if removed.iter().any(|removed| id == removed.as_str()) {
+                    CitationId::from(kept.as_str())
+                } else {
+                    id.clone()
+                };
+                let cites_itself = is_kept && id == *kept.as_str();
+                if !cites_itself && !redirected.contains(&id) {
+                    redirected.push(id);
+                }
+            }
+            citation.common_data_mut().cites = redirected;
+            mark_modified(citation);
+        }
+        Ok(())
+    }
+
+    /// People credited under several forms of their name, e.g. "J. Smith"
+    /// and "Jane Smith"
+    pub fn name_variants(&self) -> Vec<NameCluster> {
+        find_name_variants(&self.citations)
+    }
+
+    /// Rewrite every name variant found with at least `min_confidence` to
+    /// its cluster's canonical form. Returns how many citations were
+    /// updated.
+    pub fn unify_names(&mut self, min_confidence: NameConfidence) -> usize {
+        let replacements: HashMap<PersonName, PersonName> = self
+            .name_variants()
+            .into_iter()
+            .flat_map(|cluster| {
+                let canonical = cluster.canonical.name;
+                cluster
+                    .variants
+                    .into_iter()
+                    .filter(|variant| variant.confidence >= min_confidence)
+                    .map(move |variant| (variant.form.name, canonical.clone()))
+            })
+            .collect();
+        if replacements.is_empty() {
+            return 0;
+        }
+
+        let mut updated = 0;
+        for citation in &mut self.citations {
+            let mut changed = false;
+            for person in citation.persons_mut() {
+                if let Some(canonical) = replacements.get(person) {
+                    *person = canonical.clone();
+                    changed = true;
+                }
+            }
+            if changed {
+                updated += 1;
+                mark_modified(citation);
+                self.observers.notify(ChangeEvent::Updated {
+                    previous_id: citation.id(),
+                    id: citation.id(),
+                });
+            }
+        }
+        updated
+    }
+
+    /// Set the access date of every online resource in the bibliography,
+    /// e.g. to the manuscript submission date. Returns how many citations
+    /// were updated.
+    pub fn touch_access_dates(&mut self, date: impl Into<AccessDate>) -> usize {
+        let date = date.into();
+        let mut updated = 0;
+        for citation in &mut self.citations {
+            if citation.set_accessed(date) {
+                updated += 1;
+                mark_modified(citation);
+                self.observers.notify(ChangeEvent::Updated {
+                    previous_id: citation.id(),
+                    id: citation.id(),
+                });
+            }
+        }
+        updated
+    }
+
+    /// The in-text citation for the entry with the given ID, disambiguated
+    /// against every other entry in the bibliography.
+    pub fn in_text_for(&self, id: &str) -> Option<InTextCitation> {
+        self.in_text_citations().remove(id)
+    }
+
+    /// In-text citations for every entry, keyed by citation ID.
+    pub fn in_text_citations(&self) -> HashMap<String, InTextCitation> {
+        disambiguate(&self.citations)
+    }
+
+    /// Format several in-text references as one citation cluster.
+    ///
+    /// APA and ISO 690 clusters are sorted by author and date, e.g.
+    /// "(Jones, 2019; Smith, 2023)" and "(Jones 2019; Smith 2023)". Numeric
+    /// styles use each entry's position in the bibliography as its
+    /// reference number and collapse consecutive runs, e.g. "[3], [5]–[7]"
+    /// in IEEE and "(3, 5–7)" in the others.
+    pub fn format_citation_cluster(
+        &self,
+        refs: &[CiteRef],
+        style: CitationStyle,
+    ) -> Result<String, CitationError> {
+        let mut positions = Vec::with_capacity(refs.len());
+        for cite in refs {
+            let position = self
+                .citations
+                .iter()
+                .position(|c| c.id() == cite.id)
+                .ok_or(CitationError::UnknownId(cite.id.clone()))?;
+            positions.push((position, cite.locator.clone()));
+        }
+
+        if style.is_numeric() {
+            let items: Vec<(usize, Option<String>)> = positions
+                .into_iter()
+                .map(|(position, locator)| (position + 1, locator))
+                .collect();
+            // AMA and ACS set reference numbers in superscript, which plain
+            // text can't show, so they fall back to parentheses
+            return Ok(match style {
+                CitationStyle::Ieee => ieee_cluster(&items),
+                _ => vancouver_cluster(&items),
+            });
+        }
+
+        let table = self.in_text_citations();
+        let items: Vec<(InTextCitation, Option<String>)> = refs
+            .iter()
+            .zip(positions)
+            .map(|(cite, (_, locator))| (table[&cite.id].clone(), locator))
+            .collect();
+        Ok(match style {
+            CitationStyle::Iso690 => iso690_cluster(&items),
+            _ => apa_cluster(&items),
+        })
+    }
+
+    /// Format every entry as a reference list, one reference per line.
+    ///
+    /// APA and ISO 690 lists are ordered alphabetically by first author (or
+    /// title, for works without authors), then by date. Numeric styles keep
+    /// bibliography order and number each entry to match in-text reference
+    /// numbers, e.g. "[1]" in IEEE, "1." in Vancouver and AMA and "(1)" in
+    /// ACS.
+    pub fn format_reference_list(&self, style: CitationStyle) -> String {
+        self.format_reference_list_with(style, Collation::default())
+    }
+
+    /// Like [`Bibliography::format_reference_list`], ordering alphabetical
+    /// lists by `sort`, e.g. a [`Collation`] for the conventions of a
+    /// language.
+    pub fn format_reference_list_with(
+        &self,
+        style: CitationStyle,
+        sort: impl Into<SortOptions>,
+    ) -> String {
+        reference_list(self.citations.iter().collect(), style, &sort.into())
+    }
+
+    /// The reference list for `style` split into chunks of at most
+    /// `entries_per_chunk` entries, for paginated documents and lazily
+    /// loaded lists.
+    ///
+    /// Alphabetical styles start a new chunk at each letter, with the
+    /// letter as its heading; numeric styles are split into fixed-size
+    /// chunks, so entry numbers carry on from chunk to chunk. A chunk size
+    /// of 0 is taken as 1.
+    pub fn render_chunks(
+        &self,
+        style: CitationStyle,
+        entries_per_chunk: usize,
+    ) -> Vec<ReferenceChunk> {
+        let sort = SortOptions::default();
+        let options = FormatOptions::for_style(style);
+        let alphabetical = matches!(style, CitationStyle::Apa | CitationStyle::Iso690);
+        let mut chunks: Vec<ReferenceChunk> = Vec::new();
+        for (start, (label, citation)) in
+            labelled_entries(self.citations.iter().collect(), style, &sort)
+                .into_iter()
+                .enumerate()
+        {
+            let heading = alphabetical
+                .then(|| {
+                    let lead = match citation.contributors().first() {
+                        Some(contributor) => contributor.short_name().to_string(),
+                        None => sort
+                            .collation
+                            .strip_leading_article(&citation.title())
+                            .to_string(),
+                    };
+                    sort.initial(&lead)
+                })
+                .flatten();
+            let entry = match label {
+                Some(label) => format!("{} {}", label, citation.format_with(style, &options)),
+                None => citation.format_with(style, &options),
+            };
+            match chunks.last_mut() {
+                Some(chunk)
+                    if chunk.heading == heading && chunk.entries.len() < entries_per_chunk =>
+                {
+                    chunk.entries.push(entry)
+                }
+                last => {
+                    let continued = last.is_some_and(|chunk| chunk.heading == heading);
+                    chunks.push(ReferenceChunk {
+                        heading,
+                        continued,
+                        start,
+                        entries: vec![entry],
+                    });
+                }
+            }
+        }
+        chunks
+    }
+
+    /// The reference list for `style` as an HTML list whose entries are
+    /// `<cite>` elements carrying schema.org microdata, so the list is
+    /// machine-readable as well as styled. See [`citation_to_microdata`].
+    pub fn format_reference_list_html(&self, style: CitationStyle) -> String {
+        let options = FormatOptions {
+            markup: Markup::Html,
+            ..FormatOptions::for_style(style)
+        };
+        let mut lines = vec![r#"<ul class="references">"#.to_string()];
+        for (label, citation) in labelled_entries(
+            self.citations.iter().collect(),
+            style,
+            &SortOptions::default(),
+        ) {
+            let label = label.map(|label| label + " ").unwrap_or_default();
+            lines.push(format!(
+                r#"<li id="{}">{}{}</li>"#,
+                anchor(&citation.id()),
+                label,
+                citation_to_microdata(citation, style, &options)
+            ));
+        }
+        lines.push("</ul>".to_string());
+        lines.join("\n")
+    }
+
+    /// The reference list for `style` as a flat OpenDocument text
+    /// (`.fodt`) that LibreOffice opens directly, with titles in italics.
+    /// See [`reference_list_to_odt`].
+    pub fn format_reference_list_odt(&self, style: CitationStyle) -> String {
+        let options = FormatOptions {
+            markup: Markup::Odf,
+            ..FormatOptions::for_style(style)
+        };
+        reference_list_to_odt(&reference_entries(
+            self.citations.iter().collect(),
+            style,
+            &SortOptions::default(),
+            &options,
+        ))
+    }
+
+    /// The reference list of a document citing `used_ids`, in the order
+    /// they are first cited, e.g. a manuscript drawing on a larger library.
+    ///
+    /// Only the cited entries are listed. Numeric styles number them in
+    /// citation order, so the numbers match the in-text citations; IDs
+    /// cited more than once keep their first number. Fails with
+    /// [`CitationError::UnknownId`] if an ID isn't in the bibliography.
+    pub fn render_used<S: AsRef<str>>(
+        &self,
+        used_ids: &[S],
+        style: CitationStyle,
+    ) -> Result<String, CitationError> {
+        let mut seen = HashSet::new();
+        let mut used = Vec::new();
+        for id in used_ids {
+            let id = id.as_ref();
+            let citation = self
+                .get_citation(id)
+                .ok_or(CitationError::UnknownId(id.to_string()))?;
+            if seen.insert(id) {
+                used.push(citation);
+            }
+        }
+        Ok(reference_list(used, style, &SortOptions::default()))
+    }
+
+    /// Format every entry in each of `styles`, so a backend can render
+    /// the bibliography once and serve the result to a frontend.
+    ///
+    /// Entries are formatted as plain text, without the reference
+    /// numbers of numeric styles, which depend on the list they are in.
+    pub fn render_bundle(&self, styles: &[CitationStyle]) -> RenderBundle {
+        let mut bundle = RenderBundle::new();
+        for &style in styles {
+            let options = FormatOptions::for_style(style);
+            for citation in &self.citations {
+                bundle
+                    .entry(citation.id())
+                    .or_default()
+                    .insert(style, citation.format_with(style, &options));
+            }
+        }
+        bundle
+    }
+
+    /// Word and character counts of the reference list in `style`, for
+    /// checking it against a journal's limits
+    pub fn measure_reference_list(&self, style: CitationStyle) -> ReferenceListLength {
+        measure(&self.citations, style)
+    }
+
+    /// Whether any citation in the bibliography has the given DOI
+    pub fn contains_doi(&self, doi: &str) -> bool {
+        let Some(doi) = normalize_doi(doi) else {
+            return false;
+        };
+        self.citations
+            .iter()
+            .filter_map(|c| c.doi())
+            .any(|existing| normalize_doi(&existing).as_deref() == Some(doi.as_str()))
+    }
+
+    /// Fetch many DOIs from Crossref and add the resulting citations.
+    ///
+    /// See [`Bibliography::import_dois_with`].
+    #[cfg(feature = "net")]
+    pub fn import_dois<'a>(
+        &mut self,
+        dois: impl IntoIterator<Item = &'a str>,
+        progress: impl FnMut(Progress),
+    ) -> BatchImport {
+        self.import_dois_with_config(FetchConfig::default(), dois, progress)
+    }
+
+    /// Fetch many DOIs from Crossref as configured by `config`, backing
+    /// off when rate limited, and add the resulting citations.
+    ///
+    /// See [`Bibliography::import_dois_with`].
+    #[cfg(feature = "net")]
+    pub fn import_dois_with_config<'a>(
+        &mut self,
+        config: FetchConfig,
+        dois: impl IntoIterator<Item = &'a str>,
+        progress: impl FnMut(Progress),
+    ) -> BatchImport {
+        let max_concurrency = config.max_concurrency;
+        let client = RetryingClient::new(UreqClient::with_config(&config), config);
+        self.import_dois_with(&client, dois, max_concurrency, progress)
+    }
+
+    /// Fetch many DOIs with `client`, running at most `max_concurrency`
+    /// requests at a time, and add the resulting citations.
+    ///
+    /// DOIs repeated in the input or already present in the bibliography
+    /// are skipped. `progress` is called once for every DOI that is
+    /// fetched. A failing DOI is recorded in the returned [`BatchImport`]
+    /// and doesn't stop the rest of the batch. Citations are added in
+    /// input order regardless of which request finishes first.
+    pub fn import_dois_with<'a, C: HttpClient + ?Sized>(
+        &mut self,
+        client: &C,
+        dois: impl IntoIterator<Item = &'a str>,
+        max_concurrency: usize,
+        progress: impl FnMut(Progress),
+    ) -> BatchImport {
+        let mut report = BatchImport::default();
+        let mut seen = HashSet::new();
+        let mut pending = Vec::new();
+
+        for input in dois {
+            match normalize_doi(input) {
+                None => report.failures.push((
+                    input.to_string(),
+                    ImportError::InvalidIdentifier(input.to_string()),
+                )),
+                Some(doi) => {
+                    if !seen.insert(doi.clone()) || self.contains_doi(&doi) {
+                        record_cache_hit("crossref");
+                        report.duplicates.push(doi);
+                    } else {
+                        pending.push(doi);
+                    }
+                }
+            }
+        }
+
+        self.import_pending(
+            report,
+            pending,
+            max_concurrency,
+            |doi| fetch_doi(client, doi),
+            progress,
+        )
+    }
+
+    /// Scrape many web pages and add the resulting citations.
+    ///
+    /// See [`Bibliography::import_urls_with`].
+    #[cfg(feature = "net")]
+    pub fn import_urls<'a>(
+        &mut self,
+        urls: impl IntoIterator<Item = &'a str>,
+        max_concurrency: usize,
+    ) -> BatchImport {
+        self.import_urls_with(&UreqClient::new(), urls, max_concurrency, |_| {})
+    }
+
+    /// Download and scrape many web pages with `client`, at most
+    /// `max_concurrency` at a time, and add the resulting web page
+    /// citations.
+    ///
+    /// Behaves like [`Bibliography::import_dois_with`]: URLs repeated in
+    /// the input or already cited are skipped, and a page that fails to
+    /// download or has no title is recorded in the returned
+    /// [`BatchImport`] without stopping the rest of the batch.
+    pub fn import_urls_with<'a, C: HttpClient + ?Sized>(
+        &mut self,
+        client: &C,
+        urls: impl IntoIterator<Item = &'a str>,
+        max_concurrency: usize,
+        progress: impl FnMut(Progress),
+    ) -> BatchImport {
+        let mut report = BatchImport::default();
+        let mut seen = HashSet::new();
+        let mut pending = Vec::new();
+        let existing: HashSet<String> = self.citations.iter().filter_map(|c| c.url()).collect();
+
+        for input in urls {
+            match normalize_url(input) {
+                None => report.failures.push((
+                    input.to_string(),
+                    ImportError::InvalidIdentifier(input.to_string()),
+                )),
+                Some(url) => {
+                    if !seen.insert(url.clone()) || existing.contains(&url) {
+                        record_cache_hit("web");
+                        report.duplicates.push(url);
+                    } else {
+                        pending.push(url);
+                    }
+                }
+            }
+        }
+
+        self.import_pending(
+            report,
+            pending,
+            max_concurrency,
+            |url| fetch_web_page(client, url),
+            progress,
+        )
+    }
+
+    /// Add the org-bibtex headlines of an Org file. See [`read_org`] for
+    /// how headlines are read.
+    ///
+    /// Headlines whose ID is already in the bibliography are reported as
+    /// duplicates. Unread properties and guessed media types are reported
+    /// as warnings.
+    pub fn import_org(&mut self, text: &str) -> BatchImport {
+        let ImportReport {
+            citations,
+            warnings,
+        } = read_org(text);
+        let mut report = BatchImport {
+            warnings,
+            ..Default::default()
+        };
+        for citation in citations {
+            let id = citation.id();
+            if self.add_citation(citation).is_ok() {
+                report.imported.push(id);
+            } else {
+                report.duplicates.push(id);
+            }
+        }
+        report
+    }
+
+    /// Add the books in an ONIX 3.0 message. See [`read_onix`] for how
+    /// products are read.
+    ///
+    /// Books whose ID is already in the bibliography are reported as
+    /// duplicates. Skipped products and unreadable dates are reported as
+    /// warnings.
+    pub fn import_onix(&mut self, xml: &str) -> Result<BatchImport, ImportError> {
+        let ImportReport {
+            citations,
+            warnings,
+        } = read_onix(xml)?;
+        let mut report = BatchImport {
+            warnings,
+            ..Default::default()
+        };
+        for citation in citations {
+            let id = citation.id();
+            if self.add_citation(citation).is_ok() {
+                report.imported.push(id);
+            } else {
+                report.duplicates.push(id);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Add the citations in a folder of Markdown notes. See [`read_notes`]
+    /// for how notes are read.
+    ///
+    /// Notes whose ID is already in the bibliography are reported as
+    /// duplicates, and notes that aren't about a work as warnings.
+    pub fn import_notes(&mut self, folder: impl AsRef<Path>) -> Result<BatchImport, StorageError> {
+        let ImportReport {
+            citations,
+            warnings,
+        } = read_notes(folder)?;
+        let mut report = BatchImport {
+            warnings,
+            ..Default::default()
+        };
+        for citation in citations {
+            let id = citation.id();
+            if self.add_citation(citation).is_ok() {
+                report.imported.push(id);
+            } else {
+                report.duplicates.push(id);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Write one Markdown note per citation to `folder`, keeping what was
+    /// already written in existing notes. See [`write_notes`].
+    pub fn export_notes(&self, folder: impl AsRef<Path>) -> Result<Vec<PathBuf>, StorageError> {
+        write_notes(&self.citations, folder)
+    }
+
+    /// Add the bookmarks of a browser bookmarks export as web pages. See
+    /// [`read_bookmarks`] for how bookmarks are converted.
+    ///
+    /// Bookmarks whose URL is already cited, or that appear more than once
+    /// in the export, are reported as duplicates. Skipped bookmarks and
+    /// replaced access dates are reported as warnings.
+    pub fn import_bookmarks(&mut self, html: &str) -> BatchImport {
+        let ImportReport {
+            citations,
+            warnings,
+        } = read_bookmarks(html);
+        let mut report = BatchImport {
+            warnings,
+            ..Default::default()
+        };
+        for citation in citations {
+            let id = citation.id();
+            if self.citations.iter().any(|c| c.url() == citation.url()) {
+                report.duplicates.push(id);
+            } else if self.add_citation(citation).is_ok() {
+                report.imported.push(id);
+            } else {
+                report
+                    .failures
+                    .push((id.clone(), ImportError::DuplicateId(id)));
+            }
+        }
+        report
+    }
+
+    /// Fetch every pending (normalized, deduplicated) input concurrently
+    /// and add the results in input order.
+    fn import_pending(
+        &mut self,
+        mut report: BatchImport,
+        pending: Vec<String>,
+        max_concurrency: usize,
+        fetch: impl Fn(&String) -> Result<Citation, ImportError> + Sync,
+        mut progress: impl FnMut(Progress),
+    ) -> BatchImport {
+        let total = pending.len();
+        let mut completed = 0;
+        let mut results: Vec<Option<Result<Citation, ImportError>>> = vec![None; total];
+        run_bounded(&pending, max_concurrency, fetch, |index, result| {
+            completed += 1;
+            progress(Progress {
+                completed,
+                total,
+                input: pending[index].clone(),
+                succeeded: result.is_ok(),
+            });
+            results[index] = Some(result);
+        });
+
+        for (input, result) in pending.into_iter().zip(results) {
+            match result.expect("every pending input produces a result") {
+                Ok(citation) => {
+                    let id = citation.id();
+                    if self.add_citation(citation).is_ok() {
+                        report.imported.push(id);
+                    } else {
+                        report.failures.push((input, ImportError::DuplicateId(id)));
+                    }
+                }
+                Err(err) => report.failures.push((input, err)),
+            }
+        }
+
+        report
+    }
+
+    /// Sort citations by first author, or by title for works without an
+    /// author, as in an APA reference list
+    pub fn sort_alphabetically(&mut self, sort: impl Into<SortOptions>) {
+        let sort = sort.into();
+        self.citations
+            .sort_by_cached_key(|citation| alphabetical_key(citation, &sort));
+        self.observers.notify(ChangeEvent::Reordered);
+    }
 
     /// Sort citations by year (descending)
     pub fn sort_by_publish_date(&mut self) {
@@ -60,6 +1309,142 @@ impl Bibliography {
                 .unwrap_or(DEFAULT_PUBLISH_DATE)
                 .cmp(&a.published().unwrap_or(DEFAULT_PUBLISH_DATE))
         });
+        self.observers.notify(ChangeEvent::Reordered);
+    }
+
+    /// Reorder the citations to follow `ids`. Citations not listed keep
+    /// their relative order after the listed ones.
+    pub(crate) fn order_by_ids(&mut self, ids: &[String]) {
+        self.citations.sort_by_key(|citation| {
+            ids.iter()
+                .position(|id| *id == citation.id())
+                .unwrap_or(ids.len())
+        });
+        self.observers.notify(ChangeEvent::Reordered);
+    }
+}
+
+/// The reference list of `citations` in `style`. Alphabetical styles sort
+/// them by `sort`; numeric styles number them in the order given.
+fn reference_list(citations: Vec<&Citation>, style: CitationStyle, sort: &SortOptions) -> String {
+    reference_entries(citations, style, sort, &FormatOptions::for_style(style)).join("\n")
+}
+
+/// The formatted entries of a reference list, each with its label in
+/// numeric styles
+fn reference_entries(
+    citations: Vec<&Citation>,
+    style: CitationStyle,
+    sort: &SortOptions,
+    options: &FormatOptions,
+) -> Vec<String> {
+    labelled_entries(citations, style, sort)
+        .into_iter()
+        .map(|(label, citation)| {
+            let entry = citation.format_with(style, options);
+            match label {
+                Some(label) => format!("{} {}", label, entry),
+                None => entry,
+            }
+        })
+        .collect()
+}
+
+/// The entries of a reference list in order, with their labels in
+/// numeric styles, e.g. "[1]" in IEEE
+pub(crate) fn labelled_entries<'a>(
+    mut citations: Vec<&'a Citation>,
+    style: CitationStyle,
+    sort: &SortOptions,
+) -> Vec<(Option<String>, &'a Citation)> {
+    match style {
+        CitationStyle::Apa | CitationStyle::Iso690 => {
+            citations.sort_by_cached_key(|citation| alphabetical_key(citation, sort));
+            citations
+                .into_iter()
+                .map(|citation| (None, citation))
+                .collect()
+        }
+        CitationStyle::Ieee
+        | CitationStyle::Vancouver
+        | CitationStyle::Ama
+        | CitationStyle::Acs => citations
+            .into_iter()
+            .enumerate()
+            .map(|(index, citation)| {
+                let label = match style {
+                    CitationStyle::Ieee => format!("[{}]", index + 1),
+                    CitationStyle::Acs => format!("({})", index + 1),
+                    _ => format!("{}.", index + 1),
+                };
+                (Some(label), citation)
+            })
+            .collect(),
+    }
+}
+
+/// The order of alphabetical reference lists: by first author, then date,
+/// then title. Works without an author are sorted by title, ignoring a
+/// leading article.
+fn alphabetical_key(
+    citation: &Citation,
+    sort: &SortOptions,
+) -> (SortKey, Option<PublishDate>, SortKey) {
+    let title = sort.title_sort_key(&citation.title());
+    let lead = match citation.contributors().first() {
+        Some(contributor) => sort.sort_key(contributor.short_name()),
+        None => title.clone(),
+    };
+    (lead, citation.published(), title)
+}
+
+/// Set the modification time of `citation` to now
+fn mark_modified(citation: &mut Citation) {
+    citation.common_data_mut().timestamps.modified = Some(Utc::now());
+}
+
+/// Renders the reference list in APA style, like [`Citation`]'s `Display`.
+impl fmt::Display for Bibliography {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format_reference_list(CitationStyle::Apa))
+    }
+}
+
+/// Collects citations into a new bibliography. Citations whose ID is
+/// already taken are skipped, as with [`Bibliography::add_citation`].
+impl FromIterator<Citation> for Bibliography {
+    fn from_iter<I: IntoIterator<Item = Citation>>(citations: I) -> Self {
+        let mut bibliography = Bibliography::new();
+        bibliography.extend(citations);
+        bibliography
+    }
+}
+
+/// Adds every citation with [`Bibliography::add_citation`], skipping those
+/// whose ID is already taken.
+impl Extend<Citation> for Bibliography {
+    fn extend<I: IntoIterator<Item = Citation>>(&mut self, citations: I) {
+        for citation in citations {
+            let _ = self.add_citation(citation);
+        }
+    }
+}
+
+impl IntoIterator for Bibliography {
+    type Item = Citation;
+    type IntoIter = std::vec::IntoIter<Citation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.citations.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Bibliography {
+    type Item = &'a Citation;
+    type IntoIter = std::slice::Iter<'a, Citation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.citations.iter()
     }
 }
 
@@ -72,27 +1457,57 @@ impl Default for Bibliography {
 #[cfg(test)]
 mod tests {
 
-    use crate::api::{
-        author::{GenericAuthor, PersonName},
-        citation::Citation,
-        media::{book::Book, common::CommonCitationData},
+    use std::sync::Mutex;
+
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    use crate::{
+        api::{
+            author::{GenericAuthor, PersonName},
+            citation::Citation,
+            media::{
+                book::Book, common::CommonCitationData, conference_paper::ConferencePaperOnline,
+                online_video::OnlineVideo, web_page::WebPage,
+            },
+        },
+        net::{FetchError, HttpClient, HttpResponse},
     };
 
     use super::*;
 
+    /// Serves a minimal Crossref journal article for every DOI except
+    /// those ending in "missing", and records the requested URLs.
+    struct FakeCrossref {
+        requested: Mutex<Vec<String>>,
+    }
+
+    impl HttpClient for FakeCrossref {
+        fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+            self.requested.lock().unwrap().push(url.to_string());
+            if url.ends_with("missing") {
+                return Ok(HttpResponse::new(404, "Resource not found.".to_string()));
+            }
+            let doi = url.trim_start_matches("https://api.crossref.org/works/");
+            let body = format!(
+                r#"{{"message": {{"DOI": "{}", "type": "journal-article",
+                    "title": ["Title of {}"], "container-title": ["J. Tests"]}}}}"#,
+                doi, doi
+            );
+            Ok(HttpResponse::new(200, body))
+        }
+    }
+
     #[test]
     fn test_bibliography() {
         let mut bib = Bibliography::new();
 
         let citation = Citation::Book(Book {
-            common_data: CommonCitationData {
-                id: "test".to_string(),
-                published: None,
-            },
+            common_data: CommonCitationData::new("test"),
             author: GenericAuthor::Persons {
                 persons: vec![PersonName::from_first_last("Test", "Author").unwrap()],
             },
-            title: "Test Title".to_string(),
+            with_authors: Vec::new(),
+            title: "Test Title".into(),
             doi: None,
             pages: None,
             chapter: None,
@@ -106,4 +1521,973 @@ mod tests {
         assert!(found.is_some());
         assert_eq!(found.unwrap().title(), "Test Title");
     }
+
+    #[test]
+    fn test_add_reference() {
+        let book = |id: &str| {
+            Citation::Book(Book {
+                common_data: CommonCitationData::new(id),
+                author: GenericAuthor::Organization {
+                    name: "Org".to_string(),
+                    abbreviation: None,
+                },
+                with_authors: Vec::new(),
+                title: id.into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            })
+        };
+        let mut bib = Bibliography::new();
+        bib.add_citation(book("a")).unwrap();
+        bib.add_citation(book("b")).unwrap();
+
+        assert!(bib.add_reference("a", "b").is_ok());
+        assert!(bib.add_reference("a", "b").is_ok());
+        assert!(matches!(
+            bib.add_reference("a", "missing"),
+            Err(CitationError::UnknownId(id)) if id == "missing"
+        ));
+        assert_eq!(
+            bib.get_citation("a").unwrap().cites(),
+            [CitationId::from("b")]
+        );
+        assert_eq!(bib.citation_graph().cited_by("b"), [CitationId::from("a")]);
+    }
+
+    #[test]
+    fn test_render_chunks() {
+        let book = |id: &str, name: &str, year: i32| {
+            Citation::Book(Book {
+                common_data: CommonCitationData {
+                    published: Some(PublishDate::from_year(year)),
+                    ..CommonCitationData::new(id)
+                },
+                author: GenericAuthor::Organization {
+                    name: name.to_string(),
+                    abbreviation: None,
+                },
+                with_authors: Vec::new(),
+                title: format!("{} Handbook", name).into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            })
+        };
+        let mut bib = Bibliography::new();
+        for name in ["Zeta", "Atlas", "Apex", "Alpha"] {
+            bib.add_citation(book(&name.to_lowercase(), name, 2020))
+                .unwrap();
+        }
+
+        let chunks = bib.render_chunks(CitationStyle::Apa, 2);
+        let summary: Vec<(Option<&str>, bool, usize, usize)> = chunks
+            .iter()
+            .map(|chunk| {
+                (
+                    chunk.heading.as_deref(),
+                    chunk.continued,
+                    chunk.start,
+                    chunk.entries.len(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Some("A"), false, 0, 2),
+                (Some("A"), true, 2, 1),
+                (Some("Z"), false, 3, 1)
+            ]
+        );
+        assert!(chunks[1].entries[0].starts_with("Atlas."));
+
+        let ieee = bib.render_chunks(CitationStyle::Ieee, 3);
+        assert_eq!(ieee.len(), 2);
+        assert_eq!(ieee[1].heading, None);
+        assert!(ieee[1].continued);
+        assert!(ieee[1].entries[0].starts_with("[4] Alpha"));
+    }
+
+    /// A web page whose author and URL need escaping in HTML and ODF
+    fn ampersand_page() -> Citation {
+        Citation::WebPage(WebPage {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2020)),
+                ..CommonCitationData::new("att")
+            },
+            author: Some(GenericAuthor::Organization {
+                name: "AT&T <script>".to_string(),
+                abbreviation: None,
+            }),
+            title: "Annual report".into(),
+            site_name: None,
+            url: "https://example.com/index.php?title=Report&oldid=5".to_string(),
+            accessed: NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into(),
+        })
+    }
+
+    #[test]
+    fn test_reference_list_odt_escapes_text() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(ampersand_page()).unwrap();
+
+        let odt = bib.format_reference_list_odt(CitationStyle::Apa);
+        assert!(odt.contains(
+            "<text:p text:style-name=\"Bibliography_20_1\">AT&amp;T &lt;script&gt;. (2020). \
+             <text:span text:style-name=\"Italic\">Annual report</text:span>. \
+             https://example.com/index.php?title=Report&amp;oldid=5</text:p>"
+        ));
+        assert!(!odt.contains("AT&T"));
+        assert!(!odt.contains("Report&oldid"));
+    }
+
+    #[test]
+    fn test_reference_list_html_escapes_text() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(ampersand_page()).unwrap();
+
+        let html = bib.format_reference_list_html(CitationStyle::Apa);
+        assert!(html.contains(
+            "itemtype=\"https://schema.org/WebPage\">AT&amp;T &lt;script&gt;. (2020). \
+             <i>Annual report</i>. https://example.com/index.php?title=Report&amp;oldid=5"
+        ));
+        assert!(html.contains(
+            "<link itemprop=\"url\" href=\"https://example.com/index.php?title=Report&amp;oldid=5\">"
+        ));
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("Report&oldid"));
+    }
+
+    #[test]
+    fn test_reference_list() {
+        let book = |id: &str, name: &str, year: i32| {
+            Citation::Book(Book {
+                common_data: CommonCitationData {
+                    published: Some(PublishDate::from_year(year)),
+                    ..CommonCitationData::new(id)
+                },
+                author: GenericAuthor::Organization {
+                    name: name.to_string(),
+                    abbreviation: None,
+                },
+                with_authors: Vec::new(),
+                title: format!("{} Handbook", name).into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            })
+        };
+        let mut bib = Bibliography::new();
+        bib.add_citation(book("zeta", "Zeta", 2001)).unwrap();
+        bib.add_citation(book("alpha", "Alpha", 2010)).unwrap();
+
+        let apa = bib.to_string();
+        let ieee = bib.format_reference_list(CitationStyle::Ieee);
+
+        assert_eq!(apa.lines().count(), 2);
+        assert!(apa.lines().next().unwrap().starts_with("Alpha"));
+        assert!(ieee.lines().next().unwrap().starts_with("[1] Zeta"));
+        assert!(ieee.lines().nth(1).unwrap().starts_with("[2] Alpha"));
+
+        let iso690 = bib.format_reference_list(CitationStyle::Iso690);
+        let vancouver = bib.format_reference_list(CitationStyle::Vancouver);
+        assert!(iso690.lines().next().unwrap().starts_with("Alpha"));
+        assert!(vancouver.lines().next().unwrap().starts_with("1. Zeta"));
+        assert!(vancouver.lines().nth(1).unwrap().starts_with("2. Alpha"));
+
+        let html = bib.format_reference_list_html(CitationStyle::Ieee);
+        assert!(html.starts_with(
+            "<ul class=\"references\">\n<li id=\"ref-zeta\">[1] <cite itemscope itemtype=\"https://schema.org/Book\">"
+        ));
+        assert!(html.ends_with("</cite></li>\n</ul>"));
+
+        let odt = bib.format_reference_list_odt(CitationStyle::Apa);
+        assert!(odt.contains(
+            "<text:p text:style-name=\"Bibliography_20_1\">Alpha. (2010). \
+             <text:span text:style-name=\"Italic\">Alpha Handbook</text:span>.</text:p>"
+        ));
+        assert!(
+            bib.format_reference_list(CitationStyle::Acs)
+                .starts_with("(1) Zeta")
+        );
+    }
+
+    #[test]
+    fn test_display_conference_paper() {
+        let paper = Citation::ConferencePaperOnline(ConferencePaperOnline {
+            common_data: CommonCitationData::new("paper"),
+            title: "A Paper".into(),
+            venue: None,
+            volume: None,
+            number: None,
+            conference_name: "RustConf".to_string(),
+            series: Default::default(),
+            conference_date: Utc.with_ymd_and_hms(2019, 9, 18, 0, 0, 0).unwrap(),
+        });
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("org", "Board", "Handbook"))
+            .unwrap();
+        bib.add_citation(paper).unwrap();
+
+        assert_eq!(
+            bib.to_string().lines().nth(1),
+            Some("A Paper. (2019). In Proceedings of the RustConf.")
+        );
+    }
+
+    fn org_book(id: &str, name: &str, title: &str) -> Citation {
+        Citation::Book(Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2020)),
+                ..CommonCitationData::new(id)
+            },
+            author: GenericAuthor::Organization {
+                name: name.to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: title.into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        })
+    }
+
+    #[test]
+    fn test_reference_list_anonymous_and_pseudonym() {
+        let mut anonymous = org_book("anon", "", "Go Ask Alice");
+        let mut pseudonym = org_book("seuss", "", "Green Eggs and Ham");
+        if let Citation::Book(book) = &mut anonymous {
+            book.author = GenericAuthor::Anonymous;
+        }
+        if let Citation::Book(book) = &mut pseudonym {
+            book.author = GenericAuthor::Pseudonym {
+                name: "Dr. Seuss".to_string(),
+                real_name: Some("Theodor Seuss Geisel".to_string()),
+            };
+        }
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("org", "Board", "Handbook"))
+            .unwrap();
+        bib.add_citation(pseudonym).unwrap();
+        bib.add_citation(anonymous).unwrap();
+
+        let apa = bib.to_string();
+        let lines: Vec<&str> = apa.lines().collect();
+
+        assert_eq!(lines[0], "Anonymous. (2020). Go Ask Alice.");
+        assert!(lines[1].starts_with("Board."));
+        assert_eq!(lines[2], "Dr. Seuss. (2020). Green Eggs and Ham.");
+        assert_eq!(bib.search("geisel").len(), 1);
+    }
+
+    #[test]
+    fn test_reference_list_collation() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("zoo", "Zoological Society", "Animals"))
+            .unwrap();
+        bib.add_citation(org_book("oko", "Ökologie-Institut", "Auen"))
+            .unwrap();
+        bib.add_citation(org_book("osl", "Oslo Kommune", "Byer"))
+            .unwrap();
+        let ids = |bib: &Bibliography| bib.citations().iter().map(Citation::id).collect::<Vec<_>>();
+
+        let german = bib.format_reference_list_with(CitationStyle::Apa, Collation::German);
+        assert!(german.starts_with("Ökologie-Institut."));
+        let swedish = bib.format_reference_list_with(CitationStyle::Apa, Collation::Swedish);
+        assert!(swedish.starts_with("Oslo Kommune."));
+        assert!(swedish.ends_with("Auen."));
+
+        bib.sort_alphabetically(Collation::Swedish);
+        assert_eq!(ids(&bib), ["osl", "zoo", "oko"]);
+        bib.sort_alphabetically(Collation::German);
+        assert_eq!(ids(&bib), ["oko", "osl", "zoo"]);
+
+        bib.add_citation(org_book("mos", "Мосгорархив", "Moskva"))
+            .unwrap();
+        bib.sort_alphabetically(Collation::Root);
+        assert_eq!(ids(&bib)[3], "mos");
+        bib.sort_alphabetically(SortOptions {
+            collation: Collation::Root,
+            transliterate: true,
+        });
+        assert_eq!(ids(&bib), ["mos", "oko", "osl", "zoo"]);
+    }
+
+    #[test]
+    fn test_render_used() {
+        let mut bib = Bibliography::new();
+        for (id, name) in [("a", "Alpha"), ("b", "Beta"), ("c", "Gamma")] {
+            bib.add_citation(org_book(id, name, "Report")).unwrap();
+        }
+
+        let ieee = bib
+            .render_used(&["c", "a", "c"], CitationStyle::Ieee)
+            .unwrap();
+        let lines: Vec<&str> = ieee.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("[1] Gamma"));
+        assert!(lines[1].starts_with("[2] Alpha"));
+
+        let apa = bib.render_used(&["c", "a"], CitationStyle::Apa).unwrap();
+        assert!(apa.starts_with("Alpha."));
+        assert!(!apa.contains("Beta"));
+
+        assert!(matches!(
+            bib.render_used(&["x"], CitationStyle::Apa),
+            Err(CitationError::UnknownId(id)) if id == "x"
+        ));
+    }
+
+    #[test]
+    fn test_remove_and_replace() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("a", "Org", "First")).unwrap();
+        bib.add_citation(org_book("b", "Org", "Second")).unwrap();
+
+        let old = bib
+            .replace_citation("a", org_book("c", "Org", "Third"))
+            .unwrap();
+        assert_eq!(old.title(), "First");
+        assert_eq!(bib.citations()[0].id(), "c");
+        assert!(
+            bib.replace_citation("c", org_book("b", "Org", "Clash"))
+                .is_err()
+        );
+        assert!(matches!(
+            bib.replace_citation("a", org_book("a", "Org", "Gone")),
+            Err(CitationError::UnknownId(_))
+        ));
+
+        assert_eq!(
+            bib.remove_citation("b").map(|c| c.title()),
+            Some("Second".to_string())
+        );
+        assert_eq!(bib.remove_citation("b"), None);
+        assert_eq!(bib.len(), 1);
+    }
+
+    #[test]
+    fn test_transaction() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("a", "Org", "First")).unwrap();
+
+        let result = bib.transaction(|tx| {
+            tx.remove_citation("a");
+            tx.add_citation(org_book("b", "Org", "Second"))?;
+            tx.add_citation(org_book("b", "Org", "Clash"))
+        });
+        assert!(result.is_err());
+        assert_eq!(bib.len(), 1);
+        assert_eq!(bib.citations()[0].id(), "a");
+
+        bib.transaction(|tx| {
+            tx.add_citation(org_book("b", "Org", "Second"))?;
+            tx.replace_citation("a", org_book("c", "Org", "Third"))
+        })
+        .unwrap();
+        assert_eq!(bib.citations()[0].id(), "c");
+        assert_eq!(bib.len(), 2);
+    }
+
+    #[test]
+    fn test_failed_transaction_restores_history() {
+        let mut bib = Bibliography::new();
+        bib.begin_snapshot();
+        bib.add_citation(org_book("a", "Org", "First")).unwrap();
+        bib.begin_snapshot();
+        bib.add_citation(org_book("b", "Org", "Second")).unwrap();
+        bib.undo();
+        assert!(bib.can_redo());
+
+        let result = bib.transaction(|tx| {
+            tx.begin_snapshot();
+            tx.remove_citation("a");
+            tx.add_citation(org_book("a", "Org", "Clash"))?;
+            tx.add_citation(org_book("a", "Org", "Clash"))
+        });
+        assert!(result.is_err());
+
+        // The snapshot taken inside is gone, and "b" can still be redone
+        assert!(bib.redo());
+        assert_eq!(bib.len(), 2);
+        assert!(bib.undo());
+        assert!(bib.undo());
+        assert!(bib.is_empty());
+        assert!(!bib.can_undo());
+    }
+
+    #[test]
+    fn test_on_change() {
+        let events = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let mut bib = Bibliography::new();
+        let observed = events.clone();
+        bib.on_change(move |event| observed.lock().unwrap().push(event.clone()));
+
+        bib.add_citation(org_book("a", "Org", "First")).unwrap();
+        bib.update_citation("a", |citation| {
+            citation.common_data_mut().tags.push("todo".to_string())
+        })
+        .unwrap();
+        bib.replace_citation("a", org_book("b", "Org", "First"))
+            .unwrap();
+        let _ = bib.transaction(|tx| {
+            tx.remove_citation("b");
+            Err::<(), _>(CitationError::UnknownId("x".to_string()))
+        });
+        bib.transaction(|tx| tx.remove_citation("b").ok_or(()))
+            .unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                ChangeEvent::Added("a".to_string()),
+                ChangeEvent::Updated {
+                    previous_id: "a".to_string(),
+                    id: "a".to_string()
+                },
+                ChangeEvent::Updated {
+                    previous_id: "a".to_string(),
+                    id: "b".to_string()
+                },
+                ChangeEvent::Removed("b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let events = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("a", "Org", "First")).unwrap();
+        assert!(!bib.undo());
+
+        bib.begin_snapshot();
+        bib.remove_citation("a");
+        bib.add_citation(org_book("b", "Org", "Second")).unwrap();
+        let observed = events.clone();
+        bib.on_change(move |event| observed.lock().unwrap().push(event.clone()));
+
+        assert!(bib.undo());
+        assert!(!bib.can_undo());
+        assert_eq!(bib.len(), 1);
+        assert_eq!(bib.citations()[0].id(), "a");
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                ChangeEvent::Removed("b".to_string()),
+                ChangeEvent::Added("a".to_string()),
+            ]
+        );
+
+        assert!(bib.redo());
+        assert!(!bib.can_redo());
+        assert_eq!(bib.len(), 1);
+        assert_eq!(bib.citations()[0].id(), "b");
+
+        bib.undo();
+        bib.begin_snapshot();
+        assert!(!bib.redo());
+    }
+
+    #[test]
+    fn test_timestamps() {
+        let start = Utc::now();
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("a", "Org", "First")).unwrap();
+        bib.add_citation(org_book("b", "Org", "Second")).unwrap();
+
+        let created = bib.get_citation("a").unwrap().created().unwrap();
+        assert!(created >= start);
+        assert_eq!(bib.get_citation("a").unwrap().modified(), None);
+        assert_eq!(bib.recently_added(1)[0].id(), "b");
+
+        let edited = Utc::now();
+        bib.replace_citation("a", org_book("c", "Org", "Third"))
+            .unwrap();
+        let citation = bib.get_citation("c").unwrap();
+        assert_eq!(citation.created(), Some(created));
+        assert!(citation.modified().unwrap() >= edited);
+        assert_eq!(
+            bib.changed_since(edited)
+                .iter()
+                .map(|citation| citation.id())
+                .collect::<Vec<_>>(),
+            ["c"]
+        );
+
+        let json = serde_json::to_string(&bib).unwrap();
+        let loaded: Bibliography = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.citations(), bib.citations());
+    }
+
+    #[test]
+    fn test_search() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("rust", "Mozilla", "The Rust Book"))
+            .unwrap();
+        bib.add_citation(org_book("go", "Google", "The Go Book"))
+            .unwrap();
+
+        let ids = |query: &str| {
+            bib.search(query)
+                .iter()
+                .map(|c| c.id())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(ids("book"), ["rust", "go"]);
+        assert_eq!(ids("RUST mozilla"), ["rust"]);
+        assert_eq!(ids("2020 google"), ["go"]);
+        assert!(ids("python").is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(org_book("a", "Org", "First")).unwrap();
+        let path =
+            std::env::temp_dir().join(format!("citation_station_test_{}.json", std::process::id()));
+
+        bib.save(&path).unwrap();
+        let loaded = Bibliography::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.citations(), bib.citations());
+        assert!(matches!(
+            Bibliography::load(&path),
+            Err(StorageError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_canonical_json() {
+        let mut first = Bibliography::new();
+        first.add_citation(org_book("b", "Org", "Second")).unwrap();
+        first.add_citation(org_book("a", "Org", "First")).unwrap();
+        let mut second = Bibliography::new();
+        for id in ["a", "b"] {
+            let citation = first.get_citation(id).unwrap().clone();
+            second.add_citation(citation).unwrap();
+        }
+
+        let json = first.to_canonical_json();
+
+        assert_eq!(json, second.to_canonical_json());
+        assert!(json.ends_with("}\n"));
+        assert!(json.find("\"a\"").unwrap() < json.find("\"b\"").unwrap());
+        let keys: Vec<&str> = json
+            .lines()
+            .filter(|line| line.starts_with("        \""))
+            .map(|line| line.trim().split('"').nth(1).unwrap())
+            .take(4)
+            .collect();
+        assert_eq!(keys, ["author", "chapter", "common_data", "doi"]);
+
+        let loaded: Bibliography = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.citations(), second.citations());
+    }
+
+    #[test]
+    fn test_touch_access_dates() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(Citation::OnlineVideo(OnlineVideo::YouTube {
+            common_data: CommonCitationData::new("video"),
+            title: "A Video".into(),
+            url: None,
+            channel: "channel".to_string(),
+            accessed: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().into(),
+        }))
+        .unwrap();
+        bib.add_citation(Citation::Book(Book {
+            common_data: CommonCitationData::new("book"),
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: "A Book".into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        }))
+        .unwrap();
+
+        let submitted = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+
+        assert_eq!(bib.touch_access_dates(submitted), 1);
+        assert_eq!(
+            bib.get_citation("video").unwrap().accessed(),
+            Some(&AccessDate::from(submitted))
+        );
+    }
+
+    #[test]
+    fn test_unify_names() {
+        let book = |id: &str, first: &str| {
+            Citation::Book(Book {
+                common_data: CommonCitationData::new(id),
+                author: GenericAuthor::Persons {
+                    persons: vec![PersonName::from_first_last(first, "Smith").unwrap()],
+                },
+                with_authors: Vec::new(),
+                title: id.into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            })
+        };
+        let mut bib = Bibliography::new();
+        bib.add_citation(book("a", "Jane")).unwrap();
+        bib.add_citation(book("b", "J.")).unwrap();
+        bib.add_citation(book("c", "Jane")).unwrap();
+
+        assert_eq!(bib.name_variants().len(), 1);
+        assert_eq!(bib.unify_names(NameConfidence::High), 0);
+        assert_eq!(bib.unify_names(NameConfidence::Medium), 1);
+        assert_eq!(
+            bib.get_citation("b").unwrap().persons(),
+            [PersonName::from_first_last("Jane", "Smith").unwrap()]
+        );
+        assert!(bib.name_variants().is_empty());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let common_data = |id: &str| CommonCitationData::new(id);
+        let book = |id: &str| {
+            Citation::Book(Book {
+                common_data: common_data(id),
+                author: GenericAuthor::Organization {
+                    name: "Org".to_string(),
+                    abbreviation: None,
+                },
+                with_authors: Vec::new(),
+                title: id.into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            })
+        };
+        let page = Citation::WebPage(WebPage {
+            common_data: common_data("page"),
+            author: None,
+            title: "Page".into(),
+            site_name: None,
+            url: "https://example.com".to_string(),
+            accessed: AccessDate::today(),
+        });
+
+        let mut bib: Bibliography = [book("a"), page, book("a")].into_iter().collect();
+        assert_eq!(bib.len(), 2);
+        bib.extend([book("b"), book("c")]);
+
+        let books: Vec<String> = bib.books().map(|book| book.title.to_string()).collect();
+        assert_eq!(books, ["a", "b", "c"]);
+        let online: Vec<String> = bib.online_sources().map(Citation::id).collect();
+        assert_eq!(online, ["page"]);
+        assert_eq!(bib.web_pages().count(), 1);
+        assert_eq!(bib.journal_articles().count(), 0);
+        assert_eq!(bib.of_kind(MediaKind::Book).count(), 3);
+        assert_eq!((&bib).into_iter().count(), 4);
+
+        let without_books: Bibliography = bib
+            .into_iter()
+            .filter(|citation| citation.as_book().is_none())
+            .collect();
+        assert_eq!(without_books.len(), 1);
+    }
+
+    #[test]
+    fn test_render_bundle() {
+        let mut bib = Bibliography::new();
+        for id in ["b", "a"] {
+            bib.add_citation(org_book(id, "Org", id)).unwrap();
+        }
+        let bundle = bib.render_bundle(&[CitationStyle::Apa, CitationStyle::Ieee]);
+
+        assert_eq!(bundle.keys().collect::<Vec<_>>(), ["a", "b"]);
+        let citation = bib.get_citation("a").unwrap();
+        assert_eq!(
+            bundle["a"][&CitationStyle::Apa],
+            citation.format(CitationStyle::Apa)
+        );
+        assert_eq!(
+            bundle["a"][&CitationStyle::Ieee],
+            citation.format(CitationStyle::Ieee)
+        );
+
+        let json = serde_json::to_value(&bundle).unwrap();
+        assert!(json["b"]["Ieee"].is_string());
+        assert!(bib.render_bundle(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_merge_duplicates() {
+        let book = |id: &str, title: &str, year: i32| {
+            Citation::Book(Book {
+                common_data: CommonCitationData {
+                    published: Some(PublishDate::from_year(year)),
+                    tags: vec![id.to_string()],
+                    ..CommonCitationData::new(id)
+                },
+                author: GenericAuthor::Organization {
+                    name: "Org".to_string(),
+                    abbreviation: None,
+                },
+                with_authors: Vec::new(),
+                title: title.into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            })
+        };
+        let mut bib = Bibliography::new();
+        bib.add_citation(book("a", "Rust in Action", 2021)).unwrap();
+        bib.add_citation(book("b", "Rust In Action", 2021)).unwrap();
+        bib.add_citation(book("c", "Citing", 2022)).unwrap();
+        bib.add_reference("c", "b").unwrap();
+
+        let proposal = bib.propose_merge(&["a", "b"]).unwrap();
+        let fields: Vec<&str> = proposal.fields.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(fields, ["title"]);
+        assert!(matches!(
+            bib.propose_merge(&["a", "missing"]),
+            Err(CitationError::UnknownId(_))
+        ));
+
+        let resolutions = Resolutions::from([("title".to_string(), 1)]);
+        bib.apply_merge(&proposal, &resolutions).unwrap();
+        assert_eq!(bib.len(), 2);
+        let merged = bib.get_citation("a").unwrap();
+        assert_eq!(merged.title(), "Rust In Action");
+        assert_eq!(merged.tags(), ["a", "b"]);
+        assert_eq!(
+            bib.get_citation("c").unwrap().cites(),
+            [CitationId::from("a")]
+        );
+    }
+
+    #[test]
+    fn test_in_text_for() {
+        let mut bib = Bibliography::new();
+        for (id, title) in [("first", "Zebras"), ("second", "Aardvarks")] {
+            bib.add_citation(Citation::Book(Book {
+                common_data: CommonCitationData {
+                    published: Some(PublishDate::from_year(2023)),
+                    ..CommonCitationData::new(id)
+                },
+                author: GenericAuthor::Persons {
+                    persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+                },
+                with_authors: Vec::new(),
+                title: title.into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            }))
+            .unwrap();
+        }
+
+        assert_eq!(
+            bib.in_text_for("first").unwrap().parenthetical(),
+            "(Smith, 2023b)"
+        );
+        assert_eq!(
+            bib.in_text_for("second").unwrap().narrative(),
+            "Smith (2023a)"
+        );
+        assert_eq!(bib.in_text_for("missing"), None);
+    }
+
+    #[test]
+    fn test_format_citation_cluster() {
+        let mut bib = Bibliography::new();
+        for (id, surname, year) in [
+            ("smith", "Smith", 2023),
+            ("jones", "Jones", 2019),
+            ("lee", "Lee", 2020),
+            ("ng", "Ng", 2021),
+        ] {
+            bib.add_citation(Citation::Book(Book {
+                common_data: CommonCitationData {
+                    published: Some(PublishDate::from_year(year)),
+                    ..CommonCitationData::new(id)
+                },
+                author: GenericAuthor::Persons {
+                    persons: vec![PersonName::from_last(surname).unwrap()],
+                },
+                with_authors: Vec::new(),
+                title: "Title".into(),
+                doi: None,
+                pages: None,
+                chapter: None,
+                version: None,
+            }))
+            .unwrap();
+        }
+        let refs = [
+            CiteRef::new("smith"),
+            CiteRef::new("ng"),
+            CiteRef::new("jones"),
+            CiteRef::new("lee"),
+        ];
+
+        assert_eq!(
+            bib.format_citation_cluster(&refs, CitationStyle::Apa)
+                .unwrap(),
+            "(Jones, 2019; Lee, 2020; Ng, 2021; Smith, 2023)"
+        );
+        assert_eq!(
+            bib.format_citation_cluster(&refs, CitationStyle::Ieee)
+                .unwrap(),
+            "[1]\u{2013}[4]"
+        );
+        assert_eq!(
+            bib.format_citation_cluster(&refs, CitationStyle::Iso690)
+                .unwrap(),
+            "(Jones 2019; Lee 2020; Ng 2021; Smith 2023)"
+        );
+        assert_eq!(
+            bib.format_citation_cluster(&refs, CitationStyle::Vancouver)
+                .unwrap(),
+            "(1\u{2013}4)"
+        );
+        assert_eq!(
+            bib.format_citation_cluster(&refs, CitationStyle::Ama)
+                .unwrap(),
+            "(1\u{2013}4)"
+        );
+        assert!(matches!(
+            bib.format_citation_cluster(&[CiteRef::new("nope")], CitationStyle::Ieee),
+            Err(CitationError::UnknownId(_))
+        ));
+    }
+
+    #[test]
+    fn test_import_dois_with() {
+        let client = FakeCrossref {
+            requested: Mutex::new(Vec::new()),
+        };
+        let mut bib = Bibliography::new();
+        let mut updates = Vec::new();
+
+        let report = bib.import_dois_with(
+            &client,
+            [
+                "10.1000/one",
+                "https://doi.org/10.1000/ONE",
+                "10.1000/two",
+                "10.1000/missing",
+                "garbage",
+            ],
+            2,
+            |progress| updates.push(progress),
+        );
+
+        assert_eq!(report.imported, vec!["10.1000/one", "10.1000/two"]);
+        assert_eq!(report.duplicates, vec!["10.1000/one"]);
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failures[0].0, "garbage");
+        assert_eq!(report.failures[1].0, "10.1000/missing");
+        assert_eq!(client.requested.lock().unwrap().len(), 3);
+        assert_eq!(updates.len(), 3);
+        assert!(updates.iter().all(|progress| progress.total == 3));
+        assert_eq!(updates.last().unwrap().completed, 3);
+        assert_eq!(bib.citations()[1].title(), "Title of 10.1000/two");
+    }
+
+    #[test]
+    fn test_import_dois_skips_existing() {
+        let client = FakeCrossref {
+            requested: Mutex::new(Vec::new()),
+        };
+        let mut bib = Bibliography::new();
+        bib.import_dois_with(&client, ["10.1000/one"], 1, |_| {});
+
+        let report = bib.import_dois_with(&client, ["doi:10.1000/one"], 1, |_| {});
+
+        assert!(report.imported.is_empty());
+        assert_eq!(report.duplicates, vec!["10.1000/one"]);
+        assert_eq!(client.requested.lock().unwrap().len(), 1);
+    }
+
+    /// Serves a small article page for every URL except those containing
+    /// "broken"
+    struct FakeWeb;
+
+    impl HttpClient for FakeWeb {
+        fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+            if url.contains("broken") {
+                return Err(FetchError::Transport {
+                    url: url.to_string(),
+                    reason: "connection reset".to_string(),
+                });
+            }
+            let body = format!(
+                "<html><head><title>Page {} | Site</title></head></html>",
+                url.rsplit('/').next().unwrap()
+            );
+            Ok(HttpResponse::new(200, body))
+        }
+    }
+
+    #[test]
+    fn test_import_urls_with() {
+        let mut bib = Bibliography::new();
+        bib.import_urls_with(&FakeWeb, ["https://example.com/existing"], 1, |_| {});
+
+        let report = bib.import_urls_with(
+            &FakeWeb,
+            [
+                "https://example.com/a",
+                "www.example.com/b",
+                "https://example.com/a",
+                "https://example.com/existing",
+                "https://example.com/broken",
+                "mailto:someone@example.com",
+            ],
+            3,
+            |_| {},
+        );
+
+        assert_eq!(
+            report.imported,
+            vec!["https://example.com/a", "https://www.example.com/b"]
+        );
+        assert_eq!(
+            report.duplicates,
+            vec!["https://example.com/a", "https://example.com/existing"]
+        );
+        assert_eq!(report.failures.len(), 2);
+        assert!(matches!(report.failures[1].1, ImportError::Fetch(_)));
+        assert_eq!(
+            bib.get_citation("https://example.com/a").unwrap().title(),
+            "Page a"
+        );
+    }
+
+    #[test]
+    fn test_import_bookmarks_skips_duplicates() {
+        let html = r#"<DL><p>
+            <DT><A HREF="https://example.com/a">A</A>
+            <DT><A HREF="https://example.com/a">A again</A>
+        </DL><p>"#;
+        let mut bib = Bibliography::new();
+
+        let report = bib.import_bookmarks(html);
+
+        assert_eq!(report.imported, vec!["https://example.com/a"]);
+        assert_eq!(report.duplicates, vec!["https://example.com/a"]);
+        assert_eq!(bib.len(), 1);
+    }
 }
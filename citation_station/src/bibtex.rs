@@ -0,0 +1,1306 @@
+//! Reading and writing citations as BibTeX entries.
+//!
+//! Titles are written with protection braces around words whose case a
+//! bibliography style must not change, such as "{DNA}", so that a style
+//! that converts titles to sentence case doesn't turn them into "Dna".
+//!
+//! The RIS, CSV and Org readers map what they read onto BibTeX entries,
+//! and share the rules here for turning entries into citations.
+//!
+//! Format reference: https://www.bibtex.org/Format/
+
+use std::collections::HashMap;
+
+use chrono::{Month, NaiveDate};
+
+use crate::{
+    api::{
+        author::{AcademicAuthor, Contributor, GenericAuthor, PersonName},
+        citation::Citation,
+        citation_id::CitationId,
+        date::{AccessDate, PublishDate},
+        location::LocationData,
+        media::{
+            book::Book, common::CommonCitationData, journal_article::JournalArticle,
+            online_manual::OnlineManualAvailability, online_video::OnlineVideo,
+            version::GenericMediaVersion, web_page::WebPage,
+        },
+        page_range::{ArticleLocator, PageRange},
+        title::{Title, protect_case},
+        volume::{Issue, Volume},
+    },
+    export::{BibtexDialect, DateFields, ExportOptions, iso_date},
+    html::date::parse_month,
+    import::{
+        ImportIssue, ImportReport, ImportWarning, identifier::Identifier, isbn::normalize_isbn,
+    },
+    unicode::{EMDASH, ENDASH},
+};
+
+/// The entry type and fields of one BibTeX entry, in output order
+pub(crate) struct Entry {
+    pub(crate) kind: &'static str,
+    pub(crate) fields: Vec<(&'static str, String)>,
+}
+
+impl Entry {
+    fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Add a field unless its value is missing or empty
+    fn field(&mut self, name: &'static str, value: Option<impl Into<String>>) {
+        if let Some(value) = value.map(Into::into)
+            && !value.trim().is_empty()
+        {
+            self.fields.push((name, value));
+        }
+    }
+}
+
+/// Write a citation as a BibTeX entry with the default
+/// [`ExportOptions`]. The citation ID is used as the entry key, with
+/// characters BibTeX doesn't allow in keys replaced by "_".
+pub fn citation_to_bibtex(citation: &Citation) -> String {
+    citation_to_bibtex_with(citation, &ExportOptions::default())
+}
+
+/// Like [`citation_to_bibtex`], mapping fields as `options` ask
+pub fn citation_to_bibtex_with(citation: &Citation, options: &ExportOptions) -> String {
+    let entry = entry_for(citation, options);
+    let mut lines = vec![format!("@{}{{{},", entry.kind, bibtex_key(&citation.id()))];
+    for (name, value) in &entry.fields {
+        let value = match *name {
+            "month" => value.clone(),
+            "title" | "booktitle" => format!("{{{}}}", protect_case(&escape(value))),
+            _ => format!("{{{}}}", escape(value)),
+        };
+        lines.push(format!("  {} = {},", name, value));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+/// Write citations as a BibTeX file, one entry per citation
+pub fn citations_to_bibtex(citations: &[Citation], options: &ExportOptions) -> String {
+    citations
+        .iter()
+        .map(|citation| citation_to_bibtex_with(citation, options) + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The BibTeX entry type and unescaped fields of `citation`
+pub(crate) fn entry_for(citation: &Citation, options: &ExportOptions) -> Entry {
+    let biblatex = options.dialect == BibtexDialect::Biblatex;
+    let mut entry = match citation {
+        Citation::Book(_) => Entry::new("book"),
+        Citation::JournalArticle(_) => Entry::new("article"),
+        Citation::ConferencePresentation(_) | Citation::ConferencePaperOnline(_) => {
+            Entry::new("inproceedings")
+        }
+        Citation::ConferenceProceedingsOnline(_) => Entry::new("proceedings"),
+        Citation::OnlineManual(_) => Entry::new("manual"),
+        Citation::Software(_) if biblatex => Entry::new("software"),
+        Citation::OnlineVideo(_) | Citation::WebPage(_) | Citation::WikipediaArticle(_)
+            if biblatex =>
+        {
+            Entry::new("online")
+        }
+        Citation::OnlineVideo(_)
+        | Citation::Software(_)
+        | Citation::WebPage(_)
+        | Citation::WikipediaArticle(_)
+        | Citation::Custom(_) => Entry::new("misc"),
+    };
+
+    entry.field("author", authors(citation));
+    entry.field("title", Some(citation.title()));
+
+    match citation {
+        Citation::Book(book) => {
+            match &book.version {
+                Some(GenericMediaVersion::Edition { number })
+                | Some(GenericMediaVersion::DigitalEdition { number }) => {
+                    entry.field("edition", Some(number.to_string()))
+                }
+                Some(GenericMediaVersion::Volume { number }) => {
+                    entry.field("volume", Some(number.to_string()))
+                }
+                Some(GenericMediaVersion::VolumeRange { start, end }) => {
+                    entry.field("volume", Some(format!("{}--{}", start, end)))
+                }
+                Some(GenericMediaVersion::SemVer(version)) => {
+                    entry.field("version", Some(version.to_string()))
+                }
+                None => {}
+            }
+            entry.field("chapter", book.chapter.clone());
+            entry.field("pages", book.pages.as_ref().map(pages));
+            entry.field("publisher", book.common_data.extended.publisher.clone());
+            entry.field(
+                "address",
+                book.common_data
+                    .extended
+                    .place
+                    .as_ref()
+                    .map(ToString::to_string),
+            );
+        }
+        Citation::JournalArticle(article) => {
+            entry.field("journal", Some(article.journal.clone()));
+            entry.field("volume", article.volume.as_ref().map(ToString::to_string));
+            entry.field("number", article.number.as_ref().map(ToString::to_string));
+            match &article.locator {
+                Some(ArticleLocator::Pages(range)) => entry.field("pages", Some(pages(range))),
+                Some(ArticleLocator::ArticleNumber(number)) => {
+                    entry.field("eid", Some(number.clone()))
+                }
+                None => {}
+            }
+        }
+        Citation::ConferencePresentation(presentation) => {
+            entry.field(
+                "booktitle",
+                Some(presentation.series.full_name(&presentation.conference_name)),
+            );
+            entry.field(
+                "address",
+                presentation.location.as_ref().map(ToString::to_string),
+            );
+        }
+        Citation::ConferencePaperOnline(paper) => {
+            entry.field(
+                "booktitle",
+                Some(paper.series.full_name(&paper.conference_name)),
+            );
+            entry.field("volume", paper.volume.as_ref().map(ToString::to_string));
+            entry.field("number", paper.number.as_ref().map(ToString::to_string));
+            entry.field("address", paper.venue.clone());
+        }
+        Citation::ConferenceProceedingsOnline(proceedings) => {
+            let editors: Vec<String> = proceedings
+                .editors
+                .iter()
+                .map(|editor| match editor.given_names() {
+                    Some(given) => format!("{}, {}", editor.surname(), given),
+                    None => editor.surname().to_string(),
+                })
+                .collect();
+            entry.field(
+                "editor",
+                (!editors.is_empty()).then(|| editors.join(" and ")),
+            );
+            entry.field(
+                "volume",
+                proceedings.volume.as_ref().map(ToString::to_string),
+            );
+            entry.field(
+                "number",
+                proceedings.number.as_ref().map(ToString::to_string),
+            );
+            entry.field("address", proceedings.venue.clone());
+        }
+        Citation::OnlineManual(manual) => {
+            entry.field(
+                "version",
+                manual
+                    .version
+                    .as_ref()
+                    .map(GenericMediaVersion::as_apa_string),
+            );
+            if let OnlineManualAvailability::DOI(doi) = &manual.available_at {
+                entry.field("doi", Some(doi.clone()));
+            }
+        }
+        Citation::OnlineVideo(OnlineVideo::YouTube { .. }) => {
+            entry.field("howpublished", Some("YouTube"));
+        }
+        Citation::OnlineVideo(OnlineVideo::Generic { .. }) => {}
+        Citation::Software(software) => {
+            entry.field("version", software.version.clone());
+            entry.field("publisher", software.publisher.clone());
+        }
+        Citation::WebPage(page) => {
+            entry.field("organization", page.site_name.clone());
+        }
+        Citation::WikipediaArticle(_) => {
+            entry.field("organization", Some("Wikipedia"));
+        }
+        Citation::Custom(_) => {}
+    }
+
+    match (citation.published(), options.dates) {
+        (Some(published), DateFields::YearMonth) => {
+            entry.field("year", Some(published.year().to_string()));
+            entry.field("month", published.month().map(month_macro));
+        }
+        (Some(published), DateFields::Date) => entry.field("date", Some(iso_date(&published))),
+        (None, _) => {}
+    }
+    entry.field("doi", citation.doi());
+    entry.field("url", citation.url());
+    entry.field(
+        "urldate",
+        citation
+            .accessed()
+            .map(|accessed| accessed.date().to_string()),
+    );
+    if options.tags_as_keywords {
+        entry.field("keywords", Some(citation.tags().join(", ")));
+    }
+
+    if biblatex {
+        for (name, _) in &mut entry.fields {
+            *name = match *name {
+                "journal" => "journaltitle",
+                "address" => "location",
+                other => other,
+            };
+        }
+    }
+    entry
+}
+
+/// Authors joined with "and", as "Surname, Given names". Organizations
+/// and pseudonyms are braced so BibTeX doesn't split them into names.
+fn authors(citation: &Citation) -> Option<String> {
+    let mut names: Vec<String> = citation
+        .contributors()
+        .into_iter()
+        .filter_map(|contributor| match contributor {
+            Contributor::Person(person) => Some(match person.given_names() {
+                Some(given) => format!("{}, {}", person.surname(), given),
+                None => person.surname().to_string(),
+            }),
+            Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => {
+                Some(format!("{{{}}}", name))
+            }
+            Contributor::Anonymous => None,
+        })
+        .collect();
+    if let Citation::Book(book) = citation {
+        names.extend(
+            book.with_authors
+                .iter()
+                .map(|person| match person.given_names() {
+                    Some(given) => format!("{}, {}", person.surname(), given),
+                    None => person.surname().to_string(),
+                }),
+        );
+    }
+    (!names.is_empty()).then(|| names.join(" and "))
+}
+
+fn pages(range: &PageRange) -> String {
+    if range.start == range.end {
+        range.start.to_string()
+    } else {
+        format!("{}--{}", range.start, range.end)
+    }
+}
+
+/// BibTeX's predefined month macros, written without braces
+const fn month_macro(month: Month) -> &'static str {
+    match month {
+        Month::January => "jan",
+        Month::February => "feb",
+        Month::March => "mar",
+        Month::April => "apr",
+        Month::May => "may",
+        Month::June => "jun",
+        Month::July => "jul",
+        Month::August => "aug",
+        Month::September => "sep",
+        Month::October => "oct",
+        Month::November => "nov",
+        Month::December => "dec",
+    }
+}
+
+/// Escape the characters that are special to (La)TeX
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ENDASH => escaped.push_str("--"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A citation ID usable as a BibTeX key
+fn bibtex_key(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || "-_:./+".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// An entry read from a BibTeX-like source: its type, key and fields,
+/// with the 1-based line each starts on. The BibTeX, RIS, CSV and Org
+/// readers map their input onto these so that one set of rules turns
+/// entries into citations, see [`citation_from_entry`].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ReadEntry {
+    /// Lowercase BibTeX entry type, e.g. "article", if the source states
+    /// one
+    pub(crate) kind: Option<String>,
+    pub(crate) key: Option<String>,
+    pub(crate) line: usize,
+    /// Lowercase field names and their values. Values are plain text,
+    /// except that `author` keeps the braces around organization names.
+    pub(crate) fields: Vec<(String, String, usize)>,
+}
+
+impl ReadEntry {
+    /// The non-empty value of the field called `name`, and its line
+    fn field(&self, name: &str) -> Option<(&str, usize)> {
+        self.fields
+            .iter()
+            .find(|(field, ..)| field == name)
+            .map(|(_, value, line)| (value.trim(), *line))
+            .filter(|(value, _)| !value.is_empty())
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.field(name).map(|(value, _)| value)
+    }
+}
+
+/// Fields read by [`citation_from_entry`]
+const READ_FIELDS: [&str; 23] = [
+    "title",
+    "author",
+    "journal",
+    "journaltitle",
+    "volume",
+    "number",
+    "pages",
+    "eid",
+    "edition",
+    "chapter",
+    "year",
+    "month",
+    "date",
+    "publisher",
+    "address",
+    "location",
+    "isbn",
+    "doi",
+    "url",
+    "urldate",
+    "organization",
+    "keywords",
+    "howpublished",
+];
+
+/// Turn an entry into a citation, adding it and what was lost or guessed
+/// on the way to `report`.
+///
+/// `article`s with a journal become journal articles, `online`,
+/// `electronic`, `misc` and `www` entries with a URL become web pages,
+/// and the rest become books, which is reported for types other than
+/// `book`. Without a type, entries with a journal are taken to be
+/// articles and entries with a URL web pages, which is reported too.
+/// Entries without a title are skipped. The entry key is used
+/// as the ID, falling back to the DOI and then to a slug of the title.
+/// The `address` is kept as the place of publication if its country can
+/// be told, and reported otherwise.
+pub(crate) fn citation_from_entry(entry: &ReadEntry, report: &mut ImportReport) {
+    let title = entry.get("title");
+    let doi = entry.get("doi").map(str::to_string);
+    let id = entry
+        .key
+        .clone()
+        .or_else(|| doi.clone())
+        .or_else(|| {
+            title
+                .and_then(CitationId::slug)
+                .map(CitationId::into_string)
+        })
+        .unwrap_or_else(|| "untitled".to_string());
+    let mut warn = |line, issue| {
+        report.warnings.push(ImportWarning {
+            line: Some(line),
+            entry: Some(id.clone()),
+            issue,
+        })
+    };
+
+    let Some(title) = title else {
+        warn(
+            entry.line,
+            ImportIssue::SkippedEntry {
+                reason: "entry has no title".to_string(),
+            },
+        );
+        return;
+    };
+    for (name, _, line) in &entry.fields {
+        if !READ_FIELDS.contains(&name.as_str()) {
+            warn(
+                *line,
+                ImportIssue::UnknownField {
+                    field: name.clone(),
+                },
+            );
+        }
+    }
+
+    let date = entry
+        .field("date")
+        .map(|(date, line)| (date, line, PublishDate::parse_iso(date)))
+        .or_else(|| {
+            let (year, line) = entry.field("year")?;
+            Some((year, line, year_month(year, entry.get("month"))))
+        });
+    let published = date.and_then(|(original, line, published)| {
+        if published.is_none() {
+            warn(
+                line,
+                ImportIssue::CoercedDate {
+                    original: original.to_string(),
+                    used: None,
+                },
+            );
+        }
+        published
+    });
+    let (persons, organization) = parse_authors(entry.get("author").unwrap_or_default());
+    let tags = entry
+        .get("keywords")
+        .map(|keywords| {
+            keywords
+                .split(',')
+                .map(str::trim)
+                .filter(|keyword| !keyword.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut common_data = CommonCitationData {
+        published,
+        tags,
+        ..CommonCitationData::new(id.clone())
+    };
+    if let Some(isbn) = entry.get("isbn") {
+        let isbn = normalize_isbn(isbn).unwrap_or_else(|| isbn.to_string());
+        common_data.identifiers.insert(Identifier::Isbn(isbn));
+    }
+    common_data.extended.publisher = entry.get("publisher").map(str::to_string);
+    let address = ["address", "location"]
+        .into_iter()
+        .find_map(|name| Some((name, entry.field(name)?)));
+    if let Some((name, (address, line))) = address {
+        common_data.extended.place = LocationData::parse(address);
+        if common_data.extended.place.is_none() {
+            // A lone city, whose country is unknown
+            warn(
+                line,
+                ImportIssue::UnknownField {
+                    field: name.to_string(),
+                },
+            );
+        }
+    }
+    let title = Title::parse(title);
+    let pages = entry.get("pages").map(|pages| pages.replace("--", "-"));
+    let journal = entry.get("journal").or_else(|| entry.get("journaltitle"));
+    let url = entry.get("url");
+    let kind = match &entry.kind {
+        Some(kind) => kind.as_str(),
+        None => {
+            let guessed = match (journal, url) {
+                (Some(_), _) => "article",
+                (None, Some(_)) => "online",
+                (None, None) => "book",
+            };
+            warn(
+                entry.line,
+                ImportIssue::GuessedMediaType {
+                    stated: None,
+                    used: guessed.to_string(),
+                },
+            );
+            guessed
+        }
+    };
+    let citation = match (kind, journal, url) {
+        ("article", Some(journal), _) => Citation::JournalArticle(JournalArticle {
+            common_data,
+            author: match organization {
+                Some(name) => AcademicAuthor::Organization {
+                    name,
+                    abbreviation: None,
+                },
+                None => AcademicAuthor::Persons { persons },
+            },
+            title,
+            journal: journal.to_string(),
+            volume: entry.get("volume").map(Volume::parse),
+            number: entry.get("number").map(Issue::parse),
+            locator: pages
+                .as_deref()
+                .and_then(ArticleLocator::parse)
+                .or_else(|| {
+                    entry
+                        .get("eid")
+                        .map(|number| ArticleLocator::ArticleNumber(number.to_string()))
+                }),
+            doi,
+        }),
+        ("online" | "electronic" | "misc" | "www", _, Some(url)) => {
+            let urldate = entry.field("urldate");
+            let accessed = match urldate.and_then(|(date, _)| {
+                let day = date.split(['T', ' ']).next()?;
+                NaiveDate::parse_from_str(day, "%Y-%m-%d").ok()
+            }) {
+                Some(date) => AccessDate::from_date(date),
+                None => {
+                    let today = AccessDate::today();
+                    warn(
+                        urldate.map_or(entry.line, |(_, line)| line),
+                        ImportIssue::CoercedDate {
+                            original: urldate.map_or("", |(date, _)| date).to_string(),
+                            used: Some(today.date().to_string()),
+                        },
+                    );
+                    today
+                }
+            };
+            Citation::WebPage(WebPage {
+                common_data,
+                author: generic_author(persons, organization),
+                title,
+                site_name: entry.get("organization").map(str::to_string),
+                url: url.to_string(),
+                accessed,
+            })
+        }
+        (kind, ..) => {
+            if kind != "book" && entry.kind.is_some() {
+                warn(
+                    entry.line,
+                    ImportIssue::GuessedMediaType {
+                        stated: Some(kind.to_string()),
+                        used: "book".to_string(),
+                    },
+                );
+            }
+            let edition = entry
+                .get("edition")
+                .and_then(|edition| edition.parse().ok())
+                .map(|number| GenericMediaVersion::Edition { number });
+            let volume = entry
+                .get("volume")
+                .and_then(|volume| volume.parse().ok())
+                .map(|number| GenericMediaVersion::Volume { number });
+            Citation::Book(Book {
+                common_data,
+                author: generic_author(persons, organization).unwrap_or(GenericAuthor::Persons {
+                    persons: Vec::new(),
+                }),
+                with_authors: Vec::new(),
+                title,
+                chapter: entry.get("chapter").map(str::to_string),
+                version: edition.or(volume),
+                doi,
+                pages: pages.as_deref().and_then(PageRange::parse),
+            })
+        }
+    };
+    report.citations.push(citation);
+}
+
+/// A BibTeX year and month, the month as a number, a macro like "jan" or
+/// a name
+fn year_month(year: &str, month: Option<&str>) -> Option<PublishDate> {
+    let year = year.trim().parse().ok()?;
+    let month = month.and_then(|month| {
+        month
+            .parse::<u8>()
+            .ok()
+            .and_then(|number| Month::try_from(number).ok())
+            .or_else(|| parse_month(month))
+    });
+    Some(match month {
+        Some(month) => PublishDate::from_year_month(year, month),
+        None => PublishDate::from_year(year),
+    })
+}
+
+/// Persons in a BibTeX author list, or the organization if the list is a
+/// single braced name like "{World Health Organization}"
+fn parse_authors(list: &str) -> (Vec<PersonName>, Option<String>) {
+    let list = list.trim();
+    if let Some(name) = list
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        && !name.contains(['{', '}'])
+    {
+        return (Vec::new(), Some(unescape(name)));
+    }
+    let persons = list
+        .split(" and ")
+        .filter_map(|name| PersonName::parse(&unescape(name)).ok())
+        .collect();
+    (persons, None)
+}
+
+fn generic_author(persons: Vec<PersonName>, organization: Option<String>) -> Option<GenericAuthor> {
+    match organization {
+        Some(name) => Some(GenericAuthor::Organization {
+            name,
+            abbreviation: None,
+        }),
+        None => (!persons.is_empty()).then_some(GenericAuthor::Persons { persons }),
+    }
+}
+
+/// Read the entries of a BibTeX or BibLaTeX file.
+///
+/// `@string` macros are expanded, and `@comment` and `@preamble` blocks
+/// are skipped. Field values are read as plain text: braces are removed,
+/// and escaped characters, common accent commands and "--" dashes become
+/// the characters they stand for. `article`s with a journal become
+/// journal articles, `online`, `electronic`, `misc` and `www` entries
+/// with a URL become web pages, and the rest become books, which is
+/// reported for types other than `book`. Unread fields, dates that can't
+/// be read and entries that can't be parsed are reported with their line
+/// numbers.
+pub fn read_bibtex(text: &str) -> ImportReport {
+    let mut report = ImportReport::default();
+    for entry in read_entries(text, &mut report.warnings) {
+        citation_from_entry(&entry, &mut report);
+    }
+    report.warnings.sort_by_key(|warning| warning.line);
+    report
+}
+
+/// The citations in a BibTeX file, see [`read_bibtex`]
+pub fn citations_from_bibtex(text: &str) -> Vec<Citation> {
+    read_bibtex(text).citations
+}
+
+/// The entries of a BibTeX file. Entries that can't be parsed are
+/// reported in `warnings` and skipped.
+fn read_entries(text: &str, warnings: &mut Vec<ImportWarning>) -> Vec<ReadEntry> {
+    let mut parser = Parser {
+        text,
+        position: 0,
+        line: 1,
+        counted: 0,
+        macros: HashMap::new(),
+    };
+    let mut entries = Vec::new();
+    while let Some(start) = text[parser.position..].find('@') {
+        parser.position += start;
+        let line = parser.line();
+        match parser.entry() {
+            Ok(Some(entry)) => entries.push(entry),
+            Ok(None) => {}
+            Err(reason) => {
+                warnings.push(ImportWarning {
+                    line: Some(line),
+                    entry: None,
+                    issue: ImportIssue::SkippedEntry { reason },
+                });
+                parser.skip_to_next_entry();
+            }
+        }
+    }
+    entries
+}
+
+/// A position in BibTeX source, and the `@string` macros defined before it
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    /// The line of `counted`, which trails `position`
+    line: usize,
+    counted: usize,
+    macros: HashMap<String, String>,
+}
+
+impl<'a> Parser<'a> {
+    /// The 1-based line of the current position
+    fn line(&mut self) -> usize {
+        self.line += self.text[self.counted..self.position].matches('\n').count();
+        self.counted = self.position;
+        self.line
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// An entry type, key, field name or macro name
+    fn name(&mut self) -> &'a str {
+        let rest = &self.text[self.position..];
+        let end = rest
+            .find(|c: char| c.is_whitespace() || "{}()\",=#@%".contains(c))
+            .unwrap_or(rest.len());
+        self.position += end;
+        &rest[..end]
+    }
+
+    /// Read the entry at the `@` at the current position. `@string`,
+    /// `@comment` and `@preamble` blocks give `None`.
+    fn entry(&mut self) -> Result<Option<ReadEntry>, String> {
+        let line = self.line();
+        self.position += 1;
+        self.skip_whitespace();
+        let kind = self.name().to_lowercase();
+        if kind.is_empty() {
+            return Err("expected an entry type after \"@\"".to_string());
+        }
+        self.skip_whitespace();
+        if kind == "comment" && !matches!(self.peek(), Some('{' | '(')) {
+            // A comment without braces ends with the line
+            return Ok(None);
+        }
+        let close = match self.peek() {
+            Some('{') => '}',
+            Some('(') => ')',
+            _ => return Err(format!("expected \"{{\" after @{}", kind)),
+        };
+        match kind.as_str() {
+            "comment" | "preamble" => {
+                self.braced(close)?;
+                Ok(None)
+            }
+            "string" => {
+                self.position += 1;
+                for (name, value, _) in self.fields(close)? {
+                    self.macros.insert(name, value);
+                }
+                Ok(None)
+            }
+            _ => {
+                self.position += 1;
+                self.skip_whitespace();
+                let key = self.name().to_string();
+                let fields = self
+                    .fields(close)?
+                    .into_iter()
+                    .map(|(name, value, line)| {
+                        let value = match name.as_str() {
+                            "author" => value,
+                            "url" | "doi" => verbatim(&value),
+                            _ => unescape(&value),
+                        };
+                        (name, value, line)
+                    })
+                    .collect();
+                Ok(Some(ReadEntry {
+                    kind: Some(kind),
+                    key: (!key.is_empty()).then_some(key),
+                    line,
+                    fields,
+                }))
+            }
+        }
+    }
+
+    /// The `name = value` fields up to and including `close`, with raw
+    /// values
+    fn fields(&mut self, close: char) -> Result<Vec<(String, String, usize)>, String> {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Err("entry is not closed".to_string()),
+                Some(',') => self.position += 1,
+                Some(c) if c == close => {
+                    self.position += 1;
+                    return Ok(fields);
+                }
+                Some(c) => {
+                    let line = self.line();
+                    let name = self.name().to_lowercase();
+                    if name.is_empty() {
+                        return Err(format!("unexpected \"{}\"", c));
+                    }
+                    self.skip_whitespace();
+                    if self.peek() != Some('=') {
+                        return Err(format!("expected \"=\" after {}", name));
+                    }
+                    self.position += 1;
+                    let value = self.value()?;
+                    fields.push((name, value, line));
+                }
+            }
+        }
+    }
+
+    /// A field value: braced or quoted text, a number or a macro, joined
+    /// to further parts by "#"
+    fn value(&mut self) -> Result<String, String> {
+        let mut value = String::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('{') => value.push_str(self.braced('}')?),
+                Some('"') => value.push_str(self.quoted()?),
+                Some(_) => {
+                    let name = self.name();
+                    if name.is_empty() {
+                        return Err("expected a value".to_string());
+                    }
+                    // Undefined macros, including the month macros, are
+                    // kept as they are
+                    let expanded = self.macros.get(&name.to_lowercase()).cloned();
+                    value.push_str(&expanded.unwrap_or_else(|| name.to_string()));
+                }
+                None => return Err("expected a value".to_string()),
+            }
+            self.skip_whitespace();
+            if self.peek() != Some('#') {
+                return Ok(value);
+            }
+            self.position += 1;
+        }
+    }
+
+    /// The text inside the delimiters starting at the current position,
+    /// which end at `close` once all braces inside are balanced
+    fn braced(&mut self, close: char) -> Result<&'a str, String> {
+        let start = self.position + 1;
+        let mut depth = 0;
+        let mut escaped = false;
+        for (index, c) in self.text[start..].char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                _ if c == close && depth == 0 => {
+                    self.position = start + index + 1;
+                    return Ok(&self.text[start..start + index]);
+                }
+                '{' => depth += 1,
+                '}' if depth == 0 => return Err("unbalanced braces".to_string()),
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        Err("unbalanced braces".to_string())
+    }
+
+    /// The text of the quoted value starting at the current position.
+    /// Quotes inside braces don't end it.
+    fn quoted(&mut self) -> Result<&'a str, String> {
+        let start = self.position + 1;
+        let mut depth = 0;
+        let mut escaped = false;
+        for (index, c) in self.text[start..].char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' if depth == 0 => {
+                    self.position = start + index + 1;
+                    return Ok(&self.text[start..start + index]);
+                }
+                '{' => depth += 1,
+                '}' if depth == 0 => return Err("unbalanced braces".to_string()),
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        Err("unterminated quoted value".to_string())
+    }
+
+    /// Move past the current position to the next line starting with "@"
+    fn skip_to_next_entry(&mut self) {
+        let rest = &self.text[self.position..];
+        self.position += rest
+            .match_indices('\n')
+            .map(|(index, _)| index + 1)
+            .find(|&index| rest[index..].trim_start().starts_with('@'))
+            .unwrap_or(rest.len());
+    }
+}
+
+/// Letters with the accents of the LaTeX accent commands, as
+/// (command, letter, accented letter)
+const ACCENTED: [(char, &str, &str); 8] = [
+    ('"', "aeiouyAEIOU", "äëïöüÿÄËÏÖÜ"),
+    ('\'', "aeiouycnszAEIOUYCNSZ", "áéíóúýćńśźÁÉÍÓÚÝĆŃŚŹ"),
+    ('`', "aeiouAEIOU", "àèìòùÀÈÌÒÙ"),
+    ('^', "aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+    ('~', "anoANO", "ãñõÃÑÕ"),
+    ('c', "csCS", "çşÇŞ"),
+    ('v', "csznrdeCSZNRDE", "čšžňřďěČŠŽŇŘĎĚ"),
+    ('r', "auAU", "åůÅŮ"),
+];
+
+/// LaTeX commands without an argument that stand for text. Other
+/// commands are dropped, keeping their argument.
+const COMMANDS: [(&str, &str); 16] = [
+    ("ss", "ß"),
+    ("o", "ø"),
+    ("O", "Ø"),
+    ("aa", "å"),
+    ("AA", "Å"),
+    ("ae", "æ"),
+    ("AE", "Æ"),
+    ("oe", "œ"),
+    ("OE", "Œ"),
+    ("l", "ł"),
+    ("L", "Ł"),
+    ("i", "i"),
+    ("textendash", "\u{2013}"),
+    ("textemdash", "\u{2014}"),
+    ("TeX", "TeX"),
+    ("LaTeX", "LaTeX"),
+];
+
+/// A BibTeX value as plain text: braces removed, escaped characters and
+/// accent commands replaced by the characters they stand for, "--" and
+/// "---" as en and em dashes, and runs of whitespace as single spaces
+fn unescape(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' => {}
+            '\\' => {
+                let Some(command) = chars.next() else {
+                    break;
+                };
+                let accent = if command.is_ascii_alphabetic() {
+                    let mut word = command.to_string();
+                    while let Some(c) = chars.next_if(char::is_ascii_alphabetic) {
+                        word.push(c);
+                    }
+                    chars.next_if_eq(&' ');
+                    match COMMANDS.iter().find(|(name, _)| *name == word) {
+                        Some((_, replacement)) => {
+                            text.push_str(replacement);
+                            continue;
+                        }
+                        None if word.len() == 1 => command,
+                        None => continue,
+                    }
+                } else {
+                    command
+                };
+                let Some((_, plain, accented)) =
+                    ACCENTED.iter().find(|(command, ..)| *command == accent)
+                else {
+                    // Escaped special characters are kept, other accents
+                    // are dropped
+                    match accent {
+                        '&' | '%' | '$' | '#' | '_' | '{' | '}' => text.push(accent),
+                        '\\' | ' ' if !text.ends_with(' ') => text.push(' '),
+                        _ => {}
+                    }
+                    continue;
+                };
+                while chars.next_if(|c| *c == '{' || *c == ' ').is_some() {}
+                let letter = match chars.next_if_eq(&'\\') {
+                    // A dotless i or j, as in \'{\i}
+                    Some(_) => chars.next_if(|c| *c == 'i' || *c == 'j'),
+                    None => chars.next_if(|c| c.is_alphabetic()),
+                };
+                match letter.and_then(|letter| plain.chars().position(|c| c == letter)) {
+                    Some(index) => text.extend(accented.chars().nth(index)),
+                    None => text.extend(letter),
+                }
+            }
+            '-' if chars.next_if_eq(&'-').is_some() => {
+                text.push(match chars.next_if_eq(&'-') {
+                    Some(_) => EMDASH,
+                    None => ENDASH,
+                });
+            }
+            '~' => text.push(' '),
+            c if c.is_whitespace() => {
+                if !text.ends_with(' ') {
+                    text.push(' ');
+                }
+            }
+            c => text.push(c),
+        }
+    }
+    text.trim().to_string()
+}
+
+/// A URL or DOI as written: only the escapes [`escape`] adds and braces
+/// are removed, since "~" and "--" mean themselves there
+fn verbatim(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.extend(chars.next_if(|c| "&%$#_".contains(*c))),
+            '{' | '}' => {}
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            location::LocationData,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+            page_range::{ArticleLocator, PageRange},
+            title::{Title, sentence_case},
+            volume::Volume,
+        },
+        bibtex::{bibtex_key, citation_to_bibtex, citation_to_bibtex_with, read_bibtex, unescape},
+        export::ExportOptions,
+        import::ImportIssue,
+    };
+
+    fn article() -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month(2020, Month::March)),
+                ..CommonCitationData::new("smith 2020")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_last("Fuentes").unwrap(),
+                ],
+            },
+            title: Title::with_subtitle("DNA Repair in Yeast", "R&D at 50% Cost"),
+            journal: "J. Tests".to_string(),
+            volume: Some(Volume::parse("12")),
+            number: None,
+            locator: Some(ArticleLocator::Pages(PageRange { start: 3, end: 9 })),
+            doi: Some("10.1000/xyz".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_article_to_bibtex() {
+        assert_eq!(
+            citation_to_bibtex(&article()),
+            "@article{smith_2020,
+  author = {Smith, Jane and Fuentes},
+  title = {{DNA} Repair in Yeast: {R\\&D} at 50\\% Cost},
+  journal = {J. Tests},
+  volume = {12},
+  pages = {3--9},
+  year = {2020},
+  month = mar,
+  doi = {10.1000/xyz},
+}"
+        );
+    }
+
+    #[test]
+    fn test_biblatex_options() {
+        let mut citation = article();
+        citation.common_data_mut().tags = vec!["repair".to_string(), "yeast".to_string()];
+
+        let bibtex = citation_to_bibtex_with(&citation, &ExportOptions::biblatex());
+
+        assert!(bibtex.contains("  journaltitle = {J. Tests},\n"));
+        assert!(bibtex.contains("  date = {2020-03},\n"));
+        assert!(!bibtex.contains("  year = "));
+        assert!(bibtex.contains("  keywords = {repair, yeast},\n"));
+        assert!(!citation_to_bibtex(&citation).contains("keywords"));
+    }
+
+    #[test]
+    fn test_protected_title_survives_sentence_case() {
+        let bibtex = citation_to_bibtex(&article());
+        let title = bibtex
+            .lines()
+            .find_map(|line| line.strip_prefix("  title = {")?.strip_suffix("},"))
+            .unwrap();
+
+        assert!(sentence_case(title).starts_with("DNA repair in yeast"));
+    }
+
+    #[test]
+    fn test_bibtex_key() {
+        assert_eq!(bibtex_key("10.1000/xyz"), "10.1000/xyz");
+        assert_eq!(bibtex_key("a, b {c}"), "a__b__c_");
+    }
+
+    const BIBTEX: &str = r#"% Reading list
+@string{nips = "Advances in Neural " # {Information Processing Systems}}
+
+@comment{Not an entry: @book{fake, title = {Fake}}}
+
+@article{vaswani2017,
+  author = {Vaswani, Ashish and Noam Shazeer and {\"O}zt{\"u}rk, Ali},
+  title = {Attention Is {All} You Need},
+  journal = nips,
+  volume = 30,
+  pages = {5998--6008},
+  year = 2017,
+  month = dec,
+  keywords = {transformers, attention},
+  note = {Read twice},
+}
+
+@misc{rust-blog,
+  author = {{The Rust Team}},
+  title = "The {Rust} Blog",
+  url = {https://blog.rust-lang.org/~team},
+  urldate = {2024-05-01},
+}
+
+@inproceedings{broken,
+  title = {Missing a brace,
+  year = 2020,
+
+@techreport{report2021,
+  title = {Annual Report},
+  year = {Spring 2021},
+}
+"#;
+
+    #[test]
+    fn test_read_bibtex() {
+        let report = read_bibtex(BIBTEX);
+        let [
+            Citation::JournalArticle(article),
+            Citation::WebPage(page),
+            Citation::Book(book),
+        ] = report.citations.as_slice()
+        else {
+            panic!("expected an article, a web page and a book");
+        };
+        assert_eq!(article.common_data.id, "vaswani2017");
+        assert_eq!(article.title.to_string(), "Attention Is All You Need");
+        assert_eq!(
+            article.journal,
+            "Advances in Neural Information Processing Systems"
+        );
+        assert_eq!(
+            article.author.as_ieee_string().unwrap(),
+            "A. Vaswani, N. Shazeer, and A. \u{D6}zt\u{FC}rk"
+        );
+        assert_eq!(
+            article.common_data.published,
+            Some(PublishDate::from_year_month(2017, Month::December))
+        );
+        assert_eq!(article.common_data.tags, ["transformers", "attention"]);
+        assert_eq!(
+            article.locator,
+            Some(ArticleLocator::Pages(PageRange {
+                start: 5998,
+                end: 6008
+            }))
+        );
+        assert_eq!(page.url, "https://blog.rust-lang.org/~team");
+        assert_eq!(page.title.to_string(), "The Rust Blog");
+        assert_eq!(page.accessed.date().to_string(), "2024-05-01");
+        assert_eq!(book.common_data.id, "report2021");
+
+        let issues: Vec<_> = report
+            .warnings
+            .iter()
+            .map(|warning| (warning.line, &warning.issue))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (
+                    Some(15),
+                    &ImportIssue::UnknownField {
+                        field: "note".to_string()
+                    }
+                ),
+                (
+                    Some(25),
+                    &ImportIssue::SkippedEntry {
+                        reason: "unbalanced braces".to_string()
+                    }
+                ),
+                (
+                    Some(29),
+                    &ImportIssue::GuessedMediaType {
+                        stated: Some("techreport".to_string()),
+                        used: "book".to_string()
+                    }
+                ),
+                (
+                    Some(31),
+                    &ImportIssue::CoercedDate {
+                        original: "Spring 2021".to_string(),
+                        used: None
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut written = article();
+        written.common_data_mut().id = "smith_2020".to_string();
+
+        let report = read_bibtex(&citation_to_bibtex(&written));
+
+        assert_eq!(report.citations, [written]);
+        assert_eq!(report.warnings, []);
+    }
+
+    #[test]
+    fn test_read_address() {
+        let report = read_bibtex(
+            "@book{mit, title = {Structure}, address = {Cambridge, Massachusetts}}\n\
+             @book{london, title = {Elsewhere}, address = {London}}\n",
+        );
+
+        let place = &report.citations[0].common_data().extended.place;
+        assert_eq!(
+            place,
+            &Some(LocationData::new("Cambridge", "US").with_state("MA"))
+        );
+        assert_eq!(report.citations[1].common_data().extended.place, None);
+        assert_eq!(
+            report.warnings[0].issue,
+            ImportIssue::UnknownField {
+                field: "address".to_string()
+            }
+        );
+
+        // Written in full, and read back the same
+        let written = citation_to_bibtex_with(&report.citations[0], &ExportOptions::biblatex());
+        assert!(written.contains("location = {Cambridge, MA, United States}"));
+        assert_eq!(read_bibtex(&written).citations, report.citations[..1]);
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(
+            unescape("Caf{\\'e} \\c{c}a {\\v s}ance --- \\'{\\i}ndice \\ss{} \\emph{x} 50\\%"),
+            "Caf\u{E9} \u{E7}a \u{161}ance \u{2014} \u{ED}ndice \u{DF} x 50%"
+        );
+        assert_eq!(unescape("  two\n  lines~here "), "two lines here");
+    }
+}
@@ -0,0 +1,835 @@
+//! Reading and writing the Citation File Format (`CITATION.cff`), which
+//! maps to the [`Software`] media type.
+//!
+//! CFF files are YAML, but in practice only use block mappings, block
+//! lists, quoted and plain scalars, flow lists of scalars and folded or
+//! literal text. This module reads that subset rather than depending on a
+//! full YAML implementation.
+//!
+//! Format reference: https://github.com/citation-file-format/citation-file-format
+
+use thiserror::Error;
+
+use crate::{
+    api::{
+        author::{Contributor, GenericAuthor, PersonName},
+        citation::Citation,
+        date::{AccessDate, PublishDate},
+        media::{common::CommonCitationData, software::Software},
+        title::Title,
+    },
+    import::{ImportIssue, ImportReport, ImportWarning},
+};
+
+/// The CFF version written by [`software_to_cff`]
+pub const CFF_VERSION: &str = "1.2.0";
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum CffError {
+    #[error("Invalid CITATION.cff (line {line}): {message}")]
+    Syntax { line: usize, message: String },
+    #[error("Missing required field: {0}")]
+    MissingField(String),
+}
+
+/// A parsed YAML node
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Yaml {
+    Scalar(String),
+    List(Vec<Yaml>),
+    Map(Vec<(String, Yaml)>),
+}
+
+impl Yaml {
+    /// Value of `key` if this is a mapping that has it
+    pub(crate) fn get(&self, key: &str) -> Option<&Yaml> {
+        match self {
+            Yaml::Map(entries) => entries
+                .iter()
+                .find(|(entry_key, _)| entry_key == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// The text of a non-empty scalar
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Yaml::Scalar(value) if !value.trim().is_empty() => Some(value.trim()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_list(&self) -> &[Yaml] {
+        match self {
+            Yaml::List(items) => items,
+            _ => &[],
+        }
+    }
+
+    /// Shorthand for `get(key)` followed by `as_str()`
+    pub(crate) fn str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(Yaml::as_str)
+    }
+}
+
+struct Line<'a> {
+    number: usize,
+    indent: usize,
+    text: &'a str,
+}
+
+/// Parse the YAML subset used by CFF files.
+pub(crate) fn parse_yaml(text: &str) -> Result<Yaml, CffError> {
+    let lines: Vec<Line> = text
+        .lines()
+        .enumerate()
+        .filter_map(|(index, raw)| {
+            let content = strip_comment(raw).trim_end();
+            let text = content.trim_start();
+            if text.is_empty() || text == "---" || text.starts_with('%') {
+                return None;
+            }
+            Some(Line {
+                number: index + 1,
+                indent: content.len() - text.len(),
+                text,
+            })
+        })
+        .collect();
+
+    let mut parser = Parser { lines, pos: 0 };
+    let Some(first) = parser.lines.first() else {
+        return Ok(Yaml::Map(Vec::new()));
+    };
+    let root = parser.parse_node(first.indent)?;
+    match parser.lines.get(parser.pos) {
+        Some(line) => Err(syntax_error(line, "unexpected indentation")),
+        None => Ok(root),
+    }
+}
+
+struct Parser<'a> {
+    lines: Vec<Line<'a>>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_node(&mut self, indent: usize) -> Result<Yaml, CffError> {
+        if is_list_item(self.lines[self.pos].text) {
+            self.parse_list(indent)
+        } else {
+            self.parse_map(indent)
+        }
+    }
+
+    fn parse_list(&mut self, indent: usize) -> Result<Yaml, CffError> {
+        let mut items = Vec::new();
+        while let Some(line) = self.lines.get(self.pos)
+            && line.indent == indent
+            && is_list_item(line.text)
+        {
+            let (number, text) = (line.number, line.text);
+            let rest = text[1..].trim_start();
+            if rest.is_empty() {
+                self.pos += 1;
+                match self.lines.get(self.pos) {
+                    Some(next) if next.indent > indent => {
+                        let next_indent = next.indent;
+                        items.push(self.parse_node(next_indent)?);
+                    }
+                    _ => items.push(Yaml::Scalar(String::new())),
+                }
+            } else if split_key(rest).is_some() {
+                // "- key: value" starts a mapping aligned with "key"
+                let item_indent = indent + (text.len() - rest.len());
+                self.lines[self.pos] = Line {
+                    number,
+                    indent: item_indent,
+                    text: rest,
+                };
+                items.push(self.parse_map(item_indent)?);
+            } else {
+                let value = parse_inline(rest);
+                self.pos += 1;
+                items.push(value);
+            }
+        }
+        Ok(Yaml::List(items))
+    }
+
+    fn parse_map(&mut self, indent: usize) -> Result<Yaml, CffError> {
+        let mut entries = Vec::new();
+        while let Some(line) = self.lines.get(self.pos)
+            && line.indent == indent
+            && !is_list_item(line.text)
+        {
+            let (key, value) = split_key(line.text)
+                .ok_or_else(|| syntax_error(line, "expected \"key: value\""))?;
+            let key = unquote(key);
+            self.pos += 1;
+
+            let node = if value.is_empty() {
+                match self.lines.get(self.pos) {
+                    Some(next) if next.indent > indent => {
+                        let next_indent = next.indent;
+                        self.parse_node(next_indent)?
+                    }
+                    // Lists may sit at the same indentation as their key
+                    Some(next) if next.indent == indent && is_list_item(next.text) => {
+                        self.parse_list(indent)?
+                    }
+                    _ => Yaml::Scalar(String::new()),
+                }
+            } else if value.starts_with(['|', '>']) {
+                let separator = if value.starts_with('|') { "\n" } else { " " };
+                let mut block = Vec::new();
+                while let Some(next) = self.lines.get(self.pos)
+                    && next.indent > indent
+                {
+                    block.push(next.text);
+                    self.pos += 1;
+                }
+                Yaml::Scalar(block.join(separator))
+            } else {
+                parse_inline(value)
+            };
+            entries.push((key, node));
+        }
+        Ok(Yaml::Map(entries))
+    }
+}
+
+fn syntax_error(line: &Line, message: &str) -> CffError {
+    CffError::Syntax {
+        line: line.number,
+        message: message.to_string(),
+    }
+}
+
+fn is_list_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Remove a trailing comment: a '#' at the start of the line or after
+/// whitespace, outside of quotes. A backslash escapes the next character
+/// in a double-quoted string.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    let mut escaped = false;
+    for (index, c) in line.char_indices() {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if c == '\\' => escaped = true,
+            Some(open) if c == open => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && previous.is_whitespace() => return &line[..index],
+            None => {}
+        }
+        previous = c;
+    }
+    line
+}
+
+/// Split "key: value" (or "key:") into its parts, ignoring text that
+/// starts with a quote
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    if text.starts_with(['"', '\'', '[', '{']) {
+        return None;
+    }
+    if let Some(key) = text.strip_suffix(':') {
+        return Some((key.trim(), ""));
+    }
+    let (key, value) = text.split_once(": ")?;
+    Some((key.trim(), value.trim()))
+}
+
+/// A scalar or a flow list of scalars
+fn parse_inline(value: &str) -> Yaml {
+    match value
+        .strip_prefix('[')
+        .and_then(|inner| inner.strip_suffix(']'))
+    {
+        Some(inner) => Yaml::List(
+            inner
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| Yaml::Scalar(unquote(item)))
+                .collect(),
+        ),
+        None => Yaml::Scalar(unquote(value)),
+    }
+}
+
+fn unquote(value: &str) -> String {
+    if let Some(inner) = value
+        .strip_prefix('\'')
+        .and_then(|inner| inner.strip_suffix('\''))
+    {
+        return inner.replace("''", "'");
+    }
+    let Some(inner) = value
+        .strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+    else {
+        return value.to_string();
+    };
+
+    let mut unescaped = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    unescaped.push(c);
+                }
+            }
+            Some(other) => unescaped.push(other),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+/// The authors listed in a CFF file.
+///
+/// Persons are read from `given-names`, `name-particle` and
+/// `family-names`. `GenericAuthor` can't mix persons and organizations, so
+/// entities (which only have a `name`) are used only when no person is
+/// listed, in which case the first one becomes the author.
+pub fn authors_from_cff(text: &str) -> Result<Option<GenericAuthor>, CffError> {
+    Ok(cff_authors(&parse_yaml(text)?))
+}
+
+/// Read a CFF file as software.
+///
+/// `title` and `authors` are required. The `repository-code` URL is used
+/// when there is no `url`. The citation ID is the DOI if there is one,
+/// then the URL, then the title. CFF files don't record when they were
+/// read, so the access date is today.
+pub fn software_from_cff(text: &str) -> Result<Software, CffError> {
+    software_from_cff_or(text, None)
+}
+
+/// Like [`software_from_cff`], taking the title, authors, version and URL
+/// from `defaults` when the file leaves them out.
+pub(crate) fn software_from_cff_or(
+    text: &str,
+    defaults: Option<&Software>,
+) -> Result<Software, CffError> {
+    let root = parse_yaml(text)?;
+    let title = root
+        .str("title")
+        .map(Title::new)
+        .or_else(|| defaults.map(|software| software.title.clone()))
+        .ok_or(CffError::MissingField("title".to_string()))?;
+    let author = cff_authors(&root)
+        .or_else(|| defaults.map(|software| software.author.clone()))
+        .ok_or(CffError::MissingField("authors".to_string()))?;
+    let doi = root.str("doi").map(str::to_string);
+    let url = root
+        .str("url")
+        .or_else(|| root.str("repository-code"))
+        .map(str::to_string)
+        .or_else(|| defaults.and_then(|software| software.url.clone()));
+    let version = root
+        .str("version")
+        .map(str::to_string)
+        .or_else(|| defaults.and_then(|software| software.version.clone()));
+
+    Ok(Software {
+        common_data: CommonCitationData {
+            published: root.str("date-released").and_then(PublishDate::parse_iso),
+            ..CommonCitationData::new(
+                doi.clone()
+                    .or_else(|| url.clone())
+                    .unwrap_or(title.to_string()),
+            )
+        },
+        author,
+        title,
+        version,
+        publisher: None,
+        url,
+        doi,
+        accessed: AccessDate::today(),
+    })
+}
+
+/// Top-level keys that [`software_from_cff`] reads
+const READ_KEYS: &[&str] = &[
+    "cff-version",
+    "message",
+    "type",
+    "title",
+    "authors",
+    "doi",
+    "url",
+    "repository-code",
+    "version",
+    "date-released",
+];
+
+/// Like [`software_from_cff`], also reporting what didn't carry over:
+/// top-level fields that aren't read, a `date-released` that isn't a
+/// valid date, and a `type` other than software, with their line numbers.
+pub fn read_cff(text: &str) -> Result<ImportReport, CffError> {
+    let root = parse_yaml(text)?;
+    let software = software_from_cff(text)?;
+    let entry = Some(software.common_data.id.clone());
+
+    let mut warnings = Vec::new();
+    for (key, line) in top_level_keys(text) {
+        let issue = match key {
+            "type" => match root.str("type") {
+                Some(stated) if stated != "software" => ImportIssue::GuessedMediaType {
+                    stated: Some(stated.to_string()),
+                    used: "software".to_string(),
+                },
+                _ => continue,
+            },
+            "date-released" => match root.str("date-released") {
+                Some(date) if software.common_data.published.is_none() => {
+                    ImportIssue::CoercedDate {
+                        original: date.to_string(),
+                        used: None,
+                    }
+                }
+                _ => continue,
+            },
+            key if READ_KEYS.contains(&key) => continue,
+            key => ImportIssue::UnknownField {
+                field: key.to_string(),
+            },
+        };
+        warnings.push(ImportWarning {
+            line: Some(line),
+            entry: entry.clone(),
+            issue,
+        });
+    }
+
+    Ok(ImportReport {
+        citations: vec![Citation::Software(software)],
+        warnings,
+    })
+}
+
+/// The keys of the top-level mapping, with their line numbers
+fn top_level_keys(text: &str) -> Vec<(&str, usize)> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(index, raw)| {
+            let content = strip_comment(raw).trim_end();
+            if content.starts_with(char::is_whitespace) || is_list_item(content) {
+                return None;
+            }
+            split_key(content).map(|(key, _)| (key, index + 1))
+        })
+        .collect()
+}
+
+/// Write software as a CFF file.
+///
+/// CFF only accepts full release dates, so a publish date without a day
+/// is left out.
+pub fn software_to_cff(software: &Software) -> String {
+    let mut lines = vec![
+        format!("cff-version: {}", CFF_VERSION),
+        format!(
+            "message: {}",
+            quote("If you use this software, please cite it as below.")
+        ),
+        "type: software".to_string(),
+        format!("title: {}", quote(&software.title.to_string())),
+    ];
+    if let Some(version) = &software.version {
+        lines.push(format!("version: {}", quote(version)));
+    }
+    if let Some(doi) = &software.doi {
+        lines.push(format!("doi: {}", quote(doi)));
+    }
+    if let Some(url) = &software.url {
+        lines.push(format!("url: {}", quote(url)));
+    }
+    if let Some(PublishDate::YearMonthDay { year, month, day }) = &software.common_data.published {
+        lines.push(format!(
+            "date-released: {:04}-{:02}-{:02}",
+            year,
+            month.number_from_month(),
+            day
+        ));
+    }
+
+    lines.push("authors:".to_string());
+    for contributor in software.author.contributors() {
+        match contributor {
+            Contributor::Person(person) => {
+                lines.push(format!("  - family-names: {}", quote(person.surname())));
+                if let Some(given) = person.given_names() {
+                    lines.push(format!("    given-names: {}", quote(&given)));
+                }
+            }
+            Contributor::Organization { name, abbreviation } => {
+                lines.push(format!("  - name: {}", quote(&name)));
+                if let Some(abbreviation) = abbreviation {
+                    lines.push(format!("    alias: {}", quote(&abbreviation)));
+                }
+            }
+            Contributor::Anonymous => {
+                lines.push(format!("  - name: {}", quote(ANONYMOUS)));
+            }
+            Contributor::Pseudonym { name, .. } => {
+                lines.push(format!("  - alias: {}", quote(&name)));
+            }
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// The entity name of a work signed "Anonymous"
+const ANONYMOUS: &str = "Anonymous";
+
+/// A double-quoted YAML string, with line breaks and other control
+/// characters escaped so that it stays on one line
+pub(crate) fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' | '"' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+pub(crate) fn cff_authors(root: &Yaml) -> Option<GenericAuthor> {
+    let authors = root.get("authors").map(Yaml::as_list).unwrap_or_default();
+
+    // Persons and entities ("name") may be listed in any order. A person
+    // known only by an alias is a pseudonym.
+    let contributors: Vec<Contributor> = authors
+        .iter()
+        .filter_map(|author| match (cff_person(author), author.str("name")) {
+            (Some(person), _) => Some(Contributor::Person(person)),
+            (None, Some(name)) if name.eq_ignore_ascii_case(ANONYMOUS) => {
+                Some(Contributor::Anonymous)
+            }
+            (None, Some(name)) => Some(Contributor::Organization {
+                name: name.to_string(),
+                abbreviation: author.str("alias").map(str::to_string),
+            }),
+            (None, None) => Some(Contributor::Pseudonym {
+                name: author.str("alias")?.to_string(),
+                real_name: None,
+            }),
+        })
+        .collect();
+
+    if contributors
+        .iter()
+        .all(|contributor| matches!(contributor, Contributor::Person(_)))
+    {
+        let persons: Vec<PersonName> = contributors
+            .into_iter()
+            .filter_map(|contributor| match contributor {
+                Contributor::Person(person) => Some(person),
+                _ => None,
+            })
+            .collect();
+        return (!persons.is_empty()).then_some(GenericAuthor::Persons { persons });
+    }
+    match contributors.as_slice() {
+        [Contributor::Organization { name, abbreviation }] => Some(GenericAuthor::Organization {
+            name: name.clone(),
+            abbreviation: abbreviation.clone(),
+        }),
+        [Contributor::Anonymous] => Some(GenericAuthor::Anonymous),
+        [Contributor::Pseudonym { name, real_name }] => Some(GenericAuthor::Pseudonym {
+            name: name.clone(),
+            real_name: real_name.clone(),
+        }),
+        _ => Some(GenericAuthor::Mixed { contributors }),
+    }
+}
+
+fn cff_person(author: &Yaml) -> Option<PersonName> {
+    let family = author.str("family-names")?;
+    let family = match author.str("name-particle") {
+        Some(particle) => format!("{} {}", particle, family),
+        None => family.to_string(),
+    };
+    match author.str("given-names") {
+        Some(given) => PersonName::parse(&format!("{}, {}", family, given)).ok(),
+        None => PersonName::from_last(&family).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::{Contributor, GenericAuthor, PersonName},
+            date::PublishDate,
+            media::software::Software,
+        },
+        cff::{CffError, Yaml, authors_from_cff, parse_yaml, quote, read_cff},
+        import::ImportIssue,
+    };
+
+    const CFF: &str = r#"# This CITATION.cff file was generated with cffinit.
+cff-version: 1.2.0
+title: "citation_station: a bibliography manager"
+message: 'If you use this software, please cite it as below.'
+type: software
+authors:
+  - given-names: Jane Q
+    family-names: Smith
+    orcid: "https://orcid.org/0000-0000-0000-0000"
+  - given-names: Ludwig
+    name-particle: van
+    family-names: Beethoven
+  - name: "The Citation Station Project"
+keywords: [citations, bibliography]
+abstract: >-
+  Formats references
+  in several styles.
+"#;
+
+    #[test]
+    fn test_parse_yaml() {
+        let root = parse_yaml(CFF).unwrap();
+
+        assert_eq!(root.str("cff-version"), Some("1.2.0"));
+        assert_eq!(
+            root.str("title"),
+            Some("citation_station: a bibliography manager")
+        );
+        assert_eq!(
+            root.str("message"),
+            Some("If you use this software, please cite it as below.")
+        );
+        assert_eq!(root.get("authors").unwrap().as_list().len(), 3);
+        assert_eq!(
+            root.get("keywords"),
+            Some(&Yaml::List(vec![
+                Yaml::Scalar("citations".to_string()),
+                Yaml::Scalar("bibliography".to_string()),
+            ]))
+        );
+        assert_eq!(
+            root.str("abstract"),
+            Some("Formats references in several styles.")
+        );
+    }
+
+    #[test]
+    fn test_parse_yaml_list_at_key_indentation() {
+        let root = parse_yaml("authors:\n- name: ACME\n- name: Initech\ntitle: x\n").unwrap();
+
+        assert_eq!(root.get("authors").unwrap().as_list().len(), 2);
+        assert_eq!(root.str("title"), Some("x"));
+    }
+
+    #[test]
+    fn test_parse_yaml_reads_quoted_strings() {
+        let title = "The \"best\" tool: #1 in tests\nwith a second line";
+        let root = parse_yaml(&format!("title: {} # a comment\n", quote(title))).unwrap();
+
+        assert_eq!(root.str("title"), Some(title));
+    }
+
+    #[test]
+    fn test_parse_yaml_reports_bad_indentation() {
+        assert_eq!(
+            parse_yaml("title: x\n    nested: y\n"),
+            Err(CffError::Syntax {
+                line: 2,
+                message: "unexpected indentation".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_authors_from_cff() {
+        assert_eq!(
+            authors_from_cff(CFF).unwrap(),
+            Some(GenericAuthor::Mixed {
+                contributors: vec![
+                    Contributor::Person(
+                        PersonName::from_first_middle_last("Jane", "Q", "Smith").unwrap()
+                    ),
+                    Contributor::Person(
+                        PersonName::from_first_last("Ludwig", "van Beethoven").unwrap()
+                    ),
+                    Contributor::Organization {
+                        name: "The Citation Station Project".to_string(),
+                        abbreviation: None,
+                    },
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_authors_from_cff_entities_only() {
+        assert_eq!(
+            authors_from_cff("authors:\n  - name: ACME Corp.\n").unwrap(),
+            Some(GenericAuthor::Organization {
+                name: "ACME Corp.".to_string(),
+                abbreviation: None,
+            })
+        );
+        assert_eq!(authors_from_cff("title: x\n").unwrap(), None);
+    }
+
+    #[test]
+    fn test_authors_from_cff_anonymous_and_alias() {
+        assert_eq!(
+            authors_from_cff("authors:\n  - name: anonymous\n").unwrap(),
+            Some(GenericAuthor::Anonymous)
+        );
+        assert_eq!(
+            authors_from_cff("authors:\n  - alias: octocat\n").unwrap(),
+            Some(GenericAuthor::Pseudonym {
+                name: "octocat".to_string(),
+                real_name: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_authors_from_cff_persons_only() {
+        assert_eq!(
+            authors_from_cff("authors:\n  - family-names: Smith\n    given-names: Jane\n").unwrap(),
+            Some(GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()]
+            })
+        );
+    }
+
+    #[test]
+    fn test_software_from_cff() {
+        let text = "cff-version: 1.2.0\n\
+                    title: citation_station\n\
+                    version: 0.3.1\n\
+                    date-released: 2025-06-02\n\
+                    repository-code: \"https://github.com/example/citation_station\"\n\
+                    authors:\n  - family-names: Smith\n    given-names: Jane\n";
+        let software = Software::from_cff(text).unwrap();
+
+        assert_eq!(software.title, "citation_station");
+        assert_eq!(software.version.as_deref(), Some("0.3.1"));
+        assert_eq!(
+            software.common_data.published,
+            Some(PublishDate::from_year_month_day(2025, Month::June, 2).unwrap())
+        );
+        assert_eq!(
+            software.common_data.id,
+            "https://github.com/example/citation_station"
+        );
+        assert_eq!(software.url, Some(software.common_data.id.clone()));
+    }
+
+    #[test]
+    fn test_software_from_cff_requires_title_and_authors() {
+        assert_eq!(
+            Software::from_cff("authors:\n  - name: ACME\n"),
+            Err(CffError::MissingField("title".to_string()))
+        );
+        assert_eq!(
+            Software::from_cff("title: x\n"),
+            Err(CffError::MissingField("authors".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_read_cff_reports_dropped_fields() {
+        let cff = format!(
+            "{}date-released: 2024-13-01\n",
+            CFF.replace("type: software", "type: dataset")
+        );
+        let report = read_cff(&cff).unwrap();
+
+        assert_eq!(report.citations.len(), 1);
+        let issues: Vec<(Option<usize>, &ImportIssue)> = report
+            .warnings
+            .iter()
+            .map(|warning| (warning.line, &warning.issue))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (
+                    Some(5),
+                    &ImportIssue::GuessedMediaType {
+                        stated: Some("dataset".to_string()),
+                        used: "software".to_string(),
+                    }
+                ),
+                (
+                    Some(14),
+                    &ImportIssue::UnknownField {
+                        field: "keywords".to_string(),
+                    }
+                ),
+                (
+                    Some(15),
+                    &ImportIssue::UnknownField {
+                        field: "abstract".to_string(),
+                    }
+                ),
+                (
+                    Some(18),
+                    &ImportIssue::CoercedDate {
+                        original: "2024-13-01".to_string(),
+                        used: None,
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_software_cff_round_trip() {
+        let mut software = Software::from_cff(CFF).unwrap();
+        software.title = "A \"quoted\" title".into();
+        software.version = Some("1.0.0".to_string());
+        software.doi = Some("10.5281/zenodo.1234".to_string());
+        software.common_data.published =
+            Some(PublishDate::from_year_month_day(2024, Month::January, 9).unwrap());
+
+        let written = software.to_cff();
+        assert!(written.contains("date-released: 2024-01-09\n"));
+        assert!(written.contains("  - family-names: \"Smith\"\n    given-names: \"Jane Q\"\n"));
+
+        let read = Software::from_cff(&written).unwrap();
+        assert_eq!(read.title, software.title);
+        assert_eq!(read.author, software.author);
+        assert_eq!(read.version, software.version);
+        assert_eq!(read.doi, software.doi);
+        assert_eq!(read.common_data.published, software.common_data.published);
+    }
+}
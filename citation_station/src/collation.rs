@@ -0,0 +1,455 @@
+//! Alphabetical ordering of names and titles, following the conventions
+//! of a language.
+//!
+//! The order is a simplified form of the Unicode Collation Algorithm:
+//! letters are compared without regard to case or accents first, with the
+//! language deciding which accented letters are letters of their own, and
+//! punctuation other than spaces is ignored. Ties are broken by accents,
+//! then by case.
+//!
+//! Names in other scripts sort after Latin ones by code point, unless
+//! [`SortOptions::transliterate`] is set: then Cyrillic and Greek text is
+//! sorted by its Latin transliteration, as style guides such as APA ask.
+
+use std::{borrow::Cow, cmp::Ordering};
+
+use serde::{Deserialize, Serialize};
+
+/// The language whose alphabetical order to follow.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Collation {
+    /// The Unicode default: accented letters sort with their base letter,
+    /// so "Ö" sorts as "O"
+    #[default]
+    Root,
+    /// German dictionary order (DIN 5007-1): umlauts sort with their base
+    /// letter and "ß" as "ss"
+    German,
+    /// Swedish: "å", "ä" and "ö" are letters of their own after "z"
+    Swedish,
+    /// Danish and Norwegian: "æ", "ø" and "å" are letters of their own
+    /// after "z"
+    Danish,
+    /// Spanish: "ñ" is a letter of its own after "n"
+    Spanish,
+}
+
+/// A key that orders strings according to a [`Collation`]. See
+/// [`Collation::sort_key`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SortKey {
+    /// Letter weights, ignoring case and accents
+    primary: Vec<u32>,
+    /// The letters with their accents, ignoring case
+    secondary: Vec<char>,
+    /// The original text
+    tertiary: String,
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.primary
+            .cmp(&other.primary)
+            .then_with(|| self.secondary.cmp(&other.secondary))
+            .then_with(|| self.tertiary.cmp(&other.tertiary))
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// How to order a reference list alphabetically.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SortOptions {
+    pub collation: Collation,
+    /// Sort Cyrillic and Greek text as if it were transliterated to the
+    /// Latin alphabet, e.g. "Чехов" as "Chekhov"
+    #[serde(default)]
+    pub transliterate: bool,
+}
+
+impl SortOptions {
+    /// The key to sort `text` by
+    pub fn sort_key(&self, text: &str) -> SortKey {
+        if !self.transliterate {
+            return self.collation.sort_key(text);
+        }
+        let mut key = self.collation.sort_key(&transliterate(text));
+        // Tell names with the same transliteration apart by the original
+        key.tertiary = text.to_string();
+        key
+    }
+
+    /// The key to sort the title `title` by, ignoring a leading article
+    pub fn title_sort_key(&self, title: &str) -> SortKey {
+        self.sort_key(self.collation.strip_leading_article(title))
+    }
+
+    /// The letter `text` is filed under in an index, e.g. "A" for "Ávila"
+    /// but "Å" for "Åberg" in Swedish, where it sorts after "Z"
+    pub fn initial(&self, text: &str) -> Option<String> {
+        if self.transliterate {
+            self.collation.initial(&transliterate(text))
+        } else {
+            self.collation.initial(text)
+        }
+    }
+}
+
+impl From<Collation> for SortOptions {
+    fn from(collation: Collation) -> Self {
+        Self {
+            collation,
+            transliterate: false,
+        }
+    }
+}
+
+/// `text` with Cyrillic and Greek letters transliterated to the Latin
+/// alphabet, following BGN/PCGN for Cyrillic and ELOT 743 for Greek.
+/// Other characters are kept.
+pub fn transliterate(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|c| latin_letters(c).is_some()) {
+        return Cow::Borrowed(text);
+    }
+    let mut latin = String::with_capacity(text.len());
+    let mut previous = None;
+    for c in text.chars() {
+        let lower = c.to_lowercase().next();
+        // The Greek digraph "ου" is "ou", not "oy"
+        let letters = match (previous, lower) {
+            (Some('ο' | 'ό'), Some('υ' | 'ύ')) => Some("u"),
+            _ => latin_letters(c),
+        };
+        previous = lower;
+        match letters {
+            Some(letters) if c.is_uppercase() => {
+                let mut letters = letters.chars();
+                latin.extend(letters.next().into_iter().flat_map(char::to_uppercase));
+                latin.extend(letters);
+            }
+            Some(letters) => latin.push_str(letters),
+            None => latin.push(c),
+        }
+    }
+    Cow::Owned(latin)
+}
+
+/// The Latin transliteration of a Cyrillic or Greek letter
+fn latin_letters(c: char) -> Option<&'static str> {
+    let lower = c.to_lowercase().next()?;
+    let letters = match lower {
+        // Cyrillic
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'ё' => "e",
+        'є' => "ye",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'ї' => "yi",
+        'й' | 'ы' => "y",
+        'ј' => "j",
+        'к' => "k",
+        'л' => "l",
+        'љ' => "lj",
+        'м' => "m",
+        'н' => "n",
+        'њ' => "nj",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'ћ' => "c",
+        'ђ' => "dj",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'џ' => "dz",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'э' => "e",
+        'ю' => "yu",
+        'я' => "ya",
+        // Greek
+        'α' | 'ά' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' | 'έ' => "e",
+        'ζ' => "z",
+        'η' | 'ή' => "i",
+        'θ' => "th",
+        'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ό' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' | 'ώ' => "o",
+        _ => return None,
+    };
+    Some(letters)
+}
+
+/// Articles ignored at the start of titles whatever the collation, since
+/// most titles are in English
+const ENGLISH_ARTICLES: [&str; 3] = ["the", "an", "a"];
+
+impl Collation {
+    /// The key to sort `text` by
+    pub fn sort_key(self, text: &str) -> SortKey {
+        let mut primary = Vec::new();
+        let mut secondary = Vec::new();
+        for c in text.chars().flat_map(char::to_lowercase) {
+            if c.is_whitespace() {
+                // Collapse runs of spaces; a space sorts before any letter
+                if primary.last().is_some_and(|weight| *weight != 0) {
+                    primary.push(0);
+                }
+                continue;
+            }
+            if !c.is_alphanumeric() {
+                continue;
+            }
+            secondary.push(c);
+            match (self.tailored_weight(c), fold(c)) {
+                (Some(weight), _) => primary.push(weight),
+                (None, Some(base)) => primary.extend(base.chars().map(weight)),
+                (None, None) => primary.push(weight(c)),
+            }
+        }
+        if primary.last() == Some(&0) {
+            primary.pop();
+        }
+        SortKey {
+            primary,
+            secondary,
+            tertiary: text.to_string(),
+        }
+    }
+
+    /// The key to sort the title `title` by, ignoring a leading article
+    /// such as "The" or "A"
+    pub fn title_sort_key(self, title: &str) -> SortKey {
+        self.sort_key(self.strip_leading_article(title))
+    }
+
+    /// The letter `text` is filed under, uppercase and without accents
+    /// unless the collation sorts the accented letter separately
+    pub fn initial(self, text: &str) -> Option<String> {
+        let c = text
+            .chars()
+            .flat_map(char::to_lowercase)
+            .find(|c| c.is_alphanumeric())?;
+        let letter = match (self.tailored_weight(c), fold(c)) {
+            (None, Some(base)) => base.chars().next()?,
+            _ => c,
+        };
+        Some(letter.to_uppercase().collect())
+    }
+
+    /// Compare `a` and `b`
+    pub fn compare(self, a: &str, b: &str) -> Ordering {
+        self.sort_key(a).cmp(&self.sort_key(b))
+    }
+
+    /// `title` without a leading article, e.g. "The Art of Computer
+    /// Programming" becomes "Art of Computer Programming"
+    pub fn strip_leading_article(self, title: &str) -> &str {
+        let trimmed = title.trim_start();
+        for article in ENGLISH_ARTICLES.iter().chain(self.articles()) {
+            let Some(prefix) = trimmed.get(..article.len()) else {
+                continue;
+            };
+            if !prefix.eq_ignore_ascii_case(article) {
+                continue;
+            }
+            let rest = &trimmed[article.len()..];
+            if rest.starts_with(char::is_whitespace) {
+                return rest.trim_start();
+            }
+        }
+        trimmed
+    }
+
+    /// Leading articles of the collation's language, besides the English
+    /// ones
+    fn articles(self) -> &'static [&'static str] {
+        match self {
+            Collation::Root => &[],
+            Collation::German => &["der", "die", "das", "ein", "eine"],
+            // Swedish and Danish articles are mostly suffixes
+            Collation::Swedish => &["en", "ett"],
+            Collation::Danish => &["en", "et"],
+            Collation::Spanish => &["el", "la", "los", "las", "un", "una"],
+        }
+    }
+
+    /// The weight of letters that the language sorts as letters of their
+    /// own rather than with their base letter. They fit between the
+    /// weights of consecutive code points.
+    fn tailored_weight(self, c: char) -> Option<u32> {
+        match (self, c) {
+            (Collation::Swedish, 'å') => Some(weight('z') + 1),
+            (Collation::Swedish, 'ä' | 'æ') => Some(weight('z') + 2),
+            (Collation::Swedish, 'ö' | 'ø') => Some(weight('z') + 3),
+            (Collation::Danish, 'æ' | 'ä') => Some(weight('z') + 1),
+            (Collation::Danish, 'ø' | 'ö') => Some(weight('z') + 2),
+            (Collation::Danish, 'å') => Some(weight('z') + 3),
+            (Collation::Spanish, 'ñ') => Some(weight('n') + 1),
+            _ => None,
+        }
+    }
+}
+
+/// The primary weight of a letter without accents
+fn weight(c: char) -> u32 {
+    u32::from(c) * 4
+}
+
+/// The base letters of a lowercase Latin letter with accents, or `None`
+/// if `c` has no accents
+pub(crate) fn fold(c: char) -> Option<&'static str> {
+    let base = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collation::{Collation, SortOptions, transliterate};
+
+    fn sorted(collation: Collation, words: &[&str]) -> Vec<String> {
+        let mut words: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+        words.sort_by_cached_key(|word| collation.sort_key(word));
+        words
+    }
+
+    #[test]
+    fn test_umlauts_by_locale() {
+        let names = ["Zimmer", "Öberg", "Olsen", "Ödegaard", "Ostrom"];
+
+        assert_eq!(
+            sorted(Collation::German, &names),
+            ["Öberg", "Ödegaard", "Olsen", "Ostrom", "Zimmer"]
+        );
+        assert_eq!(
+            sorted(Collation::Swedish, &names),
+            ["Olsen", "Ostrom", "Zimmer", "Öberg", "Ödegaard"]
+        );
+    }
+
+    #[test]
+    fn test_accents_and_case_break_ties() {
+        assert_eq!(
+            sorted(Collation::Root, &["resume", "Résumé", "résumé", "Resume"]),
+            ["Resume", "resume", "Résumé", "résumé"]
+        );
+        assert_eq!(
+            sorted(Collation::Spanish, &["Nuñez", "Nuzzo", "Nunes"]),
+            ["Nunes", "Nuñez", "Nuzzo"]
+        );
+        assert_eq!(
+            sorted(Collation::Root, &["O'Brien", "Obama", "Brown", "Browning"]),
+            ["Brown", "Browning", "Obama", "O'Brien"]
+        );
+    }
+
+    #[test]
+    fn test_transliterated_sort() {
+        let names = ["Zweig", "Чехов", "Abramov", "Παπαδόπουλος", "Chen"];
+        let sorted = |options: SortOptions| {
+            let mut names = names.to_vec();
+            names.sort_by_cached_key(|name| options.sort_key(name));
+            names
+        };
+
+        assert_eq!(
+            sorted(Collation::Root.into()),
+            ["Abramov", "Chen", "Zweig", "Παπαδόπουλος", "Чехов"]
+        );
+        assert_eq!(
+            sorted(SortOptions {
+                collation: Collation::Root,
+                transliterate: true,
+            }),
+            ["Abramov", "Chekhov", "Chen", "Papadopoulos", "Zweig"].map(|latin| names[names
+                .iter()
+                .position(|name| transliterate(name) == latin)
+                .unwrap()])
+        );
+        assert_eq!(transliterate("Щукин"), "Shchukin");
+        assert_eq!(transliterate("Ελύτης"), "Elytis");
+        assert_eq!(transliterate("Παπαδόπουλος"), "Papadopoulos");
+    }
+
+    #[test]
+    fn test_initial() {
+        assert_eq!(Collation::Root.initial("Ávila"), Some("A".to_string()));
+        assert_eq!(Collation::Swedish.initial("Åberg"), Some("Å".to_string()));
+        assert_eq!(Collation::Root.initial("(2001)"), Some("2".to_string()));
+        assert_eq!(Collation::Root.initial(" -- "), None);
+    }
+
+    #[test]
+    fn test_strip_leading_article() {
+        let collation = Collation::German;
+
+        assert_eq!(
+            collation.strip_leading_article("The Art of Programming"),
+            "Art of Programming"
+        );
+        assert_eq!(
+            collation.strip_leading_article("Die Blechtrommel"),
+            "Blechtrommel"
+        );
+        assert_eq!(collation.strip_leading_article("Anatomy"), "Anatomy");
+        assert_eq!(collation.strip_leading_article("A"), "A");
+        assert!(collation.title_sort_key("The Zoo") > collation.title_sort_key("A Yak"));
+    }
+}
@@ -0,0 +1,295 @@
+//! Reading bibliographies kept in spreadsheets, or exported as CSV by
+//! reference managers such as Zotero.
+//!
+//! The first row names the columns. Columns named after BibTeX fields
+//! ("title", "author", "journal", "year", ...) are read as those fields,
+//! as are the column names of Zotero's CSV export ("Item Type",
+//! "Publication Title", "Manual Tags", ...). Fields are separated by
+//! commas, or by semicolons or tabs if the header has no commas, and may
+//! be quoted as described in RFC 4180.
+//!
+//! Format reference: https://www.rfc-editor.org/rfc/rfc4180
+
+use crate::{
+    api::citation::Citation,
+    bibtex::{ReadEntry, citation_from_entry},
+    import::{ImportIssue, ImportReport, ImportWarning},
+};
+
+/// Column names other than BibTeX field names, and the field each holds
+const COLUMNS: [(&str, &str); 17] = [
+    ("item type", "type"),
+    ("entry type", "type"),
+    ("entrytype", "type"),
+    ("citation key", "key"),
+    ("citekey", "key"),
+    ("id", "key"),
+    ("authors", "author"),
+    ("publication year", "year"),
+    ("publication title", "journal"),
+    ("journal title", "journal"),
+    ("website title", "organization"),
+    ("issue", "number"),
+    ("access date", "urldate"),
+    ("manual tags", "keywords"),
+    ("tags", "keywords"),
+    ("edition number", "edition"),
+    ("place", "address"),
+];
+
+/// Zotero item types and the BibTeX entry types they are read as. Other
+/// types are used as they are, in lowercase.
+const ITEM_TYPES: [(&str, &str); 11] = [
+    ("journalarticle", "article"),
+    ("magazinearticle", "article"),
+    ("newspaperarticle", "article"),
+    ("webpage", "online"),
+    ("blogpost", "online"),
+    ("booksection", "inbook"),
+    ("conferencepaper", "inproceedings"),
+    ("thesis", "phdthesis"),
+    ("report", "techreport"),
+    ("computerprogram", "software"),
+    ("document", "misc"),
+];
+
+/// Read the rows of a CSV file with a header row.
+///
+/// Rows are turned into citations as [`read_bibtex`] turns entries into
+/// them. Without a type column, rows with a journal are read as journal
+/// articles, rows with a URL as web pages and the rest as books, which is
+/// reported. Authors and tags may be separated by semicolons, as Zotero
+/// does. Columns that aren't read, dates that can't be read and rows that
+/// can't be parsed are reported with the line they start on.
+///
+/// [`read_bibtex`]: crate::bibtex::read_bibtex
+pub fn read_csv(text: &str) -> ImportReport {
+    let mut report = ImportReport::default();
+    let text = text.trim_start_matches('\u{FEFF}');
+    let header = text.lines().next().unwrap_or_default();
+    let delimiter = [',', ';', '\t']
+        .into_iter()
+        .find(|c| header.contains(*c))
+        .unwrap_or(',');
+    let (rows, error) = rows(text, delimiter);
+    let mut rows = rows.into_iter();
+    let Some((_, header)) = rows.next() else {
+        return report;
+    };
+    let columns: Vec<String> = header
+        .iter()
+        .map(|name| {
+            let name = name.trim().to_lowercase();
+            COLUMNS
+                .iter()
+                .find(|(column, _)| *column == name)
+                .map_or(name, |(_, field)| field.to_string())
+        })
+        .collect();
+
+    for (line, row) in rows {
+        if row.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        let mut entry = ReadEntry {
+            kind: None,
+            key: None,
+            line,
+            fields: Vec::new(),
+        };
+        for (name, value) in columns.iter().zip(&row) {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match name.as_str() {
+                "type" => {
+                    let kind = value.to_lowercase();
+                    entry.kind = Some(
+                        ITEM_TYPES
+                            .iter()
+                            .find(|(item_type, _)| *item_type == kind)
+                            .map_or(kind, |(_, bibtex)| bibtex.to_string()),
+                    );
+                }
+                "key" => entry.key = Some(value.to_string()),
+                "author" => entry
+                    .fields
+                    .push((name.clone(), joined(value, " and "), line)),
+                "keywords" => entry.fields.push((name.clone(), joined(value, ", "), line)),
+                _ => entry.fields.push((name.clone(), value.to_string(), line)),
+            }
+        }
+        citation_from_entry(&entry, &mut report);
+    }
+    if let Some(line) = error {
+        report.warnings.push(ImportWarning {
+            line: Some(line),
+            entry: None,
+            issue: ImportIssue::SkippedEntry {
+                reason: "quoted field is not closed".to_string(),
+            },
+        });
+    }
+    report
+}
+
+/// The citations in a CSV file, see [`read_csv`]
+pub fn citations_from_csv(text: &str) -> Vec<Citation> {
+    read_csv(text).citations
+}
+
+/// A list separated by semicolons, separated by `separator` instead
+fn joined(list: &str, separator: &str) -> String {
+    list.split(';')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// The rows of a CSV file with the line each starts on, and the line of
+/// a quoted field that isn't closed, if any. The row it starts is dropped.
+fn rows(text: &str, delimiter: char) -> (Vec<(usize, Vec<String>)>, Option<usize>) {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut quoted_since = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+        match c {
+            '"' if quoted_since.is_some() => {
+                if chars.next_if_eq(&'"').is_some() {
+                    field.push('"');
+                } else {
+                    quoted_since = None;
+                }
+            }
+            _ if quoted_since.is_some() => field.push(c),
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted_since = Some(line);
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push((row_line, std::mem::take(&mut row)));
+                row_line = line;
+            }
+            _ if c == delimiter => row.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if quoted_since.is_none() && (!field.is_empty() || !row.is_empty()) {
+        row.push(field);
+        rows.push((row_line, row));
+    }
+    (rows, quoted_since)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{citation::Citation, date::PublishDate},
+        csv::{read_csv, rows},
+        import::ImportIssue,
+    };
+
+    const ZOTERO: &str = "\u{FEFF}\"Key\",\"Item Type\",\"Publication Year\",\"Author\",\"Title\",\"Publication Title\",\"Url\",\"Access Date\",\"Manual Tags\",\"Extra\"
+\"VASWANI17\",\"journalArticle\",\"2017\",\"Vaswani, Ashish; Shazeer, Noam\",\"Attention Is All You Need\",\"Advances in Neural Information Processing Systems\",\"\",\"\",\"transformers; attention\",\"\"
+\"RUSTBLOG\",\"webpage\",\"\",\"\",\"The Rust Blog\",\"\",\"https://blog.rust-lang.org\",\"2024-05-01 10:30:00\",\"\",\"Quoted \"\"as is\"\"\"
+";
+
+    #[test]
+    fn test_read_zotero_export() {
+        let report = read_csv(ZOTERO);
+        let [Citation::JournalArticle(article), Citation::WebPage(page)] =
+            report.citations.as_slice()
+        else {
+            panic!("expected an article and a web page");
+        };
+        assert_eq!(article.common_data.id, "VASWANI17");
+        assert_eq!(
+            article.author.as_ieee_string().unwrap(),
+            "A. Vaswani and N. Shazeer"
+        );
+        assert_eq!(
+            article.common_data.published,
+            Some(PublishDate::from_year(2017))
+        );
+        assert_eq!(article.common_data.tags, ["transformers", "attention"]);
+        assert_eq!(page.accessed.date().to_string(), "2024-05-01");
+
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].line, Some(3));
+        assert_eq!(
+            report.warnings[0].issue,
+            ImportIssue::UnknownField {
+                field: "extra".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_spreadsheet() {
+        let report = read_csv(
+            "title;author;year;month;journal\n\
+             Attention Is All You Need;Vaswani, Ashish;2017;dec;NeurIPS\n\
+             \n\
+             Rust in Action;McNamara, Tim;2021;;\n",
+        );
+
+        assert_eq!(report.citations.len(), 2);
+        assert_eq!(
+            report.citations[0].published(),
+            Some(PublishDate::from_year_month(2017, Month::December))
+        );
+        assert!(matches!(report.citations[1], Citation::Book(_)));
+        let issues: Vec<_> = report
+            .warnings
+            .iter()
+            .map(|warning| (warning.line, &warning.issue))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (
+                    Some(2),
+                    &ImportIssue::GuessedMediaType {
+                        stated: None,
+                        used: "article".to_string()
+                    }
+                ),
+                (
+                    Some(4),
+                    &ImportIssue::GuessedMediaType {
+                        stated: None,
+                        used: "book".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rows() {
+        let (read, error) = rows("a,\"b, \"\"c\"\"\nd\"\r\ne,f", ',');
+
+        assert_eq!(
+            read,
+            [
+                (1, vec!["a".to_string(), "b, \"c\"\nd".to_string()]),
+                (3, vec!["e".to_string(), "f".to_string()]),
+            ]
+        );
+        assert_eq!(error, None);
+        assert_eq!(rows("a\n\"b\nc", ',').1, Some(2));
+    }
+}
@@ -0,0 +1,297 @@
+//! Finding entries that are probably the same work, imported under
+//! different IDs.
+//!
+//! How alike two entries are is decided by a [`DedupStrategy`]. The
+//! built-in strategies compare DOIs, titles and years, or authors, and
+//! [`Weighted`] combines several of them, so a bibliography can be as
+//! strict or as lenient about merging as its users want.
+
+use std::collections::BTreeSet;
+
+use crate::{
+    api::{author::Contributor, citation::Citation, citation_id::CitationId},
+    similarity::{normalize, title_similarity},
+};
+
+/// A way of scoring how likely two entries are to be the same work.
+pub trait DedupStrategy {
+    /// A score from 0.0 (different works) to 1.0 (the same work), or
+    /// `None` if the entries don't have what the strategy compares, e.g.
+    /// no DOI
+    fn score(&self, a: &Citation, b: &Citation) -> Option<f64>;
+}
+
+impl<F> DedupStrategy for F
+where
+    F: Fn(&Citation, &Citation) -> Option<f64>,
+{
+    fn score(&self, a: &Citation, b: &Citation) -> Option<f64> {
+        self(a, b)
+    }
+}
+
+/// Entries with the same DOI, compared without case or resolver prefix.
+/// Entries with different DOIs score 0.0.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ExactDoi;
+
+impl DedupStrategy for ExactDoi {
+    fn score(&self, a: &Citation, b: &Citation) -> Option<f64> {
+        let a = CitationId::from_doi(&a.doi()?)?;
+        let b = CitationId::from_doi(&b.doi()?)?;
+        Some(if a == b { 1.0 } else { 0.0 })
+    }
+}
+
+/// Entries with similar titles (see [`title_similarity`]) published at
+/// most `year_tolerance` years apart. Entries further apart score 0.0;
+/// entries without a year are compared by title alone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuzzyTitleYear {
+    /// How many years apart the same work may be dated, e.g. a preprint
+    /// and its journal version
+    pub year_tolerance: i32,
+}
+
+impl Default for FuzzyTitleYear {
+    fn default() -> Self {
+        Self { year_tolerance: 1 }
+    }
+}
+
+impl DedupStrategy for FuzzyTitleYear {
+    fn score(&self, a: &Citation, b: &Citation) -> Option<f64> {
+        let (title_a, title_b) = (a.title(), b.title());
+        if title_a.trim().is_empty() || title_b.trim().is_empty() {
+            return None;
+        }
+        if let (Some(year_a), Some(year_b)) = (a.published(), b.published())
+            && (year_a.year() - year_b.year()).abs() > self.year_tolerance
+        {
+            return Some(0.0);
+        }
+        Some(title_similarity(&title_a, &title_b))
+    }
+}
+
+/// Entries crediting the same people or organizations: the share of the
+/// shorter author list also in the other, compared by surname or name.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AuthorOverlap;
+
+impl DedupStrategy for AuthorOverlap {
+    fn score(&self, a: &Citation, b: &Citation) -> Option<f64> {
+        let (a, b) = (author_names(a), author_names(b));
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+        let shared = a.intersection(&b).count();
+        Some(shared as f64 / a.len().min(b.len()) as f64)
+    }
+}
+
+fn author_names(citation: &Citation) -> BTreeSet<String> {
+    citation
+        .contributors()
+        .into_iter()
+        .filter_map(|contributor| match contributor {
+            Contributor::Person(person) => Some(normalize(person.surname())),
+            Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => {
+                Some(normalize(&name))
+            }
+            Contributor::Anonymous => None,
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Several strategies combined: the weighted mean of the scores of the
+/// strategies that can compare the entries.
+///
+/// The default weighs [`ExactDoi`] 3, [`FuzzyTitleYear`] 2 and
+/// [`AuthorOverlap`] 1.
+pub struct Weighted {
+    strategies: Vec<(Box<dyn DedupStrategy + Send + Sync>, f64)>,
+}
+
+impl Weighted {
+    /// No strategies, to be added with [`Weighted::with`]
+    pub fn new() -> Self {
+        Self {
+            strategies: Vec::new(),
+        }
+    }
+
+    /// Add `strategy` with `weight`. A weight of zero or less is ignored.
+    pub fn with(
+        mut self,
+        strategy: impl DedupStrategy + Send + Sync + 'static,
+        weight: f64,
+    ) -> Self {
+        if weight > 0.0 {
+            self.strategies.push((Box::new(strategy), weight));
+        }
+        self
+    }
+}
+
+impl Default for Weighted {
+    fn default() -> Self {
+        Self::new()
+            .with(ExactDoi, 3.0)
+            .with(FuzzyTitleYear::default(), 2.0)
+            .with(AuthorOverlap, 1.0)
+    }
+}
+
+impl DedupStrategy for Weighted {
+    fn score(&self, a: &Citation, b: &Citation) -> Option<f64> {
+        let mut total = 0.0;
+        let mut weights = 0.0;
+        for (strategy, weight) in &self.strategies {
+            if let Some(score) = strategy.score(a, b) {
+                total += score * weight;
+                weights += weight;
+            }
+        }
+        (weights > 0.0).then(|| total / weights)
+    }
+}
+
+/// Two entries that are probably the same work.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicatePair {
+    /// The ID of the entry that comes first in the bibliography
+    pub first: String,
+    pub second: String,
+    pub score: f64,
+}
+
+/// Every pair of entries that `strategy` scores at least `threshold`,
+/// most likely duplicates first.
+pub fn find_duplicates(
+    citations: &[Citation],
+    strategy: &(impl DedupStrategy + ?Sized),
+    threshold: f64,
+) -> Vec<DuplicatePair> {
+    let mut pairs = Vec::new();
+    for (i, a) in citations.iter().enumerate() {
+        for b in &citations[i + 1..] {
+            if let Some(score) = strategy.score(a, b)
+                && score >= threshold
+            {
+                pairs.push(DuplicatePair {
+                    first: a.id(),
+                    second: b.id(),
+                    score,
+                });
+            }
+        }
+    }
+    pairs.sort_by(|a, b| b.score.total_cmp(&a.score));
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+        },
+        dedup::{
+            AuthorOverlap, DedupStrategy, ExactDoi, FuzzyTitleYear, Weighted, find_duplicates,
+        },
+    };
+
+    fn article(id: &str, title: &str, year: i32, surnames: &[&str], doi: Option<&str>) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(year)),
+                ..CommonCitationData::new(id)
+            },
+            author: AcademicAuthor::Persons {
+                persons: surnames
+                    .iter()
+                    .map(|surname| PersonName::from_last(surname).unwrap())
+                    .collect(),
+            },
+            title: title.into(),
+            journal: "Journal".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: doi.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn test_strategies() {
+        let a = article(
+            "a",
+            "Deep Residual Learning",
+            2015,
+            &["He", "Zhang"],
+            Some("10.1000/X"),
+        );
+        let b = article(
+            "b",
+            "Deep residual learning.",
+            2016,
+            &["He"],
+            Some("https://doi.org/10.1000/x"),
+        );
+        let c = article("c", "Deep Residual Learning", 2019, &["Müller"], None);
+
+        assert_eq!(ExactDoi.score(&a, &b), Some(1.0));
+        assert_eq!(ExactDoi.score(&a, &c), None);
+        assert_eq!(FuzzyTitleYear::default().score(&a, &b), Some(1.0));
+        assert_eq!(FuzzyTitleYear::default().score(&a, &c), Some(0.0));
+        assert_eq!(
+            FuzzyTitleYear { year_tolerance: 5 }.score(&a, &c),
+            Some(1.0)
+        );
+        assert_eq!(AuthorOverlap.score(&a, &b), Some(1.0));
+        assert_eq!(AuthorOverlap.score(&a, &c), Some(0.0));
+    }
+
+    #[test]
+    fn test_weighted() {
+        let a = article("a", "Deep Residual Learning", 2015, &["He"], None);
+        let b = article("b", "Deep Residual Learning", 2015, &["Smith"], None);
+
+        // Without DOIs, only the title and authors count
+        assert_eq!(Weighted::default().score(&a, &b), Some(2.0 / 3.0));
+        let titles_only = Weighted::new().with(FuzzyTitleYear::default(), 1.0);
+        assert_eq!(titles_only.score(&a, &b), Some(1.0));
+        assert_eq!(Weighted::new().score(&a, &b), None);
+
+        let same_id = |a: &Citation, b: &Citation| Some(f64::from(u8::from(a.id() == b.id())));
+        let custom = Weighted::new().with(same_id, 1.0).with(AuthorOverlap, 0.0);
+        assert_eq!(custom.score(&a, &b), Some(0.0));
+    }
+
+    #[test]
+    fn test_find_duplicates() {
+        let citations = [
+            article("a", "Attention Is All You Need", 2017, &["Vaswani"], None),
+            article("b", "Something Else Entirely", 2017, &["Vaswani"], None),
+            article(
+                "c",
+                "Attention is all you need",
+                2017,
+                &["Vaswani", "Shazeer"],
+                None,
+            ),
+        ];
+        let pairs = find_duplicates(&citations, &Weighted::default(), 0.9);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(
+            (pairs[0].first.as_str(), pairs[0].second.as_str()),
+            ("a", "c")
+        );
+        assert_eq!(pairs[0].score, 1.0);
+    }
+}
@@ -0,0 +1,79 @@
+//! Options shared by the BibTeX and RIS exporters.
+//!
+//! Tools that read these formats disagree on conventions, e.g. natbib
+//! expects `year` and `month` where biblatex prefers `date`, so the field
+//! mapping can be adjusted to suit the tool the file is meant for.
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::date::PublishDate;
+
+/// How citations are mapped onto BibTeX and RIS fields.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ExportOptions {
+    /// The BibTeX entry types and field names to use
+    #[serde(default)]
+    pub dialect: BibtexDialect,
+    /// How publish dates are written
+    #[serde(default)]
+    pub dates: DateFields,
+    /// Whether tags are exported as keywords: BibTeX `keywords`, RIS `KW`
+    #[serde(default)]
+    pub tags_as_keywords: bool,
+}
+
+impl ExportOptions {
+    /// Classic BibTeX for natbib and other `.bst` styles: `year` and
+    /// `month`, no keywords
+    pub fn natbib() -> Self {
+        Self::default()
+    }
+
+    /// biblatex entry types and fields, ISO `date`s and keywords
+    pub fn biblatex() -> Self {
+        Self {
+            dialect: BibtexDialect::Biblatex,
+            dates: DateFields::Date,
+            tags_as_keywords: true,
+        }
+    }
+}
+
+/// The flavor of BibTeX written.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BibtexDialect {
+    /// Entry types and fields known to classic BibTeX styles; online
+    /// resources are `@misc`
+    #[default]
+    Bibtex,
+    /// biblatex's `@online` and `@software` entry types, with
+    /// `journaltitle` and `location` instead of `journal` and `address`
+    Biblatex,
+}
+
+/// How publish dates are written.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum DateFields {
+    /// BibTeX `year` and `month`; only the year (`PY`) in RIS
+    #[default]
+    YearMonth,
+    /// An ISO 8601 date: biblatex `date`, RIS `DA` alongside `PY`
+    Date,
+}
+
+/// "2020", "2020-03" or "2020-03-15"
+pub(crate) fn iso_date(date: &PublishDate) -> String {
+    match (date.month(), date.day()) {
+        (Some(month), Some(day)) => {
+            format!(
+                "{}-{:02}-{:02}",
+                date.year(),
+                month.number_from_month(),
+                day
+            )
+        }
+        (Some(month), None) => format!("{}-{:02}", date.year(), month.number_from_month()),
+        _ => date.year().to_string(),
+    }
+}
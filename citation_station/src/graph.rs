@@ -0,0 +1,268 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{api::citation_id::CitationId, bibliography::Bibliography};
+
+/// References between the entries of a bibliography, built from each
+/// citation's `cites` list.
+///
+/// References to IDs that aren't in the bibliography are dropped, so every
+/// ID returned by the graph can be looked up with
+/// [`Bibliography::get_citation`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CitationGraph {
+    /// Every entry and its title, in bibliography order
+    nodes: Vec<(CitationId, String)>,
+    /// Outgoing references, in the order they were recorded
+    references: HashMap<CitationId, Vec<CitationId>>,
+    /// Incoming references, in bibliography order
+    cited_by: HashMap<CitationId, Vec<CitationId>>,
+}
+
+impl CitationGraph {
+    pub fn from_bibliography(bibliography: &Bibliography) -> Self {
+        let ids: HashSet<CitationId> = bibliography
+            .citations()
+            .iter()
+            .map(|citation| citation.citation_id())
+            .collect();
+
+        let mut graph = Self::default();
+        for citation in bibliography.citations() {
+            let from = citation.citation_id();
+            graph.nodes.push((from.clone(), citation.title()));
+            let mut seen = HashSet::new();
+            let targets: Vec<CitationId> = citation
+                .cites()
+                .iter()
+                .filter(|to| ids.contains(*to) && seen.insert(*to))
+                .cloned()
+                .collect();
+            for to in &targets {
+                graph
+                    .cited_by
+                    .entry(to.clone())
+                    .or_default()
+                    .push(from.clone());
+            }
+            graph.references.insert(from, targets);
+        }
+        graph
+    }
+
+    /// Entries directly referenced by `id`
+    pub fn references(&self, id: &str) -> &[CitationId] {
+        self.references.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Entries that directly reference `id`
+    pub fn cited_by(&self, id: &str) -> &[CitationId] {
+        self.cited_by.get(id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Every entry reachable from `id` by following references, nearest
+    /// first. `id` itself is only included if it is part of a cycle.
+    pub fn reachable_from(&self, id: &str) -> Vec<CitationId> {
+        let mut reached = Vec::new();
+        let mut seen: HashSet<&CitationId> = HashSet::new();
+        let mut queue: VecDeque<&CitationId> = self.references(id).iter().collect();
+        while let Some(next) = queue.pop_front() {
+            if !seen.insert(next) {
+                continue;
+            }
+            reached.push(next.clone());
+            queue.extend(self.references(next.as_str()));
+        }
+        reached
+    }
+
+    /// The shortest chain of references leading from `from` to `to`,
+    /// including both ends, e.g. `[a, b, c]` when a cites b and b cites c.
+    /// Returns `None` if `to` can't be reached.
+    pub fn chain(&self, from: &str, to: &str) -> Option<Vec<CitationId>> {
+        let start = self.references.get_key_value(from)?.0;
+        let mut previous: HashMap<&CitationId, &CitationId> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                let mut chain = vec![current.clone()];
+                let mut node = current;
+                while let Some(prev) = previous.get(node) {
+                    chain.push((*prev).clone());
+                    node = prev;
+                }
+                chain.reverse();
+                return Some(chain);
+            }
+            for next in self.references(current.as_str()) {
+                if next != start && !previous.contains_key(next) {
+                    previous.insert(next, current);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// The graph in GraphViz DOT, with entries labelled by title and an
+    /// edge from each entry to the entries it references
+    pub fn to_dot(&self) -> String {
+        let quote = |text: &str| format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut lines = vec!["digraph citations {".to_string()];
+        for (id, title) in &self.nodes {
+            lines.push(format!(
+                "  {} [label={}];",
+                quote(id.as_str()),
+                quote(title)
+            ));
+        }
+        for (from, _) in &self.nodes {
+            for to in self.references(from.as_str()) {
+                lines.push(format!(
+                    "  {} -> {};",
+                    quote(from.as_str()),
+                    quote(to.as_str())
+                ));
+            }
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    /// The graph as a Mermaid flowchart. Citation IDs may contain
+    /// characters Mermaid doesn't allow in node IDs, so entries are
+    /// numbered `n0`, `n1`, ... in bibliography order and labelled by title.
+    pub fn to_mermaid(&self) -> String {
+        let node: HashMap<&CitationId, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, (id, _))| (id, index))
+            .collect();
+        let mut lines = vec!["flowchart LR".to_string()];
+        for (index, (_, title)) in self.nodes.iter().enumerate() {
+            lines.push(format!(
+                "  n{}[\"{}\"]",
+                index,
+                title.replace('"', "#quot;")
+            ));
+        }
+        for (from, _) in &self.nodes {
+            for to in self.references(from.as_str()) {
+                lines.push(format!("  n{} --> n{}", node[from], node[to]));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::AcademicAuthor,
+            citation::Citation,
+            citation_id::CitationId,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+        },
+        bibliography::Bibliography,
+        graph::CitationGraph,
+    };
+
+    fn article(id: &str, cites: &[&str]) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                cites: cites.iter().map(|&id| CitationId::from(id)).collect(),
+                ..CommonCitationData::new(id)
+            },
+            author: AcademicAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            title: id.into(),
+            journal: "Journal".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: None,
+        })
+    }
+
+    fn graph(entries: &[(&str, &[&str])]) -> CitationGraph {
+        let mut bib = Bibliography::new();
+        for (id, cites) in entries {
+            bib.add_citation(article(id, cites)).unwrap();
+        }
+        bib.citation_graph()
+    }
+
+    fn ids(ids: &[&str]) -> Vec<CitationId> {
+        ids.iter().map(|&id| CitationId::from(id)).collect()
+    }
+
+    #[test]
+    fn test_references_and_cited_by() {
+        let graph = graph(&[("a", &["b", "c", "missing"]), ("b", &["c"]), ("c", &[])]);
+
+        assert_eq!(graph.references("a"), ids(&["b", "c"]));
+        assert_eq!(graph.cited_by("c"), ids(&["a", "b"]));
+        assert!(graph.cited_by("a").is_empty());
+        assert!(graph.references("missing").is_empty());
+    }
+
+    #[test]
+    fn test_reachable_from() {
+        let graph = graph(&[("a", &["b"]), ("b", &["c", "d"]), ("c", &["a"]), ("d", &[])]);
+
+        assert_eq!(graph.reachable_from("b"), ids(&["c", "d", "a", "b"]));
+        assert_eq!(graph.reachable_from("d"), ids(&[]));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = graph(&[("a", &["b"]), ("b", &[])]);
+
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph citations {
+  "a" [label="a"];
+  "b" [label="b"];
+  "a" -> "b";
+}"#
+        );
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(article("smith:2020", &["jones"])).unwrap();
+        let mut cited = article("jones", &[]);
+        if let Citation::JournalArticle(article) = &mut cited {
+            article.title = "The \"Best\" Paper".into();
+        }
+        bib.add_citation(cited).unwrap();
+
+        assert_eq!(
+            bib.citation_graph().to_mermaid(),
+            r#"flowchart LR
+  n0["smith:2020"]
+  n1["The #quot;Best#quot; Paper"]
+  n0 --> n1"#
+        );
+    }
+
+    #[test]
+    fn test_chain() {
+        let graph = graph(&[
+            ("a", &["b", "x"]),
+            ("b", &["c"]),
+            ("x", &["y"]),
+            ("y", &["c"]),
+            ("c", &[]),
+        ]);
+
+        assert_eq!(graph.chain("a", "c"), Some(ids(&["a", "b", "c"])));
+        assert_eq!(graph.chain("a", "a"), Some(ids(&["a"])));
+        assert_eq!(graph.chain("c", "a"), None);
+        assert_eq!(graph.chain("missing", "a"), None);
+    }
+}
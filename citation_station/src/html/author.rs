@@ -0,0 +1,222 @@
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+use crate::api::author::PersonName;
+
+/// Where on a page an author name was found, from most to least reliable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AuthorSource {
+    /// `author` of a JSON-LD (schema.org) object
+    JsonLd,
+    /// `<meta name="author">`
+    MetaAuthor,
+    /// `<meta property="article:author">` (Open Graph)
+    ArticleAuthor,
+    /// Text of a `rel="author"` link
+    RelAuthor,
+}
+
+/// A person who may be an author of the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorCandidate {
+    pub name: PersonName,
+    pub source: AuthorSource,
+}
+
+pub(crate) struct AuthorSelectors {
+    meta_author: Selector,
+    article_author: Selector,
+    rel_author: Selector,
+    json_ld: Selector,
+}
+
+impl AuthorSelectors {
+    pub(crate) fn new() -> Self {
+        Self {
+            meta_author: Selector::parse(r#"meta[name="author"]"#).unwrap(),
+            article_author: Selector::parse(r#"meta[property="article:author"]"#).unwrap(),
+            rel_author: Selector::parse(r#"a[rel~="author"], link[rel~="author"]"#).unwrap(),
+            json_ld: Selector::parse(r#"script[type="application/ld+json"]"#).unwrap(),
+        }
+    }
+}
+
+/// Collect author candidates from every supported source, most reliable
+/// source first. A name found twice by the same source is listed once.
+pub(crate) fn extract_authors(html: &Html, selectors: &AuthorSelectors) -> Vec<AuthorCandidate> {
+    let mut names: Vec<(String, AuthorSource)> = Vec::new();
+
+    for script in html.select(&selectors.json_ld) {
+        if let Ok(json) = serde_json::from_str::<Value>(&script.text().collect::<String>()) {
+            json_ld_authors(&json, &mut |name| names.push((name, AuthorSource::JsonLd)));
+        }
+    }
+    for tag in html.select(&selectors.meta_author) {
+        if let Some(content) = tag.value().attr("content") {
+            for name in split_names(content) {
+                names.push((name, AuthorSource::MetaAuthor));
+            }
+        }
+    }
+    for tag in html.select(&selectors.article_author) {
+        // Often a link to a profile page rather than a name
+        if let Some(content) = tag.value().attr("content")
+            && !content.contains("://")
+        {
+            for name in split_names(content) {
+                names.push((name, AuthorSource::ArticleAuthor));
+            }
+        }
+    }
+    for link in html.select(&selectors.rel_author) {
+        let text = link.text().collect::<String>();
+        if !text.trim().is_empty() {
+            names.push((text.trim().to_string(), AuthorSource::RelAuthor));
+        }
+    }
+
+    let mut candidates: Vec<AuthorCandidate> = Vec::new();
+    for (name, source) in names {
+        let Ok(name) = PersonName::parse(&name) else {
+            continue;
+        };
+        let candidate = AuthorCandidate { name, source };
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+    candidates.sort_by_key(|candidate| candidate.source);
+    candidates
+}
+
+/// Split a list like "Jane Doe and John Roe" or "Jane Doe; John Roe"
+fn split_names(content: &str) -> Vec<String> {
+    content
+        .split([';', '|'])
+        .flat_map(|part| part.split(" and "))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Call `found` with the name of every person listed as an `author`
+/// anywhere in a JSON-LD document, including inside `@graph`.
+fn json_ld_authors(json: &Value, found: &mut impl FnMut(String)) {
+    match json {
+        Value::Array(items) => items.iter().for_each(|item| json_ld_authors(item, found)),
+        Value::Object(object) => {
+            for (key, value) in object {
+                if key == "author" {
+                    json_ld_author_names(value, found);
+                } else {
+                    json_ld_authors(value, found);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn json_ld_author_names(author: &Value, found: &mut impl FnMut(String)) {
+    match author {
+        Value::String(name) => found(name.clone()),
+        Value::Array(authors) => authors
+            .iter()
+            .for_each(|author| json_ld_author_names(author, found)),
+        Value::Object(object) => {
+            let is_person = match object.get("@type") {
+                None => true,
+                Some(Value::String(kind)) => kind == "Person",
+                Some(Value::Array(kinds)) => kinds.iter().any(|kind| kind == "Person"),
+                Some(_) => false,
+            };
+            if is_person && let Some(Value::String(name)) = object.get("name") {
+                found(name.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use crate::{
+        api::author::PersonName,
+        html::{
+            HtmlParser,
+            author::{AuthorCandidate, AuthorSource},
+        },
+    };
+
+    fn candidate(name: &str, source: AuthorSource) -> AuthorCandidate {
+        AuthorCandidate {
+            name: PersonName::parse(name).unwrap(),
+            source,
+        }
+    }
+
+    #[test]
+    fn test_parse_authors_from_all_sources() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta name="author" content="Jane Doe and John Roe">
+    <meta property="article:author" content="https://example.com/jane">
+    <meta property="article:author" content="Doe, Jane">
+    <script type="application/ld+json">
+    {"@context": "https://schema.org", "@graph": [{
+        "@type": "BlogPosting",
+        "author": [
+            {"@type": "Person", "name": "Jane Q. Doe"},
+            {"@type": "Organization", "name": "Example Inc."}
+        ]
+    }]}
+    </script>
+</head>
+<body>
+    <a rel="author" href="/about">Jane Doe</a>
+</body>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        let authors = html_parser.parse_authors(&html);
+
+        assert_eq!(
+            authors,
+            [
+                candidate("Jane Q. Doe", AuthorSource::JsonLd),
+                candidate("Jane Doe", AuthorSource::MetaAuthor),
+                candidate("John Roe", AuthorSource::MetaAuthor),
+                candidate("Jane Doe", AuthorSource::ArticleAuthor),
+                candidate("Jane Doe", AuthorSource::RelAuthor),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_authors_invalid_json_ld() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <script type="application/ld+json">{"author": </script>
+    <script type="application/ld+json">{"author": "Ada Lovelace"}</script>
+</head>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        assert_eq!(
+            html_parser.parse_authors(&html),
+            [candidate("Ada Lovelace", AuthorSource::JsonLd)]
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use chrono::Month;
+use scraper::{ElementRef, Html, Selector, node::Node};
+use serde_json::Value;
+
+use crate::api::date::PublishDate;
+
+/// Where on a page a publish date was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DateSource {
+    /// `datePublished` of a JSON-LD (schema.org) object
+    JsonLd,
+    /// `<meta property="article:published_time">` (Open Graph)
+    ArticlePublishedTime,
+    /// `datetime` attribute of a `<time>` element
+    TimeElement,
+    /// A date written out in the page text, e.g. "March 5, 2023"
+    VisibleText,
+}
+
+impl DateSource {
+    pub fn confidence(&self) -> DateConfidence {
+        match self {
+            DateSource::JsonLd | DateSource::ArticlePublishedTime => DateConfidence::High,
+            // Pages also use <time> for comments, updates and events
+            DateSource::TimeElement => DateConfidence::Medium,
+            DateSource::VisibleText => DateConfidence::Low,
+        }
+    }
+}
+
+/// How likely a candidate is to be the publish date of the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DateConfidence {
+    Low,
+    Medium,
+    High,
+}
+
+/// A date that may be the publish date of the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateCandidate {
+    pub date: PublishDate,
+    pub source: DateSource,
+    pub confidence: DateConfidence,
+}
+
+pub(crate) struct DateSelectors {
+    article_published_time: Selector,
+    time: Selector,
+    json_ld: Selector,
+    body: Selector,
+}
+
+impl DateSelectors {
+    pub(crate) fn new() -> Self {
+        Self {
+            article_published_time: Selector::parse(r#"meta[property="article:published_time"]"#)
+                .unwrap(),
+            time: Selector::parse("time[datetime]").unwrap(),
+            json_ld: Selector::parse(r#"script[type="application/ld+json"]"#).unwrap(),
+            body: Selector::parse("body").unwrap(),
+        }
+    }
+}
+
+/// Collect publish date candidates, most confident first. Among equally
+/// confident candidates, more precise dates come first and page order is
+/// kept otherwise.
+pub(crate) fn extract_dates(html: &Html, selectors: &DateSelectors) -> Vec<DateCandidate> {
+    let mut found: Vec<(PublishDate, DateSource)> = Vec::new();
+
+    for script in html.select(&selectors.json_ld) {
+        if let Ok(json) = serde_json::from_str::<Value>(&script.text().collect::<String>()) {
+            json_ld_dates(&json, &mut |date| found.push((date, DateSource::JsonLd)));
+        }
+    }
+    for tag in html.select(&selectors.article_published_time) {
+        if let Some(date) = tag.value().attr("content").and_then(PublishDate::parse_iso) {
+            found.push((date, DateSource::ArticlePublishedTime));
+        }
+    }
+    for tag in html.select(&selectors.time) {
+        if let Some(date) = tag
+            .value()
+            .attr("datetime")
+            .and_then(PublishDate::parse_iso)
+        {
+            found.push((date, DateSource::TimeElement));
+        }
+    }
+    for body in html.select(&selectors.body) {
+        for text in visible_text(body) {
+            for date in dates_in_text(&text) {
+                found.push((date, DateSource::VisibleText));
+            }
+        }
+    }
+
+    let mut candidates: Vec<DateCandidate> = Vec::new();
+    for (date, source) in found {
+        let candidate = DateCandidate {
+            date,
+            source,
+            confidence: source.confidence(),
+        };
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+    candidates.sort_by_key(|candidate| {
+        (
+            std::cmp::Reverse(candidate.confidence),
+            candidate.date.day().is_none(),
+            candidate.date.month().is_none(),
+        )
+    });
+    candidates
+}
+
+fn json_ld_dates(json: &Value, found: &mut impl FnMut(PublishDate)) {
+    match json {
+        Value::Array(items) => items.iter().for_each(|item| json_ld_dates(item, found)),
+        Value::Object(object) => {
+            for (key, value) in object {
+                match value {
+                    Value::String(date) if key == "datePublished" => {
+                        if let Some(date) = PublishDate::parse_iso(date) {
+                            found(date);
+                        }
+                    }
+                    _ => json_ld_dates(value, found),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Text nodes of an element, skipping scripts and styles
+fn visible_text(element: ElementRef) -> Vec<String> {
+    element
+        .descendants()
+        .filter_map(|node| {
+            let Node::Text(text) = node.value() else {
+                return None;
+            };
+            let parent = node.parent()?;
+            match parent.value().as_element()?.name() {
+                "script" | "style" | "noscript" => None,
+                _ => Some(text.to_string()),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn parse_month(word: &str) -> Option<Month> {
+    let word = word.to_lowercase();
+    if word.len() < 3 {
+        return None;
+    }
+    (1..=12u8)
+        .filter_map(|number| Month::try_from(number).ok())
+        .find(|month| {
+            let name = month.name().to_lowercase();
+            name.starts_with(&word) || (word == "sept" && *month == Month::September)
+        })
+}
+
+fn parse_day(word: &str) -> Option<u32> {
+    let digits = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        .unwrap_or(word);
+    if digits.is_empty() || digits.len() > 2 {
+        return None;
+    }
+    digits.parse().ok().filter(|day| (1..=31).contains(day))
+}
+
+fn parse_year(word: &str) -> Option<i32> {
+    if word.len() != 4 {
+        return None;
+    }
+    word.parse().ok()
+}
+
+/// Dates written as "March 5, 2023", "5 March 2023", "Mar. 5th 2023" or
+/// "2023-03-05"
+fn dates_in_text(text: &str) -> Vec<PublishDate> {
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .collect();
+
+    let mut dates = Vec::new();
+    let mut index = 0;
+    while index < words.len() {
+        let window = &words[index..words.len().min(index + 3)];
+        let date = match window {
+            [month, day, year, ..] if parse_month(month).is_some() => parse_day(day)
+                .zip(parse_year(year))
+                .and_then(|(day, year)| {
+                    PublishDate::from_year_month_day(year, parse_month(month)?, day).ok()
+                }),
+            [day, month, year, ..] if parse_month(month).is_some() => parse_day(day)
+                .zip(parse_year(year))
+                .and_then(|(day, year)| {
+                    PublishDate::from_year_month_day(year, parse_month(month)?, day).ok()
+                }),
+            [iso, ..] if iso.len() == 10 && iso.matches('-').count() == 2 => {
+                PublishDate::parse_iso(iso)
+            }
+            _ => None,
+        };
+        match date {
+            Some(date) => {
+                index += if window[0].len() == 10 { 1 } else { 3 };
+                dates.push(date);
+            }
+            None => index += 1,
+        }
+    }
+    dates
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+    use scraper::Html;
+
+    use crate::{
+        api::date::PublishDate,
+        html::{
+            HtmlParser,
+            date::{DateConfidence, DateSource, dates_in_text},
+        },
+    };
+
+    fn ymd(year: i32, month: Month, day: u32) -> PublishDate {
+        PublishDate::from_year_month_day(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_dates_in_text() {
+        assert_eq!(
+            dates_in_text("Posted on March 5, 2023 by Jane. Updated 7th Apr. 2023 (2023-04-08)."),
+            [
+                ymd(2023, Month::March, 5),
+                ymd(2023, Month::April, 7),
+                ymd(2023, Month::April, 8)
+            ]
+        );
+        assert!(dates_in_text("May I have 5 minutes? In 2023 maybe.").is_empty());
+    }
+
+    #[test]
+    fn test_parse_publish_dates_ranked() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta property="article:published_time" content="2023-03-05T09:30:00+00:00">
+    <script type="application/ld+json">
+    {"@type": "NewsArticle", "datePublished": "2023-03", "dateModified": "2024-01-01"}
+    </script>
+</head>
+<body>
+    <p>Comment posted <time datetime="2023-06-01">June 1</time></p>
+    <p>Published 5 March 2023</p>
+    <script>var built = "January 1, 2020";</script>
+</body>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        let dates = html_parser.parse_publish_dates(&html);
+        let summary: Vec<(PublishDate, DateSource, DateConfidence)> = dates
+            .into_iter()
+            .map(|candidate| (candidate.date, candidate.source, candidate.confidence))
+            .collect();
+
+        assert_eq!(
+            summary,
+            [
+                (
+                    ymd(2023, Month::March, 5),
+                    DateSource::ArticlePublishedTime,
+                    DateConfidence::High
+                ),
+                (
+                    PublishDate::from_year_month(2023, Month::March),
+                    DateSource::JsonLd,
+                    DateConfidence::High
+                ),
+                (
+                    ymd(2023, Month::June, 1),
+                    DateSource::TimeElement,
+                    DateConfidence::Medium
+                ),
+                (
+                    ymd(2023, Month::March, 5),
+                    DateSource::VisibleText,
+                    DateConfidence::Low
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+pub mod author;
+pub mod date;
+pub mod title;
+
+use scraper::{Html, Selector};
+
+use crate::html::{
+    author::{AuthorCandidate, AuthorSelectors, extract_authors},
+    date::{DateCandidate, DateSelectors, extract_dates},
+    title::{CleanTitle, TitleCleaner},
+};
+
+pub struct WebsiteTitle {
+    pub from_title_tag: Option<String>,
+    pub from_og_title: Option<String>,
+    /// Name of the website from `og:site_name`
+    pub site_name: Option<String>,
+    // from_schema_thing_headline: Option<String>,
+}
+
+impl WebsiteTitle {
+    /// The citation title and site name.
+    ///
+    /// `og:title` is preferred since it rarely includes the site name;
+    /// either title is run through `cleaner` to strip it anyway.
+    pub fn clean(&self, cleaner: &TitleCleaner) -> Option<CleanTitle> {
+        let raw = self
+            .from_og_title
+            .as_deref()
+            .or(self.from_title_tag.as_deref())?;
+        let clean = cleaner.clean(raw, self.site_name.as_deref());
+        (!clean.title.is_empty()).then_some(clean)
+    }
+}
+
+pub struct HtmlParser {
+    title_selector: Selector,
+    og_title_selector: Selector,
+    og_site_name_selector: Selector,
+    author_selectors: AuthorSelectors,
+    date_selectors: DateSelectors,
+}
+
+impl HtmlParser {
+    pub fn new() -> Self {
+        let title_selector = Selector::parse("title").unwrap();
+        let og_title_selector = Selector::parse(r#"meta[property="og:title"]"#).unwrap();
+        let og_site_name_selector = Selector::parse(r#"meta[property="og:site_name"]"#).unwrap();
+
+        Self {
+            title_selector,
+            og_title_selector,
+            og_site_name_selector,
+            author_selectors: AuthorSelectors::new(),
+            date_selectors: DateSelectors::new(),
+        }
+    }
+
+    pub fn parse_title(&self, html: Html) -> WebsiteTitle {
+        let html_title = html
+            .select(&self.title_selector)
+            .take(1)
+            .next()
+            .map(|title_tag| title_tag.text().collect::<String>());
+
+        WebsiteTitle {
+            from_title_tag: html_title,
+            from_og_title: meta_content(&html, &self.og_title_selector),
+            site_name: meta_content(&html, &self.og_site_name_selector),
+            // from_schema_thing_headline: None,
+        }
+    }
+
+    /// Possible authors of the page with where each was found, most
+    /// reliable source first. The same person may appear more than once.
+    pub fn parse_authors(&self, html: &Html) -> Vec<AuthorCandidate> {
+        extract_authors(html, &self.author_selectors)
+    }
+
+    /// Possible publish dates of the page, most confident first
+    pub fn parse_publish_dates(&self, html: &Html) -> Vec<DateCandidate> {
+        extract_dates(html, &self.date_selectors)
+    }
+}
+
+/// The trimmed, non-empty `content` attribute of the first matching tag
+fn meta_content(html: &Html, selector: &Selector) -> Option<String> {
+    html.select(selector)
+        .filter_map(|tag| tag.value().attr("content"))
+        .map(str::trim)
+        .find(|content| !content.is_empty())
+        .map(str::to_string)
+}
+
+impl Default for HtmlParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use crate::html::{HtmlParser, title::TitleCleaner};
+
+    #[test]
+    fn test_parse_title_missing() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+</head>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        let title = html_parser.parse_title(html);
+
+        assert_eq!(title.from_title_tag, None);
+    }
+
+    #[test]
+    fn test_parse_invalid_html_two_titles() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <title>First</title>
+    <title>Second</title>
+</head>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        let title = html_parser.parse_title(html);
+
+        assert_eq!(title.from_title_tag, Some("First".to_string()));
+    }
+
+    #[test]
+    fn test_parse_title() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <title>Document</title>
+</head>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        let title = html_parser.parse_title(html);
+
+        assert_eq!(title.from_title_tag, Some("Document".to_string()))
+    }
+
+    #[test]
+    fn test_clean_title_with_og_site_name() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <title>Why Rust? &ndash; Example Site | Blog</title>
+    <meta property="og:site_name" content="Example Site">
+</head>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        let title = html_parser.parse_title(html);
+        let clean = title.clean(&TitleCleaner::new()).unwrap();
+
+        assert_eq!(clean.title, "Why Rust?");
+        assert_eq!(clean.site_name, Some("Example Site".to_string()));
+    }
+
+    #[test]
+    fn test_clean_title_prefers_og_title() {
+        let html_str = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <title>Home | Example</title>
+    <meta property="og:title" content="The Real Title">
+</head>
+</html>
+"#;
+        let html = Html::parse_document(html_str);
+        let html_parser = HtmlParser::new();
+
+        let title = html_parser.parse_title(html);
+
+        assert_eq!(
+            title.clean(&TitleCleaner::new()).unwrap().title,
+            "The Real Title"
+        );
+    }
+}
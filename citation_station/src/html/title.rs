@@ -0,0 +1,235 @@
+/// Separators commonly placed between a page title and the site name in
+/// `<title>` tags, e.g. "Article – Site Name | Blog".
+pub const DEFAULT_TITLE_DELIMITERS: [&str; 7] = [" | ", " – ", " — ", " - ", " :: ", " · ", " • "];
+
+/// A page title with the site name split off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanTitle {
+    pub title: String,
+    pub site_name: Option<String>,
+}
+
+/// Splits scraped `<title>` text into the work's title and the site name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleCleaner {
+    delimiters: Vec<String>,
+}
+
+impl TitleCleaner {
+    pub fn new() -> Self {
+        Self::with_delimiters(DEFAULT_TITLE_DELIMITERS)
+    }
+
+    pub fn with_delimiters<S: Into<String>>(delimiters: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            delimiters: delimiters.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Clean a raw title.
+    ///
+    /// With a known `site_name` (e.g. from `og:site_name`) everything from
+    /// the delimiter before the site name onwards is removed, as is a
+    /// leading "Site Name | ". Without one, the title is split on the
+    /// delimiters and the longest segment is taken as the title; the
+    /// outermost remaining segment is taken as the site name.
+    pub fn clean(&self, raw: &str, site_name: Option<&str>) -> CleanTitle {
+        let raw = collapse_whitespace(raw);
+        let site_name = site_name
+            .map(collapse_whitespace)
+            .filter(|name| !name.is_empty());
+
+        match site_name {
+            Some(site_name) => CleanTitle {
+                title: self.strip_site_name(&raw, &site_name),
+                site_name: Some(site_name),
+            },
+            None => self.split_segments(&raw),
+        }
+    }
+
+    fn strip_site_name(&self, raw: &str, site_name: &str) -> String {
+        let mut title = raw;
+
+        let suffix_start = self
+            .delimiters
+            .iter()
+            .filter_map(|delimiter| find_ignore_case(raw, &format!("{}{}", delimiter, site_name)))
+            .min();
+        if let Some(start) = suffix_start
+            && start > 0
+        {
+            title = &raw[..start];
+        } else if let Some(rest) = self.delimiters.iter().find_map(|delimiter| {
+            let prefix = format!("{}{}", site_name, delimiter);
+            prefix_len_ignore_case(raw, &prefix).map(|len| &raw[len..])
+        }) && !rest.trim().is_empty()
+        {
+            title = rest;
+        }
+        title.trim().to_string()
+    }
+
+    fn split_segments(&self, raw: &str) -> CleanTitle {
+        let mut segments = vec![raw];
+        for delimiter in &self.delimiters {
+            segments = segments
+                .into_iter()
+                .flat_map(|segment| segment.split(delimiter.as_str()))
+                .collect();
+        }
+        let segments: Vec<&str> = segments
+            .into_iter()
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        if segments.len() < 2 {
+            return CleanTitle {
+                title: raw.trim().to_string(),
+                site_name: None,
+            };
+        }
+
+        // The first of equally long segments wins, since titles usually
+        // come before the site name.
+        let (title_index, _) = segments
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, segment)| segment.chars().count())
+            .expect("at least two segments");
+        let site_index = if title_index == segments.len() - 1 {
+            0
+        } else {
+            segments.len() - 1
+        };
+        CleanTitle {
+            title: segments[title_index].to_string(),
+            site_name: Some(segments[site_index].to_string()),
+        }
+    }
+}
+
+impl Default for TitleCleaner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Byte offset in `text` of the first case-insensitive match of `pattern`.
+///
+/// Offsets always fall on `text`'s own char boundaries; lowercasing can
+/// change a character's UTF-8 length, so offsets into a lowercased copy
+/// cannot be used to slice the original.
+fn find_ignore_case(text: &str, pattern: &str) -> Option<usize> {
+    text.char_indices()
+        .map(|(i, _)| i)
+        .find(|&i| prefix_len_ignore_case(&text[i..], pattern).is_some())
+}
+
+/// Length in bytes of the part of `text` matching `prefix` case-insensitively,
+/// if `text` starts with it.
+fn prefix_len_ignore_case(text: &str, prefix: &str) -> Option<usize> {
+    let mut expected = prefix.chars().flat_map(char::to_lowercase).peekable();
+    let mut end = 0;
+    for (i, c) in text.char_indices() {
+        if expected.peek().is_none() {
+            return Some(i);
+        }
+        for lower in c.to_lowercase() {
+            if expected.next() != Some(lower) {
+                return None;
+            }
+        }
+        end = i + c.len_utf8();
+    }
+    expected.peek().is_none().then_some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::title::{CleanTitle, TitleCleaner};
+
+    fn clean(raw: &str, site_name: Option<&str>) -> CleanTitle {
+        TitleCleaner::new().clean(raw, site_name)
+    }
+
+    #[test]
+    fn test_strip_known_site_name_suffix() {
+        assert_eq!(
+            clean(
+                "Why Rust? \u{2013} Example Site | Blog",
+                Some("Example Site")
+            ),
+            CleanTitle {
+                title: "Why Rust?".to_string(),
+                site_name: Some("Example Site".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_strip_known_site_name_prefix() {
+        assert_eq!(
+            clean("example site | Why Rust?", Some("Example Site")).title,
+            "Why Rust?"
+        );
+    }
+
+    #[test]
+    fn test_strip_site_name_after_non_ascii_title() {
+        assert_eq!(
+            clean("\u{130}\u{130} \u{2013} Site", Some("Site")).title,
+            "\u{130}\u{130}"
+        );
+        assert_eq!(
+            clean("STRASSE \u{130}stanbul | Stra\u{df}e", Some("Stra\u{df}e")).title,
+            "STRASSE \u{130}stanbul"
+        );
+        assert_eq!(
+            clean(
+                "\u{130}stanbul \u{2014} Gezi | Rehber",
+                Some("\u{130}stanbul")
+            )
+            .title,
+            "Gezi | Rehber"
+        );
+    }
+
+    #[test]
+    fn test_site_name_is_whole_title() {
+        assert_eq!(
+            clean("Example Site", Some("Example Site")).title,
+            "Example Site"
+        );
+    }
+
+    #[test]
+    fn test_split_without_site_name() {
+        assert_eq!(
+            clean("  A   long article title - Blog", None),
+            CleanTitle {
+                title: "A long article title".to_string(),
+                site_name: Some("Blog".to_string()),
+            }
+        );
+        assert_eq!(
+            clean("Blog | A long article title", None).site_name,
+            Some("Blog".to_string())
+        );
+    }
+
+    #[test]
+    fn test_custom_delimiters() {
+        let cleaner = TitleCleaner::with_delimiters([" / "]);
+
+        assert_eq!(
+            cleaner.clean("Docs / Getting started - part 2", None).title,
+            "Getting started - part 2"
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        author::{AcademicAuthor, PersonName},
+        citation::Citation,
+        date::PublishDate,
+        media::{common::CommonCitationData, journal_article::JournalArticle},
+    },
+    import::{
+        ImportError, doi::normalize_doi, fetch_body, fetch_body_async, identifier::Identifier,
+        record_lookup,
+    },
+    net::{AsyncHttpClient, HttpClient},
+};
+
+const DATACITE_DOIS_ENDPOINT: &str = "https://api.datacite.org/dois/";
+
+/// Prefix of the DOIs arXiv registers for every preprint with DataCite
+const ARXIV_DOI_PREFIX: &str = "10.48550/arxiv.";
+
+/// Extract an arXiv identifier from "arXiv:2101.00001v2", an abs/pdf URL,
+/// or a bare ID. Both new-style ("2101.00001") and old-style
+/// ("hep-th/9901001") IDs are accepted; the version suffix is dropped.
+pub fn normalize_arxiv_id(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    let mut id = trimmed;
+    for prefix in [
+        "https://arxiv.org/abs/",
+        "http://arxiv.org/abs/",
+        "https://arxiv.org/pdf/",
+        "http://arxiv.org/pdf/",
+        "arxiv.org/abs/",
+        "arxiv.org/pdf/",
+    ] {
+        if let Some(rest) = id.strip_prefix(prefix) {
+            id = rest.trim_end_matches(".pdf");
+            break;
+        }
+    }
+    if let Some(prefix) = id.get(..6)
+        && prefix.eq_ignore_ascii_case("arxiv:")
+    {
+        id = &id[6..];
+    }
+    let id = match id.rsplit_once('v') {
+        Some((base, version))
+            if !version.is_empty() && version.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            base
+        }
+        _ => id,
+    };
+
+    let digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let valid = match id.split_once('/') {
+        // Old style: archive(.subject)/YYMMNNN
+        Some((archive, number)) => {
+            !archive.is_empty()
+                && archive
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c == '-' || c == '.')
+                && number.len() == 7
+                && digits(number)
+        }
+        // New style: YYMM.NNNN or YYMM.NNNNN
+        None => match id.split_once('.') {
+            Some((yymm, number)) => {
+                yymm.len() == 4 && digits(yymm) && (4..=5).contains(&number.len()) && digits(number)
+            }
+            None => false,
+        },
+    };
+    valid.then(|| id.to_string())
+}
+
+/// The DataCite API URL for the DOI of an arXiv preprint.
+pub fn datacite_url(arxiv_id: &str) -> String {
+    format!(
+        "{}{}{}",
+        DATACITE_DOIS_ENDPOINT,
+        ARXIV_DOI_PREFIX,
+        arxiv_id.to_lowercase()
+    )
+}
+
+/// Fetch an arXiv preprint's metadata from DataCite.
+pub fn fetch_arxiv<C: HttpClient + ?Sized>(
+    client: &C,
+    arxiv_id: &str,
+) -> Result<Citation, ImportError> {
+    let result =
+        fetch_body(client, datacite_url(arxiv_id)).and_then(|body| citation_from_datacite(&body));
+    record_lookup("datacite", &result);
+    result
+}
+
+/// [`fetch_arxiv`] with an [`AsyncHttpClient`]
+pub async fn fetch_arxiv_async<C: AsyncHttpClient>(
+    client: &C,
+    arxiv_id: &str,
+) -> Result<Citation, ImportError> {
+    let result = fetch_body_async(client, datacite_url(arxiv_id))
+        .await
+        .and_then(|body| citation_from_datacite(&body));
+    record_lookup("datacite", &result);
+    result
+}
+
+#[derive(Deserialize)]
+struct DataCiteEnvelope {
+    data: DataCiteRecord,
+}
+
+#[derive(Deserialize)]
+struct DataCiteRecord {
+    attributes: DataCiteAttributes,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DataCiteAttributes {
+    doi: String,
+    #[serde(default)]
+    titles: Vec<DataCiteTitle>,
+    #[serde(default)]
+    creators: Vec<DataCiteCreator>,
+    publication_year: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct DataCiteTitle {
+    title: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DataCiteCreator {
+    name: String,
+    given_name: Option<String>,
+    family_name: Option<String>,
+}
+
+impl DataCiteCreator {
+    fn to_person_name(&self) -> Option<PersonName> {
+        match (&self.given_name, &self.family_name) {
+            (Some(given), Some(family)) => {
+                PersonName::parse(&format!("{}, {}", family.trim(), given.trim())).ok()
+            }
+            _ => PersonName::parse(&self.name).ok(),
+        }
+    }
+}
+
+/// Convert a DataCite `/dois/{doi}` response for an arXiv preprint into a
+/// citation.
+///
+/// There is no preprint media type yet, so preprints are recorded as
+/// journal articles published in "arXiv". The lowercase DOI is used as the
+/// citation ID, like DOI imports.
+pub fn citation_from_datacite(json: &str) -> Result<Citation, ImportError> {
+    let envelope: DataCiteEnvelope =
+        serde_json::from_str(json).map_err(|err| ImportError::Parse(err.to_string()))?;
+    let attributes = envelope.data.attributes;
+
+    let doi = normalize_doi(&attributes.doi)
+        .ok_or(ImportError::InvalidIdentifier(attributes.doi.clone()))?;
+    let title = attributes
+        .titles
+        .into_iter()
+        .next()
+        .map(|title| title.title)
+        .ok_or(ImportError::MissingField("titles".to_string()))?;
+    let persons: Vec<PersonName> = attributes
+        .creators
+        .iter()
+        .filter_map(DataCiteCreator::to_person_name)
+        .collect();
+
+    Ok(Citation::JournalArticle(JournalArticle {
+        common_data: CommonCitationData {
+            published: attributes.publication_year.map(PublishDate::from_year),
+            identifiers: doi
+                .strip_prefix(ARXIV_DOI_PREFIX)
+                .and_then(normalize_arxiv_id)
+                .map(Identifier::ArXiv)
+                .into_iter()
+                .collect(),
+            ..CommonCitationData::new(doi.clone())
+        },
+        author: AcademicAuthor::Persons { persons },
+        title: title.into(),
+        journal: "arXiv".to_string(),
+        volume: None,
+        number: None,
+        locator: None,
+        doi: Some(doi),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{citation::Citation, date::PublishDate},
+        import::arxiv::{citation_from_datacite, datacite_url, normalize_arxiv_id},
+    };
+
+    #[test]
+    fn test_normalize_arxiv_id() {
+        assert_eq!(
+            normalize_arxiv_id("arXiv:2101.00001v2"),
+            Some("2101.00001".to_string())
+        );
+        assert_eq!(
+            normalize_arxiv_id("https://arxiv.org/pdf/1706.03762v7.pdf"),
+            Some("1706.03762".to_string())
+        );
+        assert_eq!(
+            normalize_arxiv_id("hep-th/9901001"),
+            Some("hep-th/9901001".to_string())
+        );
+        assert_eq!(normalize_arxiv_id("2101.1"), None);
+        assert_eq!(normalize_arxiv_id("10.1000/xyz"), None);
+        assert_eq!(normalize_arxiv_id("a1000ч2101.00001"), None);
+    }
+
+    #[test]
+    fn test_datacite_url() {
+        assert_eq!(
+            datacite_url("1706.03762"),
+            "https://api.datacite.org/dois/10.48550/arxiv.1706.03762"
+        );
+    }
+
+    #[test]
+    fn test_citation_from_datacite() {
+        let json = r#"{"data": {"attributes": {
+            "doi": "10.48550/ARXIV.1706.03762",
+            "titles": [{"title": "Attention Is All You Need"}],
+            "creators": [
+                {"name": "Vaswani, Ashish", "givenName": "Ashish", "familyName": "Vaswani"},
+                {"name": "Shazeer, Noam"}
+            ],
+            "publicationYear": 2017
+        }}}"#;
+
+        let Citation::JournalArticle(article) = citation_from_datacite(json).unwrap() else {
+            panic!("expected a journal article");
+        };
+
+        assert_eq!(article.common_data.id, "10.48550/arxiv.1706.03762");
+        assert_eq!(
+            article.common_data.published,
+            Some(PublishDate::from_year(2017))
+        );
+        assert_eq!(article.author.contributors().len(), 2);
+        assert_eq!(article.journal, "arXiv");
+        assert_eq!(article.common_data.identifiers.arxiv(), Some("1706.03762"));
+    }
+}
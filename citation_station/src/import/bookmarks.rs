@@ -0,0 +1,203 @@
+use chrono::DateTime;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::{
+    api::{
+        citation::Citation,
+        date::AccessDate,
+        media::{common::CommonCitationData, web_page::WebPage},
+    },
+    import::{ImportIssue, ImportReport, ImportWarning, web::normalize_url},
+};
+
+/// Convert a browser bookmarks export (the Netscape `bookmarks.html`
+/// format written by every major browser) into web page citations.
+///
+/// The names of the folders containing a bookmark become its tags,
+/// outermost first, followed by any tags stored in the `TAGS` attribute.
+/// `ADD_DATE` becomes the access date; bookmarks without one are accessed
+/// today. Bookmarks that aren't http(s) links (e.g. `javascript:` or
+/// `place:`) are skipped. The URL is used as the citation ID.
+pub fn citations_from_bookmarks(html: &str) -> Vec<Citation> {
+    read_bookmarks(html).citations
+}
+
+/// Like [`citations_from_bookmarks`], also reporting the skipped bookmarks
+/// and the access dates that had to be replaced with today. The HTML
+/// parser doesn't keep source positions, so warnings have no line number.
+pub fn read_bookmarks(html: &str) -> ImportReport {
+    let html = Html::parse_document(html);
+    let link_selector = Selector::parse("a[href]").unwrap();
+
+    let mut report = ImportReport::default();
+    for link in html.select(&link_selector) {
+        let href = link.value().attr("href").unwrap_or_default();
+        let Some(url) = normalize_url(href) else {
+            report.warnings.push(ImportWarning {
+                line: None,
+                entry: Some(href.to_string()),
+                issue: ImportIssue::SkippedEntry {
+                    reason: "not an http(s) link".to_string(),
+                },
+            });
+            continue;
+        };
+        let title = link.text().collect::<String>().trim().to_string();
+        let add_date = link.value().attr("add_date");
+        let accessed = match add_date
+            .and_then(|seconds| seconds.trim().parse::<i64>().ok())
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+        {
+            Some(date) => AccessDate::from(date),
+            None => {
+                let today = AccessDate::today();
+                report.warnings.push(ImportWarning {
+                    line: None,
+                    entry: Some(url.clone()),
+                    issue: ImportIssue::CoercedDate {
+                        original: add_date.unwrap_or_default().to_string(),
+                        used: Some(today.date().to_string()),
+                    },
+                });
+                today
+            }
+        };
+
+        let mut tags = folder_names(link);
+        if let Some(extra) = link.value().attr("tags") {
+            tags.extend(
+                extra
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(str::to_string),
+            );
+        }
+
+        report.citations.push(Citation::WebPage(WebPage {
+            common_data: CommonCitationData {
+                tags,
+                ..CommonCitationData::new(url.clone())
+            },
+            author: None,
+            title: if title.is_empty() { url.clone() } else { title }.into(),
+            site_name: None,
+            url,
+            accessed,
+        }));
+    }
+    report
+}
+
+/// Names of the folders containing a bookmark, outermost first.
+///
+/// A folder is a `<DT>` whose heading is an `<H3>`, with the folder's
+/// contents in a `<DL>` inside the same `<DT>`.
+fn folder_names(link: ElementRef) -> Vec<String> {
+    let mut names: Vec<String> = link
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .filter(|ancestor| ancestor.value().name() == "dt")
+        .filter_map(|dt| {
+            dt.children()
+                .filter_map(ElementRef::wrap)
+                .find(|child| child.value().name() == "h3")
+                .map(|heading| heading.text().collect::<String>().trim().to_string())
+        })
+        .filter(|name| !name.is_empty())
+        .collect();
+    names.reverse();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{citation::Citation, date::AccessDate},
+        import::{
+            ImportIssue,
+            bookmarks::{citations_from_bookmarks, read_bookmarks},
+        },
+    };
+
+    const BOOKMARKS: &str = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+    <DT><A HREF="https://example.com/top" ADD_DATE="1696118400">Top level</A>
+    <DT><H3 ADD_DATE="1696118400">Research</H3>
+    <DL><p>
+        <DT><H3>Rust</H3>
+        <DL><p>
+            <DT><A HREF="https://example.com/rust" ADD_DATE="1696118400" TAGS="lang, systems">Rust book</A>
+        </DL><p>
+        <DT><A HREF="https://example.com/notes">Notes</A>
+        <DT><A HREF="javascript:alert(1)">Bookmarklet</A>
+    </DL><p>
+</DL><p>
+"#;
+
+    fn web_pages() -> Vec<(String, Vec<String>, AccessDate)> {
+        citations_from_bookmarks(BOOKMARKS)
+            .into_iter()
+            .map(|citation| match citation {
+                Citation::WebPage(page) => {
+                    (page.title.to_string(), page.common_data.tags, page.accessed)
+                }
+                other => panic!("expected a web page, got {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bookmark_folders_become_tags() {
+        let pages = web_pages();
+
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0].0, "Top level");
+        assert!(pages[0].1.is_empty());
+        assert_eq!(pages[1].0, "Rust book");
+        assert_eq!(pages[1].1, ["Research", "Rust", "lang", "systems"]);
+        assert_eq!(pages[2].1, ["Research"]);
+    }
+
+    #[test]
+    fn test_bookmark_add_date_is_access_date() {
+        let pages = web_pages();
+
+        assert_eq!(pages[0].2.year(), 2023);
+        assert_eq!(pages[0].2.day(), 1);
+        assert_eq!(pages[2].2, AccessDate::today());
+    }
+
+    #[test]
+    fn test_read_bookmarks_reports_skipped_and_coerced() {
+        let report = read_bookmarks(BOOKMARKS);
+
+        assert_eq!(report.citations.len(), 3);
+        let issues: Vec<(Option<&str>, &ImportIssue)> = report
+            .warnings
+            .iter()
+            .map(|warning| (warning.entry.as_deref(), &warning.issue))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (
+                    Some("https://example.com/notes"),
+                    &ImportIssue::CoercedDate {
+                        original: String::new(),
+                        used: Some(AccessDate::today().date().to_string()),
+                    }
+                ),
+                (
+                    Some("javascript:alert(1)"),
+                    &ImportIssue::SkippedEntry {
+                        reason: "not an http(s) link".to_string(),
+                    }
+                ),
+            ]
+        );
+    }
+}
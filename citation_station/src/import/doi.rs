@@ -0,0 +1,544 @@
+use chrono::{Month, NaiveDate};
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        author::{AcademicAuthor, GenericAuthor, PersonName},
+        citation::Citation,
+        conference::ConferenceSeries,
+        date::PublishDate,
+        location::LocationData,
+        media::{
+            book::Book,
+            common::{CommonCitationData, ExtendedMetadata, Funder},
+            conference_paper::ConferencePaperOnline,
+            journal_article::JournalArticle,
+        },
+        page_range::{ArticleLocator, PageRange},
+        title::Title,
+        volume::{Issue, Volume},
+    },
+    import::{
+        ImportError, fetch_body, fetch_body_async, identifier::Identifier, isbn::normalize_isbn,
+        record_lookup,
+    },
+    net::{AsyncHttpClient, HttpClient},
+};
+
+const CROSSREF_WORKS_ENDPOINT: &str = "https://api.crossref.org/works/";
+
+const DOI_PREFIXES: [&str; 5] = [
+    "https://doi.org/",
+    "http://doi.org/",
+    "https://dx.doi.org/",
+    "http://dx.doi.org/",
+    "doi:",
+];
+
+/// Strip resolver prefixes and whitespace from a DOI and lowercase it.
+///
+/// DOIs are case-insensitive, so the lowercase form is used for
+/// comparisons and as the ID of imported citations. Returns `None` if the
+/// input doesn't look like a DOI.
+pub fn normalize_doi(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    let lowercase = trimmed.to_lowercase();
+    let doi = DOI_PREFIXES
+        .iter()
+        .find_map(|prefix| lowercase.strip_prefix(prefix))
+        .unwrap_or(&lowercase)
+        .trim();
+
+    let (prefix, suffix) = doi.split_once('/')?;
+    if prefix.starts_with("10.") && prefix.len() > 3 && !suffix.is_empty() {
+        Some(doi.to_string())
+    } else {
+        None
+    }
+}
+
+/// The Crossref REST API URL for a (normalized) DOI.
+pub fn crossref_work_url(doi: &str) -> String {
+    let mut url = String::from(CROSSREF_WORKS_ENDPOINT);
+    for c in doi.chars() {
+        match c {
+            '#' | '?' | '%' | ' ' => url.push_str(&format!("%{:02X}", c as u32)),
+            _ => url.push(c),
+        }
+    }
+    url
+}
+
+/// Fetch a single DOI from Crossref and convert it to a [`Citation`].
+/// Funders and licenses are kept in the citation's
+/// [`ExtendedMetadata`] when Crossref lists them.
+pub fn fetch_doi<C: HttpClient + ?Sized>(client: &C, doi: &str) -> Result<Citation, ImportError> {
+    let result = lookup_doi(client, doi);
+    record_lookup("crossref", &result);
+    result
+}
+
+/// [`fetch_doi`] without counting the lookup, for importers that resolve
+/// their identifier to a DOI and count the lookup as their own
+pub(crate) fn lookup_doi<C: HttpClient + ?Sized>(
+    client: &C,
+    doi: &str,
+) -> Result<Citation, ImportError> {
+    fetch_body(client, crossref_work_url(doi)).and_then(|body| citation_from_crossref(&body))
+}
+
+/// [`fetch_doi`] with an [`AsyncHttpClient`]
+pub async fn fetch_doi_async<C: AsyncHttpClient>(
+    client: &C,
+    doi: &str,
+) -> Result<Citation, ImportError> {
+    let result = lookup_doi_async(client, doi).await;
+    record_lookup("crossref", &result);
+    result
+}
+
+/// [`lookup_doi`] with an [`AsyncHttpClient`]
+pub(crate) async fn lookup_doi_async<C: AsyncHttpClient>(
+    client: &C,
+    doi: &str,
+) -> Result<Citation, ImportError> {
+    let body = fetch_body_async(client, crossref_work_url(doi)).await?;
+    citation_from_crossref(&body)
+}
+
+#[derive(Deserialize)]
+struct CrossrefEnvelope {
+    message: CrossrefWork,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CrossrefWork {
+    #[serde(rename = "DOI")]
+    doi: String,
+    #[serde(rename = "type")]
+    work_type: String,
+    #[serde(default)]
+    title: Vec<String>,
+    #[serde(default)]
+    subtitle: Vec<String>,
+    #[serde(default)]
+    author: Vec<CrossrefContributor>,
+    #[serde(default)]
+    container_title: Vec<String>,
+    volume: Option<String>,
+    issue: Option<String>,
+    page: Option<String>,
+    article_number: Option<String>,
+    #[serde(rename = "ISBN", default)]
+    isbn: Vec<String>,
+    published: Option<CrossrefDate>,
+    issued: Option<CrossrefDate>,
+    event: Option<CrossrefEvent>,
+    #[serde(default)]
+    funder: Vec<CrossrefFunder>,
+    #[serde(default)]
+    license: Vec<CrossrefLicense>,
+    publisher_location: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CrossrefFunder {
+    name: Option<String>,
+    #[serde(rename = "DOI")]
+    doi: Option<String>,
+    #[serde(default)]
+    award: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CrossrefLicense {
+    #[serde(rename = "URL")]
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CrossrefContributor {
+    given: Option<String>,
+    family: Option<String>,
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CrossrefDate {
+    date_parts: Vec<Vec<Option<i64>>>,
+}
+
+#[derive(Deserialize)]
+struct CrossrefEvent {
+    name: Option<String>,
+    start: Option<CrossrefDate>,
+}
+
+impl CrossrefDate {
+    fn parts(&self) -> (Option<i32>, Option<u32>, Option<u32>) {
+        let parts = self.date_parts.first();
+        let part = |index: usize| parts.and_then(|parts| parts.get(index).copied().flatten());
+        (
+            part(0).and_then(|year| i32::try_from(year).ok()),
+            part(1).and_then(|month| u32::try_from(month).ok()),
+            part(2).and_then(|day| u32::try_from(day).ok()),
+        )
+    }
+
+    fn to_publish_date(&self) -> Option<PublishDate> {
+        let (year, month, day) = self.parts();
+        let year = year?;
+        let Some(month) = month.and_then(|month| Month::try_from(month as u8).ok()) else {
+            return Some(PublishDate::from_year(year));
+        };
+        match day {
+            Some(day) => Some(
+                PublishDate::from_year_month_day(year, month, day)
+                    .unwrap_or(PublishDate::from_year_month(year, month)),
+            ),
+            None => Some(PublishDate::from_year_month(year, month)),
+        }
+    }
+
+    fn to_naive_date(&self) -> Option<NaiveDate> {
+        let (year, month, day) = self.parts();
+        NaiveDate::from_ymd_opt(year?, month.unwrap_or(1), day.unwrap_or(1))
+    }
+}
+
+impl CrossrefContributor {
+    fn to_person_name(&self) -> Option<PersonName> {
+        let family = self.family.as_deref()?.trim();
+        let given = self.given.as_deref().unwrap_or("").trim();
+        match given.split_once(char::is_whitespace) {
+            Some((first, middle)) => {
+                PersonName::from_first_middle_last(first, middle.trim(), family).ok()
+            }
+            None if given.is_empty() => PersonName::from_last(family).ok(),
+            None => PersonName::from_first_last(given, family).ok(),
+        }
+    }
+}
+
+/// Authors of a work, either as people or a single organization name.
+fn contributors(work: &CrossrefWork) -> Result<Vec<PersonName>, String> {
+    let persons: Vec<PersonName> = work
+        .author
+        .iter()
+        .filter_map(CrossrefContributor::to_person_name)
+        .collect();
+    if persons.is_empty()
+        && let Some(name) = work.author.iter().find_map(|author| author.name.clone())
+    {
+        return Err(name);
+    }
+    Ok(persons)
+}
+
+/// Funders, license URLs and the place of publication, which Crossref
+/// lists for many works. License URLs are deduplicated, since works often
+/// list one per content version.
+fn extended_metadata(work: &CrossrefWork) -> ExtendedMetadata {
+    let mut licenses: Vec<String> = Vec::new();
+    for url in work
+        .license
+        .iter()
+        .filter_map(|license| license.url.clone())
+    {
+        if !licenses.contains(&url) {
+            licenses.push(url);
+        }
+    }
+    ExtendedMetadata {
+        funders: work
+            .funder
+            .iter()
+            .filter_map(|funder| {
+                Some(Funder {
+                    name: funder.name.clone()?,
+                    doi: funder.doi.clone(),
+                    awards: funder.award.clone(),
+                })
+            })
+            .collect(),
+        licenses,
+        publisher: None,
+        place: work
+            .publisher_location
+            .as_deref()
+            .and_then(LocationData::parse),
+    }
+}
+
+/// Convert a Crossref `/works/{doi}` JSON response into a [`Citation`].
+///
+/// Journal articles, books, and proceedings articles are supported. The
+/// normalized DOI is used as the citation ID.
+pub fn citation_from_crossref(json: &str) -> Result<Citation, ImportError> {
+    let envelope: CrossrefEnvelope =
+        serde_json::from_str(json).map_err(|err| ImportError::Parse(err.to_string()))?;
+    let work = envelope.message;
+
+    let doi = normalize_doi(&work.doi).ok_or(ImportError::InvalidIdentifier(work.doi.clone()))?;
+    let main = work
+        .title
+        .first()
+        .cloned()
+        .ok_or(ImportError::MissingField("title".to_string()))?;
+    let title = match work.subtitle.first() {
+        Some(subtitle) => Title::with_subtitle(main, subtitle),
+        None => Title::new(main),
+    };
+    let published = work
+        .published
+        .as_ref()
+        .or(work.issued.as_ref())
+        .and_then(CrossrefDate::to_publish_date);
+    let common_data = CommonCitationData {
+        published,
+        identifiers: work
+            .isbn
+            .iter()
+            .find_map(|isbn| normalize_isbn(isbn))
+            .map(Identifier::Isbn)
+            .into_iter()
+            .collect(),
+        extended: extended_metadata(&work),
+        ..CommonCitationData::new(doi.clone())
+    };
+    let container_title = work.container_title.first().cloned();
+    let pages = work.page.as_deref().and_then(PageRange::parse);
+
+    match work.work_type.as_str() {
+        "journal-article" => Ok(Citation::JournalArticle(JournalArticle {
+            common_data,
+            author: match contributors(&work) {
+                Ok(persons) => AcademicAuthor::Persons { persons },
+                Err(name) => AcademicAuthor::Organization {
+                    name,
+                    abbreviation: None,
+                },
+            },
+            title,
+            journal: container_title
+                .ok_or(ImportError::MissingField("container-title".to_string()))?,
+            volume: work.volume.as_deref().map(Volume::parse),
+            number: work.issue.as_deref().map(Issue::parse),
+            locator: work
+                .page
+                .as_deref()
+                .or(work.article_number.as_deref())
+                .and_then(ArticleLocator::parse),
+            doi: Some(doi),
+        })),
+        "book" | "monograph" | "edited-book" | "reference-book" => Ok(Citation::Book(Book {
+            common_data,
+            author: match contributors(&work) {
+                Ok(persons) => GenericAuthor::Persons { persons },
+                Err(name) => GenericAuthor::Organization {
+                    name,
+                    abbreviation: None,
+                },
+            },
+            with_authors: Vec::new(),
+            title,
+            chapter: None,
+            version: None,
+            doi: Some(doi),
+            pages,
+        })),
+        "proceedings-article" => {
+            let event_name = work.event.as_ref().and_then(|event| event.name.clone());
+            let conference_date = work
+                .event
+                .as_ref()
+                .and_then(|event| event.start.as_ref())
+                .or(work.published.as_ref())
+                .or(work.issued.as_ref())
+                .and_then(CrossrefDate::to_naive_date)
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .ok_or(ImportError::MissingField("event.start".to_string()))?
+                .and_utc();
+            Ok(Citation::ConferencePaperOnline(ConferencePaperOnline {
+                common_data,
+                title,
+                conference_name: event_name
+                    .or(container_title.clone())
+                    .ok_or(ImportError::MissingField("event.name".to_string()))?,
+                series: ConferenceSeries::default(),
+                venue: container_title,
+                volume: work.volume.as_deref().map(Volume::parse),
+                number: work.issue.as_deref().map(Issue::parse),
+                conference_date,
+            }))
+        }
+        other => Err(ImportError::UnsupportedType(other.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::AcademicAuthor,
+            citation::Citation,
+            date::PublishDate,
+            location::LocationData,
+            media::common::Funder,
+            page_range::{ArticleLocator, PageRange},
+            style::CitationStyle,
+        },
+        import::{
+            ImportError,
+            doi::{citation_from_crossref, crossref_work_url, normalize_doi},
+        },
+    };
+
+    const JOURNAL_ARTICLE_JSON: &str = r#"{
+        "status": "ok",
+        "message": {
+            "DOI": "10.1000/XYZ123",
+            "type": "journal-article",
+            "title": ["A great paper"],
+            "author": [
+                {"given": "Jane Q.", "family": "Smith"},
+                {"given": "Humberto", "family": "Fuentes"}
+            ],
+            "container-title": ["Journal of Examples"],
+            "volume": "12",
+            "issue": "3",
+            "page": "100-110",
+            "published": {"date-parts": [[2023, 3]]}
+        }
+    }"#;
+
+    #[test]
+    fn test_normalize_doi() {
+        assert_eq!(
+            normalize_doi(" https://doi.org/10.1000/XYZ123 "),
+            Some("10.1000/xyz123".to_string())
+        );
+        assert_eq!(
+            normalize_doi("doi:10.1000/abc"),
+            Some("10.1000/abc".to_string())
+        );
+        assert_eq!(normalize_doi("not a doi"), None);
+        assert_eq!(normalize_doi("10./abc"), None);
+        assert_eq!(normalize_doi("10.1000/"), None);
+    }
+
+    #[test]
+    fn test_crossref_work_url_escapes_reserved_characters() {
+        assert_eq!(
+            crossref_work_url("10.1000/a#b"),
+            "https://api.crossref.org/works/10.1000/a%23b"
+        );
+    }
+
+    #[test]
+    fn test_journal_article_from_crossref() {
+        let Citation::JournalArticle(article) =
+            citation_from_crossref(JOURNAL_ARTICLE_JSON).unwrap()
+        else {
+            panic!("expected a journal article");
+        };
+
+        assert_eq!(article.common_data.id, "10.1000/xyz123");
+        assert_eq!(
+            article.common_data.published,
+            Some(PublishDate::from_year_month(2023, Month::March))
+        );
+        assert_eq!(article.journal, "Journal of Examples");
+        assert_eq!(
+            article.locator,
+            Some(ArticleLocator::Pages(PageRange {
+                start: 100,
+                end: 110
+            }))
+        );
+        let AcademicAuthor::Persons { persons } = article.author else {
+            panic!("expected persons");
+        };
+        assert_eq!(persons.len(), 2);
+        assert_eq!(persons[0].as_ieee_string(), "J. Q. Smith");
+    }
+
+    #[test]
+    fn test_book_from_crossref() {
+        let json = r#"{"message": {
+            "DOI": "10.1000/book",
+            "type": "book",
+            "title": ["Big Book"],
+            "author": [{"name": "The Corporation"}],
+            "issued": {"date-parts": [[2001]]},
+            "publisher-location": "Cambridge, Massachusetts"
+        }}"#;
+
+        let citation = citation_from_crossref(json).unwrap();
+
+        assert_eq!(citation.title(), "Big Book");
+        assert_eq!(citation.published(), Some(PublishDate::from_year(2001)));
+        assert_eq!(citation.doi(), Some("10.1000/book".to_string()));
+        assert_eq!(
+            citation.common_data().extended.place,
+            Some(LocationData::new("Cambridge", "US").with_state("MA"))
+        );
+    }
+
+    #[test]
+    fn test_funders_and_licenses_from_crossref() {
+        let json = r#"{"message": {
+            "DOI": "10.1000/funded",
+            "type": "book",
+            "title": ["Funded Book"],
+            "funder": [
+                {"name": "National Science Foundation", "DOI": "10.13039/100000001",
+                 "award": ["1234567"]},
+                {"DOI": "10.13039/nameless"}
+            ],
+            "license": [
+                {"URL": "https://creativecommons.org/licenses/by/4.0/", "content-version": "vor"},
+                {"URL": "https://creativecommons.org/licenses/by/4.0/", "content-version": "am"}
+            ]
+        }}"#;
+
+        let citation = citation_from_crossref(json).unwrap();
+
+        assert_eq!(
+            citation.funders(),
+            [Funder {
+                name: "National Science Foundation".to_string(),
+                doi: Some("10.13039/100000001".to_string()),
+                awards: vec!["1234567".to_string()],
+            }]
+        );
+        assert_eq!(
+            citation.licenses(),
+            ["https://creativecommons.org/licenses/by/4.0/"]
+        );
+        // Never part of a formatted citation
+        assert!(!citation.format(CitationStyle::Apa).contains("Science"));
+    }
+
+    #[test]
+    fn test_unsupported_type_from_crossref() {
+        let json = r#"{"message": {"DOI": "10.1000/ds", "type": "dataset", "title": ["Data"]}}"#;
+
+        assert_eq!(
+            citation_from_crossref(json),
+            Err(ImportError::UnsupportedType("dataset".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_json_from_crossref() {
+        assert!(matches!(
+            citation_from_crossref("<html>"),
+            Err(ImportError::Parse(_))
+        ));
+    }
+}
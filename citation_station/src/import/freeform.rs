@@ -0,0 +1,736 @@
+//! Best-effort parsing of formatted reference strings, for old documents
+//! whose bibliography only survives as text.
+//!
+//! The style is guessed from cues such as a parenthesized year after the
+//! authors (APA), a leading "[1]" and initials before surnames (IEEE), or
+//! full given names and a quoted title ending in a period (MLA). Every
+//! extracted field comes with a confidence between 0 and 1, so callers can
+//! ask the user to check the doubtful ones.
+
+use crate::{
+    api::{
+        author::{AcademicAuthor, GenericAuthor, PersonName},
+        citation::Citation,
+        citation_id::CitationId,
+        date::PublishDate,
+        location::LocationData,
+        media::{book::Book, common::CommonCitationData, journal_article::JournalArticle},
+        page_range::ArticleLocator,
+        title::Title,
+        volume::{Issue, Volume},
+    },
+    import::doi::normalize_doi,
+};
+
+/// A citation style recognized by [`parse_freeform`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DetectedStyle {
+    Apa,
+    Ieee,
+    Mla,
+}
+
+/// A field [`parse_freeform`] extracts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ParsedField {
+    Authors,
+    Year,
+    Title,
+    /// The journal an article appeared in
+    Container,
+    Volume,
+    Issue,
+    Pages,
+    Doi,
+    /// A book's publisher, when it follows the place of publication
+    Publisher,
+    /// A book's place of publication, e.g. "Cambridge, MA"
+    Place,
+}
+
+/// The result of [`parse_freeform`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FreeformParse {
+    /// A journal article if a journal was found, a book otherwise
+    pub citation: Citation,
+    pub style: DetectedStyle,
+    /// How sure the style guess is, between 0 and 1
+    pub style_confidence: f32,
+    /// The fields that were found, with how sure each one is
+    pub fields: Vec<(ParsedField, f32)>,
+}
+
+impl FreeformParse {
+    /// Confidence in `field`, or `None` if it wasn't found
+    pub fn confidence(&self, field: ParsedField) -> Option<f32> {
+        self.fields
+            .iter()
+            .find(|(found, _)| *found == field)
+            .map(|(_, confidence)| *confidence)
+    }
+}
+
+/// Fields found so far, before they are assembled into a citation
+#[derive(Default)]
+struct Fields {
+    authors: Vec<PersonName>,
+    year: Option<i32>,
+    title: Option<String>,
+    container: Option<String>,
+    volume: Option<String>,
+    issue: Option<String>,
+    pages: Option<String>,
+    doi: Option<String>,
+    publisher: Option<String>,
+    place: Option<LocationData>,
+    confidence: Vec<(ParsedField, f32)>,
+}
+
+impl Fields {
+    fn found(&mut self, field: ParsedField, confidence: f32) {
+        self.confidence.retain(|(found, _)| *found != field);
+        self.confidence.push((field, confidence));
+    }
+
+    /// Take a "City, Country: Publisher" imprint from the start of `text`,
+    /// returning the rest. The place must name a country or US state, so
+    /// that a journal with a colon in its name isn't taken for one.
+    fn imprint<'a>(&mut self, text: &'a str) -> &'a str {
+        let text = text.trim();
+        let Some((place, rest)) = text.split_once(": ") else {
+            return text;
+        };
+        let Some(place) = LocationData::parse(place).filter(|place| place.find_country().is_some())
+        else {
+            return text;
+        };
+        self.place = Some(place);
+        self.found(ParsedField::Place, 0.8);
+        let (publisher, rest) = rest.split_once(", ").unwrap_or((rest, ""));
+        let publisher = trim_punctuation(publisher);
+        if !publisher.is_empty() && find_year(publisher).is_none() {
+            self.publisher = Some(publisher.to_string());
+            self.found(ParsedField::Publisher, 0.7);
+        }
+        rest
+    }
+
+    /// Read the comma-separated tail of a reference: "J. Tests, vol. 12,
+    /// no. 3, pp. 3–9, 2020". The first segment that isn't a volume, issue,
+    /// page range or date is the container if `container` is true.
+    fn segments(&mut self, text: &str, container: bool) {
+        let mut container = container;
+        for segment in text.split(", ").map(trim_punctuation) {
+            if let Some(volume) = strip_label(segment, "vol. ") {
+                self.volume = Some(volume.trim().to_string());
+                self.found(ParsedField::Volume, 0.9);
+            } else if let Some(issue) = strip_label(segment, "no. ") {
+                self.issue = Some(issue.trim().to_string());
+                self.found(ParsedField::Issue, 0.9);
+            } else if let Some(pages) = ["pp. ", "p. "]
+                .iter()
+                .find_map(|prefix| strip_label(segment, prefix))
+            {
+                self.pages = Some(pages.trim().to_string());
+                self.found(ParsedField::Pages, 0.9);
+            } else if let Some(year) = find_year(segment) {
+                if self.year.is_none() {
+                    self.year = Some(year);
+                    self.found(ParsedField::Year, 0.8);
+                }
+            } else if container && !segment.is_empty() {
+                self.container = Some(segment.to_string());
+                self.found(ParsedField::Container, 0.7);
+                container = false;
+            }
+        }
+    }
+}
+
+/// `segment` without a leading `label` such as "vol. ", in any case.
+/// The rest keeps its case: "Vol. XII" gives "XII".
+fn strip_label<'a>(segment: &'a str, label: &str) -> Option<&'a str> {
+    let start = segment.get(..label.len())?;
+    start
+        .eq_ignore_ascii_case(label)
+        .then(|| &segment[label.len()..])
+}
+
+/// Parse a reference formatted in APA, IEEE or MLA style, e.g.
+/// "Smith, J. (2023). A great paper. MIT Press."
+///
+/// Returns `None` if no title could be found. A place of publication
+/// followed by a publisher, as in "Cambridge, MA: MIT Press", is kept;
+/// publishers on their own are only recognized so they aren't mistaken for
+/// other fields.
+pub fn parse_freeform(text: &str) -> Option<FreeformParse> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let (text, numbered) = match text
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once("] "))
+    {
+        Some((number, rest)) if number.chars().all(|c| c.is_ascii_digit()) => {
+            (rest.to_string(), true)
+        }
+        _ => (text, false),
+    };
+
+    let mut fields = Fields::default();
+    let text = take_doi(&text, &mut fields);
+
+    let (style, style_confidence) = detect_style(&text, numbered);
+    match style {
+        DetectedStyle::Apa => parse_apa(&text, &mut fields),
+        DetectedStyle::Ieee => parse_ieee(&text, &mut fields),
+        DetectedStyle::Mla => parse_mla(&text, &mut fields),
+    }
+    if !fields.authors.is_empty() {
+        fields.found(ParsedField::Authors, 0.8);
+    }
+
+    let citation = citation_from(&fields)?;
+    let mut found = fields.confidence;
+    found.sort_by_key(|(field, _)| *field);
+    Some(FreeformParse {
+        citation,
+        style,
+        style_confidence,
+        fields: found,
+    })
+}
+
+/// Score the cues for each style and pick the best one
+fn detect_style(text: &str, numbered: bool) -> (DetectedStyle, f32) {
+    let mut apa: f32 = 0.0;
+    let mut ieee: f32 = 0.0;
+    let mut mla: f32 = 0.0;
+
+    if numbered {
+        ieee += 3.0;
+    }
+    if paren_year(text).is_some() {
+        apa += 3.0;
+    }
+    if text.contains(" & ") {
+        apa += 1.0;
+    }
+    if let Some((_, title, _)) = quoted(text) {
+        if title.ends_with(',') {
+            ieee += 2.0;
+        } else if title.ends_with('.') {
+            mla += 2.0;
+        }
+    }
+    let first_word = text.split_whitespace().next().unwrap_or_default();
+    if is_initial(first_word) {
+        ieee += 2.0;
+    } else if let Some((_, given)) = text.split_once(", ") {
+        let given = given.split_whitespace().next().unwrap_or_default();
+        if is_initial(given) {
+            apa += 1.0;
+        } else if given.trim_end_matches([',', '.']).chars().count() > 1 {
+            mla += 2.0;
+        }
+    }
+
+    let total = apa + ieee + mla;
+    let (style, score) = [
+        (DetectedStyle::Apa, apa),
+        (DetectedStyle::Ieee, ieee),
+        (DetectedStyle::Mla, mla),
+    ]
+    .into_iter()
+    .fold((DetectedStyle::Apa, 0.0), |best, candidate| {
+        if candidate.1 > best.1 {
+            candidate
+        } else {
+            best
+        }
+    });
+    if total == 0.0 {
+        (style, 0.0)
+    } else {
+        (style, score / total)
+    }
+}
+
+/// "Smith, J., & Fuentes, H. (2020). Title. Journal, 12(3), 3–9."
+fn parse_apa(text: &str, fields: &mut Fields) {
+    let Some((open, close, year)) = paren_year(text) else {
+        return parse_mla(text, fields);
+    };
+    fields.year = Some(year);
+    fields.found(ParsedField::Year, 0.95);
+
+    let names = text[..open].replace('&', "");
+    let parts: Vec<&str> = names
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty() && *part != "...")
+        .collect();
+    fields.authors = parts
+        .chunks(2)
+        .filter_map(|name| match name {
+            [surname, initials] => {
+                let initials: Vec<&str> = initials
+                    .split_whitespace()
+                    .map(|initial| initial.trim_end_matches('.'))
+                    .collect();
+                match initials.as_slice() {
+                    [] => PersonName::from_last(surname).ok(),
+                    [first] => PersonName::from_first_last(first, surname).ok(),
+                    [first, middle @ ..] => {
+                        PersonName::from_first_middle_last(first, &middle.join(" "), surname).ok()
+                    }
+                }
+            }
+            [surname] => PersonName::from_last(surname).ok(),
+            _ => None,
+        })
+        .collect();
+
+    let rest = text[close..].trim_start_matches('.').trim();
+    let (title, rest) = split_sentence(rest);
+    set_title(fields, title, 0.8);
+
+    let rest = fields.imprint(rest);
+    let mut segments = rest.split(", ").map(trim_punctuation);
+    let Some(first) = segments.next() else {
+        return;
+    };
+    let Some(volume_issue) = segments.next() else {
+        // A book's publisher
+        return;
+    };
+    fields.container = Some(first.to_string());
+    fields.found(ParsedField::Container, 0.8);
+    let (volume, issue) = match volume_issue.split_once('(') {
+        Some((volume, issue)) => (volume, Some(issue.trim_end_matches(')'))),
+        None => (volume_issue, None),
+    };
+    if !volume.trim().is_empty() {
+        fields.volume = Some(volume.trim().to_string());
+        fields.found(ParsedField::Volume, 0.8);
+    }
+    if let Some(issue) = issue {
+        fields.issue = Some(issue.to_string());
+        fields.found(ParsedField::Issue, 0.8);
+    }
+    if let Some(pages) = segments.next() {
+        fields.pages = Some(pages.to_string());
+        fields.found(ParsedField::Pages, 0.8);
+    }
+}
+
+/// "J. Smith and H. Fuentes, “Title,” J. Tests, vol. 12, no. 3, pp. 3–9,
+/// Mar. 2020." or "J. Smith, Title. City: Publisher, 2020."
+fn parse_ieee(text: &str, fields: &mut Fields) {
+    if let Some((start, title, end)) = quoted(text) {
+        fields.authors = ieee_names(text[..start].trim().trim_end_matches(','));
+        set_title(fields, title, 0.9);
+        fields.segments(&text[end..], true);
+        return;
+    }
+
+    let segments: Vec<&str> = text.split(", ").collect();
+    let names = segments
+        .iter()
+        .take_while(|segment| is_ieee_name(segment))
+        .count();
+    fields.authors = ieee_names(&segments[..names].join(", "));
+    let (title, rest) = split_sentence(&text[segments[..names].join(", ").len()..]);
+    set_title(
+        fields,
+        title.trim_start_matches(',').trim().trim_end_matches(','),
+        0.6,
+    );
+    let rest = fields.imprint(rest);
+    fields.segments(rest, false);
+}
+
+/// "Smith, John, and Jane Doe. “Title.” Journal, vol. 12, no. 3, 2020,
+/// pp. 3–9." or "Smith, John. Title. Publisher, 2020."
+fn parse_mla(text: &str, fields: &mut Fields) {
+    let (names, rest) = split_sentence(text);
+    let names = names
+        .trim_end_matches("et al")
+        .trim_end_matches(|c: char| c == ',' || c.is_whitespace());
+    fields.authors = names
+        .split(", and ")
+        .flat_map(|part| part.split(" and "))
+        .enumerate()
+        .filter_map(|(i, name)| match i {
+            // Only the first author is inverted
+            0 => PersonName::parse(name).ok(),
+            _ => PersonName::parse(&name.replace(',', "")).ok(),
+        })
+        .collect();
+
+    match quoted(rest) {
+        Some((0, title, end)) => {
+            set_title(fields, title, 0.9);
+            fields.segments(&rest[end..], true);
+        }
+        _ => {
+            let (title, rest) = split_sentence(rest);
+            set_title(fields, title, 0.7);
+            let rest = fields.imprint(rest);
+            fields.segments(rest, false);
+        }
+    }
+}
+
+fn set_title(fields: &mut Fields, title: &str, confidence: f32) {
+    let title = title.trim().trim_end_matches([',', '.']).trim();
+    if !title.is_empty() {
+        fields.title = Some(title.to_string());
+        fields.found(ParsedField::Title, confidence);
+    }
+}
+
+fn citation_from(fields: &Fields) -> Option<Citation> {
+    let title = Title::parse(fields.title.as_ref()?);
+    let surname = fields
+        .authors
+        .first()
+        .and_then(|author| CitationId::slug(author.surname()));
+    let id = match (surname, fields.year) {
+        _ if fields.doi.is_some() => fields.doi.clone().unwrap_or_default(),
+        (Some(surname), Some(year)) => format!("{}{}", surname, year),
+        (Some(surname), None) => surname.into_string(),
+        (None, _) => CitationId::slug(&title.to_string())
+            .map_or_else(|| "untitled".to_string(), CitationId::into_string),
+    };
+    let mut common_data = CommonCitationData {
+        published: fields.year.map(PublishDate::from_year),
+        ..CommonCitationData::new(id)
+    };
+    common_data.extended.publisher = fields.publisher.clone();
+    common_data.extended.place = fields.place.clone();
+
+    Some(match &fields.container {
+        Some(journal) => Citation::JournalArticle(JournalArticle {
+            common_data,
+            author: AcademicAuthor::Persons {
+                persons: fields.authors.clone(),
+            },
+            title,
+            journal: journal.clone(),
+            volume: fields.volume.as_deref().map(Volume::parse),
+            number: fields.issue.as_deref().map(Issue::parse),
+            locator: fields.pages.as_deref().and_then(ArticleLocator::parse),
+            doi: fields.doi.clone(),
+        }),
+        None => Citation::Book(Book {
+            common_data,
+            author: GenericAuthor::Persons {
+                persons: fields.authors.clone(),
+            },
+            with_authors: Vec::new(),
+            title,
+            chapter: None,
+            version: None,
+            doi: fields.doi.clone(),
+            pages: None,
+        }),
+    })
+}
+
+/// Remove a DOI from `text`, recording it in `fields`
+fn take_doi(text: &str, fields: &mut Fields) -> String {
+    let words: Vec<&str> = text.split(' ').collect();
+    let position = words.iter().position(|word| {
+        let lowercase = word.to_lowercase();
+        lowercase.starts_with("10.")
+            || lowercase.starts_with("doi:")
+            || lowercase.contains("doi.org/")
+    });
+    let Some(position) = position else {
+        return text.to_string();
+    };
+    let word = words[position].trim_end_matches(['.', ',']);
+    // "doi: 10.1000/xyz" keeps the DOI in the next word
+    let word = match word.to_lowercase().as_str() {
+        "doi:" => words
+            .get(position + 1)
+            .map_or("", |next| next.trim_end_matches('.')),
+        _ => word,
+    };
+    let Some(doi) = normalize_doi(word) else {
+        return text.to_string();
+    };
+    fields.doi = Some(doi);
+    fields.found(ParsedField::Doi, 0.95);
+    let mut rest = words[..position].join(" ");
+    let trimmed = rest.trim_end_matches([' ', ',', '.']).len();
+    rest.truncate(trimmed);
+    rest.push('.');
+    rest
+}
+
+/// The position of "(2020)" or "(2020, March 5)" and the year in it
+fn paren_year(text: &str) -> Option<(usize, usize, i32)> {
+    text.match_indices('(').find_map(|(open, _)| {
+        let close = open + text[open..].find(')')?;
+        let inner = &text[open + 1..close];
+        let year = inner.split(',').next()?.trim();
+        let year = year
+            .strip_suffix(|c: char| c.is_ascii_lowercase())
+            .unwrap_or(year);
+        (year.len() == 4)
+            .then(|| year.parse().ok())
+            .flatten()
+            .map(|year| (open, close + 1, year))
+    })
+}
+
+/// The first quoted passage: its start, the text inside and the end
+fn quoted(text: &str) -> Option<(usize, &str, usize)> {
+    let (start, open) = text
+        .char_indices()
+        .find(|(_, c)| matches!(c, '\u{201C}' | '"'))?;
+    let inner = start + open.len_utf8();
+    let (end, close) = text[inner..]
+        .char_indices()
+        .find(|(_, c)| matches!(c, '\u{201D}' | '"'))?;
+    Some((
+        start,
+        &text[inner..inner + end],
+        inner + end + close.len_utf8(),
+    ))
+}
+
+/// Split at the end of the first sentence, not counting the periods of
+/// initials like "J." and abbreviations like "vol.". A "?" or "!" ending
+/// the sentence stays with it.
+fn split_sentence(text: &str) -> (&str, &str) {
+    let text = text.trim();
+    for (i, c) in text.char_indices() {
+        if !matches!(c, '.' | '?' | '!') {
+            continue;
+        }
+        let next = i + c.len_utf8();
+        if !text[next..].is_empty() && !text[next..].starts_with(' ') {
+            continue;
+        }
+        let word = text[..i].rsplit(' ').next().unwrap_or_default();
+        if c == '.' && (is_initial(&format!("{}.", word)) || ABBREVIATIONS.contains(&word)) {
+            continue;
+        }
+        let end = if c == '.' { i } else { next };
+        return (&text[..end], text[next..].trim());
+    }
+    (text.trim_end_matches('.'), "")
+}
+
+const ABBREVIATIONS: [&str; 8] = ["vol", "no", "pp", "p", "ed", "eds", "al", "Proc"];
+
+/// "J." or "J.-P."
+fn is_initial(word: &str) -> bool {
+    let Some(letters) = word.strip_suffix('.') else {
+        return false;
+    };
+    letters
+        .split(".-")
+        .all(|letter| letter.chars().count() == 1 && letter.chars().all(char::is_uppercase))
+}
+
+/// "J. Smith", "and H. Fuentes" or "J.-P. Sartre"
+fn is_ieee_name(segment: &&str) -> bool {
+    let words: Vec<&str> = segment
+        .trim_start_matches("and ")
+        .split_whitespace()
+        .collect();
+    match words.as_slice() {
+        [initials @ .., surname] if !initials.is_empty() => {
+            initials.iter().all(|word| is_initial(word))
+                && surname.chars().next().is_some_and(char::is_uppercase)
+        }
+        _ => false,
+    }
+}
+
+fn ieee_names(text: &str) -> Vec<PersonName> {
+    text.split(", ")
+        .flat_map(|part| part.split(" and "))
+        .map(|name| name.trim().trim_start_matches("and "))
+        .filter(|name| !name.is_empty() && *name != "et al.")
+        .filter_map(|name| PersonName::parse(name).ok())
+        .collect()
+}
+
+/// A plausible publication year in `text`: "2020" or "Mar. 2020"
+fn find_year(text: &str) -> Option<i32> {
+    // Page ranges like "1990-2001" also start with four digits
+    if text.starts_with(|c: char| c.is_ascii_digit()) && text.len() != 4 {
+        return None;
+    }
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter(|digits| digits.len() == 4)
+        .filter_map(|digits| digits.parse().ok())
+        .find(|year| (1000..=2999).contains(year))
+}
+
+fn trim_punctuation(text: &str) -> &str {
+    text.trim().trim_end_matches(['.', ',']).trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            citation::Citation,
+            date::PublishDate,
+            location::LocationData,
+            page_range::{ArticleLocator, PageRange},
+        },
+        import::freeform::{DetectedStyle, ParsedField, parse_freeform},
+    };
+
+    #[test]
+    fn test_apa_book() {
+        let parsed = parse_freeform("Smith, J. (2023). A Great Paper. MIT Press.").unwrap();
+
+        assert_eq!(parsed.style, DetectedStyle::Apa);
+        assert_eq!(parsed.citation.id(), "smith2023");
+        assert_eq!(parsed.citation.title(), "A Great Paper");
+        assert_eq!(parsed.confidence(ParsedField::Year), Some(0.95));
+        assert!(matches!(parsed.citation, Citation::Book(_)));
+        assert_eq!(
+            parsed.citation.format_apa(),
+            "Smith, J. (2023). A Great Paper."
+        );
+    }
+
+    #[test]
+    fn test_book_imprint() {
+        let parsed =
+            parse_freeform("Smith, J. (2023). A great book. Cambridge, MA: MIT Press.").unwrap();
+
+        assert!(matches!(parsed.citation, Citation::Book(_)));
+        let extended = &parsed.citation.common_data().extended;
+        assert_eq!(extended.publisher.as_deref(), Some("MIT Press"));
+        assert_eq!(
+            extended.place,
+            Some(LocationData::new("Cambridge", "US").with_state("MA"))
+        );
+
+        let parsed =
+            parse_freeform("[1] J. Smith, Rust in Action. London, U.K.: Example Press, 2021.")
+                .unwrap();
+
+        assert_eq!(parsed.style, DetectedStyle::Ieee);
+        assert_eq!(
+            parsed.citation.published(),
+            Some(PublishDate::from_year(2021))
+        );
+        assert_eq!(
+            parsed.citation.common_data().extended.place,
+            Some(LocationData::new("London", "GB"))
+        );
+        assert_eq!(parsed.confidence(ParsedField::Publisher), Some(0.7));
+    }
+
+    #[test]
+    fn test_apa_article() {
+        let parsed = parse_freeform(
+            "Smith, J. A., & Fuentes, H. (2020). Repairing DNA. J. Tests, 12(3), 3\u{2013}9. \
+             https://doi.org/10.1000/XYZ",
+        )
+        .unwrap();
+
+        let Citation::JournalArticle(article) = &parsed.citation else {
+            panic!("expected an article, got {:?}", parsed.citation);
+        };
+        assert_eq!(article.journal, "J. Tests");
+        assert_eq!(article.volume.as_ref().unwrap().to_string(), "12");
+        assert_eq!(article.number.as_ref().unwrap().to_string(), "3");
+        assert_eq!(
+            article.locator,
+            Some(ArticleLocator::Pages(PageRange { start: 3, end: 9 }))
+        );
+        assert_eq!(article.doi.as_deref(), Some("10.1000/xyz"));
+        assert_eq!(parsed.citation.persons().len(), 2);
+        assert_eq!(parsed.citation.title(), "Repairing DNA");
+    }
+
+    #[test]
+    fn test_ieee_article() {
+        let parsed = parse_freeform(
+            "[4] J. Smith and H. Fuentes, \u{201C}Repairing DNA,\u{201D} J. Tests, vol. 12, \
+             no. 3, pp. 3\u{2013}9, Mar. 2020.",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.style, DetectedStyle::Ieee);
+        assert!(parsed.style_confidence > 0.5);
+        let Citation::JournalArticle(article) = &parsed.citation else {
+            panic!("expected an article, got {:?}", parsed.citation);
+        };
+        assert_eq!(article.title.to_string(), "Repairing DNA");
+        assert_eq!(article.journal, "J. Tests");
+        assert_eq!(parsed.citation.id(), "smith2020");
+        assert_eq!(
+            parsed
+                .citation
+                .persons()
+                .iter()
+                .map(|person| person.full_name())
+                .collect::<Vec<_>>(),
+            ["J. Smith", "H. Fuentes"]
+        );
+    }
+
+    #[test]
+    fn test_mla_article() {
+        let parsed = parse_freeform(
+            "Smith, John, and Hugo Fuentes. \u{201C}Repairing DNA.\u{201D} Journal of Tests, \
+             vol. 12, no. 3, 2020, pp. 3-9.",
+        )
+        .unwrap();
+
+        assert_eq!(parsed.style, DetectedStyle::Mla);
+        assert_eq!(parsed.citation.title(), "Repairing DNA");
+        assert_eq!(parsed.citation.published().unwrap().year(), 2020);
+        assert_eq!(
+            parsed
+                .citation
+                .persons()
+                .iter()
+                .map(|person| person.full_name())
+                .collect::<Vec<_>>(),
+            ["John Smith", "Hugo Fuentes"]
+        );
+        assert_eq!(parsed.confidence(ParsedField::Container), Some(0.7));
+    }
+
+    #[test]
+    fn test_ieee_volume_keeps_case() {
+        let parsed = parse_freeform(
+            "[1] J. Smith, \u{201C}Über Rost,\u{201D} J. Tests, Vol. XII, No. 3A, pp. 3\u{2013}9, \
+             2020.",
+        )
+        .unwrap();
+
+        let Citation::JournalArticle(article) = &parsed.citation else {
+            panic!("expected an article, got {:?}", parsed.citation);
+        };
+        assert_eq!(article.volume.as_ref().unwrap().to_string(), "XII");
+        assert_eq!(article.number.as_ref().unwrap().to_string(), "3A");
+    }
+
+    #[test]
+    fn test_id_without_author_is_slug() {
+        let parsed =
+            parse_freeform("\u{201C}Deep Learning: A Survey,\u{201D} J. Tests, 2020.").unwrap();
+
+        assert_eq!(parsed.citation.id(), "deep-learning-a-survey");
+    }
+
+    #[test]
+    fn test_no_title() {
+        assert_eq!(parse_freeform(""), None);
+    }
+}
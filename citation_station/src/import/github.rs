@@ -0,0 +1,284 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        author::GenericAuthor,
+        date::{AccessDate, PublishDate},
+        media::{common::CommonCitationData, software::Software},
+    },
+    cff::authors_from_cff,
+    import::{ImportError, fetch_body, fetch_body_async, record_fallback, record_lookup},
+    net::{AsyncHttpClient, FetchError, HttpClient},
+};
+
+const GITHUB_API: &str = "https://api.github.com";
+const GITHUB_RAW: &str = "https://raw.githubusercontent.com";
+
+/// The GitHub API URL for a repository
+pub fn repo_api_url(owner: &str, repo: &str) -> String {
+    format!("{}/repos/{}/{}", GITHUB_API, owner, repo)
+}
+
+/// The GitHub API URL for a repository's latest release
+pub fn latest_release_api_url(owner: &str, repo: &str) -> String {
+    format!("{}/releases/latest", repo_api_url(owner, repo))
+}
+
+/// The URL of the CITATION.cff file on a repository's default branch
+pub fn citation_cff_url(owner: &str, repo: &str) -> String {
+    format!("{}/{}/{}/HEAD/CITATION.cff", GITHUB_RAW, owner, repo)
+}
+
+/// Import a GitHub repository as software.
+///
+/// The repository's canonical name and URL come from the GitHub API. The
+/// latest release, if there is one, provides the version and publish date.
+/// Authors are taken from the repository's CITATION.cff; without one, the
+/// account that owns the repository is credited. The citation ID is
+/// "github:owner/repo".
+pub fn fetch_github_repo<C: HttpClient + ?Sized>(
+    client: &C,
+    owner: &str,
+    repo: &str,
+) -> Result<Software, ImportError> {
+    let result = (|| {
+        let body = fetch_body(client, repo_api_url(owner, repo))?;
+        let release = optional(fetch_body(client, latest_release_api_url(owner, repo)))?;
+        let citation_cff = optional(fetch_body(client, citation_cff_url(owner, repo)))?;
+        if citation_cff.is_none() {
+            record_fallback("github");
+        }
+
+        software_from_github(
+            &body,
+            release.as_deref(),
+            citation_cff.as_deref(),
+            AccessDate::today(),
+        )
+    })();
+    record_lookup("github", &result);
+    result
+}
+
+/// [`fetch_github_repo`] with an [`AsyncHttpClient`]
+pub async fn fetch_github_repo_async<C: AsyncHttpClient>(
+    client: &C,
+    owner: &str,
+    repo: &str,
+) -> Result<Software, ImportError> {
+    let result = async {
+        let body = fetch_body_async(client, repo_api_url(owner, repo)).await?;
+        let release =
+            optional(fetch_body_async(client, latest_release_api_url(owner, repo)).await)?;
+        let citation_cff = optional(fetch_body_async(client, citation_cff_url(owner, repo)).await)?;
+        if citation_cff.is_none() {
+            record_fallback("github");
+        }
+
+        software_from_github(
+            &body,
+            release.as_deref(),
+            citation_cff.as_deref(),
+            AccessDate::today(),
+        )
+    }
+    .await;
+    record_lookup("github", &result);
+    result
+}
+
+/// Treat "404 Not Found" as an absent optional resource
+fn optional(fetched: Result<String, ImportError>) -> Result<Option<String>, ImportError> {
+    match fetched {
+        Ok(body) => Ok(Some(body)),
+        Err(ImportError::Fetch(FetchError::Status { status: 404, .. })) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[derive(Deserialize)]
+struct GitHubRepo {
+    full_name: String,
+    name: String,
+    html_url: String,
+    owner: GitHubOwner,
+}
+
+#[derive(Deserialize)]
+struct GitHubOwner {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    published_at: Option<DateTime<Utc>>,
+}
+
+/// Build a software citation from GitHub API responses for a repository
+/// and its latest release, and the text of its CITATION.cff.
+///
+/// A CITATION.cff that can't be read or lists no authors is ignored, since
+/// the repository can still be cited without it.
+pub fn software_from_github(
+    repo_json: &str,
+    release_json: Option<&str>,
+    citation_cff: Option<&str>,
+    accessed: AccessDate,
+) -> Result<Software, ImportError> {
+    let repo: GitHubRepo =
+        serde_json::from_str(repo_json).map_err(|err| ImportError::Parse(err.to_string()))?;
+    let release: Option<GitHubRelease> = release_json
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|err| ImportError::Parse(err.to_string()))?;
+
+    let author = citation_cff
+        .and_then(|cff| authors_from_cff(cff).ok().flatten())
+        .unwrap_or(GenericAuthor::Organization {
+            name: repo.owner.login,
+            abbreviation: None,
+        });
+    let version = release
+        .as_ref()
+        .map(|release| version_from_tag(&release.tag_name));
+    let published = release
+        .and_then(|release| release.published_at)
+        .map(PublishDate::from_chrono_utc_datetime);
+
+    Ok(Software {
+        common_data: CommonCitationData {
+            published,
+            ..CommonCitationData::new(format!("github:{}", repo.full_name))
+        },
+        author,
+        title: repo.name.into(),
+        version,
+        publisher: Some("GitHub".to_string()),
+        url: Some(repo.html_url),
+        doi: None,
+        accessed,
+    })
+}
+
+/// "v1.2.0" becomes "1.2.0"; other tags are kept as they are
+fn version_from_tag(tag: &str) -> String {
+    let tag = tag.trim();
+    match tag.strip_prefix(['v', 'V']) {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest.to_string(),
+        _ => tag.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveDate};
+
+    use crate::{
+        api::{
+            author::{GenericAuthor, PersonName},
+            date::PublishDate,
+            media::software::Software,
+        },
+        import::github::{software_from_github, version_from_tag},
+        net::{AsyncHttpClient, FetchError, HttpClient, HttpResponse, block_on},
+    };
+
+    const REPO: &str = r#"{"id": 1, "name": "citation_station",
+        "full_name": "example/citation_station",
+        "html_url": "https://github.com/example/citation_station",
+        "owner": {"login": "example", "type": "Organization"}}"#;
+
+    const RELEASE: &str = r#"{"tag_name": "v0.3.1", "published_at": "2025-06-02T10:00:00Z"}"#;
+
+    const CFF: &str =
+        "cff-version: 1.2.0\nauthors:\n  - family-names: Smith\n    given-names: Jane\n";
+
+    #[test]
+    fn test_version_from_tag() {
+        assert_eq!(version_from_tag("v1.2.0"), "1.2.0");
+        assert_eq!(version_from_tag("2024.1"), "2024.1");
+        assert_eq!(version_from_tag("vintage"), "vintage");
+    }
+
+    #[test]
+    fn test_software_from_github() {
+        let accessed = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into();
+        let software = software_from_github(REPO, Some(RELEASE), Some(CFF), accessed).unwrap();
+
+        assert_eq!(software.common_data.id, "github:example/citation_station");
+        assert_eq!(software.title, "citation_station");
+        assert_eq!(software.version.as_deref(), Some("0.3.1"));
+        assert_eq!(
+            software.common_data.published,
+            Some(PublishDate::from_year_month_day(2025, Month::June, 2).unwrap())
+        );
+        assert_eq!(
+            software.author,
+            GenericAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()]
+            }
+        );
+    }
+
+    /// Serves the repository, but no release and no CITATION.cff
+    struct FakeGitHub;
+
+    impl HttpClient for FakeGitHub {
+        fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+            let (status, body) = if url == "https://api.github.com/repos/example/citation_station" {
+                (200, REPO)
+            } else {
+                (404, "")
+            };
+            Ok(HttpResponse::new(status, body.to_string()))
+        }
+    }
+
+    impl AsyncHttpClient for FakeGitHub {
+        async fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+            HttpClient::get(self, url)
+        }
+    }
+
+    #[test]
+    fn test_from_github_repo_without_release_or_cff() {
+        let software =
+            Software::from_github_repo_with(&FakeGitHub, "example", "citation_station").unwrap();
+
+        assert_eq!(software.version, None);
+        assert_eq!(software.common_data.published, None);
+        assert_eq!(
+            software.author,
+            GenericAuthor::Organization {
+                name: "example".to_string(),
+                abbreviation: None,
+            }
+        );
+        assert_eq!(
+            software.url.as_deref(),
+            Some("https://github.com/example/citation_station")
+        );
+    }
+
+    #[test]
+    fn test_from_github_repo_missing_repo() {
+        assert!(Software::from_github_repo_with(&FakeGitHub, "example", "nope").is_err());
+    }
+
+    #[test]
+    fn test_from_github_repo_async() {
+        let software = block_on(Software::from_github_repo_async(
+            &FakeGitHub,
+            "example",
+            "citation_station",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            software,
+            Software::from_github_repo_with(&FakeGitHub, "example", "citation_station").unwrap()
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{citation::Citation, date::AccessDate, media::wikipedia_article::WikipediaArticle},
+    import::{
+        ImportError,
+        arxiv::{fetch_arxiv, fetch_arxiv_async, normalize_arxiv_id},
+        doi::{fetch_doi, fetch_doi_async, normalize_doi},
+        isbn::{fetch_isbn, fetch_isbn_async, normalize_isbn},
+        pubmed::{fetch_pmid, fetch_pmid_async, normalize_pmid},
+        web::{fetch_web_page, fetch_web_page_async, normalize_url},
+        wikipedia::{fetch_wikipedia, fetch_wikipedia_async},
+    },
+    net::{AsyncHttpClient, HttpClient},
+};
+
+/// A recognized identifier, normalized.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub enum Identifier {
+    Doi(String),
+    Isbn(String),
+    ArXiv(String),
+    Pmid(String),
+    /// A link to a Wikipedia article
+    Wikipedia(String),
+    Url(String),
+}
+
+impl Identifier {
+    /// Work out what kind of identifier `input` is.
+    ///
+    /// Prefixed and URL forms ("doi:", "https://doi.org/", "arXiv:",
+    /// "PMID:", "ISBN ") are recognized, as are bare DOIs, arXiv IDs and
+    /// ISBNs (by checksum). A bare number of up to 8 digits is taken to be
+    /// a PMID. Links to Wikipedia articles are recognized, and any other
+    /// http(s) URL is treated as a web page.
+    pub fn detect(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if let Some(doi) = normalize_doi(input) {
+            return Some(Identifier::Doi(doi));
+        }
+        if let Some(arxiv_id) = normalize_arxiv_id(input) {
+            return Some(Identifier::ArXiv(arxiv_id));
+        }
+        if let Some(isbn) = normalize_isbn(input) {
+            return Some(Identifier::Isbn(isbn));
+        }
+        if let Some(pmid) = normalize_pmid(input) {
+            return Some(Identifier::Pmid(pmid));
+        }
+        let url = normalize_url(input)?;
+        if WikipediaArticle::from_url(&url, AccessDate::default()).is_some() {
+            return Some(Identifier::Wikipedia(url));
+        }
+        Some(Identifier::Url(url))
+    }
+
+    /// The normalized identifier, e.g. "10.1000/xyz123" for a DOI
+    pub fn value(&self) -> &str {
+        match self {
+            Identifier::Doi(value)
+            | Identifier::Isbn(value)
+            | Identifier::ArXiv(value)
+            | Identifier::Pmid(value)
+            | Identifier::Wikipedia(value)
+            | Identifier::Url(value) => value,
+        }
+    }
+}
+
+/// Detect the kind of identifier in `input` and import it with the
+/// matching importer. Returns the identifier that was used alongside the
+/// citation.
+pub fn import_identifier<C: HttpClient + ?Sized>(
+    client: &C,
+    input: &str,
+) -> Result<(Identifier, Citation), ImportError> {
+    let identifier =
+        Identifier::detect(input).ok_or(ImportError::InvalidIdentifier(input.to_string()))?;
+    let citation = match &identifier {
+        Identifier::Doi(doi) => fetch_doi(client, doi)?,
+        Identifier::Isbn(isbn) => fetch_isbn(client, isbn)?,
+        Identifier::ArXiv(arxiv_id) => fetch_arxiv(client, arxiv_id)?,
+        Identifier::Pmid(pmid) => fetch_pmid(client, pmid)?,
+        Identifier::Wikipedia(url) => fetch_wikipedia(client, url)?,
+        Identifier::Url(url) => fetch_web_page(client, url)?,
+    };
+    Ok((identifier, citation))
+}
+
+/// [`import_identifier`] with an [`AsyncHttpClient`]
+pub async fn import_identifier_async<C: AsyncHttpClient>(
+    client: &C,
+    input: &str,
+) -> Result<(Identifier, Citation), ImportError> {
+    let identifier =
+        Identifier::detect(input).ok_or(ImportError::InvalidIdentifier(input.to_string()))?;
+    let citation = match &identifier {
+        Identifier::Doi(doi) => fetch_doi_async(client, doi).await?,
+        Identifier::Isbn(isbn) => fetch_isbn_async(client, isbn).await?,
+        Identifier::ArXiv(arxiv_id) => fetch_arxiv_async(client, arxiv_id).await?,
+        Identifier::Pmid(pmid) => fetch_pmid_async(client, pmid).await?,
+        Identifier::Wikipedia(url) => fetch_wikipedia_async(client, url).await?,
+        Identifier::Url(url) => fetch_web_page_async(client, url).await?,
+    };
+    Ok((identifier, citation))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::citation::Citation,
+        import::identifier::Identifier,
+        net::{AsyncHttpClient, FetchError, HttpClient, HttpResponse, block_on},
+    };
+
+    #[test]
+    fn test_detect() {
+        let detect = |input: &str| Identifier::detect(input);
+
+        assert_eq!(
+            detect("https://doi.org/10.1000/XYZ"),
+            Some(Identifier::Doi("10.1000/xyz".to_string()))
+        );
+        assert_eq!(
+            detect("arXiv:1706.03762"),
+            Some(Identifier::ArXiv("1706.03762".to_string()))
+        );
+        assert_eq!(
+            detect("978-0-306-40615-7"),
+            Some(Identifier::Isbn("9780306406157".to_string()))
+        );
+        assert_eq!(
+            detect("31452104"),
+            Some(Identifier::Pmid("31452104".to_string()))
+        );
+        assert_eq!(
+            detect("www.example.com/post"),
+            Some(Identifier::Url("https://www.example.com/post".to_string()))
+        );
+        assert_eq!(
+            detect("https://en.wikipedia.org/wiki/Rust"),
+            Some(Identifier::Wikipedia(
+                "https://en.wikipedia.org/wiki/Rust".to_string()
+            ))
+        );
+        assert_eq!(detect("not an identifier"), None);
+    }
+
+    /// Answers PubMed with a DOI and Crossref with a journal article
+    struct FakeApis;
+
+    impl HttpClient for FakeApis {
+        fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+            let body = if url.contains("eutils.ncbi.nlm.nih.gov") {
+                r#"{"result": {"42": {"articleids": [{"idtype": "doi", "value": "10.1000/pm"}]}}}"#
+            } else if url.starts_with("https://api.crossref.org/works/10.1000/pm") {
+                r#"{"message": {"DOI": "10.1000/pm", "type": "journal-article",
+                    "title": ["From PubMed"], "container-title": ["J. Tests"]}}"#
+            } else {
+                return Ok(HttpResponse::new(404, String::new()));
+            };
+            Ok(HttpResponse::new(200, body.to_string()))
+        }
+    }
+
+    impl AsyncHttpClient for FakeApis {
+        async fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+            HttpClient::get(self, url)
+        }
+    }
+
+    #[test]
+    fn test_from_identifier_routes_pmid_through_doi() {
+        let (identifier, citation) = Citation::from_identifier_with(&FakeApis, "PMID:42").unwrap();
+
+        assert_eq!(identifier, Identifier::Pmid("42".to_string()));
+        assert_eq!(citation.title(), "From PubMed");
+        assert_eq!(identifier.value(), "42");
+    }
+
+    #[test]
+    fn test_from_identifier_reports_failures() {
+        assert!(Citation::from_identifier_with(&FakeApis, "hello").is_err());
+        assert!(Citation::from_identifier_with(&FakeApis, "9780306406157").is_err());
+    }
+
+    #[test]
+    fn test_from_identifier_async() {
+        let (identifier, citation) =
+            block_on(Citation::from_identifier_async(&FakeApis, "PMID:42")).unwrap();
+
+        assert_eq!(identifier, Identifier::Pmid("42".to_string()));
+        assert_eq!(citation.title(), "From PubMed");
+        assert!(block_on(Citation::from_identifier_async(&FakeApis, "9780306406157")).is_err());
+    }
+}
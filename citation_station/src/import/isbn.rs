@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        author::{GenericAuthor, PersonName},
+        citation::Citation,
+        date::PublishDate,
+        identifiers::Identifiers,
+        media::{book::Book, common::CommonCitationData},
+        title::Title,
+    },
+    import::{ImportError, fetch_body, fetch_body_async, identifier::Identifier, record_lookup},
+    net::{AsyncHttpClient, HttpClient},
+};
+
+const OPEN_LIBRARY_BOOKS_ENDPOINT: &str = "https://openlibrary.org/api/books";
+
+/// Strip an "ISBN" prefix, hyphens and spaces and check the checksum.
+///
+/// Both ISBN-10 (possibly ending in "X") and ISBN-13 are accepted and
+/// returned as-is; they are not converted into each other.
+pub fn normalize_isbn(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    let without_prefix = ["ISBN-13:", "ISBN-10:", "ISBN:", "ISBN"]
+        .iter()
+        .find_map(|prefix| {
+            trimmed
+                .get(..prefix.len())
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| &trimmed[prefix.len()..])
+        })
+        .unwrap_or(trimmed);
+    let isbn: String = without_prefix
+        .chars()
+        .filter(|c| !matches!(c, '-' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let digit = |c: char| c.to_digit(10);
+    let valid = match isbn.len() {
+        10 => {
+            let mut sum = 0;
+            for (index, c) in isbn.chars().enumerate() {
+                let value = match (index, c) {
+                    (9, 'X') => 10,
+                    _ => digit(c)?,
+                };
+                sum += value * (10 - index as u32);
+            }
+            sum % 11 == 0
+        }
+        13 => {
+            let mut sum = 0;
+            for (index, c) in isbn.chars().enumerate() {
+                sum += digit(c)? * if index % 2 == 0 { 1 } else { 3 };
+            }
+            sum % 10 == 0
+        }
+        _ => false,
+    };
+    valid.then_some(isbn)
+}
+
+/// The Open Library books API URL for a (normalized) ISBN.
+pub fn open_library_url(isbn: &str) -> String {
+    format!(
+        "{}?bibkeys=ISBN:{}&format=json&jscmd=data",
+        OPEN_LIBRARY_BOOKS_ENDPOINT, isbn
+    )
+}
+
+/// Look up a single ISBN on Open Library and convert it to a book.
+pub fn fetch_isbn<C: HttpClient + ?Sized>(client: &C, isbn: &str) -> Result<Citation, ImportError> {
+    let result = fetch_body(client, open_library_url(isbn))
+        .and_then(|body| citation_from_open_library(&body, isbn));
+    record_lookup("open_library", &result);
+    result
+}
+
+/// [`fetch_isbn`] with an [`AsyncHttpClient`]
+pub async fn fetch_isbn_async<C: AsyncHttpClient>(
+    client: &C,
+    isbn: &str,
+) -> Result<Citation, ImportError> {
+    let result = fetch_body_async(client, open_library_url(isbn))
+        .await
+        .and_then(|body| citation_from_open_library(&body, isbn));
+    record_lookup("open_library", &result);
+    result
+}
+
+#[derive(Deserialize)]
+struct OpenLibraryBook {
+    title: Option<String>,
+    subtitle: Option<String>,
+    #[serde(default)]
+    authors: Vec<OpenLibraryName>,
+    publish_date: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OpenLibraryName {
+    name: String,
+}
+
+/// Convert an Open Library `jscmd=data` response for `isbn` into a book.
+///
+/// The ISBN is used as the citation ID. Only the year of the (free-form)
+/// publish date is kept.
+pub fn citation_from_open_library(json: &str, isbn: &str) -> Result<Citation, ImportError> {
+    let mut books: HashMap<String, OpenLibraryBook> =
+        serde_json::from_str(json).map_err(|err| ImportError::Parse(err.to_string()))?;
+    let book = books
+        .remove(&format!("ISBN:{}", isbn))
+        .ok_or(ImportError::MissingField(format!("ISBN:{}", isbn)))?;
+
+    let main = book
+        .title
+        .ok_or(ImportError::MissingField("title".to_string()))?;
+    let title = match book.subtitle {
+        Some(subtitle) => Title::with_subtitle(main, subtitle),
+        None => Title::new(main),
+    };
+    let persons: Vec<PersonName> = book
+        .authors
+        .iter()
+        .filter_map(|author| PersonName::parse(&author.name).ok())
+        .collect();
+    let author = if persons.is_empty() {
+        GenericAuthor::Organization {
+            name: book
+                .authors
+                .into_iter()
+                .next()
+                .map(|author| author.name)
+                .ok_or(ImportError::MissingField("authors".to_string()))?,
+            abbreviation: None,
+        }
+    } else {
+        GenericAuthor::Persons { persons }
+    };
+    let published = book.publish_date.as_deref().and_then(|date| {
+        date.split(|c: char| !c.is_ascii_digit())
+            .find(|part| part.len() == 4)
+            .and_then(|year| year.parse().ok())
+            .map(PublishDate::from_year)
+    });
+
+    Ok(Citation::Book(Book {
+        common_data: CommonCitationData {
+            published,
+            identifiers: Identifiers::new().with(Identifier::Isbn(isbn.to_string())),
+            ..CommonCitationData::new(isbn)
+        },
+        author,
+        with_authors: Vec::new(),
+        title,
+        chapter: None,
+        version: None,
+        doi: None,
+        pages: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{citation::Citation, date::PublishDate},
+        import::isbn::{citation_from_open_library, normalize_isbn},
+    };
+
+    #[test]
+    fn test_normalize_isbn() {
+        assert_eq!(
+            normalize_isbn("ISBN 978-0-306-40615-7"),
+            Some("9780306406157".to_string())
+        );
+        assert_eq!(
+            normalize_isbn("0-8044-2957-x"),
+            Some("080442957X".to_string())
+        );
+        assert_eq!(normalize_isbn("978-0-306-40615-8"), None);
+        assert_eq!(normalize_isbn("12345"), None);
+    }
+
+    #[test]
+    fn test_citation_from_open_library() {
+        let json = r#"{"ISBN:9780306406157": {
+            "title": "Principles of Things",
+            "subtitle": "A Primer",
+            "authors": [{"name": "Jane Q. Smith", "url": "https://openlibrary.org/authors/OL1A"}],
+            "publish_date": "March 1998"
+        }}"#;
+
+        let Citation::Book(book) = citation_from_open_library(json, "9780306406157").unwrap()
+        else {
+            panic!("expected a book");
+        };
+
+        assert_eq!(book.common_data.id, "9780306406157");
+        assert_eq!(book.title, "Principles of Things: A Primer");
+        assert_eq!(
+            book.common_data.published,
+            Some(PublishDate::from_year(1998))
+        );
+        assert_eq!(book.author.contributors().len(), 1);
+        assert_eq!(book.common_data.identifiers.isbn(), Some("9780306406157"));
+    }
+
+    #[test]
+    fn test_citation_from_open_library_not_found() {
+        assert!(citation_from_open_library("{}", "9780306406157").is_err());
+    }
+}
@@ -0,0 +1,296 @@
+pub mod arxiv;
+pub mod bookmarks;
+pub mod doi;
+pub mod freeform;
+pub mod github;
+pub mod identifier;
+pub mod isbn;
+pub mod onix;
+pub mod pubmed;
+pub mod refresh;
+pub mod web;
+pub mod wikipedia;
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use thiserror::Error;
+
+use crate::{
+    api::citation::Citation,
+    net::{AsyncHttpClient, FetchError, HttpClient, HttpResponse},
+};
+
+/// Number of simultaneous requests used by batch importers unless the
+/// caller asks for something else.
+pub const DEFAULT_MAX_CONCURRENCY: usize = 8;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ImportError {
+    #[error(transparent)]
+    Fetch(#[from] FetchError),
+    #[error("Not a valid identifier: {0}")]
+    InvalidIdentifier(String),
+    #[error("Could not parse response: {0}")]
+    Parse(String),
+    #[error("Unsupported work type: {0}")]
+    UnsupportedType(String),
+    #[error("Missing required field: {0}")]
+    MissingField(String),
+    #[error("Citation with ID '{0}' already exists")]
+    DuplicateId(String),
+}
+
+/// Reported to the caller of a batch import each time an item finishes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    /// Number of items finished so far, including this one
+    pub completed: usize,
+    /// Number of items that will be fetched in total
+    pub total: usize,
+    /// The input (e.g. normalized DOI) that just finished
+    pub input: String,
+    /// Whether the item produced a citation
+    pub succeeded: bool,
+}
+
+/// Outcome of a batch import. Failures of individual items don't abort
+/// the batch; they are collected here instead.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BatchImport {
+    /// IDs of the citations added to the bibliography, in input order
+    pub imported: Vec<String>,
+    /// Inputs that were skipped because they were repeated in the batch
+    /// or already present in the bibliography
+    pub duplicates: Vec<String>,
+    /// Inputs that could not be imported, with the reason
+    pub failures: Vec<(String, ImportError)>,
+    /// Data the importer couldn't carry over as it was
+    pub warnings: Vec<ImportWarning>,
+}
+
+/// Something an importer changed or left out while reading a file.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ImportIssue {
+    /// The entry wasn't imported
+    SkippedEntry { reason: String },
+    /// A field the importer doesn't read, so its value was dropped
+    UnknownField { field: String },
+    /// The file doesn't state a media type the importer supports, so this
+    /// one was assumed
+    GuessedMediaType {
+        stated: Option<String>,
+        used: String,
+    },
+    /// A date that couldn't be read as it was written, and the date used
+    /// instead, if any
+    CoercedDate {
+        original: String,
+        used: Option<String>,
+    },
+}
+
+impl fmt::Display for ImportIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportIssue::SkippedEntry { reason } => write!(f, "Skipped entry: {}", reason),
+            ImportIssue::UnknownField { field } => write!(f, "Field not read: {}", field),
+            ImportIssue::GuessedMediaType {
+                stated: Some(stated),
+                used,
+            } => write!(f, "Read {} entry as {}", stated, used),
+            ImportIssue::GuessedMediaType { stated: None, used } => {
+                write!(f, "Read entry without a type as {}", used)
+            }
+            ImportIssue::CoercedDate {
+                original,
+                used: Some(used),
+            } => write!(f, "Date \"{}\" read as {}", original, used),
+            ImportIssue::CoercedDate {
+                original,
+                used: None,
+            } => write!(f, "Date \"{}\" could not be read", original),
+        }
+    }
+}
+
+/// An [`ImportIssue`] and where it occurred in the imported file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportWarning {
+    /// 1-based line number, for formats where it is known
+    pub line: Option<usize>,
+    /// The ID or URL of the affected entry, if it has one
+    pub entry: Option<String>,
+    pub issue: ImportIssue,
+}
+
+/// Citations read from a file, and what was lost or guessed on the way,
+/// so that it can be reviewed instead of being dropped silently.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImportReport {
+    pub citations: Vec<Citation>,
+    pub warnings: Vec<ImportWarning>,
+}
+
+/// GET `url` and return the body of a successful response.
+pub(crate) fn fetch_body<C: HttpClient + ?Sized>(
+    client: &C,
+    url: String,
+) -> Result<String, ImportError> {
+    let response = client.get(&url)?;
+    successful_body(url, response)
+}
+
+/// [`fetch_body`] with an [`AsyncHttpClient`]
+pub(crate) async fn fetch_body_async<C: AsyncHttpClient>(
+    client: &C,
+    url: String,
+) -> Result<String, ImportError> {
+    let response = client.get(&url).await?;
+    successful_body(url, response)
+}
+
+fn successful_body(url: String, response: HttpResponse) -> Result<String, ImportError> {
+    if !response.is_success() {
+        return Err(FetchError::Status {
+            url,
+            status: response.status,
+        }
+        .into());
+    }
+    Ok(response.body)
+}
+
+/// Count the outcome of a lookup from `source` when the `metrics`
+/// feature is enabled. See [`crate::metrics`] for the source names.
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_lookup<T>(source: &'static str, result: &Result<T, ImportError>) {
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_lookup(source, result);
+}
+
+/// Count an input that didn't need looking up, see [`record_lookup`]
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_cache_hit(source: &'static str) {
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_cache_hit(source);
+}
+
+/// Count a lookup that used a fallback, see [`record_lookup`]
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_fallback(source: &'static str) {
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_fallback(source);
+}
+
+/// Run `work` over `inputs` on at most `max_concurrency` threads.
+///
+/// `on_result` is called on the calling thread, in completion order, with
+/// the index of the input that finished and its result.
+pub(crate) fn run_bounded<T, R, W, F>(
+    inputs: &[T],
+    max_concurrency: usize,
+    work: W,
+    mut on_result: F,
+) where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    F: FnMut(usize, R),
+{
+    let workers = max_concurrency.clamp(1, inputs.len().max(1));
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let next_index = &next_index;
+            let work = &work;
+            scope.spawn(move || {
+                loop {
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+                    let Some(input) = inputs.get(index) else {
+                        break;
+                    };
+                    if sender.send((index, work(input))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        for (index, result) in receiver {
+            on_result(index, result);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::import::run_bounded;
+
+    #[test]
+    fn test_run_bounded_visits_every_input() {
+        let inputs: Vec<u32> = (0..50).collect();
+        let mut results = vec![None; inputs.len()];
+
+        run_bounded(
+            &inputs,
+            4,
+            |n| n * 2,
+            |index, doubled| {
+                results[index] = Some(doubled);
+            },
+        );
+
+        assert!(
+            results
+                .iter()
+                .enumerate()
+                .all(|(index, result)| *result == Some(index as u32 * 2))
+        );
+    }
+
+    #[test]
+    fn test_run_bounded_respects_limit() {
+        let inputs: Vec<u32> = (0..20).collect();
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        run_bounded(
+            &inputs,
+            3,
+            |_| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(2));
+                running.fetch_sub(1, Ordering::SeqCst);
+            },
+            |_, _| {},
+        );
+
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn test_run_bounded_empty() {
+        let inputs: Vec<u32> = Vec::new();
+        let mut calls = 0;
+
+        run_bounded(&inputs, 8, |n| *n, |_, _| calls += 1);
+
+        assert_eq!(calls, 0);
+    }
+}
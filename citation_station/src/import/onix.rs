@@ -0,0 +1,627 @@
+//! Reading book metadata from ONIX 3.0, the XML format publishers and
+//! distributors use for their product feeds.
+//!
+//! Each `<Product>` becomes a book with its title, authors, edition,
+//! ISBN, publisher, place and publication date. Both reference names
+//! (`<TitleText>`) and short tags (`<b203>`) are read. ONIX is plain
+//! element-and-text XML, so this module reads it with a small XML reader
+//! rather than depending on a full XML implementation.
+//!
+//! Format reference: https://www.editeur.org/83/Overview/
+
+use chrono::Month;
+
+use crate::{
+    api::{
+        author::{GenericAuthor, PersonName},
+        citation::Citation,
+        date::PublishDate,
+        identifiers::Identifiers,
+        location::LocationData,
+        media::{book::Book, common::CommonCitationData, version::GenericMediaVersion},
+        title::Title,
+    },
+    import::{
+        ImportError, ImportIssue, ImportReport, ImportWarning, identifier::Identifier,
+        isbn::normalize_isbn,
+    },
+};
+
+/// Short tags of the ONIX elements read here, and their reference names
+const SHORT_TAGS: [(&str, &str); 34] = [
+    ("product", "Product"),
+    ("a001", "RecordReference"),
+    ("productidentifier", "ProductIdentifier"),
+    ("b221", "ProductIDType"),
+    ("b244", "IDValue"),
+    ("descriptivedetail", "DescriptiveDetail"),
+    ("titledetail", "TitleDetail"),
+    ("b202", "TitleType"),
+    ("titleelement", "TitleElement"),
+    ("x409", "TitleElementLevel"),
+    ("b203", "TitleText"),
+    ("b030", "TitlePrefix"),
+    ("b031", "TitleWithoutPrefix"),
+    ("b029", "Subtitle"),
+    ("contributor", "Contributor"),
+    ("b034", "SequenceNumber"),
+    ("b035", "ContributorRole"),
+    ("b036", "PersonName"),
+    ("b037", "PersonNameInverted"),
+    ("b039", "NamesBeforeKey"),
+    ("b040", "KeyNames"),
+    ("b047", "CorporateName"),
+    ("b057", "EditionNumber"),
+    ("publishingdetail", "PublishingDetail"),
+    ("imprint", "Imprint"),
+    ("b079", "ImprintName"),
+    ("publisher", "Publisher"),
+    ("b291", "PublishingRole"),
+    ("b081", "PublisherName"),
+    ("b209", "CityOfPublication"),
+    ("b083", "CountryOfPublication"),
+    ("publishingdate", "PublishingDate"),
+    ("x448", "PublishingDateRole"),
+    ("b306", "Date"),
+];
+
+/// An XML element, with its text and child elements. Names are local
+/// names, without a namespace prefix.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// The trimmed, non-empty text of the first child called `name`
+    fn text_of(&self, name: &str) -> Option<&str> {
+        self.child(name)
+            .map(|child| child.text.trim())
+            .filter(|text| !text.is_empty())
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Every element called `name` in this element's subtree
+    fn descendants<'a>(&'a self, name: &str, found: &mut Vec<&'a Element>) {
+        for child in &self.children {
+            if child.name == name {
+                found.push(child);
+            } else {
+                child.descendants(name, found);
+            }
+        }
+    }
+}
+
+/// Parse an XML document into a tree of elements under an unnamed root.
+/// Comments, processing instructions and the doctype are skipped.
+fn parse_xml(xml: &str) -> Result<Element, ImportError> {
+    let error = |message: &str| ImportError::Parse(format!("Invalid XML: {}", message));
+    let mut stack = vec![Element::default()];
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        let text = unescape(&rest[..start]);
+        stack.last_mut().unwrap().text.push_str(&text);
+        rest = &rest[start..];
+
+        let skip_to = |rest: &str, end: &str| -> Result<usize, ImportError> {
+            rest.find(end)
+                .map(|index| index + end.len())
+                .ok_or_else(|| error(&format!("missing \"{}\"", end)))
+        };
+        if rest.starts_with("<!--") {
+            rest = &rest[skip_to(rest, "-->")?..];
+        } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").ok_or_else(|| error("unclosed CDATA"))?;
+            stack.last_mut().unwrap().text.push_str(&cdata[..end]);
+            rest = &cdata[end + 3..];
+        } else if rest.starts_with("<?") {
+            rest = &rest[skip_to(rest, "?>")?..];
+        } else if rest.starts_with("<!") {
+            rest = &rest[skip_to(rest, ">")?..];
+        } else if let Some(closing) = rest.strip_prefix("</") {
+            let end = closing.find('>').ok_or_else(|| error("unclosed tag"))?;
+            let name = local_name(closing[..end].trim());
+            let element = stack.pop().filter(|_| !stack.is_empty());
+            match element {
+                Some(element) if element.name == name => {
+                    stack.last_mut().unwrap().children.push(element)
+                }
+                _ => return Err(error(&format!("unexpected </{}>", name))),
+            }
+            rest = &closing[end + 1..];
+        } else {
+            let end = tag_end(rest).ok_or_else(|| error("unclosed tag"))?;
+            let tag = &rest[1..end];
+            let (tag, self_closing) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag, false),
+            };
+            let element = start_tag(tag);
+            if self_closing {
+                stack.last_mut().unwrap().children.push(element);
+            } else {
+                stack.push(element);
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    match stack.pop() {
+        Some(root) if stack.is_empty() => Ok(root),
+        Some(element) => Err(error(&format!("<{}> is not closed", element.name))),
+        None => Err(error("empty document")),
+    }
+}
+
+/// The index of the `>` ending the tag at the start of `text`, skipping
+/// any in quoted attribute values
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// An element from the inside of a start tag, e.g. `Date dateformat="00"`
+fn start_tag(tag: &str) -> Element {
+    let tag = tag.trim();
+    let (name, mut attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+    let mut element = Element {
+        name: local_name(name),
+        ..Default::default()
+    };
+    while let Some((name, value)) = attributes.split_once('=') {
+        let value = value.trim_start();
+        let Some(quote) = value.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            break;
+        };
+        let Some(end) = value[1..].find(quote) else {
+            break;
+        };
+        element
+            .attributes
+            .push((local_name(name.trim()), unescape(&value[1..end + 1])));
+        attributes = &value[end + 2..];
+    }
+    element
+}
+
+/// `name` without a namespace prefix, with ONIX short tags replaced by
+/// their reference names
+fn local_name(name: &str) -> String {
+    let name = name.rsplit(':').next().unwrap_or(name);
+    SHORT_TAGS
+        .iter()
+        .find(|(short, _)| *short == name)
+        .map_or(name, |(_, reference)| reference)
+        .to_string()
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let c = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Read the products of an ONIX 3.0 message as books.
+///
+/// The ISBN-13 (or ISBN-10) is used as the citation ID, falling back to
+/// the record reference. Only the product-level distinctive title and
+/// contributors with the "By (author)" role (A01) are read; an author
+/// known only by a corporate name becomes an organization. The publisher
+/// is kept in the citation's [`ExtendedMetadata`], falling back to the
+/// imprint. Products without a title are skipped, and publication dates
+/// that can't be read are reported.
+///
+/// [`ExtendedMetadata`]: crate::api::media::common::ExtendedMetadata
+pub fn read_onix(xml: &str) -> Result<ImportReport, ImportError> {
+    let root = parse_xml(xml)?;
+    let mut products = Vec::new();
+    root.descendants("Product", &mut products);
+
+    let mut report = ImportReport::default();
+    for product in products {
+        let isbn = product
+            .children("ProductIdentifier")
+            .filter(|identifier| matches!(identifier.text_of("ProductIDType"), Some("15" | "02")))
+            .filter_map(|identifier| normalize_isbn(identifier.text_of("IDValue")?))
+            .max_by_key(String::len);
+        let Some(id) = isbn
+            .clone()
+            .or_else(|| product.text_of("RecordReference").map(str::to_string))
+        else {
+            report.warnings.push(ImportWarning {
+                line: None,
+                entry: None,
+                issue: ImportIssue::SkippedEntry {
+                    reason: "product has no ISBN or record reference".to_string(),
+                },
+            });
+            continue;
+        };
+        let mut warn = |issue| {
+            report.warnings.push(ImportWarning {
+                line: None,
+                entry: Some(id.clone()),
+                issue,
+            })
+        };
+
+        let detail = product.child("DescriptiveDetail");
+        let Some(title) = detail.and_then(title) else {
+            warn(ImportIssue::SkippedEntry {
+                reason: "product has no title".to_string(),
+            });
+            continue;
+        };
+        let author = detail.map(author).unwrap_or(GenericAuthor::Persons {
+            persons: Vec::new(),
+        });
+        let version = detail
+            .and_then(|detail| detail.text_of("EditionNumber"))
+            .and_then(|number| number.parse().ok())
+            .map(|number| GenericMediaVersion::Edition { number });
+
+        let publishing = product.child("PublishingDetail");
+        let publisher = publishing.and_then(|publishing| {
+            publishing
+                .children("Publisher")
+                .find(|publisher| matches!(publisher.text_of("PublishingRole"), Some("01") | None))
+                .and_then(|publisher| publisher.text_of("PublisherName"))
+                .or_else(|| publishing.child("Imprint")?.text_of("ImprintName"))
+                .map(str::to_string)
+        });
+        // The country is an ISO code, but may be left out when the city
+        // names its state, e.g. "Cambridge, MA"
+        let place = publishing.and_then(|publishing| {
+            let city = publishing.text_of("CityOfPublication")?;
+            match publishing.text_of("CountryOfPublication") {
+                Some(country) => Some(LocationData::new(city, country)),
+                None => LocationData::parse(city),
+            }
+        });
+        let date = publishing.and_then(|publishing| {
+            publishing
+                .children("PublishingDate")
+                .find(|date| matches!(date.text_of("PublishingDateRole"), Some("01" | "11")))
+                .or_else(|| publishing.child("PublishingDate"))?
+                .child("Date")
+        });
+        let published = date.and_then(|date| {
+            let published = publish_date(date);
+            if published.is_none() {
+                warn(ImportIssue::CoercedDate {
+                    original: date.text.trim().to_string(),
+                    used: None,
+                });
+            }
+            published
+        });
+
+        let mut common_data = CommonCitationData {
+            published,
+            identifiers: match &isbn {
+                Some(isbn) => Identifiers::new().with(Identifier::Isbn(isbn.clone())),
+                None => Identifiers::new(),
+            },
+            ..CommonCitationData::new(id.clone())
+        };
+        common_data.extended.publisher = publisher;
+        common_data.extended.place = place;
+        report.citations.push(Citation::Book(Book {
+            common_data,
+            author,
+            with_authors: Vec::new(),
+            title,
+            chapter: None,
+            version,
+            doi: None,
+            pages: None,
+        }));
+    }
+    Ok(report)
+}
+
+/// The books in an ONIX 3.0 message, see [`read_onix`]
+pub fn citations_from_onix(xml: &str) -> Result<Vec<Citation>, ImportError> {
+    read_onix(xml).map(|report| report.citations)
+}
+
+/// The distinctive title (type 01) at product level (level 01)
+fn title(detail: &Element) -> Option<Title> {
+    let element = detail
+        .children("TitleDetail")
+        .find(|title| matches!(title.text_of("TitleType"), Some("01") | None))?
+        .children("TitleElement")
+        .find(|element| matches!(element.text_of("TitleElementLevel"), Some("01") | None))?;
+    let main = match element.text_of("TitleText") {
+        Some(text) => text.to_string(),
+        None => {
+            let without_prefix = element.text_of("TitleWithoutPrefix")?;
+            match element.text_of("TitlePrefix") {
+                Some(prefix) => format!("{} {}", prefix, without_prefix),
+                None => without_prefix.to_string(),
+            }
+        }
+    };
+    Some(match element.text_of("Subtitle") {
+        Some(subtitle) => Title::with_subtitle(main, subtitle.to_string()),
+        None => Title::new(main),
+    })
+}
+
+/// The authors (role A01) in sequence order
+fn author(detail: &Element) -> GenericAuthor {
+    let mut contributors: Vec<&Element> = detail
+        .children("Contributor")
+        .filter(|contributor| {
+            contributor
+                .children("ContributorRole")
+                .any(|role| role.text.trim() == "A01")
+        })
+        .collect();
+    contributors.sort_by_key(|contributor| {
+        contributor
+            .text_of("SequenceNumber")
+            .and_then(|number| number.parse::<u32>().ok())
+            .unwrap_or(u32::MAX)
+    });
+
+    let persons: Vec<PersonName> = contributors
+        .iter()
+        .filter_map(|contributor| {
+            match (
+                contributor.text_of("KeyNames"),
+                contributor.text_of("NamesBeforeKey"),
+            ) {
+                (Some(key), Some(before)) => {
+                    PersonName::parse(&format!("{}, {}", key, before)).ok()
+                }
+                (Some(key), None) => PersonName::from_last(key).ok(),
+                (None, _) => contributor
+                    .text_of("PersonNameInverted")
+                    .or_else(|| contributor.text_of("PersonName"))
+                    .and_then(|name| PersonName::parse(name).ok()),
+            }
+        })
+        .collect();
+    if persons.is_empty()
+        && let Some(name) = contributors
+            .iter()
+            .find_map(|contributor| contributor.text_of("CorporateName"))
+    {
+        return GenericAuthor::Organization {
+            name: name.to_string(),
+            abbreviation: None,
+        };
+    }
+    GenericAuthor::Persons { persons }
+}
+
+/// A `<Date>` in one of the formats ONIX list 55 has for full dates:
+/// YYYYMMDD (00, the default), YYYYMM (01) or YYYY (05)
+fn publish_date(date: &Element) -> Option<PublishDate> {
+    let text = date.text.trim();
+    let digits = |range: std::ops::Range<usize>| text.get(range)?.parse::<u32>().ok();
+    let format = date.attribute("dateformat").unwrap_or(match text.len() {
+        4 => "05",
+        6 => "01",
+        _ => "00",
+    });
+    let expected = match format {
+        "00" => 8,
+        "01" => 6,
+        "05" => 4,
+        _ => return None,
+    };
+    if text.len() != expected || !text.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let year = digits(0..4)? as i32;
+    let Some(month) = digits(4..6) else {
+        return Some(PublishDate::from_year(year));
+    };
+    let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+    match digits(6..8) {
+        Some(day) => PublishDate::from_year_month_day(year, month, day).ok(),
+        None => Some(PublishDate::from_year_month(year, month)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            citation::Citation, date::PublishDate, location::LocationData,
+            media::version::GenericMediaVersion,
+        },
+        import::{
+            ImportError, ImportIssue,
+            onix::{parse_xml, read_onix},
+        },
+    };
+
+    const ONIX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ONIXMessage release="3.0" xmlns="http://ns.editeur.org/onix/3.0/reference">
+  <Header><Sender><SenderName>Example Press</SenderName></Sender></Header>
+  <Product>
+    <RecordReference>com.example.0001</RecordReference>
+    <ProductIdentifier>
+      <ProductIDType>15</ProductIDType>
+      <IDValue>9780306406157</IDValue>
+    </ProductIdentifier>
+    <DescriptiveDetail>
+      <TitleDetail>
+        <TitleType>01</TitleType>
+        <TitleElement>
+          <TitleElementLevel>01</TitleElementLevel>
+          <TitlePrefix>The</TitlePrefix>
+          <TitleWithoutPrefix>Art of Proofs</TitleWithoutPrefix>
+          <Subtitle>Reasoning &amp; Rigor</Subtitle>
+        </TitleElement>
+      </TitleDetail>
+      <Contributor>
+        <SequenceNumber>2</SequenceNumber>
+        <ContributorRole>A01</ContributorRole>
+        <NamesBeforeKey>Ada</NamesBeforeKey>
+        <KeyNames>Lovelace</KeyNames>
+      </Contributor>
+      <Contributor>
+        <SequenceNumber>1</SequenceNumber>
+        <ContributorRole>A01</ContributorRole>
+        <PersonNameInverted>Smith, Jane Q.</PersonNameInverted>
+      </Contributor>
+      <Contributor>
+        <SequenceNumber>3</SequenceNumber>
+        <ContributorRole>B06</ContributorRole>
+        <PersonName>Trans Lator</PersonName>
+      </Contributor>
+      <NoEdition/>
+      <EditionNumber>2</EditionNumber>
+    </DescriptiveDetail>
+    <PublishingDetail>
+      <Imprint><ImprintName>Example Imprint</ImprintName></Imprint>
+      <Publisher>
+        <PublishingRole>01</PublishingRole>
+        <PublisherName>Example Press</PublisherName>
+      </Publisher>
+      <CityOfPublication>Oxford</CityOfPublication>
+      <CountryOfPublication>GB</CountryOfPublication>
+      <PublishingDate>
+        <PublishingDateRole>01</PublishingDateRole>
+        <Date dateformat="00">20200315</Date>
+      </PublishingDate>
+    </PublishingDetail>
+  </Product>
+</ONIXMessage>
+"#;
+
+    #[test]
+    fn test_read_onix() {
+        let report = read_onix(ONIX).unwrap();
+        assert!(report.warnings.is_empty());
+        let [Citation::Book(book)] = report.citations.as_slice() else {
+            panic!("expected one book");
+        };
+        assert_eq!(book.common_data.id, "9780306406157");
+        assert_eq!(
+            book.title.to_string(),
+            "The Art of Proofs: Reasoning & Rigor"
+        );
+        let persons = report.citations[0].persons();
+        assert_eq!(persons.len(), 2);
+        assert_eq!(persons[0].surname(), "Smith");
+        assert_eq!(persons[1].surname(), "Lovelace");
+        assert_eq!(
+            book.version,
+            Some(GenericMediaVersion::Edition { number: 2 })
+        );
+        assert_eq!(
+            book.common_data.published,
+            Some(PublishDate::from_year_month_day(2020, Month::March, 15).unwrap())
+        );
+        assert_eq!(
+            book.common_data.extended.publisher.as_deref(),
+            Some("Example Press")
+        );
+        assert_eq!(
+            book.common_data.extended.place,
+            Some(LocationData::new("Oxford", "GB"))
+        );
+    }
+
+    #[test]
+    fn test_short_tags() {
+        let xml = r#"<ONIXmessage release="3.0"><product>
+            <a001>ref-1</a001>
+            <descriptivedetail>
+              <titledetail><b202>01</b202><titleelement><x409>01</x409><b203>Short Tagged</b203></titleelement></titledetail>
+              <contributor><b035>A01</b035><b047>Example Institute</b047></contributor>
+            </descriptivedetail>
+            <publishingdetail><b209>Boston, Massachusetts</b209><publishingdate><x448>01</x448><b306>2019</b306></publishingdate></publishingdetail>
+          </product>
+          <product><a001>ref-2</a001><publishingdetail/></product>
+        </ONIXmessage>"#;
+        let report = read_onix(xml).unwrap();
+        assert_eq!(report.citations.len(), 1);
+        let citation = &report.citations[0];
+        assert_eq!(citation.id(), "ref-1");
+        assert_eq!(citation.title(), "Short Tagged");
+        assert_eq!(citation.published(), Some(PublishDate::from_year(2019)));
+        assert_eq!(
+            citation.common_data().extended.place,
+            Some(LocationData::new("Boston", "US").with_state("MA"))
+        );
+        assert_eq!(
+            report.warnings[0].issue,
+            ImportIssue::SkippedEntry {
+                reason: "product has no title".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_xml() {
+        assert!(matches!(
+            parse_xml("<Product><Title></Product>"),
+            Err(ImportError::Parse(_))
+        ));
+        assert!(matches!(parse_xml("<Product>"), Err(ImportError::Parse(_))));
+    }
+}
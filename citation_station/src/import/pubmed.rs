@@ -0,0 +1,146 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    api::citation::Citation,
+    import::{
+        ImportError,
+        doi::{lookup_doi, lookup_doi_async},
+        fetch_body, fetch_body_async,
+        identifier::Identifier,
+        record_lookup,
+    },
+    net::{AsyncHttpClient, HttpClient},
+};
+
+const ESUMMARY_ENDPOINT: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/esummary.fcgi";
+
+/// Strip a "PMID:" prefix or PubMed URL. PMIDs are 1–8 digit numbers.
+pub fn normalize_pmid(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    let lowercase = trimmed.to_lowercase();
+    let pmid = [
+        "pmid:",
+        "https://pubmed.ncbi.nlm.nih.gov/",
+        "pubmed.ncbi.nlm.nih.gov/",
+    ]
+    .iter()
+    .find_map(|prefix| lowercase.strip_prefix(prefix))
+    .unwrap_or(&lowercase)
+    .trim()
+    .trim_end_matches('/');
+    let valid = (1..=8).contains(&pmid.len()) && pmid.chars().all(|c| c.is_ascii_digit());
+    valid.then(|| pmid.to_string())
+}
+
+/// The NCBI E-utilities summary URL for a PMID.
+pub fn esummary_url(pmid: &str) -> String {
+    format!("{}?db=pubmed&retmode=json&id={}", ESUMMARY_ENDPOINT, pmid)
+}
+
+#[derive(Deserialize)]
+struct ESummary {
+    result: Value,
+}
+
+#[derive(Deserialize)]
+struct ArticleId {
+    idtype: String,
+    value: String,
+}
+
+/// The DOI listed for `pmid` in an E-utilities summary response.
+pub fn doi_from_esummary(json: &str, pmid: &str) -> Result<String, ImportError> {
+    let summary: ESummary =
+        serde_json::from_str(json).map_err(|err| ImportError::Parse(err.to_string()))?;
+    let ids = summary
+        .result
+        .get(pmid)
+        .and_then(|article| article.get("articleids"))
+        .ok_or(ImportError::MissingField(format!("result.{}", pmid)))?;
+    let ids: Vec<ArticleId> =
+        serde_json::from_value(ids.clone()).map_err(|err| ImportError::Parse(err.to_string()))?;
+    ids.into_iter()
+        .find(|id| id.idtype == "doi")
+        .map(|id| id.value)
+        .ok_or(ImportError::MissingField("doi".to_string()))
+}
+
+/// Import a PubMed article. The PMID is resolved to the article's DOI,
+/// which is then imported from Crossref, so articles without a DOI can't
+/// be imported this way. Both requests count as one PubMed lookup.
+pub fn fetch_pmid<C: HttpClient + ?Sized>(client: &C, pmid: &str) -> Result<Citation, ImportError> {
+    let result = fetch_body(client, esummary_url(pmid))
+        .and_then(|body| doi_from_esummary(&body, pmid))
+        .and_then(|doi| lookup_doi(client, &doi))
+        .map(|mut citation| {
+            citation
+                .common_data_mut()
+                .identifiers
+                .insert(Identifier::Pmid(pmid.to_string()));
+            citation
+        });
+    record_lookup("pubmed", &result);
+    result
+}
+
+/// [`fetch_pmid`] with an [`AsyncHttpClient`]
+pub async fn fetch_pmid_async<C: AsyncHttpClient>(
+    client: &C,
+    pmid: &str,
+) -> Result<Citation, ImportError> {
+    let result = async {
+        let body = fetch_body_async(client, esummary_url(pmid)).await?;
+        let doi = doi_from_esummary(&body, pmid)?;
+        let mut citation = lookup_doi_async(client, &doi).await?;
+        citation
+            .common_data_mut()
+            .identifiers
+            .insert(Identifier::Pmid(pmid.to_string()));
+        Ok(citation)
+    }
+    .await;
+    record_lookup("pubmed", &result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::import::{
+        ImportError,
+        pubmed::{doi_from_esummary, normalize_pmid},
+    };
+
+    #[test]
+    fn test_normalize_pmid() {
+        assert_eq!(
+            normalize_pmid("PMID: 12345678"),
+            Some("12345678".to_string())
+        );
+        assert_eq!(
+            normalize_pmid("https://pubmed.ncbi.nlm.nih.gov/31452104/"),
+            Some("31452104".to_string())
+        );
+        assert_eq!(normalize_pmid("123456789"), None);
+        assert_eq!(normalize_pmid("12a"), None);
+    }
+
+    #[test]
+    fn test_doi_from_esummary() {
+        let json = r#"{"result": {"uids": ["31452104"], "31452104": {
+            "articleids": [
+                {"idtype": "pubmed", "value": "31452104"},
+                {"idtype": "doi", "value": "10.1000/xyz123"}
+            ]
+        }}}"#;
+
+        assert_eq!(
+            doi_from_esummary(json, "31452104"),
+            Ok("10.1000/xyz123".to_string())
+        );
+        assert!(matches!(
+            doi_from_esummary(json, "1"),
+            Err(ImportError::MissingField(_))
+        ));
+    }
+}
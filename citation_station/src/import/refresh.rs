@@ -0,0 +1,253 @@
+//! Refreshing imported entries from their DOI.
+//!
+//! Metadata fetched again from Crossref is compared with the entry rather
+//! than written over it, so the user can review what would change. The
+//! entry's ID, tags, references and timestamps belong to the user and are
+//! never proposed for change.
+
+use serde_json::Value;
+
+use crate::{
+    api::citation::Citation,
+    import::{ImportError, doi::fetch_doi},
+    net::HttpClient,
+};
+
+/// One field whose canonical value differs from the entry's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// Field name as serialized, e.g. "title" or "common_data.published".
+    /// "type" when the media type itself changed.
+    pub field: String,
+    /// The entry's value, or `None` if it doesn't have the field
+    pub current: Option<Value>,
+    /// The canonical value, or `None` if the field would be removed
+    pub proposed: Option<Value>,
+}
+
+/// Changes proposed by [`refresh_from_doi`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataRefresh {
+    /// The entry as it would be after accepting every change
+    pub proposed: Citation,
+    /// Fields that differ, sorted by name
+    pub changes: Vec<FieldChange>,
+}
+
+impl MetadataRefresh {
+    /// Whether the entry already matches the canonical metadata
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Accept every proposed change
+    pub fn apply(self, citation: &mut Citation) {
+        *citation = self.proposed;
+    }
+}
+
+/// Re-fetch the canonical metadata of `citation` from its DOI with
+/// `client` and compare it with the entry.
+///
+/// Fails with [`ImportError::MissingField`] if the entry has no DOI.
+pub fn refresh_from_doi<C: HttpClient + ?Sized>(
+    client: &C,
+    citation: &Citation,
+) -> Result<MetadataRefresh, ImportError> {
+    let doi = citation
+        .doi()
+        .ok_or(ImportError::MissingField("doi".to_string()))?;
+    Ok(compare(citation, fetch_doi(client, &doi)?))
+}
+
+/// Compare `citation` with freshly fetched metadata for the same work.
+/// The entry's own data (ID, tags, references, timestamps) is carried
+/// over, and its identifiers are kept unless the fetched metadata has one
+/// of the same kind.
+pub fn compare(citation: &Citation, fetched: Citation) -> MetadataRefresh {
+    let mut proposed = fetched;
+    let current_data = citation.common_data();
+    let proposed_data = proposed.common_data_mut();
+    proposed_data.id = current_data.id.clone();
+    proposed_data.cites = current_data.cites.clone();
+    proposed_data.tags = current_data.tags.clone();
+    proposed_data.timestamps = current_data.timestamps;
+    let mut identifiers = current_data.identifiers.clone();
+    for identifier in proposed_data.identifiers.iter() {
+        identifiers.insert(identifier.clone());
+    }
+    proposed_data.identifiers = identifiers;
+
+    let changes = field_changes(citation, &proposed);
+    MetadataRefresh { proposed, changes }
+}
+
+fn field_changes(current: &Citation, proposed: &Citation) -> Vec<FieldChange> {
+    let (Some((current_type, current)), Some((proposed_type, proposed))) =
+        (variant(current), variant(proposed))
+    else {
+        return Vec::new();
+    };
+    if current_type != proposed_type {
+        return vec![FieldChange {
+            field: "type".to_string(),
+            current: Some(Value::String(current_type)),
+            proposed: Some(Value::String(proposed_type)),
+        }];
+    }
+
+    let mut changes = Vec::new();
+    diff_objects("", &current, &proposed, &mut changes);
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+/// The media type and fields of a serialized citation
+fn variant(citation: &Citation) -> Option<(String, Value)> {
+    match serde_json::to_value(citation).ok()? {
+        Value::Object(map) => map.into_iter().next(),
+        _ => None,
+    }
+}
+
+/// Compare two objects field by field, descending into `common_data`
+fn diff_objects(prefix: &str, current: &Value, proposed: &Value, changes: &mut Vec<FieldChange>) {
+    let empty = serde_json::Map::new();
+    let current = current.as_object().unwrap_or(&empty);
+    let proposed = proposed.as_object().unwrap_or(&empty);
+    let mut fields: Vec<&String> = current.keys().chain(proposed.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    for field in fields {
+        let name = format!("{}{}", prefix, field);
+        let (before, after) = (current.get(field), proposed.get(field));
+        if before == after {
+            continue;
+        }
+        if field == "common_data" && prefix.is_empty() {
+            diff_objects(
+                "common_data.",
+                before.unwrap_or(&Value::Null),
+                after.unwrap_or(&Value::Null),
+                changes,
+            );
+            continue;
+        }
+        // A missing field and a null one both mean "not set"
+        let set = |value: Option<&Value>| value.filter(|value| !value.is_null()).cloned();
+        let (before, after) = (set(before), set(after));
+        if before != after {
+            changes.push(FieldChange {
+                field: name,
+                current: before,
+                proposed: after,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+        },
+        import::{
+            ImportError,
+            refresh::{FieldChange, compare, refresh_from_doi},
+        },
+        net::{FetchError, HttpClient, HttpResponse},
+    };
+
+    struct FakeCrossref;
+
+    impl HttpClient for FakeCrossref {
+        fn get(&self, _url: &str) -> Result<HttpResponse, FetchError> {
+            Ok(HttpResponse::new(
+                200,
+                r#"{"message": {"DOI": "10.1000/xyz", "type": "journal-article",
+                    "title": ["Canonical title"], "container-title": ["J. Tests"],
+                    "author": [{"given": "Jane", "family": "Smith"}],
+                    "issued": {"date-parts": [[2021]]}}}"#
+                    .to_string(),
+            ))
+        }
+    }
+
+    fn stale() -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2020)),
+                tags: vec!["to-read".to_string()],
+                ..CommonCitationData::new("smith2021")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            title: "Imported title".into(),
+            journal: "J. Tests".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: Some("10.1000/xyz".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_refresh_proposes_changes() {
+        let mut citation = stale();
+        let refresh = refresh_from_doi(&FakeCrossref, &citation).unwrap();
+
+        let fields: Vec<&str> = refresh
+            .changes
+            .iter()
+            .map(|change| change.field.as_str())
+            .collect();
+        assert_eq!(fields, ["common_data.published", "title"]);
+        // Nothing is written until the changes are applied
+        assert_eq!(citation.title(), "Imported title");
+
+        refresh.apply(&mut citation);
+        assert_eq!(citation.title(), "Canonical title");
+        assert_eq!(citation.id(), "smith2021");
+        assert_eq!(citation.tags(), ["to-read"]);
+    }
+
+    #[test]
+    fn test_unchanged_and_missing_doi() {
+        let citation = stale();
+        assert!(compare(&citation, citation.clone()).is_unchanged());
+
+        let mut without_doi = citation.clone();
+        if let Citation::JournalArticle(article) = &mut without_doi {
+            article.doi = None;
+        }
+        assert!(matches!(
+            refresh_from_doi(&FakeCrossref, &without_doi),
+            Err(ImportError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn test_removed_field() {
+        let citation = stale();
+        let mut fetched = citation.clone();
+        if let Citation::JournalArticle(article) = &mut fetched {
+            article.common_data.published = None;
+        }
+        assert_eq!(
+            compare(&citation, fetched).changes,
+            [FieldChange {
+                field: "common_data.published".to_string(),
+                current: Some(json!({ "Year": { "year": 2020 } })),
+                proposed: None,
+            }]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use scraper::Html;
+
+use crate::{
+    api::{
+        author::GenericAuthor,
+        citation::Citation,
+        date::AccessDate,
+        media::{common::CommonCitationData, web_page::WebPage},
+    },
+    html::{HtmlParser, title::TitleCleaner},
+    import::{ImportError, fetch_body, fetch_body_async, record_lookup},
+    net::{AsyncHttpClient, HttpClient},
+};
+
+/// Trim a URL and add a scheme to "www." URLs. Returns `None` for anything
+/// that isn't an http(s) URL.
+pub fn normalize_url(input: &str) -> Option<String> {
+    let url = input.trim();
+    let lowercase = url.to_lowercase();
+    let url = if lowercase.starts_with("www.") {
+        format!("https://{}", url)
+    } else {
+        url.to_string()
+    };
+    let rest = ["https://", "http://"]
+        .iter()
+        .find_map(|scheme| url.to_lowercase().strip_prefix(scheme).map(str::len))?;
+    (rest > 0 && !url.contains(char::is_whitespace)).then_some(url)
+}
+
+/// Download a page and turn it into a web page citation.
+pub fn fetch_web_page<C: HttpClient + ?Sized>(
+    client: &C,
+    url: &str,
+) -> Result<Citation, ImportError> {
+    let result = fetch_body(client, url.to_string())
+        .and_then(|body| citation_from_html(&body, url, AccessDate::today()));
+    record_lookup("web", &result);
+    result
+}
+
+/// [`fetch_web_page`] with an [`AsyncHttpClient`]
+pub async fn fetch_web_page_async<C: AsyncHttpClient>(
+    client: &C,
+    url: &str,
+) -> Result<Citation, ImportError> {
+    let result = fetch_body_async(client, url.to_string())
+        .await
+        .and_then(|body| citation_from_html(&body, url, AccessDate::today()));
+    record_lookup("web", &result);
+    result
+}
+
+/// Build a web page citation from a page's HTML.
+///
+/// The title is cleaned of the site name, the authors are taken from the
+/// most reliable source that names any, and the most confident publish
+/// date is used. The URL is used as the citation ID.
+pub fn citation_from_html(
+    html: &str,
+    url: &str,
+    accessed: AccessDate,
+) -> Result<Citation, ImportError> {
+    let html = Html::parse_document(html);
+    let parser = HtmlParser::new();
+
+    let authors = parser.parse_authors(&html);
+    let published = parser
+        .parse_publish_dates(&html)
+        .into_iter()
+        .next()
+        .map(|candidate| candidate.date);
+    let clean = parser
+        .parse_title(html)
+        .clean(&TitleCleaner::new())
+        .ok_or(ImportError::MissingField("title".to_string()))?;
+
+    let persons: Vec<_> = match authors.first() {
+        Some(best) => authors
+            .iter()
+            .filter(|candidate| candidate.source == best.source)
+            .map(|candidate| candidate.name.clone())
+            .collect(),
+        None => Vec::new(),
+    };
+    let author = (!persons.is_empty()).then_some(GenericAuthor::Persons { persons });
+
+    Ok(Citation::WebPage(WebPage {
+        common_data: CommonCitationData {
+            published,
+            ..CommonCitationData::new(url)
+        },
+        author,
+        title: clean.title.into(),
+        site_name: clean.site_name,
+        url: url.to_string(),
+        accessed,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveDate};
+
+    use crate::{
+        api::{citation::Citation, date::PublishDate},
+        import::web::{citation_from_html, normalize_url},
+    };
+
+    #[test]
+    fn test_normalize_url() {
+        assert_eq!(
+            normalize_url(" www.example.com/a "),
+            Some("https://www.example.com/a".to_string())
+        );
+        assert_eq!(
+            normalize_url("http://example.com"),
+            Some("http://example.com".to_string())
+        );
+        assert_eq!(normalize_url("ftp://example.com"), None);
+        assert_eq!(normalize_url("https://"), None);
+    }
+
+    #[test]
+    fn test_citation_from_html() {
+        let html = r#"
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <title>Why Rust? | Example Blog</title>
+    <meta property="og:site_name" content="Example Blog">
+    <meta name="author" content="Jane Smith">
+    <meta property="article:published_time" content="2023-03-05T09:30:00Z">
+</head>
+</html>
+"#;
+        let accessed = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into();
+
+        let Citation::WebPage(page) =
+            citation_from_html(html, "https://example.com/why-rust", accessed).unwrap()
+        else {
+            panic!("expected a web page");
+        };
+
+        assert_eq!(page.title, "Why Rust?");
+        assert_eq!(page.site_name, Some("Example Blog".to_string()));
+        assert_eq!(
+            page.common_data.published,
+            Some(PublishDate::from_year_month_day(2023, Month::March, 5).unwrap())
+        );
+        assert_eq!(page.author.unwrap().contributors().len(), 1);
+    }
+
+    #[test]
+    fn test_citation_from_html_without_title() {
+        let accessed = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into();
+
+        assert!(citation_from_html("<html></html>", "https://example.com", accessed).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        citation::Citation, date::AccessDate, date::PublishDate,
+        media::wikipedia_article::WikipediaArticle,
+    },
+    import::{ImportError, fetch_body, fetch_body_async, record_lookup},
+    net::{AsyncHttpClient, HttpClient},
+};
+
+/// The MediaWiki API URL that looks up the cited revision of an article:
+/// the revision in the link if there is one, otherwise the latest.
+pub fn revision_api_url(article: &WikipediaArticle) -> String {
+    let base = format!(
+        "https://{}.wikipedia.org/w/api.php?action=query&format=json&formatversion=2\
+         &prop=revisions&rvprop=ids%7Ctimestamp",
+        article.language
+    );
+    match article.revision {
+        Some(revision) => format!("{}&revids={}", base, revision),
+        None => {
+            let title = article
+                .article_url()
+                .rsplit_once("/wiki/")
+                .map(|(_, title)| title.to_string())
+                .unwrap_or_default();
+            format!("{}&titles={}", base, title)
+        }
+    }
+}
+
+/// Import a Wikipedia article from its URL, pinning the citation to a
+/// permanent revision link.
+///
+/// If the URL already names a revision it is kept; otherwise the latest
+/// revision at the time of the import is used.
+pub fn fetch_wikipedia<C: HttpClient + ?Sized>(
+    client: &C,
+    url: &str,
+) -> Result<Citation, ImportError> {
+    let article = WikipediaArticle::from_url(url, AccessDate::today())
+        .ok_or(ImportError::InvalidIdentifier(url.to_string()))?;
+    let result = fetch_body(client, revision_api_url(&article))
+        .and_then(|body| article_with_revision(article, &body).map(Citation::WikipediaArticle));
+    record_lookup("wikipedia", &result);
+    result
+}
+
+/// [`fetch_wikipedia`] with an [`AsyncHttpClient`]
+pub async fn fetch_wikipedia_async<C: AsyncHttpClient>(
+    client: &C,
+    url: &str,
+) -> Result<Citation, ImportError> {
+    let article = WikipediaArticle::from_url(url, AccessDate::today())
+        .ok_or(ImportError::InvalidIdentifier(url.to_string()))?;
+    let result = fetch_body_async(client, revision_api_url(&article))
+        .await
+        .and_then(|body| article_with_revision(article, &body).map(Citation::WikipediaArticle));
+    record_lookup("wikipedia", &result);
+    result
+}
+
+#[derive(Deserialize)]
+struct QueryEnvelope {
+    query: Query,
+}
+
+#[derive(Deserialize)]
+struct Query {
+    #[serde(default)]
+    pages: Vec<Page>,
+}
+
+#[derive(Deserialize)]
+struct Page {
+    title: String,
+    #[serde(default)]
+    missing: bool,
+    #[serde(default)]
+    revisions: Vec<Revision>,
+}
+
+#[derive(Deserialize)]
+struct Revision {
+    revid: u64,
+    timestamp: DateTime<Utc>,
+}
+
+/// Fill in the revision ID, revision date and canonical title from a
+/// MediaWiki revisions query response.
+pub fn article_with_revision(
+    mut article: WikipediaArticle,
+    json: &str,
+) -> Result<WikipediaArticle, ImportError> {
+    let envelope: QueryEnvelope =
+        serde_json::from_str(json).map_err(|err| ImportError::Parse(err.to_string()))?;
+    let page = envelope
+        .query
+        .pages
+        .into_iter()
+        .find(|page| !page.missing)
+        .ok_or(ImportError::MissingField("pages".to_string()))?;
+    let revision = page
+        .revisions
+        .into_iter()
+        .next()
+        .ok_or(ImportError::MissingField("revisions".to_string()))?;
+
+    article.title = page.title;
+    article.common_data.id = article.default_id();
+    article.revision = Some(revision.revid);
+    article.common_data.published = Some(PublishDate::from_chrono_utc_datetime(revision.timestamp));
+    Ok(article)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, NaiveDate};
+
+    use crate::{
+        api::{citation::Citation, date::PublishDate, media::wikipedia_article::WikipediaArticle},
+        import::wikipedia::{article_with_revision, fetch_wikipedia, revision_api_url},
+        net::{FetchError, HttpClient, HttpResponse},
+    };
+
+    const RESPONSE: &str = r#"{"batchcomplete": true, "query": {"pages": [{
+        "pageid": 4924, "ns": 0, "title": "Oil painting",
+        "revisions": [{"revid": 931182215, "parentid": 930000000,
+                       "timestamp": "2019-12-17T08:30:00Z"}]}]}}"#;
+
+    fn current_article() -> WikipediaArticle {
+        WikipediaArticle::from_url(
+            "https://en.wikipedia.org/wiki/oil_painting",
+            NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_revision_api_url() {
+        let mut article = current_article();
+        assert!(revision_api_url(&article).ends_with("&titles=oil_painting"));
+
+        article.revision = Some(42);
+        assert!(revision_api_url(&article).ends_with("&revids=42"));
+    }
+
+    #[test]
+    fn test_article_with_revision() {
+        let article = article_with_revision(current_article(), RESPONSE).unwrap();
+
+        assert_eq!(article.title, "Oil painting");
+        assert_eq!(article.common_data.id, "wikipedia:en:Oil_painting");
+        assert_eq!(article.revision, Some(931182215));
+        assert_eq!(
+            article.common_data.published,
+            Some(PublishDate::from_year_month_day(2019, Month::December, 17).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_article_with_revision_missing_page() {
+        let missing = r#"{"query": {"pages": [{"title": "Nope", "missing": true}]}}"#;
+
+        assert!(article_with_revision(current_article(), missing).is_err());
+    }
+
+    struct FakeWikipedia;
+
+    impl HttpClient for FakeWikipedia {
+        fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+            assert!(url.starts_with("https://en.wikipedia.org/w/api.php?"));
+            Ok(HttpResponse::new(200, RESPONSE.to_string()))
+        }
+    }
+
+    #[test]
+    fn test_fetch_wikipedia() {
+        let citation =
+            fetch_wikipedia(&FakeWikipedia, "https://en.wikipedia.org/wiki/Oil_painting").unwrap();
+
+        assert_eq!(
+            citation.url().as_deref(),
+            Some("https://en.wikipedia.org/w/index.php?title=Oil_painting&oldid=931182215")
+        );
+        assert!(matches!(citation, Citation::WikipediaArticle(_)));
+    }
+}
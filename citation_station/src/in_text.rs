@@ -0,0 +1,735 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    api::{author::Contributor, citation::Citation},
+    unicode::ENDASH,
+};
+
+/// A reference to one bibliography entry from the body of a document,
+/// optionally pointing at a specific part of the work.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiteRef {
+    /// ID of the cited entry
+    pub id: String,
+    /// Pin-point locator such as "p. 12" or "ch. 3"
+    pub locator: Option<String>,
+}
+
+impl CiteRef {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            locator: None,
+        }
+    }
+
+    pub fn with_locator(id: &str, locator: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            locator: Some(locator.to_string()),
+        }
+    }
+}
+
+/// An author-date in-text citation, e.g. "(Smith & Jones, 2023a)".
+///
+/// Disambiguation rules taken from:
+/// * https://apastyle.apa.org/style-grammar-guidelines/citations/basic-principles/author-date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InTextCitation {
+    /// Names shown before the date
+    pub names: Vec<String>,
+    /// Whether the remaining authors are abbreviated as "et al."
+    pub et_al: bool,
+    /// Year of publication, or "n.d." if there is none
+    pub year: String,
+    /// Letter(s) telling apart works by the same authors in the same year
+    pub suffix: Option<String>,
+    /// Abbreviation of a sole organizational author, introduced at the
+    /// first citation and used in place of its name afterwards
+    pub abbreviation: Option<String>,
+}
+
+impl InTextCitation {
+    pub(crate) fn author_part(&self, conjunction: &str) -> String {
+        match (self.names.as_slice(), self.et_al) {
+            ([], _) => String::new(),
+            ([only], false) => only.clone(),
+            ([first], true) => format!("{} et al.", first),
+            ([first, second], false) => format!("{} {} {}", first, conjunction, second),
+            (names, true) => format!("{}, et al.", names.join(", ")),
+            ([names @ .., last], false) => {
+                format!("{}, {} {}", names.join(", "), conjunction, last)
+            }
+        }
+    }
+
+    pub(crate) fn date_part(&self) -> String {
+        match &self.suffix {
+            None => self.year.clone(),
+            Some(suffix) if self.year == NO_DATE => format!("{}-{}", self.year, suffix),
+            Some(suffix) => format!("{}{}", self.year, suffix),
+        }
+    }
+
+    /// e.g. "(Smith & Jones, 2023a)"
+    pub fn parenthetical(&self) -> String {
+        format!("({})", self)
+    }
+
+    /// e.g. "Smith and Jones (2023a)"
+    pub fn narrative(&self) -> String {
+        format!("{} ({})", self.author_part("and"), self.date_part())
+    }
+
+    /// The parenthetical citation for the first mention of a work, which
+    /// introduces the author's abbreviation, e.g.
+    /// "(World Health Organization [WHO], 2020)"
+    pub fn first_parenthetical(&self) -> String {
+        match &self.abbreviation {
+            Some(abbreviation) => format!(
+                "({} [{}], {})",
+                self.author_part("&"),
+                abbreviation,
+                self.date_part()
+            ),
+            None => self.parenthetical(),
+        }
+    }
+
+    /// The narrative citation for the first mention of a work, e.g.
+    /// "World Health Organization (WHO, 2020)"
+    pub fn first_narrative(&self) -> String {
+        match &self.abbreviation {
+            Some(abbreviation) => format!(
+                "{} ({}, {})",
+                self.author_part("and"),
+                abbreviation,
+                self.date_part()
+            ),
+            None => self.narrative(),
+        }
+    }
+
+    /// The citation for mentions after the first, with the author's
+    /// abbreviation in place of its name, e.g. "(WHO, 2020)"
+    pub fn abbreviated(&self) -> InTextCitation {
+        match &self.abbreviation {
+            Some(abbreviation) => InTextCitation {
+                names: vec![abbreviation.clone()],
+                abbreviation: None,
+                ..self.clone()
+            },
+            None => self.clone(),
+        }
+    }
+}
+
+/// The contents of a parenthetical citation without the parentheses, so
+/// several can be joined into one cluster.
+impl fmt::Display for InTextCitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.author_part("&"), self.date_part())
+    }
+}
+
+const NO_DATE: &str = "n.d.";
+const APA_IN_TEXT_ET_AL_CUTOFF: usize = 3;
+
+struct Entry {
+    id: String,
+    title: String,
+    contributors: Vec<Contributor>,
+    year: String,
+    with_initials: bool,
+    shown: usize,
+}
+
+impl Entry {
+    fn names(&self) -> Vec<String> {
+        if self.contributors.is_empty() {
+            return vec![self.title.clone()];
+        }
+        self.contributors
+            .iter()
+            .take(self.shown)
+            .enumerate()
+            .map(|(index, contributor)| match contributor {
+                Contributor::Person(person) if index == 0 && self.with_initials => {
+                    format!("{} {}", person.initials(), person.surname())
+                }
+                contributor => contributor.short_name().to_string(),
+            })
+            .collect()
+    }
+
+    fn key(&self) -> (Vec<String>, bool, String) {
+        (
+            self.names(),
+            self.shown < self.contributors.len(),
+            self.year.clone(),
+        )
+    }
+}
+
+fn same_contributor(a: &Contributor, b: &Contributor) -> bool {
+    match (a, b) {
+        (Contributor::Person(a), Contributor::Person(b)) => {
+            a.surname() == b.surname() && a.initials() == b.initials()
+        }
+        (Contributor::Organization { name: a, .. }, Contributor::Organization { name: b, .. }) => {
+            a == b
+        }
+        (Contributor::Pseudonym { name: a, .. }, Contributor::Pseudonym { name: b, .. }) => a == b,
+        (Contributor::Anonymous, Contributor::Anonymous) => true,
+        _ => false,
+    }
+}
+
+fn same_contributors(a: &[Contributor], b: &[Contributor]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_contributor(a, b))
+}
+
+/// "a".."z", then "aa", "ab", ...
+fn year_suffix(index: usize) -> String {
+    let letter = |n: usize| char::from(b'a' + (n % 26) as u8);
+    if index < 26 {
+        letter(index).to_string()
+    } else {
+        format!("{}{}", letter(index / 26 - 1), letter(index))
+    }
+}
+
+/// Compute the shortest unambiguous in-text citation for every citation.
+///
+/// The APA rules are applied in order: first authors who share a surname
+/// but are different people get their initials; works whose abbreviated
+/// author lists collide get as many names spelled out as needed; works
+/// that still can't be told apart (same authors, same year) get year
+/// suffixes ordered by title.
+pub(crate) fn disambiguate(citations: &[Citation]) -> HashMap<String, InTextCitation> {
+    let mut entries: Vec<Entry> = citations
+        .iter()
+        .map(|citation| {
+            let contributors = citation.contributors();
+            let shown = if contributors.len() >= APA_IN_TEXT_ET_AL_CUTOFF {
+                1
+            } else {
+                contributors.len()
+            };
+            Entry {
+                id: citation.id(),
+                title: citation.title(),
+                contributors,
+                year: citation
+                    .published()
+                    .map(|published| published.year().to_string())
+                    .unwrap_or(NO_DATE.to_string()),
+                with_initials: false,
+                shown,
+            }
+        })
+        .collect();
+
+    // Different first authors with the same surname
+    let first_persons: Vec<Option<(String, String)>> = entries
+        .iter()
+        .map(|entry| match entry.contributors.first() {
+            Some(Contributor::Person(person)) => {
+                Some((person.surname().to_string(), person.initials()))
+            }
+            _ => None,
+        })
+        .collect();
+    for (index, entry) in entries.iter_mut().enumerate() {
+        if let Some((surname, initials)) = &first_persons[index] {
+            entry.with_initials = first_persons
+                .iter()
+                .flatten()
+                .any(|(other_surname, other)| other_surname == surname && other != initials);
+        }
+    }
+
+    // Colliding abbreviated author lists
+    loop {
+        let mut groups: HashMap<(Vec<String>, bool, String), Vec<usize>> = HashMap::new();
+        for (index, entry) in entries.iter().enumerate() {
+            groups.entry(entry.key()).or_default().push(index);
+        }
+
+        let mut expand: HashSet<usize> = HashSet::new();
+        for members in groups.values() {
+            for &a in members {
+                for &b in members {
+                    if !same_contributors(&entries[a].contributors, &entries[b].contributors) {
+                        expand.insert(a);
+                    }
+                }
+            }
+        }
+        expand.retain(|&index| entries[index].shown < entries[index].contributors.len());
+        if expand.is_empty() {
+            break;
+        }
+        for index in expand {
+            entries[index].shown += 1;
+        }
+    }
+
+    // Same authors, same year
+    let mut groups: HashMap<(Vec<String>, bool, String), Vec<usize>> = HashMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        groups.entry(entry.key()).or_default().push(index);
+    }
+    let mut suffixes: HashMap<usize, String> = HashMap::new();
+    for mut members in groups.into_values().filter(|members| members.len() > 1) {
+        members.sort_by(|&a, &b| {
+            (entries[a].title.to_lowercase(), &entries[a].id)
+                .cmp(&(entries[b].title.to_lowercase(), &entries[b].id))
+        });
+        for (position, index) in members.into_iter().enumerate() {
+            suffixes.insert(index, year_suffix(position));
+        }
+    }
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let (names, et_al, year) = entry.key();
+            (
+                entry.id.clone(),
+                InTextCitation {
+                    names,
+                    et_al,
+                    year,
+                    suffix: suffixes.remove(&index),
+                    abbreviation: match entry.contributors.as_slice() {
+                        [Contributor::Organization { abbreviation, .. }] => abbreviation.clone(),
+                        _ => None,
+                    },
+                },
+            )
+        })
+        .collect()
+}
+
+/// Join author-date citations into one parenthetical, e.g.
+/// "(Jones, 2019; Smith, 2020a, 2020b, p. 4)".
+///
+/// Citations are sorted by author and date; works by the same authors are
+/// listed once with their dates separated by commas.
+pub(crate) fn apa_cluster(items: &[(InTextCitation, Option<String>)]) -> String {
+    author_date_cluster(items, "&", ", ")
+}
+
+/// Join author-date citations into an ISO 690 cluster, e.g.
+/// "(Jones 2019; Smith and Lee 2020a, 2020b, p. 4)".
+pub(crate) fn iso690_cluster(items: &[(InTextCitation, Option<String>)]) -> String {
+    author_date_cluster(items, "and", " ")
+}
+
+/// `conjunction` joins the last two authors and `date_separator` sits
+/// between the authors and the first date.
+fn author_date_cluster(
+    items: &[(InTextCitation, Option<String>)],
+    conjunction: &str,
+    date_separator: &str,
+) -> String {
+    let mut sorted: Vec<&(InTextCitation, Option<String>)> = items.iter().collect();
+    sorted.sort_by_key(|(citation, _)| {
+        (
+            citation.author_part(conjunction).to_lowercase(),
+            citation.date_part(),
+        )
+    });
+    sorted.dedup();
+
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    for (citation, locator) in sorted {
+        let authors = citation.author_part(conjunction);
+        let date = match locator {
+            Some(locator) => format!("{}, {}", citation.date_part(), locator),
+            None => citation.date_part(),
+        };
+        match groups.last_mut() {
+            Some((last_authors, dates)) if *last_authors == authors => dates.push(date),
+            _ => groups.push((authors, vec![date])),
+        }
+    }
+
+    let groups: Vec<String> = groups
+        .into_iter()
+        .map(|(authors, dates)| format!("{}{}{}", authors, date_separator, dates.join(", ")))
+        .collect();
+    format!("({})", groups.join("; "))
+}
+
+/// Join numeric references into an IEEE cluster, e.g. "[3], [5]–[7], [9, p. 4]".
+pub(crate) fn ieee_cluster(items: &[(usize, Option<String>)]) -> String {
+    let mut sorted: Vec<&(usize, Option<String>)> = items.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+
+    let mut parts: Vec<String> = Vec::new();
+    let mut run: Vec<usize> = Vec::new();
+    for (number, locator) in sorted {
+        match locator {
+            None => run.push(*number),
+            Some(locator) => {
+                parts.extend(collapse_run(&run, ieee_number));
+                run.clear();
+                parts.push(format!("[{}, {}]", number, locator));
+            }
+        }
+    }
+    parts.extend(collapse_run(&run, ieee_number));
+    parts.join(", ")
+}
+
+/// Join numeric references into a Vancouver cluster, e.g. "(3, 5–7, 9 p. 4)".
+pub(crate) fn vancouver_cluster(items: &[(usize, Option<String>)]) -> String {
+    let mut sorted: Vec<&(usize, Option<String>)> = items.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+
+    let mut parts: Vec<String> = Vec::new();
+    let mut run: Vec<usize> = Vec::new();
+    for (number, locator) in sorted {
+        match locator {
+            None => run.push(*number),
+            Some(locator) => {
+                parts.extend(collapse_run(&run, usize::to_string));
+                run.clear();
+                parts.push(format!("{} {}", number, locator));
+            }
+        }
+    }
+    parts.extend(collapse_run(&run, usize::to_string));
+    format!("({})", parts.join(", "))
+}
+
+/// Format IEEE reference numbers, collapsing runs of three or more
+/// consecutive numbers into a range: `[1, 2, 3, 4, 7]` becomes
+/// "[1]–[4], [7]".
+///
+/// The input may be in any order and contain repeats. Two consecutive
+/// numbers are listed separately ("[3], [4]"), as IEEE only uses a range
+/// when it saves space.
+pub fn collapse_reference_numbers(numbers: &[usize]) -> String {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    collapse_run(&sorted, ieee_number).join(", ")
+}
+
+fn ieee_number(number: &usize) -> String {
+    format!("[{}]", number)
+}
+
+/// Collapse sorted, unique numbers into parts formatted by `number`:
+/// [1, 2, 3, 4, 7] -> ["[1]–[4]", "[7]"].
+fn collapse_run(numbers: &[usize], number: fn(&usize) -> String) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while start < numbers.len() {
+        let mut end = start;
+        while end + 1 < numbers.len() && numbers[end + 1] == numbers[end] + 1 {
+            end += 1;
+        }
+        if end - start >= 2 {
+            parts.push(format!(
+                "{}{}{}",
+                number(&numbers[start]),
+                ENDASH,
+                number(&numbers[end])
+            ));
+        } else {
+            parts.extend(numbers[start..=end].iter().map(number));
+        }
+        start = end + 1;
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+        },
+        in_text::{
+            InTextCitation, apa_cluster, collapse_reference_numbers, disambiguate, ieee_cluster,
+            iso690_cluster, vancouver_cluster, year_suffix,
+        },
+    };
+
+    #[test]
+    fn test_collapse_reference_numbers() {
+        assert_eq!(
+            collapse_reference_numbers(&[1, 2, 3, 4, 7]),
+            "[1]\u{2013}[4], [7]"
+        );
+    }
+
+    #[test]
+    fn test_collapse_reference_numbers_unsorted_with_repeats() {
+        assert_eq!(
+            collapse_reference_numbers(&[9, 2, 3, 2, 10, 11, 1]),
+            "[1]\u{2013}[3], [9]\u{2013}[11]"
+        );
+    }
+
+    #[test]
+    fn test_collapse_reference_numbers_pairs_and_singles() {
+        assert_eq!(collapse_reference_numbers(&[3, 4]), "[3], [4]");
+        assert_eq!(collapse_reference_numbers(&[5]), "[5]");
+        assert_eq!(collapse_reference_numbers(&[]), "");
+    }
+
+    fn in_text(name: &str, year: &str, suffix: Option<&str>) -> InTextCitation {
+        InTextCitation {
+            names: vec![name.to_string()],
+            et_al: false,
+            year: year.to_string(),
+            suffix: suffix.map(str::to_string),
+            abbreviation: None,
+        }
+    }
+
+    #[test]
+    fn test_apa_cluster_sorts_by_author() {
+        let cluster = apa_cluster(&[
+            (in_text("Smith", "2023", None), None),
+            (in_text("Jones", "2019", None), None),
+        ]);
+
+        assert_eq!(cluster, "(Jones, 2019; Smith, 2023)");
+    }
+
+    #[test]
+    fn test_apa_cluster_merges_same_author() {
+        let cluster = apa_cluster(&[
+            (in_text("Smith", "2020", Some("b")), None),
+            (
+                in_text("Smith", "2020", Some("a")),
+                Some("p. 4".to_string()),
+            ),
+            (in_text("Smith", "2018", None), None),
+        ]);
+
+        assert_eq!(cluster, "(Smith, 2018, 2020a, p. 4, 2020b)");
+    }
+
+    #[test]
+    fn test_ieee_cluster_collapses_ranges() {
+        let cluster = ieee_cluster(&[(7, None), (3, None), (5, None), (6, None), (5, None)]);
+
+        assert_eq!(cluster, "[3], [5]\u{2013}[7]");
+    }
+
+    #[test]
+    fn test_ieee_cluster_keeps_pairs_and_locators_separate() {
+        let cluster = ieee_cluster(&[
+            (1, None),
+            (2, None),
+            (3, Some("p. 4".to_string())),
+            (4, None),
+        ]);
+
+        assert_eq!(cluster, "[1], [2], [3, p. 4], [4]");
+    }
+
+    #[test]
+    fn test_iso690_cluster() {
+        let cluster = iso690_cluster(&[
+            (in_text("Smith", "2023", None), Some("p. 4".to_string())),
+            (in_text("Jones", "2019", None), None),
+        ]);
+
+        assert_eq!(cluster, "(Jones 2019; Smith 2023, p. 4)");
+    }
+
+    #[test]
+    fn test_vancouver_cluster() {
+        let cluster = vancouver_cluster(&[
+            (9, Some("p. 4".to_string())),
+            (7, None),
+            (3, None),
+            (5, None),
+            (6, None),
+        ]);
+
+        assert_eq!(cluster, "(3, 5\u{2013}7, 9 p. 4)");
+    }
+
+    fn article(id: &str, title: &str, authors: &[(&str, &str)], year: Option<i32>) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: year.map(PublishDate::from_year),
+                ..CommonCitationData::new(id)
+            },
+            author: AcademicAuthor::Persons {
+                persons: authors
+                    .iter()
+                    .map(|(first, last)| PersonName::from_first_last(first, last).unwrap())
+                    .collect(),
+            },
+            title: title.into(),
+            journal: "J. Tests".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: None,
+        })
+    }
+
+    #[test]
+    fn test_no_disambiguation_needed() {
+        let table = disambiguate(&[
+            article("a", "First", &[("Jane", "Smith")], Some(2023)),
+            article("b", "Second", &[("Al", "Jones"), ("Bo", "Lee")], Some(2019)),
+        ]);
+
+        assert_eq!(table["a"].parenthetical(), "(Smith, 2023)");
+        assert_eq!(table["b"].parenthetical(), "(Jones & Lee, 2019)");
+        assert_eq!(table["b"].narrative(), "Jones and Lee (2019)");
+    }
+
+    #[test]
+    fn test_same_surname_different_first_authors() {
+        let table = disambiguate(&[
+            article("a", "First", &[("Jane", "Smith")], Some(2023)),
+            article("b", "Second", &[("Adam", "Smith")], Some(2019)),
+        ]);
+
+        assert_eq!(table["a"].parenthetical(), "(J. Smith, 2023)");
+        assert_eq!(table["b"].parenthetical(), "(A. Smith, 2019)");
+    }
+
+    #[test]
+    fn test_same_author_same_year_gets_suffix_by_title() {
+        let table = disambiguate(&[
+            article("a", "Zebras", &[("Jane", "Smith")], Some(2023)),
+            article("b", "Aardvarks", &[("Jane", "Smith")], Some(2023)),
+            article("c", "Undated", &[("Jane", "Smith")], None),
+        ]);
+
+        assert_eq!(table["b"].parenthetical(), "(Smith, 2023a)");
+        assert_eq!(table["a"].parenthetical(), "(Smith, 2023b)");
+        assert_eq!(table["c"].parenthetical(), "(Smith, n.d.)");
+    }
+
+    #[test]
+    fn test_et_al_collision_spells_out_names() {
+        let table = disambiguate(&[
+            article(
+                "a",
+                "First",
+                &[("A", "Kapoor"), ("B", "Bloom"), ("C", "Montez")],
+                Some(2020),
+            ),
+            article(
+                "b",
+                "Second",
+                &[
+                    ("A", "Kapoor"),
+                    ("B", "Bloom"),
+                    ("D", "Zucker"),
+                    ("E", "Ng"),
+                ],
+                Some(2020),
+            ),
+        ]);
+
+        assert_eq!(
+            table["a"].parenthetical(),
+            "(Kapoor, Bloom, & Montez, 2020)"
+        );
+        assert_eq!(
+            table["b"].parenthetical(),
+            "(Kapoor, Bloom, Zucker, et al., 2020)"
+        );
+    }
+
+    #[test]
+    fn test_et_al_different_years_not_expanded() {
+        let table = disambiguate(&[
+            article(
+                "a",
+                "First",
+                &[("A", "Kapoor"), ("B", "Bloom"), ("C", "Montez")],
+                Some(2020),
+            ),
+            article(
+                "b",
+                "Second",
+                &[("A", "Kapoor"), ("D", "Zucker"), ("E", "Ng")],
+                Some(2021),
+            ),
+        ]);
+
+        assert_eq!(table["a"].parenthetical(), "(Kapoor et al., 2020)");
+        assert_eq!(table["b"].parenthetical(), "(Kapoor et al., 2021)");
+    }
+
+    #[test]
+    fn test_no_date_suffix_uses_hyphen() {
+        let citation = InTextCitation {
+            names: vec!["Smith".to_string()],
+            et_al: false,
+            year: "n.d.".to_string(),
+            suffix: Some("a".to_string()),
+            abbreviation: None,
+        };
+
+        assert_eq!(citation.parenthetical(), "(Smith, n.d.-a)");
+    }
+
+    #[test]
+    fn test_year_suffix() {
+        assert_eq!(year_suffix(0), "a");
+        assert_eq!(year_suffix(25), "z");
+        assert_eq!(year_suffix(26), "aa");
+        assert_eq!(year_suffix(27), "ab");
+    }
+
+    #[test]
+    fn test_organization_abbreviation() {
+        let Citation::JournalArticle(mut report) = article("who", "Report", &[], Some(2020)) else {
+            unreachable!()
+        };
+        report.author = AcademicAuthor::Organization {
+            name: "World Health Organization".to_string(),
+            abbreviation: Some("WHO".to_string()),
+        };
+        let table = disambiguate(&[Citation::JournalArticle(report)]);
+        let who = &table["who"];
+
+        assert_eq!(
+            who.first_parenthetical(),
+            "(World Health Organization [WHO], 2020)"
+        );
+        assert_eq!(
+            who.first_narrative(),
+            "World Health Organization (WHO, 2020)"
+        );
+        assert_eq!(who.abbreviated().parenthetical(), "(WHO, 2020)");
+        assert_eq!(who.abbreviated().narrative(), "WHO (2020)");
+        assert_eq!(who.parenthetical(), "(World Health Organization, 2020)");
+    }
+
+    #[test]
+    fn test_first_mention_without_abbreviation() {
+        let table = disambiguate(&[article("a", "First", &[("Jane", "Smith")], Some(2023))]);
+
+        assert_eq!(table["a"].first_parenthetical(), "(Smith, 2023)");
+        assert_eq!(table["a"].abbreviated(), table["a"]);
+    }
+}
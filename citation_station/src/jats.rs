@@ -0,0 +1,382 @@
+//! Writing reference lists as JATS XML, the tag set journal production
+//! systems use for articles, so a publisher can take a reference list
+//! as it is rather than re-keying it.
+//!
+//! Each citation becomes a `<ref>` with an `<element-citation>`, whose
+//! `publication-type` is one of the values JATS 1.3 recommends. Links
+//! use the `xlink` namespace, which JATS articles declare on their root
+//! element.
+//!
+//! Format reference: https://jats.nlm.nih.gov/publishing/tag-library/1.3/element/element-citation.html
+
+use crate::{
+    api::{
+        author::{Contributor, PersonName},
+        citation::Citation,
+        media::{online_manual::OnlineManualAvailability, version::GenericMediaVersion},
+        page_range::{ArticleLocator, PageRange},
+    },
+    export::iso_date,
+    xml::escape,
+};
+
+/// Write citations as a JATS `<ref-list>`, in the order given
+pub fn citations_to_jats(citations: &[Citation]) -> String {
+    let mut lines = vec!["<ref-list>".to_string()];
+    lines.extend(citations.iter().map(citation_to_jats));
+    lines.push("</ref-list>".to_string());
+    lines.join("\n")
+}
+
+/// Write a citation as a JATS `<ref>`. Its ID is the citation ID,
+/// prefixed with "ref-" and with characters XML doesn't allow in IDs
+/// replaced, e.g. "ref-10.1000-xyz".
+pub fn citation_to_jats(citation: &Citation) -> String {
+    let mut elements = Elements(Vec::new());
+
+    match citation {
+        Citation::Book(book) => {
+            elements.text("chapter-title", book.chapter.clone());
+            elements.text("source", Some(citation.title()));
+            match &book.version {
+                Some(GenericMediaVersion::Edition { number })
+                | Some(GenericMediaVersion::DigitalEdition { number }) => {
+                    elements.text("edition", Some(number.to_string()))
+                }
+                Some(GenericMediaVersion::Volume { number }) => {
+                    elements.text("volume", Some(number.to_string()))
+                }
+                Some(version) => elements.text("edition", Some(version.as_apa_string())),
+                None => {}
+            }
+        }
+        Citation::JournalArticle(article) => {
+            elements.text("article-title", Some(citation.title()));
+            elements.text("source", Some(article.journal.clone()));
+        }
+        Citation::ConferencePresentation(presentation) => {
+            elements.text("article-title", Some(citation.title()));
+            elements.text(
+                "conf-name",
+                Some(presentation.series.full_name(&presentation.conference_name)),
+            );
+            elements.text(
+                "conf-loc",
+                presentation.location.as_ref().map(ToString::to_string),
+            );
+        }
+        Citation::ConferencePaperOnline(paper) => {
+            elements.text("article-title", Some(citation.title()));
+            elements.text(
+                "conf-name",
+                Some(paper.series.full_name(&paper.conference_name)),
+            );
+            elements.text("conf-loc", paper.venue.clone());
+        }
+        Citation::ConferenceProceedingsOnline(proceedings) => {
+            elements.text("source", Some(citation.title()));
+            elements.text(
+                "conf-name",
+                Some(proceedings.series.full_name(&proceedings.conference_name)),
+            );
+            elements.text("conf-loc", proceedings.venue.clone());
+        }
+        Citation::OnlineManual(manual) => {
+            elements.text("source", Some(citation.title()));
+            elements.text(
+                "version",
+                manual
+                    .version
+                    .as_ref()
+                    .map(GenericMediaVersion::as_apa_string),
+            );
+        }
+        Citation::Software(software) => {
+            elements.text("source", Some(citation.title()));
+            elements.text("version", software.version.clone());
+            elements.text("publisher-name", software.publisher.clone());
+        }
+        Citation::WebPage(page) => {
+            elements.text("article-title", Some(citation.title()));
+            elements.text("source", page.site_name.clone());
+        }
+        Citation::WikipediaArticle(_) => {
+            elements.text("article-title", Some(citation.title()));
+            elements.text("source", Some("Wikipedia".to_string()));
+        }
+        Citation::OnlineVideo(_) | Citation::Custom(_) => {
+            elements.text("source", Some(citation.title()));
+        }
+    }
+
+    if let Some(published) = citation.published() {
+        elements.0.push(format!(
+            r#"<year iso-8601-date="{}">{}</year>"#,
+            iso_date(&published),
+            published.year()
+        ));
+        elements.text(
+            "month",
+            published
+                .month()
+                .map(|month| format!("{:02}", month.number_from_month())),
+        );
+        elements.text("day", published.day().map(|day| format!("{:02}", day)));
+    }
+
+    let (volume, issue, locator) = match citation {
+        Citation::JournalArticle(article) => (
+            article.volume.as_ref().map(ToString::to_string),
+            article.number.as_ref().map(ToString::to_string),
+            article.locator.clone(),
+        ),
+        Citation::ConferencePaperOnline(paper) => (
+            paper.volume.as_ref().map(ToString::to_string),
+            paper.number.as_ref().map(ToString::to_string),
+            None,
+        ),
+        Citation::ConferenceProceedingsOnline(proceedings) => (
+            proceedings.volume.as_ref().map(ToString::to_string),
+            proceedings.number.as_ref().map(ToString::to_string),
+            None,
+        ),
+        Citation::Book(book) => (None, None, book.pages.clone().map(ArticleLocator::Pages)),
+        _ => (None, None, None),
+    };
+    elements.text("volume", volume);
+    elements.text("issue", issue);
+    match locator {
+        Some(ArticleLocator::Pages(PageRange { start, end })) => {
+            elements.text("fpage", Some(start.to_string()));
+            if end != start {
+                elements.text("lpage", Some(end.to_string()));
+            }
+        }
+        Some(ArticleLocator::ArticleNumber(number)) => elements.text("elocation-id", Some(number)),
+        None => {}
+    }
+
+    let doi = match citation {
+        Citation::OnlineManual(manual) => match &manual.available_at {
+            OnlineManualAvailability::DOI(doi) => Some(doi.clone()),
+            _ => citation.doi(),
+        },
+        _ => citation.doi(),
+    };
+    if let Some(doi) = doi {
+        elements.0.push(format!(
+            r#"<pub-id pub-id-type="doi">{}</pub-id>"#,
+            escape(&doi)
+        ));
+    }
+    if let Some(url) = citation.url() {
+        let url = escape(&url);
+        elements.0.push(format!(
+            r#"<ext-link ext-link-type="uri" xlink:href="{0}">{0}</ext-link>"#,
+            url
+        ));
+    }
+    if let Some(accessed) = citation.accessed() {
+        elements.0.push(format!(
+            r#"<date-in-citation content-type="access-date" iso-8601-date="{0}">{0}</date-in-citation>"#,
+            accessed.date()
+        ));
+    }
+
+    let mut lines = vec![
+        format!(r#"<ref id="{}">"#, ref_id(&citation.id())),
+        format!(
+            r#"<element-citation publication-type="{}">"#,
+            publication_type(citation)
+        ),
+    ];
+    lines.extend(person_groups(citation));
+    lines.extend(elements.0);
+    lines.push("</element-citation>".to_string());
+    lines.push("</ref>".to_string());
+    lines.join("\n")
+}
+
+/// The child elements of an `<element-citation>`, in order
+struct Elements(Vec<String>);
+
+impl Elements {
+    /// Add a text element unless its value is missing or empty
+    fn text(&mut self, name: &str, value: Option<String>) {
+        if let Some(value) = value
+            && !value.trim().is_empty()
+        {
+            self.0.push(format!("<{0}>{1}</{0}>", name, escape(&value)));
+        }
+    }
+}
+
+/// The JATS publication type of the cited work
+fn publication_type(citation: &Citation) -> &'static str {
+    match citation {
+        Citation::Book(_) => "book",
+        Citation::JournalArticle(_) => "journal",
+        Citation::ConferencePresentation(_)
+        | Citation::ConferencePaperOnline(_)
+        | Citation::ConferenceProceedingsOnline(_) => "confproc",
+        Citation::OnlineVideo(_) | Citation::WebPage(_) | Citation::WikipediaArticle(_) => {
+            "webpage"
+        }
+        Citation::Software(_) => "software",
+        Citation::OnlineManual(_) | Citation::Custom(_) => "other",
+    }
+}
+
+/// `<person-group>`s for the authors and, for proceedings, the editors.
+/// Organizations and pseudonyms are written as `<collab>`.
+fn person_groups(citation: &Citation) -> Vec<String> {
+    let mut authors: Vec<String> = citation
+        .contributors()
+        .into_iter()
+        .map(|contributor| match contributor {
+            Contributor::Person(person) => name(&person),
+            Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => {
+                format!("<collab>{}</collab>", escape(&name))
+            }
+            Contributor::Anonymous => "<anonymous/>".to_string(),
+        })
+        .collect();
+    if let Citation::Book(book) = citation {
+        authors.extend(book.with_authors.iter().map(name));
+    }
+
+    let mut groups = Vec::new();
+    if !authors.is_empty() {
+        groups.push(format!(
+            r#"<person-group person-group-type="author">{}</person-group>"#,
+            authors.concat()
+        ));
+    }
+    if let Citation::ConferenceProceedingsOnline(proceedings) = citation
+        && !proceedings.editors.is_empty()
+    {
+        let editors: String = proceedings.editors.iter().map(name).collect();
+        groups.push(format!(
+            r#"<person-group person-group-type="editor">{}</person-group>"#,
+            editors
+        ));
+    }
+    groups
+}
+
+fn name(person: &PersonName) -> String {
+    let given = person
+        .given_names()
+        .map(|given| format!("<given-names>{}</given-names>", escape(&given)))
+        .unwrap_or_default();
+    format!(
+        "<name><surname>{}</surname>{}</name>",
+        escape(person.surname()),
+        given
+    )
+}
+
+/// A valid XML ID for the citation's `<ref>`
+fn ref_id(id: &str) -> String {
+    let id: String = id
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || "-_.".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("ref-{}", id)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, GenericAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{book::Book, common::CommonCitationData, journal_article::JournalArticle},
+            page_range::{ArticleLocator, PageRange},
+            volume::Volume,
+        },
+        jats::{citation_to_jats, citations_to_jats},
+    };
+
+    fn common_data(id: &str, published: PublishDate) -> CommonCitationData {
+        CommonCitationData {
+            published: Some(published),
+            ..CommonCitationData::new(id)
+        }
+    }
+
+    fn article() -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: common_data(
+                "10.1000/xyz",
+                PublishDate::from_year_month(2020, Month::March),
+            ),
+            author: AcademicAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_last("Fuentes").unwrap(),
+                ],
+            },
+            title: "R&D in Yeast".into(),
+            journal: "J. Tests".to_string(),
+            volume: Some(Volume::parse("12")),
+            number: None,
+            locator: Some(ArticleLocator::Pages(PageRange { start: 3, end: 9 })),
+            doi: Some("10.1000/xyz".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_article_to_jats() {
+        assert_eq!(
+            citation_to_jats(&article()),
+            r#"<ref id="ref-10.1000-xyz">
+<element-citation publication-type="journal">
+<person-group person-group-type="author"><name><surname>Smith</surname><given-names>Jane</given-names></name><name><surname>Fuentes</surname></name></person-group>
+<article-title>R&amp;D in Yeast</article-title>
+<source>J. Tests</source>
+<year iso-8601-date="2020-03">2020</year>
+<month>03</month>
+<volume>12</volume>
+<fpage>3</fpage>
+<lpage>9</lpage>
+<pub-id pub-id-type="doi">10.1000/xyz</pub-id>
+</element-citation>
+</ref>"#
+        );
+    }
+
+    #[test]
+    fn test_book_to_jats() {
+        let book = Citation::Book(Book {
+            common_data: common_data("who2021", PublishDate::from_year(2021)),
+            author: GenericAuthor::Organization {
+                name: "World Health Organization".to_string(),
+                abbreviation: Some("WHO".to_string()),
+            },
+            with_authors: Vec::new(),
+            title: "Global Report".into(),
+            chapter: None,
+            version: None,
+            doi: None,
+            pages: None,
+        });
+        let xml = citations_to_jats(&[book]);
+        assert!(xml.starts_with("<ref-list>\n<ref id=\"ref-who2021\">"));
+        assert!(xml.contains(r#"<element-citation publication-type="book">"#));
+        assert!(xml.contains(
+            r#"<person-group person-group-type="author"><collab>World Health Organization</collab></person-group>"#
+        ));
+        assert!(xml.contains("<source>Global Report</source>"));
+        assert!(xml.ends_with("</ref>\n</ref-list>"));
+    }
+}
@@ -0,0 +1,391 @@
+//! Append-only persistence for large, frequently edited bibliographies.
+//!
+//! [`Bibliography::save`] rewrites the whole file, which gets slow once a
+//! bibliography grows to several megabytes and is saved after every edit.
+//! A journal keeps a snapshot (`bib.json`, in the format written by
+//! `save`) next to a log (`bib.log`) with one JSON line per change. Saving
+//! a change only appends to the log; [`Journal::compact`] folds the log
+//! back into the snapshot.
+//!
+//! Replaying a change that is already in the snapshot has no effect, so a
+//! crash during compaction loses nothing.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{citation::Citation, errors::StorageError},
+    bibliography::{Bibliography, ChangeEvent},
+};
+
+/// Number of log entries after which [`Journal::append`] compacts
+pub const DEFAULT_COMPACT_AFTER: usize = 1000;
+
+/// One line of the log
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum LogEntry {
+    /// Add a citation, or replace the one with `previous_id` (or the same
+    /// ID) if there is one
+    Put {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        previous_id: Option<String>,
+        citation: Box<Citation>,
+    },
+    Remove {
+        id: String,
+    },
+    /// The citations were reordered to this order of IDs
+    Order {
+        ids: Vec<String>,
+    },
+}
+
+/// A bibliography snapshot and its append log.
+#[derive(Debug)]
+pub struct Journal {
+    snapshot: PathBuf,
+    log: PathBuf,
+    /// Length of the log after this journal last read or wrote it
+    log_len: u64,
+    entries: usize,
+    /// Number of log entries after which [`Journal::append`] compacts
+    pub compact_after: usize,
+}
+
+impl Journal {
+    /// Open the journal whose snapshot is at `snapshot`, with the log next
+    /// to it (`bib.json` has `bib.log`), and restore the bibliography from
+    /// them. Either file may be missing, in which case the bibliography
+    /// starts out empty.
+    ///
+    /// A last log line that is cut short, as left by a crash mid-write,
+    /// is dropped from the log.
+    pub fn open(snapshot: impl AsRef<Path>) -> Result<(Self, Bibliography), StorageError> {
+        let snapshot = snapshot.as_ref().to_path_buf();
+        let log = snapshot.with_extension("log");
+
+        let mut bibliography = if snapshot.exists() {
+            Bibliography::load(&snapshot)?
+        } else {
+            Bibliography::new()
+        };
+
+        let text = match fs::read_to_string(&log) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let mut entries = 0;
+        let mut log_len = 0;
+        let mut lines = text.split_inclusive('\n').peekable();
+        let mut number = 0;
+        while let Some(line) = lines.next() {
+            number += 1;
+            if line.trim().is_empty() {
+                log_len += line.len();
+                continue;
+            }
+            let entry: LogEntry = match serde_json::from_str(line) {
+                Ok(entry) => entry,
+                Err(_) if lines.peek().is_none() && !line.ends_with('\n') => {
+                    OpenOptions::new()
+                        .write(true)
+                        .open(&log)?
+                        .set_len(log_len as u64)?;
+                    break;
+                }
+                Err(err) => {
+                    return Err(StorageError::Log {
+                        line: number,
+                        message: err.to_string(),
+                    });
+                }
+            };
+            replay(&mut bibliography, entry);
+            entries += 1;
+            log_len += line.len();
+        }
+
+        let journal = Self {
+            snapshot,
+            log,
+            log_len: log_len as u64,
+            entries,
+            compact_after: DEFAULT_COMPACT_AFTER,
+        };
+        Ok((journal, bibliography))
+    }
+
+    /// Number of changes in the log that aren't in the snapshot yet
+    pub fn pending_entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Append `events`, as reported by [`Bibliography::on_change`], to the
+    /// log. Added and updated citations are written as they are now in
+    /// `bibliography`. Compacts once the log holds more than
+    /// [`Journal::compact_after`] entries.
+    ///
+    /// Fails with [`StorageError::ConcurrentWrite`] if another writer has
+    /// changed the log since this journal last read or wrote it.
+    pub fn append(
+        &mut self,
+        bibliography: &Bibliography,
+        events: &[ChangeEvent],
+    ) -> Result<(), StorageError> {
+        self.check_unchanged()?;
+
+        let mut lines = String::new();
+        for event in events {
+            let entry = match event {
+                ChangeEvent::Added(id) => put(bibliography, None, id),
+                ChangeEvent::Updated { previous_id, id } => {
+                    put(bibliography, Some(previous_id.clone()), id)
+                }
+                ChangeEvent::Removed(id) => Some(LogEntry::Remove { id: id.clone() }),
+                ChangeEvent::Reordered => Some(LogEntry::Order {
+                    ids: bibliography.citations().iter().map(Citation::id).collect(),
+                }),
+            };
+            if let Some(entry) = entry {
+                lines.push_str(&serde_json::to_string(&entry)?);
+                lines.push('\n');
+                self.entries += 1;
+            }
+        }
+
+        if !lines.is_empty() {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.log)?;
+            file.write_all(lines.as_bytes())?;
+            file.sync_data()?;
+            self.log_len += lines.len() as u64;
+        }
+
+        if self.entries > self.compact_after {
+            self.compact(bibliography)?;
+        }
+        Ok(())
+    }
+
+    /// Write `bibliography` as the new snapshot and empty the log.
+    ///
+    /// The snapshot is written to a temporary file and renamed into place,
+    /// so a reader never sees it half-written.
+    pub fn compact(&mut self, bibliography: &Bibliography) -> Result<(), StorageError> {
+        self.check_unchanged()?;
+
+        let temporary = self.snapshot.with_extension("json.tmp");
+        bibliography.save(&temporary)?;
+        fs::rename(&temporary, &self.snapshot)?;
+        File::create(&self.log)?;
+        self.log_len = 0;
+        self.entries = 0;
+        Ok(())
+    }
+
+    fn check_unchanged(&self) -> Result<(), StorageError> {
+        let len = match fs::metadata(&self.log) {
+            Ok(metadata) => metadata.len(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(err) => return Err(err.into()),
+        };
+        if len != self.log_len {
+            return Err(StorageError::ConcurrentWrite(
+                self.log.display().to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn put(bibliography: &Bibliography, previous_id: Option<String>, id: &str) -> Option<LogEntry> {
+    // A citation that was changed again or removed later in the same batch
+    // is written by the later event
+    let citation = Box::new(bibliography.get_citation(id)?.clone());
+    Some(LogEntry::Put {
+        previous_id: previous_id.filter(|previous_id| previous_id != id),
+        citation,
+    })
+}
+
+fn replay(bibliography: &mut Bibliography, entry: LogEntry) {
+    match entry {
+        LogEntry::Put {
+            previous_id,
+            citation,
+        } => {
+            let id = citation.id();
+            let existing = previous_id
+                .filter(|previous_id| bibliography.get_citation(previous_id).is_some())
+                .unwrap_or_else(|| id.clone());
+            if bibliography.get_citation(&existing).is_some() {
+                // Keep the logged times rather than the time of the replay
+                let timestamps = citation.common_data().timestamps;
+                if bibliography.replace_citation(&existing, *citation).is_ok()
+                    && let Some(replayed) = bibliography.get_citation_mut(&id)
+                {
+                    replayed.common_data_mut().timestamps = timestamps;
+                }
+            } else {
+                let _ = bibliography.add_citation(*citation);
+            }
+        }
+        LogEntry::Remove { id } => {
+            bibliography.remove_citation(&id);
+        }
+        LogEntry::Order { ids } => bibliography.order_by_ids(&ids),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
+
+    use crate::{
+        api::{
+            author::GenericAuthor,
+            citation::Citation,
+            errors::StorageError,
+            media::{book::Book, common::CommonCitationData},
+        },
+        bibliography::{Bibliography, ChangeEvent},
+        journal::Journal,
+    };
+
+    fn book(id: &str, title: &str) -> Citation {
+        Citation::Book(Book {
+            common_data: CommonCitationData::new(id),
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: title.into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        })
+    }
+
+    fn snapshot_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "citation_station_journal_{}_{}.json",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(path.with_extension("log"));
+        path
+    }
+
+    fn cleanup(path: &PathBuf) {
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_file(path.with_extension("log"));
+    }
+
+    /// Open a journal with an observer that collects change events
+    fn open(path: &PathBuf) -> (Journal, Bibliography, Arc<Mutex<Vec<ChangeEvent>>>) {
+        let (journal, mut bib) = Journal::open(path).unwrap();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&events);
+        bib.on_change(move |event| recorder.lock().unwrap().push(event.clone()));
+        (journal, bib, events)
+    }
+
+    fn flush(journal: &mut Journal, bib: &Bibliography, events: &Mutex<Vec<ChangeEvent>>) {
+        let events = std::mem::take(&mut *events.lock().unwrap());
+        journal.append(bib, &events).unwrap();
+    }
+
+    #[test]
+    fn test_journal_replays_log() {
+        let path = snapshot_path("replay");
+        let (mut journal, mut bib, events) = open(&path);
+
+        bib.add_citation(book("a", "First")).unwrap();
+        bib.add_citation(book("b", "Second")).unwrap();
+        flush(&mut journal, &bib, &events);
+        bib.replace_citation("a", book("c", "Third")).unwrap();
+        bib.remove_citation("b");
+        flush(&mut journal, &bib, &events);
+
+        assert!(!path.exists());
+        assert_eq!(journal.pending_entries(), 4);
+        let (_, restored) = Journal::open(&path).unwrap();
+        assert_eq!(restored.citations(), bib.citations());
+
+        cleanup(&path);
+    }
+
+    #[test]
+    fn test_journal_compaction() {
+        let path = snapshot_path("compact");
+        let (mut journal, mut bib, events) = open(&path);
+        journal.compact_after = 2;
+
+        for id in ["a", "b", "c"] {
+            bib.add_citation(book(id, id)).unwrap();
+        }
+        flush(&mut journal, &bib, &events);
+
+        assert_eq!(journal.pending_entries(), 0);
+        assert_eq!(fs::read_to_string(path.with_extension("log")).unwrap(), "");
+        assert_eq!(Bibliography::load(&path).unwrap().len(), 3);
+
+        cleanup(&path);
+    }
+
+    #[test]
+    fn test_journal_detects_concurrent_writer() {
+        let path = snapshot_path("concurrent");
+        let (mut first, mut bib, events) = open(&path);
+        let (mut second, other) = Journal::open(&path).unwrap();
+
+        bib.add_citation(book("a", "First")).unwrap();
+        flush(&mut first, &bib, &events);
+
+        assert!(matches!(
+            second.append(&other, &[ChangeEvent::Reordered]),
+            Err(StorageError::ConcurrentWrite(_))
+        ));
+
+        cleanup(&path);
+    }
+
+    #[test]
+    fn test_journal_ignores_torn_last_line() {
+        let path = snapshot_path("torn");
+        let (mut journal, mut bib, events) = open(&path);
+        bib.add_citation(book("a", "First")).unwrap();
+        flush(&mut journal, &bib, &events);
+        let log = path.with_extension("log");
+        let mut text = fs::read_to_string(&log).unwrap();
+        text.push_str(r#"{"op":"remove","#);
+        fs::write(&log, text).unwrap();
+
+        let (mut journal, mut bib) = Journal::open(&path).unwrap();
+        assert_eq!(bib.len(), 1);
+        bib.remove_citation("a");
+        journal
+            .append(&bib, &[ChangeEvent::Removed("a".to_string())])
+            .unwrap();
+
+        let (_, restored) = Journal::open(&path).unwrap();
+        assert!(restored.is_empty());
+        cleanup(&path);
+    }
+}
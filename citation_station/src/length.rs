@@ -0,0 +1,143 @@
+//! The length of rendered reference lists, for journals that limit the
+//! number of references or the length of each one.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::{citation::Citation, style::CitationStyle};
+
+/// The length of one rendered reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryLength {
+    pub id: String,
+    pub words: usize,
+    /// User-perceived characters (grapheme clusters), including spaces
+    pub characters: usize,
+}
+
+/// The length of a reference list in one style. Numeric labels such as
+/// "[1]" are not counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceListLength {
+    pub style: CitationStyle,
+    /// Each reference, in bibliography order
+    pub entries: Vec<EntryLength>,
+}
+
+/// Limits on the length of each reference. Unset limits are not checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EntryLimits {
+    pub max_words: Option<usize>,
+    pub max_characters: Option<usize>,
+}
+
+impl ReferenceListLength {
+    pub fn entry_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn words(&self) -> usize {
+        self.entries.iter().map(|entry| entry.words).sum()
+    }
+
+    pub fn characters(&self) -> usize {
+        self.entries.iter().map(|entry| entry.characters).sum()
+    }
+
+    /// The references longer than `limits` allow
+    pub fn exceeding(&self, limits: EntryLimits) -> Vec<&EntryLength> {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                limits.max_words.is_some_and(|max| entry.words > max)
+                    || limits
+                        .max_characters
+                        .is_some_and(|max| entry.characters > max)
+            })
+            .collect()
+    }
+}
+
+/// Measure `citations` as rendered in `style`
+pub fn measure(citations: &[Citation], style: CitationStyle) -> ReferenceListLength {
+    let entries = citations
+        .iter()
+        .map(|citation| {
+            let text = citation.format(style);
+            EntryLength {
+                id: citation.id(),
+                words: count_words(&text),
+                characters: count_characters(&text),
+            }
+        })
+        .collect();
+    ReferenceListLength { style, entries }
+}
+
+/// Words in `text`, by Unicode word boundaries. Numbers count as words;
+/// punctuation doesn't.
+pub fn count_words(text: &str) -> usize {
+    text.unicode_words().count()
+}
+
+/// User-perceived characters (grapheme clusters) in `text`
+pub fn count_characters(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::GenericAuthor,
+            citation::Citation,
+            date::PublishDate,
+            media::{book::Book, common::CommonCitationData},
+            style::CitationStyle,
+        },
+        length::{EntryLimits, count_characters, count_words, measure},
+    };
+
+    fn book(id: &str, title: &str) -> Citation {
+        Citation::Book(Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2020)),
+                ..CommonCitationData::new(id)
+            },
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: title.into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        })
+    }
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(count_words("Smith, J. (2020). Café culture."), 5);
+        assert_eq!(count_characters("Café"), 4);
+        assert_eq!(count_characters("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn test_measure() {
+        let citations = [book("short", "Notes"), book("long", "A Much Longer Title")];
+
+        let length = measure(&citations, CitationStyle::Apa);
+
+        // "Org. (2020). Notes."
+        assert_eq!(length.entries[0].words, 3);
+        assert_eq!(length.entries[0].characters, 19);
+        assert_eq!(length.entry_count(), 2);
+        assert_eq!(length.words(), 3 + 6);
+        let limits = EntryLimits {
+            max_words: Some(4),
+            max_characters: None,
+        };
+        assert_eq!(length.exceeding(limits)[0].id, "long");
+    }
+}
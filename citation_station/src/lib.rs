@@ -1,5 +1,41 @@
 pub mod abbrev;
+pub mod analysis;
 pub mod api;
 pub mod bibliography;
+pub mod bibtex;
+pub mod cff;
+pub mod collation;
+pub mod csv;
+pub mod dedup;
+pub mod export;
+pub mod graph;
 pub mod html;
+pub mod import;
+pub mod in_text;
+pub mod jats;
+pub mod journal;
+pub mod length;
+pub mod lint;
+pub mod merge;
+pub mod merge_proposal;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod microdata;
+pub mod name_variants;
+pub mod net;
+pub mod notes;
+pub mod odf;
+pub mod org;
+pub mod qr;
+pub mod render_session;
+pub mod ris;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod self_citation;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod similarity;
+pub mod template;
 mod unicode;
+pub mod word;
+mod xml;
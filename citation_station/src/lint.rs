@@ -0,0 +1,372 @@
+//! Checks for problems in a reference list that formatting alone won't
+//! catch, e.g. a DOI shared by two entries or a publication date in the
+//! future.
+//!
+//! Every issue has a stable [`LintCode`], so CI jobs and editors can gate
+//! on or silence specific checks.
+
+use std::{collections::BTreeMap, fmt};
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        citation::Citation,
+        date::{AccessDate, PublishDate},
+        media::online_video::OnlineVideo,
+        style::CitationStyle,
+    },
+    import::doi::normalize_doi,
+    name_variants::find_name_variants,
+};
+
+/// The kind of problem a [`LintIssue`] reports.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum LintCode {
+    /// A person is credited under different forms of their name
+    InconsistentNames,
+    /// The title or container the style italicizes is empty
+    MissingItalicTitle,
+    /// A work with a DOI also records a URL, which styles drop in favor of
+    /// the DOI
+    UrlWithDoi,
+    /// The publication date is after today
+    FutureDate,
+    /// Several entries have the same DOI
+    DuplicateDoi,
+    /// The entry was edited after its hand-written format override was
+    /// set, so the override may no longer match its metadata
+    StaleOverride,
+}
+
+impl LintCode {
+    /// The machine-readable code, e.g. "duplicate-doi"
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            LintCode::InconsistentNames => "inconsistent-names",
+            LintCode::MissingItalicTitle => "missing-italic-title",
+            LintCode::UrlWithDoi => "url-with-doi",
+            LintCode::FutureDate => "future-date",
+            LintCode::DuplicateDoi => "duplicate-doi",
+            LintCode::StaleOverride => "stale-override",
+        }
+    }
+
+    pub const fn severity(self) -> LintSeverity {
+        match self {
+            LintCode::MissingItalicTitle | LintCode::DuplicateDoi => LintSeverity::Error,
+            LintCode::InconsistentNames
+            | LintCode::UrlWithDoi
+            | LintCode::FutureDate
+            | LintCode::StaleOverride => LintSeverity::Warning,
+        }
+    }
+}
+
+impl fmt::Display for LintCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LintSeverity {
+    Warning,
+    /// The reference list would come out wrong
+    Error,
+}
+
+/// A problem found in one or more entries.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LintIssue {
+    pub code: LintCode,
+    pub severity: LintSeverity,
+    /// IDs of the entries involved
+    pub citation_ids: Vec<String>,
+    pub message: String,
+}
+
+impl LintIssue {
+    fn new(code: LintCode, citation_ids: Vec<String>, message: String) -> Self {
+        Self {
+            code,
+            severity: code.severity(),
+            citation_ids,
+            message,
+        }
+    }
+}
+
+/// "warning[future-date] smith2030: ..."
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            LintSeverity::Warning => "warning",
+            LintSeverity::Error => "error",
+        };
+        write!(
+            f,
+            "{}[{}] {}: {}",
+            severity,
+            self.code,
+            self.citation_ids.join(", "),
+            self.message
+        )
+    }
+}
+
+/// Check `citations` for problems in a reference list in `style`
+pub fn lint(citations: &[Citation], style: CitationStyle) -> Vec<LintIssue> {
+    lint_as_of(citations, style, AccessDate::today().date())
+}
+
+fn lint_as_of(citations: &[Citation], style: CitationStyle, today: NaiveDate) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    for citation in citations {
+        let id = citation.id();
+        if italic_title(citation, style).is_some_and(|title| title.trim().is_empty()) {
+            issues.push(LintIssue::new(
+                LintCode::MissingItalicTitle,
+                vec![id.clone()],
+                format!("{} entry has no italicized title", citation.kind()),
+            ));
+        }
+        let identifiers = citation.identifiers();
+        if let (Some(doi), Some(url)) = (identifiers.doi(), identifiers.url())
+            && normalize_doi(url).is_none()
+        {
+            issues.push(LintIssue::new(
+                LintCode::UrlWithDoi,
+                vec![id.clone()],
+                format!("URL {} is redundant with DOI {}", url, doi),
+            ));
+        }
+        if let Some(published) = citation.published()
+            && is_after(&published, today)
+        {
+            issues.push(LintIssue::new(
+                LintCode::FutureDate,
+                vec![id.clone()],
+                format!("published {}, which is in the future", published.year()),
+            ));
+        }
+        if citation.is_override_stale(style) {
+            issues.push(LintIssue::new(
+                LintCode::StaleOverride,
+                vec![id.clone()],
+                "entry was edited after its hand-written format was set".to_string(),
+            ));
+        }
+    }
+
+    let mut by_doi: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for citation in citations {
+        if let Some(doi) = citation.identifiers().doi().and_then(normalize_doi) {
+            by_doi.entry(doi).or_default().push(citation.id());
+        }
+    }
+    for (doi, ids) in by_doi {
+        if ids.len() > 1 {
+            issues.push(LintIssue::new(
+                LintCode::DuplicateDoi,
+                ids,
+                format!("DOI {} is used by more than one entry", doi),
+            ));
+        }
+    }
+
+    for cluster in find_name_variants(citations) {
+        let mut ids = cluster.canonical.citation_ids.clone();
+        let mut forms = Vec::new();
+        for variant in &cluster.variants {
+            ids.extend(variant.form.citation_ids.iter().cloned());
+            forms.push(format!("\"{}\"", variant.form.name.full_name()));
+        }
+        issues.push(LintIssue::new(
+            LintCode::InconsistentNames,
+            ids,
+            format!(
+                "\"{}\" also appears as {}",
+                cluster.canonical.name.full_name(),
+                forms.join(", ")
+            ),
+        ));
+    }
+
+    issues
+}
+
+/// The text `style` sets in italics, for media types that have one
+fn italic_title(citation: &Citation, style: CitationStyle) -> Option<String> {
+    match (citation, style) {
+        // Vancouver sets nothing in italics
+        (_, CitationStyle::Vancouver) => None,
+        (Citation::Book(book), _) => Some(book.title.to_string()),
+        (Citation::JournalArticle(article), _) => Some(article.journal.clone()),
+        (Citation::ConferencePaperOnline(paper), CitationStyle::Ieee) => {
+            Some(paper.conference_name.clone())
+        }
+        (Citation::ConferenceProceedingsOnline(proceedings), _) => {
+            Some(proceedings.conference_name.clone())
+        }
+        (Citation::OnlineManual(manual), style) if style != CitationStyle::Ieee => {
+            Some(manual.title.to_string())
+        }
+        (Citation::Software(software), style) if style != CitationStyle::Ieee => {
+            Some(software.title.to_string())
+        }
+        (_, CitationStyle::Ieee | CitationStyle::Ama | CitationStyle::Acs) => None,
+        (Citation::ConferencePresentation(presentation), _) => Some(presentation.title.to_string()),
+        (Citation::OnlineVideo(OnlineVideo::Generic { title, .. }), _)
+        | (Citation::OnlineVideo(OnlineVideo::YouTube { title, .. }), _) => Some(title.to_string()),
+        (Citation::WebPage(page), _) => Some(page.title.to_string()),
+        (Citation::ConferencePaperOnline(_), _)
+        | (Citation::OnlineManual(_), _)
+        | (Citation::Software(_), _)
+        | (Citation::WikipediaArticle(_), _)
+        | (Citation::Custom(_), _) => None,
+    }
+}
+
+/// Whether the earliest day `date` could mean is after `today`
+fn is_after(date: &PublishDate, today: NaiveDate) -> bool {
+    let month = date.month().map_or(1, |month| month.number_from_month());
+    let day = date.day().unwrap_or(1);
+    (date.year(), month, day) > (today.year(), today.month(), today.day())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+            style::CitationStyle,
+        },
+        import::identifier::Identifier,
+        lint::{LintCode, LintSeverity, lint_as_of},
+    };
+
+    fn article(id: &str, author: (&str, &str), year: i32, doi: &str) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(year)),
+                ..CommonCitationData::new(id)
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![PersonName::from_first_last(author.0, author.1).unwrap()],
+            },
+            title: "Title".into(),
+            journal: "J. Tests".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: Some(doi.to_string()),
+        })
+    }
+
+    fn codes(citations: &[Citation]) -> Vec<(LintCode, Vec<String>)> {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        lint_as_of(citations, CitationStyle::Apa, today)
+            .into_iter()
+            .map(|issue| (issue.code, issue.citation_ids))
+            .collect()
+    }
+
+    #[test]
+    fn test_clean_bibliography() {
+        let citations = [
+            article("a", ("Jane", "Smith"), 2020, "10.1000/a"),
+            article("b", ("Hugo", "Fuentes"), 2024, "10.1000/b"),
+        ];
+        assert_eq!(codes(&citations), []);
+    }
+
+    #[test]
+    fn test_url_with_doi() {
+        let mut citation = article("a", ("Jane", "Smith"), 2020, "10.1000/a");
+        citation
+            .common_data_mut()
+            .identifiers
+            .insert(Identifier::Url("https://example.com/a".to_string()));
+        assert_eq!(
+            codes(&[citation]),
+            [(LintCode::UrlWithDoi, vec!["a".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_lint_issues() {
+        let mut untitled = article("c", ("Ada", "Lovelace"), 2021, "10.1000/c");
+        if let Citation::JournalArticle(article) = &mut untitled {
+            article.journal = String::new();
+        }
+        let citations = [
+            article("a", ("Jane", "Smith"), 2020, "10.1000/a"),
+            article("b", ("J.", "Smith"), 2030, "https://doi.org/10.1000/A"),
+            untitled,
+        ];
+
+        assert_eq!(
+            codes(&citations),
+            [
+                (LintCode::FutureDate, vec!["b".to_string()]),
+                (LintCode::MissingItalicTitle, vec!["c".to_string()]),
+                (
+                    LintCode::DuplicateDoi,
+                    vec!["a".to_string(), "b".to_string()]
+                ),
+                (
+                    LintCode::InconsistentNames,
+                    vec!["a".to_string(), "b".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stale_override() {
+        let mut citation = article("a", ("Jane", "Smith"), 2020, "10.1000/a");
+        citation.set_override(CitationStyle::Apa, "Smith, J. (2020). Title.");
+        citation.set_override(CitationStyle::Ieee, "[1] J. Smith, \"Title\".");
+        assert!(codes(std::slice::from_ref(&citation)).is_empty());
+
+        if let Citation::JournalArticle(article) = &mut citation {
+            article.title = "Corrected title".into();
+        }
+        // Only the override of the style being checked is reported
+        assert_eq!(
+            codes(std::slice::from_ref(&citation)),
+            [(LintCode::StaleOverride, vec!["a".to_string()])]
+        );
+        assert!(citation.confirm_override(CitationStyle::Apa));
+        assert!(codes(std::slice::from_ref(&citation)).is_empty());
+        assert!(citation.is_override_stale(CitationStyle::Ieee));
+    }
+
+    #[test]
+    fn test_issue_display() {
+        let citations = [article("a", ("Jane", "Smith"), 2030, "10.1000/a")];
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let issues = lint_as_of(&citations, CitationStyle::Apa, today);
+
+        assert_eq!(issues[0].severity, LintSeverity::Warning);
+        assert_eq!(
+            issues[0].to_string(),
+            "warning[future-date] a: published 2030, which is in the future"
+        );
+        assert_eq!(
+            serde_json::to_value(&issues[0]).unwrap()["code"],
+            "future-date"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Three-way merging of bibliographies, for shared bibliography files
+//! edited on several branches.
+//!
+//! Entries are matched by ID. An entry changed on only one side takes that
+//! side's version; an entry changed differently on both sides is a
+//! conflict. Changing an entry's ID counts as removing it and adding a new
+//! one. Creation and modification times are not compared: when both sides
+//! made the same change, the later modified version is kept.
+
+use std::collections::HashMap;
+
+use crate::{api::citation::Citation, bibliography::Bibliography};
+
+/// An entry that both sides changed in different ways.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeConflict {
+    pub id: String,
+    /// The entry in the common ancestor, or `None` if both sides added it
+    pub base: Option<Citation>,
+    /// Our version, or `None` if we removed it
+    pub ours: Option<Citation>,
+    /// Their version, or `None` if they removed it
+    pub theirs: Option<Citation>,
+}
+
+/// The result of [`merge3`].
+#[derive(Debug, Clone)]
+pub struct Merge {
+    /// The merged bibliography. Conflicting entries hold our version, or
+    /// the changed version if the other side removed the entry.
+    pub merged: Bibliography,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl Merge {
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Merge the changes made in `ours` and `theirs` since their common
+/// ancestor `base`.
+///
+/// The merged bibliography keeps our order, followed by the entries only
+/// they added, in their order.
+pub fn merge3(base: &Bibliography, ours: &Bibliography, theirs: &Bibliography) -> Merge {
+    let base_by_id = by_id(base);
+    let theirs_by_id = by_id(theirs);
+    let ours_by_id = by_id(ours);
+
+    let mut merged = Bibliography::new();
+    let mut conflicts = Vec::new();
+
+    let ids = ours
+        .citations()
+        .iter()
+        .map(Citation::id)
+        .chain(
+            theirs
+                .citations()
+                .iter()
+                .map(Citation::id)
+                .filter(|id| !ours_by_id.contains_key(id)),
+        )
+        .chain(
+            base.citations()
+                .iter()
+                .map(Citation::id)
+                .filter(|id| !ours_by_id.contains_key(id) && !theirs_by_id.contains_key(id)),
+        );
+    for id in ids {
+        let base = base_by_id.get(&id).copied();
+        let ours = ours_by_id.get(&id).copied();
+        let theirs = theirs_by_id.get(&id).copied();
+
+        let kept = if same_content(ours, theirs) {
+            later(ours, theirs)
+        } else if same_content(theirs, base) {
+            ours
+        } else if same_content(ours, base) {
+            theirs
+        } else {
+            conflicts.push(MergeConflict {
+                id: id.clone(),
+                base: base.cloned(),
+                ours: ours.cloned(),
+                theirs: theirs.cloned(),
+            });
+            ours.or(theirs)
+        };
+        if let Some(citation) = kept {
+            merged
+                .add_citation(citation.clone())
+                .expect("merged IDs are unique");
+        }
+    }
+
+    Merge { merged, conflicts }
+}
+
+/// Whether two versions of an entry are the same, apart from their times
+fn same_content(a: Option<&Citation>, b: Option<&Citation>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let mut b = b.clone();
+            b.common_data_mut().timestamps = a.common_data().timestamps;
+            *a == b
+        }
+        (a, b) => a.is_none() && b.is_none(),
+    }
+}
+
+/// The more recently modified of two versions of an entry
+fn later<'a>(ours: Option<&'a Citation>, theirs: Option<&'a Citation>) -> Option<&'a Citation> {
+    match (ours, theirs) {
+        (Some(a), Some(b)) if b.modified() > a.modified() => theirs,
+        _ => ours,
+    }
+}
+
+fn by_id(bibliography: &Bibliography) -> HashMap<String, &Citation> {
+    bibliography
+        .citations()
+        .iter()
+        .map(|citation| (citation.id(), citation))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::GenericAuthor,
+            citation::Citation,
+            media::{book::Book, common::CommonCitationData},
+        },
+        bibliography::Bibliography,
+        merge::merge3,
+    };
+
+    fn book(id: &str, title: &str) -> Citation {
+        Citation::Book(Book {
+            common_data: CommonCitationData::new(id),
+            author: GenericAuthor::Organization {
+                name: "Org".to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: title.into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        })
+    }
+
+    fn bibliography(citations: &[(&str, &str)]) -> Bibliography {
+        let mut bibliography = Bibliography::new();
+        for (id, title) in citations {
+            bibliography.add_citation(book(id, title)).unwrap();
+        }
+        bibliography
+    }
+
+    fn titles(bibliography: &Bibliography) -> Vec<(String, String)> {
+        bibliography
+            .citations()
+            .iter()
+            .map(|citation| (citation.id(), citation.title()))
+            .collect()
+    }
+
+    #[test]
+    fn test_clean_merge() {
+        let base = bibliography(&[("a", "A"), ("b", "B"), ("c", "C")]);
+        // We edit a and remove c; they edit b and add d
+        let ours = bibliography(&[("a", "A2"), ("b", "B")]);
+        let theirs = bibliography(&[("a", "A"), ("b", "B2"), ("c", "C"), ("d", "D")]);
+
+        let merge = merge3(&base, &ours, &theirs);
+
+        assert!(merge.is_clean());
+        assert_eq!(
+            titles(&merge.merged),
+            [
+                ("a".to_string(), "A2".to_string()),
+                ("b".to_string(), "B2".to_string()),
+                ("d".to_string(), "D".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let base = bibliography(&[("a", "A"), ("b", "B")]);
+        // Both edit a differently; we remove b while they edit it; both
+        // add c differently
+        let ours = bibliography(&[("a", "Ours"), ("c", "Ours")]);
+        let theirs = bibliography(&[("a", "Theirs"), ("b", "B2"), ("c", "Theirs")]);
+
+        let merge = merge3(&base, &ours, &theirs);
+
+        let conflicts: Vec<(&str, bool, bool)> = merge
+            .conflicts
+            .iter()
+            .map(|conflict| {
+                (
+                    conflict.id.as_str(),
+                    conflict.base.is_some(),
+                    conflict.ours.is_some(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            conflicts,
+            [("a", true, true), ("c", false, true), ("b", true, false)]
+        );
+        assert_eq!(
+            titles(&merge.merged),
+            [
+                ("a".to_string(), "Ours".to_string()),
+                ("c".to_string(), "Ours".to_string()),
+                ("b".to_string(), "B2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_same_change_on_both_sides() {
+        let base = bibliography(&[("a", "A")]);
+        let both = bibliography(&[("b", "B")]);
+
+        let merge = merge3(&base, &both, &both);
+
+        assert!(merge.is_clean());
+        assert_eq!(titles(&merge.merged), [("b".to_string(), "B".to_string())]);
+    }
+}
@@ -0,0 +1,439 @@
+//! Field-by-field choices for combining several versions of an entry,
+//! e.g. duplicates of the same work or an entry and its metadata fetched
+//! again from its DOI.
+//!
+//! Nothing is chosen for the user: a [`MergeProposal`] lists every field
+//! the versions disagree on with the value each version has, and
+//! [`MergeProposal::apply`] builds the merged entry from the user's
+//! choices. Fields are named as in [`FieldChange`], e.g. "title" or
+//! "common_data.published".
+//!
+//! The entry's own data (ID, tags, references, identifiers, timestamps,
+//! format overrides) is never proposed: the first version's ID,
+//! timestamps and overrides are kept, and
+//! the tags, references and identifiers of every version are combined.
+//!
+//! [`FieldChange`]: crate::import::refresh::FieldChange
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::{
+    api::{citation::Citation, identifiers::Identifiers},
+    import::refresh::MetadataRefresh,
+};
+
+/// `common_data` fields that belong to the entry rather than the work
+const OWN_FIELDS: [&str; 6] = [
+    "id",
+    "cites",
+    "tags",
+    "identifiers",
+    "timestamps",
+    "overrides",
+];
+
+/// The chosen candidate for each field, by index into
+/// [`FieldCandidates::candidates`]
+pub type Resolutions = HashMap<String, usize>;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum MergeError {
+    #[error("No value chosen for {0}")]
+    Unresolved(String),
+    #[error("{0} is not a field of the proposal")]
+    UnknownField(String),
+    #[error("{field} has no candidate {index}")]
+    NoSuchCandidate { field: String, index: usize },
+    #[error("No citation with ID '{0}'")]
+    UnknownId(String),
+    #[error("The merged entry is invalid: {0}")]
+    Invalid(#[from] serde_json::Error),
+}
+
+/// One value a field could take, and the versions that have it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    /// The value as serialized, or `None` if the field is not set
+    pub value: Option<Value>,
+    pub sources: Vec<String>,
+}
+
+/// A field the versions disagree on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldCandidates {
+    pub field: String,
+    pub candidates: Vec<Candidate>,
+    /// The set value with the most sources, preferring earlier versions,
+    /// for UIs to preselect
+    pub suggested: usize,
+}
+
+/// The choices to make to merge several versions of an entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeProposal {
+    /// The versions' sources, the first version's first
+    pub sources: Vec<String>,
+    /// Fields that differ between versions, sorted by name
+    pub fields: Vec<FieldCandidates>,
+    base: Value,
+}
+
+impl MergeProposal {
+    /// Compare versions of an entry, each with the name of its source
+    /// (e.g. an entry ID or "doi"). The first version's media type is
+    /// kept; versions of another media type only contribute the fields
+    /// all media types have. Returns `None` without any versions.
+    pub fn new(versions: &[(&str, &Citation)]) -> Option<Self> {
+        let ((_, first), others) = versions.split_first()?;
+        let mut base = (*first).clone();
+        let data = base.common_data_mut();
+        let mut identifiers = Identifiers::new();
+        for (_, version) in others.iter().rev() {
+            let other = version.common_data();
+            for tag in &other.tags {
+                if !data.tags.contains(tag) {
+                    data.tags.push(tag.clone());
+                }
+            }
+            for id in &other.cites {
+                if !data.cites.contains(id) {
+                    data.cites.push(id.clone());
+                }
+            }
+            for identifier in other.identifiers.iter() {
+                identifiers.insert(identifier.clone());
+            }
+        }
+        // The first version's identifiers win over those of the same kind
+        for identifier in data.identifiers.iter() {
+            identifiers.insert(identifier.clone());
+        }
+        data.identifiers = identifiers;
+        let base = serde_json::to_value(&base).ok()?;
+
+        let kind = variant(&base)?.0.clone();
+        let sources = versions
+            .iter()
+            .map(|(source, _)| source.to_string())
+            .collect();
+        let serialized: Vec<Value> = versions
+            .iter()
+            .map(|(_, version)| serde_json::to_value(version))
+            .collect::<Result<_, _>>()
+            .ok()?;
+        // Versions of another media type only have a say on common_data
+        let versions: Vec<(&str, &Value, bool)> = versions
+            .iter()
+            .zip(&serialized)
+            .filter_map(|((source, _), value)| {
+                let (version_kind, fields) = variant(value)?;
+                Some((*source, fields, *version_kind == kind))
+            })
+            .collect();
+
+        let mut names: Vec<String> = Vec::new();
+        for (_, fields, same_kind) in &versions {
+            let new_names: Vec<String> = field_names(fields)
+                .filter(|name| *same_kind || name.starts_with("common_data."))
+                .filter(|name| !names.contains(name))
+                .collect();
+            names.extend(new_names);
+        }
+        names.sort();
+
+        let fields = names
+            .into_iter()
+            .filter_map(|field| {
+                let (parent, key) = field_path(&field);
+                let mut candidates: Vec<Candidate> = Vec::new();
+                for (source, fields, same_kind) in &versions {
+                    let value = match parent {
+                        Some(parent) => fields.get(parent).and_then(|parent| parent.get(key)),
+                        None if *same_kind => fields.get(key),
+                        None => continue,
+                    };
+                    let value = value.filter(|value| !value.is_null()).cloned();
+                    match candidates.iter_mut().find(|c| c.value == value) {
+                        Some(candidate) => candidate.sources.push(source.to_string()),
+                        None => candidates.push(Candidate {
+                            value,
+                            sources: vec![source.to_string()],
+                        }),
+                    }
+                }
+                (candidates.len() > 1).then(|| FieldCandidates {
+                    suggested: suggested(&candidates),
+                    field,
+                    candidates,
+                })
+            })
+            .collect();
+
+        Some(Self {
+            sources,
+            fields,
+            base,
+        })
+    }
+
+    /// Compare duplicates of the same work, named by their IDs. The first
+    /// citation's ID is kept.
+    pub fn from_duplicates(citations: &[&Citation]) -> Option<Self> {
+        let ids: Vec<String> = citations.iter().map(|citation| citation.id()).collect();
+        let versions: Vec<(&str, &Citation)> = ids
+            .iter()
+            .map(String::as_str)
+            .zip(citations.iter().copied())
+            .collect();
+        Self::new(&versions)
+    }
+
+    /// Whether the versions agree on every field
+    pub fn is_unanimous(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// The suggested candidate of every field
+    pub fn suggested_resolutions(&self) -> Resolutions {
+        self.fields
+            .iter()
+            .map(|field| (field.field.clone(), field.suggested))
+            .collect()
+    }
+
+    /// Build the merged entry with the chosen candidate of each field.
+    /// Every field of the proposal must be resolved.
+    pub fn apply(&self, resolutions: &Resolutions) -> Result<Citation, MergeError> {
+        if let Some(field) = resolutions.keys().find(|field| {
+            !self
+                .fields
+                .iter()
+                .any(|candidates| &candidates.field == *field)
+        }) {
+            return Err(MergeError::UnknownField(field.clone()));
+        }
+        let mut merged = self.base.clone();
+        let Some((_, fields)) = variant_mut(&mut merged) else {
+            return Ok(serde_json::from_value(merged)?);
+        };
+        for candidates in &self.fields {
+            let field = &candidates.field;
+            let index = *resolutions
+                .get(field)
+                .ok_or_else(|| MergeError::Unresolved(field.clone()))?;
+            let candidate =
+                candidates
+                    .candidates
+                    .get(index)
+                    .ok_or_else(|| MergeError::NoSuchCandidate {
+                        field: field.clone(),
+                        index,
+                    })?;
+            let (parent, key) = field_path(field);
+            let parent = match parent {
+                Some(parent) => fields.as_object_mut().map(|fields| {
+                    fields
+                        .entry(parent)
+                        .or_insert_with(|| Value::Object(Map::new()))
+                }),
+                None => Some(&mut *fields),
+            };
+            if let Some(Value::Object(parent)) = parent {
+                match &candidate.value {
+                    Some(value) => parent.insert(key.to_string(), value.clone()),
+                    None => parent.remove(key),
+                };
+            }
+        }
+        Ok(serde_json::from_value(merged)?)
+    }
+}
+
+impl MetadataRefresh {
+    /// The refresh as a [`MergeProposal`] between the fetched metadata
+    /// (source "doi", suggested) and `current`, the entry as it is
+    pub fn to_proposal(&self, current: &Citation) -> MergeProposal {
+        let id = current.id();
+        MergeProposal::new(&[("doi", &self.proposed), (&id, current)])
+            .expect("a refresh has versions to compare")
+    }
+}
+
+/// The media type and fields of a serialized citation
+fn variant(citation: &Value) -> Option<(&String, &Value)> {
+    citation.as_object()?.iter().next()
+}
+
+fn variant_mut(citation: &mut Value) -> Option<(&String, &mut Value)> {
+    citation.as_object_mut()?.iter_mut().next()
+}
+
+/// The names of the fields of a serialized media type that can be
+/// proposed, with `common_data` fields prefixed
+fn field_names(fields: &Value) -> impl Iterator<Item = String> + '_ {
+    let fields = fields.as_object().into_iter().flatten();
+    fields.flat_map(|(name, value)| -> Vec<String> {
+        if name == "common_data" {
+            value
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, _)| name)
+                .filter(|name| !OWN_FIELDS.contains(&name.as_str()))
+                .map(|name| format!("common_data.{}", name))
+                .collect()
+        } else {
+            vec![name.clone()]
+        }
+    })
+}
+
+/// The parent object (`None` for the media type's own fields) and name
+/// of a field
+fn field_path(field: &str) -> (Option<&str>, &str) {
+    match field.split_once('.') {
+        Some((parent, key)) => (Some(parent), key),
+        None => (None, field),
+    }
+}
+
+fn suggested(candidates: &[Candidate]) -> usize {
+    let set = candidates
+        .iter()
+        .enumerate()
+        .filter(|(_, candidate)| candidate.value.is_some());
+    // max_by_key keeps the last maximum, so compare in reverse order
+    set.rev()
+        .max_by_key(|(_, candidate)| candidate.sources.len())
+        .map_or(0, |(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+        },
+        import::{identifier::Identifier, refresh::compare},
+        merge_proposal::{Candidate, MergeError, MergeProposal, Resolutions},
+    };
+
+    fn article(id: &str, title: &str, year: Option<i32>) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: year.map(PublishDate::from_year),
+                tags: vec![format!("from-{}", id)],
+                ..CommonCitationData::new(id)
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            title: title.into(),
+            journal: "J. Tests".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: None,
+        })
+    }
+
+    #[test]
+    fn test_candidates() {
+        let a = article("a", "Canonical title", Some(2021));
+        let b = article("b", "Imported title", None);
+        let c = article("c", "Canonical title", Some(2020));
+        let proposal = MergeProposal::from_duplicates(&[&a, &b, &c]).unwrap();
+
+        assert_eq!(proposal.sources, ["a", "b", "c"]);
+        let fields: Vec<&str> = proposal.fields.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(fields, ["common_data.published", "title"]);
+
+        let published = &proposal.fields[0];
+        assert_eq!(
+            published.candidates[1],
+            Candidate {
+                value: None,
+                sources: vec!["b".to_string()],
+            }
+        );
+        assert_eq!(published.suggested, 0);
+        let title = &proposal.fields[1];
+        assert_eq!(title.candidates[0].value, Some(json!("Canonical title")));
+        assert_eq!(title.candidates[0].sources, ["a", "c"]);
+        assert_eq!(title.suggested, 0);
+
+        assert!(
+            MergeProposal::from_duplicates(&[&a, &a.clone()])
+                .unwrap()
+                .is_unanimous()
+        );
+        assert_eq!(MergeProposal::from_duplicates(&[]), None);
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut a = article("a", "Canonical title", Some(2021));
+        a.common_data_mut()
+            .identifiers
+            .insert(Identifier::Pmid("1".to_string()));
+        let mut b = article("b", "Imported title", None);
+        b.common_data_mut()
+            .identifiers
+            .insert(Identifier::Pmid("2".to_string()));
+        b.common_data_mut()
+            .identifiers
+            .insert(Identifier::ArXiv("2101.00001".to_string()));
+        let proposal = MergeProposal::from_duplicates(&[&a, &b]).unwrap();
+
+        assert!(matches!(
+            proposal.apply(&Resolutions::new()),
+            Err(MergeError::Unresolved(_))
+        ));
+        let mut resolutions = proposal.suggested_resolutions();
+        resolutions.insert("journal".to_string(), 0);
+        assert!(matches!(
+            proposal.apply(&resolutions),
+            Err(MergeError::UnknownField(field)) if field == "journal"
+        ));
+
+        let resolutions = Resolutions::from([
+            ("common_data.published".to_string(), 1),
+            ("title".to_string(), 1),
+        ]);
+        let merged = proposal.apply(&resolutions).unwrap();
+        assert_eq!(merged.id(), "a");
+        assert_eq!(merged.title(), "Imported title");
+        assert_eq!(merged.published(), None);
+        assert_eq!(merged.tags(), ["from-a", "from-b"]);
+        let identifiers = &merged.common_data().identifiers;
+        assert_eq!(identifiers.pmid(), Some("1"));
+        assert_eq!(identifiers.arxiv(), Some("2101.00001"));
+    }
+
+    #[test]
+    fn test_refresh_proposal() {
+        let current = article("smith2021", "Imported title", Some(2020));
+        let fetched = article("10.1000/xyz", "Canonical title", Some(2021));
+        let proposal = compare(&current, fetched).to_proposal(&current);
+
+        assert_eq!(proposal.sources, ["doi", "smith2021"]);
+        assert_eq!(proposal.fields.len(), 2);
+        let keep_title = Resolutions::from([
+            ("common_data.published".to_string(), 0),
+            ("title".to_string(), 1),
+        ]);
+        let refreshed = proposal.apply(&keep_title).unwrap();
+        assert_eq!(refreshed.id(), "smith2021");
+        assert_eq!(refreshed.title(), "Imported title");
+        assert_eq!(refreshed.published(), Some(PublishDate::from_year(2021)));
+    }
+}
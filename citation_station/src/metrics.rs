@@ -0,0 +1,213 @@
+//! Counters of how the importers fared, so applications can tell users
+//! e.g. "17 of 200 lookups failed".
+//!
+//! Counting is process-wide and only happens with the `metrics` feature.
+//! Take a [`snapshot`] before and after a batch import and
+//! [`MetricsSnapshot::since`] gives the counts for that import alone.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    ops::AddAssign,
+    sync::Mutex,
+};
+
+use crate::{import::ImportError, net::FetchError};
+
+static COUNTERS: Mutex<BTreeMap<&'static str, LookupCounts>> = Mutex::new(BTreeMap::new());
+
+/// Counts for one service, or for all of them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LookupCounts {
+    /// Lookups that produced a citation
+    pub successes: u64,
+    /// Lookups that failed for any reason, including parse failures
+    pub failures: u64,
+    /// Failed lookups whose response couldn't be read
+    pub parse_failures: u64,
+    /// Failed lookups because the service doesn't know the identifier
+    pub not_found: u64,
+    /// Inputs that didn't need a lookup because the bibliography already
+    /// had them
+    pub cache_hits: u64,
+    /// Lookups that succeeded only partly and used a fallback, e.g. a
+    /// GitHub repository without a CITATION.cff credited to its owner
+    pub fallbacks: u64,
+}
+
+impl LookupCounts {
+    /// Number of lookups made, successful or not
+    pub fn lookups(&self) -> u64 {
+        self.successes + self.failures
+    }
+
+    fn saturating_sub(self, earlier: Self) -> Self {
+        Self {
+            successes: self.successes.saturating_sub(earlier.successes),
+            failures: self.failures.saturating_sub(earlier.failures),
+            parse_failures: self.parse_failures.saturating_sub(earlier.parse_failures),
+            not_found: self.not_found.saturating_sub(earlier.not_found),
+            cache_hits: self.cache_hits.saturating_sub(earlier.cache_hits),
+            fallbacks: self.fallbacks.saturating_sub(earlier.fallbacks),
+        }
+    }
+}
+
+impl AddAssign for LookupCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.successes += other.successes;
+        self.failures += other.failures;
+        self.parse_failures += other.parse_failures;
+        self.not_found += other.not_found;
+        self.cache_hits += other.cache_hits;
+        self.fallbacks += other.fallbacks;
+    }
+}
+
+/// The counters at one point in time, per service ("crossref",
+/// "datacite", "github", "open_library", "pubmed", "web", "wikipedia").
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    pub sources: BTreeMap<&'static str, LookupCounts>,
+}
+
+impl MetricsSnapshot {
+    /// Counts for one service, all zero if it wasn't used
+    pub fn source(&self, source: &str) -> LookupCounts {
+        self.sources.get(source).copied().unwrap_or_default()
+    }
+
+    /// Counts summed over every service. Each lookup is counted under one
+    /// service only: a PubMed import is a PubMed lookup, even though the
+    /// article is read from Crossref.
+    pub fn total(&self) -> LookupCounts {
+        let mut total = LookupCounts::default();
+        for counts in self.sources.values() {
+            total += *counts;
+        }
+        total
+    }
+
+    /// What was counted between `earlier` and this snapshot
+    pub fn since(&self, earlier: &MetricsSnapshot) -> MetricsSnapshot {
+        let sources = self
+            .sources
+            .iter()
+            .map(|(source, counts)| (*source, counts.saturating_sub(earlier.source(source))))
+            .collect();
+        MetricsSnapshot { sources }
+    }
+}
+
+impl Display for MetricsSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let total = self.total();
+        write!(
+            f,
+            "{} of {} lookups failed",
+            total.failures,
+            total.lookups()
+        )
+    }
+}
+
+/// The current value of every counter
+pub fn snapshot() -> MetricsSnapshot {
+    MetricsSnapshot {
+        sources: COUNTERS.lock().unwrap().clone(),
+    }
+}
+
+/// Set every counter back to zero
+pub fn reset() {
+    COUNTERS.lock().unwrap().clear();
+}
+
+fn count(source: &'static str, update: impl FnOnce(&mut LookupCounts)) {
+    update(COUNTERS.lock().unwrap().entry(source).or_default());
+}
+
+pub(crate) fn record_lookup<T>(source: &'static str, result: &Result<T, ImportError>) {
+    count(source, |counts| match result {
+        Ok(_) => counts.successes += 1,
+        Err(err) => {
+            counts.failures += 1;
+            match err {
+                ImportError::Parse(_) => counts.parse_failures += 1,
+                ImportError::Fetch(FetchError::Status { status: 404, .. }) => counts.not_found += 1,
+                _ => {}
+            }
+        }
+    });
+}
+
+pub(crate) fn record_cache_hit(source: &'static str) {
+    count(source, |counts| counts.cache_hits += 1);
+}
+
+pub(crate) fn record_fallback(source: &'static str) {
+    count(source, |counts| counts.fallbacks += 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        import::ImportError,
+        metrics::{LookupCounts, MetricsSnapshot, record_cache_hit, record_lookup, snapshot},
+        net::FetchError,
+    };
+
+    #[test]
+    fn test_record_lookup() {
+        // Other tests count too, so only look at a made-up service
+        let before = snapshot();
+        record_lookup("test", &Ok::<(), ImportError>(()));
+        record_lookup::<()>("test", &Err(ImportError::Parse("bad".to_string())));
+        record_lookup::<()>(
+            "test",
+            &Err(ImportError::Fetch(FetchError::Status {
+                url: "https://example.com".to_string(),
+                status: 404,
+            })),
+        );
+        record_cache_hit("test");
+
+        let counted = snapshot().since(&before);
+        assert_eq!(
+            counted.source("test"),
+            LookupCounts {
+                successes: 1,
+                failures: 2,
+                parse_failures: 1,
+                not_found: 1,
+                cache_hits: 1,
+                fallbacks: 0,
+            }
+        );
+        assert_eq!(counted.source("test").lookups(), 3);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut snapshot = MetricsSnapshot::default();
+        snapshot.sources.insert(
+            "crossref",
+            LookupCounts {
+                successes: 183,
+                failures: 17,
+                ..Default::default()
+            },
+        );
+        assert_eq!(snapshot.to_string(), "17 of 200 lookups failed");
+
+        snapshot.sources.insert(
+            "pubmed",
+            LookupCounts {
+                successes: 9,
+                failures: 1,
+                ..Default::default()
+            },
+        );
+        assert_eq!(snapshot.to_string(), "18 of 210 lookups failed");
+    }
+}
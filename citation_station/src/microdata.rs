@@ -0,0 +1,193 @@
+//! Semantic HTML for published bibliographies: each formatted citation is
+//! wrapped in a `<cite>` element carrying schema.org microdata, so search
+//! engines and reference managers can read an HTML or EPUB reference list
+//! as well as display it.
+//!
+//! The styled text is HTML-escaped apart from its italics; the metadata is
+//! added as `<meta>` and `<link>` elements, which browsers don't display.
+//!
+//! Vocabulary reference: https://schema.org/CreativeWork
+
+use crate::{
+    api::{
+        author::Contributor,
+        citation::Citation,
+        style::{CitationStyle, FormatOptions, Markup},
+    },
+    export::iso_date,
+    xml::escape,
+};
+
+/// Format `citation` in `style` and wrap it in a `<cite>` element with
+/// schema.org microdata. The text is always formatted with
+/// [`Markup::Html`], whatever markup `options` asks for, so everything but
+/// its `<i>` and `<b>` elements is escaped.
+pub fn citation_to_microdata(
+    citation: &Citation,
+    style: CitationStyle,
+    options: &FormatOptions,
+) -> String {
+    let mut properties: Vec<String> = vec![meta("name", &citation.title())];
+    for contributor in citation.contributors() {
+        let (kind, name) = match contributor {
+            Contributor::Person(person) => ("Person", person.full_name()),
+            Contributor::Organization { name, .. } => ("Organization", name),
+            Contributor::Pseudonym { name, .. } => ("Person", name),
+            Contributor::Anonymous => continue,
+        };
+        properties.push(format!(
+            r#"<span itemprop="author" itemscope itemtype="https://schema.org/{}">{}</span>"#,
+            kind,
+            meta("name", &name)
+        ));
+    }
+    if let Some(published) = citation.published() {
+        properties.push(meta("datePublished", &iso_date(&published)));
+    }
+    if let Some((kind, name)) = container(citation) {
+        properties.push(format!(
+            r#"<span itemprop="isPartOf" itemscope itemtype="https://schema.org/{}">{}</span>"#,
+            kind,
+            meta("name", &name)
+        ));
+    }
+    if let Some(doi) = citation.doi() {
+        properties.push(link("sameAs", &format!("https://doi.org/{}", doi)));
+    }
+    if let Some(url) = citation.url() {
+        properties.push(link("url", &url));
+    }
+
+    format!(
+        r#"<cite itemscope itemtype="https://schema.org/{}">{}{}</cite>"#,
+        item_type(citation),
+        citation.format_with(
+            style,
+            &FormatOptions {
+                markup: Markup::Html,
+                ..options.clone()
+            }
+        ),
+        properties.concat()
+    )
+}
+
+/// A fragment identifier for linking to a citation's entry, e.g. "ref-smith2020"
+pub fn anchor(id: &str) -> String {
+    let id: String = id
+        .chars()
+        .map(|c| if c.is_whitespace() { '-' } else { c })
+        .collect();
+    format!("ref-{}", escape(&id))
+}
+
+/// The schema.org type of the cited work
+fn item_type(citation: &Citation) -> &'static str {
+    match citation {
+        Citation::Book(_) | Citation::ConferenceProceedingsOnline(_) => "Book",
+        Citation::JournalArticle(_) | Citation::ConferencePaperOnline(_) => "ScholarlyArticle",
+        Citation::ConferencePresentation(_) => "PresentationDigitalDocument",
+        Citation::OnlineManual(_) => "TechArticle",
+        Citation::OnlineVideo(_) => "VideoObject",
+        Citation::Software(_) => "SoftwareSourceCode",
+        Citation::WebPage(_) => "WebPage",
+        Citation::WikipediaArticle(_) => "Article",
+        Citation::Custom(_) => "CreativeWork",
+    }
+}
+
+/// The schema.org type and name of the work the cited work is part of
+fn container(citation: &Citation) -> Option<(&'static str, String)> {
+    match citation {
+        Citation::JournalArticle(article) => Some(("Periodical", article.journal.clone())),
+        Citation::ConferencePaperOnline(paper) => Some((
+            "Book",
+            paper.series.proceedings_title(&paper.conference_name),
+        )),
+        Citation::WebPage(page) => page
+            .site_name
+            .clone()
+            .map(|site_name| ("WebSite", site_name)),
+        Citation::WikipediaArticle(_) => Some(("WebSite", "Wikipedia".to_string())),
+        _ => None,
+    }
+}
+
+fn meta(property: &str, content: &str) -> String {
+    format!(
+        r#"<meta itemprop="{}" content="{}">"#,
+        property,
+        escape(content)
+    )
+}
+
+fn link(property: &str, href: &str) -> String {
+    format!(r#"<link itemprop="{}" href="{}">"#, property, escape(href))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+            style::{CitationStyle, FormatOptions, Markup},
+        },
+        microdata::{anchor, citation_to_microdata},
+    };
+
+    fn article() -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month(2020, Month::March)),
+                ..CommonCitationData::new("smith2020")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            title: "R&D in \"Yeast\"".into(),
+            journal: "J. Tests".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: Some("10.1000/xyz".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_citation_to_microdata() {
+        let options = FormatOptions {
+            markup: Markup::Html,
+            ..FormatOptions::for_style(CitationStyle::Apa)
+        };
+        let html = citation_to_microdata(&article(), CitationStyle::Apa, &options);
+
+        assert!(html.starts_with(
+            r#"<cite itemscope itemtype="https://schema.org/ScholarlyArticle">Smith, J. (2020"#
+        ));
+        assert!(html.contains(r#"<meta itemprop="name" content="R&amp;D in &quot;Yeast&quot;">"#));
+        assert!(html.contains(
+            r#"<span itemprop="author" itemscope itemtype="https://schema.org/Person"><meta itemprop="name" content="Jane Smith"></span>"#
+        ));
+        assert!(html.contains(r#"<meta itemprop="datePublished" content="2020-03">"#));
+        assert!(html.contains(r#"<link itemprop="sameAs" href="https://doi.org/10.1000/xyz">"#));
+        assert!(html.ends_with("</cite>"));
+
+        let plain = FormatOptions::for_style(CitationStyle::Apa);
+        assert_eq!(
+            citation_to_microdata(&article(), CitationStyle::Apa, &plain),
+            html
+        );
+        assert!(html.contains("R&amp;D in"));
+    }
+
+    #[test]
+    fn test_anchor() {
+        assert_eq!(anchor("smith 2020"), "ref-smith-2020");
+        assert_eq!(anchor("a\"b"), "ref-a&quot;b");
+    }
+}
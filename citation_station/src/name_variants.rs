@@ -0,0 +1,280 @@
+//! Authors who appear under different forms of their name across a
+//! bibliography, e.g. "J. Smith", "Jane Smith" and "Smith, Jane Q.".
+
+use std::collections::BTreeMap;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::{author::PersonName, citation::Citation};
+
+/// How likely a name form is to name the same person as another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NameConfidence {
+    /// Only the surname is known, or the initials also fit someone else
+    /// in the bibliography
+    Low,
+    /// Initials that match the full given names, e.g. "J. Smith" and
+    /// "Jane Smith"
+    Medium,
+    /// The same given names, written more or less completely, e.g.
+    /// "Jane Smith" and "Jane Q. Smith"
+    High,
+}
+
+/// One way a person's name is written, and the entries that write it so.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameForm {
+    pub name: PersonName,
+    /// IDs of the entries that credit the person in this form
+    pub citation_ids: Vec<String>,
+}
+
+/// A name form that probably names the same person as a cluster's
+/// canonical form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameVariant {
+    pub form: NameForm,
+    pub confidence: NameConfidence,
+}
+
+/// A person found under several forms of their name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameCluster {
+    /// The most complete form, which the variants would be unified to
+    pub canonical: NameForm,
+    pub variants: Vec<NameVariant>,
+}
+
+/// A given name as written, compared without case or periods
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Given {
+    Initial(String),
+    Full(String),
+}
+
+impl Given {
+    fn parse(token: &str) -> Option<Self> {
+        let token = token.trim_matches('.').to_lowercase();
+        match token.graphemes(true).count() {
+            0 => None,
+            1 => Some(Given::Initial(token)),
+            _ => Some(Given::Full(token)),
+        }
+    }
+
+    fn completeness(&self) -> usize {
+        match self {
+            Given::Initial(_) => 1,
+            Given::Full(_) => 2,
+        }
+    }
+}
+
+fn given_names(name: &PersonName) -> Vec<Given> {
+    name.given_names()
+        .map(|given| given.split_whitespace().filter_map(Given::parse).collect())
+        .unwrap_or_default()
+}
+
+fn completeness(given: &[Given]) -> usize {
+    given.iter().map(Given::completeness).sum()
+}
+
+/// How strongly two sets of given names of the same surname agree, or
+/// `None` if they can't belong to the same person
+fn agreement(a: &[Given], b: &[Given]) -> Option<NameConfidence> {
+    if a.is_empty() || b.is_empty() {
+        return Some(NameConfidence::Low);
+    }
+    let mut confidence = NameConfidence::High;
+    for pair in a.iter().zip(b) {
+        match pair {
+            (Given::Full(a), Given::Full(b)) | (Given::Initial(a), Given::Initial(b)) => {
+                if a != b {
+                    return None;
+                }
+            }
+            (Given::Initial(initial), Given::Full(full))
+            | (Given::Full(full), Given::Initial(initial)) => {
+                if !full.starts_with(initial.as_str()) {
+                    return None;
+                }
+                confidence = NameConfidence::Medium;
+            }
+        }
+    }
+    Some(confidence)
+}
+
+/// Group the persons credited in `citations` by surname and find the
+/// forms of each surname that probably name the same person.
+///
+/// Each cluster is led by its most complete form (then the most cited);
+/// every other form joins the most complete compatible cluster. A form
+/// that fits several clusters, like "J. Smith" next to both "Jane Smith"
+/// and "John Smith", gets low confidence.
+pub fn find_name_variants(citations: &[Citation]) -> Vec<NameCluster> {
+    let mut by_surname: BTreeMap<String, Vec<NameForm>> = BTreeMap::new();
+    for citation in citations {
+        let id = citation.id();
+        for person in citation.persons() {
+            let forms = by_surname
+                .entry(person.surname().to_lowercase())
+                .or_default();
+            match forms.iter_mut().find(|form| form.name == person) {
+                Some(form) if form.citation_ids.contains(&id) => {}
+                Some(form) => form.citation_ids.push(id.clone()),
+                None => forms.push(NameForm {
+                    name: person,
+                    citation_ids: vec![id.clone()],
+                }),
+            }
+        }
+    }
+
+    let mut clusters = Vec::new();
+    for mut forms in by_surname.into_values().filter(|forms| forms.len() > 1) {
+        forms.sort_by_cached_key(|form| {
+            (
+                std::cmp::Reverse(completeness(&given_names(&form.name))),
+                std::cmp::Reverse(form.citation_ids.len()),
+                form.name.full_name(),
+            )
+        });
+
+        let mut surname_clusters: Vec<NameCluster> = Vec::new();
+        for form in forms {
+            let given = given_names(&form.name);
+            let matches: Vec<(usize, NameConfidence)> = surname_clusters
+                .iter()
+                .enumerate()
+                .filter_map(|(index, cluster)| {
+                    agreement(&given_names(&cluster.canonical.name), &given)
+                        .map(|confidence| (index, confidence))
+                })
+                .collect();
+            match matches.as_slice() {
+                [] => surname_clusters.push(NameCluster {
+                    canonical: form,
+                    variants: Vec::new(),
+                }),
+                [(index, confidence)] => surname_clusters[*index].variants.push(NameVariant {
+                    form,
+                    confidence: *confidence,
+                }),
+                [(index, _), ..] => surname_clusters[*index].variants.push(NameVariant {
+                    form,
+                    confidence: NameConfidence::Low,
+                }),
+            }
+        }
+        clusters.extend(
+            surname_clusters
+                .into_iter()
+                .filter(|cluster| !cluster.variants.is_empty()),
+        );
+    }
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+        },
+        name_variants::{NameConfidence, find_name_variants},
+    };
+
+    fn article(id: &str, persons: Vec<PersonName>) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData::new(id),
+            author: AcademicAuthor::Persons { persons },
+            title: id.into(),
+            journal: "J. Tests".to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi: None,
+        })
+    }
+
+    #[test]
+    fn test_variants_join_most_complete_form() {
+        let clusters = find_name_variants(&[
+            article(
+                "a",
+                vec![PersonName::from_first_last("J.", "Smith").unwrap()],
+            ),
+            article(
+                "b",
+                vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            ),
+            article(
+                "c",
+                vec![PersonName::from_first_middle_last("Jane", "Q.", "Smith").unwrap()],
+            ),
+            article("d", vec![PersonName::from_first_last("Bo", "Lee").unwrap()]),
+        ]);
+
+        assert_eq!(clusters.len(), 1);
+        let cluster = &clusters[0];
+        assert_eq!(cluster.canonical.name.full_name(), "Jane Q. Smith");
+        assert_eq!(cluster.canonical.citation_ids, ["c"]);
+        let variants: Vec<(String, NameConfidence)> = cluster
+            .variants
+            .iter()
+            .map(|variant| (variant.form.name.full_name(), variant.confidence))
+            .collect();
+        assert_eq!(
+            variants,
+            [
+                ("Jane Smith".to_string(), NameConfidence::High),
+                ("J. Smith".to_string(), NameConfidence::Medium),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_initials_have_low_confidence() {
+        let clusters = find_name_variants(&[
+            article(
+                "a",
+                vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            ),
+            article(
+                "b",
+                vec![PersonName::from_first_last("John", "Smith").unwrap()],
+            ),
+            article(
+                "c",
+                vec![PersonName::from_first_last("J.", "Smith").unwrap()],
+            ),
+        ]);
+
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].variants[0].confidence, NameConfidence::Low);
+    }
+
+    #[test]
+    fn test_different_people_are_not_variants() {
+        let clusters = find_name_variants(&[
+            article(
+                "a",
+                vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            ),
+            article(
+                "b",
+                vec![PersonName::from_first_last("Anne", "Smith").unwrap()],
+            ),
+            article(
+                "c",
+                vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            ),
+        ]);
+
+        assert!(clusters.is_empty());
+    }
+}
@@ -0,0 +1,439 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+use crate::import::DEFAULT_MAX_CONCURRENCY;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum FetchError {
+    #[error("Request to {url} failed: {reason}")]
+    Transport { url: String, reason: String },
+    #[error("Request to {url} returned HTTP status {status}")]
+    Status { url: String, status: u16 },
+}
+
+/// A response to a GET request. Build one with [`HttpResponse::new`];
+/// fields may be added, so struct literals only work inside this crate.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+    /// How long the server asked the client to wait before trying again,
+    /// from the `Retry-After` header
+    pub retry_after: Option<Duration>,
+}
+
+impl HttpResponse {
+    /// A response without a `Retry-After` header
+    pub fn new(status: u16, body: String) -> Self {
+        Self {
+            status,
+            body,
+            retry_after: None,
+        }
+    }
+
+    /// The response with the wait asked for by a `Retry-After` header
+    pub fn with_retry_after(self, retry_after: Duration) -> Self {
+        Self {
+            retry_after: Some(retry_after),
+            ..self
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Whether the server is rate limiting the client (429) or is
+    /// temporarily unavailable (503), so the request is worth repeating
+    pub fn should_retry(&self) -> bool {
+        matches!(self.status, 429 | 503)
+    }
+}
+
+/// A `Retry-After` value in seconds. The HTTP date form isn't read, so
+/// the usual backoff is used instead.
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+/// Longest pause [`RetryingClient`] makes, whatever the configuration
+const MAX_PAUSE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How the importers talk to web services such as Crossref, which ask
+/// heavy users to identify themselves and to slow down when told to.
+///
+/// Setting `mailto` puts Crossref requests in its "polite pool", which is
+/// served more reliably than anonymous requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchConfig {
+    /// Contact address sent in the `User-Agent` header
+    pub mailto: Option<String>,
+    /// Most requests made at a time by batch imports
+    pub max_concurrency: usize,
+    /// How many times a rate limited (429) or unavailable (503) request
+    /// is repeated before giving up
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled for every retry after it
+    /// unless the server says how long to wait
+    pub initial_backoff: Duration,
+    /// Longest wait between retries, including ones asked for by the
+    /// server
+    pub max_backoff: Duration,
+}
+
+impl Default for FetchConfig {
+    fn default() -> Self {
+        Self {
+            mailto: None,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl FetchConfig {
+    /// The default configuration, identifying requests with `mailto`
+    pub fn polite(mailto: &str) -> Self {
+        Self {
+            mailto: Some(mailto.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// The `User-Agent` header, e.g.
+    /// "citation_station/0.1.0 (mailto:someone@example.com)"
+    pub fn user_agent(&self) -> String {
+        let agent = concat!("citation_station/", env!("CARGO_PKG_VERSION"));
+        match &self.mailto {
+            Some(mailto) => format!("{} (mailto:{})", agent, mailto),
+            None => agent.to_string(),
+        }
+    }
+
+    /// How long to wait before retry number `retry` (from 0), given what
+    /// the server asked for
+    fn backoff(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        retry_after
+            .unwrap_or_else(|| {
+                self.initial_backoff
+                    .saturating_mul(2u32.saturating_pow(retry))
+            })
+            .min(self.max_backoff)
+    }
+}
+
+/// An [`HttpClient`] that repeats rate limited and temporarily failing
+/// requests of another client, waiting as configured by [`FetchConfig`].
+///
+/// When one request is told to wait, requests from the other threads of a
+/// batch import wait too, rather than adding to the load on the server.
+pub struct RetryingClient<C> {
+    inner: C,
+    config: FetchConfig,
+    /// Until when every request waits, after the server asked for a pause
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl<C: HttpClient> RetryingClient<C> {
+    pub fn new(inner: C, config: FetchConfig) -> Self {
+        Self {
+            inner,
+            config,
+            paused_until: Mutex::new(None),
+        }
+    }
+
+    pub fn config(&self) -> &FetchConfig {
+        &self.config
+    }
+
+    fn wait_for_pause(&self) {
+        let paused_until = *self.paused_until.lock().unwrap();
+        if let Some(wait) =
+            paused_until.and_then(|until| until.checked_duration_since(Instant::now()))
+        {
+            thread::sleep(wait);
+        }
+    }
+
+    fn pause(&self, wait: Duration) {
+        // `max_backoff` can be set high enough to overflow an `Instant`
+        let now = Instant::now();
+        let until = now.checked_add(wait.min(MAX_PAUSE)).unwrap_or(now);
+        let mut paused_until = self.paused_until.lock().unwrap();
+        if paused_until.is_none_or(|current| current < until) {
+            *paused_until = Some(until);
+        }
+    }
+}
+
+impl<C: HttpClient> HttpClient for RetryingClient<C> {
+    fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+        let mut retry = 0;
+        loop {
+            self.wait_for_pause();
+            let response = self.inner.get(url)?;
+            if !response.should_retry() || retry >= self.config.max_retries {
+                return Ok(response);
+            }
+            self.pause(self.config.backoff(retry, response.retry_after));
+            retry += 1;
+        }
+    }
+}
+
+/// A blocking HTTP client used by the importers.
+///
+/// Importers are generic over this trait so that applications can bring
+/// their own HTTP stack (and tests can serve canned responses). The client
+/// is shared between worker threads during batch imports, hence `Sync`.
+pub trait HttpClient: Sync {
+    fn get(&self, url: &str) -> Result<HttpResponse, FetchError>;
+}
+
+/// Default [`HttpClient`] backed by `ureq`.
+#[cfg(feature = "net")]
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "net")]
+impl UreqClient {
+    pub fn new() -> Self {
+        Self::with_config(&FetchConfig::default())
+    }
+
+    /// A client sending the `User-Agent` of `config`. Wrap it in a
+    /// [`RetryingClient`] to also back off as configured.
+    pub fn with_config(config: &FetchConfig) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(config.user_agent())
+            .build()
+            .into();
+
+        Self { agent }
+    }
+}
+
+#[cfg(feature = "net")]
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "net")]
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+        let transport_error = |err: ureq::Error| FetchError::Transport {
+            url: url.to_string(),
+            reason: err.to_string(),
+        };
+
+        let mut response = self.agent.get(url).call().map_err(transport_error)?;
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(transport_error)?;
+
+        Ok(HttpResponse {
+            retry_after,
+            ..HttpResponse::new(status, body)
+        })
+    }
+}
+
+/// A non-blocking HTTP client, for platforms that can't wait for a
+/// response on the calling thread, such as WebAssembly in the browser.
+///
+/// The `_async` importers, like
+/// [`import_identifier_async`](crate::import::identifier::import_identifier_async),
+/// are generic over this trait the way the blocking ones are over
+/// [`HttpClient`].
+// The futures aren't required to be `Send`: the browser's aren't, and
+// they are awaited on the thread that made them.
+#[allow(async_fn_in_trait)]
+pub trait AsyncHttpClient {
+    async fn get(&self, url: &str) -> Result<HttpResponse, FetchError>;
+}
+
+/// Run `future` to completion on this thread, for testing the async
+/// importers with clients that answer straight away
+#[cfg(test)]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, Waker};
+
+    let mut future = std::pin::pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// HTTP client for WebAssembly in the browser, over the `fetch` API, for
+/// web apps that import citations without a server of their own.
+///
+/// It works in windows as well as Web Workers. The sites fetched from must
+/// allow cross-origin requests, as Crossref and DataCite do.
+///
+/// Browsers have no threads to run the batch importers such as
+/// [`Bibliography::import_dois_with`] on; import one item at a time
+/// instead.
+///
+/// ```ignore
+/// let (_, citation) = Citation::from_identifier_async(&BrowserClient, "10.1000/xyz123").await?;
+/// ```
+///
+/// [`Bibliography::import_dois_with`]: crate::bibliography::Bibliography::import_dois_with
+#[cfg(feature = "wasm")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BrowserClient;
+
+#[cfg(feature = "wasm")]
+impl AsyncHttpClient for BrowserClient {
+    async fn get(&self, url: &str) -> Result<HttpResponse, FetchError> {
+        use js_sys::wasm_bindgen::{JsCast, JsValue};
+        use wasm_bindgen_futures::JsFuture;
+        use web_sys::{Response, Window, WorkerGlobalScope};
+
+        // `fetch` rejects on network errors and blocked cross-origin requests
+        let transport_error = |err: JsValue| FetchError::Transport {
+            url: url.to_string(),
+            reason: err.as_string().unwrap_or_else(|| format!("{:?}", err)),
+        };
+        let global = js_sys::global();
+        let request = match global.dyn_ref::<Window>() {
+            Some(window) => window.fetch_with_str(url),
+            None => global
+                .unchecked_ref::<WorkerGlobalScope>()
+                .fetch_with_str(url),
+        };
+        let response: Response = JsFuture::from(request)
+            .await
+            .map_err(transport_error)?
+            .unchecked_into();
+        let retry_after = response
+            .headers()
+            .get("Retry-After")
+            .ok()
+            .flatten()
+            .and_then(|value| parse_retry_after(&value));
+        let body = JsFuture::from(response.text().map_err(transport_error)?)
+            .await
+            .map_err(transport_error)?
+            .as_string()
+            .unwrap_or_default();
+
+        Ok(HttpResponse {
+            retry_after,
+            ..HttpResponse::new(response.status(), body)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Mutex,
+        time::{Duration, Instant},
+    };
+
+    use crate::net::{
+        FetchConfig, FetchError, HttpClient, HttpResponse, MAX_PAUSE, RetryingClient,
+    };
+
+    /// Answers with the given statuses in turn, then with 200
+    struct Flaky {
+        statuses: Mutex<Vec<u16>>,
+    }
+
+    impl HttpClient for Flaky {
+        fn get(&self, _url: &str) -> Result<HttpResponse, FetchError> {
+            let mut statuses = self.statuses.lock().unwrap();
+            let status = if statuses.is_empty() {
+                200
+            } else {
+                statuses.remove(0)
+            };
+            Ok(HttpResponse::new(status, String::new()).with_retry_after(Duration::from_secs(30)))
+        }
+    }
+
+    fn retrying(statuses: Vec<u16>, max_retries: u32) -> RetryingClient<Flaky> {
+        let config = FetchConfig {
+            max_retries,
+            max_backoff: Duration::ZERO,
+            ..Default::default()
+        };
+        RetryingClient::new(
+            Flaky {
+                statuses: Mutex::new(statuses),
+            },
+            config,
+        )
+    }
+
+    #[test]
+    fn test_retry() {
+        let client = retrying(vec![429, 503], 3);
+        assert_eq!(client.get("https://example.com").unwrap().status, 200);
+
+        let client = retrying(vec![429, 429, 429], 2);
+        assert_eq!(client.get("https://example.com").unwrap().status, 429);
+
+        // Other errors are returned straight away
+        let client = retrying(vec![404], 3);
+        assert_eq!(client.get("https://example.com").unwrap().status, 404);
+    }
+
+    #[test]
+    fn test_pause_is_clamped() {
+        let client = retrying(Vec::new(), 3);
+        let before = Instant::now();
+        client.pause(Duration::MAX);
+
+        let until = client.paused_until.lock().unwrap().unwrap();
+        assert!(until <= before + MAX_PAUSE + Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_backoff() {
+        let config = FetchConfig::default();
+        assert_eq!(config.backoff(0, None), Duration::from_secs(1));
+        assert_eq!(config.backoff(3, None), Duration::from_secs(8));
+        assert_eq!(config.backoff(10, None), Duration::from_secs(60));
+        assert_eq!(
+            config.backoff(0, Some(Duration::from_secs(5))),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn test_user_agent() {
+        assert!(!FetchConfig::default().user_agent().contains("mailto"));
+        assert!(
+            FetchConfig::polite("someone@example.com")
+                .user_agent()
+                .ends_with(" (mailto:someone@example.com)")
+        );
+    }
+}
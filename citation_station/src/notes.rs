@@ -0,0 +1,364 @@
+//! Reading notes kept as Markdown files with YAML front matter, one note
+//! per work, as in Obsidian vaults and other Zettelkasten setups.
+//!
+//! The front matter fields read and written are `id`, `title`, `authors`,
+//! `year`, `date`, `journal`, `doi`, `url` and `tags`. Anything else in
+//! the front matter, and the note itself, is left to the user.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    api::{
+        author::{AcademicAuthor, Contributor, GenericAuthor, PersonName},
+        citation::Citation,
+        date::{AccessDate, PublishDate},
+        errors::StorageError,
+        media::{
+            book::Book, common::CommonCitationData, journal_article::JournalArticle,
+            web_page::WebPage,
+        },
+        title::Title,
+    },
+    cff::{Yaml, parse_yaml, quote},
+    export::iso_date,
+    import::{ImportIssue, ImportReport, ImportWarning},
+};
+
+/// The front matter of a note and the text after it, or `None` if the
+/// note doesn't start with a front matter block
+fn split_front_matter(note: &str) -> Option<(&str, &str)> {
+    let rest = note
+        .strip_prefix("---\n")
+        .or_else(|| note.strip_prefix("---\r\n"))?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Read a note's front matter as a citation.
+///
+/// Notes naming a `journal` become journal articles, notes with a `url`
+/// and no `doi` become web pages, and the rest become books. Authors are
+/// read as "Last, First" or "First Last". Without an `id`, the DOI, URL
+/// or title is used. Returns `None` if the note has no front matter or no
+/// `title`.
+pub fn citation_from_note(note: &str) -> Option<Citation> {
+    let (front_matter, _) = split_front_matter(note)?;
+    let root = parse_yaml(front_matter).ok()?;
+    let title = root.str("title")?;
+    let doi = root.str("doi").map(str::to_string);
+    let url = root.str("url").map(str::to_string);
+
+    let authors = root.get("authors").or_else(|| root.get("author"));
+    let persons: Vec<PersonName> = match authors {
+        Some(Yaml::List(items)) => items
+            .iter()
+            .filter_map(|item| PersonName::parse(item.as_str()?).ok())
+            .collect(),
+        Some(author) => author
+            .as_str()
+            .and_then(|name| PersonName::parse(name).ok())
+            .into_iter()
+            .collect(),
+        None => Vec::new(),
+    };
+    let published = root
+        .str("date")
+        .and_then(PublishDate::parse_iso)
+        .or_else(|| {
+            root.str("year")
+                .and_then(|year| year.parse().ok())
+                .map(PublishDate::from_year)
+        });
+    let tags = match root.get("tags") {
+        Some(Yaml::List(items)) => items
+            .iter()
+            .filter_map(Yaml::as_str)
+            .map(|tag| tag.trim_start_matches('#').to_string())
+            .collect(),
+        Some(tag) => tag.as_str().map(str::to_string).into_iter().collect(),
+        None => Vec::new(),
+    };
+    let id = root
+        .str("id")
+        .map(str::to_string)
+        .or_else(|| doi.clone())
+        .or_else(|| url.clone())
+        .unwrap_or_else(|| title.to_string());
+
+    let common_data = CommonCitationData {
+        published,
+        tags,
+        ..CommonCitationData::new(id)
+    };
+    let title = Title::parse(title);
+    Some(match (root.str("journal"), url) {
+        (Some(journal), _) => Citation::JournalArticle(JournalArticle {
+            common_data,
+            author: AcademicAuthor::Persons { persons },
+            title,
+            journal: journal.to_string(),
+            volume: None,
+            number: None,
+            locator: None,
+            doi,
+        }),
+        (None, Some(url)) if doi.is_none() => Citation::WebPage(WebPage {
+            common_data,
+            author: (!persons.is_empty()).then_some(GenericAuthor::Persons { persons }),
+            title,
+            site_name: None,
+            url,
+            accessed: AccessDate::today(),
+        }),
+        (None, _) => Citation::Book(Book {
+            common_data,
+            author: GenericAuthor::Persons { persons },
+            with_authors: Vec::new(),
+            title,
+            chapter: None,
+            version: None,
+            doi,
+            pages: None,
+        }),
+    })
+}
+
+/// Read every Markdown note under `folder` and its subfolders, in path
+/// order. Notes without front matter or a title are reported as skipped
+/// rather than failing the import.
+pub fn read_notes(folder: impl AsRef<Path>) -> Result<ImportReport, StorageError> {
+    let mut paths = Vec::new();
+    collect_notes(folder.as_ref(), &mut paths)?;
+    paths.sort();
+
+    let mut report = ImportReport::default();
+    for path in paths {
+        match citation_from_note(&fs::read_to_string(&path)?) {
+            Some(citation) => report.citations.push(citation),
+            None => report.warnings.push(ImportWarning {
+                line: None,
+                entry: Some(path.display().to_string()),
+                issue: ImportIssue::SkippedEntry {
+                    reason: "no front matter with a title".to_string(),
+                },
+            }),
+        }
+    }
+    Ok(report)
+}
+
+fn collect_notes(folder: &Path, paths: &mut Vec<PathBuf>) -> Result<(), StorageError> {
+    for entry in fs::read_dir(folder)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if hidden {
+            // e.g. the .obsidian settings folder
+            continue;
+        }
+        if path.is_dir() {
+            collect_notes(&path, paths)?;
+        } else if path.extension().is_some_and(|extension| extension == "md") {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The front matter block for `citation`, including its `---` lines
+pub fn citation_front_matter(citation: &Citation) -> String {
+    let mut lines = vec![
+        "---".to_string(),
+        format!("id: {}", quote(&citation.id())),
+        format!("title: {}", quote(&citation.title())),
+    ];
+    let authors: Vec<String> = citation
+        .contributors()
+        .into_iter()
+        .map(|contributor| match contributor {
+            Contributor::Person(person) => match person.given_names() {
+                Some(given) => format!("{}, {}", person.surname(), given),
+                None => person.surname().to_string(),
+            },
+            Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => name,
+            Contributor::Anonymous => "Anonymous".to_string(),
+        })
+        .collect();
+    if !authors.is_empty() {
+        lines.push("authors:".to_string());
+        lines.extend(
+            authors
+                .iter()
+                .map(|author| format!("  - {}", quote(author))),
+        );
+    }
+    if let Some(published) = &citation.common_data().published {
+        lines.push(format!("year: {}", published.year()));
+        if !matches!(published, PublishDate::Year { .. }) {
+            lines.push(format!("date: {}", iso_date(published)));
+        }
+    }
+    if let Citation::JournalArticle(article) = citation {
+        lines.push(format!("journal: {}", quote(&article.journal)));
+    }
+    if let Some(doi) = citation.doi() {
+        lines.push(format!("doi: {}", quote(&doi)));
+    }
+    if let Some(url) = citation.url() {
+        lines.push(format!("url: {}", quote(&url)));
+    }
+    if !citation.tags().is_empty() {
+        lines.push("tags:".to_string());
+        lines.extend(
+            citation
+                .tags()
+                .iter()
+                .map(|tag| format!("  - {}", quote(tag))),
+        );
+    }
+    lines.push("---".to_string());
+    lines.join("\n") + "\n"
+}
+
+/// A new note for `citation`: its front matter and a heading
+pub fn citation_to_note(citation: &Citation) -> String {
+    format!(
+        "{}\n# {}\n",
+        citation_front_matter(citation),
+        citation.title()
+    )
+}
+
+/// The file name of the note for `citation`: its ID with characters that
+/// aren't safe in file names replaced, e.g. "10.1000-xyz.md"
+pub fn note_file_name(citation: &Citation) -> String {
+    let stem: String = citation
+        .id()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => '-',
+            c => c,
+        })
+        .collect();
+    format!("{}.md", stem.trim_start_matches('.'))
+}
+
+/// Write one note per citation to `folder`, returning the paths written.
+///
+/// A note that already exists keeps everything after its front matter,
+/// so reading notes aren't lost when the bibliography is exported again.
+pub fn write_notes(
+    citations: &[Citation],
+    folder: impl AsRef<Path>,
+) -> Result<Vec<PathBuf>, StorageError> {
+    let folder = folder.as_ref();
+    fs::create_dir_all(folder)?;
+    let mut written = Vec::new();
+    for citation in citations {
+        let path = folder.join(note_file_name(citation));
+        let existing = fs::read_to_string(&path).ok();
+        let note = match existing.as_deref().and_then(split_front_matter) {
+            Some((_, body)) => citation_front_matter(citation) + body,
+            None => citation_to_note(citation),
+        };
+        fs::write(&path, note)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{
+        api::{citation::Citation, date::PublishDate},
+        notes::{citation_from_note, citation_to_note, read_notes, write_notes},
+    };
+
+    const NOTE: &str = r##"---
+title: "Attention Is All You Need"
+authors:
+  - Vaswani, Ashish
+  - Noam Shazeer
+year: 2017
+doi: 10.48550/arXiv.1706.03762
+tags: [transformers, "#to-read"]
+aliases: [Transformer paper]
+---
+
+# Attention Is All You Need
+
+Self-attention replaces recurrence.
+"##;
+
+    #[test]
+    fn test_citation_from_note() {
+        let Some(Citation::Book(book)) = citation_from_note(NOTE) else {
+            panic!("expected a book");
+        };
+        assert_eq!(book.common_data.id, "10.48550/arXiv.1706.03762");
+        assert_eq!(book.title.to_string(), "Attention Is All You Need");
+        let persons = Citation::Book(book.clone()).persons();
+        assert_eq!(persons.len(), 2);
+        assert_eq!(persons[1].surname(), "Shazeer");
+        assert_eq!(
+            book.common_data.published,
+            Some(PublishDate::from_year(2017))
+        );
+        assert_eq!(book.common_data.tags, ["transformers", "to-read"]);
+
+        let web = "---\ntitle: Why Rust?\nurl: https://example.com/why-rust\n---\n";
+        assert!(matches!(
+            citation_from_note(web),
+            Some(Citation::WebPage(_))
+        ));
+        assert_eq!(citation_from_note("# Just a note\n"), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let citation = citation_from_note(NOTE).unwrap();
+        assert_eq!(
+            citation_from_note(&citation_to_note(&citation)),
+            Some(citation)
+        );
+    }
+
+    #[test]
+    fn test_write_and_read_notes() {
+        let folder = std::env::temp_dir().join(format!(
+            "citation_station_test_notes_{}",
+            std::process::id()
+        ));
+        let citation = citation_from_note(NOTE).unwrap();
+        let paths = write_notes(std::slice::from_ref(&citation), &folder).unwrap();
+        assert_eq!(
+            paths[0].file_name().unwrap(),
+            "10.48550-arXiv.1706.03762.md"
+        );
+
+        // The reading notes survive exporting again
+        fs::write(&paths[0], NOTE).unwrap();
+        write_notes(std::slice::from_ref(&citation), &folder).unwrap();
+        let note = fs::read_to_string(&paths[0]).unwrap();
+        assert!(note.ends_with("Self-attention replaces recurrence.\n"));
+        assert!(note.contains("id: \"10.48550/arXiv.1706.03762\""));
+
+        fs::write(folder.join("todo.md"), "- [ ] read more\n").unwrap();
+        let report = read_notes(&folder).unwrap();
+        assert_eq!(report.citations, [citation]);
+        assert_eq!(report.warnings.len(), 1);
+        fs::remove_dir_all(&folder).unwrap();
+    }
+}
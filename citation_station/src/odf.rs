@@ -0,0 +1,101 @@
+//! Writing reference lists as OpenDocument text for LibreOffice and other
+//! ODF word processors.
+//!
+//! Entries are expected to be formatted with [`Markup::Odf`], so they are
+//! XML-escaped and titles are set in italics with the "Italic" text style
+//! defined here rather than with direct formatting, and can be restyled in
+//! the word processor.
+//!
+//! Format reference: OpenDocument v1.3, Part 3 (Schema)
+//!
+//! [`Markup::Odf`]: crate::api::style::Markup::Odf
+
+/// Paragraph style of reference list entries: a half-inch hanging indent
+pub const ENTRY_STYLE: &str = "Bibliography_20_1";
+
+const NAMESPACES: &str = concat!(
+    r#"xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" "#,
+    r#"xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" "#,
+    r#"xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" "#,
+    r#"xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0""#,
+);
+
+/// Reference list entries as `<text:p>` paragraphs, for pasting into the
+/// `<office:text>` of an existing document that defines the styles
+/// written by [`reference_list_to_odt`]
+pub fn reference_list_to_odf_paragraphs(entries: &[String]) -> String {
+    entries
+        .iter()
+        .map(|entry| {
+            format!(
+                r#"<text:p text:style-name="{}">{}</text:p>"#,
+                ENTRY_STYLE, entry
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reference list entries as a complete flat OpenDocument text (`.fodt`),
+/// a single XML file LibreOffice opens like an `.odt`
+pub fn reference_list_to_odt(entries: &[String]) -> String {
+    let styles = [
+        format!(
+            r#"<style:style style:name="{}" style:display-name="Bibliography 1" style:family="paragraph">"#,
+            ENTRY_STYLE
+        ),
+        r#"<style:paragraph-properties fo:margin-left="0.5in" fo:text-indent="-0.5in"/>"#
+            .to_string(),
+        "</style:style>".to_string(),
+        r#"<style:style style:name="Italic" style:family="text">"#.to_string(),
+        r#"<style:text-properties fo:font-style="italic"/>"#.to_string(),
+        "</style:style>".to_string(),
+        r#"<style:style style:name="Bold" style:family="text">"#.to_string(),
+        r#"<style:text-properties fo:font-weight="bold"/>"#.to_string(),
+        "</style:style>".to_string(),
+    ];
+    [
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+        format!(
+            r#"<office:document {} office:version="1.3" office:mimetype="application/vnd.oasis.opendocument.text">"#,
+            NAMESPACES
+        ),
+        "<office:styles>".to_string(),
+        styles.join("\n"),
+        "</office:styles>".to_string(),
+        "<office:body>".to_string(),
+        "<office:text>".to_string(),
+        reference_list_to_odf_paragraphs(entries),
+        "</office:text>".to_string(),
+        "</office:body>".to_string(),
+        "</office:document>".to_string(),
+    ]
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::odf::{reference_list_to_odf_paragraphs, reference_list_to_odt};
+
+    #[test]
+    fn test_paragraphs() {
+        let entries = [
+            "Smith, J. (2020). <text:span text:style-name=\"Italic\">Rust</text:span>.".to_string(),
+        ];
+
+        assert_eq!(
+            reference_list_to_odf_paragraphs(&entries),
+            "<text:p text:style-name=\"Bibliography_20_1\">Smith, J. (2020). \
+             <text:span text:style-name=\"Italic\">Rust</text:span>.</text:p>"
+        );
+    }
+
+    #[test]
+    fn test_document() {
+        let document = reference_list_to_odt(&["Entry".to_string()]);
+
+        assert!(document.contains("office:mimetype=\"application/vnd.oasis.opendocument.text\""));
+        assert!(document.contains("<style:style style:name=\"Italic\" style:family=\"text\">"));
+        assert!(document.contains("<office:text>\n<text:p"));
+    }
+}
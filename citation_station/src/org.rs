@@ -0,0 +1,235 @@
+//! Reading and writing Org-mode headlines with org-bibtex properties.
+//!
+//! Emacs' org-bibtex keeps one reference per headline, with the BibTeX
+//! entry type in `:BTYPE:`, the key in `:CUSTOM_ID:` and every BibTeX
+//! field as a property of the same name:
+//!
+//! ```org
+//! * Attention Is All You Need
+//!   :PROPERTIES:
+//!   :TITLE: Attention Is All You Need
+//!   :BTYPE: article
+//!   :CUSTOM_ID: vaswani2017
+//!   :AUTHOR: Vaswani, Ashish and Shazeer, Noam
+//!   :JOURNAL: Advances in Neural Information Processing Systems
+//!   :YEAR: 2017
+//!   :END:
+//! ```
+//!
+//! Headlines without a `:BTYPE:` are ordinary Org content and are left
+//! alone.
+
+use crate::{
+    api::citation::Citation,
+    bibtex::{ReadEntry, citation_from_entry, entry_for},
+    export::ExportOptions,
+    import::ImportReport,
+};
+
+/// Write a citation as an Org headline with org-bibtex properties. Tags
+/// are written as `:KEYWORDS:`.
+pub fn citation_to_org(citation: &Citation) -> String {
+    let options = ExportOptions {
+        tags_as_keywords: true,
+        ..Default::default()
+    };
+    let entry = entry_for(citation, &options);
+    // org-bibtex puts the title first, then the type and key
+    let mut lines = vec![
+        format!("* {}", citation.title()),
+        "  :PROPERTIES:".to_string(),
+        format!("  :TITLE: {}", citation.title()),
+        format!("  :BTYPE: {}", entry.kind),
+        format!("  :CUSTOM_ID: {}", citation.id()),
+    ];
+    for (name, value) in entry.fields.iter().filter(|(name, _)| *name != "title") {
+        lines.push(format!("  :{}: {}", name.to_uppercase(), value));
+    }
+    lines.push("  :END:".to_string());
+    lines.join("\n")
+}
+
+/// Write citations as an Org file, one headline per citation
+pub fn citations_to_org(citations: &[Citation]) -> String {
+    citations
+        .iter()
+        .map(|citation| citation_to_org(citation) + "\n")
+        .collect()
+}
+
+/// One headline's properties, with their line numbers
+struct Headline<'a> {
+    text: &'a str,
+    line: usize,
+    properties: Vec<(String, &'a str, usize)>,
+}
+
+impl Headline<'_> {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(property, ..)| property == name)
+            .map(|(_, value, _)| *value)
+            .filter(|value| !value.is_empty())
+    }
+}
+
+fn headlines(text: &str) -> Vec<Headline<'_>> {
+    let mut headlines: Vec<Headline> = Vec::new();
+    let mut in_drawer = false;
+    for (index, raw) in text.lines().enumerate() {
+        let line = raw.trim();
+        let stars = raw.len() - raw.trim_start_matches('*').len();
+        if stars > 0 && raw[stars..].starts_with(' ') {
+            headlines.push(Headline {
+                text: raw[stars..].trim(),
+                line: index + 1,
+                properties: Vec::new(),
+            });
+            in_drawer = false;
+        } else if line.eq_ignore_ascii_case(":PROPERTIES:") {
+            in_drawer = true;
+        } else if line.eq_ignore_ascii_case(":END:") {
+            in_drawer = false;
+        } else if in_drawer
+            && let Some(headline) = headlines.last_mut()
+            && let Some((name, value)) =
+                line.strip_prefix(':').and_then(|rest| rest.split_once(':'))
+        {
+            headline
+                .properties
+                .push((name.to_uppercase(), value.trim(), index + 1));
+        }
+    }
+    headlines
+}
+
+/// Read the headlines of an Org file that have org-bibtex properties.
+///
+/// Properties are read as the BibTeX fields of the same name, see
+/// [`read_bibtex`]. A headline without a `:TITLE:` is titled with its
+/// text. Org's own `:ID:` property is ignored.
+///
+/// [`read_bibtex`]: crate::bibtex::read_bibtex
+pub fn read_org(text: &str) -> ImportReport {
+    let mut report = ImportReport::default();
+    for headline in headlines(text) {
+        let Some(kind) = headline.get("BTYPE") else {
+            continue;
+        };
+        let mut fields: Vec<(String, String, usize)> = headline
+            .properties
+            .iter()
+            .filter(|(name, ..)| !matches!(name.as_str(), "BTYPE" | "CUSTOM_ID" | "ID"))
+            .map(|(name, value, line)| (name.to_lowercase(), value.to_string(), *line))
+            .collect();
+        if headline.get("TITLE").is_none() {
+            fields.push((
+                "title".to_string(),
+                headline.text.to_string(),
+                headline.line,
+            ));
+        }
+        let entry = ReadEntry {
+            kind: Some(kind.to_lowercase()),
+            key: headline.get("CUSTOM_ID").map(str::to_string),
+            line: headline.line,
+            fields,
+        };
+        citation_from_entry(&entry, &mut report);
+    }
+    report
+}
+
+/// The citations in an Org file, see [`read_org`]
+pub fn citations_from_org(text: &str) -> Vec<Citation> {
+    read_org(text).citations
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{citation::Citation, date::PublishDate},
+        import::ImportIssue,
+        org::{citation_to_org, read_org},
+    };
+
+    const ORG: &str = "#+TITLE: Reading list
+
+* Attention Is All You Need
+  :PROPERTIES:
+  :TITLE:    Attention Is All You Need
+  :BTYPE:    article
+  :CUSTOM_ID: vaswani2017
+  :AUTHOR:   Vaswani, Ashish and Noam Shazeer
+  :JOURNAL:  Advances in Neural Information Processing Systems
+  :VOLUME:   30
+  :PAGES:    5998--6008
+  :YEAR:     2017
+  :MONTH:    dec
+  :KEYWORDS: transformers, attention
+  :NOTE:     Read twice
+  :END:
+** My notes
+   Self-attention replaces recurrence.
+* Rust Blog
+  :PROPERTIES:
+  :BTYPE:    online
+  :CUSTOM_ID: rust-blog
+  :AUTHOR:   {The Rust Team}
+  :URL:      https://blog.rust-lang.org
+  :URLDATE:  2024-05-01
+  :END:
+";
+
+    #[test]
+    fn test_read_org() {
+        let report = read_org(ORG);
+        let [Citation::JournalArticle(article), Citation::WebPage(page)] =
+            report.citations.as_slice()
+        else {
+            panic!("expected an article and a web page");
+        };
+        assert_eq!(article.common_data.id, "vaswani2017");
+        assert_eq!(
+            article.common_data.published,
+            Some(PublishDate::from_year_month(2017, Month::December))
+        );
+        assert_eq!(article.common_data.tags, ["transformers", "attention"]);
+        assert_eq!(
+            article.locator.as_ref().unwrap().as_ieee_string(),
+            "pp. 5998\u{2013}6008"
+        );
+        assert_eq!(page.title.to_string(), "Rust Blog");
+        assert_eq!(page.accessed.date().to_string(), "2024-05-01");
+
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].line, Some(15));
+        assert_eq!(
+            report.warnings[0].issue,
+            ImportIssue::UnknownField {
+                field: "note".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for citation in read_org(ORG).citations {
+            let org = citation_to_org(&citation);
+            assert_eq!(read_org(&org).citations, [citation]);
+        }
+    }
+
+    #[test]
+    fn test_citation_to_org() {
+        let citation = read_org(ORG).citations.remove(0);
+        let org = citation_to_org(&citation);
+        assert!(org.starts_with(
+            "* Attention Is All You Need\n  :PROPERTIES:\n  :TITLE: Attention Is All You Need\n  :BTYPE: article\n  :CUSTOM_ID: vaswani2017\n  :AUTHOR: Vaswani, Ashish and Shazeer, Noam\n"
+        ));
+        assert!(org.ends_with("  :KEYWORDS: transformers, attention\n  :END:"));
+    }
+}
@@ -0,0 +1,488 @@
+//! QR codes for printed bibliographies, so a reader of a poster or handout
+//! can open a cited work's DOI or URL with a phone.
+//!
+//! Text is encoded in byte mode at error correction level M, in the
+//! smallest of versions 1 to 10 that holds it (up to 213 bytes, plenty for
+//! a DOI link).
+//!
+//! Format reference: ISO/IEC 18004:2015
+
+/// Error correction codewords per block, by version
+const EC_CODEWORDS_PER_BLOCK: [usize; 10] = [10, 16, 26, 18, 24, 16, 18, 22, 22, 26];
+
+/// Number of short blocks, their data codewords and number of long blocks
+/// (one codeword longer), by version
+const BLOCKS: [(usize, usize, usize); 10] = [
+    (1, 16, 0),
+    (1, 28, 0),
+    (1, 44, 0),
+    (2, 32, 0),
+    (2, 43, 0),
+    (4, 27, 0),
+    (4, 31, 0),
+    (2, 38, 2),
+    (3, 36, 2),
+    (4, 43, 1),
+];
+
+/// Centers of the alignment patterns, by version
+const ALIGNMENT_POSITIONS: [&[usize]; 10] = [
+    &[],
+    &[6, 18],
+    &[6, 22],
+    &[6, 26],
+    &[6, 30],
+    &[6, 34],
+    &[6, 22, 38],
+    &[6, 24, 42],
+    &[6, 26, 46],
+    &[6, 28, 50],
+];
+
+/// A QR code symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCode {
+    version: usize,
+    size: usize,
+    /// Dark modules, row by row
+    modules: Vec<bool>,
+    /// Modules of the finder, timing, alignment and format patterns
+    function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encode `text`, or `None` if it is longer than 213 bytes
+    pub fn encode(text: &str) -> Option<Self> {
+        let data = text.as_bytes();
+        let version = (1..=10).find(|&version| capacity(version) >= data.len())?;
+        let size = 17 + 4 * version;
+        let mut code = Self {
+            version,
+            size,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        };
+        code.draw_function_patterns();
+        code.draw_codewords(&code.codewords(data));
+
+        let mask = (0..8)
+            .min_by_key(|&mask| {
+                code.apply_mask(mask);
+                code.draw_format_bits(mask);
+                let penalty = code.penalty();
+                code.apply_mask(mask);
+                penalty
+            })
+            .unwrap_or(0);
+        code.apply_mask(mask);
+        code.draw_format_bits(mask);
+        Some(code)
+    }
+
+    /// Modules per side, without the quiet zone
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module in column `x` of row `y` is dark
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    /// The symbol as an SVG image, one unit per module, with the four
+    /// module quiet zone scanners need
+    pub fn to_svg(&self) -> String {
+        let side = self.size + 8;
+        let mut path = String::new();
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if self.is_dark(x, y) {
+                    path.push_str(&format!("M{},{}h1v1h-1z", x + 4, y + 4));
+                }
+            }
+        }
+        format!(
+            "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 {0} {0}' \
+             shape-rendering='crispEdges'><rect width='{0}' height='{0}' fill='#fff'/>\
+             <path d='{1}' fill='#000'/></svg>",
+            side, path
+        )
+    }
+
+    /// The SVG image as a `data:` URI for an `<img>` element's `src`
+    pub fn to_data_uri(&self) -> String {
+        let mut uri = "data:image/svg+xml,".to_string();
+        for c in self.to_svg().chars() {
+            match c {
+                '<' => uri.push_str("%3C"),
+                '>' => uri.push_str("%3E"),
+                '#' => uri.push_str("%23"),
+                '"' => uri.push_str("%22"),
+                '%' => uri.push_str("%25"),
+                ' ' => uri.push_str("%20"),
+                c => uri.push(c),
+            }
+        }
+        uri
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.function[y * self.size + x] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        for (x, y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            for dy in -4..=4_isize {
+                for dx in -4..=4_isize {
+                    let (xx, yy) = (x as isize + dx, y as isize + dy);
+                    if (0..size as isize).contains(&xx) && (0..size as isize).contains(&yy) {
+                        let distance = dx.abs().max(dy.abs());
+                        self.set_function(xx as usize, yy as usize, distance != 2 && distance != 4);
+                    }
+                }
+            }
+        }
+
+        let positions = ALIGNMENT_POSITIONS[self.version - 1];
+        let last = positions.len().saturating_sub(1);
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                // The corners taken by finder patterns
+                if [(0, 0), (0, last), (last, 0)].contains(&(i, j)) {
+                    continue;
+                }
+                for dy in -2..=2_isize {
+                    for dx in -2..=2_isize {
+                        let dark = dx.abs().max(dy.abs()) != 1;
+                        self.set_function(
+                            (x as isize + dx) as usize,
+                            (y as isize + dy) as usize,
+                            dark,
+                        );
+                    }
+                }
+            }
+        }
+
+        // Reserve the format areas until a mask is chosen
+        self.draw_format_bits(0);
+        if self.version >= 7 {
+            let bits = version_bits(self.version);
+            for i in 0..18 {
+                let dark = (bits >> i) & 1 == 1;
+                let (a, b) = (size - 11 + i % 3, i / 3);
+                self.set_function(a, b, dark);
+                self.set_function(b, a, dark);
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let size = self.size;
+        let bits = format_bits(mask);
+        let bit = |i: usize| (bits >> i) & 1 == 1;
+
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        // Always dark
+        self.set_function(8, size - 8, true);
+    }
+
+    /// The data and error correction codewords, interleaved
+    fn codewords(&self, data: &[u8]) -> Vec<u8> {
+        let (short_blocks, short_length, long_blocks) = BLOCKS[self.version - 1];
+        let capacity = data_codewords(self.version);
+
+        let mut bits: Vec<bool> = Vec::new();
+        let mut push = |value: usize, length: usize| {
+            bits.extend((0..length).rev().map(|i| (value >> i) & 1 == 1));
+        };
+        push(0b0100, 4);
+        push(data.len(), count_bits(self.version));
+        for &byte in data {
+            push(byte as usize, 8);
+        }
+        let terminator = (capacity * 8 - bits.len()).min(4);
+        bits.extend(std::iter::repeat_n(false, terminator));
+        bits.extend(std::iter::repeat_n(false, (8 - bits.len() % 8) % 8));
+
+        let mut bytes: Vec<u8> = bits
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0, |acc, &bit| (acc << 1) | bit as u8))
+            .collect();
+        for pad in [0xEC, 0x11].into_iter().cycle() {
+            if bytes.len() >= capacity {
+                break;
+            }
+            bytes.push(pad);
+        }
+
+        let ec_length = EC_CODEWORDS_PER_BLOCK[self.version - 1];
+        let divisor = reed_solomon_divisor(ec_length);
+        let mut blocks: Vec<(&[u8], Vec<u8>)> = Vec::new();
+        let mut rest = bytes.as_slice();
+        for block in 0..short_blocks + long_blocks {
+            let length = short_length + usize::from(block >= short_blocks);
+            let (data, remaining) = rest.split_at(length);
+            blocks.push((data, reed_solomon_remainder(data, &divisor)));
+            rest = remaining;
+        }
+
+        let mut codewords = Vec::new();
+        for i in 0..=short_length {
+            codewords.extend(blocks.iter().filter_map(|(data, _)| data.get(i)));
+        }
+        for i in 0..ec_length {
+            codewords.extend(blocks.iter().map(|(_, ec)| ec[i]));
+        }
+        codewords
+    }
+
+    /// Place codewords in the zigzag order, two columns at a time from
+    /// the right, skipping the vertical timing pattern
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let mut bit = 0;
+        let mut right = size - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vertical in 0..size {
+                let y = if upward {
+                    size - 1 - vertical
+                } else {
+                    vertical
+                };
+                for x in [right, right - 1] {
+                    if !self.function[y * size + x] && bit < codewords.len() * 8 {
+                        self.modules[y * size + x] = (codewords[bit / 8] >> (7 - bit % 8)) & 1 == 1;
+                        bit += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// Flip the data modules selected by `mask`; applying it twice undoes it
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let flip = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if flip && !self.function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    /// How hard the symbol is to scan, by the four rules of the standard;
+    /// the mask with the lowest penalty is used
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let dark = |x: usize, y: usize| self.modules[y * size + x];
+        let mut penalty = 0;
+
+        let rows = (0..size).map(|y| (0..size).map(|x| dark(x, y)).collect::<Vec<_>>());
+        let columns = (0..size).map(|x| (0..size).map(|y| dark(x, y)).collect::<Vec<_>>());
+        for line in rows.chain(columns) {
+            let mut run = 1;
+            for i in 1..=line.len() {
+                if i < line.len() && line[i] == line[i - 1] {
+                    run += 1;
+                    continue;
+                }
+                if run >= 5 {
+                    penalty += run - 2;
+                }
+                run = 1;
+            }
+            const FINDER_LIKE: [[bool; 11]; 2] = [
+                [
+                    true, false, true, true, true, false, true, false, false, false, false,
+                ],
+                [
+                    false, false, false, false, true, false, true, true, true, false, true,
+                ],
+            ];
+            penalty += line
+                .windows(11)
+                .filter(|window| FINDER_LIKE.iter().any(|pattern| window == pattern))
+                .count()
+                * 40;
+        }
+
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = dark(x, y);
+                if dark(x + 1, y) == color && dark(x, y + 1) == color && dark(x + 1, y + 1) == color
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        let dark_count = self.modules.iter().filter(|&&dark| dark).count();
+        let percent = dark_count * 100 / self.modules.len();
+        penalty + percent.abs_diff(50) / 5 * 10
+    }
+}
+
+fn data_codewords(version: usize) -> usize {
+    let (short_blocks, short_length, long_blocks) = BLOCKS[version - 1];
+    short_blocks * short_length + long_blocks * (short_length + 1)
+}
+
+/// Length of the byte count field
+fn count_bits(version: usize) -> usize {
+    if version < 10 { 8 } else { 16 }
+}
+
+/// Bytes that fit in `version`, after the mode and count fields
+fn capacity(version: usize) -> usize {
+    (data_codewords(version) * 8 - 4 - count_bits(version)) / 8
+}
+
+/// The 15 format bits for level M and `mask`, with their BCH code
+fn format_bits(mask: u32) -> u32 {
+    // Level M is 00
+    let data = mask;
+    let mut remainder = data;
+    for _ in 0..10 {
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+    }
+    ((data << 10) | remainder) ^ 0x5412
+}
+
+/// The 18 version bits, with their BCH code
+fn version_bits(version: usize) -> u32 {
+    let mut remainder = version as u32;
+    for _ in 0..12 {
+        remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+    }
+    ((version as u32) << 12) | remainder
+}
+
+/// Multiply in GF(256) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut product: u8 = 0;
+    for i in (0..8).rev() {
+        product = (product << 1) ^ ((product >> 7) * 0x1D);
+        product ^= ((y >> i) & 1) * x;
+    }
+    product
+}
+
+/// The generator polynomial of degree `degree`, without its leading term
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut divisor = vec![0; degree];
+    divisor[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            divisor[j] = gf_multiply(divisor[j], root);
+            if j + 1 < degree {
+                divisor[j] ^= divisor[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    divisor
+}
+
+/// The error correction codewords of `data`
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut remainder = vec![0; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ remainder.remove(0);
+        remainder.push(0);
+        for (term, &coefficient) in remainder.iter_mut().zip(divisor) {
+            *term ^= gf_multiply(coefficient, factor);
+        }
+    }
+    remainder
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::qr::{
+        QrCode, capacity, format_bits, reed_solomon_divisor, reed_solomon_remainder, version_bits,
+    };
+
+    #[test]
+    fn test_reed_solomon() {
+        // "HELLO WORLD" at 1-M, from the worked example in ISO/IEC 18004
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        assert_eq!(
+            reed_solomon_remainder(&data, &reed_solomon_divisor(10)),
+            [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+        );
+    }
+
+    #[test]
+    fn test_format_and_version_bits() {
+        assert_eq!(format_bits(0), 0b101010000010010);
+        assert_eq!(format_bits(5), 0b100000011001110);
+        assert_eq!(version_bits(7), 0b000111110010010100);
+        assert_eq!(capacity(1), 14);
+        assert_eq!(capacity(10), 213);
+    }
+
+    #[test]
+    fn test_encode() {
+        let code = QrCode::encode("https://doi.org/10.1000/xyz123").unwrap();
+        assert_eq!(code.size(), 29);
+        // Finder pattern corners and the dark module
+        assert!(code.is_dark(0, 0) && code.is_dark(28, 0) && code.is_dark(0, 28));
+        assert!(!code.is_dark(7, 7));
+        assert!(code.is_dark(8, 21));
+
+        assert_eq!(QrCode::encode(&"x".repeat(100)).unwrap().size(), 17 + 4 * 6);
+        assert!(QrCode::encode(&"x".repeat(214)).is_none());
+    }
+
+    #[test]
+    fn test_data_uri() {
+        let uri = QrCode::encode("https://example.com").unwrap().to_data_uri();
+        assert!(uri.starts_with("data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'"));
+        assert!(!uri.contains(['<', '>', '#', ' ']));
+    }
+}
@@ -0,0 +1,220 @@
+//! Incremental rendering of a reference list for live previews.
+//!
+//! A [`RenderSession`] remembers what it rendered last time, so after an
+//! edit only the entries that changed are formatted and reported. An
+//! entry is re-rendered when its citation changed or when it moved, since
+//! moving changes its number in numeric styles.
+
+use std::{
+    collections::{HashMap, hash_map::DefaultHasher},
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    api::{
+        citation::Citation,
+        citation_id::CitationId,
+        style::{CitationStyle, FormatOptions},
+    },
+    bibliography::{Bibliography, labelled_entries},
+    collation::SortOptions,
+};
+
+/// An entry that is new or changed since the last render.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedEntry {
+    /// Position in the reference list, from 0
+    pub position: usize,
+    pub id: CitationId,
+    /// The formatted entry, with its label in numeric styles
+    pub text: String,
+}
+
+/// What changed in the reference list since the last render.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderUpdate {
+    /// New, edited and moved entries, in list order
+    pub changed: Vec<RenderedEntry>,
+    /// Entries no longer in the list
+    pub removed: Vec<CitationId>,
+    /// Number of entries in the list
+    pub len: usize,
+}
+
+impl RenderUpdate {
+    /// Whether the list is exactly as last rendered
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Renders a bibliography's reference list again and again, formatting
+/// only what changed in between.
+#[derive(Debug, Clone)]
+pub struct RenderSession {
+    style: CitationStyle,
+    options: FormatOptions,
+    sort: SortOptions,
+    /// Each rendered entry's position and a hash of its citation
+    rendered: HashMap<CitationId, (usize, u64)>,
+}
+
+impl RenderSession {
+    pub fn new(style: CitationStyle) -> Self {
+        Self::with_options(
+            style,
+            FormatOptions::for_style(style),
+            SortOptions::default(),
+        )
+    }
+
+    pub fn with_options(style: CitationStyle, options: FormatOptions, sort: SortOptions) -> Self {
+        Self {
+            style,
+            options,
+            sort,
+            rendered: HashMap::new(),
+        }
+    }
+
+    /// The entries of `bibliography` that changed since the last call.
+    /// The first call reports every entry.
+    pub fn render(&mut self, bibliography: &Bibliography) -> RenderUpdate {
+        let entries = labelled_entries(
+            bibliography.citations().iter().collect(),
+            self.style,
+            &self.sort,
+        );
+        let mut rendered = HashMap::with_capacity(entries.len());
+        let mut update = RenderUpdate {
+            len: entries.len(),
+            ..Default::default()
+        };
+        for (position, (label, citation)) in entries.into_iter().enumerate() {
+            let id = citation.citation_id();
+            let hash = hash(citation);
+            if self.rendered.get(&id) != Some(&(position, hash)) {
+                let text = citation.format_with(self.style, &self.options);
+                update.changed.push(RenderedEntry {
+                    position,
+                    id: id.clone(),
+                    text: match label {
+                        Some(label) => format!("{} {}", label, text),
+                        None => text,
+                    },
+                });
+            }
+            rendered.insert(id, (position, hash));
+        }
+        update.removed = self
+            .rendered
+            .keys()
+            .filter(|id| !rendered.contains_key(*id))
+            .cloned()
+            .collect();
+        update.removed.sort();
+        self.rendered = rendered;
+        update
+    }
+
+    /// Forget what was rendered, so the next render reports every entry
+    pub fn reset(&mut self) {
+        self.rendered.clear();
+    }
+}
+
+/// A hash of every field, since overrides and access dates show up in the
+/// formatted entry too
+fn hash(citation: &Citation) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(citation)
+        .expect("citations serialize to JSON")
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::GenericAuthor,
+            citation::Citation,
+            citation_id::CitationId,
+            date::PublishDate,
+            media::{book::Book, common::CommonCitationData},
+            style::CitationStyle,
+        },
+        bibliography::Bibliography,
+        render_session::{RenderSession, RenderUpdate},
+    };
+
+    fn book(id: &str, name: &str) -> Citation {
+        Citation::Book(Book {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year(2020)),
+                ..CommonCitationData::new(id)
+            },
+            author: GenericAuthor::Organization {
+                name: name.to_string(),
+                abbreviation: None,
+            },
+            with_authors: Vec::new(),
+            title: format!("{} Handbook", name).into(),
+            doi: None,
+            pages: None,
+            chapter: None,
+            version: None,
+        })
+    }
+
+    fn positions(update: &RenderUpdate) -> Vec<(usize, &str)> {
+        update
+            .changed
+            .iter()
+            .map(|entry| (entry.position, entry.id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_render_session() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(book("b", "Beta")).unwrap();
+        bib.add_citation(book("c", "Gamma")).unwrap();
+        let mut session = RenderSession::new(CitationStyle::Apa);
+
+        assert_eq!(positions(&session.render(&bib)), [(0, "b"), (1, "c")]);
+        assert!(session.render(&bib).is_empty());
+
+        // Editing one entry re-renders only that entry
+        bib.update_citation("c", |citation| {
+            if let Citation::Book(book) = citation {
+                book.title = "Gamma Guide".into();
+            }
+        })
+        .unwrap();
+        let update = session.render(&bib);
+        assert_eq!(positions(&update), [(1, "c")]);
+        assert!(update.changed[0].text.contains("Gamma Guide"));
+
+        // An entry sorted in front moves the others down
+        bib.add_citation(book("a", "Alpha")).unwrap();
+        bib.remove_citation("c");
+        let update = session.render(&bib);
+        assert_eq!(positions(&update), [(0, "a"), (1, "b")]);
+        assert_eq!(update.removed, [CitationId::from("c")]);
+        assert_eq!(update.len, 2);
+    }
+
+    #[test]
+    fn test_numeric_labels() {
+        let mut bib = Bibliography::new();
+        bib.add_citation(book("a", "Alpha")).unwrap();
+        let mut session = RenderSession::new(CitationStyle::Ieee);
+        session.render(&bib);
+
+        bib.add_citation(book("b", "Beta")).unwrap();
+        let update = session.render(&bib);
+        assert_eq!(positions(&update), [(1, "b")]);
+        assert!(update.changed[0].text.starts_with("[2] Beta"));
+    }
+}
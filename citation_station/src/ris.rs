@@ -0,0 +1,593 @@
+//! Reading and writing citations in the RIS format read by EndNote, Zotero, Mendeley
+//! and most reference managers.
+//!
+//! Format reference: https://en.wikipedia.org/wiki/RIS_(file_format)
+
+use crate::{
+    api::{
+        author::Contributor,
+        citation::Citation,
+        media::{
+            online_manual::OnlineManualAvailability, online_video::OnlineVideo,
+            version::GenericMediaVersion,
+        },
+        page_range::{ArticleLocator, PageRange},
+    },
+    bibtex::{ReadEntry, citation_from_entry},
+    export::{DateFields, ExportOptions, iso_date},
+    import::{ImportIssue, ImportReport, ImportWarning},
+};
+
+/// Write a citation as an RIS record with the default [`ExportOptions`].
+/// The citation ID is written as the record's `ID`.
+pub fn citation_to_ris(citation: &Citation) -> String {
+    citation_to_ris_with(citation, &ExportOptions::default())
+}
+
+/// Like [`citation_to_ris`], mapping fields as `options` ask
+pub fn citation_to_ris_with(citation: &Citation, options: &ExportOptions) -> String {
+    let mut tags: Vec<(&str, String)> = vec![("TY", record_type(citation).to_string())];
+    let mut tag = |name: &'static str, value: Option<String>| {
+        if let Some(value) = value
+            && !value.trim().is_empty()
+        {
+            tags.push((name, value));
+        }
+    };
+
+    tag("ID", Some(citation.id()));
+    for contributor in citation.contributors() {
+        tag(
+            "AU",
+            match contributor {
+                Contributor::Person(person) => Some(match person.given_names() {
+                    Some(given) => format!("{}, {}", person.surname(), given),
+                    None => person.surname().to_string(),
+                }),
+                Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => {
+                    Some(name)
+                }
+                Contributor::Anonymous => None,
+            },
+        );
+    }
+    tag("TI", Some(citation.title()));
+
+    match citation {
+        Citation::Book(book) => {
+            match &book.version {
+                Some(GenericMediaVersion::Edition { number })
+                | Some(GenericMediaVersion::DigitalEdition { number }) => {
+                    tag("ET", Some(number.to_string()))
+                }
+                Some(GenericMediaVersion::Volume { number }) => tag("VL", Some(number.to_string())),
+                Some(version) => tag("ET", Some(version.as_apa_string())),
+                None => {}
+            }
+            if let Some(pages) = &book.pages {
+                page_tags(&mut tag, pages);
+            }
+            tag("PB", book.common_data.extended.publisher.clone());
+            tag(
+                "CY",
+                book.common_data
+                    .extended
+                    .place
+                    .as_ref()
+                    .map(ToString::to_string),
+            );
+        }
+        Citation::JournalArticle(article) => {
+            tag("T2", Some(article.journal.clone()));
+            tag("VL", article.volume.as_ref().map(ToString::to_string));
+            tag("IS", article.number.as_ref().map(ToString::to_string));
+            match &article.locator {
+                Some(ArticleLocator::Pages(pages)) => page_tags(&mut tag, pages),
+                Some(ArticleLocator::ArticleNumber(number)) => tag("C7", Some(number.clone())),
+                None => {}
+            }
+        }
+        Citation::ConferencePresentation(presentation) => {
+            tag(
+                "T2",
+                Some(presentation.series.full_name(&presentation.conference_name)),
+            );
+            tag(
+                "CY",
+                presentation
+                    .location
+                    .as_ref()
+                    .map(|location| location.city.clone()),
+            );
+        }
+        Citation::ConferencePaperOnline(paper) => {
+            tag("T2", Some(paper.series.full_name(&paper.conference_name)));
+            tag("VL", paper.volume.as_ref().map(ToString::to_string));
+            tag("IS", paper.number.as_ref().map(ToString::to_string));
+            tag("CY", paper.venue.clone());
+        }
+        Citation::ConferenceProceedingsOnline(proceedings) => {
+            tag(
+                "T2",
+                Some(proceedings.series.full_name(&proceedings.conference_name)),
+            );
+            tag("VL", proceedings.volume.as_ref().map(ToString::to_string));
+            tag("IS", proceedings.number.as_ref().map(ToString::to_string));
+            tag("CY", proceedings.venue.clone());
+            for editor in &proceedings.editors {
+                tag(
+                    "A2",
+                    Some(match editor.given_names() {
+                        Some(given) => format!("{}, {}", editor.surname(), given),
+                        None => editor.surname().to_string(),
+                    }),
+                );
+            }
+        }
+        Citation::OnlineManual(manual) => {
+            tag(
+                "ET",
+                manual
+                    .version
+                    .as_ref()
+                    .map(GenericMediaVersion::as_apa_string),
+            );
+            if let OnlineManualAvailability::DOI(doi) = &manual.available_at {
+                tag("DO", Some(doi.clone()));
+            }
+        }
+        Citation::OnlineVideo(OnlineVideo::YouTube { .. }) => {
+            tag("PB", Some("YouTube".to_string()));
+        }
+        Citation::OnlineVideo(OnlineVideo::Generic { .. }) => {}
+        Citation::Software(software) => {
+            tag("ET", software.version.clone());
+            tag("PB", software.publisher.clone());
+        }
+        Citation::WebPage(page) => tag("T2", page.site_name.clone()),
+        Citation::WikipediaArticle(_) => tag("T2", Some("Wikipedia".to_string())),
+        Citation::Custom(_) => {}
+    }
+
+    if let Some(published) = citation.published() {
+        tag("PY", Some(published.year().to_string()));
+        if options.dates == DateFields::Date {
+            tag("DA", Some(iso_date(&published).replace('-', "/")));
+        }
+    }
+    tag("DO", citation.doi());
+    tag("UR", citation.url());
+    tag(
+        "Y2",
+        citation
+            .accessed()
+            .map(|accessed| accessed.date().format("%Y/%m/%d").to_string()),
+    );
+    if options.tags_as_keywords {
+        for keyword in citation.tags() {
+            tag("KW", Some(keyword.clone()));
+        }
+    }
+
+    let mut lines: Vec<String> = tags
+        .into_iter()
+        .map(|(name, value)| format!("{}  - {}", name, value))
+        .collect();
+    lines.push("ER  - ".to_string());
+    lines.join("\n")
+}
+
+/// Write citations as an RIS file, one record per citation
+pub fn citations_to_ris(citations: &[Citation], options: &ExportOptions) -> String {
+    citations
+        .iter()
+        .map(|citation| citation_to_ris_with(citation, options) + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn record_type(citation: &Citation) -> &'static str {
+    match citation {
+        Citation::Book(_) => "BOOK",
+        Citation::JournalArticle(_) => "JOUR",
+        Citation::ConferencePresentation(_) | Citation::ConferencePaperOnline(_) => "CPAPER",
+        Citation::ConferenceProceedingsOnline(_) => "CONF",
+        Citation::OnlineManual(_) => "GEN",
+        Citation::OnlineVideo(_) => "VIDEO",
+        Citation::Software(_) => "COMP",
+        Citation::WebPage(_) | Citation::WikipediaArticle(_) => "ELEC",
+        Citation::Custom(_) => "GEN",
+    }
+}
+
+fn page_tags(tag: &mut impl FnMut(&'static str, Option<String>), pages: &PageRange) {
+    tag("SP", Some(pages.start.to_string()));
+    if pages.end != pages.start {
+        tag("EP", Some(pages.end.to_string()));
+    }
+}
+
+/// RIS record types and the BibTeX entry types they are read as. Other
+/// types are kept as they are, in lowercase.
+const RECORD_TYPES: [(&str, &str); 12] = [
+    ("JOUR", "article"),
+    ("JFULL", "article"),
+    ("EJOUR", "article"),
+    ("MGZN", "article"),
+    ("NEWS", "article"),
+    ("BOOK", "book"),
+    ("EBOOK", "book"),
+    ("ELEC", "online"),
+    ("WEB", "online"),
+    ("BLOG", "online"),
+    ("GEN", "misc"),
+    ("COMP", "software"),
+];
+
+/// Read the records of an RIS file.
+///
+/// Tags are read as the BibTeX fields they correspond to and turned into
+/// citations as [`read_bibtex`] does: `JOUR` records with a journal
+/// (`T2`, `JO` or `JF`) become journal articles, `ELEC` records with a
+/// URL become web pages, and the rest become books, which is reported
+/// for types other than `BOOK`. Authors are read from `AU` and `A1`, the
+/// year from `PY` or `Y1`, the full date from `DA` and the place of
+/// publication from `CY`. Tags that aren't read, dates that can't be
+/// read and tags outside a record are reported with their line numbers.
+///
+/// [`read_bibtex`]: crate::bibtex::read_bibtex
+pub fn read_ris(text: &str) -> ImportReport {
+    let mut report = ImportReport::default();
+    let mut record: Option<Record> = None;
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim_start_matches('\u{FEFF}');
+        let Some((tag, value)) = tag_line(line) else {
+            // Text without a tag continues the value above it
+            if let Some(record) = &mut record
+                && let Some((_, last, _)) = record.tags.last_mut()
+                && !line.trim().is_empty()
+            {
+                last.push(' ');
+                last.push_str(line.trim());
+            }
+            continue;
+        };
+        match (tag, &mut record) {
+            ("TY", _) => {
+                if let Some(record) = record.take() {
+                    citation_from_entry(&record.entry(), &mut report);
+                }
+                record = Some(Record {
+                    kind: value.to_string(),
+                    line: line_number,
+                    tags: Vec::new(),
+                });
+            }
+            ("ER", Some(_)) => {
+                citation_from_entry(&record.take().unwrap().entry(), &mut report);
+            }
+            (_, Some(record)) => record.tags.push((tag, value.to_string(), line_number)),
+            (_, None) => report.warnings.push(ImportWarning {
+                line: Some(line_number),
+                entry: None,
+                issue: ImportIssue::SkippedEntry {
+                    reason: format!("{} tag outside a record", tag),
+                },
+            }),
+        }
+    }
+    if let Some(record) = record {
+        citation_from_entry(&record.entry(), &mut report);
+    }
+    report.warnings.sort_by_key(|warning| warning.line);
+    report
+}
+
+/// The citations in an RIS file, see [`read_ris`]
+pub fn citations_from_ris(text: &str) -> Vec<Citation> {
+    read_ris(text).citations
+}
+
+/// The tag and value of a line like "AU  - Smith, Jane"
+fn tag_line(line: &str) -> Option<(&str, &str)> {
+    let tag = line.get(..2)?;
+    let value = line.get(2..)?.strip_prefix("  -")?;
+    let mut chars = tag.chars();
+    (chars.next()?.is_ascii_uppercase()
+        && chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()))
+    .then(|| (tag, value.trim()))
+}
+
+/// The tags of an RIS record between `TY` and `ER`
+struct Record<'a> {
+    kind: String,
+    line: usize,
+    tags: Vec<(&'a str, String, usize)>,
+}
+
+impl Record<'_> {
+    /// The record as a BibTeX entry. Repeated authors and keywords are
+    /// joined; tags that aren't read keep their RIS name.
+    fn entry(&self) -> ReadEntry {
+        let kind = RECORD_TYPES
+            .iter()
+            .find(|(ris, _)| *ris == self.kind)
+            .map_or_else(
+                || self.kind.to_lowercase(),
+                |(_, bibtex)| bibtex.to_string(),
+            );
+        let mut fields: Vec<(String, String, usize)> = Vec::new();
+        let mut key = None;
+        for (tag, value, line) in &self.tags {
+            let name = match *tag {
+                "ID" => {
+                    key = Some(value.clone());
+                    continue;
+                }
+                "AU" | "A1" => "author",
+                "TI" | "T1" => "title",
+                "T2" | "JO" | "JF" if kind == "article" => "journal",
+                "T2" if kind == "online" => "organization",
+                "VL" => "volume",
+                "IS" => "number",
+                "SP" => "pages",
+                "EP" => {
+                    if let Some((_, pages, _)) =
+                        fields.iter_mut().find(|(name, ..)| name == "pages")
+                    {
+                        pages.push_str("--");
+                        pages.push_str(value);
+                    }
+                    continue;
+                }
+                "C7" => "eid",
+                "PY" | "Y1" => {
+                    let mut parts = value.split('/');
+                    fields.push((
+                        "year".to_string(),
+                        parts.next().unwrap_or_default().to_string(),
+                        *line,
+                    ));
+                    if let Some(month) = parts.next().filter(|month| !month.is_empty()) {
+                        fields.push(("month".to_string(), month.to_string(), *line));
+                    }
+                    continue;
+                }
+                "DA" => {
+                    fields.push(("date".to_string(), iso(value), *line));
+                    continue;
+                }
+                "Y2" => {
+                    fields.push(("urldate".to_string(), iso(value), *line));
+                    continue;
+                }
+                "ET" => "edition",
+                "PB" => "publisher",
+                "CY" => "address",
+                "SN" if kind != "article" => "isbn",
+                "DO" => "doi",
+                "UR" => "url",
+                "KW" => "keywords",
+                tag => tag,
+            };
+            match fields.iter_mut().find(|(field, ..)| field == name) {
+                Some((_, joined, _)) if name == "author" => {
+                    joined.push_str(" and ");
+                    joined.push_str(value);
+                }
+                Some((_, joined, _)) if name == "keywords" => {
+                    joined.push_str(", ");
+                    joined.push_str(value);
+                }
+                _ => fields.push((name.to_string(), value.clone(), *line)),
+            }
+        }
+        ReadEntry {
+            kind: Some(kind),
+            key,
+            line: self.line,
+            fields,
+        }
+    }
+}
+
+/// An RIS date like "2020/03/05/" as "2020-03-05"
+fn iso(date: &str) -> String {
+    date.trim_end_matches('/').replace('/', "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            location::LocationData,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+            page_range::{ArticleLocator, PageRange},
+            volume::Volume,
+        },
+        export::{DateFields, ExportOptions},
+        import::ImportIssue,
+        ris::{citation_to_ris, citation_to_ris_with, read_ris},
+    };
+
+    fn article() -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month_day(2020, Month::March, 5).unwrap()),
+                tags: vec!["repair".to_string()],
+                ..CommonCitationData::new("smith2020")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![PersonName::from_first_last("Jane", "Smith").unwrap()],
+            },
+            title: "DNA Repair".into(),
+            journal: "J. Tests".to_string(),
+            volume: Some(Volume::parse("12")),
+            number: None,
+            locator: Some(ArticleLocator::Pages(PageRange { start: 3, end: 9 })),
+            doi: Some("10.1000/xyz".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_article_to_ris() {
+        assert_eq!(
+            citation_to_ris(&article()),
+            "TY  - JOUR
+ID  - smith2020
+AU  - Smith, Jane
+TI  - DNA Repair
+T2  - J. Tests
+VL  - 12
+SP  - 3
+EP  - 9
+PY  - 2020
+DO  - 10.1000/xyz
+ER  - "
+        );
+    }
+
+    #[test]
+    fn test_ris_options() {
+        let options = ExportOptions {
+            dates: DateFields::Date,
+            tags_as_keywords: true,
+            ..Default::default()
+        };
+        let ris = citation_to_ris_with(&article(), &options);
+
+        assert!(ris.contains("PY  - 2020\nDA  - 2020/03/05\n"));
+        assert!(ris.contains("KW  - repair\n"));
+    }
+
+    const RIS: &str = "TY  - JOUR
+AU  - Vaswani, Ashish
+AU  - Shazeer, Noam
+TI  - Attention Is All You Need
+JO  - Advances in Neural Information Processing Systems
+VL  - 30
+SP  - 5998
+EP  - 6008
+PY  - 2017/12/06/
+N2  - The dominant sequence transduction models are based on complex
+recurrent or convolutional neural networks.
+ER  - 
+
+TY  - ELEC
+ID  - rust-blog
+TI  - The Rust Blog
+T2  - Rust
+UR  - https://blog.rust-lang.org
+Y2  - 2024/05/01
+ER  - 
+
+PY  - 2020
+TY  - CHAP
+TI  - Parsing
+PY  - n.d.
+ER  - 
+";
+
+    #[test]
+    fn test_read_ris() {
+        let report = read_ris(RIS);
+        let [
+            Citation::JournalArticle(article),
+            Citation::WebPage(page),
+            Citation::Book(chapter),
+        ] = report.citations.as_slice()
+        else {
+            panic!("expected an article, a web page and a book");
+        };
+        assert_eq!(article.common_data.id, "attention-is-all-you-need");
+        assert_eq!(
+            article.author.as_ieee_string().unwrap(),
+            "A. Vaswani and N. Shazeer"
+        );
+        assert_eq!(
+            article.common_data.published,
+            Some(PublishDate::from_year_month(2017, Month::December))
+        );
+        assert_eq!(
+            article.locator,
+            Some(ArticleLocator::Pages(PageRange {
+                start: 5998,
+                end: 6008
+            }))
+        );
+        assert_eq!(page.common_data.id, "rust-blog");
+        assert_eq!(page.site_name.as_deref(), Some("Rust"));
+        assert_eq!(page.accessed.date().to_string(), "2024-05-01");
+        assert_eq!(chapter.title.to_string(), "Parsing");
+
+        let issues: Vec<_> = report
+            .warnings
+            .iter()
+            .map(|warning| (warning.line, &warning.issue))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (
+                    Some(10),
+                    &ImportIssue::UnknownField {
+                        field: "N2".to_string()
+                    }
+                ),
+                (
+                    Some(22),
+                    &ImportIssue::SkippedEntry {
+                        reason: "PY tag outside a record".to_string()
+                    }
+                ),
+                (
+                    Some(23),
+                    &ImportIssue::GuessedMediaType {
+                        stated: Some("chap".to_string()),
+                        used: "book".to_string()
+                    }
+                ),
+                (
+                    Some(25),
+                    &ImportIssue::CoercedDate {
+                        original: "n.d.".to_string(),
+                        used: None
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let options = ExportOptions {
+            dates: DateFields::Date,
+            tags_as_keywords: true,
+            ..Default::default()
+        };
+
+        let report = read_ris(&citation_to_ris_with(&article(), &options));
+
+        assert_eq!(report.citations, [article()]);
+        assert_eq!(report.warnings, []);
+    }
+
+    #[test]
+    fn test_place_round_trip() {
+        let report =
+            read_ris("TY  - BOOK\nTI  - Structure\nPB  - Example Press\nCY  - Paris, FR\nER  - \n");
+        let extended = &report.citations[0].common_data().extended;
+        assert_eq!(extended.place, Some(LocationData::new("Paris", "France")));
+        assert_eq!(extended.publisher.as_deref(), Some("Example Press"));
+
+        let written = citation_to_ris(&report.citations[0]);
+        assert!(written.contains("CY  - Paris, France\n"), "{}", written);
+        assert_eq!(read_ris(&written).citations, report.citations);
+    }
+}
@@ -0,0 +1,353 @@
+//! JSON Schema (draft 2020-12) of the data model, so that tools and web
+//! front-ends not written in Rust can validate the citations and
+//! bibliography files they produce.
+//!
+//! ```
+//! let schema = citation_station::schema::schema();
+//! assert_eq!(schema["title"], "Bibliography");
+//! ```
+//!
+//! The schemas are derived from the serde types with `schemars`, so they
+//! describe the JSON written by `serde_json`. The schema of a bibliography
+//! file is also kept in `schema/bibliography.schema.json`; the tests fail
+//! when it is out of date.
+
+use chrono::Month;
+use schemars::{Schema, SchemaGenerator, json_schema};
+use serde_json::Value;
+
+use crate::bibliography::Bibliography;
+
+pub use schemars::JsonSchema;
+
+/// The schema of a bibliography file, with every citation type under
+/// `$defs`
+pub fn schema() -> Value {
+    schema_for::<Bibliography>()
+}
+
+/// The schema of `T`, e.g. `schema_for::<Citation>()` for a single citation
+pub fn schema_for<T: JsonSchema>() -> Value {
+    SchemaGenerator::default()
+        .into_root_schema_for::<T>()
+        .to_value()
+}
+
+/// chrono writes months as their English names
+pub(crate) fn month(_: &mut SchemaGenerator) -> Schema {
+    let names: Vec<&str> = (1..=12)
+        .filter_map(|number| Month::try_from(number).ok())
+        .map(|month| month.name())
+        .collect();
+    json_schema!({ "type": "string", "enum": names })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use chrono::{Month, NaiveDate, TimeZone, Utc};
+    use serde_json::{Value, json};
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, Contributor, GenericAuthor, PersonName},
+            citation::Citation,
+            conference::ConferenceSeries,
+            date::PublishDate,
+            identifiers::Identifiers,
+            location::LocationData,
+            media::{
+                book::Book,
+                common::{CommonCitationData, FormatOverride, FormatOverrides},
+                conference_paper::ConferencePaperOnline,
+                conference_presentation::{ConferencePresentation, PresentationKind},
+                journal_article::JournalArticle,
+                online_manual::{OnlineManual, OnlineManualAvailability},
+                online_video::OnlineVideo,
+                software::Software,
+                version::{GenericMediaVersion, SemVer},
+                web_page::WebPage,
+                wikipedia_article::WikipediaArticle,
+            },
+            page_range::{ArticleLocator, PageRange},
+            style::CitationStyle,
+            title::Title,
+            volume::{Issue, Volume},
+        },
+        bibliography::Bibliography,
+        import::identifier::Identifier,
+        schema::schema,
+    };
+
+    /// Check `value` against the subset of JSON Schema that `schemars`
+    /// writes. Unlike a real validator, keys that the schema doesn't
+    /// declare are errors unless `additionalProperties` describes them, so
+    /// that fields serde writes but the schema leaves out are caught.
+    fn check(root: &Value, schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.trim_start_matches("#/$defs/");
+            return check(root, &root["$defs"][name], value, path);
+        }
+        if let Some(options) = schema["anyOf"].as_array().or(schema["oneOf"].as_array()) {
+            let matching = options
+                .iter()
+                .filter(|option| check(root, option, value, path).is_ok())
+                .count();
+            let expected = if schema["oneOf"].is_array() {
+                1
+            } else {
+                matching.max(1)
+            };
+            return if matching == expected {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{}: {} alternatives match {}",
+                    path, matching, value
+                ))
+            };
+        }
+        if let Some(constant) = schema.get("const") {
+            return (constant == value)
+                .then_some(())
+                .ok_or(format!("{}: expected {}", path, constant));
+        }
+        if let Some(allowed) = schema["enum"].as_array() {
+            return allowed
+                .contains(value)
+                .then_some(())
+                .ok_or(format!("{}: {} is not allowed", path, value));
+        }
+        let types: Vec<&str> = match &schema["type"] {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        let type_matches = types.is_empty()
+            || types.iter().any(|name| match *name {
+                "string" => value.is_string(),
+                "integer" => value.is_i64() || value.is_u64(),
+                "boolean" => value.is_boolean(),
+                "null" => value.is_null(),
+                "array" => value.is_array(),
+                "object" => value.is_object(),
+                _ => false,
+            });
+        if !type_matches {
+            return Err(format!("{}: {} is not a {}", path, value, schema["type"]));
+        }
+        if let Some(items) = value.as_array() {
+            for (index, item) in items.iter().enumerate() {
+                check(
+                    root,
+                    &schema["items"],
+                    item,
+                    &format!("{}[{}]", path, index),
+                )?;
+            }
+        }
+        if let Some(fields) = value.as_object() {
+            for required in schema["required"].as_array().into_iter().flatten() {
+                let required = required.as_str().unwrap();
+                if !fields.contains_key(required) {
+                    return Err(format!("{}: missing {}", path, required));
+                }
+            }
+            for (key, field) in fields {
+                let field_schema = schema["properties"]
+                    .get(key)
+                    .or(schema
+                        .get("additionalProperties")
+                        .filter(|extra| extra.is_object()))
+                    .ok_or(format!("{}: undeclared field {}", path, key))?;
+                check(root, field_schema, field, &format!("{}.{}", path, key))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn common_data(id: &str) -> CommonCitationData {
+        let mut overrides = FormatOverrides::default();
+        overrides.insert(
+            CitationStyle::Ieee,
+            FormatOverride {
+                text: "[1] Hand-written.".to_string(),
+                content_hash: Some(42),
+            },
+        );
+        CommonCitationData {
+            published: Some(PublishDate::from_year_month_day(2020, Month::May, 4).unwrap()),
+            cites: vec!["other".into()],
+            tags: vec!["tag".to_string()],
+            identifiers: Identifiers::new().with(Identifier::Pmid("42".to_string())),
+            overrides,
+            ..CommonCitationData::new(id)
+        }
+    }
+
+    fn persons() -> Vec<PersonName> {
+        vec![
+            PersonName::from_first_last("Jane", "Smith").unwrap(),
+            PersonName::parse("Smith, Jane Q.").unwrap(),
+            PersonName::SurnameOnly {
+                surname: "Plato".to_string(),
+            },
+        ]
+    }
+
+    /// One citation of every type, with as many fields set as possible
+    fn bibliography() -> Bibliography {
+        let accessed = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap().into();
+        let citations = vec![
+            Citation::Book(Book {
+                common_data: common_data("book"),
+                author: GenericAuthor::Persons { persons: persons() },
+                with_authors: Vec::new(),
+                title: Title::with_subtitle("Title", "subtitle"),
+                chapter: Some("Chapter".to_string()),
+                version: Some(GenericMediaVersion::SemVer(SemVer::MajorMinorPatch {
+                    major: 1,
+                    minor: 2,
+                    patch: 3,
+                    pre_release: Some("beta".to_string()),
+                    build: None,
+                })),
+                doi: Some("10.1000/book".to_string()),
+                pages: Some(PageRange { start: 1, end: 9 }),
+            }),
+            Citation::ConferencePaperOnline(ConferencePaperOnline {
+                common_data: common_data("paper"),
+                title: "Title".into(),
+                venue: Some("Venue".to_string()),
+                volume: Some(Volume::parse("3-4")),
+                number: Some(Issue::parse("Suppl. 1")),
+                conference_name: "Conference".to_string(),
+                series: ConferenceSeries::default(),
+                conference_date: Utc.with_ymd_and_hms(2020, 5, 4, 0, 0, 0).unwrap(),
+            }),
+            Citation::JournalArticle(JournalArticle {
+                common_data: common_data("article"),
+                author: AcademicAuthor::Organization {
+                    name: "Org".to_string(),
+                    abbreviation: None,
+                },
+                title: "Title".into(),
+                journal: "Journal".to_string(),
+                volume: Some(Volume::parse("12")),
+                number: None,
+                locator: Some(ArticleLocator::ArticleNumber("e123".to_string())),
+                doi: None,
+            }),
+            Citation::ConferencePresentation(ConferencePresentation {
+                common_data: common_data("talk"),
+                author: AcademicAuthor::Persons { persons: persons() },
+                title: "Title".into(),
+                kind: PresentationKind::Poster,
+                conference_name: "Conference".to_string(),
+                series: ConferenceSeries::default(),
+                location: Some(LocationData {
+                    city: "City".to_string(),
+                    state: None,
+                    country: "Country".to_string(),
+                }),
+                ends: Some(PublishDate::from_year(2020)),
+                url: None,
+            }),
+            Citation::OnlineManual(OnlineManual {
+                common_data: common_data("manual"),
+                author: GenericAuthor::Mixed {
+                    contributors: vec![
+                        Contributor::Organization {
+                            name: "Org".to_string(),
+                            abbreviation: Some("O".to_string()),
+                        },
+                        Contributor::Person(PersonName::from_last("Doe").unwrap()),
+                    ],
+                },
+                title: "Title".into(),
+                version: Some(GenericMediaVersion::VolumeRange { start: 1, end: 2 }),
+                available_at: OnlineManualAvailability::NotAvailable,
+                accessed,
+            }),
+            Citation::OnlineVideo(OnlineVideo::YouTube {
+                common_data: common_data("video"),
+                title: "Title".into(),
+                url: Some("https://youtube.com/watch?v=1".to_string()),
+                channel: "Channel".to_string(),
+                accessed,
+            }),
+            Citation::Software(Software {
+                common_data: common_data("software"),
+                author: GenericAuthor::Persons { persons: persons() },
+                title: "Title".into(),
+                version: Some("1.0".to_string()),
+                publisher: Some("GitHub".to_string()),
+                url: None,
+                doi: None,
+                accessed,
+            }),
+            Citation::WebPage(WebPage {
+                common_data: common_data("page"),
+                author: None,
+                title: "Title".into(),
+                site_name: Some("Site".to_string()),
+                url: "https://example.com".to_string(),
+                accessed,
+            }),
+            Citation::WikipediaArticle(WikipediaArticle {
+                common_data: common_data("wikipedia"),
+                title: "Title".to_string(),
+                language: "en".to_string(),
+                revision: Some(1),
+                accessed,
+            }),
+        ];
+        let mut bibliography = Bibliography::new();
+        for citation in citations {
+            bibliography.add_citation(citation).unwrap();
+        }
+        bibliography
+    }
+
+    #[test]
+    fn test_schema_describes_serialized_bibliography() {
+        let schema = schema();
+        let value = serde_json::to_value(bibliography()).unwrap();
+
+        assert_eq!(check(&schema, &schema, &value, "$"), Ok(()));
+    }
+
+    #[test]
+    fn test_schema_rejects_invalid_citation() {
+        let schema = schema();
+        let value = json!({ "citations": [{ "Book": { "common_data": { "id": "x" } } }] });
+
+        assert!(check(&schema, &schema, &value, "$").is_err());
+        assert_eq!(
+            schema["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
+        assert!(schema["$defs"]["Citation"]["oneOf"].is_array());
+    }
+
+    /// Set `UPDATE_SCHEMA=1` to rewrite the file after an intended change
+    #[test]
+    fn test_schema_matches_file() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/schema/bibliography.schema.json"
+        );
+        let generated = serde_json::to_string_pretty(&schema()).unwrap() + "\n";
+        if env::var_os("UPDATE_SCHEMA").is_some() {
+            fs::write(path, &generated).unwrap();
+        }
+
+        let expected = fs::read_to_string(path).unwrap();
+        assert!(
+            generated == expected,
+            "{} is out of date; rerun with UPDATE_SCHEMA=1 and review the diff",
+            path
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! "How to cite this tool": a citation for the crate being built, made from
+//! its Cargo metadata and, optionally, its CITATION.cff.
+//!
+//! ```ignore
+//! let citation = citation_station::self_citation!("CITATION.cff")?;
+//! println!("{}", citation.format_apa());
+//! ```
+
+use crate::{
+    api::{
+        author::{GenericAuthor, PersonName},
+        citation::Citation,
+        date::AccessDate,
+        media::{common::CommonCitationData, software::Software},
+    },
+    cff::{CffError, software_from_cff_or},
+};
+
+/// The parts of a package's Cargo.toml used for citing it, as provided by
+/// Cargo in `CARGO_PKG_*` environment variables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageMetadata<'a> {
+    pub name: &'a str,
+    pub version: &'a str,
+    /// Authors separated by ':', e.g. "Jane Smith <jane@example.com>:ACME"
+    pub authors: &'a str,
+    pub repository: &'a str,
+    pub homepage: &'a str,
+}
+
+/// The Cargo metadata of the crate that invokes this macro.
+#[macro_export]
+macro_rules! package_metadata {
+    () => {
+        $crate::self_citation::PackageMetadata {
+            name: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+            authors: env!("CARGO_PKG_AUTHORS"),
+            repository: env!("CARGO_PKG_REPOSITORY"),
+            homepage: env!("CARGO_PKG_HOMEPAGE"),
+        }
+    };
+}
+
+/// A citation for the crate that invokes this macro, as a
+/// `Result<Citation, CffError>`.
+///
+/// With no arguments, the citation is made from Cargo metadata. Given the
+/// path of a CITATION.cff file relative to the crate's manifest directory,
+/// the file is embedded at compile time and takes precedence over Cargo
+/// metadata. See [`self_citation::citation_for_package`](crate::self_citation::citation_for_package).
+#[macro_export]
+macro_rules! self_citation {
+    () => {
+        $crate::self_citation::citation_for_package(&$crate::package_metadata!(), None)
+    };
+    ($citation_cff:literal) => {
+        $crate::self_citation::citation_for_package(
+            &$crate::package_metadata!(),
+            Some(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/",
+                $citation_cff
+            ))),
+        )
+    };
+}
+
+/// Build a software citation for a package.
+///
+/// Fields from `citation_cff` win; Cargo metadata fills in the title,
+/// version, authors and URL (repository, then homepage) when the CFF file
+/// is absent or leaves them out. Authors that look like people ("First
+/// Last <email>") become persons; without any, the package name is
+/// credited as an organization. The citation ID is the package name.
+pub fn citation_for_package(
+    package: &PackageMetadata,
+    citation_cff: Option<&str>,
+) -> Result<Citation, CffError> {
+    let package_software = software_from_package(package);
+    let mut software = match citation_cff {
+        Some(text) => software_from_cff_or(text, Some(&package_software))?,
+        None => package_software,
+    };
+    software.common_data.id = package.name.to_string();
+    Ok(Citation::Software(software))
+}
+
+fn software_from_package(package: &PackageMetadata) -> Software {
+    Software {
+        common_data: CommonCitationData::new(package.name.to_string()),
+        author: package_authors(package),
+        title: package.name.into(),
+        version: (!package.version.is_empty()).then(|| package.version.to_string()),
+        publisher: None,
+        url: package_url(package),
+        doi: None,
+        accessed: AccessDate::today(),
+    }
+}
+
+fn package_url(package: &PackageMetadata) -> Option<String> {
+    [package.repository, package.homepage]
+        .into_iter()
+        .map(str::trim)
+        .find(|url| !url.is_empty())
+        .map(str::to_string)
+}
+
+fn package_authors(package: &PackageMetadata) -> GenericAuthor {
+    let persons: Vec<PersonName> = package
+        .authors
+        .split(':')
+        .map(|author| author.split('<').next().unwrap_or_default().trim())
+        .filter(|name| name.contains(' '))
+        .filter_map(|name| PersonName::parse(name).ok())
+        .collect();
+    if persons.is_empty() {
+        GenericAuthor::Organization {
+            name: package.name.to_string(),
+            abbreviation: None,
+        }
+    } else {
+        GenericAuthor::Persons { persons }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            author::{GenericAuthor, PersonName},
+            citation::Citation,
+            media::software::Software,
+        },
+        self_citation::{PackageMetadata, citation_for_package},
+    };
+
+    fn package() -> PackageMetadata<'static> {
+        PackageMetadata {
+            name: "citation_station",
+            version: "0.1.0",
+            authors: "Jane Smith <jane@example.com>:Jo Bloggs",
+            repository: "",
+            homepage: "https://example.com",
+        }
+    }
+
+    fn software(citation: Citation) -> Software {
+        match citation {
+            Citation::Software(software) => software,
+            other => panic!("expected software, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_citation_for_package() {
+        let software = software(citation_for_package(&package(), None).unwrap());
+
+        assert_eq!(software.common_data.id, "citation_station");
+        assert_eq!(software.title, "citation_station");
+        assert_eq!(software.version.as_deref(), Some("0.1.0"));
+        assert_eq!(software.url.as_deref(), Some("https://example.com"));
+        assert_eq!(
+            software.author,
+            GenericAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_first_last("Jo", "Bloggs").unwrap(),
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_citation_for_package_prefers_cff() {
+        let cff = "cff-version: 1.2.0\ntitle: Citation Station\ndoi: 10.5281/zenodo.1\n\
+                   authors:\n  - name: The Citation Station Project\n";
+        let software = software(citation_for_package(&package(), Some(cff)).unwrap());
+
+        assert_eq!(software.common_data.id, "citation_station");
+        assert_eq!(software.title, "Citation Station");
+        assert_eq!(software.version.as_deref(), Some("0.1.0"));
+        assert_eq!(software.doi.as_deref(), Some("10.5281/zenodo.1"));
+        assert_eq!(
+            software.author,
+            GenericAuthor::Organization {
+                name: "The Citation Station Project".to_string(),
+                abbreviation: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_citation_for_package_fills_cff_gaps() {
+        let software =
+            software(citation_for_package(&package(), Some("doi: 10.5281/zenodo.1\n")).unwrap());
+
+        assert_eq!(software.title, "citation_station");
+        assert_eq!(software.author.contributors().len(), 2);
+    }
+
+    #[test]
+    fn test_self_citation_macro() {
+        let software = software(crate::self_citation!().unwrap());
+
+        assert_eq!(software.title, "citation_station");
+        assert_eq!(software.version.as_deref(), Some(env!("CARGO_PKG_VERSION")));
+    }
+}
@@ -0,0 +1,483 @@
+//! A small HTTP service over the crate's APIs, so that citation formatting
+//! can be run as a service by programs not written in Rust.
+//!
+//! | Endpoint                       | Body                        | Response                      |
+//! |--------------------------------|-----------------------------|-------------------------------|
+//! | `POST /format?style=<style>`   | a citation, or an array     | the references, one per line  |
+//! | `POST /import/bibtex`          | a BibTeX file               | the citations and warnings    |
+//! | `GET /bibliography`            |                             | the bibliography as JSON      |
+//! | `POST /bibliography`           | a citation                  | `201 Created`                 |
+//!
+//! `style` is one of `apa` (the default), `ieee`, `iso690`, `vancouver`,
+//! `ama` or `acs`. A citation that can't be formatted in the requested
+//! style, such as a custom media type without rules for it, gets
+//! `422 Unprocessable Entity`; any other failure is a
+//! `500 Internal Server Error` rather than a dropped connection.
+//!
+//! `/import/bibtex` answers with `{"citations": [...], "warnings": [...]}`,
+//! where each warning has the `line` and `entry` it concerns and a
+//! `message` describing what was skipped or guessed, see
+//! [`read_bibtex`]. Nothing is added to the bibliography.
+//!
+//! The server runs on axum. Every connection is handled on its own task,
+//! and a request whose body doesn't arrive within [`READ_TIMEOUT`] is
+//! answered with `408 Request Timeout`, so a slow client can't hold up
+//! the others.
+
+use std::{
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{
+    Router,
+    body::to_bytes,
+    extract::{Request as HttpRequest, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response as HttpResponse},
+};
+use tokio::net::TcpListener;
+
+use crate::{
+    api::{citation::Citation, style::CitationStyle, url::percent_decode},
+    bibliography::Bibliography,
+    bibtex::read_bibtex,
+};
+
+/// How long a client has to send its request body
+pub const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest request body accepted, in bytes
+const MAX_BODY_LENGTH: usize = 1 << 20;
+
+/// The parts of an HTTP request used by [`Service`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    /// Path and query string, e.g. "/format?style=ieee"
+    pub target: String,
+    pub body: String,
+}
+
+impl Request {
+    fn path(&self) -> &str {
+        self.target.split('?').next().unwrap_or_default()
+    }
+
+    /// The value of query parameter `name`, with `%XX` escapes and "+"
+    /// decoded in both. Parameters that don't decode are skipped.
+    fn query(&self, name: &str) -> Option<String> {
+        let (_, query) = self.target.split_once('?')?;
+        let decode = |text: &str| percent_decode(&text.replace('+', " "));
+        query
+            .split('&')
+            .filter_map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                Some((decode(key)?, decode(value)?))
+            })
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: body.into(),
+        }
+    }
+
+    fn json(status: u16, body: String) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body,
+        }
+    }
+}
+
+/// Answers requests against a bibliography shared by every client
+#[derive(Debug, Default)]
+pub struct Service {
+    bibliography: Mutex<Bibliography>,
+}
+
+impl Service {
+    pub fn new(bibliography: Bibliography) -> Self {
+        Self {
+            bibliography: Mutex::new(bibliography),
+        }
+    }
+
+    /// The bibliography, with the citations added by clients
+    pub fn into_bibliography(self) -> Bibliography {
+        self.bibliography
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Answer `request`. A panic while answering is turned into
+    /// `500 Internal Server Error`.
+    pub fn handle(&self, request: &Request) -> Response {
+        panic::catch_unwind(AssertUnwindSafe(|| self.route(request)))
+            .unwrap_or_else(|_| Response::text(500, "Internal server error"))
+    }
+
+    fn route(&self, request: &Request) -> Response {
+        match (request.method.as_str(), request.path()) {
+            ("POST", "/format") => format(request),
+            ("POST", "/import/bibtex") => import_bibtex(request),
+            ("GET", "/bibliography") => {
+                let bibliography = self.lock();
+                match serde_json::to_string(&*bibliography) {
+                    Ok(json) => Response::json(200, json),
+                    Err(err) => Response::text(500, err.to_string()),
+                }
+            }
+            ("POST", "/bibliography") => match serde_json::from_str::<Citation>(&request.body) {
+                Ok(citation) => match self.lock().add_citation(citation) {
+                    Ok(()) => Response::text(201, "Created"),
+                    Err(err) => Response::text(409, err.to_string()),
+                },
+                Err(err) => Response::text(400, format!("Invalid citation: {}", err)),
+            },
+            (_, "/format" | "/import/bibtex" | "/bibliography") => {
+                Response::text(405, "Method not allowed")
+            }
+            _ => Response::text(404, "Not found"),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bibliography> {
+        self.bibliography
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn format(request: &Request) -> Response {
+    let style = match request.query("style").as_deref() {
+        None | Some("apa") => CitationStyle::Apa,
+        Some("ieee") => CitationStyle::Ieee,
+        Some("iso690") => CitationStyle::Iso690,
+        Some("vancouver") => CitationStyle::Vancouver,
+        Some("ama") => CitationStyle::Ama,
+        Some("acs") => CitationStyle::Acs,
+        Some(other) => return Response::text(400, format!("Unknown style: {}", other)),
+    };
+    let citations = match serde_json::from_str::<Vec<Citation>>(&request.body) {
+        Ok(citations) => citations,
+        Err(_) => match serde_json::from_str::<Citation>(&request.body) {
+            Ok(citation) => vec![citation],
+            Err(err) => return Response::text(400, format!("Invalid citation: {}", err)),
+        },
+    };
+    let references: Result<Vec<String>, _> = citations
+        .iter()
+        .map(|citation| citation.try_format(style))
+        .collect();
+    match references {
+        Ok(references) => Response::text(200, references.join("\n")),
+        Err(err) => Response::text(422, err.to_string()),
+    }
+}
+
+fn import_bibtex(request: &Request) -> Response {
+    let report = read_bibtex(&request.body);
+    let warnings: Vec<serde_json::Value> = report
+        .warnings
+        .iter()
+        .map(|warning| {
+            serde_json::json!({
+                "line": warning.line,
+                "entry": warning.entry,
+                "message": warning.issue.to_string(),
+            })
+        })
+        .collect();
+    let body = serde_json::json!({
+        "citations": report.citations,
+        "warnings": warnings,
+    });
+    Response::json(200, body.to_string())
+}
+
+/// The routes of `service`, for serving it alongside other axum routes
+pub fn router(service: Arc<Service>) -> Router {
+    Router::new().fallback(respond).with_state(service)
+}
+
+/// Serve `service` on `listener` until accepting connections fails
+pub async fn serve(listener: TcpListener, service: Arc<Service>) -> io::Result<()> {
+    axum::serve(listener, router(service)).await
+}
+
+async fn respond(State(service): State<Arc<Service>>, request: HttpRequest) -> HttpResponse {
+    let (parts, body) = request.into_parts();
+    let too_large = parts
+        .headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok()?.parse::<usize>().ok())
+        .is_some_and(|length| length > MAX_BODY_LENGTH);
+    let response = if too_large {
+        Response::text(413, "Request body too large")
+    } else {
+        match tokio::time::timeout(READ_TIMEOUT, to_bytes(body, MAX_BODY_LENGTH)).await {
+            Err(_) => Response::text(408, "Request body not received in time"),
+            Ok(Err(_)) => Response::text(400, "Request body could not be read"),
+            Ok(Ok(body)) => match String::from_utf8(body.to_vec()) {
+                Ok(body) => {
+                    let request = Request {
+                        method: parts.method.to_string(),
+                        target: parts
+                            .uri
+                            .path_and_query()
+                            .map(|target| target.to_string())
+                            .unwrap_or_default(),
+                        body,
+                    };
+                    // Formatting and the bibliography lock block, so keep
+                    // them off the async workers
+                    tokio::task::spawn_blocking(move || service.handle(&request))
+                        .await
+                        .unwrap_or_else(|_| Response::text(500, "Internal server error"))
+                }
+                Err(_) => Response::text(400, "Body is not UTF-8"),
+            },
+        }
+    };
+    (
+        StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+        [(header::CONTENT_TYPE, response.content_type)],
+        response.body,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+        sync::Arc,
+    };
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        api::{
+            errors::CitationError,
+            media::{
+                common::CommonCitationData,
+                custom::{CitationLike, register_custom_media},
+            },
+            style::{CitationStyle, FormatOptions},
+        },
+        server::{Request, Service, serve},
+    };
+
+    const BOOK: &str = r#"{"Book": {"common_data": {"id": "rust", "published": null},
+        "author": {"Organization": {"name": "The Rust Project"}},
+        "title": "The Rust Programming Language",
+        "chapter": null, "version": null, "doi": null, "pages": null}}"#;
+
+    fn request(method: &str, target: &str, body: &str) -> Request {
+        Request {
+            method: method.to_string(),
+            target: target.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let service = Service::default();
+        let response = service.handle(&request("POST", "/format?style=ieee", BOOK));
+
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains("The Rust Programming Language"));
+        assert_eq!(
+            service
+                .handle(&request("POST", "/format?style=mla", BOOK))
+                .status,
+            400
+        );
+    }
+
+    #[test]
+    fn test_bibliography() {
+        let service = Service::default();
+
+        assert_eq!(
+            service
+                .handle(&request("POST", "/bibliography", BOOK))
+                .status,
+            201
+        );
+        assert_eq!(
+            service
+                .handle(&request("POST", "/bibliography", BOOK))
+                .status,
+            409
+        );
+        assert_eq!(
+            service
+                .handle(&request("DELETE", "/bibliography", ""))
+                .status,
+            405
+        );
+        let response = service.handle(&request("GET", "/bibliography", ""));
+        assert_eq!(response.content_type, "application/json");
+        assert!(response.body.contains("\"rust\""));
+        assert_eq!(service.into_bibliography().len(), 1);
+    }
+
+    /// A custom media type with rules for APA only
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct ApaOnly {
+        common_data: CommonCitationData,
+    }
+
+    impl CitationLike for ApaOnly {
+        fn media_type(&self) -> &str {
+            "apa-only"
+        }
+
+        fn common_data(&self) -> &CommonCitationData {
+            &self.common_data
+        }
+
+        fn common_data_mut(&mut self) -> &mut CommonCitationData {
+            &mut self.common_data
+        }
+
+        fn title(&self) -> String {
+            "APA only".to_string()
+        }
+
+        fn format_with(
+            &self,
+            style: CitationStyle,
+            _options: &FormatOptions,
+        ) -> Result<String, CitationError> {
+            match style {
+                CitationStyle::Apa => Ok("APA only.".to_string()),
+                CitationStyle::Vancouver => panic!("a bug in the media type"),
+                _ => Err(CitationError::UnsupportedStyle {
+                    media_type: self.media_type().to_string(),
+                    style,
+                }),
+            }
+        }
+
+        fn to_json(&self) -> serde_json::Value {
+            serde_json::to_value(self).unwrap()
+        }
+
+        fn clone_box(&self) -> Box<dyn CitationLike> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_format_unsupported_style() {
+        register_custom_media::<ApaOnly>("apa-only");
+        let citation = r#"{"Custom": {"media_type": "apa-only",
+            "data": {"common_data": {"id": "only", "published": null}}}}"#;
+        let service = Service::default();
+
+        let response = service.handle(&request("POST", "/format?style=apa", citation));
+        assert_eq!(
+            (response.status, response.body.as_str()),
+            (200, "APA only.")
+        );
+
+        let response = service.handle(&request("POST", "/format?style=ieee", citation));
+        assert_eq!(response.status, 422);
+        assert_eq!(
+            response.body,
+            "apa-only citations can't be formatted in IEEE"
+        );
+
+        let response = service.handle(&request("POST", "/format?style=vancouver", citation));
+        assert_eq!(response.status, 500);
+    }
+
+    #[test]
+    fn test_format_decodes_query() {
+        let service = Service::default();
+        let response = service.handle(&request("POST", "/format?st%79le=i%65ee&x=a+b", BOOK));
+
+        assert_eq!(response.status, 200);
+        assert!(response.body.starts_with("The Rust Project"));
+    }
+
+    #[test]
+    fn test_import_bibtex() {
+        let service = Service::default();
+        let response = service.handle(&request(
+            "POST",
+            "/import/bibtex",
+            "@book{rust, title = {The Rust Programming Language}, note = {2nd ed.}}",
+        ));
+
+        assert_eq!(response.status, 200);
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["citations"][0]["Book"]["common_data"]["id"], "rust");
+        assert_eq!(
+            body["warnings"],
+            serde_json::json!([
+                {"line": 1, "entry": "rust", "message": "Field not read: note"}
+            ])
+        );
+        assert_eq!(
+            service.handle(&request("GET", "/import/bibtex", "")).status,
+            405
+        );
+        assert_eq!(service.into_bibliography().len(), 0);
+    }
+
+    #[test]
+    fn test_serve_alongside_slow_client() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let listener = runtime
+            .block_on(tokio::net::TcpListener::bind("127.0.0.1:0"))
+            .unwrap();
+        let address = listener.local_addr().unwrap();
+        runtime.spawn(serve(listener, Arc::new(Service::default())));
+
+        // Promises a body it never sends
+        let mut slow = TcpStream::connect(address).unwrap();
+        write!(
+            slow,
+            "POST /format HTTP/1.1\r\nHost: localhost\r\nContent-Length: 100\r\n\r\n[]"
+        )
+        .unwrap();
+
+        let mut client = TcpStream::connect(address).unwrap();
+        write!(
+            client,
+            "POST /format?style=ieee HTTP/1.1\r\nHost: localhost\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            BOOK.len(),
+            BOOK
+        )
+        .unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("The Rust Programming Language."));
+    }
+}
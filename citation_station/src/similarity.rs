@@ -0,0 +1,237 @@
+//! Fuzzy string matching, for finding the same work imported twice with
+//! slightly different titles.
+//!
+//! Scores are between 0.0 (nothing in common) and 1.0 (the same). The
+//! `title_*` functions compare [`normalize`]d text, so case, accents and
+//! punctuation don't count as differences.
+
+use std::collections::BTreeSet;
+
+use crate::collation::fold;
+
+/// `text` in lowercase without accents, with punctuation replaced by
+/// spaces and runs of spaces collapsed, e.g. "Über-Größe: Café" is
+/// "uber grosse cafe"
+pub fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            match fold(c) {
+                Some(base) => normalized.push_str(base),
+                None => normalized.push(c),
+            }
+        } else if !normalized.is_empty() && !normalized.ends_with(' ') {
+            normalized.push(' ');
+        }
+    }
+    let trimmed = normalized.trim_end().len();
+    normalized.truncate(trimmed);
+    normalized
+}
+
+/// The Levenshtein distance between `a` and `b`: the number of characters
+/// inserted, deleted or replaced to turn one into the other
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let replace = previous[j] + usize::from(a != *b);
+            current[j + 1] = replace.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// The Levenshtein distance divided by the length of the longer string,
+/// from 0.0 for equal strings to 1.0 for strings with nothing in common
+pub fn normalized_edit_distance(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 0.0;
+    }
+    levenshtein(a, b) as f64 / longest as f64
+}
+
+/// One minus the [`normalized_edit_distance`]
+pub fn edit_similarity(a: &str, b: &str) -> f64 {
+    1.0 - normalized_edit_distance(a, b)
+}
+
+/// The Jaro similarity of `a` and `b`, which counts characters in common
+/// near the same position and how many of them are out of order
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, c) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *c {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+    let a_in_order = a.iter().zip(&a_matched).filter(|(_, matched)| **matched);
+    let b_in_order = b.iter().zip(&b_matched).filter(|(_, matched)| **matched);
+    let transpositions = a_in_order
+        .zip(b_in_order)
+        .filter(|((a, _), (b, _))| a != b)
+        .count()
+        / 2;
+    let matches = matches as f64;
+    (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+/// The Jaro-Winkler similarity of `a` and `b`: [`jaro`] with a bonus for
+/// a common prefix of up to four characters, which suits names
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let jaro = jaro(a, b);
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(4)
+        .take_while(|(a, b)| a == b)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// How alike the words of `a` and `b` are, ignoring their order and
+/// repeated words.
+///
+/// The words both have in common are compared with each string's words,
+/// and the best [`edit_similarity`] is used, so a title that is a
+/// shortened form of the other (e.g. without its subtitle) still scores
+/// highly.
+pub fn token_set_ratio(a: &str, b: &str) -> f64 {
+    let a: BTreeSet<&str> = a.split_whitespace().collect();
+    let b: BTreeSet<&str> = b.split_whitespace().collect();
+    if a.is_empty() || b.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+    let join = |words: Vec<&str>| words.join(" ");
+    let common: Vec<&str> = a.intersection(&b).copied().collect();
+    let a_words = join(
+        common
+            .iter()
+            .copied()
+            .chain(a.difference(&b).copied())
+            .collect(),
+    );
+    let b_words = join(
+        common
+            .iter()
+            .copied()
+            .chain(b.difference(&a).copied())
+            .collect(),
+    );
+    let common = join(common);
+    let mut best = edit_similarity(&a_words, &b_words);
+    if !common.is_empty() {
+        best = best
+            .max(edit_similarity(&common, &a_words))
+            .max(edit_similarity(&common, &b_words));
+    }
+    best
+}
+
+/// The [`edit_similarity`] of two titles after [normalizing](normalize)
+/// them
+pub fn title_edit_similarity(a: &str, b: &str) -> f64 {
+    edit_similarity(&normalize(a), &normalize(b))
+}
+
+/// The [`token_set_ratio`] of two titles after [normalizing](normalize)
+/// them. This is the title score used to find duplicates.
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    token_set_ratio(&normalize(a), &normalize(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::similarity::{
+        edit_similarity, jaro, jaro_winkler, levenshtein, normalize, normalized_edit_distance,
+        title_similarity, token_set_ratio,
+    };
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.001,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Über-Größe: Café!"), "uber grosse cafe");
+        assert_eq!(normalize("  The  (Rust) book "), "the rust book");
+        assert_eq!(normalize("?!"), "");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("naïve", "naive"), 1);
+        assert_close(normalized_edit_distance("kitten", "sitting"), 3.0 / 7.0);
+        assert_close(normalized_edit_distance("", ""), 0.0);
+        assert_close(edit_similarity("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn test_jaro() {
+        assert_close(jaro("MARTHA", "MARHTA"), 0.944);
+        assert_close(jaro("DIXON", "DICKSONX"), 0.767);
+        assert_close(jaro_winkler("MARTHA", "MARHTA"), 0.961);
+        assert_close(jaro_winkler("DWAYNE", "DUANE"), 0.84);
+        assert_close(jaro("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn test_token_set_ratio() {
+        assert_close(
+            token_set_ratio("fuzzy wuzzy was a bear", "wuzzy fuzzy was a bear"),
+            1.0,
+        );
+        assert_close(token_set_ratio("the rust book", "the rust book book"), 1.0);
+        assert_close(token_set_ratio("", "rust"), 0.0);
+        assert!(token_set_ratio("rust book", "haskell manual") < 0.5);
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        assert_close(
+            title_similarity(
+                "Attention Is All You Need",
+                "Attention is all you need: Transformers explained",
+            ),
+            1.0,
+        );
+        assert!(title_similarity("Deep Residual Learning", "Deep residual learnng") > 0.9);
+        assert!(title_similarity("Deep Residual Learning", "Shallow Networks") < 0.5);
+    }
+}
@@ -0,0 +1,399 @@
+//! Custom citation templates, for house styles that APA and IEEE don't
+//! cover.
+//!
+//! A template is text with fields in braces, e.g.
+//! `{authors_apa} ({year}). {title}. {container}, {volume}({issue}).`
+//! [`Template::infer`] builds one from a single correctly formatted example
+//! and the citation it was made from.
+
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
+
+use crate::api::{
+    author::AuthorListRules,
+    citation::Citation,
+    media::{online_video::OnlineVideo, version::GenericMediaVersion},
+    page_range::{ArticleLocator, PageRange},
+    style::{CitationStyle, FormatOptions},
+};
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TemplateError {
+    #[error("Unknown template field: {0}")]
+    UnknownField(String),
+    #[error("Unclosed '{{' in template")]
+    UnclosedField,
+    #[error("None of the citation's fields appear in the example")]
+    NoFieldsFound,
+}
+
+/// A value of a citation that a template can insert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TemplateField {
+    /// Authors as APA lists them, e.g. "Smith, J., & Fuentes, H."
+    AuthorsApa,
+    /// Authors as IEEE lists them, e.g. "J. Smith and H. Fuentes"
+    AuthorsIeee,
+    Title,
+    /// The journal, conference, website or publisher the work appeared in
+    Container,
+    Year,
+    Volume,
+    Issue,
+    /// Page range with an en dash, or an article number
+    Pages,
+    Edition,
+    Doi,
+    Url,
+}
+
+const FIELDS: [TemplateField; 11] = [
+    TemplateField::AuthorsApa,
+    TemplateField::AuthorsIeee,
+    TemplateField::Title,
+    TemplateField::Container,
+    TemplateField::Year,
+    TemplateField::Volume,
+    TemplateField::Issue,
+    TemplateField::Pages,
+    TemplateField::Edition,
+    TemplateField::Doi,
+    TemplateField::Url,
+];
+
+impl TemplateField {
+    /// The name used in template text
+    pub const fn name(self) -> &'static str {
+        match self {
+            TemplateField::AuthorsApa => "authors_apa",
+            TemplateField::AuthorsIeee => "authors_ieee",
+            TemplateField::Title => "title",
+            TemplateField::Container => "container",
+            TemplateField::Year => "year",
+            TemplateField::Volume => "volume",
+            TemplateField::Issue => "issue",
+            TemplateField::Pages => "pages",
+            TemplateField::Edition => "edition",
+            TemplateField::Doi => "doi",
+            TemplateField::Url => "url",
+        }
+    }
+
+    /// The field's value for `citation`, if it has one
+    pub fn value(self, citation: &Citation) -> Option<String> {
+        let value = match self {
+            TemplateField::AuthorsApa => authors(citation, CitationStyle::Apa),
+            TemplateField::AuthorsIeee => authors(citation, CitationStyle::Ieee),
+            TemplateField::Title => Some(citation.title()),
+            TemplateField::Container => match citation {
+                Citation::JournalArticle(article) => Some(article.journal.clone()),
+                Citation::ConferencePresentation(presentation) => {
+                    Some(presentation.series.full_name(&presentation.conference_name))
+                }
+                Citation::ConferencePaperOnline(paper) => {
+                    Some(paper.series.full_name(&paper.conference_name))
+                }
+                Citation::Software(software) => software.publisher.clone(),
+                Citation::WebPage(page) => page.site_name.clone(),
+                Citation::OnlineVideo(OnlineVideo::YouTube { .. }) => Some("YouTube".to_string()),
+                Citation::WikipediaArticle(_) => Some("Wikipedia".to_string()),
+                _ => None,
+            },
+            TemplateField::Year => citation
+                .published()
+                .map(|published| published.year().to_string()),
+            TemplateField::Volume => match citation {
+                Citation::JournalArticle(article) => article.volume.as_ref().map(|v| v.to_string()),
+                Citation::ConferencePaperOnline(paper) => {
+                    paper.volume.as_ref().map(|v| v.to_string())
+                }
+                _ => None,
+            },
+            TemplateField::Issue => match citation {
+                Citation::JournalArticle(article) => article.number.as_ref().map(|n| n.to_string()),
+                Citation::ConferencePaperOnline(paper) => {
+                    paper.number.as_ref().map(|n| n.to_string())
+                }
+                _ => None,
+            },
+            TemplateField::Pages => match citation {
+                Citation::JournalArticle(article) => match &article.locator {
+                    Some(ArticleLocator::Pages(pages)) => Some(pages.as_apa_string()),
+                    Some(ArticleLocator::ArticleNumber(number)) => Some(number.clone()),
+                    None => None,
+                },
+                Citation::Book(book) => book.pages.as_ref().map(PageRange::as_apa_string),
+                _ => None,
+            },
+            TemplateField::Edition => match citation {
+                Citation::Book(book) => match &book.version {
+                    Some(GenericMediaVersion::Edition { number })
+                    | Some(GenericMediaVersion::DigitalEdition { number }) => {
+                        Some(number.to_string())
+                    }
+                    _ => None,
+                },
+                Citation::Software(software) => software.version.clone(),
+                _ => None,
+            },
+            TemplateField::Doi => citation.doi(),
+            TemplateField::Url => citation.url(),
+        };
+        value.filter(|value| !value.trim().is_empty())
+    }
+}
+
+impl FromStr for TemplateField {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FIELDS
+            .into_iter()
+            .find(|field| field.name() == s.trim())
+            .ok_or_else(|| TemplateError::UnknownField(s.to_string()))
+    }
+}
+
+fn authors(citation: &Citation, style: CitationStyle) -> Option<String> {
+    let options = FormatOptions::for_style(style);
+    AuthorListRules::for_style(style).join(
+        &citation
+            .contributors()
+            .iter()
+            .map(|contributor| contributor.as_string_with(style, &options))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplatePart {
+    Literal(String),
+    Field(TemplateField),
+}
+
+/// A custom citation format: literal text and fields, in order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Template {
+    pub parts: Vec<TemplatePart>,
+}
+
+impl Template {
+    /// Infer a template from `example`, a correctly formatted citation of
+    /// `citation`.
+    ///
+    /// Every field whose value appears in the example as a whole word is
+    /// replaced by the field; the text around them is kept as literal
+    /// punctuation. Longer values are matched first, so a year inside a
+    /// DOI isn't mistaken for the year.
+    pub fn infer(example: &str, citation: &Citation) -> Result<Self, TemplateError> {
+        let mut candidates: Vec<(TemplateField, String)> = FIELDS
+            .into_iter()
+            .filter_map(|field| field.value(citation).map(|value| (field, value)))
+            .collect();
+        candidates.sort_by_key(|(_, value)| std::cmp::Reverse(value.len()));
+
+        let mut found: Vec<(usize, usize, TemplateField)> = Vec::new();
+        for (field, value) in candidates {
+            let position = example
+                .match_indices(value.as_str())
+                .find_map(|(start, _)| {
+                    let end = start + value.len();
+                    let free = found.iter().all(|&(s, e, _)| end <= s || start >= e);
+                    (free && is_word_boundary(example, start, end)).then_some((start, end))
+                });
+            if let Some((start, end)) = position {
+                found.push((start, end, field));
+            }
+        }
+        if found.is_empty() {
+            return Err(TemplateError::NoFieldsFound);
+        }
+        found.sort_by_key(|&(start, _, _)| start);
+
+        let mut parts = Vec::new();
+        let mut position = 0;
+        for (start, end, field) in found {
+            if start > position {
+                parts.push(TemplatePart::Literal(example[position..start].to_string()));
+            }
+            parts.push(TemplatePart::Field(field));
+            position = end;
+        }
+        if position < example.len() {
+            parts.push(TemplatePart::Literal(example[position..].to_string()));
+        }
+        Ok(Self { parts })
+    }
+
+    /// Fill in the template for `citation`.
+    ///
+    /// A field the citation doesn't have is left out together with the
+    /// text before it, and a closing bracket right after it.
+    pub fn render(&self, citation: &Citation) -> String {
+        let mut rendered = String::new();
+        let mut pending = "";
+        let mut skip_close = false;
+        for part in &self.parts {
+            match part {
+                TemplatePart::Literal(text) => {
+                    pending = if skip_close {
+                        text.strip_prefix([')', ']']).unwrap_or(text)
+                    } else {
+                        text
+                    };
+                    skip_close = false;
+                }
+                TemplatePart::Field(field) => match field.value(citation) {
+                    Some(value) => {
+                        push_literal(&mut rendered, pending);
+                        rendered.push_str(&value);
+                        pending = "";
+                    }
+                    None => {
+                        pending = "";
+                        skip_close = true;
+                    }
+                },
+            }
+        }
+        push_literal(&mut rendered, pending);
+        rendered
+    }
+}
+
+/// Append literal text, without doubling a period already at the end
+fn push_literal(rendered: &mut String, text: &str) {
+    match text.strip_prefix('.') {
+        Some(rest) if rendered.ends_with('.') => rendered.push_str(rest),
+        _ => rendered.push_str(text),
+    }
+}
+
+/// Whether `example[start..end]` isn't part of a longer word or number
+fn is_word_boundary(example: &str, start: usize, end: usize) -> bool {
+    let before = example[..start].chars().next_back();
+    let after = example[end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+/// Template text, with fields in braces
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in &self.parts {
+            match part {
+                TemplatePart::Literal(text) => write!(f, "{}", text)?,
+                TemplatePart::Field(field) => write!(f, "{{{}}}", field.name())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = Vec::new();
+        let mut rest = s;
+        while let Some(open) = rest.find('{') {
+            if open > 0 {
+                parts.push(TemplatePart::Literal(rest[..open].to_string()));
+            }
+            let close = rest[open..].find('}').ok_or(TemplateError::UnclosedField)?;
+            parts.push(TemplatePart::Field(rest[open + 1..open + close].parse()?));
+            rest = &rest[open + close + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Literal(rest.to_string()));
+        }
+        Ok(Self { parts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+            page_range::{ArticleLocator, PageRange},
+            volume::{Issue, Volume},
+        },
+        template::{Template, TemplateError},
+    };
+
+    fn article(issue: Option<&str>) -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month(2020, Month::March)),
+                ..CommonCitationData::new("smith2020")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_first_last("Hugo", "Fuentes").unwrap(),
+                ],
+            },
+            title: "Repairing DNA in 2020".into(),
+            journal: "J. Tests".to_string(),
+            volume: Some(Volume::parse("12")),
+            number: issue.map(Issue::parse),
+            locator: Some(ArticleLocator::Pages(PageRange {
+                start: 112,
+                end: 120,
+            })),
+            doi: Some("10.1000/2020.12".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_infer_template() {
+        let example = "J. Smith and H. Fuentes, \u{201C}Repairing DNA in 2020,\u{201D} \
+            J. Tests 12:3 (2020) 112\u{2013}120. doi:10.1000/2020.12";
+
+        let template = Template::infer(example, &article(Some("3"))).unwrap();
+
+        assert_eq!(
+            template.to_string(),
+            "{authors_ieee}, \u{201C}{title},\u{201D} {container} {volume}:{issue} ({year}) \
+            {pages}. doi:{doi}"
+        );
+        assert_eq!(template.render(&article(Some("3"))), example);
+    }
+
+    #[test]
+    fn test_render_drops_missing_fields() {
+        let template: Template =
+            "{authors_apa} ({year}). {title}. {container}, {volume}({issue}), {pages}."
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            template.render(&article(None)),
+            "Smith, J., & Fuentes, H. (2020). Repairing DNA in 2020. J. Tests, 12, 112\u{2013}120."
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "{nope}".parse::<Template>(),
+            Err(TemplateError::UnknownField("nope".to_string()))
+        );
+        assert_eq!(
+            "{title".parse::<Template>(),
+            Err(TemplateError::UnclosedField)
+        );
+        assert_eq!(
+            Template::infer("Nothing to see", &article(None)),
+            Err(TemplateError::NoFieldsFound)
+        );
+    }
+}
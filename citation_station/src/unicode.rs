@@ -1,3 +1,2 @@
-pub(crate) const LEFT_QUOTE: char = '\u{201C}';
-pub(crate) const RIGHT_QUOTE: char = '\u{201D}';
 pub(crate) const EMDASH: char = '\u{2014}';
+pub(crate) const ENDASH: char = '\u{2013}';
@@ -0,0 +1,309 @@
+//! Writing citations as a Microsoft Word bibliography source list, the
+//! `Sources.xml` file Word's Source Manager imports and the citations and
+//! bibliography fields of a document are built from.
+//!
+//! Format reference: ECMA-376 Part 1, §22.6 (Bibliography)
+
+use crate::{
+    api::{
+        author::{Contributor, PersonName},
+        citation::Citation,
+        media::{online_manual::OnlineManualAvailability, version::GenericMediaVersion},
+        page_range::{ArticleLocator, PageRange},
+    },
+    xml::escape,
+};
+
+const NAMESPACE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/bibliography";
+
+/// Write citations as a Word bibliography source list. Each citation ID
+/// becomes the source's tag, which Word's citation fields refer to.
+pub fn citations_to_word_sources(citations: &[Citation]) -> String {
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#.to_string(),
+        format!(
+            r#"<b:Sources SelectedStyle="" xmlns:b="{0}" xmlns="{0}">"#,
+            NAMESPACE
+        ),
+    ];
+    lines.extend(citations.iter().map(citation_to_word_source));
+    lines.push("</b:Sources>".to_string());
+    lines.join("\n")
+}
+
+/// Write a citation as one `<b:Source>` element of a Word source list
+pub fn citation_to_word_source(citation: &Citation) -> String {
+    let mut fields: Vec<String> = Vec::new();
+    let mut field = |name: &str, value: Option<String>| {
+        if let Some(value) = value
+            && !value.trim().is_empty()
+        {
+            fields.push(format!("<b:{0}>{1}</b:{0}>", name, escape(&value)));
+        }
+    };
+
+    field("Tag", Some(citation.id()));
+    field("SourceType", Some(source_type(citation).to_string()));
+    field("Title", Some(citation.title()));
+
+    match citation {
+        Citation::Book(book) => {
+            match &book.version {
+                Some(GenericMediaVersion::Edition { number })
+                | Some(GenericMediaVersion::DigitalEdition { number }) => {
+                    field("Edition", Some(number.to_string()))
+                }
+                Some(GenericMediaVersion::Volume { number }) => {
+                    field("Volume", Some(number.to_string()))
+                }
+                Some(version) => field("Edition", Some(version.as_apa_string())),
+                None => {}
+            }
+            field("Pages", book.pages.as_ref().map(pages));
+        }
+        Citation::JournalArticle(article) => {
+            field("JournalName", Some(article.journal.clone()));
+            field("Volume", article.volume.as_ref().map(ToString::to_string));
+            field("Issue", article.number.as_ref().map(ToString::to_string));
+            if let Some(ArticleLocator::Pages(range)) = &article.locator {
+                field("Pages", Some(pages(range)));
+            }
+        }
+        Citation::ConferencePresentation(presentation) => {
+            field(
+                "ConferenceName",
+                Some(presentation.series.full_name(&presentation.conference_name)),
+            );
+            field(
+                "City",
+                presentation
+                    .location
+                    .as_ref()
+                    .map(|location| location.city.clone()),
+            );
+        }
+        Citation::ConferencePaperOnline(paper) => {
+            field(
+                "ConferenceName",
+                Some(paper.series.full_name(&paper.conference_name)),
+            );
+            field("Volume", paper.volume.as_ref().map(ToString::to_string));
+            field("Issue", paper.number.as_ref().map(ToString::to_string));
+            field("City", paper.venue.clone());
+        }
+        Citation::ConferenceProceedingsOnline(proceedings) => {
+            field(
+                "ConferenceName",
+                Some(proceedings.series.full_name(&proceedings.conference_name)),
+            );
+            field(
+                "Volume",
+                proceedings.volume.as_ref().map(ToString::to_string),
+            );
+            field(
+                "Issue",
+                proceedings.number.as_ref().map(ToString::to_string),
+            );
+            field("City", proceedings.venue.clone());
+        }
+        Citation::OnlineManual(manual) => {
+            field(
+                "Version",
+                manual
+                    .version
+                    .as_ref()
+                    .map(GenericMediaVersion::as_apa_string),
+            );
+        }
+        Citation::OnlineVideo(_) => {}
+        Citation::Software(software) => {
+            field("Version", software.version.clone());
+            field("Publisher", software.publisher.clone());
+        }
+        Citation::WebPage(page) => field("InternetSiteTitle", page.site_name.clone()),
+        Citation::WikipediaArticle(_) => field("InternetSiteTitle", Some("Wikipedia".to_string())),
+        Citation::Custom(_) => {}
+    }
+
+    if let Some(published) = citation.published() {
+        field("Year", Some(published.year().to_string()));
+        field(
+            "Month",
+            published.month().map(|month| month.name().to_string()),
+        );
+        field("Day", published.day().map(|day| day.to_string()));
+    }
+    if let Some(accessed) = citation.accessed() {
+        field("YearAccessed", Some(accessed.year().to_string()));
+        field("MonthAccessed", Some(accessed.month().name().to_string()));
+        field("DayAccessed", Some(accessed.day().to_string()));
+    }
+    let doi = match citation {
+        Citation::OnlineManual(manual) => match &manual.available_at {
+            OnlineManualAvailability::DOI(doi) => Some(doi.clone()),
+            _ => citation.doi(),
+        },
+        _ => citation.doi(),
+    };
+    field("DOI", doi);
+    field("URL", citation.url());
+
+    let mut lines = vec!["<b:Source>".to_string()];
+    lines.extend(fields.iter().take(2).cloned());
+    if let Some(authors) = authors(citation) {
+        lines.push(authors);
+    }
+    lines.extend(fields.iter().skip(2).cloned());
+    lines.push("</b:Source>".to_string());
+    lines.join("\n")
+}
+
+/// Word's name for the kind of source
+fn source_type(citation: &Citation) -> &'static str {
+    match citation {
+        Citation::Book(book) if book.chapter.is_some() => "BookSection",
+        Citation::Book(_) => "Book",
+        Citation::JournalArticle(_) => "JournalArticle",
+        Citation::ConferencePresentation(_)
+        | Citation::ConferencePaperOnline(_)
+        | Citation::ConferenceProceedingsOnline(_) => "ConferenceProceedings",
+        Citation::OnlineVideo(_) => "DocumentFromInternetSite",
+        Citation::WebPage(_) | Citation::WikipediaArticle(_) => "InternetSite",
+        Citation::OnlineManual(_) | Citation::Software(_) => "ElectronicSource",
+        Citation::Custom(_) => "Misc",
+    }
+}
+
+/// The `<b:Author>` element with the citation's authors and, for
+/// proceedings, editors. An organization is written as a corporate
+/// author, which Word allows only one of.
+fn authors(citation: &Citation) -> Option<String> {
+    let mut persons: Vec<PersonName> = Vec::new();
+    let mut corporate: Option<String> = None;
+    for contributor in citation.contributors() {
+        match contributor {
+            Contributor::Person(person) => persons.push(person),
+            Contributor::Organization { name, .. } | Contributor::Pseudonym { name, .. } => {
+                corporate.get_or_insert(name);
+            }
+            Contributor::Anonymous => {}
+        }
+    }
+    if let Citation::Book(book) = citation {
+        persons.extend(book.with_authors.iter().cloned());
+    }
+
+    let mut roles: Vec<String> = Vec::new();
+    if !persons.is_empty() {
+        roles.push(format!("<b:Author>{}</b:Author>", name_list(&persons)));
+    } else if let Some(name) = corporate {
+        roles.push(format!(
+            "<b:Author><b:Corporate>{}</b:Corporate></b:Author>",
+            escape(&name)
+        ));
+    }
+    if let Citation::ConferenceProceedingsOnline(proceedings) = citation
+        && !proceedings.editors.is_empty()
+    {
+        roles.push(format!(
+            "<b:Editor>{}</b:Editor>",
+            name_list(&proceedings.editors)
+        ));
+    }
+    (!roles.is_empty()).then(|| format!("<b:Author>{}</b:Author>", roles.concat()))
+}
+
+fn name_list(persons: &[PersonName]) -> String {
+    let names: String = persons
+        .iter()
+        .map(|person| {
+            let first = person
+                .given_names()
+                .map(|given| format!("<b:First>{}</b:First>", escape(&given)))
+                .unwrap_or_default();
+            format!(
+                "<b:Person><b:Last>{}</b:Last>{}</b:Person>",
+                escape(person.surname()),
+                first
+            )
+        })
+        .collect();
+    format!("<b:NameList>{}</b:NameList>", names)
+}
+
+fn pages(range: &PageRange) -> String {
+    if range.start == range.end {
+        range.start.to_string()
+    } else {
+        format!("{}-{}", range.start, range.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Month;
+
+    use crate::{
+        api::{
+            author::{AcademicAuthor, PersonName},
+            citation::Citation,
+            date::PublishDate,
+            media::{common::CommonCitationData, journal_article::JournalArticle},
+            page_range::{ArticleLocator, PageRange},
+            volume::Volume,
+        },
+        word::{citation_to_word_source, citations_to_word_sources},
+    };
+
+    fn article() -> Citation {
+        Citation::JournalArticle(JournalArticle {
+            common_data: CommonCitationData {
+                published: Some(PublishDate::from_year_month(2020, Month::March)),
+                ..CommonCitationData::new("smith2020")
+            },
+            author: AcademicAuthor::Persons {
+                persons: vec![
+                    PersonName::from_first_last("Jane", "Smith").unwrap(),
+                    PersonName::from_last("Fuentes").unwrap(),
+                ],
+            },
+            title: "R&D in Yeast".into(),
+            journal: "J. Tests".to_string(),
+            volume: Some(Volume::parse("12")),
+            number: None,
+            locator: Some(ArticleLocator::Pages(PageRange { start: 3, end: 9 })),
+            doi: Some("10.1000/xyz".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_article_to_word_source() {
+        assert_eq!(
+            citation_to_word_source(&article()),
+            "<b:Source>
+<b:Tag>smith2020</b:Tag>
+<b:SourceType>JournalArticle</b:SourceType>
+<b:Author><b:Author><b:NameList>\
+<b:Person><b:Last>Smith</b:Last><b:First>Jane</b:First></b:Person>\
+<b:Person><b:Last>Fuentes</b:Last></b:Person>\
+</b:NameList></b:Author></b:Author>
+<b:Title>R&amp;D in Yeast</b:Title>
+<b:JournalName>J. Tests</b:JournalName>
+<b:Volume>12</b:Volume>
+<b:Pages>3-9</b:Pages>
+<b:Year>2020</b:Year>
+<b:Month>March</b:Month>
+<b:DOI>10.1000/xyz</b:DOI>
+</b:Source>"
+        );
+    }
+
+    #[test]
+    fn test_word_sources() {
+        let xml = citations_to_word_sources(&[article()]);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\""));
+        assert!(xml.contains("<b:Sources SelectedStyle=\"\" xmlns:b=\"http://schemas.openxmlformats.org/officeDocument/2006/bibliography\""));
+        assert!(xml.ends_with("</b:Source>\n</b:Sources>"));
+    }
+}
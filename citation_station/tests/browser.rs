@@ -0,0 +1,73 @@
+//! The browser HTTP client, run in a headless browser with
+//! `wasm-pack test --headless --firefox -- --features wasm --test browser`
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use citation_station::{
+    api::citation::Citation,
+    import::identifier::Identifier,
+    net::{AsyncHttpClient, BrowserClient, FetchError, HttpResponse},
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const CROSSREF_JSON: &str = r#"{
+    "status": "ok",
+    "message": {
+        "DOI": "10.1000/xyz123",
+        "type": "journal-article",
+        "title": ["A great paper"],
+        "author": [{"given": "Jane Q.", "family": "Smith"}],
+        "container-title": ["Journal of Examples"],
+        "published": {"date-parts": [[2023, 3]]}
+    }
+}"#;
+
+/// A URL that `fetch` answers without a network
+fn data_url(body: &str) -> String {
+    format!(
+        "data:application/json,{}",
+        String::from(js_sys::encode_uri_component(body))
+    )
+}
+
+#[wasm_bindgen_test]
+async fn test_get() {
+    let response = BrowserClient.get(&data_url("{}")).await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, "{}");
+}
+
+#[wasm_bindgen_test]
+async fn test_get_network_error() {
+    let result = BrowserClient.get("http://localhost:0/").await;
+
+    assert!(matches!(result, Err(FetchError::Transport { .. })));
+}
+
+/// Answers every request with `body`, fetched by the browser
+struct DataUrls {
+    body: &'static str,
+}
+
+impl AsyncHttpClient for DataUrls {
+    async fn get(&self, _url: &str) -> Result<HttpResponse, FetchError> {
+        BrowserClient.get(&data_url(self.body)).await
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_from_identifier_async() {
+    let client = DataUrls {
+        body: CROSSREF_JSON,
+    };
+    let (identifier, citation) = Citation::from_identifier_async(&client, "10.1000/xyz123")
+        .await
+        .unwrap();
+
+    assert_eq!(identifier, Identifier::Doi("10.1000/xyz123".to_string()));
+    assert!(matches!(citation, Citation::JournalArticle(_)));
+    assert_eq!(citation.title(), "A great paper");
+}